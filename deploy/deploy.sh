@@ -52,6 +52,7 @@ gen_cov() {
 }
 gen_cov default
 gen_cov no-default --no-default-features
+gen_cov examples --examples
 rm -rf $outdir
 mv $tmpdir $outdir
 chgrp -R srv $outdir
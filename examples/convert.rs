@@ -0,0 +1,69 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Convert a Gregorian date into many other calendars
+//!
+//! ```text
+//! cargo run --example convert -- 1969 7 20
+//! ```
+
+use radnelac::calendar::*;
+use radnelac::day_count::*;
+use radnelac::display::*;
+
+const CALENDARS: [(&str, EventRenderer); 15] = [
+    ("Armenian", render_long_date::<Armenian>),
+    ("Bengali", render_long_date::<Bengali>),
+    ("Coptic", render_long_date::<Coptic>),
+    ("Cotsworth", render_long_date::<Cotsworth>),
+    ("Egyptian", render_long_date::<Egyptian>),
+    ("Ethiopic", render_long_date::<Ethiopic>),
+    ("French Rev.", render_long_date::<FrenchRevArith<true>>),
+    ("Gregorian", render_long_date::<Gregorian>),
+    ("Holocene", render_long_date::<Holocene>),
+    ("ISO", render_long_date::<ISO>),
+    ("Julian", render_long_date::<Julian>),
+    ("Positivist", render_long_date::<Positivist>),
+    ("Symmetry454", render_long_date::<Symmetry454>),
+    ("Tamil", render_long_date::<Tamil>),
+    ("Tranquility", render_long_date::<Tranquility>),
+];
+
+fn parse_args() -> Result<Gregorian, String> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.is_empty() {
+        return Ok(Gregorian::try_new(1969, GregorianMonth::July, 20).expect("Valid date"));
+    }
+    let n: Vec<i64> = args
+        .iter()
+        .map(|a| a.parse::<i64>().map_err(|e| format!("{}: {}", a, e)))
+        .collect::<Result<_, _>>()?;
+    match n[..] {
+        [y, m, d] => Gregorian::try_from_ymd_i64(y, m as u8, d as u8).map_err(|e| e.to_string()),
+        _ => Err(String::from("Expected YEAR MONTH DAY")),
+    }
+}
+
+fn main() {
+    let g = match parse_args() {
+        Ok(g) => g,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+    let t = g.to_fixed();
+    for lang in [Language::EN, Language::FR] {
+        println!("{:?}", lang);
+        for (label, s) in Event::at("", t).render_in(lang, &CALENDARS) {
+            println!("  {:<14}{}", label, s);
+        }
+    }
+    println!("{:?}", g);
+    println!("Julian day {}", JulianDay::from_fixed(t).get());
+    match Date32::try_from(g) {
+        Ok(d) => println!("Date32 {}", d.get()),
+        Err(e) => println!("Date32 {}", e),
+    }
+}
@@ -0,0 +1,90 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! List some holidays and fasts of a Gregorian year
+//!
+//! Overlapping events are reported at the end.
+//!
+//! ```text
+//! cargo run --example holidays -- 2025
+//! ```
+
+use radnelac::calendar::*;
+use radnelac::day_count::*;
+use radnelac::day_cycle::*;
+use radnelac::display::*;
+use radnelac::CalendarError;
+use std::num::NonZero;
+
+fn span<T: ToFixed>(name: &str, (first, last): (T, T)) -> Event {
+    let interval = FixedInterval::closed(first.to_fixed(), last.to_fixed()).expect("Ordered span");
+    Event::new(name, interval)
+}
+
+fn holidays(year: i32) -> Result<Vec<Event>, CalendarError> {
+    let start = Gregorian::try_new(year, GregorianMonth::January, 1)?.to_fixed();
+    let coptic_year = Coptic::from_fixed(start).year();
+    let ethiopic_year = Ethiopic::from_fixed(start).year();
+    //Orthodox Easter of a Julian year is in the spring of the same Gregorian year
+    let julian_year = NonZero::new(year).ok_or(CalendarError::InvalidYear)?;
+    let mut events = vec![
+        Event::at(
+            "Labour Day (Canada, United States)",
+            Gregorian::first_kday_of_month(year, GregorianMonth::September, Weekday::Monday)?
+                .to_fixed(),
+        ),
+        Event::at(
+            "Thanksgiving (United States)",
+            Gregorian::nth_kday_of_month(
+                year,
+                GregorianMonth::November,
+                NonZero::new(4).expect("4 is non-zero"),
+                Weekday::Thursday,
+            )?
+            .to_fixed(),
+        ),
+        Event::at("Orthodox Easter", Julian::easter(julian_year).to_fixed()),
+        Event::at("Coptic Easter", Coptic::easter(coptic_year).to_fixed()),
+        Event::at("Fasika", Ethiopic::easter(ethiopic_year).to_fixed()),
+        span("Great Lent (Coptic)", Coptic::great_lent(coptic_year)),
+        span(
+            "Apostles' Fast (Coptic)",
+            Coptic::apostles_fast(coptic_year),
+        ),
+        span(
+            "Fast of the Virgin (Coptic)",
+            Coptic::fast_of_the_virgin(coptic_year),
+        ),
+    ];
+    events.retain(|e| Gregorian::from_fixed(e.first_day()).year() == year);
+    events.sort_by(|a, b| a.first_day().partial_cmp(&b.first_day()).expect("No NaN"));
+    Ok(events)
+}
+
+fn main() {
+    let year = std::env::args()
+        .nth(1)
+        .and_then(|a| a.parse::<i32>().ok())
+        .unwrap_or(2025);
+    let events = match holidays(year) {
+        Ok(events) => events,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+    let calendars: [(&str, EventRenderer); 2] = [
+        ("Gregorian", render_long_date::<Gregorian>),
+        ("Coptic", render_long_date::<Coptic>),
+    ];
+    for e in events.iter() {
+        println!("{}", e.name());
+        for (label, s) in e.render_in(Language::EN, &calendars) {
+            println!("  {:<10}{}", label, s);
+        }
+    }
+    for (i, j) in Event::conflicts(&events) {
+        println!("{} overlaps {}", events[i].name(), events[j].name());
+    }
+}
@@ -0,0 +1,56 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Print a month of the Gregorian calendar as a grid
+//!
+//! Rest days are marked with `*`.
+//!
+//! ```text
+//! cargo run --example month_grid -- 2025 7
+//! ```
+
+use num_traits::FromPrimitive;
+use radnelac::calendar::*;
+use radnelac::display::*;
+
+fn main() {
+    let args: Vec<i32> = std::env::args()
+        .skip(1)
+        .filter_map(|a| a.parse().ok())
+        .collect();
+    let (year, m) = match args[..] {
+        [y, m] => (y, m),
+        _ => (2025, 7),
+    };
+    let Some(month) = u8::try_from(m).ok().and_then(GregorianMonth::from_u8) else {
+        eprintln!("Invalid month {}", m);
+        std::process::exit(1);
+    };
+    let rule = WeekRule::ISO;
+    let grid = match rule.month_grid::<GregorianMonth, Gregorian>(year, month) {
+        Ok(grid) => grid,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+    let lang = Language::EN;
+    println!("{:?} {}", month, year);
+    let header = weekday_header(lang, rule.first_weekday()).expect("English is supported");
+    print!("Wk ");
+    for name in header {
+        print!("{:>4}", &name[..2]);
+    }
+    println!();
+    for row in grid {
+        print!("{:>2} ", row.week);
+        for (d, rest) in row.days.iter().zip(row.rest_days()) {
+            match d {
+                Some(d) => print!("{:>3}{}", d.day(), if rest { '*' } else { ' ' }),
+                None => print!("    "),
+            }
+        }
+        println!();
+    }
+}
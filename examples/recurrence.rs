@@ -0,0 +1,91 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Expand some recurring events into dates
+//!
+//! ```text
+//! cargo run --example recurrence -- 2028
+//! ```
+
+use num_traits::FromPrimitive;
+use radnelac::calendar::*;
+use radnelac::day_count::*;
+use radnelac::day_cycle::*;
+use radnelac::display::*;
+use radnelac::math::*;
+use radnelac::CalendarError;
+use std::num::NonZero;
+
+/// Fourth Saturday of every month
+fn fourth_saturdays(year: i32) -> Vec<Gregorian> {
+    let fourth = NonZero::new(4).expect("4 is non-zero");
+    (1..=12)
+        .filter_map(GregorianMonth::from_u8)
+        .filter_map(|m| Gregorian::nth_kday_of_month(year, m, fourth, Weekday::Saturday).ok())
+        .collect()
+}
+
+/// Anniversaries of a leap day, with each policy for common years
+fn leap_day_anniversaries(first_year: i32) -> Vec<(AnniversaryPolicy, Vec<Gregorian>)> {
+    let birthday = Gregorian::try_new(2024, GregorianMonth::February, 29).expect("Leap day");
+    [
+        AnniversaryPolicy::Skip,
+        AnniversaryPolicy::Before,
+        AnniversaryPolicy::After,
+    ]
+    .into_iter()
+    .map(|p| {
+        let dates = (first_year..(first_year + 4))
+            .filter_map(|y| birthday.anniversary_in(y, p))
+            .collect();
+        (p, dates)
+    })
+    .collect()
+}
+
+/// Rest days of the French Revolutionary calendar within a Gregorian month
+fn decadi(year: i32) -> Result<Vec<FrenchRevArith<true>>, CalendarError> {
+    let start = Gregorian::try_new(year, GregorianMonth::September, 1)?.to_fixed();
+    let end = Gregorian::try_new(year, GregorianMonth::October, 1)?.to_fixed();
+    let is_rest_day = |t: Fixed| FrenchRevArith::<true>::from_fixed(t).is_rest_day();
+    Ok(
+        fixed_days_matching(is_rest_day, range(start.get(), end.get())?)
+            .map(FrenchRevArith::<true>::from_fixed)
+            .collect(),
+    )
+}
+
+fn main() {
+    let year = std::env::args()
+        .nth(1)
+        .and_then(|a| a.parse::<i32>().ok())
+        .unwrap_or(2028);
+    println!("Fourth Saturday of every month");
+    for d in fourth_saturdays(year) {
+        println!(
+            "  {}  {}",
+            d.short_date(),
+            d.preset_str(Language::EN, NTH_WEEKDAY_OF_MONTH)
+        );
+    }
+    println!("Anniversaries of February 29");
+    for (policy, dates) in leap_day_anniversaries(year) {
+        let s: Vec<String> = dates.iter().map(|d| d.short_date()).collect();
+        println!("  {:<8}{}", format!("{:?}", policy), s.join(", "));
+    }
+    println!("French Revolutionary rest days in September");
+    match decadi(year) {
+        Ok(days) => {
+            for d in days {
+                let g = d.convert::<Gregorian>();
+                println!(
+                    "  {}  {}",
+                    g.short_date(),
+                    d.preset_str(Language::FR, d.long_date_preset())
+                );
+            }
+        }
+        Err(e) => eprintln!("{}", e),
+    }
+}
@@ -0,0 +1,163 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::common::error::CalendarError;
+use crate::common::units::MILLIS_PER_DAY;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::Date32;
+use crate::day_count::Epoch;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use crate::day_count::UnixMoment;
+#[cfg(feature = "display")]
+use crate::display::Language;
+#[cfg(feature = "display")]
+use crate::display::PresetDisplay;
+#[cfg(feature = "display")]
+use crate::display::PresetFormat;
+
+fn fixed_from_millis(ms: i64) -> Result<Fixed, CalendarError> {
+    let t = UnixMoment::epoch().get() + ((ms as f64) / (MILLIS_PER_DAY as f64));
+    Fixed::in_effective_bounds(t)?;
+    Ok(Fixed::new(t))
+}
+
+/// Convert days since the Unix epoch into dates
+///
+/// This matches the semantics of the Arrow `Date32` type.
+///
+/// ```
+/// use radnelac::arrow::*;
+/// use radnelac::calendar::*;
+///
+/// let d = dates_from_date32::<Julian>(&[0, 20223]);
+/// assert_eq!(d[0], Julian::try_new(1969, JulianMonth::December, 19).unwrap());
+/// assert_eq!(d[1], Julian::try_new(2025, JulianMonth::May, 2).unwrap());
+/// ```
+pub fn dates_from_date32<T: FromFixed>(days: &[i32]) -> Vec<T> {
+    days.iter().map(|d| Date32::new(*d).to_date()).collect()
+}
+
+/// Convert milliseconds since the Unix epoch into dates
+///
+/// This matches the semantics of the Arrow `Date64` type, and the Arrow `Timestamp` type
+/// with millisecond precision and no time zone.
+///
+/// Returns an error if any of the values are outside the supported range of time.
+pub fn dates_from_timestamp_millis<T: FromFixed>(millis: &[i64]) -> Result<Vec<T>, CalendarError> {
+    millis
+        .iter()
+        .map(|ms| Ok(T::from_fixed(fixed_from_millis(*ms)?)))
+        .collect()
+}
+
+/// Convert dates into days since the Unix epoch
+///
+/// This matches the semantics of the Arrow `Date32` type.
+///
+/// Returns an error if any of the values do not fit into an [`i32`].
+pub fn dates_to_date32<T: ToFixed>(dates: &[T]) -> Result<Vec<i32>, CalendarError> {
+    dates
+        .iter()
+        .map(|d| Ok(Date32::try_from_fixed(d.to_fixed())?.get()))
+        .collect()
+}
+
+/// Format days since the Unix epoch as dates in a specific calendar
+///
+/// This matches the semantics of the Arrow `Date32` type.
+///
+/// ```
+/// use radnelac::arrow::*;
+/// use radnelac::calendar::*;
+/// use radnelac::display::*;
+///
+/// let s = strings_from_date32::<Julian>(&[20223], Language::EN, YYYYMMDD_DASH);
+/// assert_eq!(s[0], "2025-05-02");
+/// ```
+///
+/// ## Crate Features
+///
+/// This is only available if `display` is enabled.
+#[cfg(feature = "display")]
+pub fn strings_from_date32<T: FromFixed + PresetDisplay>(
+    days: &[i32],
+    lang: Language,
+    preset: PresetFormat,
+) -> Vec<String> {
+    days.iter()
+        .map(|d| Date32::new(*d).to_date::<T>().preset_str(lang, preset))
+        .collect()
+}
+
+/// Format milliseconds since the Unix epoch as dates in a specific calendar
+///
+/// This matches the semantics of the Arrow `Date64` type, and the Arrow `Timestamp` type
+/// with millisecond precision and no time zone.
+///
+/// Returns an error if any of the values are outside the supported range of time.
+///
+/// ## Crate Features
+///
+/// This is only available if `display` is enabled.
+#[cfg(feature = "display")]
+pub fn strings_from_timestamp_millis<T: FromFixed + PresetDisplay>(
+    millis: &[i64],
+    lang: Language,
+    preset: PresetFormat,
+) -> Result<Vec<String>, CalendarError> {
+    millis
+        .iter()
+        .map(|ms| Ok(T::from_fixed(fixed_from_millis(*ms)?).preset_str(lang, preset)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Gregorian;
+    use crate::calendar::GregorianMoment;
+    use crate::calendar::GregorianMonth;
+    use crate::calendar::GuaranteedMonth;
+    use proptest::proptest;
+
+    #[test]
+    fn unix_epoch() {
+        let g = dates_from_date32::<Gregorian>(&[0, -1]);
+        assert_eq!(
+            g[0],
+            Gregorian::try_new(1970, GregorianMonth::January, 1).unwrap()
+        );
+        assert_eq!(
+            g[1],
+            Gregorian::try_new(1969, GregorianMonth::December, 31).unwrap()
+        );
+        let g = dates_from_timestamp_millis::<Gregorian>(&[86399999, 86400000]).unwrap();
+        assert_eq!(
+            g[0],
+            Gregorian::try_new(1970, GregorianMonth::January, 1).unwrap()
+        );
+        assert_eq!(
+            g[1],
+            Gregorian::try_new(1970, GregorianMonth::January, 2).unwrap()
+        );
+        assert!(dates_from_timestamp_millis::<Gregorian>(&[i64::MAX]).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrip(d in i32::MIN..i32::MAX) {
+            let g = dates_from_date32::<Gregorian>(&[d]);
+            assert_eq!(dates_to_date32(&g).unwrap(), vec![d]);
+        }
+
+        #[test]
+        fn millis(ms in (i32::MIN as i64 * 1000)..(i32::MAX as i64 * 1000)) {
+            let m = dates_from_timestamp_millis::<GregorianMoment>(&[ms]).unwrap();
+            let g = dates_from_date32::<Gregorian>(&[ms.div_euclid(86400000) as i32]);
+            assert_eq!(m[0].date(), g[0]);
+        }
+    }
+}
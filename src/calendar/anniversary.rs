@@ -0,0 +1,232 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::prelude::CommonDate;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::common::error::CalendarError;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use num_traits::FromPrimitive;
+
+//Irvember is the longest run of days which may be missing from a year
+const MAX_SHIFT: usize = 31;
+//Every leap day in the supported calendars recurs within this many years
+const MAX_SEARCH_YEARS: i32 = 400;
+
+/// Describes how to handle anniversaries of dates which do not occur every year
+///
+/// Examples of such dates include February 29 in the Gregorian calendar, Irvember in
+/// the Symmetry calendars, the 6th sansculottide in the French Revolutionary calendar
+/// and Aldrin Day in the Tranquility calendar.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum AnniversaryPolicy {
+    /// There is no anniversary in years where the date does not occur
+    Skip,
+    /// Use the last day before the missing date
+    Before,
+    /// Use the first day after the missing date
+    After,
+}
+
+/// Calendar systems in which the anniversary of a date can be calculated
+///
+/// ```
+/// use radnelac::calendar::*;
+///
+/// let birthday = Gregorian::try_new(2024, GregorianMonth::February, 29).unwrap();
+/// let today = Gregorian::try_new(2025, GregorianMonth::January, 10).unwrap();
+///
+/// let skip = birthday.next_anniversary(today, AnniversaryPolicy::Skip).unwrap();
+/// assert_eq!(skip, Gregorian::try_new(2028, GregorianMonth::February, 29).unwrap());
+/// let before = birthday.next_anniversary(today, AnniversaryPolicy::Before).unwrap();
+/// assert_eq!(before, Gregorian::try_new(2025, GregorianMonth::February, 28).unwrap());
+/// let after = birthday.next_anniversary(today, AnniversaryPolicy::After).unwrap();
+/// assert_eq!(after, Gregorian::try_new(2025, GregorianMonth::March, 1).unwrap());
+/// ```
+pub trait Anniversary<T: FromPrimitive>: ToFromCommonDate<T> + ToFixed + FromFixed {
+    /// Anniversary of a date in a specific year
+    ///
+    /// The anniversary has the same month and day as `self`. If that month and day does
+    /// not occur in `year`, the `policy` is applied. With [`AnniversaryPolicy::Before`]
+    /// or [`AnniversaryPolicy::After`], the result may be in the previous or next year.
+    fn anniversary_in(self, year: i32, policy: AnniversaryPolicy) -> Option<Self> {
+        let shift = match policy {
+            AnniversaryPolicy::Skip => 0.0,
+            AnniversaryPolicy::Before => -1.0,
+            AnniversaryPolicy::After => 1.0,
+        };
+        let mut d = self;
+        let mut y = year;
+        for _ in 0..MAX_SHIFT {
+            let c = d.to_common_date();
+            let result = Self::try_from_common_date(CommonDate::new(y, c.month, c.day));
+            if result.is_ok() || policy == AnniversaryPolicy::Skip {
+                return result.ok();
+            }
+            //Use the anniversary of the neighbouring day instead
+            let n = Self::from_fixed(Fixed::new(d.to_fixed().get() + shift));
+            y += n.year() - d.year();
+            d = n;
+        }
+        None
+    }
+
+    /// Next anniversary of a date, on or after `date`
+    ///
+    /// Returns an error if there is no anniversary within 400 years of `date`, or
+    /// if the next anniversary is outside the supported range of time.
+    fn next_anniversary(
+        self,
+        date: Self,
+        policy: AnniversaryPolicy,
+    ) -> Result<Self, CalendarError> {
+        let start = date.to_fixed();
+        //AnniversaryPolicy::After might move an anniversary into the following year
+        let first = date.year() - 1;
+        let last = date.year().saturating_add(MAX_SEARCH_YEARS);
+        for year in first..=last {
+            if year > Self::EFFECTIVE_YEAR_MAX {
+                return Err(CalendarError::OutOfBounds);
+            }
+            let next = self.anniversary_in(year, policy);
+            if let Some(a) = next.filter(|a| a.to_fixed() >= start) {
+                return Ok(a);
+            }
+        }
+        Err(CalendarError::ImpossibleResult)
+    }
+}
+
+impl<T: FromPrimitive, U: ToFromCommonDate<T> + ToFixed + FromFixed> Anniversary<T> for U {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::prelude::GuaranteedMonth;
+    use crate::calendar::Gregorian;
+    use crate::calendar::GregorianMonth;
+    use crate::calendar::Julian;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use proptest::proptest;
+
+    const SMALL_MAX: f64 = 365.25 * 1000000.0;
+    const POLICIES: [AnniversaryPolicy; 3] = [
+        AnniversaryPolicy::Skip,
+        AnniversaryPolicy::Before,
+        AnniversaryPolicy::After,
+    ];
+
+    #[test]
+    fn leap_day() {
+        let d = Gregorian::try_new(2024, GregorianMonth::February, 29).unwrap();
+        let r = Gregorian::try_new(2025, GregorianMonth::March, 1).unwrap();
+        let expected = [
+            Gregorian::try_new(2028, GregorianMonth::February, 29).unwrap(),
+            Gregorian::try_new(2026, GregorianMonth::February, 28).unwrap(),
+            Gregorian::try_new(2025, GregorianMonth::March, 1).unwrap(),
+        ];
+        for (p, e) in POLICIES.iter().zip(expected) {
+            assert_eq!(d.next_anniversary(r, *p).unwrap(), e);
+        }
+        let y1900 = [None, Some((2, 28)), Some((3, 1))];
+        for (p, e) in POLICIES.iter().zip(y1900) {
+            let a = d
+                .anniversary_in(1900, *p)
+                .map(|a| (a.month() as u8, a.day()));
+            assert_eq!(a, e);
+        }
+    }
+
+    #[test]
+    fn julian_year_zero() {
+        let d = Julian::try_year_start(5).unwrap();
+        assert!(d.anniversary_in(0, AnniversaryPolicy::Skip).is_none());
+        let before = d.anniversary_in(0, AnniversaryPolicy::Before).unwrap();
+        assert_eq!(before, Julian::try_year_end(-1).unwrap());
+        let r = Julian::try_year_start(-1).unwrap();
+        let a = d.next_anniversary(r, AnniversaryPolicy::Skip).unwrap();
+        assert_eq!(a, r);
+    }
+
+    #[cfg(feature = "reform-calendars")]
+    #[test]
+    fn irvember() {
+        use crate::calendar::HasLeapYears;
+        use crate::calendar::Symmetry454;
+        use crate::calendar::SymmetryMonth;
+        assert!(Symmetry454::is_leap(2009) && !Symmetry454::is_leap(2010));
+        let d = Symmetry454::try_new(2009, SymmetryMonth::Irvember, 7).unwrap();
+        let before = d.anniversary_in(2010, AnniversaryPolicy::Before).unwrap();
+        assert_eq!(before, Symmetry454::try_year_end(2010).unwrap());
+        let after = d.anniversary_in(2010, AnniversaryPolicy::After).unwrap();
+        assert_eq!(after, Symmetry454::try_year_start(2011).unwrap());
+        assert!(d.anniversary_in(2010, AnniversaryPolicy::Skip).is_none());
+    }
+
+    #[cfg(feature = "reform-calendars")]
+    #[test]
+    fn sansculottide() {
+        use crate::calendar::FrenchRevArith;
+        use crate::calendar::HasEpagemonae;
+        use crate::calendar::HasLeapYears;
+        use crate::calendar::Sansculottide;
+        type FR = FrenchRevArith<true>;
+        let leap = (1..100).find(|y| FR::is_leap(*y)).unwrap();
+        let d = FR::try_year_end(leap).unwrap();
+        assert_eq!(d.epagomenae(), Some(Sansculottide::Revolution));
+        let before = d
+            .anniversary_in(leap + 1, AnniversaryPolicy::Before)
+            .unwrap();
+        assert_eq!(before.epagomenae(), Some(Sansculottide::Recompense));
+        assert_eq!(before.year(), leap + 1);
+        let after = d
+            .anniversary_in(leap + 1, AnniversaryPolicy::After)
+            .unwrap();
+        assert_eq!(after, FR::try_year_start(leap + 2).unwrap());
+    }
+
+    #[cfg(feature = "reform-calendars")]
+    #[test]
+    fn aldrin_day() {
+        use crate::calendar::HasEpagemonae;
+        use crate::calendar::HasLeapYears;
+        use crate::calendar::Tranquility;
+        use crate::calendar::TranquilityComplementaryDay;
+        use crate::calendar::TranquilityMonth;
+        let leap = (1..100).find(|y| Tranquility::is_leap(*y)).unwrap();
+        let c = CommonDate::new(leap, 0, TranquilityComplementaryDay::AldrinDay as u8);
+        let d = Tranquility::try_from_common_date(c).unwrap();
+        assert_eq!(d.epagomenae(), Some(TranquilityComplementaryDay::AldrinDay));
+        let before = d
+            .anniversary_in(leap + 1, AnniversaryPolicy::Before)
+            .unwrap();
+        assert_eq!(before.try_month(), Some(TranquilityMonth::Hippocrates));
+        assert_eq!((before.year(), before.day()), (leap + 1, 27));
+        let after = d
+            .anniversary_in(leap + 1, AnniversaryPolicy::After)
+            .unwrap();
+        assert_eq!(after.try_month(), Some(TranquilityMonth::Hippocrates));
+        assert_eq!((after.year(), after.day()), (leap + 1, 28));
+    }
+
+    proptest! {
+        #[test]
+        fn next(t0 in FIXED_MIN..FIXED_MAX, t1 in -SMALL_MAX..SMALL_MAX) {
+            let d = Gregorian::from_fixed(Fixed::new(t0).to_day());
+            let r = Gregorian::from_fixed(Fixed::new(t1).to_day());
+            for p in POLICIES {
+                let a = d.next_anniversary(r, p).unwrap();
+                assert!(a >= r);
+                assert!(a.year() - r.year() <= 8);
+                if p == AnniversaryPolicy::Skip {
+                    assert_eq!((a.month(), a.day()), (d.month(), d.day()));
+                }
+            }
+        }
+    }
+}
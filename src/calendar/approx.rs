@@ -0,0 +1,346 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::prelude::CommonDate;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::common::error::CalendarError;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::EffectiveBound;
+use crate::day_count::Fixed;
+use crate::day_count::FixedInterval;
+use crate::day_count::ToFixed;
+use num_traits::FromPrimitive;
+use std::cmp::Ordering;
+
+/// Describes how an [`ApproxDate`] relates to the interval it was created from
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[non_exhaustive]
+pub enum ApproxQualifier {
+    /// The day is somewhere within the interval
+    Within,
+    /// The day is somewhere near the interval
+    About,
+    /// The day is before the interval
+    Before,
+    /// The day is after the interval
+    After,
+    /// The day is between two intervals
+    Between,
+}
+
+/// Represents a date which is only partially known
+///
+/// ## Introduction
+///
+/// Genealogical and archival records often contain dates which are incomplete or uncertain.
+/// For example, a record might only give the year of a birth, or say that a death occurred
+/// "before 1900".
+///
+/// `ApproxDate` represents such a date as an interval of days. The interval is inclusive:
+/// both the [`earliest`](ApproxDate::earliest) and the [`latest`](ApproxDate::latest) days
+/// are possible values for the actual date.
+///
+/// An `ApproxDate` can be created from a date in any calendar supported by this crate.
+///
+/// ## Comparison
+///
+/// Two `ApproxDate` values are only ordered if their intervals do not overlap, or if they are
+/// equal. If the intervals overlap, it is not known which date occurred first, so
+/// [`partial_cmp`](PartialOrd::partial_cmp) returns [`None`]. Values with the same interval
+/// but different qualifiers are also unordered.
+///
+/// Intervals are clamped to the effective bounds of [`Fixed`].
+///
+/// ## Representation and Examples
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// let y1850 = ApproxDate::year_of(Gregorian::try_year_start(1850).unwrap()).unwrap();
+/// let about_1850 = y1850.about(365 * 2);
+/// let before_1900 = ApproxDate::year_of(Gregorian::try_year_start(1900).unwrap())
+///     .unwrap()
+///     .before();
+/// let march_1851 = ApproxDate::month_of(Gregorian::try_new(1851, GregorianMonth::March, 1).unwrap())
+///     .unwrap();
+///
+/// assert!(y1850 < march_1851);
+/// assert!(about_1850.overlaps(march_1851));
+/// assert!(about_1850.partial_cmp(&march_1851).is_none());
+/// assert!(before_1900.overlaps(about_1850));
+/// assert!(before_1900.contains(Gregorian::try_new(1899, GregorianMonth::December, 31).unwrap()));
+/// assert!(!before_1900.contains(Gregorian::try_new(1900, GregorianMonth::January, 1).unwrap()));
+/// ```
+///
+/// ## Further reading
+/// + [Wikipedia](https://en.wikipedia.org/wiki/GEDCOM)
+///   + GEDCOM uses the keywords `ABT`, `BEF`, `AFT` and `BET` for similar purposes
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ApproxDate {
+    qualifier: ApproxQualifier,
+    earliest: Fixed,
+    latest: Fixed,
+}
+
+impl ApproxDate {
+    fn new(qualifier: ApproxQualifier, earliest: i64, latest: i64) -> ApproxDate {
+        let min = Fixed::effective_min().get_day_i();
+        let max = Fixed::effective_max().get_day_i();
+        let earliest = earliest.clamp(min, max);
+        let latest = latest.clamp(earliest, max);
+        ApproxDate {
+            qualifier,
+            earliest: Fixed::cast_new(earliest),
+            latest: Fixed::cast_new(latest),
+        }
+    }
+
+    /// Create an `ApproxDate` for a day which is known exactly
+    pub fn on<T: ToFixed>(date: T) -> ApproxDate {
+        let d = date.to_fixed().get_day_i();
+        ApproxDate::new(ApproxQualifier::Within, d, d)
+    }
+
+    /// Create an `ApproxDate` for the year containing a date
+    pub fn year_of<S, T>(date: T) -> Result<ApproxDate, CalendarError>
+    where
+        S: FromPrimitive,
+        T: ToFromCommonDate<S> + ToFixed,
+    {
+        let start = T::try_year_start(date.year())?.to_fixed().get_day_i();
+        let end = T::try_year_end(date.year())?.to_fixed().get_day_i();
+        Ok(ApproxDate::new(ApproxQualifier::Within, start, end))
+    }
+
+    /// Create an `ApproxDate` for the month containing a date
+    ///
+    /// Returns an error if the date is not associated with a month.
+    pub fn month_of<S, T>(date: T) -> Result<ApproxDate, CalendarError>
+    where
+        S: FromPrimitive,
+        T: ToFromCommonDate<S> + ToFixed,
+    {
+        let d = date.to_common_date();
+        match date.try_month() {
+            Some(month) => {
+                let length = T::month_length(d.year, month);
+                let start = T::try_from_common_date(CommonDate::new(d.year, d.month, 1))?;
+                let end = T::try_from_common_date(CommonDate::new(d.year, d.month, length))?;
+                Ok(ApproxDate::new(
+                    ApproxQualifier::Within,
+                    start.to_fixed().get_day_i(),
+                    end.to_fixed().get_day_i(),
+                ))
+            }
+            None => Err(CalendarError::InvalidMonth),
+        }
+    }
+
+    /// Create an `ApproxDate` for a day somewhere between two other `ApproxDate` values
+    ///
+    /// The arguments may be given in either order.
+    pub fn between(a: ApproxDate, b: ApproxDate) -> ApproxDate {
+        let start = a.earliest.get_day_i().min(b.earliest.get_day_i());
+        let end = a.latest.get_day_i().max(b.latest.get_day_i());
+        ApproxDate::new(ApproxQualifier::Between, start, end)
+    }
+
+    /// Widen the interval by `margin` days in both directions
+    pub fn about(self, margin: u32) -> ApproxDate {
+        let start = self.earliest.get_day_i() - (margin as i64);
+        let end = self.latest.get_day_i() + (margin as i64);
+        ApproxDate::new(ApproxQualifier::About, start, end)
+    }
+
+    /// Create an `ApproxDate` for any day before the interval
+    ///
+    /// If the interval starts at the effective minimum, only that day is possible.
+    pub fn before(self) -> ApproxDate {
+        let start = Fixed::effective_min().get_day_i();
+        let end = self.earliest.get_day_i() - 1;
+        ApproxDate::new(ApproxQualifier::Before, start, end)
+    }
+
+    /// Create an `ApproxDate` for any day after the interval
+    ///
+    /// If the interval ends at the effective maximum, only that day is possible.
+    pub fn after(self) -> ApproxDate {
+        let start = self.latest.get_day_i() + 1;
+        let end = Fixed::effective_max().get_day_i();
+        ApproxDate::new(ApproxQualifier::After, start, end)
+    }
+
+    /// How the interval relates to the date it was created from
+    pub fn qualifier(self) -> ApproxQualifier {
+        self.qualifier
+    }
+
+    /// Earliest possible day
+    pub fn earliest(self) -> Fixed {
+        self.earliest
+    }
+
+    /// Latest possible day
+    pub fn latest(self) -> Fixed {
+        self.latest
+    }
+
+    /// Interval of time covered by all possible days
+    pub fn interval(self) -> FixedInterval {
+        let end = Fixed::cast_new(self.latest.get_day_i() + 1);
+        FixedInterval::half_open(self.earliest, end).expect("latest >= earliest")
+    }
+
+    /// [`true`] if only one day is possible
+    pub fn is_exact(self) -> bool {
+        self.earliest == self.latest
+    }
+
+    /// [`true`] if the given date is within the interval
+    pub fn contains<T: ToFixed>(self, date: T) -> bool {
+        let d = date.to_fixed().get_day_i();
+        d >= self.earliest.get_day_i() && d <= self.latest.get_day_i()
+    }
+
+    /// [`true`] if at least one day is possible for both `self` and `other`
+    pub fn overlaps(self, other: ApproxDate) -> bool {
+        self.interval().overlaps(other.interval())
+    }
+}
+
+impl PartialOrd for ApproxDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.earliest == other.earliest && self.latest == other.latest {
+            if self.qualifier == other.qualifier {
+                Some(Ordering::Equal)
+            } else {
+                None
+            }
+        } else if self.latest < other.earliest {
+            Some(Ordering::Less)
+        } else if self.earliest > other.latest {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Gregorian;
+    use crate::calendar::GregorianMonth;
+    use crate::calendar::GuaranteedMonth;
+    use crate::calendar::Julian;
+    #[cfg(feature = "reform-calendars")]
+    use crate::calendar::Positivist;
+    use crate::day_count::FromFixed;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use proptest::proptest;
+
+    #[test]
+    fn month_lengths() {
+        let feb = Gregorian::try_new(2024, GregorianMonth::February, 10).unwrap();
+        let m = ApproxDate::month_of(feb).unwrap();
+        assert_eq!(m.latest().get_day_i() - m.earliest().get_day_i(), 28);
+        let y = ApproxDate::year_of(feb).unwrap();
+        assert_eq!(y.latest().get_day_i() - y.earliest().get_day_i(), 365);
+    }
+
+    #[test]
+    #[cfg(feature = "reform-calendars")]
+    fn perennial_month() {
+        let p = Positivist::try_from_common_date(CommonDate::new(200, 1, 5)).unwrap();
+        let m = ApproxDate::month_of(p).unwrap();
+        assert_eq!(m.latest().get_day_i() - m.earliest().get_day_i(), 27);
+    }
+
+    #[test]
+    #[cfg(feature = "reform-calendars")]
+    fn complementary_day() {
+        let p = Positivist::try_year_end(200).unwrap();
+        assert!(ApproxDate::month_of(p).is_err());
+    }
+
+    #[test]
+    fn julian_year() {
+        let j = ApproxDate::year_of(Julian::try_year_start(1700).unwrap()).unwrap();
+        let g = ApproxDate::year_of(Gregorian::try_year_start(1700).unwrap()).unwrap();
+        assert!(j.overlaps(g));
+        assert!(j.partial_cmp(&g).is_none());
+        assert_eq!(j.qualifier(), ApproxQualifier::Within);
+    }
+
+    #[test]
+    fn between() {
+        let a = ApproxDate::year_of(Gregorian::try_year_start(1850).unwrap()).unwrap();
+        let b = ApproxDate::year_of(Gregorian::try_year_start(1860).unwrap()).unwrap();
+        let c = ApproxDate::between(b, a);
+        assert_eq!(c, ApproxDate::between(a, b));
+        assert_eq!(c.earliest(), a.earliest());
+        assert_eq!(c.latest(), b.latest());
+        assert_eq!(c.qualifier(), ApproxQualifier::Between);
+    }
+
+    #[test]
+    fn qualifier_ordering() {
+        let a = ApproxDate::year_of(Gregorian::try_year_start(1850).unwrap()).unwrap();
+        let b = ApproxDate::between(a, a);
+        assert_ne!(a, b);
+        assert!(a.partial_cmp(&b).is_none());
+        assert_eq!(a.partial_cmp(&a), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn effective_bounds() {
+        let min = Fixed::effective_min().get_day_i();
+        let max = Fixed::effective_max().get_day_i();
+        let first = ApproxDate::on(Gregorian::from_fixed(Fixed::effective_min()));
+        let last = ApproxDate::on(Gregorian::from_fixed(Fixed::effective_max()));
+        for d in [
+            first.before(),
+            first.about(10),
+            last.after(),
+            last.about(10),
+        ] {
+            assert!(d.earliest().get_day_i() >= min);
+            assert!(d.latest().get_day_i() <= max);
+            assert!(d.earliest() <= d.latest());
+        }
+        assert!(first.before().is_exact());
+        assert!(last.after().is_exact());
+        let all = ApproxDate::between(first, last);
+        assert_eq!(all.about(u32::MAX).interval(), all.interval());
+    }
+
+    proptest! {
+        #[test]
+        fn ordering(t0 in FIXED_MIN..FIXED_MAX, t1 in FIXED_MIN..FIXED_MAX) {
+            let a = ApproxDate::on(Gregorian::from_fixed(Fixed::new(t0)));
+            let b = ApproxDate::on(Gregorian::from_fixed(Fixed::new(t1)));
+            assert!(a.is_exact());
+            let d0 = Fixed::new(t0).get_day_i();
+            let d1 = Fixed::new(t1).get_day_i();
+            assert_eq!(a.partial_cmp(&b), Some(d0.cmp(&d1)));
+            assert_eq!(a.overlaps(b), d0 == d1);
+        }
+
+        #[test]
+        fn before_after(t in -1000000.0..1000000.0, margin in 0..1000) {
+            let f = Gregorian::from_fixed(Fixed::new(t));
+            let y = ApproxDate::year_of(f).unwrap();
+            assert!(y.contains(f));
+            assert!(y.before() < y);
+            assert!(y.after() > y);
+            assert!(!y.before().overlaps(y.after()));
+            let a = y.about(margin as u32);
+            assert!(a.overlaps(y));
+            assert!(a.contains(f));
+            assert!(a.partial_cmp(&y).is_none());
+        }
+    }
+}
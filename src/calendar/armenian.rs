@@ -23,6 +23,7 @@ use crate::day_count::RataDie;
 use crate::day_count::ToFixed;
 #[allow(unused_imports)] //FromPrimitive is needed for derive
 use num_traits::FromPrimitive;
+use std::fmt;
 use std::num::NonZero;
 
 //LISTING 1.50 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
@@ -155,9 +156,15 @@ pub enum ArmenianDaysOfMonth {
 ///
 /// ## Further reading
 /// + [Wikipedia](https://en.wikipedia.org/wiki/Armenian_calendar)
-#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
 pub struct Armenian(CommonDate);
 
+impl fmt::Debug for Armenian {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<ArmenianMonth>("Armenian", f)
+    }
+}
+
 impl Armenian {
     /// Returns the day name of month if one exists
     pub fn day_name(self) -> Option<ArmenianDaysOfMonth> {
@@ -184,14 +191,13 @@ impl ToFromOrdinalDate for Armenian {
     }
 
     fn to_ordinal(self) -> OrdinalDate {
-        let e =
-            Egyptian::try_from_common_date(self.to_common_date()).expect("Same month/day validity");
+        let e = Egyptian::from_common_date_unchecked(self.to_common_date());
         e.to_ordinal()
     }
 
     fn from_ordinal_unchecked(ord: OrdinalDate) -> Self {
         let e = Egyptian::from_ordinal_unchecked(ord);
-        Armenian::try_from_common_date(e.to_common_date()).expect("Same month/day validity")
+        Armenian::from_common_date_unchecked(e.to_common_date())
     }
 }
 
@@ -207,16 +213,14 @@ impl FromFixed for Armenian {
     fn from_fixed(date: Fixed) -> Armenian {
         //LISTING 1.52 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
         let f = Fixed::new(date.get() + Egyptian::epoch().to_day().get() - Armenian::epoch().get());
-        Armenian::try_from_common_date(Egyptian::from_fixed(f).to_common_date())
-            .expect("Same month/day validity")
+        Armenian::from_common_date_unchecked(Egyptian::from_fixed(f).to_common_date())
     }
 }
 
 impl ToFixed for Armenian {
     fn to_fixed(self) -> Fixed {
         //LISTING 1.51 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
-        let e =
-            Egyptian::try_from_common_date(self.to_common_date()).expect("Same month/day validity");
+        let e = Egyptian::from_common_date_unchecked(self.to_common_date());
         Fixed::new(Armenian::epoch().get() + e.to_fixed().get() - Egyptian::epoch().to_day().get())
     }
 }
@@ -224,6 +228,9 @@ impl ToFixed for Armenian {
 /// The epagomenal days at the end of the Armenian calendar year are represented
 /// as month 13 when converting to and from a [`CommonDate`].
 impl ToFromCommonDate<ArmenianMonth> for Armenian {
+    const EFFECTIVE_YEAR_MIN: i32 = -47045703;
+    const EFFECTIVE_YEAR_MAX: i32 = 47044600;
+
     fn to_common_date(self) -> CommonDate {
         self.0
     }
@@ -274,6 +281,18 @@ impl Quarter for Armenian {
 
 impl CommonWeekOfYear<ArmenianMonth> for Armenian {}
 
+impl From<Egyptian> for Armenian {
+    fn from(date: Egyptian) -> Armenian {
+        date.convert::<Armenian>()
+    }
+}
+
+impl From<Armenian> for Egyptian {
+    fn from(date: Armenian) -> Egyptian {
+        date.convert::<Egyptian>()
+    }
+}
+
 /// Represents a date *and time* in the Armenian Calendar
 pub type ArmenianMoment = CalendarMoment<Armenian>;
 
@@ -0,0 +1,337 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::julian::Julian;
+use crate::calendar::metonic;
+use crate::calendar::prelude::CommonDate;
+use crate::calendar::prelude::GuaranteedMonth;
+use crate::calendar::prelude::HasLeapYears;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::AllowYearZero;
+use crate::calendar::CalendarMoment;
+use crate::calendar::OrdinalDate;
+use crate::calendar::ToFromOrdinalDate;
+use crate::common::error::CalendarError;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::CalculatedBounds;
+use crate::day_count::Epoch;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+#[allow(unused_imports)] //FromPrimitive is needed for derive
+use num_traits::FromPrimitive;
+use std::fmt;
+
+const METON_SOLSTICE_JULIAN: CommonDate = CommonDate {
+    year: -432,
+    month: 6,
+    day: 27,
+};
+const LEAP_OFFSET: i64 = 1;
+const INTERCALARY_MONTH: u8 = 7;
+
+/// Represents a month in the Attic calendar
+///
+/// The intercalary month [`AtticMonth::SecondPoseideon`] only occurs in leap years.
+/// In common years, [`AtticMonth::Poseideon`] is immediately followed by
+/// [`AtticMonth::Gamelion`].
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, FromPrimitive, ToPrimitive)]
+pub enum AtticMonth {
+    Hekatombaion = 1,
+    Metageitnion,
+    Boedromion,
+    Pyanepsion,
+    Maimakterion,
+    Poseideon,
+    SecondPoseideon,
+    Gamelion,
+    Anthesterion,
+    Elaphebolion,
+    Mounichion,
+    Thargelion,
+    Skirophorion,
+}
+
+/// Represents a date in an arithmetic approximation of the Attic calendar
+///
+/// ## Introduction
+///
+/// The Attic calendar was the festival calendar of ancient Athens. It was a lunisolar
+/// calendar: months followed the phases of the moon, and a thirteenth month was added to
+/// some years to keep the year aligned with the seasons.
+///
+/// `AtticArith` is **an approximation** of the Attic calendar. The historic calendar was
+/// regulated by magistrates, who could add or remove days for political or religious
+/// reasons. Such irregularities are not recorded well enough to be reproduced by
+/// any algorithm.
+///
+/// ### Approximation
+///
+/// This approximation is based on the 19 year cycle proposed by the Athenian astronomer
+/// Meton. **AtticArith does not read astronomical data nor approximate such data -
+/// instead it makes the following assumptions:**
+///
+/// * Every 19 years contain exactly 235 months and 6940 days.
+/// * Months start at evenly spaced intervals of 6940/235 days, rounded down to the
+///   nearest day. This results in "full" months of 30 days and "hollow" months of 29 days.
+/// * Years 3, 6, 8, 11, 14, 17 and 19 of each cycle are leap years, with a second
+///   Poseideon after the first. The historic intercalation pattern is unknown, so the
+///   pattern of the arithmetic Hebrew calendar is used instead.
+/// * The first cycle starts on the day after the summer solstice observed by Meton.
+///
+/// The results may differ by several days or a whole month from the dates
+/// reconstructed by historians for specific events.
+///
+/// ## Basic Structure
+///
+/// Common years have 12 months and 354 or 355 days. Leap years have 13 months and
+/// 383 or 384 days.
+///
+/// ## Epoch
+///
+/// Year 1 starts on the day after the summer solstice observed by Meton, which
+/// was 27 June 432 BC in the proleptic Julian calendar. Year 0 is supported.
+///
+/// The actual years in Athens were named after the eponymous archon.
+///
+/// ## Representation and Examples
+///
+/// The months are represented in this crate as [`AtticMonth`].
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// let j = Julian::try_new(-432, JulianMonth::June, 28).unwrap();
+/// let a = j.convert::<AtticArith>();
+/// assert_eq!(a.year(), 1);
+/// assert_eq!(a.month(), AtticMonth::Hekatombaion);
+/// assert_eq!(a.day(), 1);
+/// ```
+///
+/// When converting to and from a [`CommonDate`](crate::calendar::CommonDate), the months
+/// are numbered as if every year was a leap year. In common years, month 7 is skipped.
+///
+/// ```
+/// use radnelac::calendar::*;
+///
+/// assert!(AtticArith::is_leap(3));
+/// assert!(AtticArith::try_from_common_date(CommonDate::new(3, 7, 1)).is_ok());
+/// assert!(!AtticArith::is_leap(4));
+/// assert!(AtticArith::try_from_common_date(CommonDate::new(4, 7, 1)).is_err());
+/// ```
+///
+/// ## Further reading
+/// + Wikipedia
+///   + [Attic calendar](https://en.wikipedia.org/wiki/Attic_calendar)
+///   + [Metonic cycle](https://en.wikipedia.org/wiki/Metonic_cycle)
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
+pub struct AtticArith(CommonDate);
+
+impl fmt::Debug for AtticArith {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<AtticMonth>("AtticArith", f)
+    }
+}
+
+impl AtticArith {
+    fn month_index(year: i32, month: u8) -> i64 {
+        if month > INTERCALARY_MONTH && !Self::is_leap(year) {
+            (month as i64) - 2
+        } else {
+            (month as i64) - 1
+        }
+    }
+
+    fn year_length(year: i32) -> u16 {
+        let end = metonic::days_before_year(year + 1, LEAP_OFFSET);
+        (end - metonic::days_before_year(year, LEAP_OFFSET)) as u16
+    }
+}
+
+impl AllowYearZero for AtticArith {}
+
+impl HasLeapYears for AtticArith {
+    fn is_leap(year: i32) -> bool {
+        metonic::is_leap(year, LEAP_OFFSET)
+    }
+}
+
+impl ToFromOrdinalDate for AtticArith {
+    fn valid_ordinal(ord: OrdinalDate) -> Result<(), CalendarError> {
+        if ord.day_of_year < 1 || ord.day_of_year > AtticArith::year_length(ord.year) {
+            Err(CalendarError::InvalidDayOfYear)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn ordinal_from_fixed(fixed_date: Fixed) -> OrdinalDate {
+        let year = AtticArith::from_fixed(fixed_date).0.year;
+        let days = fixed_date.get_day_i() - AtticArith::epoch().get_day_i();
+        OrdinalDate {
+            year,
+            day_of_year: (days - metonic::days_before_year(year, LEAP_OFFSET) + 1) as u16,
+        }
+    }
+
+    fn to_ordinal(self) -> OrdinalDate {
+        let months = metonic::months_before_year(self.0.year, LEAP_OFFSET)
+            + AtticArith::month_index(self.0.year, self.0.month);
+        let days = metonic::days_before_month(months) + (self.0.day as i64)
+            - metonic::days_before_year(self.0.year, LEAP_OFFSET);
+        OrdinalDate {
+            year: self.0.year,
+            day_of_year: days as u16,
+        }
+    }
+
+    fn from_ordinal_unchecked(ord: OrdinalDate) -> Self {
+        let days = metonic::days_before_year(ord.year, LEAP_OFFSET) + (ord.day_of_year as i64) - 1;
+        AtticArith::from_fixed(Fixed::cast_new(AtticArith::epoch().get_day_i() + days))
+    }
+}
+
+impl CalculatedBounds for AtticArith {}
+
+impl Epoch for AtticArith {
+    fn epoch() -> Fixed {
+        let solstice = Julian::from_common_date_unchecked(METON_SOLSTICE_JULIAN).to_fixed();
+        Fixed::cast_new(solstice.get_day_i() + 1)
+    }
+}
+
+impl FromFixed for AtticArith {
+    fn from_fixed(date: Fixed) -> AtticArith {
+        let days = date.get_day_i() - AtticArith::epoch().get_day_i();
+        let months = metonic::months_before_day(days);
+        let year = metonic::year_of_month(months, LEAP_OFFSET);
+        let index = months - metonic::months_before_year(year, LEAP_OFFSET);
+        let month = if index < (INTERCALARY_MONTH as i64) - 1 || AtticArith::is_leap(year) {
+            index + 1
+        } else {
+            index + 2
+        };
+        let day = days - metonic::days_before_month(months) + 1;
+        AtticArith(CommonDate::new(year, month as u8, day as u8))
+    }
+}
+
+impl ToFixed for AtticArith {
+    fn to_fixed(self) -> Fixed {
+        let months = metonic::months_before_year(self.0.year, LEAP_OFFSET)
+            + AtticArith::month_index(self.0.year, self.0.month);
+        let days = metonic::days_before_month(months) + (self.0.day as i64) - 1;
+        Fixed::cast_new(AtticArith::epoch().get_day_i() + days)
+    }
+}
+
+impl ToFromCommonDate<AtticMonth> for AtticArith {
+    const EFFECTIVE_YEAR_MIN: i32 = -47010825;
+    const EFFECTIVE_YEAR_MAX: i32 = 47011689;
+
+    fn to_common_date(self) -> CommonDate {
+        self.0
+    }
+
+    fn from_common_date_unchecked(date: CommonDate) -> Self {
+        debug_assert!(Self::valid_ymd(date).is_ok());
+        Self(date)
+    }
+
+    fn valid_ymd(date: CommonDate) -> Result<(), CalendarError> {
+        match AtticMonth::from_u8(date.month) {
+            None => Err(CalendarError::InvalidMonth),
+            Some(m) => match Self::month_length(date.year, m) {
+                0 => Err(CalendarError::InvalidMonth),
+                len if date.day < 1 || date.day > len => Err(CalendarError::InvalidDay),
+                _ => Ok(()),
+            },
+        }
+    }
+
+    fn year_end_date(year: i32) -> CommonDate {
+        let m = AtticMonth::Skirophorion;
+        CommonDate::new(year, m as u8, Self::month_length(year, m))
+    }
+
+    /// Length of month in a given year
+    ///
+    /// Returns 0 for [`AtticMonth::SecondPoseideon`] in common years.
+    fn month_length(year: i32, month: AtticMonth) -> u8 {
+        let m = month as u8;
+        if m == INTERCALARY_MONTH && !Self::is_leap(year) {
+            return 0;
+        }
+        let months =
+            metonic::months_before_year(year, LEAP_OFFSET) + AtticArith::month_index(year, m);
+        let start = metonic::days_before_month(months);
+        (metonic::days_before_month(months + 1) - start) as u8
+    }
+}
+
+impl GuaranteedMonth<AtticMonth> for AtticArith {}
+
+/// Represents a date *and time* in an arithmetic approximation of the Attic calendar
+pub type AtticArithMoment = CalendarMoment<AtticArith>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_count::FIXED_MAX;
+    use proptest::proptest;
+    const MAX_YEARS: i32 = ((FIXED_MAX / 365.25) - 20000.0) as i32;
+
+    #[test]
+    fn metonic_cycle() {
+        let leap: Vec<i32> = (1..=19).filter(|y| AtticArith::is_leap(*y)).collect();
+        assert_eq!(leap, vec![3, 6, 8, 11, 14, 17, 19]);
+        let days: u32 = (1..=19).map(|y| AtticArith::year_length(y) as u32).sum();
+        assert_eq!(days as i64, metonic::CYCLE_DAYS);
+    }
+
+    #[test]
+    fn longest_year() {
+        let year = (1..=19)
+            .find(|y| AtticArith::year_length(*y) == 384)
+            .unwrap();
+        let ord = OrdinalDate::try_from((year, 384)).unwrap();
+        let d = AtticArith::try_from_ordinal(ord).unwrap();
+        assert_eq!(d.to_ordinal(), ord);
+    }
+
+    proptest! {
+        #[test]
+        fn year_structure(year in -MAX_YEARS..MAX_YEARS) {
+            let len = AtticArith::year_length(year);
+            if AtticArith::is_leap(year) {
+                assert!(len == 383 || len == 384);
+            } else {
+                assert!(len == 354 || len == 355);
+            }
+            let mut total = 0;
+            for m in 1..=13 {
+                let month = AtticMonth::from_u8(m).unwrap();
+                let ml = AtticArith::month_length(year, month);
+                if m == INTERCALARY_MONTH && !AtticArith::is_leap(year) {
+                    assert_eq!(ml, 0);
+                } else {
+                    assert!(ml == 29 || ml == 30);
+                }
+                total += ml as u16;
+            }
+            assert_eq!(total, len);
+            let start = AtticArith::try_year_start(year).unwrap().to_fixed();
+            let end = AtticArith::try_year_end(year).unwrap().to_fixed();
+            assert_eq!(end.get_day_i() - start.get_day_i() + 1, len as i64);
+        }
+
+        #[test]
+        fn cycle_length(year in -MAX_YEARS..MAX_YEARS) {
+            let d0 = metonic::days_before_year(year, LEAP_OFFSET);
+            let d1 = metonic::days_before_year(year + 19, LEAP_OFFSET);
+            assert_eq!(d1 - d0, metonic::CYCLE_DAYS);
+        }
+    }
+}
@@ -0,0 +1,339 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::julian::Julian;
+use crate::calendar::metonic;
+use crate::calendar::prelude::CommonDate;
+use crate::calendar::prelude::GuaranteedMonth;
+use crate::calendar::prelude::HasLeapYears;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::AllowYearZero;
+use crate::calendar::CalendarMoment;
+use crate::calendar::OrdinalDate;
+use crate::calendar::ToFromOrdinalDate;
+use crate::common::error::CalendarError;
+use crate::common::math::TermNum;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::CalculatedBounds;
+use crate::day_count::Epoch;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+#[allow(unused_imports)] //FromPrimitive is needed for derive
+use num_traits::FromPrimitive;
+use std::fmt;
+
+const SELEUCID_EPOCH_JULIAN: CommonDate = CommonDate {
+    year: -311,
+    month: 4,
+    day: 3,
+};
+const LEAP_OFFSET: i64 = 13;
+const SECOND_ULULU: u8 = 7;
+const SECOND_ADDARU: u8 = 14;
+
+/// Represents a month in the late Babylonian calendar
+///
+/// Leap years have either [`BabylonianMonth::SecondUlulu`] or
+/// [`BabylonianMonth::SecondAddaru`], but never both. Common years have neither.
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, FromPrimitive, ToPrimitive)]
+pub enum BabylonianMonth {
+    Nisannu = 1,
+    Ayyaru,
+    Simanu,
+    Duzu,
+    Abu,
+    Ululu,
+    SecondUlulu,
+    Tashritu,
+    Arahsamnu,
+    Kislimu,
+    Tebetu,
+    Shabatu,
+    Addaru,
+    SecondAddaru,
+}
+
+/// Represents a date in an arithmetic approximation of the late Babylonian calendar
+///
+/// ## Introduction
+///
+/// The Babylonian calendar was a lunisolar calendar used in Mesopotamia. From the late 6th
+/// century BC onwards, intercalary months were added according to a fixed 19 year cycle.
+/// This cycle was later adopted by the Hebrew calendar.
+///
+/// `BabylonianArith` is **an approximation** of the late Babylonian calendar. The historic
+/// calendar started each month on the evening when the crescent moon was first seen,
+/// so the length of each month depended on observations.
+///
+/// ### Approximation
+///
+/// **BabylonianArith does not read astronomical data nor approximate such data -
+/// instead it makes the following assumptions:**
+///
+/// * Every 19 years contain exactly 235 months and 6940 days.
+/// * Months start at evenly spaced intervals of 6940/235 days, rounded down to the
+///   nearest day. This results in months of 29 or 30 days.
+/// * The intercalary months follow the standard 19 year cycle exactly, even for
+///   years before the cycle was established.
+///
+/// The year, month and position of each intercalary month is consistent with the
+/// historic calendar for the years where the standard cycle was followed. The days
+/// where each month starts may differ by a day or two.
+///
+/// ## Basic Structure
+///
+/// Common years have 12 months. Leap years have 13 months: in the 17th year of
+/// each cycle, a second Ululu is added after Ululu. In years 3, 6, 8, 11, 14 and 19
+/// of each cycle, a second Addaru is added at the end of the year.
+///
+/// ## Epoch
+///
+/// Years are numbered according to the Seleucid Era as reckoned in Babylon. The first
+/// year started on 3 April 311 BC in the proleptic Julian calendar. Year 0 is supported.
+///
+/// ## Representation and Examples
+///
+/// The months are represented in this crate as [`BabylonianMonth`].
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// let j = Julian::try_new(-311, JulianMonth::April, 3).unwrap();
+/// let b = j.convert::<BabylonianArith>();
+/// assert_eq!(b.year(), 1);
+/// assert_eq!(b.month(), BabylonianMonth::Nisannu);
+/// assert_eq!(b.day(), 1);
+/// ```
+///
+/// When converting to and from a [`CommonDate`](crate::calendar::CommonDate), the months
+/// are numbered as if every year had both intercalary months. Common years skip months 7
+/// and 14, and leap years skip one of them.
+///
+/// ```
+/// use radnelac::calendar::*;
+///
+/// assert!(BabylonianArith::try_from_common_date(CommonDate::new(18, 7, 1)).is_ok());
+/// assert!(BabylonianArith::try_from_common_date(CommonDate::new(18, 14, 1)).is_err());
+/// assert!(BabylonianArith::try_from_common_date(CommonDate::new(20, 14, 1)).is_ok());
+/// assert!(BabylonianArith::try_from_common_date(CommonDate::new(21, 14, 1)).is_err());
+/// ```
+///
+/// ## Further reading
+/// + Wikipedia
+///   + [Babylonian calendar](https://en.wikipedia.org/wiki/Babylonian_calendar)
+///   + [Seleucid era](https://en.wikipedia.org/wiki/Seleucid_era)
+/// + *Babylonian Chronology 626 B.C. - A.D. 75* by Parker & Dubberstein
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
+pub struct BabylonianArith(CommonDate);
+
+impl fmt::Debug for BabylonianArith {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<BabylonianMonth>("BabylonianArith", f)
+    }
+}
+
+impl BabylonianArith {
+    /// Intercalary month of a year, if any
+    pub fn leap_month(year: i32) -> Option<BabylonianMonth> {
+        if !BabylonianArith::is_leap(year) {
+            None
+        } else if (year as i64).modulus(metonic::CYCLE_YEARS) == 18 {
+            Some(BabylonianMonth::SecondUlulu)
+        } else {
+            Some(BabylonianMonth::SecondAddaru)
+        }
+    }
+
+    fn month_index(year: i32, month: u8) -> i64 {
+        let ululu = BabylonianArith::leap_month(year) == Some(BabylonianMonth::SecondUlulu);
+        if month > SECOND_ULULU && !ululu {
+            (month as i64) - 2
+        } else {
+            (month as i64) - 1
+        }
+    }
+
+    fn months_before(self) -> i64 {
+        metonic::months_before_year(self.0.year, LEAP_OFFSET)
+            + BabylonianArith::month_index(self.0.year, self.0.month)
+    }
+
+    fn year_length(year: i32) -> u16 {
+        let end = metonic::days_before_year(year + 1, LEAP_OFFSET);
+        (end - metonic::days_before_year(year, LEAP_OFFSET)) as u16
+    }
+}
+
+impl AllowYearZero for BabylonianArith {}
+
+impl HasLeapYears for BabylonianArith {
+    fn is_leap(year: i32) -> bool {
+        metonic::is_leap(year, LEAP_OFFSET)
+    }
+}
+
+impl ToFromOrdinalDate for BabylonianArith {
+    fn valid_ordinal(ord: OrdinalDate) -> Result<(), CalendarError> {
+        if ord.day_of_year < 1 || ord.day_of_year > BabylonianArith::year_length(ord.year) {
+            Err(CalendarError::InvalidDayOfYear)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn ordinal_from_fixed(fixed_date: Fixed) -> OrdinalDate {
+        let year = BabylonianArith::from_fixed(fixed_date).0.year;
+        let days = fixed_date.get_day_i() - BabylonianArith::epoch().get_day_i();
+        OrdinalDate {
+            year,
+            day_of_year: (days - metonic::days_before_year(year, LEAP_OFFSET) + 1) as u16,
+        }
+    }
+
+    fn to_ordinal(self) -> OrdinalDate {
+        let days = metonic::days_before_month(self.months_before()) + (self.0.day as i64)
+            - metonic::days_before_year(self.0.year, LEAP_OFFSET);
+        OrdinalDate {
+            year: self.0.year,
+            day_of_year: days as u16,
+        }
+    }
+
+    fn from_ordinal_unchecked(ord: OrdinalDate) -> Self {
+        let days = metonic::days_before_year(ord.year, LEAP_OFFSET) + (ord.day_of_year as i64) - 1;
+        BabylonianArith::from_fixed(Fixed::cast_new(BabylonianArith::epoch().get_day_i() + days))
+    }
+}
+
+impl CalculatedBounds for BabylonianArith {}
+
+impl Epoch for BabylonianArith {
+    fn epoch() -> Fixed {
+        Julian::from_common_date_unchecked(SELEUCID_EPOCH_JULIAN).to_fixed()
+    }
+}
+
+impl FromFixed for BabylonianArith {
+    fn from_fixed(date: Fixed) -> BabylonianArith {
+        let days = date.get_day_i() - BabylonianArith::epoch().get_day_i();
+        let months = metonic::months_before_day(days);
+        let year = metonic::year_of_month(months, LEAP_OFFSET);
+        let index = months - metonic::months_before_year(year, LEAP_OFFSET);
+        let ululu = BabylonianArith::leap_month(year) == Some(BabylonianMonth::SecondUlulu);
+        let month = if index < (SECOND_ULULU as i64) - 1 || ululu {
+            index + 1
+        } else {
+            index + 2
+        };
+        let day = days - metonic::days_before_month(months) + 1;
+        BabylonianArith(CommonDate::new(year, month as u8, day as u8))
+    }
+}
+
+impl ToFixed for BabylonianArith {
+    fn to_fixed(self) -> Fixed {
+        let days = metonic::days_before_month(self.months_before()) + (self.0.day as i64) - 1;
+        Fixed::cast_new(BabylonianArith::epoch().get_day_i() + days)
+    }
+}
+
+impl ToFromCommonDate<BabylonianMonth> for BabylonianArith {
+    const EFFECTIVE_YEAR_MIN: i32 = -47010946;
+    const EFFECTIVE_YEAR_MAX: i32 = 47011568;
+
+    fn to_common_date(self) -> CommonDate {
+        self.0
+    }
+
+    fn from_common_date_unchecked(date: CommonDate) -> Self {
+        debug_assert!(Self::valid_ymd(date).is_ok());
+        Self(date)
+    }
+
+    fn valid_ymd(date: CommonDate) -> Result<(), CalendarError> {
+        match BabylonianMonth::from_u8(date.month) {
+            None => Err(CalendarError::InvalidMonth),
+            Some(m) => match Self::month_length(date.year, m) {
+                0 => Err(CalendarError::InvalidMonth),
+                len if date.day < 1 || date.day > len => Err(CalendarError::InvalidDay),
+                _ => Ok(()),
+            },
+        }
+    }
+
+    fn year_end_date(year: i32) -> CommonDate {
+        let m = if BabylonianArith::leap_month(year) == Some(BabylonianMonth::SecondAddaru) {
+            BabylonianMonth::SecondAddaru
+        } else {
+            BabylonianMonth::Addaru
+        };
+        CommonDate::new(year, m as u8, Self::month_length(year, m))
+    }
+
+    /// Length of month in a given year
+    ///
+    /// Returns 0 for intercalary months which do not occur in that year.
+    fn month_length(year: i32, month: BabylonianMonth) -> u8 {
+        let m = month as u8;
+        if (m == SECOND_ULULU || m == SECOND_ADDARU)
+            && BabylonianArith::leap_month(year) != Some(month)
+        {
+            return 0;
+        }
+        let months =
+            metonic::months_before_year(year, LEAP_OFFSET) + BabylonianArith::month_index(year, m);
+        let start = metonic::days_before_month(months);
+        (metonic::days_before_month(months + 1) - start) as u8
+    }
+}
+
+impl GuaranteedMonth<BabylonianMonth> for BabylonianArith {}
+
+/// Represents a date *and time* in an arithmetic approximation of the late Babylonian calendar
+pub type BabylonianArithMoment = CalendarMoment<BabylonianArith>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_count::FIXED_MAX;
+    use proptest::proptest;
+    const MAX_YEARS: i32 = ((FIXED_MAX / 365.25) - 20000.0) as i32;
+
+    #[test]
+    fn standard_cycle() {
+        //The cycle is usually numbered from 747 BC, so year 1 of the cycle is 2 SE
+        let leap: Vec<(i32, BabylonianMonth)> = (2..=20)
+            .filter_map(|y| BabylonianArith::leap_month(y).map(|m| (y - 1, m)))
+            .collect();
+        let a = BabylonianMonth::SecondAddaru;
+        let u = BabylonianMonth::SecondUlulu;
+        let expected = vec![(3, a), (6, a), (8, a), (11, a), (14, a), (17, u), (19, a)];
+        assert_eq!(leap, expected);
+    }
+
+    proptest! {
+        #[test]
+        fn year_structure(year in -MAX_YEARS..MAX_YEARS) {
+            let len = BabylonianArith::year_length(year);
+            let mut total = 0;
+            let mut count = 0;
+            for m in 1..=14 {
+                let month = BabylonianMonth::from_u8(m).unwrap();
+                let ml = BabylonianArith::month_length(year, month);
+                assert!(ml == 0 || ml == 29 || ml == 30);
+                if ml > 0 {
+                    count += 1;
+                }
+                total += ml as u16;
+            }
+            assert_eq!(total, len);
+            assert_eq!(count, if BabylonianArith::is_leap(year) { 13 } else { 12 });
+            let start = BabylonianArith::try_year_start(year).unwrap().to_fixed();
+            let end = BabylonianArith::try_year_end(year).unwrap().to_fixed();
+            assert_eq!(end.get_day_i() - start.get_day_i() + 1, len as i64);
+        }
+    }
+}
@@ -0,0 +1,297 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::gregorian::Gregorian;
+use crate::calendar::month_table::cumulative_lengths;
+use crate::calendar::month_table::MonthTable;
+use crate::calendar::prelude::CommonDate;
+use crate::calendar::prelude::CommonWeekOfYear;
+use crate::calendar::prelude::GuaranteedMonth;
+use crate::calendar::prelude::HasLeapYears;
+use crate::calendar::prelude::OrdinalDate;
+use crate::calendar::prelude::Quarter;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::AllowYearZero;
+use crate::calendar::CalendarMoment;
+use crate::calendar::ToFromOrdinalDate;
+use crate::common::error::CalendarError;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::CalculatedBounds;
+use crate::day_count::Epoch;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use std::num::NonZero;
+
+#[allow(unused_imports)] //FromPrimitive is needed for derive
+use num_traits::FromPrimitive;
+use std::fmt;
+
+const BENGALI_YEAR_OFFSET: i32 = 593;
+const BENGALI_NEW_YEAR_GREGORIAN_MONTH: u8 = 4;
+const BENGALI_NEW_YEAR_GREGORIAN_DAY: u8 = 14;
+
+/// Represents a month in the revised Bengali calendar
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, FromPrimitive, ToPrimitive)]
+pub enum BengaliMonth {
+    Boishakh = 1,
+    Joishtho,
+    Asharh,
+    Shrabon,
+    Bhadro,
+    Ashshin,
+    Kartik,
+    Ogrohayon,
+    Poush,
+    Magh,
+    Falgun,
+    Choitro,
+}
+
+/// Represents a date in the revised Bengali calendar
+///
+/// ## Introduction
+///
+/// The revised Bengali calendar is the official calendar of Bangladesh. It is a solar
+/// calendar which was originally based on astronomical observations, but was reformed
+/// into an arithmetical calendar synchronized with the Gregorian calendar.
+///
+/// This crate implements the revision adopted in 2019. The older revision, and the
+/// astronomical calendar still used in India, are not supported.
+///
+/// ## Basic Structure
+///
+/// Years always start on April 14 of the Gregorian calendar.
+///
+/// Years are divided into 12 months. The first 6 months have 31 days, and the remaining
+/// months have 30 days, except for Falgun. Falgun has 29 days in common years and 30 days
+/// in leap years.
+///
+/// A year is a leap year if the Gregorian year containing its month of Falgun is a
+/// Gregorian leap year.
+///
+/// ## Epoch
+///
+/// The first year of the calendar starts on April 14, 594 Common Era in the proleptic
+/// Gregorian calendar.
+///
+/// This epoch is called "Bangabda".
+///
+/// ## Representation and Examples
+///
+/// The months are represented in this crate as [`BengaliMonth`].
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// let g = Gregorian::try_new(2025, GregorianMonth::April, 14).unwrap();
+/// let b = g.convert::<Bengali>();
+/// assert_eq!(b, Bengali::try_new(1432, BengaliMonth::Boishakh, 1).unwrap());
+/// ```
+///
+/// ## Further reading
+/// + [Wikipedia](https://en.wikipedia.org/wiki/Bengali_calendars)
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
+pub struct Bengali(CommonDate);
+
+impl fmt::Debug for Bengali {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<BengaliMonth>("Bengali", f)
+    }
+}
+
+impl Bengali {
+    /// Fixed day number of the first day of a year
+    fn year_start_fixed(year: i32) -> i64 {
+        let g = Gregorian::from_common_date_unchecked(CommonDate::new(
+            year + BENGALI_YEAR_OFFSET,
+            BENGALI_NEW_YEAR_GREGORIAN_MONTH,
+            BENGALI_NEW_YEAR_GREGORIAN_DAY,
+        ));
+        g.to_fixed().get_day_i()
+    }
+
+    fn days_before_month(year: i32, month: u8) -> u16 {
+        let m = month as u16;
+        let correction = if month == 12 && !Bengali::is_leap(year) {
+            1
+        } else {
+            0
+        };
+        (31 * (m - 1).min(6)) + (30 * m.saturating_sub(7)) - correction
+    }
+}
+
+impl AllowYearZero for Bengali {}
+
+impl ToFromOrdinalDate for Bengali {
+    fn valid_ordinal(ord: OrdinalDate) -> Result<(), CalendarError> {
+        let correction = if Bengali::is_leap(ord.year) { 1 } else { 0 };
+        if ord.day_of_year > 0 && ord.day_of_year <= (365 + correction) {
+            Ok(())
+        } else {
+            Err(CalendarError::InvalidDayOfYear)
+        }
+    }
+
+    fn ordinal_from_fixed(fixed_date: Fixed) -> OrdinalDate {
+        let date = fixed_date.get_day_i();
+        let g_year = Gregorian::ordinal_from_fixed(fixed_date).year;
+        let mut year = g_year - BENGALI_YEAR_OFFSET;
+        if date < Bengali::year_start_fixed(year) {
+            year -= 1;
+        }
+        OrdinalDate {
+            year,
+            day_of_year: (date - Bengali::year_start_fixed(year) + 1) as u16,
+        }
+    }
+
+    fn to_ordinal(self) -> OrdinalDate {
+        OrdinalDate {
+            year: self.0.year,
+            day_of_year: Bengali::days_before_month(self.0.year, self.0.month)
+                + (self.0.day as u16),
+        }
+    }
+
+    fn from_ordinal_unchecked(ord: OrdinalDate) -> Self {
+        let month = (1..=12)
+            .rev()
+            .find(|m| Bengali::days_before_month(ord.year, *m) < ord.day_of_year)
+            .expect("Day of year known to be valid");
+        let day = ord.day_of_year - Bengali::days_before_month(ord.year, month);
+        Bengali(CommonDate::new(ord.year, month, day as u8))
+    }
+}
+
+impl HasLeapYears for Bengali {
+    fn is_leap(year: i32) -> bool {
+        Gregorian::is_leap(year + BENGALI_YEAR_OFFSET + 1)
+    }
+}
+
+const COMMON_MONTH_LENGTHS: [u8; 12] = [31, 31, 31, 31, 31, 31, 30, 30, 30, 30, 29, 30];
+const LEAP_MONTH_LENGTHS: [u8; 12] = [31, 31, 31, 31, 31, 31, 30, 30, 30, 30, 30, 30];
+
+impl MonthTable for Bengali {
+    const COMMON_MONTH_LENGTHS: &'static [u8] = &COMMON_MONTH_LENGTHS;
+    const LEAP_MONTH_LENGTHS: &'static [u8] = &LEAP_MONTH_LENGTHS;
+    const COMMON_DAYS_BEFORE_MONTH: &'static [u16] = &cumulative_lengths(COMMON_MONTH_LENGTHS);
+    const LEAP_DAYS_BEFORE_MONTH: &'static [u16] = &cumulative_lengths(LEAP_MONTH_LENGTHS);
+}
+
+impl CalculatedBounds for Bengali {}
+
+impl Epoch for Bengali {
+    fn epoch() -> Fixed {
+        Fixed::cast_new(Bengali::year_start_fixed(1))
+    }
+}
+
+impl FromFixed for Bengali {
+    fn from_fixed(fixed_date: Fixed) -> Bengali {
+        Self::from_ordinal_unchecked(Self::ordinal_from_fixed(fixed_date))
+    }
+}
+
+impl ToFixed for Bengali {
+    fn to_fixed(self) -> Fixed {
+        let offset_prior = Bengali::year_start_fixed(self.0.year) - 1;
+        Fixed::cast_new(offset_prior + (self.to_ordinal().day_of_year as i64))
+    }
+}
+
+impl ToFromCommonDate<BengaliMonth> for Bengali {
+    const EFFECTIVE_YEAR_MIN: i32 = -47014509;
+    const EFFECTIVE_YEAR_MAX: i32 = 47013323;
+
+    fn to_common_date(self) -> CommonDate {
+        self.0
+    }
+
+    fn from_common_date_unchecked(date: CommonDate) -> Self {
+        debug_assert!(Self::valid_ymd(date).is_ok());
+        Self(date)
+    }
+
+    fn valid_ymd(date: CommonDate) -> Result<(), CalendarError> {
+        match BengaliMonth::from_u8(date.month) {
+            None => Err(CalendarError::InvalidMonth),
+            Some(m) if date.day < 1 || date.day > Self::month_length(date.year, m) => {
+                Err(CalendarError::InvalidDay)
+            }
+            Some(_) => Ok(()),
+        }
+    }
+
+    fn year_end_date(year: i32) -> CommonDate {
+        let m = BengaliMonth::Choitro;
+        CommonDate::new(year, m as u8, Self::month_length(year, m))
+    }
+
+    fn month_length(year: i32, month: BengaliMonth) -> u8 {
+        match month {
+            BengaliMonth::Falgun if Bengali::is_leap(year) => 30,
+            BengaliMonth::Falgun => 29,
+            m if m <= BengaliMonth::Ashshin => 31,
+            _ => 30,
+        }
+    }
+}
+
+impl Quarter for Bengali {
+    fn quarter(self) -> NonZero<u8> {
+        NonZero::new(((self.to_common_date().month - 1) / 3) + 1).expect("(m-1)/3 > -1")
+    }
+}
+
+impl GuaranteedMonth<BengaliMonth> for Bengali {}
+impl CommonWeekOfYear<BengaliMonth> for Bengali {}
+
+/// Represents a date *and time* in the revised Bengali calendar
+pub type BengaliMoment = CalendarMoment<Bengali>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::GregorianMonth;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use proptest::proptest;
+
+    #[test]
+    fn falgun() {
+        // Falgun 1431 is in February 2025, Falgun 1430 is in February 2024
+        assert!(!Bengali::is_leap(1431));
+        assert!(Bengali::is_leap(1430));
+        let g = Gregorian::try_new(2024, GregorianMonth::February, 29).unwrap();
+        let b = g.convert::<Bengali>();
+        assert_eq!(b.to_common_date(), CommonDate::new(1430, 11, 16));
+    }
+
+    #[test]
+    fn month_lengths() {
+        for year in [1430, 1431] {
+            let total: u16 = (1..=12)
+                .map(|m| Bengali::month_length(year, BengaliMonth::from_u8(m).unwrap()) as u16)
+                .sum();
+            let expected = if Bengali::is_leap(year) { 366 } else { 365 };
+            assert_eq!(total, expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn new_year_april_14(t in FIXED_MIN..FIXED_MAX) {
+            let b = Bengali::from_fixed(Fixed::new(t));
+            let start = Bengali::try_year_start(b.year()).unwrap();
+            let g = start.convert::<Gregorian>();
+            assert_eq!(g.month(), GregorianMonth::April);
+            assert_eq!(g.day(), 14);
+            assert_eq!(g.year(), b.year() + BENGALI_YEAR_OFFSET);
+        }
+    }
+}
@@ -0,0 +1,508 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::gregorian::Gregorian;
+use crate::calendar::prelude::CommonDate;
+use crate::calendar::prelude::CommonWeekOfYear;
+use crate::calendar::prelude::GuaranteedMonth;
+use crate::calendar::prelude::HasLeapYears;
+use crate::calendar::prelude::OrdinalDate;
+use crate::calendar::prelude::Quarter;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::AllowYearZero;
+use crate::calendar::CalendarMoment;
+use crate::calendar::ToFromOrdinalDate;
+use crate::common::error::CalendarError;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::EffectiveBound;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use std::num::NonZero;
+use std::ops::RangeInclusive;
+
+#[allow(unused_imports)] //FromPrimitive is needed for derive
+use num_traits::FromPrimitive;
+use std::fmt;
+
+const TABLE_START_GREGORIAN: CommonDate = CommonDate {
+    year: 1913,
+    month: 4,
+    day: 13,
+};
+
+/// Years covered by [`MONTH_LENGTHS`]
+const TABLE_YEARS: RangeInclusive<i32> = 1970..=2082;
+
+/// Lengths of the months of each year in the table, starting with Baisakh
+///
+/// This is the same data as the month tables distributed with widely used Bikram Sambat
+/// date converters, which copy the calendars published in Nepal.
+const MONTH_LENGTHS: [[u8; 12]; 113] = [
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //1970
+    [31, 31, 32, 31, 32, 30, 30, 29, 30, 29, 30, 30], //1971
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //1972
+    [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], //1973
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //1974
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], //1975
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //1976
+    [30, 32, 31, 32, 31, 31, 29, 30, 29, 30, 29, 31], //1977
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //1978
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], //1979
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //1980
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 30, 30], //1981
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //1982
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], //1983
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //1984
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 30, 30], //1985
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //1986
+    [31, 32, 31, 32, 31, 30, 30, 29, 30, 29, 30, 30], //1987
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //1988
+    [31, 31, 31, 32, 31, 31, 30, 29, 30, 29, 30, 30], //1989
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //1990
+    [31, 32, 31, 32, 31, 30, 30, 29, 30, 29, 30, 30], //1991
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], //1992
+    [31, 31, 31, 32, 31, 31, 30, 29, 30, 29, 30, 30], //1993
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //1994
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 30], //1995
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], //1996
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //1997
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //1998
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //1999
+    [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], //2000
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2001
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], //2002
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //2003
+    [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], //2004
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2005
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], //2006
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //2007
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 29, 31], //2008
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2009
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], //2010
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //2011
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 30, 30], //2012
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2013
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], //2014
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //2015
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 30, 30], //2016
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2017
+    [31, 32, 31, 32, 31, 30, 30, 29, 30, 29, 30, 30], //2018
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], //2019
+    [31, 31, 31, 32, 31, 31, 30, 29, 30, 29, 30, 30], //2020
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2021
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 30], //2022
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], //2023
+    [31, 31, 31, 32, 31, 31, 30, 29, 30, 29, 30, 30], //2024
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2025
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //2026
+    [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], //2027
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2028
+    [31, 31, 32, 31, 32, 30, 30, 29, 30, 29, 30, 30], //2029
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //2030
+    [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], //2031
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2032
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], //2033
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //2034
+    [30, 32, 31, 32, 31, 31, 29, 30, 30, 29, 29, 31], //2035
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2036
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], //2037
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //2038
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 30, 30], //2039
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2040
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], //2041
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //2042
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 30, 30], //2043
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2044
+    [31, 32, 31, 32, 31, 30, 30, 29, 30, 29, 30, 30], //2045
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //2046
+    [31, 31, 31, 32, 31, 31, 30, 29, 30, 29, 30, 30], //2047
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2048
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 30], //2049
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], //2050
+    [31, 31, 31, 32, 31, 31, 30, 29, 30, 29, 30, 30], //2051
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2052
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 30], //2053
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], //2054
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2055
+    [31, 31, 32, 31, 32, 30, 30, 29, 30, 29, 30, 30], //2056
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //2057
+    [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], //2058
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2059
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], //2060
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //2061
+    [30, 32, 31, 32, 31, 31, 29, 30, 29, 30, 29, 31], //2062
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2063
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], //2064
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //2065
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 29, 31], //2066
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2067
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], //2068
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //2069
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 30, 30], //2070
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2071
+    [31, 32, 31, 32, 31, 30, 30, 29, 30, 29, 30, 30], //2072
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], //2073
+    [31, 31, 31, 32, 31, 31, 30, 29, 30, 29, 30, 30], //2074
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2075
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 30], //2076
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], //2077
+    [31, 31, 31, 32, 31, 31, 30, 29, 30, 29, 30, 30], //2078
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2079
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 30], //2080
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], //2081
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], //2082
+];
+
+const fn days_before_table_year() -> [u32; 114] {
+    let mut result = [0; 114];
+    let mut i = 0;
+    while i < 113 {
+        let mut total = 0;
+        let mut m = 0;
+        while m < 12 {
+            total += MONTH_LENGTHS[i][m] as u32;
+            m += 1;
+        }
+        result[i + 1] = result[i] + total;
+        i += 1;
+    }
+    result
+}
+
+const DAYS_BEFORE_TABLE_YEAR: [u32; 114] = days_before_table_year();
+
+/// Represents a month in the Bikram Sambat calendar
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, FromPrimitive, ToPrimitive)]
+pub enum BikramSambatMonth {
+    Baisakh = 1,
+    Jestha,
+    Asar,
+    Shrawan,
+    Bhadra,
+    Asoj,
+    Kartik,
+    Mangsir,
+    Poush,
+    Magh,
+    Falgun,
+    Chaitra,
+}
+
+/// Represents a date in the Bikram Sambat calendar
+///
+/// ## Introduction
+///
+/// The Bikram Sambat (or Vikram Samvat) calendar is the official calendar of Nepal. It is
+/// a sidereal solar calendar: each month starts when the sun enters a sign of the
+/// sidereal zodiac.
+///
+/// There is no arithmetic rule for the lengths of the months. In Nepal, they are set in
+/// advance by an official committee and published as a table.
+///
+/// `BikramSambat` is **table driven**. It contains the month lengths for the years 1970
+/// to 2082 BS inclusive (13 April 1913 to 13 April 2026 Common Era). There is no fallback
+/// outside of this range: creating a date in any other year returns
+/// [`CalendarError::OutOfBounds`], and the effective bounds of this calendar are the
+/// first and last days of the table. [`FromFixed::from_fixed`] cannot fail, so it returns
+/// the first or last day of the table for earlier or later days. Use
+/// [`EffectiveBound::contains`] to check a day first.
+///
+/// Years after 2082 BS will be added as their calendars are published.
+///
+/// ## Basic Structure
+///
+/// Years are divided into 12 months. Months have 29 to 32 days, and years have 365 or 366
+/// days. This crate treats a year with 366 days as a leap year.
+///
+/// ## Epoch
+///
+/// Years are counted from 57 Before Common Era. Since the table does not reach back to the
+/// epoch, this calendar does not implement [`Epoch`](crate::day_count::Epoch).
+///
+/// ## Representation and Examples
+///
+/// The months are represented in this crate as [`BikramSambatMonth`].
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+/// use radnelac::CalendarError;
+///
+/// let g = Gregorian::try_new(2024, GregorianMonth::April, 13).unwrap();
+/// let b = g.convert::<BikramSambat>();
+/// assert_eq!(b, BikramSambat::try_new(2081, BikramSambatMonth::Baisakh, 1).unwrap());
+///
+/// let e = BikramSambat::try_new(2100, BikramSambatMonth::Baisakh, 1);
+/// assert!(matches!(e, Err(CalendarError::OutOfBounds)));
+/// ```
+///
+/// ## Further reading
+/// + [Wikipedia](https://en.wikipedia.org/wiki/Vikram_Samvat)
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
+pub struct BikramSambat(CommonDate);
+
+impl fmt::Debug for BikramSambat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<BikramSambatMonth>("BikramSambat", f)
+    }
+}
+
+impl BikramSambat {
+    fn table_start() -> i64 {
+        Gregorian::from_common_date_unchecked(TABLE_START_GREGORIAN)
+            .to_fixed()
+            .get_day_i()
+    }
+
+    fn table_index(year: i32) -> Option<usize> {
+        if TABLE_YEARS.contains(&year) {
+            Some((year - TABLE_YEARS.start()) as usize)
+        } else {
+            None
+        }
+    }
+
+    fn year_length(year: i32) -> u16 {
+        match Self::table_index(year) {
+            Some(i) => (DAYS_BEFORE_TABLE_YEAR[i + 1] - DAYS_BEFORE_TABLE_YEAR[i]) as u16,
+            None => 0,
+        }
+    }
+}
+
+impl AllowYearZero for BikramSambat {}
+
+impl ToFromOrdinalDate for BikramSambat {
+    fn valid_ordinal(ord: OrdinalDate) -> Result<(), CalendarError> {
+        if !TABLE_YEARS.contains(&ord.year) {
+            Err(CalendarError::OutOfBounds)
+        } else if ord.day_of_year > 0 && ord.day_of_year <= Self::year_length(ord.year) {
+            Ok(())
+        } else {
+            Err(CalendarError::InvalidDayOfYear)
+        }
+    }
+
+    fn ordinal_from_fixed(fixed_date: Fixed) -> OrdinalDate {
+        let last = (DAYS_BEFORE_TABLE_YEAR[113] as i64) - 1;
+        let offset = (fixed_date.get_day_i() - Self::table_start()).clamp(0, last);
+        let i = DAYS_BEFORE_TABLE_YEAR.partition_point(|d| (*d as i64) <= offset) - 1;
+        OrdinalDate {
+            year: TABLE_YEARS.start() + (i as i32),
+            day_of_year: (offset - (DAYS_BEFORE_TABLE_YEAR[i] as i64) + 1) as u16,
+        }
+    }
+
+    fn to_ordinal(self) -> OrdinalDate {
+        let prior: u16 = (1..self.0.month)
+            .filter_map(BikramSambatMonth::from_u8)
+            .map(|m| Self::month_length(self.0.year, m) as u16)
+            .sum();
+        OrdinalDate {
+            year: self.0.year,
+            day_of_year: prior + (self.0.day as u16),
+        }
+    }
+
+    fn from_ordinal_unchecked(ord: OrdinalDate) -> Self {
+        let mut day = ord.day_of_year;
+        let mut month = BikramSambatMonth::Baisakh;
+        loop {
+            let length = Self::month_length(ord.year, month) as u16;
+            if day <= length || month == BikramSambatMonth::Chaitra {
+                break;
+            }
+            day -= length;
+            month = BikramSambatMonth::from_u8((month as u8) + 1).expect("Not the last month");
+        }
+        BikramSambat(CommonDate::new(ord.year, month as u8, day as u8))
+    }
+}
+
+impl HasLeapYears for BikramSambat {
+    /// [`true`] if the year has 366 days
+    fn is_leap(year: i32) -> bool {
+        Self::year_length(year) > 365
+    }
+}
+
+impl EffectiveBound for BikramSambat {
+    fn effective_min() -> Self {
+        Self(CommonDate::new(*TABLE_YEARS.start(), 1, 1))
+    }
+
+    fn effective_max() -> Self {
+        Self(Self::year_end_date(*TABLE_YEARS.end()))
+    }
+}
+
+impl FromFixed for BikramSambat {
+    fn from_fixed(fixed_date: Fixed) -> BikramSambat {
+        Self::from_ordinal_unchecked(Self::ordinal_from_fixed(fixed_date))
+    }
+}
+
+impl ToFixed for BikramSambat {
+    fn to_fixed(self) -> Fixed {
+        let i = Self::table_index(self.0.year).expect("Dates are only created within the table");
+        let offset_prior = Self::table_start() - 1 + (DAYS_BEFORE_TABLE_YEAR[i] as i64);
+        Fixed::cast_new(offset_prior + (self.to_ordinal().day_of_year as i64))
+    }
+}
+
+impl ToFromCommonDate<BikramSambatMonth> for BikramSambat {
+    const EFFECTIVE_YEAR_MIN: i32 = *TABLE_YEARS.start();
+    const EFFECTIVE_YEAR_MAX: i32 = *TABLE_YEARS.end();
+
+    fn to_common_date(self) -> CommonDate {
+        self.0
+    }
+
+    fn from_common_date_unchecked(date: CommonDate) -> Self {
+        debug_assert!(Self::valid_ymd(date).is_ok());
+        Self(date)
+    }
+
+    fn valid_ymd(date: CommonDate) -> Result<(), CalendarError> {
+        match BikramSambatMonth::from_u8(date.month) {
+            _ if !TABLE_YEARS.contains(&date.year) => Err(CalendarError::OutOfBounds),
+            None => Err(CalendarError::InvalidMonth),
+            Some(m) if date.day < 1 || date.day > Self::month_length(date.year, m) => {
+                Err(CalendarError::InvalidDay)
+            }
+            Some(_) => Ok(()),
+        }
+    }
+
+    fn year_end_date(year: i32) -> CommonDate {
+        let m = BikramSambatMonth::Chaitra;
+        CommonDate::new(year, m as u8, Self::month_length(year, m))
+    }
+
+    /// Length of month in a given year, or 0 if the year is outside the table
+    fn month_length(year: i32, month: BikramSambatMonth) -> u8 {
+        match Self::table_index(year) {
+            Some(i) => MONTH_LENGTHS[i][(month as usize) - 1],
+            None => 0,
+        }
+    }
+}
+
+impl Quarter for BikramSambat {
+    fn quarter(self) -> NonZero<u8> {
+        NonZero::new(((self.to_common_date().month - 1) / 3) + 1).expect("(m-1)/3 > -1")
+    }
+}
+
+impl GuaranteedMonth<BikramSambatMonth> for BikramSambat {}
+impl CommonWeekOfYear<BikramSambatMonth> for BikramSambat {}
+
+/// Represents a date *and time* in the Bikram Sambat calendar
+pub type BikramSambatMoment = CalendarMoment<BikramSambat>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use proptest::proptest;
+
+    #[test]
+    fn new_years() {
+        let d_list = [
+            (1970, CommonDate::new(1913, 4, 13)),
+            (2000, CommonDate::new(1943, 4, 14)),
+            (2076, CommonDate::new(2019, 4, 14)),
+            (2077, CommonDate::new(2020, 4, 13)),
+            (2078, CommonDate::new(2021, 4, 14)),
+            (2079, CommonDate::new(2022, 4, 14)),
+            (2080, CommonDate::new(2023, 4, 14)),
+            (2081, CommonDate::new(2024, 4, 13)),
+            (2082, CommonDate::new(2025, 4, 14)),
+        ];
+        for (year, g) in d_list {
+            let b = BikramSambat::try_year_start(year).unwrap();
+            assert_eq!(b.convert::<Gregorian>().to_common_date(), g);
+        }
+    }
+
+    #[test]
+    fn month_starts() {
+        let mut expected = BikramSambat::table_start();
+        for (i, lengths) in MONTH_LENGTHS.iter().enumerate() {
+            let year = TABLE_YEARS.start() + (i as i32);
+            for (j, len) in lengths.iter().enumerate() {
+                let month = BikramSambatMonth::from_u8((j + 1) as u8).unwrap();
+                let start = BikramSambat::try_new(year, month, 1).unwrap();
+                assert_eq!(start.to_fixed().get_day_i(), expected, "{}-{}", year, j + 1);
+                assert_eq!(BikramSambat::from_fixed(start.to_fixed()), start);
+                assert_eq!(BikramSambat::month_length(year, month), *len);
+                assert!(BikramSambat::try_new(year, month, *len).is_ok());
+                assert!(matches!(
+                    BikramSambat::try_new(year, month, *len + 1),
+                    Err(CalendarError::InvalidDay)
+                ));
+                expected += *len as i64;
+            }
+            let total: u16 = lengths.iter().map(|x| *x as u16).sum();
+            assert!(total == 365 || total == 366);
+            assert_eq!(BikramSambat::is_leap(year), total == 366);
+        }
+    }
+
+    #[test]
+    fn table_edges() {
+        let first = BikramSambat::effective_min();
+        assert_eq!(
+            first.convert::<Gregorian>().to_common_date(),
+            TABLE_START_GREGORIAN
+        );
+        let last = BikramSambat::effective_max();
+        assert_eq!(
+            last.convert::<Gregorian>().to_common_date(),
+            CommonDate::new(2026, 4, 13)
+        );
+        for year in [1969, 2083] {
+            assert!(matches!(
+                BikramSambat::try_new(year, BikramSambatMonth::Baisakh, 1),
+                Err(CalendarError::OutOfBounds)
+            ));
+            let ord = OrdinalDate {
+                year,
+                day_of_year: 1,
+            };
+            assert!(matches!(
+                BikramSambat::try_from_ordinal(ord),
+                Err(CalendarError::OutOfBounds)
+            ));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn table_round_trip(t in 698446.0..739720.0) {
+            let f = Fixed::new(t).to_day();
+            let b = BikramSambat::from_fixed(f);
+            assert!(BikramSambat::contains(f));
+            assert_eq!(b.to_fixed(), f);
+            let ord = b.to_ordinal();
+            assert_eq!(BikramSambat::try_from_ordinal(ord).unwrap(), b);
+            assert_eq!(BikramSambat::try_from_common_date(b.to_common_date()).unwrap(), b);
+        }
+
+        #[test]
+        fn outside_table(t in FIXED_MIN..FIXED_MAX) {
+            let f = Fixed::new(t).to_day();
+            let b = BikramSambat::from_fixed(f);
+            if f < BikramSambat::effective_min().to_fixed() {
+                assert!(!BikramSambat::contains(f));
+                assert_eq!(b, BikramSambat::effective_min());
+            } else if f > BikramSambat::effective_max().to_fixed() {
+                assert!(!BikramSambat::contains(f));
+                assert_eq!(b, BikramSambat::effective_max());
+            }
+        }
+    }
+}
@@ -0,0 +1,397 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::julian::Julian;
+use crate::calendar::prelude::CommonDate;
+use crate::calendar::prelude::GuaranteedMonth;
+use crate::calendar::prelude::HasLeapYears;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::AllowYearZero;
+use crate::calendar::CalendarMoment;
+use crate::calendar::OrdinalDate;
+use crate::calendar::ToFromOrdinalDate;
+use crate::common::error::CalendarError;
+use crate::common::math::TermNum;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::CalculatedBounds;
+use crate::day_count::Epoch;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+#[allow(unused_imports)] //FromPrimitive is needed for derive
+use num_traits::FromPrimitive;
+use std::fmt;
+
+const COLIGNY_EPOCH_JULIAN: CommonDate = CommonDate {
+    year: 1,
+    month: 1,
+    day: 1,
+};
+const CYCLE_YEARS: i64 = 5;
+const CYCLE_DAYS: i64 = 1835;
+const SAECULUM_YEARS: i64 = 30;
+const COMMON_YEAR_DAYS: i64 = 355;
+const INTERCALARY_DAYS: u8 = 30;
+const FIRST_INTERCALARY: u8 = 1;
+const SECOND_INTERCALARY: u8 = 8;
+const LAST_MONTH: u8 = 14;
+
+/// Represents a month in the Coligny calendar
+///
+/// The intercalary months [`ColignyMonth::Quimonios`] and [`ColignyMonth::Rantaranos`]
+/// only occur in some years.
+///
+/// The names of the intercalary months are only partially preserved on the tablet, so
+/// other sources may use different reconstructions of these names.
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, FromPrimitive, ToPrimitive)]
+pub enum ColignyMonth {
+    Quimonios = 1,
+    Samonios,
+    Dumannios,
+    Riuros,
+    Anagantios,
+    Ogronnios,
+    Cutios,
+    Rantaranos,
+    Giamonios,
+    Simivisonnos,
+    Equos,
+    Elembivos,
+    Edrinios,
+    Cantlos,
+}
+
+/// Represents a date in an arithmetic reconstruction of the Coligny calendar
+///
+/// ## Introduction
+///
+/// The Coligny calendar is a Gaulish lunisolar calendar engraved on a bronze tablet during
+/// the Roman period. The tablet was found near Coligny, France in 1897. It is the
+/// main source for the calendars used by the Celtic peoples of Gaul.
+///
+/// `Coligny` is **a reconstruction**. Only fragments of the tablet survive, and there is
+/// no consensus about how the calendar was kept over periods longer than the 5 years
+/// covered by the tablet.
+///
+/// ## Basic Structure
+///
+/// Common years have 12 months with 29 or 30 days each, for a total of 355 days.
+///
+/// Years are grouped into 5 year cycles. An intercalary month of 30 days is added
+/// before Samonios in the first year of each cycle, and another intercalary month of 30
+/// days is added before Giamonios in the third year of each cycle. This results in 62 months
+/// and 1835 days per cycle.
+///
+/// ### Reconstruction Variants
+///
+/// The 5 year cycle on the tablet is several days longer than 5 solar years, so it cannot
+/// have been repeated indefinitely without adjustments. The adjustments are determined by
+/// the parameter S.
+/// * S = false: the 5 year cycle on the tablet is repeated without adjustment.
+/// * S = true: the first intercalary month is omitted once every 30 years, as proposed by
+///   Garrett Olmsted. The 30 year period is sometimes called a *saeculum*.
+///
+/// **Neither variant reads astronomical data nor approximates such data.** In both variants,
+/// months drift relative to the phases of the moon over long periods of time.
+///
+/// ## Epoch
+///
+/// The correlation between the Coligny calendar and the Julian calendar is unknown.
+/// **This implementation arbitrarily starts year 1 on 1 January 1 AD in the proleptic
+/// Julian calendar.** Year 1 is the first year of a 5 year cycle and the first year of
+/// a *saeculum*. The results should not be used to date historic events. Year 0 is supported.
+///
+/// ## Representation and Examples
+///
+/// The months are represented in this crate as [`ColignyMonth`].
+///
+/// ```
+/// use radnelac::calendar::*;
+///
+/// let c = Coligny::<false>::try_new(2, ColignyMonth::Samonios, 1).unwrap();
+/// assert_eq!(c.month(), ColignyMonth::Samonios);
+/// assert!(Coligny::<false>::try_new(2, ColignyMonth::Quimonios, 1).is_err());
+/// assert!(Coligny::<false>::try_new(3, ColignyMonth::Rantaranos, 1).is_ok());
+/// ```
+///
+/// The parameter `S` determines whether one intercalary month is omitted every 30 years.
+///
+/// ```
+/// use radnelac::calendar::*;
+///
+/// assert!(Coligny::<false>::try_new(31, ColignyMonth::Quimonios, 1).is_ok());
+/// assert!(Coligny::<true>::try_new(31, ColignyMonth::Quimonios, 1).is_err());
+/// assert!(Coligny::<true>::try_new(36, ColignyMonth::Quimonios, 1).is_ok());
+/// ```
+///
+/// ## Further reading
+/// + [Wikipedia](https://en.wikipedia.org/wiki/Coligny_calendar)
+/// + *A Definitive Reconstructed Text of the Coligny Calendar* by Garrett Olmsted
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
+pub struct Coligny<const S: bool>(CommonDate);
+
+impl<const S: bool> fmt::Debug for Coligny<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<ColignyMonth>("Coligny", f)
+    }
+}
+
+impl<const S: bool> Coligny<S> {
+    /// `true` if the month occurs in the given year
+    pub fn has_month(year: i32, month: ColignyMonth) -> bool {
+        let y = (year as i64) - 1;
+        match month as u8 {
+            FIRST_INTERCALARY => {
+                y.modulus(CYCLE_YEARS) == 0 && !(S && y.modulus(SAECULUM_YEARS) == 0)
+            }
+            SECOND_INTERCALARY => y.modulus(CYCLE_YEARS) == 2,
+            _ => true,
+        }
+    }
+
+    fn days_before_year(year: i32) -> i64 {
+        let y = (year as i64) - 1;
+        let r = y.modulus(CYCLE_YEARS);
+        let intercalary = INTERCALARY_DAYS as i64;
+        let mut result = y.div_euclid(CYCLE_YEARS) * CYCLE_DAYS + r * COMMON_YEAR_DAYS;
+        if r >= 1 {
+            result += intercalary;
+        }
+        if r >= 3 {
+            result += intercalary;
+        }
+        if S {
+            //Number of omitted months before the year
+            result -= (y + SAECULUM_YEARS - 1).div_euclid(SAECULUM_YEARS) * intercalary;
+        }
+        result
+    }
+
+    fn year_length(year: i32) -> u16 {
+        (Self::days_before_year(year + 1) - Self::days_before_year(year)) as u16
+    }
+
+    fn days_before_month(year: i32, month: u8) -> i64 {
+        (FIRST_INTERCALARY..month)
+            .map(|m| Self::month_length(year, ColignyMonth::from_u8(m).expect("In range")) as i64)
+            .sum()
+    }
+}
+
+impl<const S: bool> AllowYearZero for Coligny<S> {}
+
+impl<const S: bool> HasLeapYears for Coligny<S> {
+    /// `true` if the year has an intercalary month
+    fn is_leap(year: i32) -> bool {
+        Self::has_month(year, ColignyMonth::Quimonios)
+            || Self::has_month(year, ColignyMonth::Rantaranos)
+    }
+}
+
+impl<const S: bool> ToFromOrdinalDate for Coligny<S> {
+    fn valid_ordinal(ord: OrdinalDate) -> Result<(), CalendarError> {
+        if ord.day_of_year < 1 || ord.day_of_year > Self::year_length(ord.year) {
+            Err(CalendarError::InvalidDayOfYear)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn ordinal_from_fixed(fixed_date: Fixed) -> OrdinalDate {
+        let days = fixed_date.get_day_i() - Self::epoch().get_day_i();
+        let (years, cycle) = if S {
+            (
+                SAECULUM_YEARS,
+                (SAECULUM_YEARS / CYCLE_YEARS) * CYCLE_DAYS - 30,
+            )
+        } else {
+            (CYCLE_YEARS, CYCLE_DAYS)
+        };
+        //Initial estimate is off by at most one or two years
+        let mut year = ((days * years).div_euclid(cycle) + 1) as i32;
+        while Self::days_before_year(year + 1) <= days {
+            year += 1;
+        }
+        while Self::days_before_year(year) > days {
+            year -= 1;
+        }
+        OrdinalDate {
+            year,
+            day_of_year: (days - Self::days_before_year(year) + 1) as u16,
+        }
+    }
+
+    fn to_ordinal(self) -> OrdinalDate {
+        let days = Self::days_before_month(self.0.year, self.0.month) + (self.0.day as i64);
+        OrdinalDate {
+            year: self.0.year,
+            day_of_year: days as u16,
+        }
+    }
+
+    fn from_ordinal_unchecked(ord: OrdinalDate) -> Self {
+        let mut days = ord.day_of_year as i64;
+        for m in FIRST_INTERCALARY..=LAST_MONTH {
+            let month = ColignyMonth::from_u8(m).expect("In range");
+            let len = Self::month_length(ord.year, month) as i64;
+            if days <= len {
+                return Coligny(CommonDate::new(ord.year, m, days as u8));
+            }
+            days -= len;
+        }
+        unreachable!("Day of year is checked to be within the year")
+    }
+}
+
+impl<const S: bool> CalculatedBounds for Coligny<S> {}
+
+impl<const S: bool> Epoch for Coligny<S> {
+    fn epoch() -> Fixed {
+        Julian::from_common_date_unchecked(COLIGNY_EPOCH_JULIAN).to_fixed()
+    }
+}
+
+impl<const S: bool> FromFixed for Coligny<S> {
+    fn from_fixed(date: Fixed) -> Coligny<S> {
+        Self::from_ordinal_unchecked(Self::ordinal_from_fixed(date))
+    }
+}
+
+impl<const S: bool> ToFixed for Coligny<S> {
+    fn to_fixed(self) -> Fixed {
+        let days = Self::days_before_year(self.0.year) + (self.to_ordinal().day_of_year as i64);
+        Fixed::cast_new(Self::epoch().get_day_i() + days - 1)
+    }
+}
+
+impl<const S: bool> ToFromCommonDate<ColignyMonth> for Coligny<S> {
+    const EFFECTIVE_YEAR_MIN: i32 = if S { -46916612 } else { -46788774 };
+    const EFFECTIVE_YEAR_MAX: i32 = if S { 46916613 } else { 46788775 };
+
+    fn to_common_date(self) -> CommonDate {
+        self.0
+    }
+
+    fn from_common_date_unchecked(date: CommonDate) -> Self {
+        debug_assert!(Self::valid_ymd(date).is_ok());
+        Self(date)
+    }
+
+    fn valid_ymd(date: CommonDate) -> Result<(), CalendarError> {
+        match ColignyMonth::from_u8(date.month) {
+            None => Err(CalendarError::InvalidMonth),
+            Some(m) => match Self::month_length(date.year, m) {
+                0 => Err(CalendarError::InvalidMonth),
+                len if date.day < 1 || date.day > len => Err(CalendarError::InvalidDay),
+                _ => Ok(()),
+            },
+        }
+    }
+
+    fn year_start_date(year: i32) -> CommonDate {
+        if Self::has_month(year, ColignyMonth::Quimonios) {
+            CommonDate::new(year, ColignyMonth::Quimonios as u8, 1)
+        } else {
+            CommonDate::new(year, ColignyMonth::Samonios as u8, 1)
+        }
+    }
+
+    fn year_end_date(year: i32) -> CommonDate {
+        let m = ColignyMonth::Cantlos;
+        CommonDate::new(year, m as u8, Self::month_length(year, m))
+    }
+
+    /// Length of month in a given year
+    ///
+    /// Returns 0 for intercalary months which do not occur in that year.
+    fn month_length(year: i32, month: ColignyMonth) -> u8 {
+        match month {
+            ColignyMonth::Quimonios | ColignyMonth::Rantaranos => {
+                if Self::has_month(year, month) {
+                    INTERCALARY_DAYS
+                } else {
+                    0
+                }
+            }
+            ColignyMonth::Samonios => 30,
+            ColignyMonth::Dumannios => 29,
+            ColignyMonth::Riuros => 30,
+            ColignyMonth::Anagantios => 29,
+            ColignyMonth::Ogronnios => 30,
+            ColignyMonth::Cutios => 30,
+            ColignyMonth::Giamonios => 29,
+            ColignyMonth::Simivisonnos => 30,
+            ColignyMonth::Equos => 30,
+            ColignyMonth::Elembivos => 29,
+            ColignyMonth::Edrinios => 30,
+            ColignyMonth::Cantlos => 29,
+        }
+    }
+}
+
+impl<const S: bool> GuaranteedMonth<ColignyMonth> for Coligny<S> {}
+
+/// Represents a date *and time* in an arithmetic reconstruction of the Coligny calendar
+pub type ColignyMoment<const S: bool> = CalendarMoment<Coligny<S>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::proptest;
+    //The variant without the saeculum has the narrower range
+    const MIN_YEARS: i32 = Coligny::<false>::EFFECTIVE_YEAR_MIN;
+    const MAX_YEARS: i32 = Coligny::<false>::EFFECTIVE_YEAR_MAX;
+
+    fn year_structure<const S: bool>(year: i32) {
+        let len = Coligny::<S>::year_length(year);
+        let mut total = 0;
+        for m in FIRST_INTERCALARY..=LAST_MONTH {
+            total += Coligny::<S>::month_length(year, ColignyMonth::from_u8(m).unwrap()) as u16;
+        }
+        assert_eq!(total, len);
+        let start = Coligny::<S>::try_year_start(year).unwrap().to_fixed();
+        let end = Coligny::<S>::try_year_end(year).unwrap().to_fixed();
+        assert_eq!(end.get_day_i() - start.get_day_i() + 1, len as i64);
+        assert_eq!(Coligny::<S>::from_fixed(start).to_ordinal().day_of_year, 1);
+    }
+
+    #[test]
+    fn tablet() {
+        let lengths: Vec<u16> = (1..=5).map(Coligny::<false>::year_length).collect();
+        assert_eq!(lengths, vec![385, 355, 385, 355, 355]);
+        let total: u16 = lengths.iter().sum();
+        assert_eq!(total as i64, CYCLE_DAYS);
+    }
+
+    #[test]
+    fn longest_year() {
+        let ord = OrdinalDate::try_from((1, 385)).unwrap();
+        let d = Coligny::<false>::try_from_ordinal(ord).unwrap();
+        assert_eq!(d.to_ordinal(), ord);
+        assert!(OrdinalDate::try_from((1, 386)).is_err());
+    }
+
+    #[test]
+    fn saeculum() {
+        let total_f: i64 = (1..=30)
+            .map(|y| Coligny::<false>::year_length(y) as i64)
+            .sum();
+        let total_t: i64 = (1..=30)
+            .map(|y| Coligny::<true>::year_length(y) as i64)
+            .sum();
+        assert_eq!(total_f, 6 * CYCLE_DAYS);
+        assert_eq!(total_t, 6 * CYCLE_DAYS - 30);
+        assert_eq!(Coligny::<true>::year_length(1), 355);
+        assert_eq!(Coligny::<true>::year_length(-29), 355);
+        assert_eq!(Coligny::<true>::year_length(6), 385);
+    }
+
+    proptest! {
+        #[test]
+        fn structure(year in MIN_YEARS..MAX_YEARS) {
+            year_structure::<false>(year);
+            year_structure::<true>(year);
+        }
+    }
+}
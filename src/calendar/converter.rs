@@ -0,0 +1,205 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::prelude::OrdinalDate;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::prelude::ToFromOrdinalDate;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use num_traits::FromPrimitive;
+use std::marker::PhantomData;
+
+const CACHE_SIZE: usize = 4;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct YearStart {
+    year: i32,
+    start: i64,
+    length: u16,
+}
+
+/// Converts dates while remembering the start of recently used years
+///
+/// Converting between a date and a [`Fixed`] usually requires calculating the day on
+/// which the year starts. This is expensive in some calendar systems, such as
+/// [`Symmetry454`](crate::calendar::Symmetry454) and
+/// [`FrenchRevArith`](crate::calendar::FrenchRevArith). A `Converter` remembers the
+/// start of the last few years it has seen, so that converting many dates within the
+/// same years is faster.
+///
+/// The results are always the same as [`FromFixed::from_fixed`] and [`ToFixed::to_fixed`],
+/// except that the time of day is discarded.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// let mut c = Converter::<SymmetryMonth, Symmetry454>::new();
+/// for t in 733500..733600 {
+///     let f = Fixed::cast_new(t);
+///     let d = c.date_from_fixed(f);
+///     assert_eq!(d, Symmetry454::from_fixed(f));
+///     assert_eq!(c.fixed_from_date(d), f);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Converter<S, T> {
+    years: [Option<YearStart>; CACHE_SIZE],
+    next: usize,
+    calendar: PhantomData<(S, T)>,
+}
+
+impl<S, T> Converter<S, T>
+where
+    S: FromPrimitive,
+    T: ToFromCommonDate<S> + ToFromOrdinalDate + ToFixed + FromFixed,
+{
+    /// Create a `Converter` which does not remember any years
+    pub fn new() -> Self {
+        Converter {
+            years: [None; CACHE_SIZE],
+            next: 0,
+            calendar: PhantomData,
+        }
+    }
+
+    fn remember(&mut self, year: i32, start: i64) -> YearStart {
+        let end = T::from_common_date_unchecked(T::year_end_date(year));
+        let y = YearStart {
+            year,
+            start,
+            length: end.to_ordinal().day_of_year,
+        };
+        self.years[self.next] = Some(y);
+        self.next = (self.next + 1) % CACHE_SIZE;
+        y
+    }
+
+    /// Convert a [`Fixed`] to a date
+    pub fn date_from_fixed(&mut self, fixed_date: Fixed) -> T {
+        let day = fixed_date.get_day_i();
+        let cached = self.years.iter().flatten().find_map(|y| {
+            let day_of_year = day - y.start + 1;
+            if day_of_year >= 1 && day_of_year <= (y.length as i64) {
+                Some(OrdinalDate {
+                    year: y.year,
+                    day_of_year: day_of_year as u16,
+                })
+            } else {
+                None
+            }
+        });
+        let ord = match cached {
+            Some(ord) => ord,
+            None => {
+                let ord = T::ordinal_from_fixed(fixed_date.to_day());
+                self.remember(ord.year, day - (ord.day_of_year as i64) + 1);
+                ord
+            }
+        };
+        T::from_ordinal_unchecked(ord)
+    }
+
+    /// Convert a date to a [`Fixed`]
+    pub fn fixed_from_date(&mut self, date: T) -> Fixed {
+        let ord = date.to_ordinal();
+        let cached = self.years.iter().flatten().find(|y| y.year == ord.year);
+        let start = match cached {
+            Some(y) => y.start,
+            None => {
+                let day = date.to_fixed().get_day_i();
+                self.remember(ord.year, day - (ord.day_of_year as i64) + 1)
+                    .start
+            }
+        };
+        Fixed::cast_new(start + (ord.day_of_year as i64) - 1)
+    }
+}
+
+impl<S, T> Default for Converter<S, T>
+where
+    S: FromPrimitive,
+    T: ToFromCommonDate<S> + ToFromOrdinalDate + ToFixed + FromFixed,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Gregorian;
+    use crate::calendar::GregorianMonth;
+    use crate::calendar::Julian;
+    use crate::calendar::JulianMonth;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use proptest::proptest;
+
+    const MAX_STEP: f64 = 800.0;
+
+    fn same_as_direct<S, T>(t: f64, steps: &[f64])
+    where
+        S: FromPrimitive,
+        T: ToFromCommonDate<S> + ToFromOrdinalDate + ToFixed + FromFixed + PartialEq + Copy,
+        T: std::fmt::Debug,
+    {
+        let mut c = Converter::<S, T>::new();
+        let mut f = t;
+        for dt in steps {
+            let fixed = Fixed::new(f.clamp(FIXED_MIN, FIXED_MAX));
+            let d = c.date_from_fixed(fixed);
+            assert_eq!(d, T::from_fixed(fixed));
+            assert_eq!(c.fixed_from_date(d), fixed.to_day());
+            let d_ahead = T::from_fixed(Fixed::new((f + dt).clamp(FIXED_MIN, FIXED_MAX)));
+            assert_eq!(c.fixed_from_date(d_ahead), d_ahead.to_fixed());
+            f += dt;
+        }
+    }
+
+    #[test]
+    fn julian_year_zero() {
+        let mut c = Converter::<JulianMonth, Julian>::new();
+        let end = Julian::try_year_end(-1).unwrap();
+        let start = Julian::try_year_start(1).unwrap();
+        assert_eq!(c.fixed_from_date(end), end.to_fixed());
+        assert_eq!(c.date_from_fixed(start.to_fixed()), start);
+        assert_eq!(c.date_from_fixed(end.to_fixed()), end);
+    }
+
+    proptest! {
+        #[test]
+        fn gregorian(t in FIXED_MIN..FIXED_MAX, steps in proptest::collection::vec(-MAX_STEP..MAX_STEP, 1..20)) {
+            same_as_direct::<GregorianMonth, Gregorian>(t, &steps);
+        }
+
+        #[test]
+        fn julian(t in FIXED_MIN..FIXED_MAX, steps in proptest::collection::vec(-MAX_STEP..MAX_STEP, 1..20)) {
+            same_as_direct::<JulianMonth, Julian>(t, &steps);
+        }
+    }
+
+    #[cfg(feature = "reform-calendars")]
+    proptest! {
+        #[test]
+        fn french_rev_arith(t in FIXED_MIN..FIXED_MAX, steps in proptest::collection::vec(-MAX_STEP..MAX_STEP, 1..20)) {
+            use crate::calendar::FrenchRevArith;
+            use crate::calendar::FrenchRevMonth;
+            same_as_direct::<FrenchRevMonth, FrenchRevArith<true>>(t, &steps);
+            same_as_direct::<FrenchRevMonth, FrenchRevArith<false>>(t, &steps);
+        }
+
+        #[test]
+        fn symmetry(t in FIXED_MIN..FIXED_MAX, steps in proptest::collection::vec(-MAX_STEP..MAX_STEP, 1..20)) {
+            use crate::calendar::Symmetry010;
+            use crate::calendar::Symmetry454Solstice;
+            use crate::calendar::SymmetryMonth;
+            same_as_direct::<SymmetryMonth, Symmetry010>(t, &steps);
+            same_as_direct::<SymmetryMonth, Symmetry454Solstice>(t, &steps);
+        }
+    }
+}
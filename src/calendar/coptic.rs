@@ -3,6 +3,8 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use crate::calendar::julian::Julian;
+use crate::calendar::month_table::cumulative_lengths;
+use crate::calendar::month_table::MonthTable;
 use crate::calendar::prelude::CommonDate;
 use crate::calendar::prelude::CommonWeekOfYear;
 use crate::calendar::prelude::GuaranteedMonth;
@@ -23,6 +25,7 @@ use crate::day_count::FromFixed;
 use crate::day_count::ToFixed;
 #[allow(unused_imports)] //FromPrimitive is needed for derive
 use num_traits::FromPrimitive;
+use std::fmt;
 use std::num::NonZero;
 
 //TODO: Coptic weekdays
@@ -117,9 +120,85 @@ pub enum CopticMonth {
 /// + [Coptic Orthodox Church](https://copticorthodox.church/en/coptic-church/coptic-history/)
 /// + [*The Coptic Christian Heritage* by Lois M. Farag](https://www.google.ca/books/edition/The_Coptic_Christian_Heritage/dYK3AQAAQBAJ)
 /// + [*A Handbook for Travellers in Lower and_Upper Egypt*](https://www.google.ca/books/edition/A_Handbook_for_Travellers_in_Lower_and_U/CnhJYhBzMmgC?hl=en&gbpv=1)
-#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
 pub struct Coptic(CommonDate);
 
+impl fmt::Debug for Coptic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<CopticMonth>("Coptic", f)
+    }
+}
+
+impl Coptic {
+    fn easter_offset(year: i32, days: i64) -> Coptic {
+        let easter = Coptic::easter(year).to_fixed().get_day_i();
+        Coptic::from_fixed(Fixed::cast_new(easter + days))
+    }
+
+    /// Calculate the date of Easter (Pascha) in a given Coptic year
+    ///
+    /// This uses the Alexandrian computus, which is purely arithmetic. Easter
+    /// always falls in the months of Paremotep, Parmoute or Pashons.
+    pub fn easter(year: i32) -> Coptic {
+        //The spring of Coptic year 1 is in 285 AD of the Julian calendar
+        let y = year + 284;
+        let j_year = if y <= 0 { y - 1 } else { y };
+        let nz = NonZero::new(j_year).expect("Adjusted to skip year 0");
+        Julian::easter(nz).convert::<Coptic>()
+    }
+
+    /// Calculate the first and last days of the Fast of Nineveh in a given Coptic year
+    ///
+    /// This is a 3 day fast starting two weeks before Great Lent.
+    pub fn fast_of_nineveh(year: i32) -> (Coptic, Coptic) {
+        (
+            Coptic::easter_offset(year, -69),
+            Coptic::easter_offset(year, -67),
+        )
+    }
+
+    /// Calculate the first and last days of Great Lent in a given Coptic year
+    ///
+    /// This is a 55 day fast, including Holy Week, which ends the day before Easter.
+    pub fn great_lent(year: i32) -> (Coptic, Coptic) {
+        (
+            Coptic::easter_offset(year, -55),
+            Coptic::easter_offset(year, -1),
+        )
+    }
+
+    /// Calculate the first and last days of the Apostles' Fast in a given Coptic year
+    ///
+    /// This fast starts the day after Pentecost and ends the day before the Feast of
+    /// the Apostles on 5 Epep.
+    pub fn apostles_fast(year: i32) -> (Coptic, Coptic) {
+        (
+            Coptic::easter_offset(year, 50),
+            Coptic(CommonDate::new(year, CopticMonth::Epep as u8, 4)),
+        )
+    }
+
+    /// Calculate the first and last days of the Nativity Fast in a given Coptic year
+    ///
+    /// This is a 43 day fast which ends the day before Christmas on 29 Koiak.
+    pub fn nativity_fast(year: i32) -> (Coptic, Coptic) {
+        (
+            Coptic(CommonDate::new(year, CopticMonth::Athor as u8, 16)),
+            Coptic(CommonDate::new(year, CopticMonth::Koiak as u8, 28)),
+        )
+    }
+
+    /// Calculate the first and last days of the Fast of the Virgin in a given Coptic year
+    ///
+    /// This is a 15 day fast ending on the Feast of the Assumption on 16 Mesore.
+    pub fn fast_of_the_virgin(year: i32) -> (Coptic, Coptic) {
+        (
+            Coptic(CommonDate::new(year, CopticMonth::Mesore as u8, 1)),
+            Coptic(CommonDate::new(year, CopticMonth::Mesore as u8, 15)),
+        )
+    }
+}
+
 impl AllowYearZero for Coptic {}
 
 impl ToFromOrdinalDate for Coptic {
@@ -172,6 +251,16 @@ impl HasLeapYears for Coptic {
     }
 }
 
+const COMMON_MONTH_LENGTHS: [u8; 13] = [30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 5];
+const LEAP_MONTH_LENGTHS: [u8; 13] = [30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 6];
+
+impl MonthTable for Coptic {
+    const COMMON_MONTH_LENGTHS: &'static [u8] = &COMMON_MONTH_LENGTHS;
+    const LEAP_MONTH_LENGTHS: &'static [u8] = &LEAP_MONTH_LENGTHS;
+    const COMMON_DAYS_BEFORE_MONTH: &'static [u16] = &cumulative_lengths(COMMON_MONTH_LENGTHS);
+    const LEAP_DAYS_BEFORE_MONTH: &'static [u16] = &cumulative_lengths(LEAP_MONTH_LENGTHS);
+}
+
 impl CalculatedBounds for Coptic {}
 
 impl Epoch for Coptic {
@@ -203,6 +292,9 @@ impl ToFixed for Coptic {
 }
 
 impl ToFromCommonDate<CopticMonth> for Coptic {
+    const EFFECTIVE_YEAR_MIN: i32 = -47013234;
+    const EFFECTIVE_YEAR_MAX: i32 = 47012667;
+
     fn to_common_date(self) -> CommonDate {
         self.0
     }
@@ -261,6 +353,7 @@ mod tests {
     use super::*;
     use crate::calendar::julian::JulianMonth;
     use crate::calendar::Gregorian;
+    use crate::day_cycle::Weekday;
     use proptest::prop_assume;
 
     use proptest::proptest;
@@ -273,7 +366,50 @@ mod tests {
         assert_eq!(g.to_common_date(), CommonDate::new(1887, 9, 11));
     }
 
+    #[test]
+    fn easter() {
+        // https://en.wikipedia.org/wiki/List_of_dates_for_Easter
+        let list = [
+            (1717, CommonDate::new(2001, 4, 15)),
+            (1726, CommonDate::new(2010, 4, 4)),
+            (1740, CommonDate::new(2024, 5, 5)),
+            (1741, CommonDate::new(2025, 4, 20)),
+        ];
+        for pair in list {
+            let g = Coptic::easter(pair.0).convert::<Gregorian>();
+            assert_eq!(g.to_common_date(), pair.1);
+        }
+    }
+
     proptest! {
+        #[test]
+        fn easter_and_fasts(y in i16::MIN..i16::MAX) {
+            let year = y as i32;
+            let e = Coptic::easter(year);
+            assert_eq!(e.year(), year);
+            assert_eq!(e.convert::<Weekday>(), Weekday::Sunday);
+            assert!(e.month() >= CopticMonth::Paremotep && e.month() <= CopticMonth::Pashons);
+            let fasts = [
+                (Coptic::fast_of_nineveh(year), Some(3)),
+                (Coptic::great_lent(year), Some(55)),
+                (Coptic::apostles_fast(year), None),
+                (Coptic::nativity_fast(year), Some(43)),
+                (Coptic::fast_of_the_virgin(year), Some(15)),
+            ];
+            for (fast, expected_length) in fasts {
+                let start = fast.0.to_fixed().get_day_i();
+                let end = fast.1.to_fixed().get_day_i();
+                assert_eq!(fast.0.year(), year);
+                assert_eq!(fast.1.year(), year);
+                assert!(start <= end);
+                if let Some(length) = expected_length {
+                    assert_eq!(end - start + 1, length);
+                }
+            }
+            assert_eq!(Coptic::great_lent(year).0.convert::<Weekday>(), Weekday::Monday);
+            assert_eq!(Coptic::fast_of_nineveh(year).0.convert::<Weekday>(), Weekday::Monday);
+        }
+
         #[test]
         fn julian_leap_ad(x in 1..(i16::MAX/4)) {
             let jy: i32 = (x * 4) as i32;
@@ -0,0 +1,218 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::prelude::CommonDate;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use num_traits::FromPrimitive;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// The same day represented in two calendar systems
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct Correlation {
+    pub from: CommonDate,
+    pub to: CommonDate,
+}
+
+/// Table of the same days represented in two calendar systems
+///
+/// ## Representation and Examples
+///
+/// Each row of the table is a [`Correlation`]. The text rendering has one row per line,
+/// the CSV rendering has a header followed by one row per line, and the JSON rendering is
+/// a single array.
+///
+/// ```
+/// use radnelac::calendar::*;
+///
+/// let t = CorrelationTable::month_starts::<_, Gregorian, _, Julian>(1900..=1900);
+/// assert_eq!(t.rows().len(), 12);
+/// assert_eq!(t.rows()[0].from, CommonDate::new(1900, 1, 1));
+/// assert_eq!(t.rows()[0].to, CommonDate::new(1899, 12, 20));
+/// assert!(t.to_string().starts_with("1900-01-01 = 1899-12-20\n"));
+/// assert!(t.to_csv().starts_with("from_year,from_month,from_day,to_year,to_month,to_day\n"));
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct CorrelationTable(Vec<Correlation>);
+
+impl CorrelationTable {
+    /// Correlate the first day of each month of `T` with the same day in `U`, over a range of years
+    ///
+    /// Years are counted in `T`. Complementary days which are numbered as their own month
+    /// are included if they have a day 1.
+    pub fn month_starts<S, T, V, U>(years: RangeInclusive<i32>) -> CorrelationTable
+    where
+        S: FromPrimitive,
+        T: ToFromCommonDate<S> + ToFixed,
+        V: FromPrimitive,
+        U: ToFromCommonDate<V> + FromFixed,
+    {
+        CorrelationTable::month_starts_with::<S, T, _>(years, |t| U::from_fixed(t).to_common_date())
+    }
+
+    /// Correlate the first day of each month of `T` with the result of `to`, over a range of years
+    ///
+    /// This is the same as [`CorrelationTable::month_starts`], except that the other calendar
+    /// is chosen at runtime. `to` is called with the first day of each month of `T`.
+    ///
+    /// ```
+    /// use radnelac::calendar::*;
+    /// use radnelac::day_count::*;
+    ///
+    /// let to: fn(Fixed) -> CommonDate = |t| Julian::from_fixed(t).to_common_date();
+    /// let a = CorrelationTable::month_starts_with::<_, Gregorian, _>(1900..=1900, to);
+    /// let b = CorrelationTable::month_starts::<_, Gregorian, _, Julian>(1900..=1900);
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn month_starts_with<S, T, F>(years: RangeInclusive<i32>, to: F) -> CorrelationTable
+    where
+        S: FromPrimitive,
+        T: ToFromCommonDate<S> + ToFixed,
+        F: Fn(Fixed) -> CommonDate,
+    {
+        let mut result = Vec::new();
+        for year in years {
+            for month in 0..=u8::MAX {
+                if let Ok(d) = T::try_from_common_date(CommonDate::new(year, month, 1)) {
+                    let t = d.to_fixed();
+                    let row = Correlation {
+                        from: d.to_common_date(),
+                        to: to(t),
+                    };
+                    result.push((t.get_day_i(), row));
+                }
+            }
+        }
+        //Complementary days might be numbered before the months they follow
+        result.sort_by_key(|x| x.0);
+        CorrelationTable(result.into_iter().map(|x| x.1).collect())
+    }
+
+    /// Rows of the table in chronological order
+    pub fn rows(&self) -> &[Correlation] {
+        &self.0
+    }
+
+    /// Write the table as comma separated values
+    pub fn write_csv(&self, w: &mut impl fmt::Write) -> fmt::Result {
+        writeln!(w, "from_year,from_month,from_day,to_year,to_month,to_day")?;
+        for row in &self.0 {
+            writeln!(
+                w,
+                "{},{},{},{},{},{}",
+                row.from.year, row.from.month, row.from.day, row.to.year, row.to.month, row.to.day
+            )?;
+        }
+        Ok(())
+    }
+
+    /// The table as comma separated values
+    pub fn to_csv(&self) -> String {
+        let mut result = String::new();
+        self.write_csv(&mut result)
+            .expect("Writing to String does not fail");
+        result
+    }
+
+    /// Write the table as a JSON array
+    ///
+    /// Each row is an object with `from` and `to` fields, which are objects with
+    /// `year`, `month` and `day` fields.
+    pub fn write_json(&self, w: &mut impl fmt::Write) -> fmt::Result {
+        write!(w, "[")?;
+        for (i, row) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(w, ",")?;
+            }
+            write!(w, "{{\"from\":")?;
+            write_json_date(w, row.from)?;
+            write!(w, ",\"to\":")?;
+            write_json_date(w, row.to)?;
+            write!(w, "}}")?;
+        }
+        writeln!(w, "]")
+    }
+
+    /// The table as a JSON array
+    pub fn to_json(&self) -> String {
+        let mut result = String::new();
+        self.write_json(&mut result)
+            .expect("Writing to String does not fail");
+        result
+    }
+}
+
+fn write_json_date(w: &mut impl fmt::Write, d: CommonDate) -> fmt::Result {
+    write!(
+        w,
+        "{{\"year\":{},\"month\":{},\"day\":{}}}",
+        d.year, d.month, d.day
+    )
+}
+
+fn fmt_common_date(d: CommonDate, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{:04}-{:02}-{:02}", d.year, d.month, d.day)
+}
+
+impl fmt::Display for CorrelationTable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in &self.0 {
+            fmt_common_date(row.from, f)?;
+            write!(f, " = ")?;
+            fmt_common_date(row.to, f)?;
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Gregorian;
+    use crate::calendar::Julian;
+    #[cfg(feature = "reform-calendars")]
+    use crate::calendar::Tranquility;
+
+    #[test]
+    fn same_days() {
+        let t = CorrelationTable::month_starts::<_, Julian, _, Gregorian>(-10..=10);
+        assert_eq!(t.rows().len(), 20 * 12);
+        for row in t.rows() {
+            let j = Julian::try_from_common_date(row.from).unwrap();
+            let g = Gregorian::try_from_common_date(row.to).unwrap();
+            assert_eq!(j.to_fixed().get_day_i(), g.to_fixed().get_day_i());
+        }
+        assert!(t.rows().windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    #[cfg(feature = "reform-calendars")]
+    fn tranquility() {
+        let t = CorrelationTable::month_starts::<_, Gregorian, _, Tranquility>(1969..=1969);
+        let july = t.rows()[6];
+        assert_eq!(july.from, CommonDate::new(1969, 7, 1));
+        assert_eq!(july.to.year, -1);
+        let tq = CorrelationTable::month_starts::<_, Tranquility, _, Gregorian>(1..=1);
+        assert_eq!(tq.rows()[0].to, CommonDate::new(1969, 7, 21));
+        assert_eq!(tq.rows()[13].to, CommonDate::new(1970, 7, 20));
+        let csv = t.to_csv();
+        assert_eq!(csv.lines().count(), 13);
+        assert_eq!(t.to_string().lines().count(), 12);
+    }
+
+    #[test]
+    fn json() {
+        let t = CorrelationTable::month_starts::<_, Gregorian, _, Julian>(1900..=1900);
+        let json = t.to_json();
+        assert!(json.starts_with(
+            "[{\"from\":{\"year\":1900,\"month\":1,\"day\":1},\"to\":{\"year\":1899,\"month\":12,\"day\":20}},"
+        ));
+        assert_eq!(json.matches("\"from\"").count(), 12);
+        assert_eq!(CorrelationTable::default().to_json(), "[]\n");
+    }
+}
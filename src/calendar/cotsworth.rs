@@ -3,6 +3,8 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use crate::calendar::gregorian::Gregorian;
+use crate::calendar::month_table::cumulative_lengths;
+use crate::calendar::month_table::MonthTable;
 use crate::calendar::prelude::CommonDate;
 use crate::calendar::prelude::GuaranteedMonth;
 use crate::calendar::prelude::HasLeapYears;
@@ -10,6 +12,8 @@ use crate::calendar::prelude::Perennial;
 use crate::calendar::prelude::Quarter;
 use crate::calendar::prelude::ToFromCommonDate;
 use crate::calendar::prelude::ToFromOrdinalDate;
+use crate::calendar::thirteen_month;
+use crate::calendar::thirteen_month::ThirteenMonthDay;
 use crate::calendar::AllowYearZero;
 use crate::calendar::CalendarMoment;
 use crate::calendar::HasEpagemonae;
@@ -25,8 +29,12 @@ use crate::day_count::ToFixed;
 use crate::day_cycle::Weekday;
 #[allow(unused_imports)] //FromPrimitive is needed for derive
 use num_traits::FromPrimitive;
+use std::fmt;
 use std::num::NonZero;
 
+const COTSWORTH_YEAR_OFFSET: i32 = 0;
+const COTSWORTH_LEAP_DAY_ORDINAL: u16 = (6 * 28) + 1;
+
 /// Represents a month in the Cotsworth Calendar
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy, FromPrimitive, ToPrimitive)]
 pub enum CotsworthMonth {
@@ -169,9 +177,15 @@ pub enum CotsworthComplementaryDay {
 /// + [*The Rational Almanac* by Moses Bruine Cotsworth](https://archive.org/details/rationalalmanact00cotsuoft/mode/2up)
 /// + [*The Importance of Calendar Reform to the Business World* by George Eastman](https://www.freexenon.com/wp-content/uploads/2018/07/The-Importance-of-Calendar-Reform-to-the-Business-World-George-Eastman.pdf)
 
-#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
 pub struct Cotsworth(CommonDate);
 
+impl fmt::Debug for Cotsworth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<CotsworthMonth>("Cotsworth", f)
+    }
+}
+
 impl AllowYearZero for Cotsworth {}
 
 impl ToFromOrdinalDate for Cotsworth {
@@ -180,37 +194,32 @@ impl ToFromOrdinalDate for Cotsworth {
     }
 
     fn ordinal_from_fixed(fixed_date: Fixed) -> OrdinalDate {
-        Gregorian::ordinal_from_fixed(fixed_date)
+        thirteen_month::ordinal_from_fixed(fixed_date, COTSWORTH_YEAR_OFFSET)
     }
 
     fn to_ordinal(self) -> OrdinalDate {
-        let approx_m = ((self.0.month as i64) - 1) * 28;
-        let offset_m = if self.0.month > 6 && Cotsworth::is_leap(self.0.year) {
-            approx_m + 1
-        } else {
-            approx_m
+        let day = match self.epagomenae() {
+            Some(CotsworthComplementaryDay::YearDay) => ThirteenMonthDay::YearDay,
+            Some(CotsworthComplementaryDay::LeapDay) => ThirteenMonthDay::LeapDay,
+            None => ThirteenMonthDay::Month(self.0.month, self.0.day),
         };
-        let doy = (offset_m as u16) + (self.0.day as u16);
+        let is_leap = Cotsworth::is_leap(self.0.year);
         OrdinalDate {
             year: self.0.year,
-            day_of_year: doy,
+            day_of_year: thirteen_month::to_ordinal(day, is_leap, COTSWORTH_LEAP_DAY_ORDINAL),
         }
     }
 
     fn from_ordinal_unchecked(ord: OrdinalDate) -> Self {
-        const LEAP_DAY_ORD: u16 = (6 * 28) + 1;
-        let result = match (ord.day_of_year, Cotsworth::is_leap(ord.year)) {
-            (366, true) => CommonDate::new(ord.year, 13, 29),
-            (365, false) => CommonDate::new(ord.year, 13, 29),
-            (LEAP_DAY_ORD, true) => CommonDate::new(ord.year, 6, 29),
-            (doy, is_leap) => {
-                let correction = if doy < LEAP_DAY_ORD || !is_leap { 0 } else { 1 };
-                let month = ((((doy - correction) - 1) as i64).div_euclid(28) + 1) as u8;
-                let day = ((doy - correction) as i64).adjusted_remainder(28) as u8;
-                CommonDate::new(ord.year, month, day)
-            }
+        let is_leap = Cotsworth::is_leap(ord.year);
+        let day =
+            thirteen_month::from_ordinal(ord.day_of_year, is_leap, COTSWORTH_LEAP_DAY_ORDINAL);
+        let (month, day) = match day {
+            ThirteenMonthDay::YearDay => (CotsworthMonth::December as u8, 29),
+            ThirteenMonthDay::LeapDay => (CotsworthMonth::June as u8, 29),
+            ThirteenMonthDay::Month(month, day) => (month, day),
         };
-        Cotsworth(result)
+        Cotsworth(CommonDate::new(ord.year, month, day))
     }
 }
 
@@ -254,10 +263,20 @@ impl Perennial<CotsworthMonth, Weekday> for Cotsworth {
 
 impl HasLeapYears for Cotsworth {
     fn is_leap(c_year: i32) -> bool {
-        Gregorian::is_leap(c_year)
+        thirteen_month::is_leap(c_year, COTSWORTH_YEAR_OFFSET)
     }
 }
 
+const COMMON_MONTH_LENGTHS: [u8; 13] = [28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 29];
+const LEAP_MONTH_LENGTHS: [u8; 13] = [28, 28, 28, 28, 28, 29, 28, 28, 28, 28, 28, 28, 29];
+
+impl MonthTable for Cotsworth {
+    const COMMON_MONTH_LENGTHS: &'static [u8] = &COMMON_MONTH_LENGTHS;
+    const LEAP_MONTH_LENGTHS: &'static [u8] = &LEAP_MONTH_LENGTHS;
+    const COMMON_DAYS_BEFORE_MONTH: &'static [u16] = &cumulative_lengths(COMMON_MONTH_LENGTHS);
+    const LEAP_DAYS_BEFORE_MONTH: &'static [u16] = &cumulative_lengths(LEAP_MONTH_LENGTHS);
+}
+
 impl CalculatedBounds for Cotsworth {}
 
 impl Epoch for Cotsworth {
@@ -275,17 +294,16 @@ impl FromFixed for Cotsworth {
 
 impl ToFixed for Cotsworth {
     fn to_fixed(self) -> Fixed {
-        let offset_y = Gregorian::try_year_start(self.0.year)
-            .expect("Year known to be valid")
-            .to_fixed()
-            .get_day_i()
-            - 1;
+        let offset_y = thirteen_month::prior_elapsed_days(self.0.year, COTSWORTH_YEAR_OFFSET);
         let ord = self.to_ordinal();
         Fixed::cast_new(offset_y + (ord.day_of_year as i64))
     }
 }
 
 impl ToFromCommonDate<CotsworthMonth> for Cotsworth {
+    const EFFECTIVE_YEAR_MIN: i32 = -47013915;
+    const EFFECTIVE_YEAR_MAX: i32 = 47013916;
+
     fn to_common_date(self) -> CommonDate {
         self.0
     }
@@ -22,6 +22,7 @@ use crate::day_count::JulianDay;
 use crate::day_count::ToFixed;
 #[allow(unused_imports)] //FromPrimitive is needed for derive
 use num_traits::FromPrimitive;
+use std::fmt;
 use std::num::NonZero;
 
 //LISTING 1.46 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
@@ -132,9 +133,15 @@ pub enum EgyptianDaysUponTheYear {
 ///   + [Egyptian Calendar](https://en.wikipedia.org/wiki/Egyptian_calendar)
 ///   + [Nabonassar](https://en.wikipedia.org/wiki/Nabonassar)
 ///   + [Egyptian chronology](https://en.wikipedia.org/wiki/Egyptian_chronology)
-#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
 pub struct Egyptian(CommonDate);
 
+impl fmt::Debug for Egyptian {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<EgyptianMonth>("Egyptian", f)
+    }
+}
+
 impl AllowYearZero for Egyptian {}
 
 impl ToFromOrdinalDate for Egyptian {
@@ -208,6 +215,9 @@ impl ToFixed for Egyptian {
 }
 
 impl ToFromCommonDate<EgyptianMonth> for Egyptian {
+    const EFFECTIVE_YEAR_MIN: i32 = -47044403;
+    const EFFECTIVE_YEAR_MAX: i32 = 47045899;
+
     fn to_common_date(self) -> CommonDate {
         self.0
     }
@@ -0,0 +1,207 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::prelude::CommonDate;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::common::error::CalendarError;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use num_traits::FromPrimitive;
+
+/// Describes a way of numbering years, defined at runtime
+///
+/// The year in the era is the year in the base calendar plus `offset`. Conversions which
+/// would overflow an [`i32`] return [`CalendarError::OutOfBounds`].
+///
+/// ```
+/// use radnelac::calendar::*;
+///
+/// let juche = Era::new("Juche", -1911);
+/// assert_eq!(juche.try_year_from_base(2025).unwrap(), 114);
+/// assert_eq!(juche.try_year_to_base(114).unwrap(), 2025);
+/// assert!(juche.try_year_to_base(i32::MAX).is_err());
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Era<'a> {
+    pub name: &'a str,
+    pub offset: i32,
+}
+
+impl<'a> Era<'a> {
+    pub const fn new(name: &'a str, offset: i32) -> Self {
+        Era { name, offset }
+    }
+
+    pub fn try_year_from_base(self, year: i32) -> Result<i32, CalendarError> {
+        year.checked_add(self.offset)
+            .ok_or(CalendarError::OutOfBounds)
+    }
+
+    pub fn try_year_to_base(self, year: i32) -> Result<i32, CalendarError> {
+        year.checked_sub(self.offset)
+            .ok_or(CalendarError::OutOfBounds)
+    }
+}
+
+/// Represents a date in a calendar with years numbered in a runtime [`Era`]
+///
+/// This is similar to [`OffsetCalendar`](crate::calendar::OffsetCalendar), except that the
+/// name and offset of the era are supplied by the application instead of being part of
+/// the type. This is intended for year numbering schemes which are too obscure to be
+/// included in this crate.
+///
+/// The months and days are the same as the base calendar. Since the era is not part of the
+/// type, `EraOffset` does not implement [`FromFixed`] or [`ToFromCommonDate`]. Instead
+/// the era must be passed to [`EraOffset::from_fixed`] and [`EraOffset::try_from_common_date`].
+///
+/// Year 0 is valid in the era if the corresponding year is valid in the base calendar.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// const JUCHE: Era = Era::new("Juche", -1911);
+///
+/// let g = Gregorian::try_new(2025, GregorianMonth::May, 15).unwrap();
+/// let j = EraOffset::from_fixed(JUCHE, g.to_fixed());
+/// assert_eq!(j.try_year().unwrap(), 114);
+/// assert_eq!(format!("{} {}", j.era().name, j.try_year().unwrap()), "Juche 114");
+/// assert_eq!(j.base(), g);
+///
+/// let j2 = EraOffset::<Gregorian>::try_from_common_date(JUCHE, CommonDate::new(114, 5, 15));
+/// assert_eq!(j2.unwrap(), j);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct EraOffset<'a, Base> {
+    base: Base,
+    era: Era<'a>,
+}
+
+impl<'a, B> EraOffset<'a, B> {
+    pub fn new(base: B, era: Era<'a>) -> Self {
+        EraOffset { base, era }
+    }
+
+    /// Returns the same day in the base calendar
+    pub fn base(self) -> B {
+        self.base
+    }
+
+    pub fn era(self) -> Era<'a> {
+        self.era
+    }
+
+    /// Returns the same day with years numbered in a different era
+    pub fn with_era<'b>(self, era: Era<'b>) -> EraOffset<'b, B> {
+        EraOffset::new(self.base, era)
+    }
+
+    pub fn from_fixed(era: Era<'a>, date: Fixed) -> Self
+    where
+        B: FromFixed,
+    {
+        EraOffset::new(B::from_fixed(date), era)
+    }
+
+    pub fn try_to_common_date<S>(self) -> Result<CommonDate, CalendarError>
+    where
+        S: FromPrimitive,
+        B: ToFromCommonDate<S>,
+    {
+        let d = self.base.to_common_date();
+        let year = self.era.try_year_from_base(d.year)?;
+        Ok(CommonDate::new(year, d.month, d.day))
+    }
+
+    pub fn try_from_common_date<S>(era: Era<'a>, date: CommonDate) -> Result<Self, CalendarError>
+    where
+        S: FromPrimitive,
+        B: ToFromCommonDate<S>,
+    {
+        let d = CommonDate::new(era.try_year_to_base(date.year)?, date.month, date.day);
+        Ok(EraOffset::new(B::try_from_common_date(d)?, era))
+    }
+
+    pub fn try_year<S>(self) -> Result<i32, CalendarError>
+    where
+        S: FromPrimitive,
+        B: ToFromCommonDate<S>,
+    {
+        self.era.try_year_from_base(self.base.year())
+    }
+}
+
+impl<B: ToFixed> ToFixed for EraOffset<'_, B> {
+    fn to_fixed(self) -> Fixed {
+        self.base.to_fixed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Gregorian;
+    use crate::calendar::Julian;
+    use crate::calendar::Minguo;
+    use crate::day_count::BoundedDayCount;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use proptest::proptest;
+
+    const ROC: Era = Era::new("Republic of China", -1911);
+
+    #[test]
+    fn invalid() {
+        let e = EraOffset::<Gregorian>::try_from_common_date(ROC, CommonDate::new(114, 2, 30));
+        assert!(matches!(e, Err(CalendarError::InvalidDay)));
+        let e = EraOffset::<Julian>::try_from_common_date(ROC, CommonDate::new(-1911, 1, 1));
+        assert!(matches!(e, Err(CalendarError::InvalidYear)));
+    }
+
+    #[test]
+    fn overflow() {
+        let era = Era::new("x", 5);
+        let e = EraOffset::<Gregorian>::try_from_common_date(era, CommonDate::new(i32::MIN, 1, 1));
+        assert!(matches!(e, Err(CalendarError::OutOfBounds)));
+        let era = Era::new("x", -5);
+        let e = EraOffset::<Gregorian>::try_from_common_date(era, CommonDate::new(i32::MAX, 1, 1));
+        assert!(matches!(e, Err(CalendarError::OutOfBounds)));
+        let g = Gregorian::try_from_common_date(CommonDate::new(2025, 1, 1)).unwrap();
+        let e = EraOffset::new(g, Era::new("x", i32::MAX));
+        assert!(matches!(e.try_year(), Err(CalendarError::OutOfBounds)));
+        assert!(matches!(
+            e.try_to_common_date(),
+            Err(CalendarError::OutOfBounds)
+        ));
+        assert_eq!(e.to_fixed(), g.to_fixed());
+        let g = Gregorian::try_from_common_date(CommonDate::new(-1, 1, 1)).unwrap();
+        let e = EraOffset::new(g, Era::new("x", i32::MIN));
+        assert!(matches!(e.try_year(), Err(CalendarError::OutOfBounds)));
+        assert_eq!(e.to_fixed(), g.to_fixed());
+    }
+
+    proptest! {
+        #[test]
+        fn same_as_offset_calendar(t in FIXED_MIN..FIXED_MAX) {
+            let f = Fixed::new(t).to_day();
+            let e = EraOffset::<Gregorian>::from_fixed(ROC, f);
+            let m = Minguo::from_fixed(f);
+            let c = e.try_to_common_date().unwrap();
+            assert_eq!(c, m.to_common_date());
+            assert_eq!(e.to_fixed(), f);
+            let e2 = EraOffset::<Gregorian>::try_from_common_date(ROC, c);
+            assert_eq!(e2.unwrap(), e);
+        }
+
+        #[test]
+        fn change_era(t in FIXED_MIN..FIXED_MAX, offset in -10000..10000) {
+            let f = Fixed::new(t).to_day();
+            let e = EraOffset::<Gregorian>::from_fixed(ROC, f);
+            let e2 = e.with_era(Era::new("Test", offset));
+            assert_eq!(e2.to_fixed(), f);
+            assert_eq!(e2.try_year().unwrap() - e.try_year().unwrap(), offset - ROC.offset);
+        }
+    }
+}
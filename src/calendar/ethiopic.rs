@@ -4,6 +4,7 @@
 
 use crate::calendar::coptic::Coptic;
 use crate::calendar::julian::Julian;
+use crate::calendar::month_table::MonthTable;
 use crate::calendar::prelude::CommonDate;
 use crate::calendar::prelude::CommonWeekOfYear;
 use crate::calendar::prelude::GuaranteedMonth;
@@ -25,6 +26,7 @@ use crate::day_count::FromFixed;
 use crate::day_count::ToFixed;
 #[allow(unused_imports)] //FromPrimitive is needed for derive
 use num_traits::FromPrimitive;
+use std::fmt;
 use std::num::NonZero;
 
 //TODO: Ethiopic weekdays
@@ -35,6 +37,7 @@ const ETHIOPIC_EPOCH_JULIAN: CommonDate = CommonDate {
     month: 8,
     day: 29,
 };
+const COPTIC_YEAR_OFFSET: i32 = 284 - 8;
 
 /// Represents a month in the Ethiopic Calendar
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy, FromPrimitive, ToPrimitive)]
@@ -114,9 +117,53 @@ pub enum EthiopicMonth {
 /// ## Further reading
 /// + [Wikipedia](https://en.wikipedia.org/wiki/Ethiopic_calendar)
 /// + [Embassy of Ethiopia, Washington D.C.](https://ethiopianembassy.org/ethiopian-time/)
-#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
 pub struct Ethiopic(CommonDate);
 
+impl fmt::Debug for Ethiopic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<EthiopicMonth>("Ethiopic", f)
+    }
+}
+
+impl Ethiopic {
+    fn from_coptic_pair(pair: (Coptic, Coptic)) -> (Ethiopic, Ethiopic) {
+        (pair.0.convert::<Ethiopic>(), pair.1.convert::<Ethiopic>())
+    }
+
+    /// Calculate the date of Easter (Fasika) in a given Ethiopic year
+    ///
+    /// This uses the Alexandrian computus, and is always the same day as [`Coptic::easter`].
+    pub fn easter(year: i32) -> Ethiopic {
+        Coptic::easter(year - COPTIC_YEAR_OFFSET).convert::<Ethiopic>()
+    }
+
+    /// Calculate the first and last days of the Fast of Nineveh (Tsome Nenewe) in a given Ethiopic year
+    pub fn fast_of_nineveh(year: i32) -> (Ethiopic, Ethiopic) {
+        Ethiopic::from_coptic_pair(Coptic::fast_of_nineveh(year - COPTIC_YEAR_OFFSET))
+    }
+
+    /// Calculate the first and last days of Great Lent (Abiy Tsom) in a given Ethiopic year
+    pub fn great_lent(year: i32) -> (Ethiopic, Ethiopic) {
+        Ethiopic::from_coptic_pair(Coptic::great_lent(year - COPTIC_YEAR_OFFSET))
+    }
+
+    /// Calculate the first and last days of the Apostles' Fast (Tsome Hawaryat) in a given Ethiopic year
+    pub fn apostles_fast(year: i32) -> (Ethiopic, Ethiopic) {
+        Ethiopic::from_coptic_pair(Coptic::apostles_fast(year - COPTIC_YEAR_OFFSET))
+    }
+
+    /// Calculate the first and last days of the Nativity Fast (Tsome Gahad) in a given Ethiopic year
+    pub fn nativity_fast(year: i32) -> (Ethiopic, Ethiopic) {
+        Ethiopic::from_coptic_pair(Coptic::nativity_fast(year - COPTIC_YEAR_OFFSET))
+    }
+
+    /// Calculate the first and last days of the Fast of the Virgin (Tsome Filseta) in a given Ethiopic year
+    pub fn fast_of_the_virgin(year: i32) -> (Ethiopic, Ethiopic) {
+        Ethiopic::from_coptic_pair(Coptic::fast_of_the_virgin(year - COPTIC_YEAR_OFFSET))
+    }
+}
+
 impl AllowYearZero for Ethiopic {}
 
 impl ToFromOrdinalDate for Ethiopic {
@@ -135,14 +182,13 @@ impl ToFromOrdinalDate for Ethiopic {
     }
 
     fn to_ordinal(self) -> OrdinalDate {
-        let e =
-            Coptic::try_from_common_date(self.to_common_date()).expect("Same month/day validity");
+        let e = Coptic::from_common_date_unchecked(self.to_common_date());
         e.to_ordinal()
     }
 
     fn from_ordinal_unchecked(ord: OrdinalDate) -> Self {
         let e = Coptic::from_ordinal_unchecked(ord);
-        Ethiopic::try_from_common_date(e.to_common_date()).expect("Same month/day validity")
+        Ethiopic::from_common_date_unchecked(e.to_common_date())
     }
 }
 
@@ -152,6 +198,13 @@ impl HasLeapYears for Ethiopic {
     }
 }
 
+impl MonthTable for Ethiopic {
+    const COMMON_MONTH_LENGTHS: &'static [u8] = Coptic::COMMON_MONTH_LENGTHS;
+    const LEAP_MONTH_LENGTHS: &'static [u8] = Coptic::LEAP_MONTH_LENGTHS;
+    const COMMON_DAYS_BEFORE_MONTH: &'static [u16] = Coptic::COMMON_DAYS_BEFORE_MONTH;
+    const LEAP_DAYS_BEFORE_MONTH: &'static [u16] = Coptic::LEAP_DAYS_BEFORE_MONTH;
+}
+
 impl CalculatedBounds for Ethiopic {}
 
 impl Epoch for Ethiopic {
@@ -166,21 +219,22 @@ impl FromFixed for Ethiopic {
     fn from_fixed(date: Fixed) -> Ethiopic {
         //LISTING 4.7 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
         let f = Fixed::new(date.get() + Coptic::epoch().get() - Ethiopic::epoch().get());
-        Ethiopic::try_from_common_date(Coptic::from_fixed(f).to_common_date())
-            .expect("Same month/day validity")
+        Ethiopic::from_common_date_unchecked(Coptic::from_fixed(f).to_common_date())
     }
 }
 
 impl ToFixed for Ethiopic {
     fn to_fixed(self) -> Fixed {
         //LISTING 4.6 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
-        let e =
-            Coptic::try_from_common_date(self.to_common_date()).expect("Same month/day validity");
+        let e = Coptic::from_common_date_unchecked(self.to_common_date());
         Fixed::new(Ethiopic::epoch().get() + e.to_fixed().get() - Coptic::epoch().get())
     }
 }
 
 impl ToFromCommonDate<EthiopicMonth> for Ethiopic {
+    const EFFECTIVE_YEAR_MIN: i32 = -47012958;
+    const EFFECTIVE_YEAR_MAX: i32 = 47012943;
+
     fn to_common_date(self) -> CommonDate {
         self.0
     }
@@ -226,5 +280,34 @@ impl Quarter for Ethiopic {
 impl GuaranteedMonth<EthiopicMonth> for Ethiopic {}
 impl CommonWeekOfYear<EthiopicMonth> for Ethiopic {}
 
+impl From<Coptic> for Ethiopic {
+    fn from(date: Coptic) -> Ethiopic {
+        date.convert::<Ethiopic>()
+    }
+}
+
+impl From<Ethiopic> for Coptic {
+    fn from(date: Ethiopic) -> Coptic {
+        date.convert::<Coptic>()
+    }
+}
+
 /// Represents a date *and time* in the Ethiopic Calendar
 pub type EthiopicMoment = CalendarMoment<Ethiopic>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Gregorian;
+
+    #[test]
+    fn fasika() {
+        // https://en.wikipedia.org/wiki/List_of_dates_for_Easter
+        let e = Ethiopic::easter(2017);
+        assert_eq!(e.to_common_date(), CommonDate::new(2017, 8, 12));
+        let g = e.convert::<Gregorian>();
+        assert_eq!(g.to_common_date(), CommonDate::new(2025, 4, 20));
+        let lent = Ethiopic::great_lent(2017);
+        assert_eq!(lent.1.to_fixed().get_day_i() + 1, e.to_fixed().get_day_i());
+    }
+}
@@ -8,6 +8,7 @@ use crate::calendar::prelude::HasLeapYears;
 use crate::calendar::prelude::Perennial;
 use crate::calendar::prelude::Quarter;
 use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::rest_days::RestDays;
 use crate::calendar::AllowYearZero;
 use crate::calendar::CalendarMoment;
 use crate::calendar::HasEpagemonae;
@@ -23,6 +24,7 @@ use crate::day_count::FromFixed;
 use crate::day_count::ToFixed;
 #[allow(unused_imports)] //FromPrimitive is needed for derive
 use num_traits::FromPrimitive;
+use std::fmt;
 use std::num::NonZero;
 
 const FRENCH_EPOCH_GREGORIAN: CommonDate = CommonDate {
@@ -222,9 +224,15 @@ pub enum Sansculottide {
 /// ## Further reading
 /// + [Wikipedia](https://en.wikipedia.org/wiki/French_Republican_calendar)
 /// + [Guanzhong "quantum" Chen](https://quantum5.ca/2022/03/09/art-of-time-keeping-part-4-french-republican-calendar/)
-#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
 pub struct FrenchRevArith<const L: bool>(CommonDate);
 
+impl<const L: bool> fmt::Debug for FrenchRevArith<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<FrenchRevMonth>("FrenchRevArith", f)
+    }
+}
+
 impl<const L: bool> AllowYearZero for FrenchRevArith<L> {}
 
 impl<const L: bool> ToFromOrdinalDate for FrenchRevArith<L> {
@@ -243,13 +251,12 @@ impl<const L: bool> ToFromOrdinalDate for FrenchRevArith<L> {
         let date = fixed_date.get_day_i();
         let epoch = Self::epoch().get_day_i();
         let approx = ((4000 * (date - epoch + 2)).div_euclid(1460969) + 1) as i32;
-        let approx_start = Self(CommonDate::new(approx, 1, 1)).to_fixed().get_day_i();
-        let year = if date < approx_start {
-            approx - 1
+        let approx_start = Self::new_year_day_unchecked(approx, epoch);
+        let (year, year_start) = if date < approx_start {
+            (approx - 1, Self::new_year_day_unchecked(approx - 1, epoch))
         } else {
-            approx
+            (approx, approx_start)
         };
-        let year_start = Self(CommonDate::new(year, 1, 1)).to_fixed().get_day_i();
         let doy = (date - year_start + 1) as u16;
         OrdinalDate {
             year: year,
@@ -273,8 +280,7 @@ impl<const L: bool> ToFromOrdinalDate for FrenchRevArith<L> {
         //This is only the terms relying on month and day.
         //This is modified to use ordinal days instead of days from epoch.
         let month = (1 + (ord.day_of_year - 1).div_euclid(30)) as u8;
-        let month_start = Self(CommonDate::new(ord.year, month, 1)).to_ordinal();
-        let day = (1 + ord.day_of_year - month_start.day_of_year) as u8;
+        let day = (1 + (ord.day_of_year - 1).rem_euclid(30)) as u8;
         FrenchRevArith(CommonDate::new(ord.year, month, day))
     }
 }
@@ -284,6 +290,18 @@ impl<const L: bool> FrenchRevArith<L> {
     pub fn is_adjusted(self) -> bool {
         L
     }
+
+    fn new_year_day_unchecked(year: i32, epoch: i64) -> i64 {
+        //LISTING 17.9 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
+        //This is only the terms relying on the year, with the epoch passed in by the caller.
+        let year = year as i64;
+        let y_adj = if L { 1 } else { 0 };
+        let offset_y = 365 * (year - 1);
+        let offset_leap = (year + y_adj - 1).div_euclid(4) - (year + y_adj - 1).div_euclid(100)
+            + (year + y_adj - 1).div_euclid(400)
+            - (year + y_adj - 1).div_euclid(4000);
+        epoch + offset_y + offset_leap
+    }
 }
 
 impl<const L: bool> HasEpagemonae<Sansculottide> for FrenchRevArith<L> {
@@ -304,6 +322,13 @@ impl<const L: bool> HasEpagemonae<Sansculottide> for FrenchRevArith<L> {
     }
 }
 
+/// Décadi and the sansculottides are days of rest
+impl<const L: bool> RestDays for FrenchRevArith<L> {
+    fn is_rest_day(self) -> bool {
+        self.weekday() == Some(FrenchRevWeekday::Decadi) || self.epagomenae().is_some()
+    }
+}
+
 impl<const L: bool> Perennial<FrenchRevMonth, FrenchRevWeekday> for FrenchRevArith<L> {
     fn weekday(self) -> Option<FrenchRevWeekday> {
         if self.0.month == NON_MONTH {
@@ -357,20 +382,16 @@ impl<const L: bool> ToFixed for FrenchRevArith<L> {
     fn to_fixed(self) -> Fixed {
         //LISTING 17.9 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
         //Split compared to original: terms relying on month and day are processed in to_ordinal
-        let year = self.0.year as i64;
-        let y_adj = if L { 1 } else { 0 };
-
-        let offset_e = Self::epoch().get_day_i() - 1;
-        let offset_y = 365 * (year - 1);
-        let offset_leap = (year + y_adj - 1).div_euclid(4) - (year + y_adj - 1).div_euclid(100)
-            + (year + y_adj - 1).div_euclid(400)
-            - (year + y_adj - 1).div_euclid(4000);
+        let new_year = Self::new_year_day_unchecked(self.0.year, Self::epoch().get_day_i());
         let ord = self.to_ordinal().day_of_year as i64;
-        Fixed::cast_new(offset_e + offset_y + offset_leap + ord)
+        Fixed::cast_new(new_year + ord - 1)
     }
 }
 
 impl<const L: bool> ToFromCommonDate<FrenchRevMonth> for FrenchRevArith<L> {
+    const EFFECTIVE_YEAR_MIN: i32 = -47015739;
+    const EFFECTIVE_YEAR_MAX: i32 = 47012157;
+
     fn to_common_date(self) -> CommonDate {
         self.0
     }
@@ -422,6 +443,8 @@ pub type FrenchRevArithMoment<const L: bool> = CalendarMoment<FrenchRevArith<L>>
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
     use proptest::proptest;
 
     #[test]
@@ -495,6 +518,13 @@ mod tests {
     }
 
     proptest! {
+        #[test]
+        fn rest_days(t in FIXED_MIN..FIXED_MAX) {
+            let d = FrenchRevArith::<true>::from_fixed(Fixed::new(t));
+            let c = d.to_common_date();
+            assert_eq!(d.is_rest_day(), c.day == 10 || c.day == 20 || c.day == 30 || c.month == NON_MONTH);
+        }
+
         #[test]
         fn align_to_gregorian(year in 0..100) {
             // https://en.wikipedia.org/wiki/French_Republican_calendar
@@ -543,5 +573,51 @@ mod tests {
                 assert!((f1.get_day_i() - f0.get_day_i()).abs() < 2);
             }
         }
+
+        #[test]
+        fn same_as_round_trips(t in FIXED_MIN..FIXED_MAX) {
+            let f = Fixed::new(t);
+            assert_eq!(FrenchRevArith::<true>::from_fixed(f), round_trip_from_fixed::<true>(f));
+            assert_eq!(FrenchRevArith::<false>::from_fixed(f), round_trip_from_fixed::<false>(f));
+            let d = FrenchRevArith::<true>::from_fixed(f);
+            assert_eq!(d.to_fixed().get_day_i(), original_to_fixed(d));
+            let d = FrenchRevArith::<false>::from_fixed(f);
+            assert_eq!(d.to_fixed().get_day_i(), original_to_fixed(d));
+        }
+    }
+
+    fn original_to_fixed<const L: bool>(d: FrenchRevArith<L>) -> i64 {
+        //Previous implementation of to_fixed, copied verbatim
+        //LISTING 17.9 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
+        //Split compared to original: terms relying on month and day are processed in to_ordinal
+        let year = d.0.year as i64;
+        let y_adj = if L { 1 } else { 0 };
+
+        let offset_e = FrenchRevArith::<L>::epoch().get_day_i() - 1;
+        let offset_y = 365 * (year - 1);
+        let offset_leap = (year + y_adj - 1).div_euclid(4) - (year + y_adj - 1).div_euclid(100)
+            + (year + y_adj - 1).div_euclid(400)
+            - (year + y_adj - 1).div_euclid(4000);
+        let ord = d.to_ordinal().day_of_year as i64;
+        offset_e + offset_y + offset_leap + ord
+    }
+
+    fn round_trip_from_fixed<const L: bool>(fixed_date: Fixed) -> FrenchRevArith<L> {
+        //Previous implementation, which found the start of the year using to_fixed
+        let date = fixed_date.get_day_i();
+        let epoch = FrenchRevArith::<L>::epoch().get_day_i();
+        let approx = ((4000 * (date - epoch + 2)).div_euclid(1460969) + 1) as i32;
+        let approx_start = original_to_fixed(FrenchRevArith::<L>(CommonDate::new(approx, 1, 1)));
+        let year = if date < approx_start {
+            approx - 1
+        } else {
+            approx
+        };
+        let year_start = original_to_fixed(FrenchRevArith::<L>(CommonDate::new(year, 1, 1)));
+        let doy = (date - year_start + 1) as u16;
+        let month = (1 + (doy - 1).div_euclid(30)) as u8;
+        let month_start = FrenchRevArith::<L>(CommonDate::new(year, month, 1)).to_ordinal();
+        let day = (1 + doy - month_start.day_of_year) as u8;
+        FrenchRevArith::<L>(CommonDate::new(year, month, day))
     }
 }
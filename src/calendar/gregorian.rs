@@ -2,6 +2,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use crate::calendar::month_table::cumulative_lengths;
+use crate::calendar::month_table::MonthTable;
 use crate::calendar::prelude::CommonDate;
 use crate::calendar::prelude::CommonWeekOfYear;
 use crate::calendar::prelude::GuaranteedMonth;
@@ -9,6 +11,7 @@ use crate::calendar::prelude::HasLeapYears;
 use crate::calendar::prelude::OrdinalDate;
 use crate::calendar::prelude::Quarter;
 use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::rest_days::RestDays;
 use crate::calendar::AllowYearZero;
 use crate::calendar::CalendarMoment;
 use crate::calendar::ToFromOrdinalDate;
@@ -21,10 +24,14 @@ use crate::day_count::Fixed;
 use crate::day_count::FromFixed;
 use crate::day_count::RataDie;
 use crate::day_count::ToFixed;
+use crate::raw::days_before_month;
+use crate::raw::gregorian_days_before_year;
+use crate::raw::is_gregorian_leap_year;
 use std::num::NonZero;
 
 #[allow(unused_imports)] //FromPrimitive is needed for derive
 use num_traits::FromPrimitive;
+use std::fmt;
 
 //LISTING 2.3 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
 const GREGORIAN_EPOCH_RD: i32 = 1;
@@ -147,20 +154,25 @@ pub enum GregorianMonth {
 ///   + [Gregorian calendar](https://en.wikipedia.org/wiki/Gregorian_calendar)
 ///   + [Proleptic Gregorian calendar](https://en.wikipedia.org/wiki/Proleptic_Gregorian_calendar)
 /// + [OpenGroup `cal`](https://pubs.opengroup.org/onlinepubs/9699919799/utilities/cal.html)
-#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
 pub struct Gregorian(CommonDate);
 
+impl fmt::Debug for Gregorian {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<GregorianMonth>("Gregorian", f)
+    }
+}
+
 impl Gregorian {
+    /// Fixed day number of the last day before the start of a year
+    ///
+    /// See also [`gregorian_days_before_year`](crate::raw::gregorian_days_before_year).
     pub fn prior_elapsed_days(year: i32) -> i64 {
         //LISTING 2.17 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
         //These are the terms of the sum which do not rely on the month or day.
         //LISTING PriorElapsedDays (*Basic Symmetry454 and Symmetry010 Calendar Arithmetic* by Dr. Irvin L. Bromberg)
-        let year = year as i64;
         let offset_e = Gregorian::epoch().get_day_i() - 1;
-        let offset_y = 365 * (year - 1);
-        let offset_leap =
-            (year - 1).div_euclid(4) - (year - 1).div_euclid(100) + (year - 1).div_euclid(400);
-        offset_e + offset_y + offset_leap
+        offset_e + gregorian_days_before_year(year as i64)
     }
 }
 
@@ -205,20 +217,10 @@ impl ToFromOrdinalDate for Gregorian {
     fn to_ordinal(self) -> OrdinalDate {
         //LISTING 2.17 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
         //These are the terms of the sum which rely on the month or day
-        let month = self.0.month as i64;
-        let day = self.0.day as i64;
-        let offset_m = ((367 * month) - 362).div_euclid(12);
-        let offset_x = if month <= 2 {
-            0
-        } else if Gregorian::is_leap(self.0.year) {
-            -1
-        } else {
-            -2
-        };
-        let offset_d = day;
+        let offset_m = days_before_month(self.0.month, Gregorian::is_leap(self.0.year));
         OrdinalDate {
             year: self.0.year,
-            day_of_year: (offset_m + offset_x + offset_d) as u16,
+            day_of_year: offset_m + (self.0.day as u16),
         }
     }
 
@@ -246,13 +248,22 @@ impl ToFromOrdinalDate for Gregorian {
 
 impl HasLeapYears for Gregorian {
     fn is_leap(g_year: i32) -> bool {
-        //LISTING 2.16 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
-        let m4 = g_year.modulus(4);
-        let m400 = g_year.modulus(400);
-        m4 == 0 && m400 != 100 && m400 != 200 && m400 != 300
+        is_gregorian_leap_year(g_year as i64)
     }
 }
 
+impl RestDays for Gregorian {}
+
+const COMMON_MONTH_LENGTHS: [u8; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+const LEAP_MONTH_LENGTHS: [u8; 12] = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+impl MonthTable for Gregorian {
+    const COMMON_MONTH_LENGTHS: &'static [u8] = &COMMON_MONTH_LENGTHS;
+    const LEAP_MONTH_LENGTHS: &'static [u8] = &LEAP_MONTH_LENGTHS;
+    const COMMON_DAYS_BEFORE_MONTH: &'static [u16] = &cumulative_lengths(COMMON_MONTH_LENGTHS);
+    const LEAP_DAYS_BEFORE_MONTH: &'static [u16] = &cumulative_lengths(LEAP_MONTH_LENGTHS);
+}
+
 impl CalculatedBounds for Gregorian {}
 
 impl Epoch for Gregorian {
@@ -279,6 +290,9 @@ impl ToFixed for Gregorian {
 }
 
 impl ToFromCommonDate<GregorianMonth> for Gregorian {
+    const EFFECTIVE_YEAR_MIN: i32 = -47013915;
+    const EFFECTIVE_YEAR_MAX: i32 = 47013916;
+
     fn to_common_date(self) -> CommonDate {
         self.0
     }
@@ -345,6 +359,52 @@ impl CommonWeekOfYear<GregorianMonth> for Gregorian {}
 /// Represents a date *and time* in the Gregorian Calendar
 pub type GregorianMoment = CalendarMoment<Gregorian>;
 
+/// Number of days in a month of the Gregorian calendar
+///
+/// Returns [`None`] if the month is not between 1 and 12. This does not require
+/// constructing a [`Gregorian`] or [`GregorianMonth`], and is intended for hot loops
+/// in parsers and validators.
+///
+/// Leap years are the same as in [`is_gregorian_leap_year`](crate::raw::is_gregorian_leap_year).
+///
+/// ```
+/// use radnelac::calendar::*;
+///
+/// assert_eq!(days_in_gregorian_month(2024, 2), Some(29));
+/// assert_eq!(days_in_gregorian_month(2025, 2), Some(28));
+/// assert_eq!(days_in_gregorian_month(2025, 13), None);
+/// ```
+pub const fn days_in_gregorian_month(year: i32, month: u8) -> Option<u8> {
+    match month {
+        2 => Some(if is_gregorian_leap_year(year as i64) {
+            29
+        } else {
+            28
+        }),
+        4 | 6 | 9 | 11 => Some(30),
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+        _ => None,
+    }
+}
+
+/// [`true`] if the year, month and day form a valid date in the Gregorian calendar
+///
+/// This does not check whether the date is within the supported range of time.
+///
+/// ```
+/// use radnelac::calendar::*;
+///
+/// assert!(is_valid_gregorian_date(2024, 2, 29));
+/// assert!(!is_valid_gregorian_date(2025, 2, 29));
+/// assert!(!is_valid_gregorian_date(2025, 4, 0));
+/// ```
+pub const fn is_valid_gregorian_date(year: i32, month: u8, day: u8) -> bool {
+    match days_in_gregorian_month(year, month) {
+        Some(length) => day >= 1 && day <= length,
+        None => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -439,6 +499,16 @@ mod tests {
     }
 
     proptest! {
+        #[test]
+        fn free_validation(year in -10000..10000i32, month in 0..15u8, day in 0..35u8) {
+            let c = CommonDate::new(year, month, day);
+            assert_eq!(is_valid_gregorian_date(year, month, day), Gregorian::valid_ymd(c).is_ok());
+            match GregorianMonth::from_u8(month) {
+                Some(m) => assert_eq!(days_in_gregorian_month(year, month), Some(Gregorian::month_length(year, m))),
+                None => assert!(days_in_gregorian_month(year, month).is_none()),
+            }
+        }
+
         #[test]
         fn cycle_146097(t in FIXED_MIN..(FIXED_MAX-146097.0), w in 1..55) {
             let f_start = Fixed::new(t);
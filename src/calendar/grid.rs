@@ -0,0 +1,324 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::prelude::CommonDate;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::rest_days::RestDays;
+use crate::common::error::CalendarError;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use crate::day_cycle::OnOrBefore;
+use crate::day_cycle::Weekday;
+use num_traits::FromPrimitive;
+use num_traits::ToPrimitive;
+
+/// Conventions for arranging days into rows of weeks
+///
+/// Each row starts on `first_weekday`. The first row of a month or year is numbered 1 if
+/// it contains at least `minimal_days` days of that month or year, otherwise it is
+/// numbered 0. These are the same parameters used by the Unicode Common Locale Data
+/// Repository (CLDR).
+///
+/// This uses the common week cycle, even for calendars which re-use [`Weekday`] for
+/// their own weeks.
+///
+/// ## Representation and Examples
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_cycle::*;
+///
+/// // July 2025 starts on a Tuesday
+/// let iso = WeekRule::ISO.month_grid::<GregorianMonth, Gregorian>(2025, GregorianMonth::July).unwrap();
+/// assert_eq!(iso.len(), 5);
+/// assert_eq!(iso[0].week, 1);
+/// assert_eq!(iso[0].days[0], None);
+/// assert_eq!(iso[0].days[1].unwrap().day(), 1);
+///
+/// let rule = WeekRule::try_new(Weekday::Thursday, 4).unwrap();
+/// let grid = rule.month_grid::<GregorianMonth, Gregorian>(2025, GregorianMonth::July).unwrap();
+/// assert_eq!(grid.len(), 6);
+/// assert_eq!(grid[0].week, 0);
+/// assert_eq!(grid[0].days[5].unwrap().day(), 1);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct WeekRule {
+    first_weekday: Weekday,
+    minimal_days: u8,
+}
+
+/// One row of a month grid
+///
+/// Days outside of the month are [`None`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct GridWeek<T> {
+    pub week: u8,
+    pub days: [Option<T>; 7],
+}
+
+impl<T: RestDays + Copy> GridWeek<T> {
+    /// [`true`] for each day in the row which is a day of rest
+    ///
+    /// This is intended for highlighting rest days when displaying a grid. Days outside of
+    /// the month are [`false`].
+    ///
+    /// ```
+    /// use radnelac::calendar::*;
+    ///
+    /// let grid = WeekRule::ISO.month_grid::<GregorianMonth, Gregorian>(2025, GregorianMonth::July).unwrap();
+    /// assert_eq!(grid[0].rest_days(), [false, false, false, false, false, true, true]);
+    /// ```
+    pub fn rest_days(&self) -> [bool; 7] {
+        self.days.map(|d| d.is_some_and(|d| d.is_rest_day()))
+    }
+}
+
+impl WeekRule {
+    /// Weeks start on Monday, and the first week has at least 4 days (ISO 8601)
+    pub const ISO: WeekRule = WeekRule {
+        first_weekday: Weekday::Monday,
+        minimal_days: 4,
+    };
+    /// Weeks start on Sunday, and the first week has at least 1 day (United States)
+    pub const US: WeekRule = WeekRule {
+        first_weekday: Weekday::Sunday,
+        minimal_days: 1,
+    };
+
+    /// Attempt to create a `WeekRule`
+    ///
+    /// Returns an error if `minimal_days` is not between 1 and 7.
+    pub fn try_new(first_weekday: Weekday, minimal_days: u8) -> Result<WeekRule, CalendarError> {
+        if (1..=7).contains(&minimal_days) {
+            Ok(WeekRule {
+                first_weekday,
+                minimal_days,
+            })
+        } else {
+            Err(CalendarError::InvalidWeek)
+        }
+    }
+
+    /// Day of the week on which each row starts
+    pub fn first_weekday(self) -> Weekday {
+        self.first_weekday
+    }
+
+    /// Minimum number of days in the first week of a month or year
+    pub fn minimal_days(self) -> u8 {
+        self.minimal_days
+    }
+
+    fn row_start(self, t: Fixed) -> i64 {
+        self.first_weekday.on_or_before(t).get_day_i()
+    }
+
+    fn week_since(self, start: Fixed, t: Fixed) -> i64 {
+        let first_row = self.row_start(start);
+        let first_row_days = 7 - (start.get_day_i() - first_row);
+        let base = if first_row_days >= (self.minimal_days as i64) {
+            1
+        } else {
+            0
+        };
+        (self.row_start(t) - first_row).div_euclid(7) + base
+    }
+
+    /// Week of month of a date
+    ///
+    /// This may be 0 if the first row of the month has fewer than `minimal_days` days.
+    pub fn week_of_month<S, T>(self, date: T) -> u8
+    where
+        S: FromPrimitive + ToPrimitive,
+        T: ToFromCommonDate<S> + ToFixed,
+    {
+        let c = date.to_common_date();
+        let start = T::from_common_date_unchecked(CommonDate::new(c.year, c.month, 1));
+        self.week_since(start.to_fixed(), date.to_fixed()) as u8
+    }
+
+    /// Year and week of year of a date
+    ///
+    /// Days in a row which does not have enough days in the year belong to the last week
+    /// of the previous year or the first week of the next year.
+    ///
+    /// ```
+    /// use radnelac::calendar::*;
+    ///
+    /// let d = Gregorian::try_new(2021, GregorianMonth::January, 1).unwrap();
+    /// assert_eq!(WeekRule::ISO.week_of_year(d), (2020, 53));
+    /// assert_eq!(WeekRule::US.week_of_year(d), (2021, 1));
+    /// let d = Gregorian::try_new(2024, GregorianMonth::December, 31).unwrap();
+    /// assert_eq!(WeekRule::ISO.week_of_year(d), (2025, 1));
+    /// ```
+    pub fn week_of_year<S, T>(self, date: T) -> (i32, u8)
+    where
+        S: FromPrimitive + ToPrimitive,
+        T: ToFromCommonDate<S> + ToFixed + FromFixed,
+    {
+        let t = date.to_fixed();
+        let year = date.year();
+        let start = T::try_year_start(year)
+            .expect("Year known to be valid")
+            .to_fixed();
+        let week = self.week_since(start, t);
+        if week == 0 {
+            let prev = T::from_fixed(Fixed::cast_new(start.get_day_i() - 1));
+            return self.week_of_year(prev);
+        }
+        let end = T::from_common_date_unchecked(T::year_end_date(year)).to_fixed();
+        let next_start = Fixed::cast_new(end.get_day_i() + 1);
+        let next_row = self.row_start(next_start);
+        let next_row_days = 7 - (next_start.get_day_i() - next_row);
+        if self.row_start(t) == next_row && next_row_days >= (self.minimal_days as i64) {
+            (T::from_fixed(next_start).year(), 1)
+        } else {
+            (year, week as u8)
+        }
+    }
+
+    /// Arrange the days of a month into rows of weeks
+    ///
+    /// Returns an error if the year or month is invalid.
+    pub fn month_grid<S, T>(self, year: i32, month: S) -> Result<Vec<GridWeek<T>>, CalendarError>
+    where
+        S: FromPrimitive + ToPrimitive,
+        T: ToFromCommonDate<S> + ToFixed + FromFixed,
+    {
+        let m = month.to_u8().ok_or(CalendarError::InvalidMonth)?;
+        let len = T::month_length(year, month);
+        let first = T::try_from_common_date(CommonDate::new(year, m, 1))?.to_fixed();
+        let last = first.get_day_i() + (len as i64) - 1;
+        let mut result = Vec::new();
+        let mut row = self.row_start(first);
+        let mut week = self.week_since(first, first) as u8;
+        while row <= last {
+            let mut days = [None; 7];
+            for (i, d) in days.iter_mut().enumerate() {
+                let t = row + (i as i64);
+                if t >= first.get_day_i() && t <= last {
+                    *d = Some(T::from_fixed(Fixed::cast_new(t)));
+                }
+            }
+            result.push(GridWeek { week, days });
+            row += 7;
+            week += 1;
+        }
+        Ok(result)
+    }
+
+    /// Arrange the days of every month in a year into rows of weeks
+    ///
+    /// Days which are not associated with a month (such as complementary days) are not
+    /// included. Returns an error if the year is invalid.
+    pub fn year_grid<S, T>(self, year: i32) -> Result<Vec<Vec<GridWeek<T>>>, CalendarError>
+    where
+        S: FromPrimitive + ToPrimitive,
+        T: ToFromCommonDate<S> + ToFixed + FromFixed,
+    {
+        (1..)
+            .map_while(S::from_u8)
+            .map(|m| self.month_grid::<S, T>(year, m))
+            .collect()
+    }
+}
+
+/// Day of the common week of a date
+///
+/// This is a shortcut for finding which column of a month grid a day belongs in, such
+/// as the first day of a month. Returns an error if the date is invalid.
+///
+/// This uses the common week cycle. Perennial calendars with their own weekday labels,
+/// such as the Cotsworth calendar, may label the same day differently; see
+/// [`Perennial::weekday`](crate::calendar::Perennial::weekday).
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_cycle::*;
+///
+/// let w = day_of_week_of::<GregorianMonth, Gregorian>(2025, GregorianMonth::July, 1).unwrap();
+/// assert_eq!(w, Weekday::Tuesday);
+/// ```
+pub fn day_of_week_of<S, T>(year: i32, month: S, day: u8) -> Result<Weekday, CalendarError>
+where
+    S: FromPrimitive + ToPrimitive,
+    T: ToFromCommonDate<S> + ToFixed,
+{
+    let m = month.to_u8().ok_or(CalendarError::InvalidMonth)?;
+    let date = T::try_from_common_date(CommonDate::new(year, m, day))?;
+    Ok(Weekday::from_fixed(date.to_fixed()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Gregorian;
+    use crate::calendar::GregorianMonth;
+    use crate::calendar::GuaranteedMonth;
+    use crate::calendar::ISO;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use proptest::proptest;
+
+    #[test]
+    fn day_of_week_of_invalid() {
+        assert!(matches!(
+            day_of_week_of::<GregorianMonth, Gregorian>(2025, GregorianMonth::February, 29),
+            Err(CalendarError::InvalidDay)
+        ));
+    }
+
+    #[test]
+    fn invalid_rule() {
+        assert!(WeekRule::try_new(Weekday::Monday, 0).is_err());
+        assert!(WeekRule::try_new(Weekday::Monday, 8).is_err());
+    }
+
+    #[test]
+    fn year_grid_covers_year() {
+        let g = WeekRule::US
+            .year_grid::<GregorianMonth, Gregorian>(2024)
+            .unwrap();
+        assert_eq!(g.len(), 12);
+        let days: usize = g
+            .iter()
+            .flatten()
+            .map(|w| w.days.iter().flatten().count())
+            .sum();
+        assert_eq!(days, 366);
+    }
+
+    proptest! {
+        #[test]
+        fn day_of_week_of_month_start(t in -100000000..100000000i64) {
+            let g = Gregorian::from_fixed(Fixed::cast_new(t));
+            let w = day_of_week_of::<GregorianMonth, Gregorian>(g.year(), g.month(), 1).unwrap();
+            let start = Gregorian::try_new(g.year(), g.month(), 1).unwrap();
+            assert_eq!(w, start.convert::<Weekday>());
+        }
+
+        #[test]
+        fn week_of_year_iso(t in FIXED_MIN..FIXED_MAX) {
+            let f = Fixed::new(t);
+            let d = Gregorian::from_fixed(f);
+            let i = ISO::from_fixed(f);
+            assert_eq!(WeekRule::ISO.week_of_year(d), (i.year(), i.week().get()));
+        }
+
+        #[test]
+        fn month_grid_rows(t in FIXED_MIN..FIXED_MAX, w in 0..7, m in 1..7) {
+            let d = Gregorian::from_fixed(Fixed::new(t));
+            let rule = WeekRule::try_new(Weekday::from_i64(w as i64).unwrap(), m as u8).unwrap();
+            let grid = rule.month_grid::<GregorianMonth, Gregorian>(d.year(), d.month()).unwrap();
+            let wm = rule.week_of_month(d);
+            let row = grid.iter().find(|r| r.week == wm).unwrap();
+            assert!(row.days.contains(&Some(d)));
+            let col = row.days.iter().position(|x| *x == Some(d)).unwrap();
+            assert_eq!(Weekday::from_fixed(d.to_fixed()), Weekday::from_i64((w as i64 + col as i64) % 7).unwrap());
+            assert!(grid[0].week <= 1);
+        }
+    }
+}
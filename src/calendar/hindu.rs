@@ -0,0 +1,201 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::prelude::CommonDate;
+use crate::common::math::TermNum;
+
+//Based on *Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz
+//These are the "modern" Hindu calculations, which follow the Surya Siddhanta.
+//Moments are in days, measured in local mean time at Ujjain.
+
+/// Length of a sidereal year in days, according to the Surya Siddhanta
+const HINDU_SIDEREAL_YEAR: f64 = 365.0 + (279457.0 / 1080000.0);
+const HINDU_ANOMALISTIC_YEAR: f64 = 1577917828000.0 / (4320000000.0 - 387.0);
+/// Fixed date of the start of the Kali Yuga
+const HINDU_EPOCH: i64 = -1132959;
+/// Days from the creation to [`HINDU_EPOCH`]
+const HINDU_CREATION_DAYS: f64 = 1955880000.0 * HINDU_SIDEREAL_YEAR;
+const SINE_TABLE_STEP: f64 = 3.75;
+const UJJAIN_LATITUDE: f64 = 23.0 + (9.0 / 60.0);
+
+fn sine_table(entry: i32) -> f64 {
+    let exact = 3438.0 * (((entry as f64) * SINE_TABLE_STEP).to_radians()).sin();
+    let error = 0.215 * exact.signum() * (exact.abs() - 1716.0).signum();
+    (exact + error).round() / 3438.0
+}
+
+fn hindu_sine(theta: f64) -> f64 {
+    let entry = theta / SINE_TABLE_STEP;
+    let fraction = entry.modulus(1.0);
+    (fraction * sine_table(entry.ceil() as i32))
+        + ((1.0 - fraction) * sine_table(entry.floor() as i32))
+}
+
+fn hindu_arcsin(amp: f64) -> f64 {
+    if amp < 0.0 {
+        return -hindu_arcsin(-amp);
+    }
+    let pos = (0..=24)
+        .find(|k| amp <= sine_table(*k))
+        .expect("Hindu sine is at most 1");
+    if pos == 0 {
+        return 0.0;
+    }
+    let below = sine_table(pos - 1);
+    SINE_TABLE_STEP * ((pos as f64) - 1.0 + ((amp - below) / (sine_table(pos) - below)))
+}
+
+fn mean_position(tee: f64, period: f64) -> f64 {
+    let since_creation = (tee - (HINDU_EPOCH as f64)) + HINDU_CREATION_DAYS;
+    360.0 * (since_creation / period).modulus(1.0)
+}
+
+fn true_position(tee: f64, period: f64, size: f64, anomalistic: f64, change: f64) -> f64 {
+    let lambda = mean_position(tee, period);
+    let offset = hindu_sine(mean_position(tee, anomalistic));
+    let contraction = offset.abs() * change * size;
+    let equation = hindu_arcsin(offset * (size - contraction));
+    (lambda - equation).modulus(360.0)
+}
+
+/// Sidereal longitude of the sun in degrees
+pub(crate) fn solar_longitude(tee: f64) -> f64 {
+    true_position(
+        tee,
+        HINDU_SIDEREAL_YEAR,
+        14.0 / 360.0,
+        HINDU_ANOMALISTIC_YEAR,
+        1.0 / 42.0,
+    )
+}
+
+fn daily_motion(date: f64) -> f64 {
+    let mean_motion = 360.0 / HINDU_SIDEREAL_YEAR;
+    let anomaly = mean_position(date, HINDU_ANOMALISTIC_YEAR);
+    let epicycle = (14.0 / 360.0) - (hindu_sine(anomaly).abs() / 1080.0);
+    let entry = (anomaly / SINE_TABLE_STEP).floor() as i32;
+    let sine_table_step = sine_table(entry + 1) - sine_table(entry);
+    let factor = (-3438.0 / 225.0) * sine_table_step * epicycle;
+    mean_motion * (1.0 + factor)
+}
+
+fn tropical_longitude(date: f64) -> f64 {
+    let days = date - (HINDU_EPOCH as f64);
+    let cycle = (27.0 + (108.0 * (600.0 / 1577917828.0) * days)).modulus(108.0);
+    let precession = 27.0 - (54.0 - cycle).abs();
+    (solar_longitude(date) - precession).modulus(360.0)
+}
+
+fn rising_sign(date: f64) -> f64 {
+    let i = (tropical_longitude(date) / 30.0).floor() as usize;
+    [1670.0, 1795.0, 1935.0, 1935.0, 1795.0, 1670.0][i % 6] / 1800.0
+}
+
+fn equation_of_time(date: f64) -> f64 {
+    let offset = hindu_sine(mean_position(date, HINDU_ANOMALISTIC_YEAR));
+    let equation_sun = offset * (57.0 + (18.0 / 60.0)) * ((14.0 / 360.0) - (offset.abs() / 1080.0));
+    (daily_motion(date) / 360.0) * (equation_sun / 360.0) * HINDU_SIDEREAL_YEAR
+}
+
+fn ascensional_difference(date: f64) -> f64 {
+    let sin_delta = (1397.0 / 3438.0) * hindu_sine(tropical_longitude(date));
+    let diurnal_radius = hindu_sine(90.0 + hindu_arcsin(sin_delta));
+    let earth_sine = sin_delta * UJJAIN_LATITUDE.to_radians().tan();
+    hindu_arcsin(-(earth_sine / diurnal_radius))
+}
+
+fn solar_sidereal_difference(date: f64) -> f64 {
+    daily_motion(date) * rising_sign(date)
+}
+
+/// Moment of sunset at Ujjain
+pub(crate) fn sunset(date: i64) -> f64 {
+    let d = date as f64;
+    let adjust = -ascensional_difference(d) + (0.75 * solar_sidereal_difference(d));
+    d + 0.75 - equation_of_time(d) + ((1577917828.0 / 1582237828.0) * (adjust / 360.0))
+}
+
+/// Sidereal sign of the zodiac containing the sun, from 1 (Mesha) to 12 (Mina)
+pub(crate) fn zodiac(tee: f64) -> u8 {
+    (solar_longitude(tee) / 30.0).floor() as u8 + 1
+}
+
+/// Solar year counted from the start of the Kali Yuga
+fn calendar_year(tee: f64) -> i32 {
+    let years = (tee - (HINDU_EPOCH as f64)) / HINDU_SIDEREAL_YEAR;
+    (years - (solar_longitude(tee) / 360.0)).round() as i32
+}
+
+/// First day on or after `approx` on which `month` is in effect
+fn month_start(approx: i64, month: u8, critical: impl Fn(i64) -> f64) -> i64 {
+    (approx..)
+        .find(|d| zodiac(critical(*d)) == month)
+        .expect("Every sign is entered within a year")
+}
+
+/// Convert a fixed day number into a solar date
+///
+/// A day belongs to the month of the zodiac sign containing the sun at `critical(day)`. The
+/// year is counted from `era` years after the start of the Kali Yuga.
+pub(crate) fn solar_from_fixed(date: i64, era: i32, critical: impl Fn(i64) -> f64) -> CommonDate {
+    let tee = critical(date);
+    let month = zodiac(tee);
+    let year = calendar_year(tee) - era;
+    let approx = date - 3 - (solar_longitude(tee).floor() as i64).modulus(30);
+    let start = month_start(approx, month, critical);
+    CommonDate::new(year, month, (date - start + 1) as u8)
+}
+
+/// Fixed day number of the first day of a month in a solar calendar
+///
+/// The parameters have the same meaning as in [`solar_from_fixed`].
+pub(crate) fn fixed_month_start(
+    year: i32,
+    month: u8,
+    era: i32,
+    critical: impl Fn(i64) -> f64,
+) -> i64 {
+    let years = (year as f64) + (era as f64) + (((month as f64) - 1.0) / 12.0);
+    let begin = ((years * HINDU_SIDEREAL_YEAR) + (HINDU_EPOCH as f64)).floor() as i64;
+    month_start(begin - 3, month, critical)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::proptest;
+
+    #[test]
+    fn sine_table_ends() {
+        assert_eq!(sine_table(0), 0.0);
+        assert_eq!(sine_table(24), 1.0);
+        assert_eq!(hindu_sine(90.0), 1.0);
+        assert_eq!(hindu_arcsin(1.0), 90.0);
+        assert_eq!(hindu_arcsin(0.0), 0.0);
+    }
+
+    #[test]
+    fn sunset_in_evening() {
+        //March 20, 2024 and June 21, 2024
+        for (date, hours) in [(738965, 18.2), (739058, 18.8)] {
+            let t = sunset(date) - (date as f64);
+            assert!(((t * 24.0) - hours).abs() < 0.1);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn arcsin_inverts_sine(theta in -90.0..90.0f64) {
+            let x = hindu_arcsin(hindu_sine(theta.modulus(360.0)));
+            assert!((x - theta).abs() < 1e-9);
+        }
+
+        #[test]
+        fn longitude_in_range(t in -1e9..1e9) {
+            let lon = solar_longitude(t);
+            assert!((0.0..360.0).contains(&lon));
+            assert!((1..=12).contains(&zodiac(t)));
+        }
+    }
+}
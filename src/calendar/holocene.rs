@@ -4,12 +4,14 @@
 
 use crate::calendar::gregorian::Gregorian;
 use crate::calendar::gregorian::GregorianMonth;
+use crate::calendar::month_table::MonthTable;
 use crate::calendar::prelude::CommonDate;
 use crate::calendar::prelude::CommonWeekOfYear;
 use crate::calendar::prelude::GuaranteedMonth;
 use crate::calendar::prelude::HasLeapYears;
 use crate::calendar::prelude::Quarter;
 use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::rest_days::RestDays;
 use crate::calendar::AllowYearZero;
 use crate::calendar::CalendarMoment;
 use crate::calendar::OrdinalDate;
@@ -20,6 +22,7 @@ use crate::day_count::Epoch;
 use crate::day_count::Fixed;
 use crate::day_count::FromFixed;
 use crate::day_count::ToFixed;
+use std::fmt;
 use std::num::NonZero;
 
 const HOLOCENE_YEAR_OFFSET: i32 = -10000;
@@ -66,7 +69,7 @@ pub type HoloceneMonth = GregorianMonth;
 /// use radnelac::calendar::*;
 /// use radnelac::day_count::*;
 ///
-/// let g = Gregorian::try_new(1752, JulianMonth::September, 14).unwrap();
+/// let g = Gregorian::try_new(1752, GregorianMonth::September, 14).unwrap();
 /// let h = g.convert::<Holocene>();
 /// assert_eq!(h, Holocene::try_new(11752, GregorianMonth::September, 14).unwrap());
 /// ```
@@ -84,9 +87,15 @@ pub type HoloceneMonth = GregorianMonth;
 /// ## Further reading
 /// + [Wikipedia](https://en.wikipedia.org/wiki/Holocene_calendar)
 /// + [Kurzgesagt](https://www.youtube.com/watch?v=czgOWmtGVGs)
-#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
 pub struct Holocene(CommonDate);
 
+impl fmt::Debug for Holocene {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<HoloceneMonth>("Holocene", f)
+    }
+}
+
 impl AllowYearZero for Holocene {}
 
 impl ToFromOrdinalDate for Holocene {
@@ -103,14 +112,13 @@ impl ToFromOrdinalDate for Holocene {
     }
 
     fn to_ordinal(self) -> OrdinalDate {
-        let g = Gregorian::try_from_common_date(self.to_common_date())
-            .expect("Same month/day validity");
+        let g = Gregorian::from_common_date_unchecked(self.to_common_date());
         g.to_ordinal()
     }
 
     fn from_ordinal_unchecked(ord: OrdinalDate) -> Self {
         let e = Gregorian::from_ordinal_unchecked(ord);
-        Holocene::try_from_common_date(e.to_common_date()).expect("Same month/day validity")
+        Holocene::from_common_date_unchecked(e.to_common_date())
     }
 }
 
@@ -120,6 +128,15 @@ impl HasLeapYears for Holocene {
     }
 }
 
+impl RestDays for Holocene {}
+
+impl MonthTable for Holocene {
+    const COMMON_MONTH_LENGTHS: &'static [u8] = Gregorian::COMMON_MONTH_LENGTHS;
+    const LEAP_MONTH_LENGTHS: &'static [u8] = Gregorian::LEAP_MONTH_LENGTHS;
+    const COMMON_DAYS_BEFORE_MONTH: &'static [u16] = Gregorian::COMMON_DAYS_BEFORE_MONTH;
+    const LEAP_DAYS_BEFORE_MONTH: &'static [u16] = Gregorian::LEAP_DAYS_BEFORE_MONTH;
+}
+
 impl CalculatedBounds for Holocene {}
 
 impl Epoch for Holocene {
@@ -143,17 +160,19 @@ impl FromFixed for Holocene {
 
 impl ToFixed for Holocene {
     fn to_fixed(self) -> Fixed {
-        let g = Gregorian::try_from_common_date(CommonDate::new(
+        let g = Gregorian::from_common_date_unchecked(CommonDate::new(
             self.0.year + (HOLOCENE_YEAR_OFFSET as i32),
             self.0.month,
             self.0.day,
-        ))
-        .expect("Same month/day rules");
+        ));
         g.to_fixed()
     }
 }
 
 impl ToFromCommonDate<HoloceneMonth> for Holocene {
+    const EFFECTIVE_YEAR_MIN: i32 = -47003915;
+    const EFFECTIVE_YEAR_MAX: i32 = 47023916;
+
     fn to_common_date(self) -> CommonDate {
         self.0
     }
@@ -185,6 +204,18 @@ impl Quarter for Holocene {
 impl GuaranteedMonth<HoloceneMonth> for Holocene {}
 impl CommonWeekOfYear<HoloceneMonth> for Holocene {}
 
+impl From<Gregorian> for Holocene {
+    fn from(date: Gregorian) -> Holocene {
+        date.convert::<Holocene>()
+    }
+}
+
+impl From<Holocene> for Gregorian {
+    fn from(date: Holocene) -> Gregorian {
+        date.convert::<Gregorian>()
+    }
+}
+
 /// Represents a date *and time* in the Holocen Calendar
 pub type HoloceneMoment = CalendarMoment<Holocene>;
 
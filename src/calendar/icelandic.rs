@@ -0,0 +1,401 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::gregorian::Gregorian;
+use crate::calendar::prelude::CommonDate;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::prelude::ToFromOrdinalDate;
+use crate::calendar::AllowYearZero;
+use crate::calendar::CalendarMoment;
+use crate::calendar::HasLeapYears;
+use crate::clock::TimeOfDay;
+use crate::common::math::TermNum;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::CalculatedBounds;
+use crate::day_count::Epoch;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use crate::day_cycle::OnOrBefore;
+use crate::day_cycle::Weekday;
+use crate::CalendarError;
+use num_traits::FromPrimitive;
+use std::cmp::Ordering;
+use std::num::NonZero;
+
+/// Represents a season (misseri) in the Icelandic calendar
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum IcelandicSeason {
+    Summer,
+    Winter,
+}
+
+impl IcelandicSeason {
+    fn first_weekday(self) -> Weekday {
+        match self {
+            IcelandicSeason::Summer => Weekday::Thursday,
+            IcelandicSeason::Winter => Weekday::Saturday,
+        }
+    }
+}
+
+/// Represents a month in the Icelandic calendar
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, FromPrimitive, ToPrimitive)]
+pub enum IcelandicMonth {
+    Harpa = 1,
+    Skerpla,
+    Solmanudur,
+    Heyannir,
+    Tvimanudur,
+    Haustmanudur,
+    Gormanudur,
+    Ylir,
+    Morsugur,
+    Thorri,
+    Goa,
+    Einmanudur,
+}
+
+/// Represents a date in the old Icelandic calendar
+///
+/// ## Introduction
+///
+/// The Icelandic calendar was used in Iceland from the early settlement period, and
+/// has been kept alongside the Julian and Gregorian calendars for festivals and
+/// folk customs. Many of the same customs were kept in the other Norse societies.
+///
+/// ## Basic Structure
+///
+/// Years are divided into two seasons (*misseri*): summer and winter.
+///
+/// Summer starts on the first Thursday on or after April 19 of the proleptic
+/// Gregorian calendar. Winter starts on a Saturday, 180 days before the next summer.
+///
+/// Dates are expressed as a week number within the season and a day of the common
+/// week. Summer weeks start on Thursday and winter weeks start on Saturday. The last
+/// week of each season is incomplete.
+///
+/// Common years have 364 days. Leap years have an additional week called the
+/// *sumarauki* ("summer addition") in the middle of summer, so that each year starts
+/// within a week of April 19.
+///
+/// Each season is also divided into 6 months of 30 days. The 4 extra nights of
+/// summer (*aukanætur*), along with the *sumarauki*, are not part of any month.
+///
+/// ## Epoch
+///
+/// Years are numbered according to the proleptic Gregorian calendar. Year 0 is
+/// supported.
+///
+/// ## Representation and Examples
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+/// use radnelac::day_cycle::*;
+///
+/// let g = Gregorian::try_new(2025, GregorianMonth::April, 24).unwrap();
+/// let i = g.convert::<Icelandic>();
+/// assert_eq!(i.year(), 2025);
+/// assert_eq!(i.season(), IcelandicSeason::Summer);
+/// assert_eq!(i.week().get(), 1);
+/// assert_eq!(i.day(), Weekday::Thursday);
+/// assert_eq!(i.month(), Some(IcelandicMonth::Harpa));
+/// ```
+///
+/// ## Further reading
+/// + [Wikipedia](https://en.wikipedia.org/wiki/Icelandic_calendar)
+/// + *Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Icelandic {
+    year: i32,
+    season: IcelandicSeason,
+    week: NonZero<u8>,
+    day: Weekday,
+}
+
+impl Icelandic {
+    /// Attempt to create a new Icelandic date
+    pub fn try_new(
+        year: i32,
+        season: IcelandicSeason,
+        week: u8,
+        day: Weekday,
+    ) -> Result<Self, CalendarError> {
+        if !(1..=28).contains(&week) {
+            return Err(CalendarError::InvalidWeek);
+        }
+        if !(Gregorian::EFFECTIVE_YEAR_MIN..=Gregorian::EFFECTIVE_YEAR_MAX).contains(&year) {
+            return Err(CalendarError::OutOfBounds);
+        }
+        let result = Icelandic {
+            year,
+            season,
+            week: NonZero::<u8>::new(week).expect("Checked in if"),
+            day,
+        };
+        //The last week of each season is incomplete, and the number of weeks
+        //in summer depends on the year.
+        if Icelandic::from_fixed(result.to_fixed()) == result {
+            Ok(result)
+        } else {
+            Err(CalendarError::InvalidWeek)
+        }
+    }
+
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    pub fn season(self) -> IcelandicSeason {
+        self.season
+    }
+
+    pub fn week(self) -> NonZero<u8> {
+        self.week
+    }
+
+    pub fn day(self) -> Weekday {
+        self.day
+    }
+
+    /// Month containing this date
+    ///
+    /// Returns [`None`] for the extra nights and the *sumarauki* in the middle of summer.
+    pub fn month(self) -> Option<IcelandicMonth> {
+        //Based on *Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz
+        //Modified to number the winter months after the summer months
+        let date = self.to_fixed().get_day_i();
+        let winter = Icelandic::winter(self.year);
+        let midsummer = winter - 90;
+        let (start, offset) = match self.season {
+            IcelandicSeason::Winter => (winter, 6),
+            IcelandicSeason::Summer if date >= midsummer => (midsummer - 90, 0),
+            IcelandicSeason::Summer if date < Icelandic::summer(self.year) + 90 => {
+                (Icelandic::summer(self.year), 0)
+            }
+            IcelandicSeason::Summer => return None,
+        };
+        IcelandicMonth::from_i64((date - start).div_euclid(30) + 1 + offset)
+    }
+
+    /// Fixed day number of the first day of summer
+    fn summer(year: i32) -> i64 {
+        //Based on *Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz
+        //Modified to use the existing Gregorian calendar functions
+        let apr19 = Gregorian::from_common_date_unchecked(CommonDate::new(year, 4, 19));
+        Weekday::Thursday.on_or_after(apr19.to_fixed()).get_day_i()
+    }
+
+    /// Fixed day number of the first day of winter
+    fn winter(year: i32) -> i64 {
+        //Based on *Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz
+        Icelandic::summer(year + 1) - 180
+    }
+
+    fn season_start(self) -> i64 {
+        match self.season {
+            IcelandicSeason::Summer => Icelandic::summer(self.year),
+            IcelandicSeason::Winter => Icelandic::winter(self.year),
+        }
+    }
+
+    fn day_offset(self) -> i64 {
+        ((self.day as i64) - (self.season.first_weekday() as i64)).modulus(7)
+    }
+
+    pub fn new_year(year: i32) -> Self {
+        Icelandic::try_new(year, IcelandicSeason::Summer, 1, Weekday::Thursday)
+            .expect("Week 1 known to be valid")
+    }
+}
+
+impl PartialOrd for Icelandic {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else if self.year != other.year {
+            self.year.partial_cmp(&other.year)
+        } else if self.season != other.season {
+            self.season.partial_cmp(&other.season)
+        } else if self.week != other.week {
+            self.week.partial_cmp(&other.week)
+        } else {
+            self.day_offset().partial_cmp(&other.day_offset())
+        }
+    }
+}
+
+impl AllowYearZero for Icelandic {}
+
+impl CalculatedBounds for Icelandic {}
+
+impl Epoch for Icelandic {
+    fn epoch() -> Fixed {
+        //Based on *Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz
+        Gregorian::from_common_date_unchecked(CommonDate::new(1, 4, 19)).to_fixed()
+    }
+}
+
+impl HasLeapYears for Icelandic {
+    fn is_leap(i_year: i32) -> bool {
+        //Based on *Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz
+        Icelandic::summer(i_year + 1) - Icelandic::summer(i_year) != 364
+    }
+}
+
+impl FromFixed for Icelandic {
+    fn from_fixed(fixed_date: Fixed) -> Icelandic {
+        //Based on *Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz
+        //Modified to use the Gregorian year as the approximation
+        let date = fixed_date.get_day_i();
+        let approx = Gregorian::ordinal_from_fixed(fixed_date).year;
+        let year = if date >= Icelandic::summer(approx) {
+            approx
+        } else {
+            approx - 1
+        };
+        let winter = Icelandic::winter(year);
+        let (season, start) = if date < winter {
+            (IcelandicSeason::Summer, Icelandic::summer(year))
+        } else {
+            (IcelandicSeason::Winter, winter)
+        };
+        let week = (date - start).div_euclid(7) + 1;
+        debug_assert!(week < 29 && week > 0);
+        Icelandic {
+            year,
+            season,
+            week: NonZero::<u8>::new(week as u8).expect("Date on or after season start"),
+            day: Weekday::from_fixed(fixed_date),
+        }
+    }
+}
+
+impl ToFixed for Icelandic {
+    fn to_fixed(self) -> Fixed {
+        //Based on *Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz
+        let week = self.week.get() as i64;
+        Fixed::cast_new(self.season_start() + 7 * (week - 1) + self.day_offset())
+    }
+}
+
+/// Represents a date *and time* in the Icelandic Calendar
+pub type IcelandicMoment = CalendarMoment<Icelandic>;
+
+impl IcelandicMoment {
+    pub fn year(self) -> i32 {
+        self.date().year()
+    }
+
+    pub fn season(self) -> IcelandicSeason {
+        self.date().season()
+    }
+
+    pub fn week(self) -> NonZero<u8> {
+        self.date().week()
+    }
+
+    pub fn day(self) -> Weekday {
+        self.date().day()
+    }
+
+    pub fn month(self) -> Option<IcelandicMonth> {
+        self.date().month()
+    }
+
+    pub fn new_year(year: i32) -> Self {
+        IcelandicMoment::new(Icelandic::new_year(year), TimeOfDay::midnight())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_count::FIXED_MAX;
+    use proptest::proptest;
+    const MAX_YEARS: i32 = ((FIXED_MAX / 365.25) - 20000.0) as i32;
+
+    #[test]
+    fn known_years() {
+        //Dates from the Almanak Háskóla Íslands
+        let expected = [
+            (2023, 4, 20, true),
+            (2024, 4, 25, false),
+            (2025, 4, 24, false),
+            (2026, 4, 23, false),
+        ];
+        for (year, month, day, leap) in expected {
+            let g = Gregorian::try_from_common_date(CommonDate::new(year, month, day)).unwrap();
+            assert_eq!(g.convert::<Icelandic>(), Icelandic::new_year(year));
+            assert_eq!(Icelandic::is_leap(year), leap);
+        }
+        let g = Gregorian::try_from_common_date(CommonDate::new(2025, 10, 25)).unwrap();
+        let i = g.convert::<Icelandic>();
+        assert_eq!(i.season(), IcelandicSeason::Winter);
+        assert_eq!(i.week().get(), 1);
+        assert_eq!(i.day(), Weekday::Saturday);
+        assert_eq!(i.month(), Some(IcelandicMonth::Gormanudur));
+    }
+
+    #[test]
+    fn invalid() {
+        let s = IcelandicSeason::Summer;
+        let w = IcelandicSeason::Winter;
+        assert!(Icelandic::try_new(2025, s, 0, Weekday::Thursday).is_err());
+        assert!(Icelandic::try_new(2025, s, 27, Weekday::Thursday).is_ok());
+        assert!(Icelandic::try_new(2025, s, 27, Weekday::Saturday).is_err());
+        assert!(Icelandic::try_new(2025, w, 26, Weekday::Wednesday).is_ok());
+        assert!(Icelandic::try_new(2025, w, 26, Weekday::Thursday).is_err());
+        assert!(Icelandic::try_new(2023, s, 28, Weekday::Friday).is_ok());
+        assert!(Icelandic::try_new(2025, s, 28, Weekday::Thursday).is_err());
+        assert!(Icelandic::try_new(i32::MAX, s, 1, Weekday::Thursday).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn year_length(year in -MAX_YEARS..MAX_YEARS) {
+            let len = Icelandic::summer(year + 1) - Icelandic::summer(year);
+            let expected = if Icelandic::is_leap(year) { 371 } else { 364 };
+            assert_eq!(len, expected);
+            let w = Weekday::from_fixed(Fixed::cast_new(Icelandic::winter(year)));
+            assert_eq!(w, Weekday::Saturday);
+        }
+
+        #[test]
+        fn months(year in -MAX_YEARS..MAX_YEARS) {
+            let start = Icelandic::summer(year);
+            let end = Icelandic::summer(year + 1);
+            let mut count = [0; 13];
+            for date in start..end {
+                let i = Icelandic::from_fixed(Fixed::cast_new(date));
+                assert_eq!(i.year(), year);
+                match i.month() {
+                    Some(m) => count[m as usize] += 1,
+                    None => count[0] += 1,
+                }
+            }
+            assert_eq!(count[0], if Icelandic::is_leap(year) { 11 } else { 4 });
+            for c in &count[1..] {
+                assert_eq!(*c, 30);
+            }
+        }
+
+        #[test]
+        fn next_day(t in (-MAX_YEARS as f64 * 365.0)..(MAX_YEARS as f64 * 365.0)) {
+            let f0 = Fixed::cast_new(t as i64);
+            let f1 = Fixed::cast_new((t as i64) + 1);
+            let i0 = Icelandic::from_fixed(f0);
+            let i1 = Icelandic::from_fixed(f1);
+            assert!(i0 < i1);
+            assert_eq!(i1.to_fixed(), f1);
+            if i1.day() == i1.season.first_weekday() {
+                assert!(i1.week().get() == 1 || i1.week().get() == i0.week().get() + 1);
+            } else {
+                assert_eq!(i1.week(), i0.week());
+                assert_eq!(i1.season(), i0.season());
+            }
+        }
+    }
+}
@@ -0,0 +1,345 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+/// Broad grouping of calendar systems
+///
+/// Each family other than `Standard` corresponds to a crate feature.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[non_exhaustive]
+pub enum CalendarFamily {
+    /// Calendars in widespread civil or commercial use, always available
+    Standard,
+    /// Historical calendars, requires `ancient-calendars`
+    Ancient,
+    /// Proposed calendar reforms, requires `reform-calendars`
+    Reform,
+    /// Calendars from fiction, requires `fiction`
+    Fiction,
+}
+
+/// Treatment of the year before year 1
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum YearZeroPolicy {
+    /// Year 0 is a normal year
+    Allowed,
+    /// Year 1 is immediately preceded by year -1
+    Skipped,
+    /// Year 0 is only used for the epoch
+    EpochOnly,
+}
+
+/// Metadata about a supported calendar system
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct CalendarInfo {
+    /// Stable identifier, in lower case with words separated by `-`
+    pub id: &'static str,
+    /// Human readable name in English
+    pub name: &'static str,
+    pub family: CalendarFamily,
+    pub year_zero: YearZeroPolicy,
+}
+
+impl CalendarInfo {
+    const fn new(
+        id: &'static str,
+        name: &'static str,
+        family: CalendarFamily,
+        year_zero: YearZeroPolicy,
+    ) -> CalendarInfo {
+        CalendarInfo {
+            id,
+            name,
+            family,
+            year_zero,
+        }
+    }
+}
+
+/// Metadata about every calendar system enabled by the crate features, sorted by `id`
+///
+/// ```
+/// use radnelac::calendar::*;
+///
+/// let g = all().into_iter().find(|c| c.id == "gregorian").unwrap();
+/// assert_eq!(g.name, "Gregorian");
+/// assert_eq!(g.family, CalendarFamily::Standard);
+/// assert_eq!(g.year_zero, YearZeroPolicy::Allowed);
+/// ```
+pub fn all() -> Vec<CalendarInfo> {
+    use CalendarFamily::*;
+    use YearZeroPolicy::*;
+    let mut result = Vec::new();
+    result.push(CalendarInfo::new(
+        "afghan",
+        "Afghan (arithmetic approximation)",
+        Standard,
+        Skipped,
+    ));
+    #[cfg(feature = "ancient-calendars")]
+    {
+        result.push(CalendarInfo::new("armenian", "Armenian", Ancient, Allowed));
+        result.push(CalendarInfo::new(
+            "attic",
+            "Attic (arithmetic approximation)",
+            Ancient,
+            Allowed,
+        ));
+        result.push(CalendarInfo::new(
+            "babylonian",
+            "Late Babylonian (arithmetic approximation)",
+            Ancient,
+            Allowed,
+        ));
+    }
+    result.push(CalendarInfo::new(
+        "bengali",
+        "Bengali (revised)",
+        Standard,
+        Allowed,
+    ));
+    result.push(CalendarInfo::new(
+        "bikram-sambat",
+        "Bikram Sambat",
+        Standard,
+        Allowed,
+    ));
+    #[cfg(feature = "ancient-calendars")]
+    {
+        result.push(CalendarInfo::new(
+            "coligny",
+            "Coligny (reconstruction)",
+            Ancient,
+            Allowed,
+        ));
+        result.push(CalendarInfo::new(
+            "coligny-saeculum",
+            "Coligny (reconstruction with saeculum)",
+            Ancient,
+            Allowed,
+        ));
+        result.push(CalendarInfo::new("coptic", "Coptic", Ancient, Allowed));
+    }
+    #[cfg(feature = "reform-calendars")]
+    result.push(CalendarInfo::new("cotsworth", "Cotsworth", Reform, Allowed));
+    #[cfg(feature = "ancient-calendars")]
+    result.push(CalendarInfo::new("egyptian", "Egyptian", Ancient, Allowed));
+    #[cfg(feature = "reform-calendars")]
+    result.push(CalendarInfo::new(
+        "erisian-year",
+        "Erisian year",
+        Reform,
+        Allowed,
+    ));
+    #[cfg(feature = "ancient-calendars")]
+    result.push(CalendarInfo::new("ethiopic", "Ethiopic", Ancient, Allowed));
+    #[cfg(feature = "reform-calendars")]
+    {
+        result.push(CalendarInfo::new(
+            "french-rev",
+            "French Revolutionary (adjusted)",
+            Reform,
+            Allowed,
+        ));
+        result.push(CalendarInfo::new(
+            "french-rev-unadjusted",
+            "French Revolutionary (unadjusted)",
+            Reform,
+            Allowed,
+        ));
+    }
+    result.push(CalendarInfo::new(
+        "gregorian",
+        "Gregorian",
+        Standard,
+        Allowed,
+    ));
+    #[cfg(feature = "reform-calendars")]
+    result.push(CalendarInfo::new("holocene", "Holocene", Reform, Allowed));
+    #[cfg(feature = "ancient-calendars")]
+    result.push(CalendarInfo::new(
+        "icelandic",
+        "Icelandic",
+        Ancient,
+        Allowed,
+    ));
+    result.push(CalendarInfo::new(
+        "islamic",
+        "Islamic (arithmetic)",
+        Standard,
+        Allowed,
+    ));
+    result.push(CalendarInfo::new(
+        "islamic-umm-al-qura",
+        "Islamic (Umm al-Qura)",
+        Standard,
+        Allowed,
+    ));
+    result.push(CalendarInfo::new("iso", "ISO week date", Standard, Allowed));
+    result.push(CalendarInfo::new("julian", "Julian", Standard, Skipped));
+    result.push(CalendarInfo::new(
+        "kurdish",
+        "Kurdish (arithmetic approximation)",
+        Standard,
+        Skipped,
+    ));
+    result.push(CalendarInfo::new("minguo", "Minguo", Standard, Allowed));
+    #[cfg(feature = "reform-calendars")]
+    result.push(CalendarInfo::new(
+        "pataphysical",
+        "Pataphysical",
+        Reform,
+        Allowed,
+    ));
+    result.push(CalendarInfo::new(
+        "persian",
+        "Persian (arithmetic approximation)",
+        Standard,
+        Skipped,
+    ));
+    #[cfg(feature = "reform-calendars")]
+    result.push(CalendarInfo::new(
+        "positivist",
+        "Positivist",
+        Reform,
+        Allowed,
+    ));
+    result.push(CalendarInfo::new(
+        "retail445",
+        "Retail 4-4-5",
+        Standard,
+        Allowed,
+    ));
+    result.push(CalendarInfo::new(
+        "retail454",
+        "Retail 4-5-4",
+        Standard,
+        Allowed,
+    ));
+    result.push(CalendarInfo::new(
+        "retail544",
+        "Retail 5-4-4",
+        Standard,
+        Allowed,
+    ));
+    #[cfg(feature = "ancient-calendars")]
+    result.push(CalendarInfo::new("roman", "Roman", Ancient, Skipped));
+    #[cfg(feature = "fiction")]
+    result.push(CalendarInfo::new("shire", "Shire", Fiction, Allowed));
+    #[cfg(feature = "reform-calendars")]
+    {
+        result.push(CalendarInfo::new(
+            "symmetry010",
+            "Symmetry010",
+            Reform,
+            Allowed,
+        ));
+        result.push(CalendarInfo::new(
+            "symmetry010-solstice",
+            "Symmetry010 (solstice)",
+            Reform,
+            Allowed,
+        ));
+        result.push(CalendarInfo::new(
+            "symmetry454",
+            "Symmetry454",
+            Reform,
+            Allowed,
+        ));
+        result.push(CalendarInfo::new(
+            "symmetry454-solstice",
+            "Symmetry454 (solstice)",
+            Reform,
+            Allowed,
+        ));
+    }
+    result.push(CalendarInfo::new(
+        "tamil",
+        "Tamil (solar)",
+        Standard,
+        Allowed,
+    ));
+    result.push(CalendarInfo::new(
+        "thai-solar",
+        "Thai solar",
+        Standard,
+        Allowed,
+    ));
+    #[cfg(feature = "reform-calendars")]
+    result.push(CalendarInfo::new(
+        "tranquility",
+        "Tranquility",
+        Reform,
+        EpochOnly,
+    ));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Afghan;
+    use crate::calendar::Gregorian;
+    use crate::calendar::Julian;
+    use crate::calendar::PersianArith;
+    #[cfg(feature = "ancient-calendars")]
+    use crate::calendar::Roman;
+    #[cfg(feature = "reform-calendars")]
+    use crate::calendar::Tranquility;
+    use crate::calendar::YearNumbering;
+    use crate::calendar::ISO;
+    use proptest::proptest;
+
+    #[test]
+    fn sorted_and_unique() {
+        let list = all();
+        assert!(list.windows(2).all(|w| w[0].id < w[1].id));
+        for c in &list {
+            assert!(!c.name.is_empty());
+            assert!(c
+                .id
+                .chars()
+                .all(|x| x.is_ascii_lowercase() || x.is_ascii_digit() || x == '-'));
+        }
+        let has = |f| list.iter().any(|c| c.family == f);
+        assert_eq!(
+            has(CalendarFamily::Ancient),
+            cfg!(feature = "ancient-calendars")
+        );
+        assert_eq!(
+            has(CalendarFamily::Reform),
+            cfg!(feature = "reform-calendars")
+        );
+        assert_eq!(has(CalendarFamily::Fiction), cfg!(feature = "fiction"));
+        assert!(has(CalendarFamily::Standard));
+    }
+
+    #[test]
+    fn year_numbering() {
+        let list = all();
+        let policy = |id: &str| list.iter().find(|c| c.id == id).unwrap().year_zero;
+        assert_eq!(policy("afghan"), Afghan::YEAR_ZERO);
+        assert_eq!(policy("gregorian"), Gregorian::YEAR_ZERO);
+        assert_eq!(policy("iso"), ISO::YEAR_ZERO);
+        assert_eq!(policy("julian"), Julian::YEAR_ZERO);
+        assert_eq!(policy("persian"), PersianArith::YEAR_ZERO);
+        #[cfg(feature = "ancient-calendars")]
+        assert_eq!(policy("roman"), Roman::YEAR_ZERO);
+        #[cfg(feature = "reform-calendars")]
+        assert_eq!(policy("tranquility"), Tranquility::YEAR_ZERO);
+    }
+
+    proptest! {
+        #[test]
+        fn astronomical_round_trip(y in -1000000..1000000i32) {
+            let a = Gregorian::astronomical_year(y);
+            assert_eq!(a, y);
+            assert_eq!(Gregorian::from_astronomical_year(a), y);
+            assert_ne!(Gregorian::historical_year(y), 0);
+            let j = Julian::from_astronomical_year(a);
+            assert_ne!(j, 0);
+            assert_eq!(Julian::astronomical_year(j), a);
+            assert_eq!(Julian::historical_year(j), Gregorian::historical_year(y));
+        }
+    }
+}
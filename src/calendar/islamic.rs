@@ -0,0 +1,295 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::julian::Julian;
+use crate::calendar::month_table::cumulative_lengths;
+use crate::calendar::month_table::MonthTable;
+use crate::calendar::prelude::CommonDate;
+use crate::calendar::prelude::CommonWeekOfYear;
+use crate::calendar::prelude::GuaranteedMonth;
+use crate::calendar::prelude::HasLeapYears;
+use crate::calendar::prelude::Quarter;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::rest_days::RestDays;
+use crate::calendar::rest_days::RestWeekdays;
+use crate::calendar::AllowYearZero;
+use crate::calendar::CalendarMoment;
+use crate::calendar::OrdinalDate;
+use crate::calendar::ToFromOrdinalDate;
+use crate::common::error::CalendarError;
+use crate::common::math::TermNum;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::CalculatedBounds;
+use crate::day_count::Epoch;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use std::num::NonZero;
+
+#[allow(unused_imports)] //FromPrimitive is needed for derive
+use num_traits::FromPrimitive;
+use std::fmt;
+
+const ISLAMIC_EPOCH_JULIAN: CommonDate = CommonDate {
+    year: 622,
+    month: 7,
+    day: 16,
+};
+
+/// Represents a month in the Islamic calendar
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, FromPrimitive, ToPrimitive)]
+pub enum IslamicMonth {
+    Muharram = 1,
+    Safar,
+    RabiAlAwwal,
+    RabiAlThani,
+    JumadaAlUla,
+    JumadaAlAkhira,
+    Rajab,
+    Shaban,
+    Ramadan,
+    Shawwal,
+    DhuAlQadah,
+    DhuAlHijjah,
+}
+
+/// Represents a date in the tabular (arithmetic) Islamic calendar
+///
+/// ## Introduction
+///
+/// The Islamic calendar is a lunar calendar used to determine the dates of Islamic
+/// holidays and rituals. It is the official calendar of Saudi Arabia.
+///
+/// `IslamicArith` is the **tabular** Islamic calendar, which follows a fixed 30 year
+/// cycle of leap years. **It does not read astronomical data nor approximate such data.**
+/// In practice, most communities start each month based on observation of the crescent
+/// moon, or on astronomical calculations, so the dates used in practice may differ from
+/// this calendar by a day or two.
+///
+/// ## Basic Structure
+///
+/// Years are divided into 12 months. Odd-numbered months have 30 days and even-numbered
+/// months have 29 days, except that the last month has 30 days in leap years.
+///
+/// There are 11 leap years in each 30 year cycle: years 2, 5, 7, 10, 13, 16, 18, 21,
+/// 24, 26 and 29.
+///
+/// ## Epoch
+///
+/// Years are numbered from the year of the Hijra of Muhammad. The first day of the
+/// first year was 16 July 622 Common Era in the proleptic Julian calendar.
+///
+/// This epoch is called "Anno Hegirae" (AH).
+///
+/// ## Representation and Examples
+///
+/// The months are represented in this crate as [`IslamicMonth`].
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// let j = Julian::try_new(622, JulianMonth::July, 16).unwrap();
+/// let i = j.convert::<IslamicArith>();
+/// assert_eq!(i, IslamicArith::try_new(1, IslamicMonth::Muharram, 1).unwrap());
+/// ```
+///
+/// ## Further reading
+/// + [Wikipedia](https://en.wikipedia.org/wiki/Tabular_Islamic_calendar)
+/// + *Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
+pub struct IslamicArith(CommonDate);
+
+impl fmt::Debug for IslamicArith {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<IslamicMonth>("IslamicArith", f)
+    }
+}
+
+impl IslamicArith {
+    /// Name of the era, used as a suffix for years
+    pub const ERA_NAME: &'static str = "Anno Hegirae";
+
+    pub(crate) fn prior_elapsed_days(year: i32) -> i64 {
+        //Based on *Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz
+        let y = year as i64;
+        IslamicArith::epoch().get_day_i() - 1 + (354 * (y - 1)) + (3 + (11 * y)).div_euclid(30)
+    }
+}
+
+impl AllowYearZero for IslamicArith {}
+
+impl ToFromOrdinalDate for IslamicArith {
+    fn valid_ordinal(ord: OrdinalDate) -> Result<(), CalendarError> {
+        let correction = if IslamicArith::is_leap(ord.year) {
+            1
+        } else {
+            0
+        };
+        if ord.day_of_year > 0 && ord.day_of_year <= (354 + correction) {
+            Ok(())
+        } else {
+            Err(CalendarError::InvalidDayOfYear)
+        }
+    }
+
+    fn ordinal_from_fixed(fixed_date: Fixed) -> OrdinalDate {
+        //Based on *Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz
+        let date = fixed_date.get_day_i();
+        let epoch = IslamicArith::epoch().get_day_i();
+        let year = ((30 * (date - epoch)) + 10646).div_euclid(10631) as i32;
+        OrdinalDate {
+            year,
+            day_of_year: (date - IslamicArith::prior_elapsed_days(year)) as u16,
+        }
+    }
+
+    fn to_ordinal(self) -> OrdinalDate {
+        let month = self.0.month as u16;
+        OrdinalDate {
+            year: self.0.year,
+            day_of_year: (29 * (month - 1)) + (month / 2) + (self.0.day as u16),
+        }
+    }
+
+    fn from_ordinal_unchecked(ord: OrdinalDate) -> Self {
+        //Based on *Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz
+        let prior_days = ord.day_of_year - 1;
+        let month = (((11 * prior_days) + 330) / 325).min(12) as u8;
+        let month_start = IslamicArith(CommonDate::new(ord.year, month, 1)).to_ordinal();
+        let day = (ord.day_of_year - month_start.day_of_year + 1) as u8;
+        IslamicArith(CommonDate::new(ord.year, month, day))
+    }
+}
+
+impl HasLeapYears for IslamicArith {
+    fn is_leap(year: i32) -> bool {
+        //Based on *Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz
+        (14 + (11 * (year as i64))).modulus(30) < 11
+    }
+}
+
+impl RestDays for IslamicArith {
+    const REST_WEEKDAYS: RestWeekdays = RestWeekdays::FRIDAY;
+}
+
+const COMMON_MONTH_LENGTHS: [u8; 12] = [30, 29, 30, 29, 30, 29, 30, 29, 30, 29, 30, 29];
+const LEAP_MONTH_LENGTHS: [u8; 12] = [30, 29, 30, 29, 30, 29, 30, 29, 30, 29, 30, 30];
+
+impl MonthTable for IslamicArith {
+    const COMMON_MONTH_LENGTHS: &'static [u8] = &COMMON_MONTH_LENGTHS;
+    const LEAP_MONTH_LENGTHS: &'static [u8] = &LEAP_MONTH_LENGTHS;
+    const COMMON_DAYS_BEFORE_MONTH: &'static [u16] = &cumulative_lengths(COMMON_MONTH_LENGTHS);
+    const LEAP_DAYS_BEFORE_MONTH: &'static [u16] = &cumulative_lengths(LEAP_MONTH_LENGTHS);
+}
+
+impl CalculatedBounds for IslamicArith {}
+
+impl Epoch for IslamicArith {
+    fn epoch() -> Fixed {
+        Julian::from_common_date_unchecked(ISLAMIC_EPOCH_JULIAN).to_fixed()
+    }
+}
+
+impl FromFixed for IslamicArith {
+    fn from_fixed(fixed_date: Fixed) -> IslamicArith {
+        Self::from_ordinal_unchecked(Self::ordinal_from_fixed(fixed_date))
+    }
+}
+
+impl ToFixed for IslamicArith {
+    fn to_fixed(self) -> Fixed {
+        let offset_prior = IslamicArith::prior_elapsed_days(self.0.year);
+        Fixed::cast_new(offset_prior + (self.to_ordinal().day_of_year as i64))
+    }
+}
+
+impl ToFromCommonDate<IslamicMonth> for IslamicArith {
+    const EFFECTIVE_YEAR_MIN: i32 = -48457456;
+    const EFFECTIVE_YEAR_MAX: i32 = 48456175;
+
+    fn to_common_date(self) -> CommonDate {
+        self.0
+    }
+
+    fn from_common_date_unchecked(date: CommonDate) -> Self {
+        debug_assert!(Self::valid_ymd(date).is_ok());
+        Self(date)
+    }
+
+    fn valid_ymd(date: CommonDate) -> Result<(), CalendarError> {
+        match IslamicMonth::from_u8(date.month) {
+            None => Err(CalendarError::InvalidMonth),
+            Some(m) if date.day < 1 || date.day > Self::month_length(date.year, m) => {
+                Err(CalendarError::InvalidDay)
+            }
+            Some(_) => Ok(()),
+        }
+    }
+
+    fn year_end_date(year: i32) -> CommonDate {
+        let m = IslamicMonth::DhuAlHijjah;
+        CommonDate::new(year, m as u8, Self::month_length(year, m))
+    }
+
+    fn month_length(year: i32, month: IslamicMonth) -> u8 {
+        match month {
+            IslamicMonth::DhuAlHijjah if IslamicArith::is_leap(year) => 30,
+            m if (m as u8) % 2 == 1 => 30,
+            _ => 29,
+        }
+    }
+}
+
+impl Quarter for IslamicArith {
+    fn quarter(self) -> NonZero<u8> {
+        NonZero::new(((self.to_common_date().month - 1) / 3) + 1).expect("(m-1)/3 > -1")
+    }
+}
+
+impl GuaranteedMonth<IslamicMonth> for IslamicArith {}
+impl CommonWeekOfYear<IslamicMonth> for IslamicArith {}
+
+/// Represents a date *and time* in the tabular Islamic calendar
+pub type IslamicArithMoment = CalendarMoment<IslamicArith>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_cycle::Weekday;
+
+    #[test]
+    fn epoch() {
+        let start = IslamicArith::try_year_start(1).unwrap();
+        assert_eq!(start.to_fixed(), IslamicArith::epoch());
+        assert_eq!(IslamicArith::epoch().get_day_i(), 227015);
+        assert_eq!(Weekday::from_fixed(IslamicArith::epoch()), Weekday::Friday);
+    }
+
+    #[test]
+    fn cycle() {
+        let leap: Vec<i32> = (1..=30).filter(|y| IslamicArith::is_leap(*y)).collect();
+        assert_eq!(leap, vec![2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29]);
+        let d0 = IslamicArith::prior_elapsed_days(1);
+        let d1 = IslamicArith::prior_elapsed_days(31);
+        assert_eq!(d1 - d0, 10631);
+    }
+
+    #[test]
+    fn month_lengths() {
+        for year in [1, 2] {
+            let total: u16 = (1..=12)
+                .map(|m| IslamicArith::month_length(year, IslamicMonth::from_u8(m).unwrap()) as u16)
+                .sum();
+            let expected = if IslamicArith::is_leap(year) {
+                355
+            } else {
+                354
+            };
+            assert_eq!(total, expected);
+            let end = IslamicArith::try_year_end(year).unwrap();
+            assert_eq!(end.to_ordinal().day_of_year, expected);
+        }
+    }
+}
@@ -8,9 +8,11 @@ use crate::calendar::prelude::CommonWeekOfYear;
 use crate::calendar::prelude::Quarter;
 use crate::calendar::prelude::ToFromCommonDate;
 use crate::calendar::prelude::ToFromOrdinalDate;
+use crate::calendar::rest_days::RestDays;
 use crate::calendar::AllowYearZero;
 use crate::calendar::CalendarMoment;
 use crate::calendar::HasLeapYears;
+use crate::calendar::LeapWeekCalendar;
 use crate::clock::TimeOfDay;
 use crate::common::math::TermNum;
 use crate::day_count::BoundedDayCount;
@@ -148,14 +150,30 @@ impl HasLeapYears for ISO {
     }
 }
 
+impl RestDays for ISO {}
+
+impl LeapWeekCalendar for ISO {
+    fn year_start_day(year: i32) -> i64 {
+        ISO::new_year(year).to_fixed().get_day_i()
+    }
+
+    fn year_and_start(date: i64) -> (i32, i64) {
+        //LISTING 5.2 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
+        let approx = Gregorian::ordinal_from_fixed(Fixed::cast_new(date - 3)).year;
+        let next = ISO::year_start_day(approx + 1);
+        if date >= next {
+            (approx + 1, next)
+        } else {
+            (approx, ISO::year_start_day(approx))
+        }
+    }
+}
+
 impl FromFixed for ISO {
     fn from_fixed(fixed_date: Fixed) -> ISO {
         //LISTING 5.2 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
         let date = fixed_date.get_day_i();
-        let approx = Gregorian::ordinal_from_fixed(Fixed::cast_new(date - 3)).year;
-        let next = ISO::new_year(approx + 1).to_fixed().get_day_i();
-        let year = if date >= next { approx + 1 } else { approx };
-        let current = ISO::new_year(year).to_fixed().get_day_i();
+        let (year, current) = ISO::year_and_start(date);
         let week = (date - current).div_euclid(7) + 1;
         debug_assert!(week < 55 && week > 0);
         //Calendrical Calculations stores "day" as 7 for Sunday, as per ISO.
@@ -175,8 +193,8 @@ impl ToFixed for ISO {
         //However since we have an unambiguous enum, we can save such details for
         //functions that need it. We also adjust "from_fixed_unchecked"
         let day_i = (self.day as i64).adjusted_remainder(7);
-        let result = Gregorian::try_from_common_date(g)
-            .expect("month 12, day 28 is always valid for Gregorian")
+        //Month 12, day 28 is always valid for Gregorian
+        let result = Gregorian::from_common_date_unchecked(g)
             .nth_kday(w, Weekday::Sunday)
             .get_day_i()
             + day_i;
@@ -190,6 +208,31 @@ impl Quarter for ISO {
     }
 }
 
+/// Dates which can be labeled with an ISO-8601 week date
+///
+/// This is implemented for every type which can be converted to [`Fixed`], so that dates
+/// in other calendars can be labeled with ISO weeks without converting to [`ISO`] first.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+/// use radnelac::day_cycle::*;
+///
+/// let g = Gregorian::try_new(2025, GregorianMonth::May, 15).unwrap();
+/// let s = g.convert::<Symmetry454>();
+/// let (year, week, day) = s.iso_week();
+/// assert_eq!((year, week.get(), day), (2025, 20, Weekday::Thursday));
+/// ```
+pub trait ISOWeekDate: ToFixed {
+    /// ISO year, week and day of week of the same day
+    fn iso_week(self) -> (i32, NonZero<u8>, Weekday) {
+        let i = ISO::from_fixed(self.to_fixed());
+        (i.year(), i.week(), i.day())
+    }
+}
+
+impl<T: ToFixed> ISOWeekDate for T {}
+
 /// Represents a date *and time* in the ISO Calendar
 pub type ISOMoment = CalendarMoment<ISO>;
 
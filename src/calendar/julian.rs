@@ -2,17 +2,23 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use crate::calendar::gregorian::days_in_gregorian_month;
 use crate::calendar::gregorian::GregorianMonth;
+use crate::calendar::month_table::MonthTable;
 use crate::calendar::prelude::CommonDate;
 use crate::calendar::prelude::CommonWeekOfYear;
 use crate::calendar::prelude::GuaranteedMonth;
 use crate::calendar::prelude::HasLeapYears;
 use crate::calendar::prelude::Quarter;
 use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::prelude::YearNumbering;
+use crate::calendar::rest_days::RestDays;
+use crate::calendar::rest_days::RestWeekdays;
 use crate::calendar::CalendarMoment;
 use crate::calendar::Gregorian;
 use crate::calendar::OrdinalDate;
 use crate::calendar::ToFromOrdinalDate;
+use crate::calendar::YearZeroPolicy;
 use crate::common::error::CalendarError;
 use crate::common::math::TermNum;
 use crate::day_count::BoundedDayCount;
@@ -22,13 +28,74 @@ use crate::day_count::Fixed;
 use crate::day_count::FromFixed;
 use crate::day_count::RataDie;
 use crate::day_count::ToFixed;
+use crate::day_cycle::OnOrBefore;
+use crate::day_cycle::Weekday;
+use crate::raw::days_before_month;
+use crate::raw::is_julian_leap_year;
+use crate::raw::julian_days_before_year;
 use std::num::NonZero;
 
 #[allow(unused_imports)] //FromPrimitive is needed for derive
 use num_traits::FromPrimitive;
+use std::fmt;
 
 /// Represents a month in the Julian calendar
-pub type JulianMonth = GregorianMonth;
+///
+/// The months have the same names and order as [`GregorianMonth`], but are a distinct
+/// type so that function signatures can require a Julian month specifically. Use
+/// [`From`] to convert between the two.
+///
+/// ```
+/// use radnelac::calendar::*;
+///
+/// assert_eq!(GregorianMonth::from(JulianMonth::March), GregorianMonth::March);
+/// assert_eq!(JulianMonth::from(GregorianMonth::March), JulianMonth::March);
+/// ```
+///
+/// ## Migration
+///
+/// In version 0.0.2 and earlier, `JulianMonth` was an alias of [`GregorianMonth`]. Code
+/// which passes a [`GregorianMonth`] to [`Julian`], or compares the month of a [`Julian`]
+/// with a [`GregorianMonth`], should convert the month with [`From`] or [`Into`].
+///
+/// For one release, [`Julian::try_new_gregorian_month`] and [`Julian::gregorian_month`]
+/// accept and return a [`GregorianMonth`] as before. They are deprecated and will be
+/// removed in the following release.
+///
+/// ```
+/// use radnelac::calendar::*;
+///
+/// let g_month = GregorianMonth::September;
+/// let j = Julian::try_new(1752, g_month.into(), 3).unwrap();
+/// assert_eq!(GregorianMonth::from(j.month()), g_month);
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, FromPrimitive, ToPrimitive)]
+pub enum JulianMonth {
+    January = 1,
+    February,
+    March,
+    April,
+    May,
+    June,
+    July,
+    August,
+    September,
+    October,
+    November,
+    December,
+}
+
+impl From<GregorianMonth> for JulianMonth {
+    fn from(month: GregorianMonth) -> JulianMonth {
+        JulianMonth::from_u8(month as u8).expect("Same range of months")
+    }
+}
+
+impl From<JulianMonth> for GregorianMonth {
+    fn from(month: JulianMonth) -> GregorianMonth {
+        GregorianMonth::from_u8(month as u8).expect("Same range of months")
+    }
+}
 
 //LISTING 3.2 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
 //Instead of explicitly converting from Gregorian, just use the known Rata Die value.
@@ -131,22 +198,62 @@ const JULIAN_EPOCH_RD: i32 = -1;
 ///   + [Julian calendar](https://en.wikipedia.org/wiki/Julian_calendar)
 ///   + [Proleptic Julian calendar](https://en.m.wikipedia.org/wiki/Proleptic_Julian_calendar)
 ///   + [Ab urbe condita](https://en.m.wikipedia.org/wiki/Ab_urbe_condita)
-#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
 pub struct Julian(CommonDate);
 
+impl fmt::Debug for Julian {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<JulianMonth>("Julian", f)
+    }
+}
+
 impl Julian {
     pub fn nz_year(self) -> NonZero<i32> {
         NonZero::new(self.0.year).expect("Will not be assigned zero")
     }
 
+    /// Attempt to create a date from a [`GregorianMonth`]
+    #[deprecated(since = "0.0.3", note = "use `Julian::try_new` with a `JulianMonth`")]
+    pub fn try_new_gregorian_month(
+        year: i32,
+        month: GregorianMonth,
+        day: u8,
+    ) -> Result<Julian, CalendarError> {
+        Julian::try_new(year, month.into(), day)
+    }
+
+    /// Month of the date as a [`GregorianMonth`]
+    #[deprecated(
+        since = "0.0.3",
+        note = "use `Julian::month`, which returns a `JulianMonth`"
+    )]
+    pub fn gregorian_month(self) -> GregorianMonth {
+        self.month().into()
+    }
+
+    /// Fixed day number of the last day before the start of a year
+    ///
+    /// See also [`julian_days_before_year`](crate::raw::julian_days_before_year).
     pub fn prior_elapsed_days(year: i32) -> i64 {
         //LISTING 3.3 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
         //These are the terms which do not rely on the day or month
-        let y = if year < 0 { year + 1 } else { year } as i64;
         let offset_e = Julian::epoch().get_day_i() - 1;
-        let offset_y = 365 * (y - 1);
-        let offset_leap = (y - 1).div_euclid(4);
-        offset_e + offset_y + offset_leap
+        offset_e + julian_days_before_year(year as i64)
+    }
+
+    /// Calculate the date of Easter in a given Julian year
+    ///
+    /// This uses the Alexandrian computus, which is the method used by the Eastern
+    /// Orthodox churches as well as the Coptic and Ethiopian Orthodox churches.
+    pub fn easter(year: NonZero<i32>) -> Julian {
+        //LISTING 8.1 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
+        //Modified to accept a Julian year instead of a Gregorian year
+        let j_year = year.get();
+        let y = if j_year < 0 { j_year + 1 } else { j_year } as i64;
+        let shifted_epact = (14 + (11 * y.modulus(19))).modulus(30);
+        let april_19 = Julian(CommonDate::new(j_year, 4, 19)).to_fixed();
+        let paschal_moon = Fixed::cast_new(april_19.get_day_i() - shifted_epact);
+        Julian::from_fixed(Weekday::Sunday.after(paschal_moon))
     }
 }
 
@@ -179,21 +286,10 @@ impl ToFromOrdinalDate for Julian {
         //LISTING 3.3 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
         //These are the terms which rely on the day or month
         let year = self.0.year;
-        let month = self.0.month as i64;
-        let day = self.0.day as i64;
-        let offset_m = ((367 * month) - 362).div_euclid(12);
-        let offset_x = if month <= 2 {
-            0
-        } else if Julian::is_leap(year) {
-            -1
-        } else {
-            -2
-        };
-        let offset_d = day;
-
+        let offset_m = days_before_month(self.0.month, Julian::is_leap(year));
         OrdinalDate {
             year: year,
-            day_of_year: (offset_m + offset_x + offset_d) as u16,
+            day_of_year: offset_m + (self.0.day as u16),
         }
     }
 
@@ -218,18 +314,27 @@ impl ToFromOrdinalDate for Julian {
     }
 }
 
+impl YearNumbering for Julian {
+    const YEAR_ZERO: YearZeroPolicy = YearZeroPolicy::Skipped;
+}
+
 impl HasLeapYears for Julian {
     fn is_leap(j_year: i32) -> bool {
-        //LISTING 3.1 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
-        let m4 = j_year.modulus(4);
-        if j_year > 0 {
-            m4 == 0
-        } else {
-            m4 == 3
-        }
+        is_julian_leap_year(j_year as i64)
     }
 }
 
+impl RestDays for Julian {
+    const REST_WEEKDAYS: RestWeekdays = RestWeekdays::SUNDAY;
+}
+
+impl MonthTable for Julian {
+    const COMMON_MONTH_LENGTHS: &'static [u8] = Gregorian::COMMON_MONTH_LENGTHS;
+    const LEAP_MONTH_LENGTHS: &'static [u8] = Gregorian::LEAP_MONTH_LENGTHS;
+    const COMMON_DAYS_BEFORE_MONTH: &'static [u16] = Gregorian::COMMON_DAYS_BEFORE_MONTH;
+    const LEAP_DAYS_BEFORE_MONTH: &'static [u16] = Gregorian::LEAP_DAYS_BEFORE_MONTH;
+}
+
 impl CalculatedBounds for Julian {}
 
 impl Epoch for Julian {
@@ -258,6 +363,9 @@ impl ToFixed for Julian {
 }
 
 impl ToFromCommonDate<JulianMonth> for Julian {
+    const EFFECTIVE_YEAR_MIN: i32 = -47012951;
+    const EFFECTIVE_YEAR_MAX: i32 = 47012951;
+
     fn to_common_date(self) -> CommonDate {
         self.0
     }
@@ -298,7 +406,7 @@ impl ToFromCommonDate<JulianMonth> for Julian {
                     28
                 }
             }
-            _ => Gregorian::month_length(year, month),
+            _ => Gregorian::month_length(year, month.into()),
         }
     }
 }
@@ -315,12 +423,75 @@ impl CommonWeekOfYear<JulianMonth> for Julian {}
 /// Represents a date *and time* in the Julian Calendar
 pub type JulianMoment = CalendarMoment<Julian>;
 
+/// Number of days in a month of the Julian calendar
+///
+/// Returns [`None`] if the month is not between 1 and 12. This does not require
+/// constructing a [`Julian`] or [`JulianMonth`], and is intended for hot loops
+/// in parsers and validators.
+///
+/// Leap years are the same as in [`is_julian_leap_year`](crate::raw::is_julian_leap_year).
+///
+/// ```
+/// use radnelac::calendar::*;
+///
+/// assert_eq!(days_in_julian_month(1900, 2), Some(29));
+/// assert_eq!(days_in_julian_month(-1, 2), Some(29));
+/// assert_eq!(days_in_julian_month(1901, 13), None);
+/// ```
+pub const fn days_in_julian_month(year: i32, month: u8) -> Option<u8> {
+    match month {
+        2 => Some(if is_julian_leap_year(year as i64) {
+            29
+        } else {
+            28
+        }),
+        _ => days_in_gregorian_month(year, month),
+    }
+}
+
+/// [`true`] if the year, month and day form a valid date in the Julian calendar
+///
+/// Year 0 is never valid. This does not check whether the date is within the supported
+/// range of time.
+///
+/// ```
+/// use radnelac::calendar::*;
+///
+/// assert!(is_valid_julian_date(1900, 2, 29));
+/// assert!(!is_valid_julian_date(0, 1, 1));
+/// ```
+pub const fn is_valid_julian_date(year: i32, month: u8, day: u8) -> bool {
+    match days_in_julian_month(year, month) {
+        Some(length) => year != 0 && day >= 1 && day <= length,
+        None => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::calendar::gregorian::Gregorian;
     use proptest::proptest;
 
+    #[test]
+    fn month_conversion() {
+        for m in 1..=12 {
+            let j = JulianMonth::from_u8(m).unwrap();
+            let g = GregorianMonth::from_u8(m).unwrap();
+            assert_eq!(GregorianMonth::from(j), g);
+            assert_eq!(JulianMonth::from(g), j);
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn gregorian_month_compatibility() {
+        let j = Julian::try_new_gregorian_month(1752, GregorianMonth::September, 3).unwrap();
+        assert_eq!(j, Julian::try_new(1752, JulianMonth::September, 3).unwrap());
+        assert_eq!(j.gregorian_month(), GregorianMonth::September);
+        assert!(Julian::try_new_gregorian_month(0, GregorianMonth::January, 1).is_err());
+    }
+
     #[test]
     fn julian_gregorian_conversion() {
         let gap_list = [
@@ -376,7 +547,41 @@ mod tests {
         assert!(Julian::try_year_end(0).is_err());
     }
 
+    #[test]
+    fn easter() {
+        // https://en.wikipedia.org/wiki/List_of_dates_for_Easter
+        let list = [
+            (2001, CommonDate::new(2001, 4, 2)),
+            (2010, CommonDate::new(2010, 3, 22)),
+            (2024, CommonDate::new(2024, 4, 22)),
+            (2025, CommonDate::new(2025, 4, 7)),
+        ];
+        for pair in list {
+            let e = Julian::easter(NonZero::new(pair.0).unwrap());
+            assert_eq!(e.to_common_date(), pair.1);
+        }
+    }
+
     proptest! {
+        #[test]
+        fn free_validation(year in -10000..10000i32, month in 0..15u8, day in 0..35u8) {
+            let c = CommonDate::new(year, month, day);
+            assert_eq!(is_valid_julian_date(year, month, day), Julian::valid_ymd(c).is_ok());
+            match JulianMonth::from_u8(month) {
+                Some(m) => assert_eq!(days_in_julian_month(year, month), Some(Julian::month_length(year, m))),
+                None => assert!(days_in_julian_month(year, month).is_none()),
+            }
+        }
+
+        #[test]
+        fn easter_sunday(y in i16::MIN..i16::MAX) {
+            let year = NonZero::new(y as i32).unwrap_or(NonZero::new(1).unwrap());
+            let e = Julian::easter(year);
+            assert_eq!(e.year(), year.get());
+            assert_eq!(e.convert::<Weekday>(), Weekday::Sunday);
+            assert!(e.month() == JulianMonth::March || e.month() == JulianMonth::April);
+        }
+
         #[test]
         fn invalid_year_0(month in 1..12, day in 1..28) {
             let c = CommonDate::new(0, month as u8, day as u8);
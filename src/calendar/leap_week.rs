@@ -0,0 +1,163 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::prelude::HasLeapYears;
+use crate::calendar::OrdinalDate;
+use crate::common::error::CalendarError;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use std::num::NonZero;
+
+const WEEKS_IN_COMMON_YEAR: u8 = 52;
+
+/// Calendars with whole weeks in every year, and an extra week in leap years
+///
+/// Every year of these calendars starts on the same day of the week. Common years have
+/// 52 weeks, and leap years have one additional week.
+///
+/// Implementors only describe where each year starts; the week date conversions are
+/// shared. The position of the leap week defaults to the end of the year.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// let g = Gregorian::try_new(2026, GregorianMonth::December, 31).unwrap();
+/// let i = g.convert::<ISO>();
+/// assert_eq!(ISO::weeks_in_year(2026), 53);
+/// assert_eq!(ISO::leap_week_position(2026).unwrap().get(), 53);
+/// let (year, week, day) = i.week_date();
+/// assert_eq!((year, week.get(), day.get()), (2026, 53, 4));
+/// assert_eq!(ISO::try_from_week_date(year, 53, 4).unwrap(), i);
+/// ```
+pub trait LeapWeekCalendar: HasLeapYears + FromFixed + ToFixed {
+    /// Fixed day number of the first day of a year
+    fn year_start_day(year: i32) -> i64;
+
+    /// Year containing a fixed day number, and the fixed day number of the first day of that year
+    fn year_and_start(date: i64) -> (i32, i64);
+
+    /// Number of weeks in a year
+    fn weeks_in_year(year: i32) -> u8 {
+        if Self::is_leap(year) {
+            WEEKS_IN_COMMON_YEAR + 1
+        } else {
+            WEEKS_IN_COMMON_YEAR
+        }
+    }
+
+    /// Week of the year which is the leap week, or [`None`] in common years
+    fn leap_week_position(year: i32) -> Option<NonZero<u8>> {
+        if Self::is_leap(year) {
+            NonZero::new(WEEKS_IN_COMMON_YEAR + 1)
+        } else {
+            None
+        }
+    }
+
+    /// Year and day of year of a fixed day number
+    fn leap_week_ordinal(fixed_date: Fixed) -> OrdinalDate {
+        let date = fixed_date.get_day_i();
+        let (year, start) = Self::year_and_start(date);
+        OrdinalDate {
+            year,
+            day_of_year: (date - start + 1) as u16,
+        }
+    }
+
+    /// Checks if a day of year is within a year
+    fn valid_leap_week_ordinal(ord: OrdinalDate) -> Result<(), CalendarError> {
+        let len = 7 * (Self::weeks_in_year(ord.year) as u16);
+        if ord.day_of_year > 0 && ord.day_of_year <= len {
+            Ok(())
+        } else {
+            Err(CalendarError::InvalidDayOfYear)
+        }
+    }
+
+    /// Year, week of year and day of week
+    ///
+    /// Days of the week are numbered from 1 to 7, starting on the first day of the year.
+    fn week_date(self) -> (i32, NonZero<u8>, NonZero<u8>) {
+        let ord = Self::leap_week_ordinal(self.to_fixed());
+        let day_index = ord.day_of_year - 1;
+        let week = NonZero::new((day_index / 7) as u8 + 1).expect("Adding 1 to u8");
+        let day = NonZero::new((day_index % 7) as u8 + 1).expect("Adding 1 to u8");
+        (ord.year, week, day)
+    }
+
+    /// Date from a year, week of year and day of week
+    fn try_from_week_date(year: i32, week: u8, day: u8) -> Result<Self, CalendarError> {
+        if week < 1 || week > Self::weeks_in_year(year) {
+            return Err(CalendarError::InvalidWeek);
+        }
+        if !(1..=7).contains(&day) {
+            return Err(CalendarError::InvalidDay);
+        }
+        let date = Self::year_start_day(year) + (7 * (week as i64 - 1)) + (day as i64 - 1);
+        Ok(Self::from_fixed(Fixed::cast_new(date)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Retail454;
+    #[cfg(feature = "reform-calendars")]
+    use crate::calendar::Symmetry010;
+    #[cfg(feature = "reform-calendars")]
+    use crate::calendar::Symmetry454Solstice;
+    use crate::calendar::ISO;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use proptest::proptest;
+
+    fn roundtrip<T: LeapWeekCalendar + PartialEq + std::fmt::Debug>(t: f64) {
+        let d = T::from_fixed(Fixed::new(t));
+        let (year, week, day) = d.week_date();
+        assert!(week.get() <= T::weeks_in_year(year));
+        assert_eq!(
+            T::try_from_week_date(year, week.get(), day.get()).unwrap(),
+            d
+        );
+    }
+
+    #[test]
+    fn invalid_week_date() {
+        assert!(matches!(
+            ISO::try_from_week_date(2025, 53, 1),
+            Err(CalendarError::InvalidWeek)
+        ));
+        assert!(matches!(
+            ISO::try_from_week_date(2025, 0, 1),
+            Err(CalendarError::InvalidWeek)
+        ));
+        assert!(matches!(
+            ISO::try_from_week_date(2025, 1, 8),
+            Err(CalendarError::InvalidDay)
+        ));
+        assert!(ISO::leap_week_position(2025).is_none());
+    }
+
+    proptest! {
+        #[test]
+        fn week_date_roundtrip(t in FIXED_MIN..FIXED_MAX) {
+            roundtrip::<ISO>(t);
+            roundtrip::<Retail454>(t);
+            #[cfg(feature = "reform-calendars")]
+            roundtrip::<Symmetry010>(t);
+            #[cfg(feature = "reform-calendars")]
+            roundtrip::<Symmetry454Solstice>(t);
+        }
+
+        #[test]
+        fn same_as_iso(t in FIXED_MIN..FIXED_MAX) {
+            let i = ISO::from_fixed(Fixed::new(t));
+            let (year, week, day) = i.week_date();
+            assert_eq!((year, week, day.get()), (i.year(), i.week(), i.day_num()));
+        }
+    }
+}
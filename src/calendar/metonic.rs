@@ -0,0 +1,67 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// Arithmetic for lunisolar calendars based on a 19 year cycle of 235 months
+//
+// Months start at evenly spaced intervals of 6940/235 days, rounded down.
+//
+// A year `y` is a leap year if `(7 * y + leap_offset) mod 19 < 7`. This places
+// the leap years of each cycle as evenly as possible.
+
+use crate::common::math::TermNum;
+
+pub(crate) const CYCLE_YEARS: i64 = 19;
+pub(crate) const CYCLE_MONTHS: i64 = 235;
+pub(crate) const CYCLE_DAYS: i64 = 6940;
+
+pub(crate) fn is_leap(year: i32, leap_offset: i64) -> bool {
+    ((7 * (year as i64)) + leap_offset).modulus(CYCLE_YEARS) < 7
+}
+
+/// Number of months elapsed between the epoch and the start of a year
+pub(crate) fn months_before_year(year: i32, leap_offset: i64) -> i64 {
+    ((CYCLE_MONTHS * ((year as i64) - 1)) + leap_offset).div_euclid(CYCLE_YEARS)
+}
+
+/// Number of days elapsed between the epoch and the start of a month
+pub(crate) fn days_before_month(months: i64) -> i64 {
+    (CYCLE_DAYS * months).div_euclid(CYCLE_MONTHS)
+}
+
+pub(crate) fn days_before_year(year: i32, leap_offset: i64) -> i64 {
+    days_before_month(months_before_year(year, leap_offset))
+}
+
+/// Number of months elapsed between the epoch and the start of the month containing a day
+pub(crate) fn months_before_day(days: i64) -> i64 {
+    ((CYCLE_MONTHS * (days + 1)) - 1).div_euclid(CYCLE_DAYS)
+}
+
+/// Year containing a month
+pub(crate) fn year_of_month(months: i64, leap_offset: i64) -> i32 {
+    ((CYCLE_YEARS * months) + (CYCLE_YEARS - 1) - leap_offset).div_euclid(CYCLE_MONTHS) as i32 + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::proptest;
+
+    proptest! {
+        #[test]
+        fn consistent(year in -1000000..1000000, leap_offset in 0..19i64) {
+            let m0 = months_before_year(year, leap_offset);
+            let m1 = months_before_year(year + 1, leap_offset);
+            let expected = if is_leap(year, leap_offset) { 13 } else { 12 };
+            assert_eq!(m1 - m0, expected);
+            assert_eq!(year_of_month(m0, leap_offset), year);
+            assert_eq!(year_of_month(m1 - 1, leap_offset), year);
+            let d0 = days_before_month(m0);
+            let d1 = days_before_month(m0 + 1);
+            assert!(d1 - d0 == 29 || d1 - d0 == 30);
+            assert_eq!(months_before_day(d0), m0);
+            assert_eq!(months_before_day(d1 - 1), m0);
+        }
+    }
+}
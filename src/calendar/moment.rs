@@ -12,9 +12,13 @@ use crate::calendar::Perennial;
 use crate::calendar::Quarter;
 use crate::calendar::ToFromCommonDate;
 use crate::calendar::ToFromOrdinalDate;
+use crate::calendar::YearNumbering;
+use crate::calendar::YearZeroPolicy;
 use crate::clock::ClockTime;
 use crate::clock::TimeOfDay;
+use crate::common::units::seconds_to_day_fraction;
 use crate::day_count::BoundedDayCount;
+use crate::day_count::DayRounding;
 use crate::day_count::EffectiveBound;
 use crate::day_count::Epoch;
 use crate::day_count::Fixed;
@@ -27,6 +31,29 @@ use num_traits::ToPrimitive;
 use std::num::NonZero;
 
 /// Represents an instant in time using calendar system T
+///
+/// A `CalendarMoment` is a date combined with a time of day. It can be converted into other
+/// timekeeping systems using [`convert`](ToFixed::convert), like any other date.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::clock::*;
+/// use radnelac::day_count::*;
+///
+/// let d = Gregorian::try_new(2025, GregorianMonth::March, 1).unwrap();
+/// let m = GregorianMoment::new(d, TimeOfDay::noon());
+/// assert_eq!(m.date(), d);
+/// assert_eq!(m.time_of_day(), ClockTime::try_new(12, 0, 0.0).unwrap());
+///
+/// let j = m.convert::<JulianMoment>();
+/// assert_eq!(j.date(), d.convert::<Julian>());
+/// assert_eq!(j.time_of_day(), m.time_of_day());
+///
+/// let evening = ClockTime::try_new(18, 30, 0.0).unwrap();
+/// let m2 = m.try_with_clock(evening).unwrap();
+/// assert_eq!(m2.time_of_day(), evening);
+/// assert_eq!(m2.with_time(TimeOfDay::noon()), m);
+/// ```
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
 pub struct CalendarMoment<T> {
     date: T,
@@ -42,6 +69,14 @@ impl<T> CalendarMoment<T> {
         }
     }
 
+    /// Attempt to create a CalendarMoment with the given date and clock time
+    ///
+    /// Returns an error if the clock time is invalid.
+    pub fn try_from_clock(date: T, clock: ClockTime) -> Result<Self, CalendarError> {
+        clock.validate()?;
+        Ok(Self { date, time: clock })
+    }
+
     /// Date of the given CalendarMoment
     pub fn date(self) -> T {
         self.date
@@ -51,11 +86,79 @@ impl<T> CalendarMoment<T> {
     pub fn time_of_day(self) -> ClockTime {
         self.time
     }
+
+    /// Replace the date, keeping the time of day
+    pub fn with_date(self, date: T) -> Self {
+        Self {
+            date,
+            time: self.time,
+        }
+    }
+
+    /// Replace the time of day, keeping the date
+    pub fn with_time(self, t: TimeOfDay) -> Self {
+        Self {
+            date: self.date,
+            time: t.to_clock(),
+        }
+    }
+
+    /// Attempt to replace the time of day, keeping the date
+    ///
+    /// Returns an error if the clock time is invalid.
+    pub fn try_with_clock(self, clock: ClockTime) -> Result<Self, CalendarError> {
+        Self::try_from_clock(self.date, clock)
+    }
+}
+
+impl<T: FromFixed + ToFixed> CalendarMoment<T> {
+    fn try_shift(self, days: f64) -> Result<Self, CalendarError> {
+        let t = self.to_fixed().get() + days;
+        Fixed::in_effective_bounds(t)?;
+        Ok(Self::from_fixed(Fixed::new(t)))
+    }
+
+    /// Attempt to move the CalendarMoment forward by a number of days
+    ///
+    /// The time of day is not changed. Negative values move the CalendarMoment backwards.
+    pub fn try_add_days(self, days: i64) -> Result<Self, CalendarError> {
+        //Shifting the date alone avoids rounding errors in the time of day
+        let t = self.date.to_fixed().to_day().get() + (days as f64);
+        Fixed::in_effective_bounds(t)?;
+        Ok(Self {
+            date: T::from_fixed(Fixed::new(t)),
+            time: self.time,
+        })
+    }
+
+    /// Attempt to move the CalendarMoment forward by a number of seconds
+    ///
+    /// The date changes if the result crosses midnight. Negative values move the
+    /// CalendarMoment backwards.
+    pub fn try_add_seconds(self, seconds: f64) -> Result<Self, CalendarError> {
+        self.try_shift(seconds_to_day_fraction(seconds))
+    }
+
+    /// Attempt to move the CalendarMoment forward by a span of hours, minutes and seconds
+    ///
+    /// The date changes if the result crosses midnight.
+    pub fn try_add_clock_time(self, span: ClockTime) -> Result<Self, CalendarError> {
+        self.try_shift(TimeOfDay::try_from_clock(span)?.get())
+    }
+
+    /// Attempt to move the CalendarMoment backward by a span of hours, minutes and seconds
+    ///
+    /// The date changes if the result crosses midnight.
+    pub fn try_sub_clock_time(self, span: ClockTime) -> Result<Self, CalendarError> {
+        self.try_shift(-TimeOfDay::try_from_clock(span)?.get())
+    }
 }
 
 impl<T: FromFixed> FromFixed for CalendarMoment<T> {
     fn from_fixed(fixed_date: Fixed) -> Self {
-        Self::new(T::from_fixed(fixed_date), TimeOfDay::from_fixed(fixed_date))
+        //Times which round up to 00:00:00 belong to the following day
+        let day = fixed_date.to_day_rounded(DayRounding::Snap);
+        Self::new(T::from_fixed(day), TimeOfDay::from_fixed(fixed_date))
     }
 }
 
@@ -63,7 +166,7 @@ impl<T: ToFixed> ToFixed for CalendarMoment<T> {
     fn to_fixed(self) -> Fixed {
         let fd = self.date.to_fixed();
         let ft = TimeOfDay::try_from_clock(self.time).expect("Guaranteed valid");
-        Fixed::new(fd.to_day().get() + ft.get())
+        Fixed::compose(fd.get_day_i(), ft.get())
     }
 }
 
@@ -93,6 +196,9 @@ where
     T: FromPrimitive,
     U: ToFromCommonDate<T> + EffectiveBound,
 {
+    const EFFECTIVE_YEAR_MIN: i32 = U::EFFECTIVE_YEAR_MIN;
+    const EFFECTIVE_YEAR_MAX: i32 = U::EFFECTIVE_YEAR_MAX;
+
     fn to_common_date(self) -> CommonDate {
         self.date.to_common_date()
     }
@@ -117,6 +223,14 @@ where
         U::month_length(year, month)
     }
 
+    fn min_date() -> Self {
+        Self::new(U::min_date(), TimeOfDay::midnight())
+    }
+
+    fn max_date() -> Self {
+        Self::new(U::max_date(), TimeOfDay::midnight())
+    }
+
     fn in_effective_bounds(d: CommonDate) -> bool {
         U::in_effective_bounds(d)
     }
@@ -201,6 +315,10 @@ where
     }
 }
 
+impl<T: YearNumbering> YearNumbering for CalendarMoment<T> {
+    const YEAR_ZERO: YearZeroPolicy = T::YEAR_ZERO;
+}
+
 impl<T: Quarter> Quarter for CalendarMoment<T> {
     fn quarter(self) -> NonZero<u8> {
         self.date.quarter()
@@ -216,6 +334,10 @@ where
         self.date.week_of_year()
     }
 
+    fn weekday_of_new_year(year: i32) -> Result<Weekday, CalendarError> {
+        U::weekday_of_new_year(year)
+    }
+
     fn nth_kday(self, nz: NonZero<i16>, k: Weekday) -> Fixed {
         self.date.nth_kday(nz, k)
     }
@@ -238,3 +360,97 @@ impl<T: ToFromOrdinalDate> ToFromOrdinalDate for CalendarMoment<T> {
         Self::new(T::from_ordinal_unchecked(ord), TimeOfDay::midnight())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Gregorian;
+    use crate::calendar::GregorianMonth;
+    use crate::day_count::FIXED_MAX;
+    use proptest::proptest;
+
+    fn moment(day: u8, hours: u8, minutes: u8) -> CalendarMoment<Gregorian> {
+        let d = Gregorian::try_new(2025, GregorianMonth::December, day).unwrap();
+        let c = ClockTime {
+            hours,
+            minutes,
+            seconds: 0.0,
+        };
+        CalendarMoment::new(d, TimeOfDay::try_from_clock(c).unwrap())
+    }
+
+    fn assert_close(a: CalendarMoment<Gregorian>, b: CalendarMoment<Gregorian>) {
+        assert!((a.to_fixed().get() - b.to_fixed().get()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn across_midnight() {
+        let span = ClockTime {
+            hours: 2,
+            minutes: 30,
+            seconds: 0.0,
+        };
+        let m = moment(31, 23, 0).try_add_clock_time(span).unwrap();
+        assert_eq!(
+            m.date(),
+            Gregorian::try_new(2026, GregorianMonth::January, 1).unwrap()
+        );
+        assert_close(m, moment(31, 1, 30).try_add_days(1).unwrap());
+        assert_close(m.try_sub_clock_time(span).unwrap(), moment(31, 23, 0));
+        assert_close(
+            moment(2, 0, 30).try_add_seconds(-3600.0).unwrap(),
+            moment(1, 23, 30),
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let span = ClockTime {
+            hours: 25,
+            minutes: 0,
+            seconds: 0.0,
+        };
+        assert!(moment(1, 0, 0).try_add_clock_time(span).is_err());
+        assert!(moment(1, 0, 0).try_add_days(i64::MAX / 2).is_err());
+        assert!(moment(1, 0, 0).try_add_seconds(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn near_midnight() {
+        let midnight = moment(2, 0, 0).to_fixed().get();
+        for eps in [1e-12, 1e-9, 1e-7] {
+            let before = CalendarMoment::<Gregorian>::from_fixed(Fixed::new(midnight - eps));
+            assert_eq!(before.date(), moment(2, 0, 0).date());
+            assert_eq!(before.time_of_day(), ClockTime::new_unchecked(0, 0, 0.0));
+            assert!((before.to_fixed().get() - midnight).abs() < 1e-6);
+            let after = CalendarMoment::<Gregorian>::from_fixed(Fixed::new(midnight + eps));
+            assert_eq!(after.date(), moment(2, 0, 0).date());
+        }
+        let second = 1.0 / (24.0 * 60.0 * 60.0);
+        let before = CalendarMoment::<Gregorian>::from_fixed(Fixed::new(midnight - second));
+        assert_eq!(before.date(), moment(1, 0, 0).date());
+        assert_eq!(before.time_of_day().hours, 23);
+    }
+
+    proptest! {
+        #[test]
+        fn snap(t in -FIXED_MAX/2.0..FIXED_MAX/2.0) {
+            let m = CalendarMoment::<Gregorian>::from_fixed(Fixed::new(t));
+            let f = m.to_fixed();
+            assert_eq!(f.get_day_i(), Fixed::new(t).to_day_rounded(DayRounding::Snap).get_day_i());
+            assert!((f.get() - t).abs() < 1e-4);
+        }
+
+        #[test]
+        fn days(t in -FIXED_MAX/2.0..FIXED_MAX/2.0, n in -100000i64..100000) {
+            let m = CalendarMoment::<Gregorian>::from_fixed(Fixed::new(t));
+            let m1 = m.try_add_days(n).unwrap();
+            assert_eq!(m1.time_of_day(), m.time_of_day());
+            assert_eq!(
+                m1.date().to_fixed().get_day_i() - m.date().to_fixed().get_day_i(),
+                n
+            );
+            assert_eq!(m1.try_add_days(-n).unwrap().date(), m.date());
+        }
+    }
+}
@@ -0,0 +1,125 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::prelude::HasLeapYears;
+
+/// Calculate the number of days before each month, given the length of each month
+pub(crate) const fn cumulative_lengths<const N: usize>(lengths: [u8; N]) -> [u16; N] {
+    let mut result = [0; N];
+    let mut i = 1;
+    while i < N {
+        result[i] = result[i - 1] + (lengths[i - 1] as u16);
+        i += 1;
+    }
+    result
+}
+
+/// Calendars where the length of each month depends only on whether the year is a leap year
+///
+/// The tables are indexed by month number minus one. They allow quick calculations,
+/// such as validating a day of month or finding the day of the year, without constructing
+/// a date.
+///
+/// Only calendars where every day of the year is within a month implement this trait, so
+/// the sum of the month lengths is the length of the year. A month which only exists in
+/// leap years has a length of 0 in common years.
+///
+/// ```
+/// use radnelac::calendar::*;
+///
+/// assert_eq!(Gregorian::month_lengths(2024)[1], 29);
+/// assert_eq!(Gregorian::month_lengths(2025)[1], 28);
+/// assert_eq!(Gregorian::COMMON_DAYS_BEFORE_MONTH[2], 59);
+/// assert_eq!(Gregorian::cumulative_days_before_month(2024, 3), Some(60));
+/// assert_eq!(Gregorian::cumulative_days_before_month(2024, 13), None);
+/// ```
+pub trait MonthTable: HasLeapYears {
+    /// Length of each month in a common year
+    const COMMON_MONTH_LENGTHS: &'static [u8];
+    /// Length of each month in a leap year
+    const LEAP_MONTH_LENGTHS: &'static [u8];
+    /// Number of days before the start of each month in a common year
+    const COMMON_DAYS_BEFORE_MONTH: &'static [u16];
+    /// Number of days before the start of each month in a leap year
+    const LEAP_DAYS_BEFORE_MONTH: &'static [u16];
+
+    /// Length of each month in a specific year
+    fn month_lengths(year: i32) -> &'static [u8] {
+        if Self::is_leap(year) {
+            Self::LEAP_MONTH_LENGTHS
+        } else {
+            Self::COMMON_MONTH_LENGTHS
+        }
+    }
+
+    /// Number of days in a specific year before the start of a month
+    ///
+    /// Returns [`None`] if the month number is invalid.
+    fn cumulative_days_before_month(year: i32, month: u8) -> Option<u16> {
+        let table = if Self::is_leap(year) {
+            Self::LEAP_DAYS_BEFORE_MONTH
+        } else {
+            Self::COMMON_DAYS_BEFORE_MONTH
+        };
+        table.get((month as usize).checked_sub(1)?).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::prelude::CommonDate;
+    use crate::calendar::prelude::ToFromCommonDate;
+    use crate::calendar::prelude::ToFromOrdinalDate;
+    use crate::calendar::*;
+    use num_traits::FromPrimitive;
+
+    fn check<S: FromPrimitive, T: MonthTable + ToFromCommonDate<S> + ToFromOrdinalDate>() {
+        for year in 1..800 {
+            let lengths = T::month_lengths(year);
+            let mut last = 0;
+            for (i, len) in lengths.iter().enumerate() {
+                let month = (i + 1) as u8;
+                let m = S::from_u8(month).unwrap();
+                assert_eq!(T::month_length(year, m), *len, "{}-{}", year, month);
+                if *len == 0 {
+                    continue;
+                }
+                let before = T::cumulative_days_before_month(year, month).unwrap();
+                let d = T::try_from_common_date(CommonDate::new(year, month, 1)).unwrap();
+                assert_eq!(d.to_ordinal().day_of_year, before + 1, "{}-{}", year, month);
+                let d = T::try_from_common_date(CommonDate::new(year, month, *len)).unwrap();
+                last = d.to_ordinal().day_of_year;
+            }
+            let total: u16 = lengths.iter().map(|x| *x as u16).sum();
+            assert_eq!(last, total, "{}", year);
+            assert!(T::cumulative_days_before_month(year, 0).is_none());
+            let after = (lengths.len() + 1) as u8;
+            assert!(T::cumulative_days_before_month(year, after).is_none());
+        }
+    }
+
+    #[test]
+    fn tables() {
+        check::<GregorianMonth, Gregorian>();
+        check::<BengaliMonth, Bengali>();
+        check::<IslamicMonth, IslamicArith>();
+        check::<JulianMonth, Julian>();
+        check::<PersianMonth, PersianArith>();
+        #[cfg(feature = "ancient-calendars")]
+        check::<CopticMonth, Coptic>();
+        #[cfg(feature = "ancient-calendars")]
+        check::<EthiopicMonth, Ethiopic>();
+        #[cfg(feature = "reform-calendars")]
+        check::<CotsworthMonth, Cotsworth>();
+        #[cfg(feature = "reform-calendars")]
+        check::<HoloceneMonth, Holocene>();
+        #[cfg(feature = "reform-calendars")]
+        check::<PataphysicalMonth, Pataphysical>();
+        #[cfg(feature = "reform-calendars")]
+        check::<SymmetryMonth, Symmetry454>();
+        #[cfg(feature = "reform-calendars")]
+        check::<SymmetryMonth, Symmetry010Solstice>();
+    }
+}
@@ -0,0 +1,375 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::gregorian::Gregorian;
+use crate::calendar::prelude::CommonDate;
+use crate::calendar::prelude::CommonWeekOfYear;
+use crate::calendar::prelude::GuaranteedMonth;
+use crate::calendar::prelude::HasLeapYears;
+use crate::calendar::prelude::Quarter;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::AllowYearZero;
+use crate::calendar::CalendarMoment;
+use crate::calendar::OrdinalDate;
+use crate::calendar::ToFromOrdinalDate;
+use crate::common::error::CalendarError;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::CalculatedBounds;
+use crate::day_count::Epoch;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use num_traits::FromPrimitive;
+use num_traits::ToPrimitive;
+use std::num::NonZero;
+
+/// Represents a date in a calendar which is locked to another calendar
+///
+/// ## Introduction
+///
+/// Many calendars share all their rules for months, days and leap years with another
+/// calendar, and differ only in how the years are numbered or in when the calendar
+/// starts. For example, the Thai solar calendar is the proleptic Gregorian calendar with
+/// 543 years added to each year.
+///
+/// `OffsetCalendar` implements such calendars generically. The `Base` calendar provides
+/// the structure of the year, `YEARS` is added to the year number of the base calendar,
+/// and `DAYS` is added to the fixed day of the base calendar.
+///
+/// In other words, the date (Y, M, D) in an `OffsetCalendar` is `DAYS` days after the date
+/// (Y - `YEARS`, M, D) in the `Base` calendar.
+///
+/// ## Relationship to Other Calendars
+///
+/// Some of the calendars in this crate are locked to another calendar in this way.
+/// + [`Holocene`](crate::calendar::Holocene) is `OffsetCalendar<Gregorian, 10000, 0>`
+/// + [`Ethiopic`](crate::calendar::Ethiopic) is `OffsetCalendar<Coptic, 0, -100809>`
+///
+/// These calendars are implemented separately, because they have their own documentation,
+/// month names and formatting.
+///
+/// ## Representation and Examples
+///
+/// The month type of an `OffsetCalendar` is the same as the month type of the `Base`.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// let g = Gregorian::try_new(2025, GregorianMonth::May, 15).unwrap();
+/// let t = g.convert::<ThaiSolar>();
+/// assert_eq!(t, ThaiSolar::try_new(2568, GregorianMonth::May, 15).unwrap());
+/// let m = g.convert::<Minguo>();
+/// assert_eq!(m, Minguo::try_new(114, GregorianMonth::May, 15).unwrap());
+/// ```
+///
+/// New calendars can be defined with a type alias.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// type HumanEra = OffsetCalendar<Gregorian, 10000, 0>;
+/// let g = Gregorian::try_new(2016, GregorianMonth::January, 1).unwrap();
+/// assert_eq!(g.convert::<HumanEra>().year(), 12016);
+/// assert_eq!(g.convert::<HumanEra>().to_fixed(), g.to_fixed());
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct OffsetCalendar<Base, const YEARS: i32, const DAYS: i64>(Base);
+
+impl<B, const Y: i32, const D: i64> OffsetCalendar<B, Y, D> {
+    /// Returns the date in the base calendar with the same month and day
+    ///
+    /// The year of the returned date is `YEARS` less than the year of `self`. This is the
+    /// same day as `self` only if `DAYS` is 0: otherwise, `self` is `DAYS` days after the
+    /// returned date. Use [`convert`](crate::day_count::ToFixed::convert) to get the same
+    /// day in the base calendar.
+    pub fn base(self) -> B {
+        self.0
+    }
+
+    fn to_base_date(d: CommonDate) -> CommonDate {
+        CommonDate::new(d.year - Y, d.month, d.day)
+    }
+
+    fn from_base_date(d: CommonDate) -> CommonDate {
+        CommonDate::new(d.year + Y, d.month, d.day)
+    }
+}
+
+impl<B: AllowYearZero, const Y: i32, const D: i64> AllowYearZero for OffsetCalendar<B, Y, D> {}
+
+impl<B, const Y: i32, const D: i64> ToFromOrdinalDate for OffsetCalendar<B, Y, D>
+where
+    B: ToFromOrdinalDate,
+{
+    fn valid_ordinal(ord: OrdinalDate) -> Result<(), CalendarError> {
+        B::valid_ordinal(OrdinalDate {
+            year: ord.year - Y,
+            day_of_year: ord.day_of_year,
+        })
+    }
+
+    fn ordinal_from_fixed(fixed_date: Fixed) -> OrdinalDate {
+        let ord = B::ordinal_from_fixed(Fixed::new(fixed_date.get() - (D as f64)));
+        OrdinalDate {
+            year: ord.year + Y,
+            day_of_year: ord.day_of_year,
+        }
+    }
+
+    fn to_ordinal(self) -> OrdinalDate {
+        let ord = self.0.to_ordinal();
+        OrdinalDate {
+            year: ord.year + Y,
+            day_of_year: ord.day_of_year,
+        }
+    }
+
+    fn from_ordinal_unchecked(ord: OrdinalDate) -> Self {
+        Self(B::from_ordinal_unchecked(OrdinalDate {
+            year: ord.year - Y,
+            day_of_year: ord.day_of_year,
+        }))
+    }
+}
+
+impl<B: HasLeapYears, const Y: i32, const D: i64> HasLeapYears for OffsetCalendar<B, Y, D> {
+    fn is_leap(year: i32) -> bool {
+        B::is_leap(year - Y)
+    }
+}
+
+impl<B: CalculatedBounds, const Y: i32, const D: i64> CalculatedBounds for OffsetCalendar<B, Y, D> {}
+
+impl<B, const Y: i32, const D: i64> Epoch for OffsetCalendar<B, Y, D>
+where
+    B: ToFromOrdinalDate + FromFixed + ToFixed,
+{
+    fn epoch() -> Fixed {
+        let ord = OrdinalDate {
+            year: 1,
+            day_of_year: 1,
+        };
+        Self::from_ordinal_unchecked(ord).to_fixed()
+    }
+}
+
+impl<B: FromFixed, const Y: i32, const D: i64> FromFixed for OffsetCalendar<B, Y, D> {
+    fn from_fixed(date: Fixed) -> Self {
+        Self(B::from_fixed(Fixed::new(date.get() - (D as f64))))
+    }
+}
+
+impl<B: ToFixed, const Y: i32, const D: i64> ToFixed for OffsetCalendar<B, Y, D> {
+    fn to_fixed(self) -> Fixed {
+        Fixed::new(self.0.to_fixed().get() + (D as f64))
+    }
+}
+
+impl<S, B, const Y: i32, const D: i64> ToFromCommonDate<S> for OffsetCalendar<B, Y, D>
+where
+    S: FromPrimitive,
+    B: ToFromCommonDate<S> + CalculatedBounds,
+{
+    const EFFECTIVE_YEAR_MIN: i32 = B::EFFECTIVE_YEAR_MIN + Y;
+    const EFFECTIVE_YEAR_MAX: i32 = B::EFFECTIVE_YEAR_MAX + Y;
+
+    fn to_common_date(self) -> CommonDate {
+        Self::from_base_date(self.0.to_common_date())
+    }
+
+    fn from_common_date_unchecked(date: CommonDate) -> Self {
+        debug_assert!(Self::valid_ymd(date).is_ok());
+        Self(B::from_common_date_unchecked(Self::to_base_date(date)))
+    }
+
+    fn valid_ymd(date: CommonDate) -> Result<(), CalendarError> {
+        B::valid_ymd(Self::to_base_date(date))
+    }
+
+    fn year_start_date(year: i32) -> CommonDate {
+        Self::from_base_date(B::year_start_date(year - Y))
+    }
+
+    fn year_end_date(year: i32) -> CommonDate {
+        Self::from_base_date(B::year_end_date(year - Y))
+    }
+
+    fn month_length(year: i32, month: S) -> u8 {
+        B::month_length(year - Y, month)
+    }
+}
+
+impl<B: Quarter, const Y: i32, const D: i64> Quarter for OffsetCalendar<B, Y, D> {
+    fn quarter(self) -> NonZero<u8> {
+        self.0.quarter()
+    }
+}
+
+impl<S, B, const Y: i32, const D: i64> GuaranteedMonth<S> for OffsetCalendar<B, Y, D>
+where
+    S: FromPrimitive + ToPrimitive,
+    B: GuaranteedMonth<S> + CalculatedBounds,
+{
+}
+
+impl<S, B, const Y: i32, const D: i64> CommonWeekOfYear<S> for OffsetCalendar<B, Y, D>
+where
+    S: FromPrimitive,
+    B: CommonWeekOfYear<S> + CalculatedBounds,
+{
+}
+
+/// Represents a date in the Thai solar calendar
+///
+/// The Thai solar calendar is the proleptic Gregorian calendar with years numbered in the
+/// Buddhist Era. 2025 Common Era is 2568 Buddhist Era.
+///
+/// This crate uses the modern rule, in which the year starts on January 1. Before
+/// 1941 Common Era, the year started on April 1 in Thailand.
+///
+/// ## Further reading
+/// + [Wikipedia](https://en.wikipedia.org/wiki/Thai_solar_calendar)
+pub type ThaiSolar = OffsetCalendar<Gregorian, 543, 0>;
+
+/// Represents a date *and time* in the Thai solar calendar
+pub type ThaiSolarMoment = CalendarMoment<ThaiSolar>;
+
+/// Represents a date in the Minguo calendar
+///
+/// The Minguo calendar is the proleptic Gregorian calendar with years numbered from the
+/// founding of the Republic of China. 1912 Common Era is year 1 in the Minguo calendar.
+///
+/// Like the Gregorian calendar in this crate, the Minguo calendar here has a year 0, which
+/// corresponds to 1911 Common Era.
+///
+/// ## Further reading
+/// + [Wikipedia](https://en.wikipedia.org/wiki/Republic_of_China_calendar)
+pub type Minguo = OffsetCalendar<Gregorian, -1911, 0>;
+
+/// Represents a date *and time* in the Minguo calendar
+pub type MinguoMoment = CalendarMoment<Minguo>;
+
+/// Represents a date in the Gregorian calendar with Erisian year numbering
+///
+/// The Erisian years are numbered in the Year of Our Lady of Discord (YOLD), which is used
+/// by the Discordian calendar. 2025 Common Era is 3191 YOLD.
+///
+/// Only the year numbering is Erisian: the months and days are the same as the proleptic
+/// Gregorian calendar. The 5 seasons of the Discordian calendar are not implemented.
+///
+/// ## Further reading
+/// + [Wikipedia](https://en.wikipedia.org/wiki/Discordian_calendar)
+#[cfg(feature = "reform-calendars")]
+pub type ErisianYear = OffsetCalendar<Gregorian, 1166, 0>;
+
+/// Represents a date *and time* in the Gregorian calendar with Erisian year numbering
+#[cfg(feature = "reform-calendars")]
+pub type ErisianYearMoment = CalendarMoment<ErisianYear>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "ancient-calendars")]
+    use crate::calendar::Coptic;
+    #[cfg(feature = "ancient-calendars")]
+    use crate::calendar::Ethiopic;
+    use crate::calendar::GregorianMonth;
+    #[cfg(feature = "reform-calendars")]
+    use crate::calendar::Holocene;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use proptest::proptest;
+
+    #[cfg(feature = "reform-calendars")]
+    type HumanEra = OffsetCalendar<Gregorian, 10000, 0>;
+    #[cfg(feature = "ancient-calendars")]
+    type CopticEthiopic = OffsetCalendar<Coptic, 0, -100809>;
+
+    #[test]
+    #[cfg(feature = "ancient-calendars")]
+    fn ethiopic_days() {
+        let d = Ethiopic::epoch().get_day_i() - Coptic::epoch().get_day_i();
+        assert_eq!(d, -100809);
+        assert_eq!(CopticEthiopic::epoch(), Ethiopic::epoch());
+    }
+
+    #[test]
+    fn epoch() {
+        #[cfg(feature = "reform-calendars")]
+        assert_eq!(HumanEra::epoch(), Holocene::epoch());
+        let t = Gregorian::from_fixed(ThaiSolar::epoch());
+        assert_eq!(t.to_common_date(), CommonDate::new(-542, 1, 1));
+        let m = Gregorian::from_fixed(Minguo::epoch());
+        assert_eq!(m.to_common_date(), CommonDate::new(1912, 1, 1));
+        #[cfg(feature = "reform-calendars")]
+        {
+            let e = Gregorian::from_fixed(ErisianYear::epoch());
+            assert_eq!(e.to_common_date(), CommonDate::new(-1165, 1, 1));
+        }
+    }
+
+    #[test]
+    fn minguo_leap() {
+        //Minguo 1 is 1912 CE
+        assert!(Minguo::is_leap(1));
+        assert!(!Minguo::is_leap(2));
+        assert!(Minguo::is_leap(89)); //2000 CE
+    }
+
+    #[test]
+    fn year_bounds() {
+        let start = ThaiSolar::try_year_start(2568).unwrap();
+        let end = ThaiSolar::try_year_end(2568).unwrap();
+        assert_eq!(
+            start.convert::<Gregorian>().to_common_date(),
+            CommonDate::new(2025, 1, 1)
+        );
+        assert_eq!(
+            end.convert::<Gregorian>().to_common_date(),
+            CommonDate::new(2025, 12, 31)
+        );
+        assert_eq!(end.month(), GregorianMonth::December);
+    }
+
+    proptest! {
+        #[test]
+        #[cfg(feature = "reform-calendars")]
+        fn holocene(t in FIXED_MIN..FIXED_MAX) {
+            let f = Fixed::new(t).to_day();
+            let h0 = Holocene::from_fixed(f);
+            let h1 = HumanEra::from_fixed(f);
+            assert_eq!(h0.to_common_date(), h1.to_common_date());
+            assert_eq!(h1.to_fixed(), f);
+            assert_eq!(HumanEra::is_leap(h1.year()), Holocene::is_leap(h0.year()));
+        }
+
+        #[test]
+        #[cfg(feature = "ancient-calendars")]
+        fn ethiopic(t in FIXED_MIN..FIXED_MAX) {
+            let f = Fixed::new(t).to_day();
+            let e0 = Ethiopic::from_fixed(f);
+            let e1 = CopticEthiopic::from_fixed(f);
+            assert_eq!(e0.to_common_date(), e1.to_common_date());
+            assert_eq!(e1.to_fixed(), f);
+            let c = e1.base();
+            assert_eq!(c.to_common_date(), e1.to_common_date());
+            assert_eq!(e1.to_fixed().get_day_i() - c.to_fixed().get_day_i(), -100809);
+            assert_eq!(e1.convert::<Coptic>(), Coptic::from_fixed(f));
+        }
+
+        #[test]
+        fn ordinal(t in FIXED_MIN..FIXED_MAX) {
+            let f = Fixed::new(t).to_day();
+            let m = Minguo::from_fixed(f);
+            let g = Gregorian::from_fixed(f);
+            let ord = m.to_ordinal();
+            assert_eq!(ord, Minguo::ordinal_from_fixed(f));
+            assert_eq!(ord.year, g.year() - 1911);
+            assert_eq!(ord.day_of_year, g.to_ordinal().day_of_year);
+            assert_eq!(Minguo::try_from_ordinal(ord).unwrap(), m);
+        }
+    }
+}
@@ -0,0 +1,354 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::gregorian::Gregorian;
+use crate::calendar::month_table::cumulative_lengths;
+use crate::calendar::month_table::MonthTable;
+use crate::calendar::prelude::CommonDate;
+use crate::calendar::prelude::HasLeapYears;
+use crate::calendar::prelude::Perennial;
+use crate::calendar::prelude::Quarter;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::prelude::ToFromOrdinalDate;
+use crate::calendar::AllowYearZero;
+use crate::calendar::CalendarMoment;
+use crate::calendar::GuaranteedMonth;
+use crate::calendar::OrdinalDate;
+use crate::common::error::CalendarError;
+use crate::common::math::TermNum;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::CalculatedBounds;
+use crate::day_count::Epoch;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use crate::day_cycle::Weekday;
+use std::num::NonZero;
+
+#[allow(unused_imports)] //FromPrimitive is needed for derive
+use num_traits::FromPrimitive;
+use std::fmt;
+
+const PATAPHYSICAL_YEAR_OFFSET: i32 = 1873 - 1;
+const PATAPHYSICAL_EPOCH_GREGORIAN: CommonDate = CommonDate {
+    year: 1873,
+    month: 9,
+    day: 8,
+};
+const HUNYADI: u8 = 29;
+
+/// Represents a month of the Pataphysical Calendar
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, FromPrimitive, ToPrimitive)]
+pub enum PataphysicalMonth {
+    Absolu = 1,
+    Haha,
+    As,
+    Sable,
+    Decervelage,
+    Gueules,
+    Pedale,
+    Clinamen,
+    Palotin,
+    Merdre,
+    Gidouille,
+    Tatane,
+    Phalle,
+}
+
+/// Represents a date in the Pataphysical calendar
+///
+/// ## Introduction
+///
+/// The Pataphysical calendar is used by the Collège de 'Pataphysique, a society of artists
+/// and writers inspired by Alfred Jarry.
+///
+/// ## Basic structure
+///
+/// Years are divided into 13 months of 29 days. The first 28 days of each month form 4 weeks,
+/// and every month starts on a Sunday. The 29th day of each month is called *hunyadi*, and
+/// is not part of any week.
+///
+/// Most hunyadi are imaginary, meaning they do not occur at all. 29 Gidouille occurs every year.
+/// 29 Gueules occurs in leap years.
+///
+/// Leap years occur so that the Pataphysical calendar always starts on 8 September in the
+/// proleptic Gregorian calendar. A Pataphysical year is a leap year if the next Gregorian year
+/// is a leap year.
+///
+/// ## Epoch
+///
+/// Years are numbered from the birth of Alfred Jarry. The first day of the first year of the
+/// Pataphysical calendar occurs on 8 September 1873 Common Era of the Gregorian calendar.
+///
+/// This epoch is called the Pataphysical Era (E.P.).
+///
+/// ## Representation and Examples
+///
+/// The months are represented in this crate as [`PataphysicalMonth`].
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+/// use radnelac::day_cycle::*;
+///
+/// let g = Gregorian::try_new(2025, GregorianMonth::September, 8).unwrap();
+/// let p = g.convert::<Pataphysical>();
+/// assert_eq!(p, Pataphysical::try_new(153, PataphysicalMonth::Absolu, 1).unwrap());
+/// assert_eq!(p.weekday().unwrap(), Weekday::Sunday);
+///
+/// let h = Pataphysical::try_new(153, PataphysicalMonth::Gidouille, 29).unwrap();
+/// assert!(h.weekday().is_none());
+/// assert!(Pataphysical::try_new(153, PataphysicalMonth::Merdre, 29).is_err());
+/// ```
+///
+/// ## Further reading
+/// + [Wikipedia](https://en.wikipedia.org/wiki/Pataphysics#Pataphysical_calendar)
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
+pub struct Pataphysical(CommonDate);
+
+impl fmt::Debug for Pataphysical {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<PataphysicalMonth>("Pataphysical", f)
+    }
+}
+
+impl Pataphysical {
+    fn gregorian_year_start(p_year: i32) -> i64 {
+        let start = CommonDate::new(
+            p_year + PATAPHYSICAL_YEAR_OFFSET,
+            PATAPHYSICAL_EPOCH_GREGORIAN.month,
+            PATAPHYSICAL_EPOCH_GREGORIAN.day,
+        );
+        //The Gregorian date may be slightly beyond the effective bounds
+        Gregorian::from_common_date_unchecked(start)
+            .to_fixed()
+            .get_day_i()
+    }
+}
+
+impl AllowYearZero for Pataphysical {}
+
+impl ToFromOrdinalDate for Pataphysical {
+    fn valid_ordinal(ord: OrdinalDate) -> Result<(), CalendarError> {
+        let correction = if Pataphysical::is_leap(ord.year) {
+            1
+        } else {
+            0
+        };
+        if ord.day_of_year > 0 && ord.day_of_year <= (365 + correction) {
+            Ok(())
+        } else {
+            Err(CalendarError::InvalidDayOfYear)
+        }
+    }
+
+    fn ordinal_from_fixed(fixed_date: Fixed) -> OrdinalDate {
+        let date = fixed_date.get_day_i();
+        let g_year = Gregorian::ordinal_from_fixed(fixed_date).year;
+        let approx = g_year - PATAPHYSICAL_YEAR_OFFSET;
+        let year = if date < Pataphysical::gregorian_year_start(approx) {
+            approx - 1
+        } else {
+            approx
+        };
+        let doy = date - Pataphysical::gregorian_year_start(year) + 1;
+        OrdinalDate {
+            year,
+            day_of_year: doy as u16,
+        }
+    }
+
+    fn to_ordinal(self) -> OrdinalDate {
+        let month = self.0.month;
+        let mut doy = ((month as u16) - 1) * 28 + (self.0.day as u16);
+        if month > (PataphysicalMonth::Gueules as u8) && Pataphysical::is_leap(self.0.year) {
+            doy += 1;
+        }
+        if month > (PataphysicalMonth::Gidouille as u8) {
+            doy += 1;
+        }
+        OrdinalDate {
+            year: self.0.year,
+            day_of_year: doy,
+        }
+    }
+
+    fn from_ordinal_unchecked(ord: OrdinalDate) -> Self {
+        let mut day = ord.day_of_year;
+        let mut month = PataphysicalMonth::Absolu;
+        loop {
+            let length = Pataphysical::month_length(ord.year, month) as u16;
+            if day <= length {
+                break;
+            }
+            day -= length;
+            month = PataphysicalMonth::from_u8((month as u8) + 1).expect("doy in range");
+        }
+        Pataphysical(CommonDate::new(ord.year, month as u8, day as u8))
+    }
+}
+
+impl Perennial<PataphysicalMonth, Weekday> for Pataphysical {
+    fn weekday(self) -> Option<Weekday> {
+        if self.0.day == HUNYADI {
+            None
+        } else {
+            Weekday::from_i64(((self.0.day as i64) - 1).modulus(7))
+        }
+    }
+
+    fn days_per_week() -> u8 {
+        7
+    }
+
+    fn weeks_per_month() -> u8 {
+        4
+    }
+}
+
+impl HasLeapYears for Pataphysical {
+    fn is_leap(p_year: i32) -> bool {
+        Gregorian::is_leap(p_year + PATAPHYSICAL_YEAR_OFFSET + 1)
+    }
+}
+
+const COMMON_MONTH_LENGTHS: [u8; 13] = [28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 29, 28, 28];
+const LEAP_MONTH_LENGTHS: [u8; 13] = [28, 28, 28, 28, 28, 29, 28, 28, 28, 28, 29, 28, 28];
+
+impl MonthTable for Pataphysical {
+    const COMMON_MONTH_LENGTHS: &'static [u8] = &COMMON_MONTH_LENGTHS;
+    const LEAP_MONTH_LENGTHS: &'static [u8] = &LEAP_MONTH_LENGTHS;
+    const COMMON_DAYS_BEFORE_MONTH: &'static [u16] = &cumulative_lengths(COMMON_MONTH_LENGTHS);
+    const LEAP_DAYS_BEFORE_MONTH: &'static [u16] = &cumulative_lengths(LEAP_MONTH_LENGTHS);
+}
+
+impl CalculatedBounds for Pataphysical {}
+
+impl Epoch for Pataphysical {
+    fn epoch() -> Fixed {
+        Gregorian::try_from_common_date(PATAPHYSICAL_EPOCH_GREGORIAN)
+            .expect("Epoch known to be valid")
+            .to_fixed()
+    }
+}
+
+impl FromFixed for Pataphysical {
+    fn from_fixed(date: Fixed) -> Pataphysical {
+        Pataphysical::from_ordinal_unchecked(Pataphysical::ordinal_from_fixed(date))
+    }
+}
+
+impl ToFixed for Pataphysical {
+    fn to_fixed(self) -> Fixed {
+        let offset_y = Pataphysical::gregorian_year_start(self.0.year) - 1;
+        Fixed::cast_new(offset_y + (self.to_ordinal().day_of_year as i64))
+    }
+}
+
+impl ToFromCommonDate<PataphysicalMonth> for Pataphysical {
+    const EFFECTIVE_YEAR_MIN: i32 = -47015788;
+    const EFFECTIVE_YEAR_MAX: i32 = 47012044;
+
+    fn to_common_date(self) -> CommonDate {
+        self.0
+    }
+
+    fn from_common_date_unchecked(date: CommonDate) -> Self {
+        debug_assert!(Self::valid_ymd(date).is_ok());
+        Self(date)
+    }
+
+    fn valid_ymd(date: CommonDate) -> Result<(), CalendarError> {
+        match PataphysicalMonth::from_u8(date.month) {
+            None => Err(CalendarError::InvalidMonth),
+            Some(m) if date.day < 1 || date.day > Self::month_length(date.year, m) => {
+                Err(CalendarError::InvalidDay)
+            }
+            Some(_) => Ok(()),
+        }
+    }
+
+    fn year_end_date(year: i32) -> CommonDate {
+        CommonDate::new(year, PataphysicalMonth::Phalle as u8, 28)
+    }
+
+    fn month_length(year: i32, month: PataphysicalMonth) -> u8 {
+        match month {
+            PataphysicalMonth::Gidouille => HUNYADI,
+            PataphysicalMonth::Gueules if Pataphysical::is_leap(year) => HUNYADI,
+            _ => 28,
+        }
+    }
+}
+
+impl Quarter for Pataphysical {
+    fn quarter(self) -> NonZero<u8> {
+        match (self.try_week_of_year(), self.month()) {
+            (Some(w), _) => NonZero::new((w - 1) / 13 + 1).expect("w > 0"),
+            (None, PataphysicalMonth::Gueules) => NonZero::new(2).unwrap(),
+            (None, _) => NonZero::new(4).unwrap(),
+        }
+    }
+}
+
+impl GuaranteedMonth<PataphysicalMonth> for Pataphysical {}
+
+/// Represents a date *and time* in the Pataphysical Calendar
+pub type PataphysicalMoment = CalendarMoment<Pataphysical>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use proptest::proptest;
+
+    #[test]
+    fn epoch() {
+        let g = Gregorian::from_fixed(Pataphysical::epoch());
+        assert_eq!(g.to_common_date(), PATAPHYSICAL_EPOCH_GREGORIAN);
+        let p = Pataphysical::try_year_start(1).unwrap();
+        assert_eq!(p.to_fixed(), Pataphysical::epoch());
+    }
+
+    #[test]
+    fn hunyadi() {
+        //153 E.P. contains February 2026, 155 E.P. contains February 2028
+        assert!(!Pataphysical::is_leap(153));
+        assert!(Pataphysical::is_leap(155));
+        let c = CommonDate::new(153, PataphysicalMonth::Gueules as u8, 29);
+        assert!(matches!(
+            Pataphysical::try_from_common_date(c),
+            Err(CalendarError::InvalidDay)
+        ));
+        let c = CommonDate::new(155, PataphysicalMonth::Gueules as u8, 29);
+        assert!(Pataphysical::try_from_common_date(c).is_ok());
+        let c = CommonDate::new(153, PataphysicalMonth::Gidouille as u8, 29);
+        assert!(Pataphysical::try_from_common_date(c).is_ok());
+    }
+
+    proptest! {
+        #[test]
+        fn starts_on_8_september(year in -100000..100000i32) {
+            let p = Pataphysical::try_year_start(year).unwrap();
+            let g = p.convert::<Gregorian>().to_common_date();
+            assert_eq!(g, CommonDate::new(year + PATAPHYSICAL_YEAR_OFFSET, 9, 8));
+            let e = Pataphysical::try_year_end(year).unwrap();
+            let g = e.convert::<Gregorian>().to_common_date();
+            assert_eq!(g, CommonDate::new(year + PATAPHYSICAL_YEAR_OFFSET + 1, 9, 7));
+        }
+
+        #[test]
+        fn weekdays(t in FIXED_MIN..FIXED_MAX) {
+            let f = Fixed::new(t).to_day();
+            let p = Pataphysical::from_fixed(f);
+            if let Some(w) = p.weekday() {
+                let week_start = Fixed::cast_new(f.get_day_i() - (w as i64));
+                let s = Pataphysical::from_fixed(week_start);
+                assert_eq!(s.weekday(), Some(Weekday::Sunday));
+                assert_eq!(s.month(), p.month());
+            }
+        }
+    }
+}
@@ -0,0 +1,479 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::julian::Julian;
+use crate::calendar::month_table::cumulative_lengths;
+use crate::calendar::month_table::MonthTable;
+use crate::calendar::offset::OffsetCalendar;
+use crate::calendar::prelude::CommonDate;
+use crate::calendar::prelude::CommonWeekOfYear;
+use crate::calendar::prelude::GuaranteedMonth;
+use crate::calendar::prelude::HasLeapYears;
+use crate::calendar::prelude::Quarter;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::prelude::YearNumbering;
+use crate::calendar::rest_days::RestDays;
+use crate::calendar::rest_days::RestWeekdays;
+use crate::calendar::CalendarMoment;
+use crate::calendar::OrdinalDate;
+use crate::calendar::ToFromOrdinalDate;
+use crate::calendar::YearZeroPolicy;
+use crate::common::error::CalendarError;
+use crate::common::math::TermNum;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::CalculatedBounds;
+use crate::day_count::Epoch;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use std::num::NonZero;
+
+#[allow(unused_imports)] //FromPrimitive is needed for derive
+use num_traits::FromPrimitive;
+use std::fmt;
+
+const PERSIAN_EPOCH_JULIAN: CommonDate = CommonDate {
+    year: 622,
+    month: 3,
+    day: 19,
+};
+const CYCLE_YEARS: i64 = 2820;
+const CYCLE_DAYS: i64 = 1029983;
+
+/// Represents a month in the Persian calendar
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, FromPrimitive, ToPrimitive)]
+pub enum PersianMonth {
+    Farvardin = 1,
+    Ordibehesht,
+    Khordad,
+    Tir,
+    Mordad,
+    Shahrivar,
+    Mehr,
+    Aban,
+    Azar,
+    Dey,
+    Bahman,
+    Esfand,
+}
+
+/// Represents a date in the arithmetic approximation of the Persian calendar
+///
+/// ## Introduction
+///
+/// The Persian calendar (also called the Solar Hijri calendar) is the official calendar
+/// of Iran and Afghanistan.
+///
+/// `PersianArith` is **an approximation** of the Persian calendar. The official calendar
+/// starts each year on the day of the spring equinox, as observed in Tehran. **PersianArith
+/// does not read astronomical data nor approximate such data - instead it relies on the
+/// 2820 year cycle of leap years proposed by Ahmad Birashk.** The approximation agrees with
+/// the official calendar for most years in the 20th and 21st centuries Common Era, but not
+/// for all of them.
+///
+/// ## Basic Structure
+///
+/// Years are divided into 12 months. The first 6 months have 31 days, the next 5 months
+/// have 30 days, and the last month has 29 days in common years and 30 days in leap years.
+///
+/// There are 683 leap years in each 2820 year cycle.
+///
+/// ## Epoch
+///
+/// Years are numbered from the year of the Hijra of Muhammad. The first day of the
+/// first year was 19 March 622 Common Era in the proleptic Julian calendar.
+///
+/// This epoch is called "Anno Persico" (AP).
+///
+/// ## Year 0
+///
+/// Year 0 is **not** supported because it is not supported in Calendrical Calculations.
+///
+/// ## Representation and Examples
+///
+/// The months are represented in this crate as [`PersianMonth`].
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// let g = Gregorian::try_new(2021, GregorianMonth::March, 21).unwrap();
+/// let p = g.convert::<PersianArith>();
+/// assert_eq!(p, PersianArith::try_new(1400, PersianMonth::Farvardin, 1).unwrap());
+/// ```
+///
+/// ## Further reading
+/// + [Wikipedia](https://en.wikipedia.org/wiki/Solar_Hijri_calendar)
+/// + *Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
+pub struct PersianArith(CommonDate);
+
+impl fmt::Debug for PersianArith {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<PersianMonth>("PersianArith", f)
+    }
+}
+
+impl PersianArith {
+    /// Name of the era, used as a suffix for years
+    pub const ERA_NAME: &'static str = "Anno Persico";
+
+    /// Year within the 2820 year cycle, counting from year 474
+    fn cycle_year(p_year: i32) -> (i64, i64) {
+        //Based on *Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz
+        let y = if p_year > 0 {
+            p_year - 474
+        } else {
+            p_year - 473
+        } as i64;
+        (y.div_euclid(CYCLE_YEARS), y.modulus(CYCLE_YEARS) + 474)
+    }
+
+    fn prior_elapsed_days(p_year: i32) -> i64 {
+        //Based on *Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz
+        let (cycles, year) = PersianArith::cycle_year(p_year);
+        PersianArith::epoch().get_day_i() - 1
+            + (CYCLE_DAYS * cycles)
+            + (365 * (year - 1))
+            + ((31 * year) - 5).div_euclid(128)
+    }
+
+    fn year_from_fixed(date: i64) -> i32 {
+        //Based on *Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz
+        let d0 = date - (PersianArith::prior_elapsed_days(475) + 1);
+        let n2820 = d0.div_euclid(CYCLE_DAYS);
+        let d1 = d0.modulus(CYCLE_DAYS);
+        let y2820 = if d1 == CYCLE_DAYS - 1 {
+            CYCLE_YEARS
+        } else {
+            ((128 * d1) + 46878).div_euclid(46751)
+        };
+        let year = (474 + (CYCLE_YEARS * n2820) + y2820) as i32;
+        if year > 0 {
+            year
+        } else {
+            year - 1
+        }
+    }
+}
+
+impl ToFromOrdinalDate for PersianArith {
+    fn valid_ordinal(ord: OrdinalDate) -> Result<(), CalendarError> {
+        let correction = if PersianArith::is_leap(ord.year) {
+            1
+        } else {
+            0
+        };
+        if ord.year == 0 {
+            Err(CalendarError::InvalidYear)
+        } else if ord.day_of_year > 0 && ord.day_of_year <= (365 + correction) {
+            Ok(())
+        } else {
+            Err(CalendarError::InvalidDayOfYear)
+        }
+    }
+
+    fn ordinal_from_fixed(fixed_date: Fixed) -> OrdinalDate {
+        let date = fixed_date.get_day_i();
+        let year = PersianArith::year_from_fixed(date);
+        OrdinalDate {
+            year,
+            day_of_year: (date - PersianArith::prior_elapsed_days(year)) as u16,
+        }
+    }
+
+    fn to_ordinal(self) -> OrdinalDate {
+        let month = self.0.month as u16;
+        let offset_m = if month <= 7 {
+            31 * (month - 1)
+        } else {
+            (30 * (month - 1)) + 6
+        };
+        OrdinalDate {
+            year: self.0.year,
+            day_of_year: offset_m + (self.0.day as u16),
+        }
+    }
+
+    fn from_ordinal_unchecked(ord: OrdinalDate) -> Self {
+        let doy = ord.day_of_year;
+        let month = if doy <= 186 {
+            doy.div_ceil(31)
+        } else {
+            (doy - 6).div_ceil(30)
+        };
+        let month_start = PersianArith(CommonDate::new(ord.year, month as u8, 1)).to_ordinal();
+        let day = (doy - month_start.day_of_year + 1) as u8;
+        PersianArith(CommonDate::new(ord.year, month as u8, day))
+    }
+}
+
+impl YearNumbering for PersianArith {
+    const YEAR_ZERO: YearZeroPolicy = YearZeroPolicy::Skipped;
+}
+
+impl HasLeapYears for PersianArith {
+    fn is_leap(p_year: i32) -> bool {
+        //Based on *Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz
+        let (_, year) = PersianArith::cycle_year(p_year);
+        ((year + 38) * 31).modulus(128) < 31
+    }
+}
+
+impl RestDays for PersianArith {
+    const REST_WEEKDAYS: RestWeekdays = RestWeekdays::FRIDAY;
+}
+
+const COMMON_MONTH_LENGTHS: [u8; 12] = [31, 31, 31, 31, 31, 31, 30, 30, 30, 30, 30, 29];
+const LEAP_MONTH_LENGTHS: [u8; 12] = [31, 31, 31, 31, 31, 31, 30, 30, 30, 30, 30, 30];
+
+impl MonthTable for PersianArith {
+    const COMMON_MONTH_LENGTHS: &'static [u8] = &COMMON_MONTH_LENGTHS;
+    const LEAP_MONTH_LENGTHS: &'static [u8] = &LEAP_MONTH_LENGTHS;
+    const COMMON_DAYS_BEFORE_MONTH: &'static [u16] = &cumulative_lengths(COMMON_MONTH_LENGTHS);
+    const LEAP_DAYS_BEFORE_MONTH: &'static [u16] = &cumulative_lengths(LEAP_MONTH_LENGTHS);
+}
+
+impl CalculatedBounds for PersianArith {}
+
+impl Epoch for PersianArith {
+    fn epoch() -> Fixed {
+        Julian::from_common_date_unchecked(PERSIAN_EPOCH_JULIAN).to_fixed()
+    }
+}
+
+impl FromFixed for PersianArith {
+    fn from_fixed(fixed_date: Fixed) -> PersianArith {
+        Self::from_ordinal_unchecked(Self::ordinal_from_fixed(fixed_date))
+    }
+}
+
+impl ToFixed for PersianArith {
+    fn to_fixed(self) -> Fixed {
+        let offset_prior = PersianArith::prior_elapsed_days(self.0.year);
+        Fixed::cast_new(offset_prior + (self.to_ordinal().day_of_year as i64))
+    }
+}
+
+impl ToFromCommonDate<PersianMonth> for PersianArith {
+    const EFFECTIVE_YEAR_MIN: i32 = -47014577;
+    const EFFECTIVE_YEAR_MAX: i32 = 47013334;
+
+    fn to_common_date(self) -> CommonDate {
+        self.0
+    }
+
+    fn from_common_date_unchecked(date: CommonDate) -> Self {
+        debug_assert!(Self::valid_ymd(date).is_ok());
+        Self(date)
+    }
+
+    fn valid_ymd(date: CommonDate) -> Result<(), CalendarError> {
+        match PersianMonth::from_u8(date.month) {
+            None => Err(CalendarError::InvalidMonth),
+            Some(m) if date.day < 1 || date.day > Self::month_length(date.year, m) => {
+                Err(CalendarError::InvalidDay)
+            }
+            Some(_) if date.year == 0 => Err(CalendarError::InvalidYear),
+            Some(_) => Ok(()),
+        }
+    }
+
+    fn year_end_date(year: i32) -> CommonDate {
+        let m = PersianMonth::Esfand;
+        CommonDate::new(year, m as u8, Self::month_length(year, m))
+    }
+
+    fn month_length(year: i32, month: PersianMonth) -> u8 {
+        match month {
+            PersianMonth::Esfand if PersianArith::is_leap(year) => 30,
+            PersianMonth::Esfand => 29,
+            m if m <= PersianMonth::Shahrivar => 31,
+            _ => 30,
+        }
+    }
+}
+
+impl Quarter for PersianArith {
+    fn quarter(self) -> NonZero<u8> {
+        NonZero::new(((self.to_common_date().month - 1) / 3) + 1).expect("(m-1)/3 > -1")
+    }
+}
+
+impl GuaranteedMonth<PersianMonth> for PersianArith {}
+impl CommonWeekOfYear<PersianMonth> for PersianArith {}
+
+/// Represents a date *and time* in the arithmetic approximation of the Persian calendar
+pub type PersianArithMoment = CalendarMoment<PersianArith>;
+
+/// Represents a month in the Kurdish calendar
+///
+/// These are the Sorani names of the months.
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, FromPrimitive, ToPrimitive)]
+pub enum KurdishMonth {
+    Xakelewe = 1,
+    Gullan,
+    Jozerdan,
+    Pushperr,
+    Gelawej,
+    Xermanan,
+    Rezber,
+    Gelarezan,
+    Sermawez,
+    Befranbar,
+    Rebendan,
+    Resheme,
+}
+
+/// Represents a date in the arithmetic approximation of the Kurdish calendar
+///
+/// The Kurdish calendar is the Persian calendar with years numbered from the fall of
+/// Nineveh. 1 Farvardin 1404 in the Persian calendar is the first day of 2725 in
+/// the Kurdish calendar.
+///
+/// Like [`PersianArith`], this is **an approximation**. Since the Persian calendar does
+/// not have a year 0, the Kurdish calendar in this crate does not have a year 1321.
+///
+/// The months are the same as the Persian calendar, but have different names. Use
+/// [`Kurdish::kurdish_month`] to read the name of the month.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// let g = Gregorian::try_new(2021, GregorianMonth::March, 21).unwrap();
+/// let k = g.convert::<Kurdish>();
+/// assert_eq!(k.year(), 2721);
+/// assert_eq!(k.kurdish_month(), KurdishMonth::Xakelewe);
+/// ```
+///
+/// ## Further reading
+/// + [Wikipedia](https://en.wikipedia.org/wiki/Kurdish_calendar)
+pub type Kurdish = OffsetCalendar<PersianArith, 1321, 0>;
+
+impl Kurdish {
+    /// Name of the era, used as a suffix for years
+    pub const ERA_NAME: &'static str = "Kurdish Era";
+
+    pub fn kurdish_month(self) -> KurdishMonth {
+        KurdishMonth::from_u8(self.to_common_date().month).expect("Same months as Persian")
+    }
+}
+
+/// Represents a date *and time* in the arithmetic approximation of the Kurdish calendar
+pub type KurdishMoment = CalendarMoment<Kurdish>;
+
+/// Represents a month in the Afghan calendar
+///
+/// These are the Dari names of the months, which are based on the signs of the zodiac.
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, FromPrimitive, ToPrimitive)]
+pub enum AfghanMonth {
+    Hamal = 1,
+    Sawr,
+    Jawza,
+    Saratan,
+    Asad,
+    Sunbula,
+    Mizan,
+    Aqrab,
+    Qaws,
+    Jadi,
+    Dalw,
+    Hut,
+}
+
+/// Represents a date in the arithmetic approximation of the Afghan calendar
+///
+/// The Afghan calendar is the Persian calendar with different month names. The years
+/// are numbered in the same way.
+///
+/// Like [`PersianArith`], this is **an approximation**. Use [`Afghan::afghan_month`] to
+/// read the name of the month.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// let g = Gregorian::try_new(2021, GregorianMonth::March, 21).unwrap();
+/// let a = g.convert::<Afghan>();
+/// assert_eq!(a.year(), 1400);
+/// assert_eq!(a.afghan_month(), AfghanMonth::Hamal);
+/// ```
+///
+/// ## Further reading
+/// + [Wikipedia](https://en.wikipedia.org/wiki/Solar_Hijri_calendar)
+pub type Afghan = OffsetCalendar<PersianArith, 0, 0>;
+
+impl YearNumbering for Afghan {
+    const YEAR_ZERO: YearZeroPolicy = YearZeroPolicy::Skipped;
+}
+
+impl Afghan {
+    /// Name of the era, used as a suffix for years
+    pub const ERA_NAME: &'static str = "Hijri Shamsi";
+
+    pub fn afghan_month(self) -> AfghanMonth {
+        AfghanMonth::from_u8(self.to_common_date().month).expect("Same months as Persian")
+    }
+}
+
+/// Represents a date *and time* in the arithmetic approximation of the Afghan calendar
+pub type AfghanMoment = CalendarMoment<Afghan>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Gregorian;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use proptest::proptest;
+
+    #[test]
+    fn nowruz() {
+        let expected = [
+            (1354, CommonDate::new(1975, 3, 21)),
+            (1375, CommonDate::new(1996, 3, 20)),
+            (1400, CommonDate::new(2021, 3, 21)),
+        ];
+        for (year, g) in expected {
+            let p = PersianArith::try_year_start(year).unwrap();
+            assert_eq!(p.convert::<Gregorian>().to_common_date(), g);
+        }
+        let j = Julian::from_fixed(PersianArith::epoch()).to_common_date();
+        assert_eq!(j, PERSIAN_EPOCH_JULIAN);
+    }
+
+    #[test]
+    fn cycle() {
+        let leap = (1..=2820).filter(|y| PersianArith::is_leap(*y)).count();
+        assert_eq!(leap, 683);
+        let d0 = PersianArith::prior_elapsed_days(1);
+        let d1 = PersianArith::prior_elapsed_days(2821);
+        assert_eq!(d1 - d0, CYCLE_DAYS);
+    }
+
+    #[test]
+    fn year_zero() {
+        assert!(PersianArith::try_from_common_date(CommonDate::new(0, 1, 1)).is_err());
+        let end = PersianArith::try_year_end(-1).unwrap().to_fixed();
+        let start = PersianArith::try_year_start(1).unwrap().to_fixed();
+        assert_eq!(end.get_day_i() + 1, start.get_day_i());
+    }
+
+    proptest! {
+        #[test]
+        fn wrappers(t in FIXED_MIN..FIXED_MAX) {
+            let f = Fixed::new(t).to_day();
+            let p = PersianArith::from_fixed(f);
+            let k = Kurdish::from_fixed(f);
+            let a = Afghan::from_fixed(f);
+            assert_eq!(k.year(), p.year() + 1321);
+            assert_eq!(a.year(), p.year());
+            assert_eq!(k.kurdish_month() as u8, p.month() as u8);
+            assert_eq!(a.afghan_month() as u8, p.month() as u8);
+            assert_eq!(k.to_fixed(), f);
+            assert_eq!(a.to_fixed(), f);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::FrenchRevArith;
+use crate::calendar::HasEpagemonae;
+use crate::calendar::Symmetry454;
+use crate::calendar::Symmetry454Solstice;
+use crate::calendar::Tranquility;
+use crate::calendar::TranquilityComplementaryDay;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::Fixed;
+use crate::day_count::FixedInterval;
+use crate::day_count::FromFixed;
+
+/// Represents a rule of a calendar which other implementations may interpret differently
+///
+/// These are rules where the original sources are ambiguous, and this crate supports or
+/// documents more than one interpretation. Use [`PolicyAmbiguity::disagreements`] to find
+/// the days which are affected.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// let a = Gregorian::try_new(1969, GregorianMonth::January, 1).unwrap().to_fixed();
+/// let b = Gregorian::try_new(1970, GregorianMonth::January, 1).unwrap().to_fixed();
+/// let interval = FixedInterval::half_open(a, b).unwrap();
+/// let days = PolicyAmbiguity::TranquilityOneBT.disagreements(interval);
+/// assert_eq!(days, vec![TranquilityMoment::epoch().to_day()]);
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[non_exhaustive]
+pub enum PolicyAmbiguity {
+    /// Whether Moon Landing Day replaces Armstrong Day, 1 BT in the Tranquility calendar
+    ///
+    /// See "The Curious Case of 1 Before Tranquility" in [`Tranquility`].
+    TranquilityOneBT,
+    /// Whether the French Revolutionary calendar uses `L = true` or `L = false`
+    ///
+    /// See [`FrenchRevArith`].
+    FrenchRevLeapRule,
+    /// Whether Irvember is placed using the 293 year or the 389 year leap rule
+    ///
+    /// See [`Symmetry`](crate::calendar::Symmetry). Only the standalone Irvember scheme
+    /// is compared.
+    SymmetryIrvember,
+}
+
+impl PolicyAmbiguity {
+    /// [`true`] if the interpretations give different dates for a day
+    pub fn disagrees(self, t: Fixed) -> bool {
+        match self {
+            PolicyAmbiguity::TranquilityOneBT => {
+                Tranquility::from_fixed(t).epagomenae()
+                    == Some(TranquilityComplementaryDay::MoonLandingDay)
+            }
+            PolicyAmbiguity::FrenchRevLeapRule => {
+                FrenchRevArith::<true>::from_fixed(t).to_common_date()
+                    != FrenchRevArith::<false>::from_fixed(t).to_common_date()
+            }
+            PolicyAmbiguity::SymmetryIrvember => {
+                Symmetry454::from_fixed(t).to_common_date()
+                    != Symmetry454Solstice::from_fixed(t).to_common_date()
+            }
+        }
+    }
+
+    /// Every day which starts within an interval where the interpretations disagree
+    ///
+    /// The time taken is proportional to the duration of the interval.
+    pub fn disagreements(self, interval: FixedInterval) -> Vec<Fixed> {
+        let first = interval.start().get().ceil() as i64;
+        let last = interval.end().get_day_i();
+        (first..=last)
+            .map(Fixed::cast_new)
+            .filter(|t| interval.contains(*t) && self.disagrees(*t))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::prelude::HasLeapYears;
+    use crate::calendar::LeapWeekCalendar;
+    use crate::calendar::TranquilityMoment;
+    use crate::day_count::Epoch;
+    use crate::day_count::ToFixed;
+
+    fn years(start: i32, end: i32) -> FixedInterval {
+        let a = Symmetry454::try_year_start(start).unwrap().to_fixed();
+        let b = Symmetry454::try_year_start(end).unwrap().to_fixed();
+        FixedInterval::half_open(a, b).unwrap()
+    }
+
+    #[test]
+    fn tranquility() {
+        let days = PolicyAmbiguity::TranquilityOneBT.disagreements(years(1900, 2100));
+        assert_eq!(days, vec![TranquilityMoment::epoch().to_day()]);
+    }
+
+    #[test]
+    fn french_rev() {
+        let p = PolicyAmbiguity::FrenchRevLeapRule;
+        let days = p.disagreements(years(1700, 2300));
+        assert!(!days.is_empty());
+        for t in days {
+            let a = FrenchRevArith::<true>::from_fixed(t).to_common_date();
+            let b = FrenchRevArith::<false>::from_fixed(t).to_common_date();
+            assert_ne!(a, b);
+        }
+    }
+
+    #[test]
+    fn symmetry() {
+        let p = PolicyAmbiguity::SymmetryIrvember;
+        for y in 1900..2100 {
+            let days = p.disagreements(years(y, y + 1));
+            let same_start =
+                Symmetry454::year_start_day(y) == Symmetry454Solstice::year_start_day(y);
+            let same_leap = Symmetry454::is_leap(y) == Symmetry454Solstice::is_leap(y);
+            assert_eq!(days.is_empty(), same_start && same_leap, "y: {}", y);
+        }
+    }
+}
@@ -10,6 +10,8 @@ use crate::calendar::prelude::Perennial;
 use crate::calendar::prelude::Quarter;
 use crate::calendar::prelude::ToFromCommonDate;
 use crate::calendar::prelude::ToFromOrdinalDate;
+use crate::calendar::thirteen_month;
+use crate::calendar::thirteen_month::ThirteenMonthDay;
 use crate::calendar::AllowYearZero;
 use crate::calendar::CalendarMoment;
 use crate::calendar::HasEpagemonae;
@@ -27,9 +29,11 @@ use std::num::NonZero;
 
 #[allow(unused_imports)] //FromPrimitive is needed for derive
 use num_traits::FromPrimitive;
+use std::fmt;
 
 const POSITIVIST_YEAR_OFFSET: i32 = 1789 - 1;
 const NON_MONTH: u8 = 14;
+const POSITIVIST_LEAP_DAY_ORDINAL: u16 = 366;
 
 /// Represents a month of the Positivist Calendar
 ///
@@ -153,9 +157,15 @@ pub enum PositivistComplementaryDay {
 /// + [Positivists.org](http://positivists.org/calendar.html)
 /// + [*Calendrier Positiviste* by August Comte](https://gallica.bnf.fr/ark:/12148/bpt6k21868f/f42.planchecontact)
 /// + [*The Positivist Calendar* by Henry Edger](https://books.google.ca/books?id=S_BRAAAAMAAJ)
-#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
 pub struct Positivist(CommonDate);
 
+impl fmt::Debug for Positivist {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<PositivistMonth>("Positivist", f)
+    }
+}
+
 impl AllowYearZero for Positivist {}
 
 impl ToFromOrdinalDate for Positivist {
@@ -168,28 +178,38 @@ impl ToFromOrdinalDate for Positivist {
     }
 
     fn ordinal_from_fixed(fixed_date: Fixed) -> OrdinalDate {
-        let ord_g = Gregorian::ordinal_from_fixed(fixed_date);
-        OrdinalDate {
-            year: ord_g.year - POSITIVIST_YEAR_OFFSET,
-            day_of_year: ord_g.day_of_year,
-        }
+        thirteen_month::ordinal_from_fixed(fixed_date, POSITIVIST_YEAR_OFFSET)
     }
 
     fn to_ordinal(self) -> OrdinalDate {
-        let offset_m = ((self.0.month as i64) - 1) * 28;
-        let doy = (offset_m as u16) + (self.0.day as u16);
+        let day = match self.epagomenae() {
+            Some(PositivistComplementaryDay::FestivalOfTheDead) => ThirteenMonthDay::YearDay,
+            Some(PositivistComplementaryDay::FestivalOfHolyWomen) => ThirteenMonthDay::LeapDay,
+            None => ThirteenMonthDay::Month(self.0.month, self.0.day),
+        };
+        let is_leap = Positivist::is_leap(self.0.year);
         OrdinalDate {
             year: self.0.year,
-            day_of_year: doy,
+            day_of_year: thirteen_month::to_ordinal(day, is_leap, POSITIVIST_LEAP_DAY_ORDINAL),
         }
     }
 
     fn from_ordinal_unchecked(ord: OrdinalDate) -> Self {
-        let year = ord.year;
-        let month = (((ord.day_of_year - 1) as i64).div_euclid(28) + 1) as u8;
-        let day = (ord.day_of_year as i64).adjusted_remainder(28) as u8;
-        debug_assert!(day > 0 && day < 29);
-        Positivist(CommonDate::new(year, month, day))
+        let is_leap = Positivist::is_leap(ord.year);
+        let day =
+            thirteen_month::from_ordinal(ord.day_of_year, is_leap, POSITIVIST_LEAP_DAY_ORDINAL);
+        let (month, day) = match day {
+            ThirteenMonthDay::YearDay => (
+                NON_MONTH,
+                PositivistComplementaryDay::FestivalOfTheDead as u8,
+            ),
+            ThirteenMonthDay::LeapDay => (
+                NON_MONTH,
+                PositivistComplementaryDay::FestivalOfHolyWomen as u8,
+            ),
+            ThirteenMonthDay::Month(month, day) => (month, day),
+        };
+        Positivist(CommonDate::new(ord.year, month, day))
     }
 }
 
@@ -234,7 +254,7 @@ impl Perennial<PositivistMonth, Weekday> for Positivist {
 impl HasLeapYears for Positivist {
     // Not sure about the source for this...
     fn is_leap(p_year: i32) -> bool {
-        Gregorian::is_leap(POSITIVIST_YEAR_OFFSET + p_year)
+        thirteen_month::is_leap(p_year, POSITIVIST_YEAR_OFFSET)
     }
 }
 
@@ -250,29 +270,23 @@ impl Epoch for Positivist {
 
 impl FromFixed for Positivist {
     fn from_fixed(date: Fixed) -> Positivist {
-        let ord_g = Gregorian::ordinal_from_fixed(date);
-        let ord = OrdinalDate {
-            year: ord_g.year - POSITIVIST_YEAR_OFFSET,
-            day_of_year: ord_g.day_of_year,
-        };
+        let ord = Self::ordinal_from_fixed(date);
         Self::from_ordinal_unchecked(ord)
     }
 }
 
 impl ToFixed for Positivist {
     fn to_fixed(self) -> Fixed {
-        let y = self.0.year + POSITIVIST_YEAR_OFFSET;
-        let offset_y = Gregorian::try_year_start(y)
-            .expect("Year known to be valid")
-            .to_fixed()
-            .get_day_i()
-            - 1;
+        let offset_y = thirteen_month::prior_elapsed_days(self.0.year, POSITIVIST_YEAR_OFFSET);
         let doy = self.to_ordinal().day_of_year as i64;
         Fixed::cast_new(offset_y + doy)
     }
 }
 
 impl ToFromCommonDate<PositivistMonth> for Positivist {
+    const EFFECTIVE_YEAR_MIN: i32 = -47015703;
+    const EFFECTIVE_YEAR_MAX: i32 = 47012128;
+
     fn to_common_date(self) -> CommonDate {
         self.0
     }
@@ -2,20 +2,85 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use crate::calendar::YearZeroPolicy;
 use crate::common::error::CalendarError;
+use crate::common::trace::conversion_trace;
 use crate::day_count::BoundedDayCount;
 use crate::day_count::EffectiveBound;
 use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
 use crate::day_count::ToFixed;
+use crate::day_count::FIXED_MAX;
+use crate::day_count::FIXED_MIN;
 use crate::day_cycle::OnOrBefore;
 use crate::day_cycle::Weekday;
 use num_traits::FromPrimitive;
 use num_traits::ToPrimitive;
+use std::fmt;
+use std::fmt::Debug;
 use std::num::NonZero;
 
+const STABLE_HASH_OFFSET: u64 = 0xcbf29ce484222325;
+const STABLE_HASH_PRIME: u64 = 0x100000001b3;
+
 /// Calendar systems with year 0
 pub trait AllowYearZero {}
 
+/// Calendar systems with a known treatment of year 0
+///
+/// In astronomical year numbering, the year before year 1 is year 0. In historical year
+/// numbering, the year before year 1 is year -1. For example, 44 BC is year -43 in
+/// astronomical numbering and year -44 in historical numbering.
+///
+/// These functions are only for changing between numbering conventions. They do not
+/// convert a year of one calendar into a year of another calendar.
+///
+/// For calendars with [`YearZeroPolicy::EpochOnly`], year 0 is left unchanged.
+///
+/// ```
+/// use radnelac::calendar::*;
+///
+/// assert_eq!(Julian::astronomical_year(-44), -43);
+/// assert_eq!(Gregorian::historical_year(-43), -44);
+/// assert_eq!(Gregorian::from_astronomical_year(Julian::astronomical_year(-44)), -43);
+/// assert_eq!(Julian::from_astronomical_year(Gregorian::astronomical_year(0)), -1);
+/// ```
+pub trait YearNumbering {
+    /// Treatment of year 0 in this calendar
+    const YEAR_ZERO: YearZeroPolicy;
+
+    /// Convert a year of this calendar to astronomical numbering
+    fn astronomical_year(year: i32) -> i32 {
+        if Self::YEAR_ZERO != YearZeroPolicy::Allowed && year < 0 {
+            year + 1
+        } else {
+            year
+        }
+    }
+
+    /// Convert a year of this calendar to historical numbering
+    fn historical_year(year: i32) -> i32 {
+        if Self::YEAR_ZERO == YearZeroPolicy::Allowed && year <= 0 {
+            year - 1
+        } else {
+            year
+        }
+    }
+
+    /// Convert a year in astronomical numbering to this calendar
+    fn from_astronomical_year(year: i32) -> i32 {
+        match Self::YEAR_ZERO {
+            YearZeroPolicy::Skipped if year <= 0 => year - 1,
+            YearZeroPolicy::EpochOnly if year < 0 => year - 1,
+            _ => year,
+        }
+    }
+}
+
+impl<T: AllowYearZero> YearNumbering for T {
+    const YEAR_ZERO: YearZeroPolicy = YearZeroPolicy::Allowed;
+}
+
 /// Calendar systems with leap years
 pub trait HasLeapYears {
     /// [`true`] if a the given year is a leap year.
@@ -25,7 +90,7 @@ pub trait HasLeapYears {
 /// Represents a combination of numeric year, month and day
 ///
 /// This is not specific to any particular calendar system.
-#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
 pub struct CommonDate {
     pub year: i32,
     pub month: u8,
@@ -37,10 +102,126 @@ impl CommonDate {
     pub fn new(year: i32, month: u8, day: u8) -> CommonDate {
         CommonDate { year, month, day }
     }
+
+    /// Split into a tuple of year, month and day
+    ///
+    /// ```
+    /// use radnelac::calendar::*;
+    ///
+    /// let c = CommonDate::from((2025, 5, 15));
+    /// let (year, month, day) = c.into_tuple();
+    /// assert_eq!((year, month, day), (2025, 5, 15));
+    /// let a: [i64; 3] = c.into();
+    /// assert_eq!(CommonDate::try_from(a).unwrap(), c);
+    /// assert!(CommonDate::try_from([2025, 256, 1]).is_err());
+    /// ```
+    pub const fn into_tuple(self) -> (i32, u8, u8) {
+        (self.year, self.month, self.day)
+    }
+
+    /// Hash of the year, month and day which is the same on every platform
+    ///
+    /// Unlike [`Hash`](std::hash::Hash) with the standard library hashers, the result does not
+    /// depend on random state, the platform or the version of Rust. It is the 64 bit FNV-1a
+    /// hash of the year as 4 little-endian bytes, followed by the month and the day.
+    ///
+    /// This is not a cryptographic hash.
+    ///
+    /// ```
+    /// use radnelac::calendar::*;
+    ///
+    /// let c = CommonDate::new(2025, 7, 26);
+    /// assert_eq!(c.stable_hash_u64(), 0xb1626b08e514b4d8);
+    /// ```
+    pub const fn stable_hash_u64(self) -> u64 {
+        let y = self.year.to_le_bytes();
+        let bytes = [y[0], y[1], y[2], y[3], self.month, self.day];
+        let mut hash = STABLE_HASH_OFFSET;
+        let mut i = 0;
+        while i < bytes.len() {
+            hash ^= bytes[i] as u64;
+            hash = hash.wrapping_mul(STABLE_HASH_PRIME);
+            i += 1;
+        }
+        hash
+    }
+
+    /// Write the date as a struct named after a calendar, with the month written by name
+    ///
+    /// Days which are not within any month have a numeric month.
+    pub(crate) fn fmt_debug<T: FromPrimitive + Debug>(
+        self,
+        name: &str,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let mut s = f.debug_struct(name);
+        s.field("year", &self.year);
+        match T::from_u8(self.month) {
+            Some(m) => s.field("month", &m),
+            None => s.field("month", &self.month),
+        };
+        s.field("day", &self.day).finish()
+    }
 }
 
+impl From<(i32, u8, u8)> for CommonDate {
+    fn from(value: (i32, u8, u8)) -> CommonDate {
+        let (year, month, day) = value;
+        CommonDate::new(year, month, day)
+    }
+}
+
+impl From<CommonDate> for (i32, u8, u8) {
+    fn from(value: CommonDate) -> (i32, u8, u8) {
+        value.into_tuple()
+    }
+}
+
+impl TryFrom<[i64; 3]> for CommonDate {
+    type Error = CalendarError;
+
+    /// Create a [`CommonDate`] from an array of year, month and day
+    ///
+    /// This only rejects values which do not fit into the fields of [`CommonDate`]. Use
+    /// [`ToFromCommonDate::valid_ymd`] for checks specific to one calendar system.
+    fn try_from(value: [i64; 3]) -> Result<CommonDate, CalendarError> {
+        let [year, month, day] = value;
+        let year = i32::try_from(year).map_err(|_| CalendarError::OutOfBounds)?;
+        let month = u8::try_from(month).map_err(|_| CalendarError::InvalidMonth)?;
+        let day = u8::try_from(day).map_err(|_| CalendarError::InvalidDay)?;
+        Ok(CommonDate::new(year, month, day))
+    }
+}
+
+impl From<CommonDate> for [i64; 3] {
+    fn from(value: CommonDate) -> [i64; 3] {
+        [value.year as i64, value.month as i64, value.day as i64]
+    }
+}
+
+//The shortest years in this crate are lunar years of 354 days
+const DEFAULT_EFFECTIVE_YEAR_MIN: i32 = (FIXED_MIN / 354.0) as i32;
+const DEFAULT_EFFECTIVE_YEAR_MAX: i32 = (FIXED_MAX / 354.0) as i32;
+
 /// Calendar systems in which a date can be represented by a year, month and day
 pub trait ToFromCommonDate<T: FromPrimitive>: Sized + EffectiveBound {
+    /// Earliest year which is entirely within the supported range of time
+    ///
+    /// The previous year is partially within the supported range of time.
+    ///
+    /// The default is derived from [`FIXED_MIN`], assuming years of at least 354 days
+    /// counted from near the start of the Common Era. This is earlier than the true bound
+    /// of every calendar in this crate, so it never rejects a supported year. Calendars
+    /// in this crate override it with the exact value.
+    const EFFECTIVE_YEAR_MIN: i32 = DEFAULT_EFFECTIVE_YEAR_MIN;
+    /// Latest year which is entirely within the supported range of time
+    ///
+    /// The next year is partially within the supported range of time.
+    ///
+    /// The default is derived from [`FIXED_MAX`] in the same way as
+    /// [`EFFECTIVE_YEAR_MIN`](ToFromCommonDate::EFFECTIVE_YEAR_MIN).
+    const EFFECTIVE_YEAR_MAX: i32 = DEFAULT_EFFECTIVE_YEAR_MAX;
+
     /// Convert calendar date to a year, month and day
     fn to_common_date(self) -> CommonDate;
     /// Convert a year, month and day into a calendar date without checking validity
@@ -60,6 +241,16 @@ pub trait ToFromCommonDate<T: FromPrimitive>: Sized + EffectiveBound {
     /// Length of month in a given year
     fn month_length(year: i32, month: T) -> u8;
 
+    /// Earliest date within the supported range of time
+    fn min_date() -> Self {
+        Self::effective_min()
+    }
+
+    /// Latest date within the supported range of time
+    fn max_date() -> Self {
+        Self::effective_max()
+    }
+
     /// [`true`] if the year, month and day is within the supported range of time.
     ///
     /// This does not check the validity of the date.
@@ -70,13 +261,40 @@ pub trait ToFromCommonDate<T: FromPrimitive>: Sized + EffectiveBound {
     }
 
     /// Attempt to create a date in a specific calendar from a [`CommonDate`]
+    ///
+    /// Returns an error if the year is entirely outside the supported range of time.
     fn try_from_common_date(d: CommonDate) -> Result<Self, CalendarError> {
+        conversion_trace!(
+            calendar = std::any::type_name::<Self>(),
+            year = d.year,
+            month = d.month,
+            day = d.day,
+            "try_from_common_date"
+        );
+        //The years just outside these bounds are partially supported
+        if d.year < Self::EFFECTIVE_YEAR_MIN - 1 || d.year > Self::EFFECTIVE_YEAR_MAX + 1 {
+            return Err(CalendarError::OutOfBounds);
+        }
         match Self::valid_ymd(d) {
             Err(e) => Err(e),
             Ok(_) => Ok(Self::from_common_date_unchecked(d)),
         }
     }
 
+    /// Attempt to create a date in a specific calendar from a year which might not fit in an [`i32`]
+    ///
+    /// The supported range of time is much smaller than the range of an [`i32`] year,
+    /// so this returns an error instead of overflowing for extreme years.
+    fn try_from_ymd_i64(year: i64, month: u8, day: u8) -> Result<Self, CalendarError> {
+        let y = i32::try_from(year).map_err(|_| CalendarError::OutOfBounds)?;
+        let d = CommonDate::new(y, month, day);
+        if Self::in_effective_bounds(d) {
+            Self::try_from_common_date(d)
+        } else {
+            Err(CalendarError::OutOfBounds)
+        }
+    }
+
     /// Attempt to create a date in a specific calendar at the start of a specific year
     ///
     /// This may return an error if the year is 0, and the implementor does not support
@@ -117,8 +335,109 @@ pub trait ToFromCommonDate<T: FromPrimitive>: Sized + EffectiveBound {
     fn year(self) -> i32 {
         self.to_common_date().year
     }
+
+    /// Hash of the date which is the same on every platform
+    ///
+    /// This is [`CommonDate::stable_hash_u64`] of the year, month and day. Dates in different
+    /// calendars with the same year, month and day have the same hash.
+    ///
+    /// The [`Hash`](std::hash::Hash) implementations of calendars are also based only on the
+    /// year, month and day, so they are consistent with equality.
+    fn stable_hash_u64(self) -> u64 {
+        self.to_common_date().stable_hash_u64()
+    }
+
+    /// Describe the date for logs, with the month written by name
+    ///
+    /// This is the [`Debug`] output, and does not depend on `serde` or `display`.
+    ///
+    /// ```
+    /// use radnelac::calendar::*;
+    ///
+    /// let g = Gregorian::try_new(2025, GregorianMonth::July, 26).unwrap();
+    /// assert_eq!(
+    ///     g.to_debug_string(),
+    ///     "Gregorian { year: 2025, month: July, day: 26 }"
+    /// );
+    /// ```
+    fn to_debug_string(self) -> String
+    where
+        Self: Debug,
+    {
+        format!("{:?}", self)
+    }
+}
+
+const PACKED_YEAR_FACTOR: i32 = 10000;
+const PACKED_MONTH_FACTOR: i32 = 100;
+
+/// Calendar systems in which a date can be packed into a single integer
+///
+/// The packed integer is `year * 10000 + month * 100 + day`. For example, 2025-05-15
+/// is packed as `20250515`. The packed integers have the same ordering as the dates.
+///
+/// This is intended for compact storage in databases and columnar formats.
+pub trait ToFromPackedDate<T: FromPrimitive>: ToFromCommonDate<T> {
+    /// Attempt to pack a date into a single integer
+    ///
+    /// Returns an error if the year is too large or too small to pack into an [`i32`].
+    fn to_packed_i32(self) -> Result<i32, CalendarError> {
+        let d = self.to_common_date();
+        let md = (d.month as i32) * PACKED_MONTH_FACTOR + (d.day as i32);
+        match d
+            .year
+            .checked_mul(PACKED_YEAR_FACTOR)
+            .and_then(|y| y.checked_add(md))
+        {
+            Some(p) => Ok(p),
+            None => Err(CalendarError::IntegerOverflow),
+        }
+    }
+
+    /// Attempt to unpack a date from a single integer
+    fn try_from_packed_i32(p: i32) -> Result<Self, CalendarError> {
+        let year = p.div_euclid(PACKED_YEAR_FACTOR);
+        let md = p.rem_euclid(PACKED_YEAR_FACTOR);
+        let month = (md / PACKED_MONTH_FACTOR) as u8;
+        let day = (md % PACKED_MONTH_FACTOR) as u8;
+        Self::try_from_common_date(CommonDate::new(year, month, day))
+    }
 }
 
+impl<T: FromPrimitive, U: ToFromCommonDate<T>> ToFromPackedDate<T> for U {}
+
+/// Calendar systems in which the time elapsed within a year or month can be calculated
+///
+/// The fractions use the actual lengths of the year and month containing the date, and
+/// include the time of day for moments. The first moment of a year or month is `0.0`,
+/// and the fractions are always less than `1.0`.
+///
+/// This is intended for progress bars and prorating.
+pub trait ElapsedFraction<T: FromPrimitive>: ToFromCommonDate<T> + ToFixed {
+    /// Fraction of the year which has elapsed
+    fn fraction_of_year_elapsed(self) -> f64 {
+        let year = self.year();
+        let start = Self::from_common_date_unchecked(Self::year_start_date(year)).to_fixed();
+        let end = Self::from_common_date_unchecked(Self::year_end_date(year)).to_fixed();
+        let length = end.get() - start.get() + 1.0;
+        (self.to_fixed().get() - start.get()) / length
+    }
+
+    /// Fraction of the month which has elapsed
+    ///
+    /// Returns [`None`] for dates which are not associated with a month.
+    fn fraction_of_month_elapsed(self) -> Option<f64> {
+        //Epagomenae inside a month (such as Aldrin Day) are not counted
+        let d = self.to_common_date();
+        let length = Self::month_length(d.year, self.try_month()?) as f64;
+        let f = self.to_fixed();
+        let time = f.get() - f.to_day().get();
+        Some(((d.day as f64) - 1.0 + time) / length)
+    }
+}
+
+impl<T: FromPrimitive, U: ToFromCommonDate<T> + ToFixed> ElapsedFraction<T> for U {}
+
 /// Calendar systems in which dates which are guaranteed to have a month
 pub trait GuaranteedMonth<T: FromPrimitive + ToPrimitive>: ToFromCommonDate<T> {
     fn month(self) -> T {
@@ -136,6 +455,10 @@ pub trait GuaranteedMonth<T: FromPrimitive + ToPrimitive>: ToFromCommonDate<T> {
 ///
 /// "Epagomenae" are also known as "intercalary days", "blank days" or "monthless days".[^1][^2]
 ///
+/// Perennial calendar systems with epagomenae, such as [`Cotsworth`](crate::calendar::Cotsworth),
+/// [`FrenchRevArith`](crate::calendar::FrenchRevArith) and
+/// [`Tranquility`](crate::calendar::Tranquility), implement both [`Perennial`] and this trait.
+///
 /// [^1]: <https://en.wikipedia.org/wiki/Intercalary_month_(Egypt)>
 /// [^2]: <https://en.wikipedia.org/wiki/Intercalation_(timekeeping)>
 pub trait HasEpagemonae<T: FromPrimitive + ToPrimitive> {
@@ -166,6 +489,71 @@ where
             (_, _) => None,
         }
     }
+
+    /// Calculate the week of month for a particular date
+    ///
+    /// Returns [`None`] for dates which are not associated with a week or a month.
+    fn try_week_of_month(self) -> Option<u8> {
+        match (self.weekday(), self.try_month()) {
+            (Some(_), Some(_)) => Some((self.day() - 1) / Self::days_per_week() + 1),
+            (_, _) => None,
+        }
+    }
+
+    /// Calculate the position of a date within its week of month, starting from 1
+    ///
+    /// Returns [`None`] for dates which are not associated with a week or a month.
+    fn try_day_of_month_week(self) -> Option<u8> {
+        match (self.weekday(), self.try_month()) {
+            (Some(_), Some(_)) => Some((self.day() - 1) % Self::days_per_week() + 1),
+            (_, _) => None,
+        }
+    }
+}
+
+/// Perennial calendar systems in which every date has a week of year
+///
+/// Epagomenae are not part of any week, so [`Perennial::try_week_of_year`] returns
+/// [`None`] for them. This trait gives each epagomenal day the week of year of the closest
+/// preceding day in the same year which is part of a week. For example, Year Day in the
+/// Cotsworth calendar is in the same week as December 28, and Leap Day is in the same
+/// week as June 28. An epagomenal day at the start of a year is in week 1.
+///
+/// ```
+/// use radnelac::calendar::*;
+///
+/// let year_day = Cotsworth::try_year_end(2025).unwrap();
+/// assert_eq!(year_day.try_week_of_year(), None);
+/// assert_eq!(year_day.complementary_week_of_year(), 52);
+/// ```
+pub trait ComplementaryWeekOfYear<S, T>: Perennial<S, T> + ToFixed + FromFixed
+where
+    S: FromPrimitive + ToPrimitive,
+    T: FromPrimitive + ToPrimitive,
+{
+    /// Calculate the week of year for a particular date, including epagomenae
+    fn complementary_week_of_year(self) -> u8 {
+        let year = self.year();
+        let mut d = self;
+        loop {
+            if let Some(w) = d.try_week_of_year() {
+                return w;
+            }
+            let prior = Self::from_fixed(Fixed::new(d.to_fixed().get() - 1.0));
+            if prior.year() != year {
+                return 1;
+            }
+            d = prior;
+        }
+    }
+}
+
+impl<S, T, U> ComplementaryWeekOfYear<S, T> for U
+where
+    S: FromPrimitive + ToPrimitive,
+    T: FromPrimitive + ToPrimitive,
+    U: Perennial<S, T> + ToFixed + FromFixed,
+{
 }
 
 /// Calendar systems in which a year can be divided into quarters
@@ -203,8 +591,127 @@ pub trait CommonWeekOfYear<T: FromPrimitive>: ToFromCommonDate<T> + ToFixed {
         Fixed::cast_new(result)
     }
 
-    //TODO: first_kday (listing 2.34)
-    //TODO: last_kday (listing 2.34)
+    /// Calculate which occurence of its day of the week a date is within its month
+    ///
+    /// For example, this is 4 for the fourth Saturday of July. The first 7 days of a
+    /// month are always the first occurence of their day of the week. This is the
+    /// inverse of [`nth_kday_of_month`](CommonWeekOfYear::nth_kday_of_month).
+    fn week_of_month(self) -> u8 {
+        (self.day() - 1) / 7 + 1
+    }
+
+    /// Calculate the position of a date within its week of month, starting from 1
+    ///
+    /// The weeks are the same as in [`week_of_month`](CommonWeekOfYear::week_of_month),
+    /// so the first day of each month is always 1.
+    fn day_of_month_week(self) -> u8 {
+        (self.day() - 1) % 7 + 1
+    }
+
+    /// Calculate the day of the week on which a particular year starts
+    ///
+    /// This may return an error if the year is 0, and the implementor does not support
+    /// year 0.
+    fn weekday_of_new_year(year: i32) -> Result<Weekday, CalendarError> {
+        Ok(Weekday::from_fixed(Self::try_year_start(year)?.to_fixed()))
+    }
+
+    /// [`true`] if a particular year is not a leap year and starts on a particular day of the week
+    ///
+    /// Perpetual calendars usually have one page for each combination of leap year and
+    /// starting weekday. This can be used to select the correct page.
+    fn is_common_year_starting_on(year: i32, w: Weekday) -> bool
+    where
+        Self: HasLeapYears,
+    {
+        !Self::is_leap(year) && Self::weekday_of_new_year(year).ok() == Some(w)
+    }
+
+    /// [`true`] if a particular year is a leap year and starts on a particular day of the week
+    fn is_leap_year_starting_on(year: i32, w: Weekday) -> bool
+    where
+        Self: HasLeapYears,
+    {
+        Self::is_leap(year) && Self::weekday_of_new_year(year).ok() == Some(w)
+    }
+
+    /// Find the first occurence of a given day of the week on or after this date
+    fn first_kday(self, k: Weekday) -> Fixed {
+        //LISTING 2.34 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
+        //Arguments swapped from the original
+        self.nth_kday(NonZero::new(1).expect("1 is non-zero"), k)
+    }
+
+    /// Find the last occurence of a given day of the week on or before this date
+    fn last_kday(self, k: Weekday) -> Fixed {
+        //LISTING 2.35 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
+        //Arguments swapped from the original
+        self.nth_kday(NonZero::new(-1).expect("-1 is non-zero"), k)
+    }
+
+    /// Find the nth occurence of a given day of the week within a month
+    ///
+    /// If `n` is negative, occurences are counted backwards from the end of the month.
+    /// Returns an error if the month does not have `n` occurences of the day of the week.
+    ///
+    /// ```
+    /// use radnelac::calendar::*;
+    /// use radnelac::day_cycle::*;
+    /// use std::num::NonZero;
+    ///
+    /// let labour_day = Gregorian::nth_kday_of_month(
+    ///     2024,
+    ///     GregorianMonth::September,
+    ///     NonZero::new(1).unwrap(),
+    ///     Weekday::Monday,
+    /// );
+    /// assert_eq!(labour_day.unwrap().day(), 2);
+    /// let memorial_day = Gregorian::last_kday_of_month(2024, GregorianMonth::May, Weekday::Monday);
+    /// assert_eq!(memorial_day.unwrap().day(), 27);
+    /// ```
+    fn nth_kday_of_month(
+        year: i32,
+        month: T,
+        nz: NonZero<i16>,
+        k: Weekday,
+    ) -> Result<Self, CalendarError>
+    where
+        T: ToPrimitive,
+        Self: FromFixed,
+    {
+        let m = month.to_u8().ok_or(CalendarError::InvalidMonth)?;
+        let length = Self::month_length(year, month);
+        let start = Self::try_from_common_date(CommonDate::new(year, m, 1))?;
+        let end = Self::try_from_common_date(CommonDate::new(year, m, length))?;
+        let result = if nz.get() > 0 {
+            start.nth_kday(nz, k)
+        } else {
+            end.nth_kday(nz, k)
+        };
+        if result < start.to_fixed() || result > end.to_fixed() {
+            Err(CalendarError::InvalidWeek)
+        } else {
+            Ok(Self::from_fixed(result))
+        }
+    }
+
+    /// Find the first occurence of a given day of the week within a month
+    fn first_kday_of_month(year: i32, month: T, k: Weekday) -> Result<Self, CalendarError>
+    where
+        T: ToPrimitive,
+        Self: FromFixed,
+    {
+        Self::nth_kday_of_month(year, month, NonZero::new(1).expect("1 is non-zero"), k)
+    }
+
+    /// Find the last occurence of a given day of the week within a month
+    fn last_kday_of_month(year: i32, month: T, k: Weekday) -> Result<Self, CalendarError>
+    where
+        T: ToPrimitive,
+        Self: FromFixed,
+    {
+        Self::nth_kday_of_month(year, month, NonZero::new(-1).expect("-1 is non-zero"), k)
+    }
 }
 
 /// Represents a numeric year and day of year.
@@ -216,6 +723,83 @@ pub struct OrdinalDate {
     pub day_of_year: u16,
 }
 
+//Coligny years with an intercalary month are the longest of any supported calendar
+const MAX_DAY_OF_YEAR: u16 = 385;
+
+impl OrdinalDate {
+    /// Split into a tuple of year and day of year
+    ///
+    /// ```
+    /// use radnelac::calendar::*;
+    ///
+    /// let ord = OrdinalDate::try_from((2025, 135)).unwrap();
+    /// let (year, day_of_year) = ord.into_tuple();
+    /// assert_eq!((year, day_of_year), (2025, 135));
+    /// assert_eq!(<(i32, u16)>::from(ord), (2025, 135));
+    /// ```
+    pub const fn into_tuple(self) -> (i32, u16) {
+        (self.year, self.day_of_year)
+    }
+
+    fn shift<T: ToFromOrdinalDate + ToFixed>(
+        self,
+        days: f64,
+    ) -> Result<OrdinalDate, CalendarError> {
+        let f = T::try_from_ordinal(self)?.to_fixed();
+        let result = Fixed::new(f.get() + days);
+        Fixed::almost_in_effective_bounds(result.get(), 0.0)?;
+        Ok(T::ordinal_from_fixed(result))
+    }
+
+    /// The next day of year in a specific calendar system
+    ///
+    /// The year is incremented after the last day of the year.
+    /// Returns an error if `self` is not valid in the calendar system.
+    ///
+    /// ```
+    /// use radnelac::calendar::*;
+    ///
+    /// let ord = OrdinalDate { year: 2024, day_of_year: 366 };
+    /// let next = ord.succ::<Gregorian>().unwrap();
+    /// assert_eq!(next, OrdinalDate { year: 2025, day_of_year: 1 });
+    /// assert_eq!(next.pred::<Gregorian>().unwrap(), ord);
+    /// ```
+    pub fn succ<T: ToFromOrdinalDate + ToFixed>(self) -> Result<OrdinalDate, CalendarError> {
+        self.shift::<T>(1.0)
+    }
+
+    /// The previous day of year in a specific calendar system
+    ///
+    /// The year is decremented before the first day of the year.
+    /// Returns an error if `self` is not valid in the calendar system.
+    pub fn pred<T: ToFromOrdinalDate + ToFixed>(self) -> Result<OrdinalDate, CalendarError> {
+        self.shift::<T>(-1.0)
+    }
+}
+
+impl TryFrom<(i32, u16)> for OrdinalDate {
+    type Error = CalendarError;
+
+    /// Create an [`OrdinalDate`] from a year and day of year
+    ///
+    /// This only rejects days of year which are invalid in every supported calendar system.
+    /// Use [`ToFromOrdinalDate::valid_ordinal`] for checks specific to one calendar system.
+    fn try_from(value: (i32, u16)) -> Result<OrdinalDate, CalendarError> {
+        let (year, day_of_year) = value;
+        if !(1..=MAX_DAY_OF_YEAR).contains(&day_of_year) {
+            Err(CalendarError::InvalidDayOfYear)
+        } else {
+            Ok(OrdinalDate { year, day_of_year })
+        }
+    }
+}
+
+impl From<OrdinalDate> for (i32, u16) {
+    fn from(value: OrdinalDate) -> (i32, u16) {
+        value.into_tuple()
+    }
+}
+
 /// Calendar systems in which a date can be represented by a year and day of year
 pub trait ToFromOrdinalDate: Sized {
     /// Check if the year and day of year is valid for a particular calendar system
@@ -230,9 +814,57 @@ pub trait ToFromOrdinalDate: Sized {
     fn from_ordinal_unchecked(ord: OrdinalDate) -> Self;
     /// Attempt to create a date in a specific calendar from an [`OrdinalDate`]
     fn try_from_ordinal(ord: OrdinalDate) -> Result<Self, CalendarError> {
+        conversion_trace!(
+            calendar = std::any::type_name::<Self>(),
+            year = ord.year,
+            day_of_year = ord.day_of_year,
+            "try_from_ordinal"
+        );
         match Self::valid_ordinal(ord) {
             Err(e) => Err(e),
             Ok(_) => Ok(Self::from_ordinal_unchecked(ord)),
         }
     }
+    /// Attempt to create a date in a specific calendar from a year and day of year
+    ///
+    /// This is the same as [`try_from_ordinal`](ToFromOrdinalDate::try_from_ordinal),
+    /// and is convenient for data formats which identify a day by its day of year.
+    ///
+    /// ```
+    /// use radnelac::calendar::*;
+    ///
+    /// let d = Gregorian::try_from_year_day(2024, 366).unwrap();
+    /// assert_eq!(d, Gregorian::try_new(2024, GregorianMonth::December, 31).unwrap());
+    /// assert!(Gregorian::try_from_year_day(2025, 366).is_err());
+    /// assert!(Gregorian::try_from_year_day(2025, 0).is_err());
+    /// ```
+    fn try_from_year_day(year: i32, day_of_year: u16) -> Result<Self, CalendarError> {
+        Self::try_from_ordinal(OrdinalDate { year, day_of_year })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "ancient-calendars")]
+    use crate::calendar::Armenian;
+    use crate::calendar::Gregorian;
+    #[cfg(feature = "reform-calendars")]
+    use crate::calendar::Holocene;
+    use crate::calendar::IslamicArith;
+
+    fn within_default<S: FromPrimitive, T: ToFromCommonDate<S>>() {
+        assert!(DEFAULT_EFFECTIVE_YEAR_MIN <= T::EFFECTIVE_YEAR_MIN);
+        assert!(DEFAULT_EFFECTIVE_YEAR_MAX >= T::EFFECTIVE_YEAR_MAX);
+    }
+
+    #[test]
+    fn default_effective_years() {
+        #[cfg(feature = "ancient-calendars")]
+        within_default::<_, Armenian>();
+        within_default::<_, Gregorian>();
+        #[cfg(feature = "reform-calendars")]
+        within_default::<_, Holocene>();
+        within_default::<_, IslamicArith>();
+    }
 }
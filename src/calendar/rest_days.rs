@@ -0,0 +1,130 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use crate::day_cycle::Weekday;
+
+/// Represents a set of days of the common week which are days of rest
+///
+/// This can be used to override the rest days of a calendar, for example to use a
+/// Friday and Saturday weekend with the Gregorian calendar.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+/// use radnelac::day_cycle::*;
+///
+/// let d = Gregorian::try_new(2025, GregorianMonth::July, 25).unwrap();
+/// assert_eq!(d.convert::<Weekday>(), Weekday::Friday);
+/// assert!(!RestWeekdays::SATURDAY_SUNDAY.contains_date(d));
+/// assert!(RestWeekdays::FRIDAY_SATURDAY.contains_date(d));
+/// let custom = RestWeekdays::new(&[Weekday::Thursday, Weekday::Friday]);
+/// assert!(custom.contains(Weekday::Thursday));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct RestWeekdays(u8);
+
+impl RestWeekdays {
+    /// Saturday and Sunday
+    pub const SATURDAY_SUNDAY: RestWeekdays =
+        RestWeekdays::new(&[Weekday::Saturday, Weekday::Sunday]);
+    /// Friday and Saturday
+    pub const FRIDAY_SATURDAY: RestWeekdays =
+        RestWeekdays::new(&[Weekday::Friday, Weekday::Saturday]);
+    /// Friday only
+    pub const FRIDAY: RestWeekdays = RestWeekdays::new(&[Weekday::Friday]);
+    /// Sunday only
+    pub const SUNDAY: RestWeekdays = RestWeekdays::new(&[Weekday::Sunday]);
+
+    /// Create a set of rest days
+    pub const fn new(days: &[Weekday]) -> RestWeekdays {
+        let mut mask = 0;
+        let mut i = 0;
+        while i < days.len() {
+            mask |= 1 << (days[i] as u8);
+            i += 1;
+        }
+        RestWeekdays(mask)
+    }
+
+    /// [`true`] if the day of the week is a rest day
+    pub const fn contains(self, w: Weekday) -> bool {
+        self.0 & (1 << (w as u8)) != 0
+    }
+
+    /// [`true`] if the date falls on a rest day of the week
+    pub fn contains_date<T: ToFixed>(self, date: T) -> bool {
+        self.contains(Weekday::from_fixed(date.to_fixed()))
+    }
+}
+
+/// Calendar systems with customary days of rest
+///
+/// The default rest days are Saturday and Sunday of the common week. Calendars with their
+/// own weeks, such as the French Revolutionary calendar, override
+/// [`is_rest_day`](RestDays::is_rest_day). Use [`RestWeekdays`] to apply different
+/// rest days.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// let d = Gregorian::try_new(2025, GregorianMonth::July, 26).unwrap();
+/// assert!(d.is_weekend());
+/// assert!(d.is_rest_day());
+///
+/// let f = d.convert::<FrenchRevArith<true>>();
+/// assert_eq!(f.weekday(), Some(FrenchRevWeekday::Octidi));
+/// assert!(!f.is_rest_day());
+/// assert!(f.is_weekend());
+/// ```
+pub trait RestDays: ToFixed + Sized {
+    /// Days of the common week which are rest days
+    const REST_WEEKDAYS: RestWeekdays = RestWeekdays::SATURDAY_SUNDAY;
+
+    /// [`true`] if the date is a customary day of rest in this calendar
+    fn is_rest_day(self) -> bool {
+        Self::REST_WEEKDAYS.contains_date(self)
+    }
+
+    /// [`true`] if the date is a Saturday or Sunday of the common week
+    ///
+    /// This does not depend on the calendar.
+    fn is_weekend(self) -> bool {
+        RestWeekdays::SATURDAY_SUNDAY.contains_date(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_count::BoundedDayCount;
+    use crate::day_count::RataDie;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use num_traits::FromPrimitive;
+    use proptest::proptest;
+
+    #[test]
+    fn masks() {
+        for i in 0..7 {
+            let w = Weekday::from_u8(i).unwrap();
+            assert!(RestWeekdays::new(&[w]).contains(w));
+            assert!(!RestWeekdays::default().contains(w));
+            let expected = w == Weekday::Saturday || w == Weekday::Sunday;
+            assert_eq!(RestWeekdays::SATURDAY_SUNDAY.contains(w), expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn contains_date(t in FIXED_MIN..FIXED_MAX) {
+            let f = RataDie::new(t);
+            let w = Weekday::from_fixed(f.to_fixed());
+            assert!(RestWeekdays::new(&[w]).contains_date(f));
+            assert_eq!(RestWeekdays::SUNDAY.contains_date(f), w == Weekday::Sunday);
+        }
+    }
+}
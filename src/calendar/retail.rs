@@ -0,0 +1,389 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::gregorian::Gregorian;
+use crate::calendar::gregorian::GregorianMonth;
+use crate::calendar::prelude::CommonDate;
+use crate::calendar::prelude::CommonWeekOfYear;
+use crate::calendar::prelude::GuaranteedMonth;
+use crate::calendar::prelude::HasLeapYears;
+use crate::calendar::prelude::Quarter;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::AllowYearZero;
+use crate::calendar::CalendarMoment;
+use crate::calendar::LeapWeekCalendar;
+use crate::calendar::OrdinalDate;
+use crate::calendar::ToFromOrdinalDate;
+use crate::common::error::CalendarError;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::CalculatedBounds;
+use crate::day_count::Epoch;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use crate::day_cycle::OnOrBefore;
+use crate::day_cycle::Weekday;
+use std::num::NonZero;
+
+#[allow(unused_imports)] //FromPrimitive is needed for derive
+use num_traits::FromPrimitive;
+use std::fmt;
+
+/// Represents a period (month) of a retail calendar
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, FromPrimitive, ToPrimitive)]
+pub enum RetailPeriod {
+    P1 = 1,
+    P2,
+    P3,
+    P4,
+    P5,
+    P6,
+    P7,
+    P8,
+    P9,
+    P10,
+    P11,
+    /// Has an extra week in leap years
+    P12,
+}
+
+/// Represents a date in one of the retail (52/53 week) calendars
+///
+/// ## Introduction
+///
+/// Retail calendars (also called 4-4-5 calendars or 52/53 week calendars) are used by many
+/// retailers and other businesses for accounting purposes. Every period is a whole number
+/// of weeks, so that periods can be compared without adjusting for the number of weekends.
+///
+/// ## Basic Structure
+///
+/// Years are divided into 4 quarters of 13 weeks each. Each quarter is divided into 3 periods.
+/// Depending on the variant, the periods in a quarter have lengths of 4, 4 and 5 weeks,
+/// 4, 5 and 4 weeks, or 5, 4 and 4 weeks.
+///
+/// Each year ends on a particular weekday near the end of a particular Gregorian month,
+/// called the year-end anchor. A year usually has 52 weeks, but a "leap year" has 53 weeks.
+/// The extra week is added to the end of the final period.
+///
+/// ### Variants
+///
+/// The type parameters select the variant:
+///
+/// | Parameter | Meaning                                                           |
+/// |-----------|-------------------------------------------------------------------|
+/// | `P`       | Weeks per period in a quarter, must be `445`, `454` or `544`      |
+/// | `M`       | Gregorian month of the year-end anchor, in the range 1..12        |
+/// | `W`       | Weekday on which the year ends, using the numbering of [`Weekday`] |
+/// | `N`       | [`true`] for nearest to the end of the month, [`false`] for last in the month |
+///
+/// For example, the calendar of the National Retail Federation uses the 4-5-4 pattern
+/// and ends each year on the Saturday nearest to January 31. This is [`Retail454`].
+///
+/// ## Epoch
+///
+/// Years are numbered after the Gregorian year in which most of their days fall. If the
+/// year-end anchor is in the first half of the Gregorian year, a retail year ends in the
+/// Gregorian year *after* the one it is numbered after.
+///
+/// ## Representation and Examples
+///
+/// The periods are represented in this crate as [`RetailPeriod`].
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// let r = Retail454::try_new(2025, RetailPeriod::P1, 1).unwrap();
+/// let g = r.convert::<Gregorian>();
+/// assert_eq!(g, Gregorian::try_new(2025, GregorianMonth::February, 2).unwrap());
+/// ```
+///
+/// ## Further reading
+/// + [Wikipedia](https://en.wikipedia.org/wiki/4%E2%80%934%E2%80%935_calendar)
+/// + [National Retail Federation](https://nrf.com/resources/4-5-4-calendar)
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
+pub struct Retail<const P: u16, const M: u8, const W: u8, const N: bool>(CommonDate);
+
+impl<const P: u16, const M: u8, const W: u8, const N: bool> fmt::Debug for Retail<P, M, W, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<RetailPeriod>("Retail", f)
+    }
+}
+
+/// Retail calendar with 4-4-5 week periods, ending on the Saturday nearest January 31
+///
+/// See [Retail] for more details.
+pub type Retail445 = Retail<445, 1, 6, true>;
+/// Retail calendar with 4-5-4 week periods, ending on the Saturday nearest January 31
+///
+/// This is the calendar of the National Retail Federation. See [Retail] for more details.
+pub type Retail454 = Retail<454, 1, 6, true>;
+/// Retail calendar with 5-4-4 week periods, ending on the Saturday nearest January 31
+///
+/// See [Retail] for more details.
+pub type Retail544 = Retail<544, 1, 6, true>;
+
+impl<const P: u16, const M: u8, const W: u8, const N: bool> Retail<P, M, W, N> {
+    fn weeks_per_period_in_quarter() -> [u8; 3] {
+        match P {
+            445 => [4, 4, 5],
+            454 => [4, 5, 4],
+            544 => [5, 4, 4],
+            _ => panic!("P must be 445, 454 or 544 for Retail"),
+        }
+    }
+
+    /// Returns the number of weeks in a period of a retail year
+    pub fn weeks_in_period(year: i32, period: RetailPeriod) -> u8 {
+        let p = period as u8;
+        let extra = if period == RetailPeriod::P12 && Self::is_leap(year) {
+            1
+        } else {
+            0
+        };
+        Self::weeks_per_period_in_quarter()[((p - 1) % 3) as usize] + extra
+    }
+
+    fn days_before_period(period: u8) -> u16 {
+        let full_quarters = ((period - 1) / 3) as u16;
+        let weeks = Self::weeks_per_period_in_quarter();
+        let partial: u16 = weeks[0..((period - 1) % 3) as usize]
+            .iter()
+            .map(|w| *w as u16)
+            .sum();
+        (91 * full_quarters) + (7 * partial)
+    }
+
+    /// Returns the fixed day number of the last day of a retail year
+    pub fn year_end_day_unchecked(year: i32) -> i64 {
+        let m = GregorianMonth::from_u8(M).expect("M must be 1..12 for Retail");
+        let w = Weekday::from_u8(W).expect("W must be 0..6 for Retail");
+        let g_year = if M <= 6 { year + 1 } else { year };
+        let month_end = CommonDate::new(g_year, M, Gregorian::month_length(g_year, m));
+        let end = Gregorian::from_common_date_unchecked(month_end).to_fixed();
+        let result = if N {
+            w.nearest(end)
+        } else {
+            w.on_or_before(end)
+        };
+        result.get_day_i()
+    }
+
+    fn year_from_fixed(date: i64) -> (i32, i64) {
+        let g_year = Gregorian::ordinal_from_fixed(Fixed::cast_new(date)).year;
+        let approx = if M <= 6 { g_year - 1 } else { g_year };
+        let prior_end = Self::year_end_day_unchecked(approx - 1);
+        if date <= prior_end {
+            (approx - 1, Self::year_end_day_unchecked(approx - 2) + 1)
+        } else if date > Self::year_end_day_unchecked(approx) {
+            (approx + 1, Self::year_end_day_unchecked(approx) + 1)
+        } else {
+            (approx, prior_end + 1)
+        }
+    }
+}
+
+impl<const P: u16, const M: u8, const W: u8, const N: bool> AllowYearZero for Retail<P, M, W, N> {}
+
+impl<const P: u16, const M: u8, const W: u8, const N: bool> ToFromOrdinalDate
+    for Retail<P, M, W, N>
+{
+    fn valid_ordinal(ord: OrdinalDate) -> Result<(), CalendarError> {
+        Self::valid_leap_week_ordinal(ord)
+    }
+
+    fn ordinal_from_fixed(fixed_date: Fixed) -> OrdinalDate {
+        Self::leap_week_ordinal(fixed_date)
+    }
+
+    fn to_ordinal(self) -> OrdinalDate {
+        OrdinalDate {
+            year: self.0.year,
+            day_of_year: Self::days_before_period(self.0.month) + (self.0.day as u16),
+        }
+    }
+
+    fn from_ordinal_unchecked(ord: OrdinalDate) -> Self {
+        let weeks_before = (ord.day_of_year - 1) / 7;
+        let quarter = (weeks_before / 13).min(3) as u8;
+        let mut period = (quarter * 3) + 1;
+        while period < 12 && Self::days_before_period(period + 1) < ord.day_of_year {
+            period += 1;
+        }
+        let day = (ord.day_of_year - Self::days_before_period(period)) as u8;
+        Self(CommonDate::new(ord.year, period, day))
+    }
+}
+
+impl<const P: u16, const M: u8, const W: u8, const N: bool> HasLeapYears for Retail<P, M, W, N> {
+    fn is_leap(year: i32) -> bool {
+        let diff = Self::year_end_day_unchecked(year) - Self::year_end_day_unchecked(year - 1);
+        diff == 371
+    }
+}
+
+impl<const P: u16, const M: u8, const W: u8, const N: bool> LeapWeekCalendar
+    for Retail<P, M, W, N>
+{
+    fn year_start_day(year: i32) -> i64 {
+        Self::year_end_day_unchecked(year - 1) + 1
+    }
+
+    fn year_and_start(date: i64) -> (i32, i64) {
+        Self::year_from_fixed(date)
+    }
+}
+
+impl<const P: u16, const M: u8, const W: u8, const N: bool> CalculatedBounds
+    for Retail<P, M, W, N>
+{
+}
+
+impl<const P: u16, const M: u8, const W: u8, const N: bool> Epoch for Retail<P, M, W, N> {
+    fn epoch() -> Fixed {
+        Fixed::cast_new(Self::year_end_day_unchecked(0) + 1)
+    }
+}
+
+impl<const P: u16, const M: u8, const W: u8, const N: bool> FromFixed for Retail<P, M, W, N> {
+    fn from_fixed(fixed_date: Fixed) -> Retail<P, M, W, N> {
+        let ord = Self::ordinal_from_fixed(fixed_date);
+        Self::from_ordinal_unchecked(ord)
+    }
+}
+
+impl<const P: u16, const M: u8, const W: u8, const N: bool> ToFixed for Retail<P, M, W, N> {
+    fn to_fixed(self) -> Fixed {
+        let doy = self.to_ordinal().day_of_year as i64;
+        Fixed::cast_new(Self::year_start_day(self.0.year) + doy - 1)
+    }
+}
+
+impl<const P: u16, const M: u8, const W: u8, const N: bool> ToFromCommonDate<RetailPeriod>
+    for Retail<P, M, W, N>
+{
+    const EFFECTIVE_YEAR_MIN: i32 = -47013916;
+    const EFFECTIVE_YEAR_MAX: i32 = 47013916;
+
+    fn to_common_date(self) -> CommonDate {
+        self.0
+    }
+
+    fn from_common_date_unchecked(date: CommonDate) -> Self {
+        debug_assert!(Self::valid_ymd(date).is_ok());
+        Self(date)
+    }
+
+    fn valid_ymd(date: CommonDate) -> Result<(), CalendarError> {
+        match RetailPeriod::from_u8(date.month) {
+            None => Err(CalendarError::InvalidMonth),
+            Some(m) if date.day < 1 || date.day > Self::month_length(date.year, m) => {
+                Err(CalendarError::InvalidDay)
+            }
+            Some(_) => Ok(()),
+        }
+    }
+
+    fn year_end_date(year: i32) -> CommonDate {
+        let m = RetailPeriod::P12;
+        CommonDate::new(year, m as u8, Self::month_length(year, m))
+    }
+
+    fn month_length(year: i32, month: RetailPeriod) -> u8 {
+        7 * Self::weeks_in_period(year, month)
+    }
+}
+
+impl<const P: u16, const M: u8, const W: u8, const N: bool> Quarter for Retail<P, M, W, N> {
+    fn quarter(self) -> NonZero<u8> {
+        NonZero::new(((self.0.month - 1) / 3) + 1).expect("(m-1)/3 > -1")
+    }
+}
+
+impl<const P: u16, const M: u8, const W: u8, const N: bool> GuaranteedMonth<RetailPeriod>
+    for Retail<P, M, W, N>
+{
+}
+impl<const P: u16, const M: u8, const W: u8, const N: bool> CommonWeekOfYear<RetailPeriod>
+    for Retail<P, M, W, N>
+{
+}
+
+/// Represents a date *and time* in the 4-4-5 Retail Calendar
+pub type Retail445Moment = CalendarMoment<Retail445>;
+
+/// Represents a date *and time* in the 4-5-4 Retail Calendar
+pub type Retail454Moment = CalendarMoment<Retail454>;
+
+/// Represents a date *and time* in the 5-4-4 Retail Calendar
+pub type Retail544Moment = CalendarMoment<Retail544>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_count::FIXED_MAX;
+    use proptest::proptest;
+    const MAX_YEARS: i32 = (FIXED_MAX / 365.25) as i32;
+
+    #[test]
+    fn nrf_years() {
+        // https://nrf.com/resources/4-5-4-calendar
+        let list = [
+            (2022, CommonDate::new(2022, 1, 30), false),
+            (2023, CommonDate::new(2023, 1, 29), true),
+            (2024, CommonDate::new(2024, 2, 4), false),
+            (2025, CommonDate::new(2025, 2, 2), false),
+        ];
+        for item in list {
+            let r = Retail454::try_year_start(item.0).unwrap();
+            let g = r.convert::<Gregorian>();
+            assert_eq!(g.to_common_date(), item.1);
+            assert_eq!(Retail454::is_leap(item.0), item.2);
+        }
+    }
+
+    #[test]
+    fn days_before_period() {
+        assert_eq!(Retail445::days_before_period(1), 0);
+        assert_eq!(Retail445::days_before_period(3), 56);
+        assert_eq!(Retail454::days_before_period(3), 63);
+        assert_eq!(Retail544::days_before_period(3), 63);
+        assert_eq!(Retail445::days_before_period(12), 329);
+        assert_eq!(Retail454::days_before_period(12), 336);
+        assert_eq!(Retail544::days_before_period(12), 336);
+    }
+
+    #[test]
+    fn last_saturday_of_december() {
+        type R = Retail<445, 12, 6, false>;
+        let r = R::try_year_end(2025).unwrap();
+        let g = r.convert::<Gregorian>();
+        assert_eq!(g.to_common_date(), CommonDate::new(2025, 12, 27));
+    }
+
+    proptest! {
+        #[test]
+        fn year_ends_on_anchor(year in -MAX_YEARS..MAX_YEARS) {
+            let r = Retail454::try_year_end(year).unwrap();
+            assert_eq!(r.convert::<Weekday>(), Weekday::Saturday);
+            let r = Retail::<544, 9, 0, false>::try_year_end(year).unwrap();
+            assert_eq!(r.convert::<Weekday>(), Weekday::Sunday);
+            let g = r.convert::<Gregorian>();
+            assert_eq!(g.month(), GregorianMonth::September);
+            assert!(g.day() > 23);
+        }
+
+        #[test]
+        fn period_lengths(year in -MAX_YEARS..MAX_YEARS) {
+            let r0 = Retail445::try_year_start(year).unwrap().to_fixed();
+            let r1 = Retail445::try_year_start(year + 1).unwrap().to_fixed();
+            let mut total: i64 = 0;
+            for p in 1..13 {
+                let period = RetailPeriod::from_u8(p).unwrap();
+                total += Retail445::month_length(year, period) as i64;
+            }
+            assert_eq!(total, r1.get_day_i() - r0.get_day_i());
+        }
+    }
+}
@@ -8,6 +8,8 @@ use crate::calendar::prelude::CommonDate;
 use crate::calendar::prelude::HasLeapYears;
 use crate::calendar::prelude::Quarter;
 use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::prelude::YearNumbering;
+use crate::calendar::YearZeroPolicy;
 use crate::common::math::TermNum;
 use crate::day_count::BoundedDayCount;
 use crate::day_count::CalculatedBounds;
@@ -156,6 +158,10 @@ impl PartialOrd for Roman {
 
 impl CalculatedBounds for Roman {}
 
+impl YearNumbering for Roman {
+    const YEAR_ZERO: YearZeroPolicy = YearZeroPolicy::Skipped;
+}
+
 impl FromFixed for Roman {
     fn from_fixed(date: Fixed) -> Roman {
         //LISTING 3.11 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
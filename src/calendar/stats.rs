@@ -0,0 +1,205 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::prelude::CommonDate;
+use crate::calendar::prelude::HasLeapYears;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use crate::day_cycle::Weekday;
+use num_traits::FromPrimitive;
+use std::ops::RangeInclusive;
+
+/// Number of times each day of the common week occurs in a sample of dates
+///
+/// ## Representation and Examples
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_cycle::*;
+///
+/// // The 13th of the month is slightly more likely to be a Friday than any other weekday
+/// let d = day_of_month_weekdays::<GregorianMonth, Gregorian>(2000..=2399, 13);
+/// assert_eq!(d.count(Weekday::Friday), 688);
+/// assert_eq!(d.total(), 4800);
+/// assert_eq!(d.most_common(), Weekday::Friday);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct WeekdayDistribution([u32; 7]);
+
+impl WeekdayDistribution {
+    fn add(&mut self, w: Weekday) {
+        self.0[w as usize] += 1;
+    }
+
+    /// Number of dates in the sample which fall on `w`
+    pub fn count(self, w: Weekday) -> u32 {
+        self.0[w as usize]
+    }
+
+    /// Number of dates in the sample
+    pub fn total(self) -> u32 {
+        self.0.iter().sum()
+    }
+
+    /// Weekday with the highest count
+    ///
+    /// Ties are broken in favour of the earliest weekday, starting from Sunday.
+    pub fn most_common(self) -> Weekday {
+        let mut result = 0;
+        for i in 1..self.0.len() {
+            if self.0[i] > self.0[result] {
+                result = i;
+            }
+        }
+        Weekday::from_usize(result).expect("Index within week")
+    }
+
+    /// Fraction of the sample which falls on `w`
+    ///
+    /// Returns [`None`] if the sample is empty.
+    pub fn frequency(self, w: Weekday) -> Option<f64> {
+        match self.total() {
+            0 => None,
+            t => Some((self.count(w) as f64) / (t as f64)),
+        }
+    }
+}
+
+/// Weekdays of a specific day of each month, for every month in a range of years
+///
+/// Days which are not associated with a month (such as complementary days) are not counted.
+/// Months which are too short to contain `day` are skipped.
+pub fn day_of_month_weekdays<S, T>(years: RangeInclusive<i32>, day: u8) -> WeekdayDistribution
+where
+    S: FromPrimitive,
+    T: ToFromCommonDate<S> + ToFixed,
+{
+    let mut result = WeekdayDistribution::default();
+    for year in years {
+        for month in 0..=u8::MAX {
+            match T::try_from_common_date(CommonDate::new(year, month, day)) {
+                Ok(d) if d.try_month().is_some() => result.add(Weekday::from_fixed(d.to_fixed())),
+                _ => (),
+            }
+        }
+    }
+    result
+}
+
+/// Weekdays of the first day of each month, for every month in a range of years
+pub fn month_start_weekdays<S, T>(years: RangeInclusive<i32>) -> WeekdayDistribution
+where
+    S: FromPrimitive,
+    T: ToFromCommonDate<S> + ToFixed,
+{
+    day_of_month_weekdays::<S, T>(years, 1)
+}
+
+/// Weekdays of the first day of each year in a range of years
+pub fn year_start_weekdays<S, T>(years: RangeInclusive<i32>) -> WeekdayDistribution
+where
+    S: FromPrimitive,
+    T: ToFromCommonDate<S> + ToFixed,
+{
+    let mut result = WeekdayDistribution::default();
+    for year in years {
+        if let Ok(d) = T::try_year_start(year) {
+            result.add(Weekday::from_fixed(d.to_fixed()));
+        }
+    }
+    result
+}
+
+/// Fraction of years in a range which are leap years
+///
+/// Returns [`None`] if the range is empty.
+///
+/// ```
+/// use radnelac::calendar::*;
+///
+/// assert_eq!(leap_year_density::<Gregorian>(1..=400), Some(0.2425));
+/// assert_eq!(leap_year_density::<Julian>(1..=400), Some(0.25));
+/// ```
+pub fn leap_year_density<T: HasLeapYears>(years: RangeInclusive<i32>) -> Option<f64> {
+    let mut total: u32 = 0;
+    let mut leap: u32 = 0;
+    for year in years {
+        total += 1;
+        if T::is_leap(year) {
+            leap += 1;
+        }
+    }
+    match total {
+        0 => None,
+        t => Some((leap as f64) / (t as f64)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "reform-calendars")]
+    use crate::calendar::Cotsworth;
+    #[cfg(feature = "reform-calendars")]
+    use crate::calendar::CotsworthMonth;
+    use crate::calendar::Gregorian;
+    use crate::calendar::GregorianMonth;
+    use crate::calendar::Julian;
+    use crate::calendar::JulianMonth;
+    #[cfg(feature = "reform-calendars")]
+    use crate::calendar::Positivist;
+    #[cfg(feature = "reform-calendars")]
+    use crate::calendar::PositivistMonth;
+
+    #[test]
+    fn gregorian_cycle() {
+        let d = day_of_month_weekdays::<GregorianMonth, Gregorian>(1..=400, 13);
+        let expected = [687, 685, 685, 687, 684, 688, 684];
+        for (i, e) in expected.iter().enumerate() {
+            assert_eq!(d.count(Weekday::from_usize(i).unwrap()), *e);
+        }
+        let s = month_start_weekdays::<GregorianMonth, Gregorian>(1..=400);
+        assert_eq!(s.count(Weekday::Sunday), 688);
+        assert_eq!(s.most_common(), Weekday::Sunday);
+        let y = year_start_weekdays::<GregorianMonth, Gregorian>(1..=400);
+        assert_eq!(y.total(), 400);
+        assert_eq!(y.count(Weekday::Sunday), 58);
+    }
+
+    #[test]
+    fn julian_cycle() {
+        let d = day_of_month_weekdays::<JulianMonth, Julian>(1..=28, 13);
+        assert_eq!(d.total(), 28 * 12);
+        for i in 0..7 {
+            assert_eq!(d.count(Weekday::from_usize(i).unwrap()), 48);
+        }
+        assert_eq!(d.frequency(Weekday::Friday), Some(1.0 / 7.0));
+    }
+
+    #[test]
+    #[cfg(feature = "reform-calendars")]
+    fn perennial() {
+        // Every month is 28 days long and the complementary days are at the end of the year,
+        // so the 13th always falls on the same common weekday within a year.
+        for year in 1..=100 {
+            let d = day_of_month_weekdays::<PositivistMonth, Positivist>(year..=year, 13);
+            assert_eq!(d.total(), 13);
+            assert_eq!(d.count(d.most_common()), 13);
+        }
+        let s = month_start_weekdays::<CotsworthMonth, Cotsworth>(1..=10);
+        assert_eq!(s.total(), 130);
+    }
+
+    #[test]
+    fn empty() {
+        let years = RangeInclusive::new(1, 0);
+        let d = day_of_month_weekdays::<GregorianMonth, Gregorian>(years.clone(), 13);
+        assert_eq!(d.total(), 0);
+        assert_eq!(d.frequency(Weekday::Friday), None);
+        assert_eq!(leap_year_density::<Gregorian>(years), None);
+        let d = day_of_month_weekdays::<GregorianMonth, Gregorian>(2024..=2024, 31);
+        assert_eq!(d.total(), 7);
+    }
+}
@@ -3,6 +3,8 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use crate::calendar::gregorian::Gregorian;
+use crate::calendar::month_table::cumulative_lengths;
+use crate::calendar::month_table::MonthTable;
 use crate::calendar::prelude::CommonDate;
 use crate::calendar::prelude::CommonWeekOfYear;
 use crate::calendar::prelude::GuaranteedMonth;
@@ -11,6 +13,7 @@ use crate::calendar::prelude::Quarter;
 use crate::calendar::prelude::ToFromCommonDate;
 use crate::calendar::AllowYearZero;
 use crate::calendar::CalendarMoment;
+use crate::calendar::LeapWeekCalendar;
 use crate::calendar::OrdinalDate;
 use crate::calendar::ToFromOrdinalDate;
 use crate::common::error::CalendarError;
@@ -26,6 +29,7 @@ use crate::common::math::TermNum;
 
 #[allow(unused_imports)] //FromPrimitive is needed for derive
 use num_traits::FromPrimitive;
+use std::fmt;
 
 #[allow(non_snake_case)]
 struct SymmetryParams {
@@ -169,9 +173,15 @@ pub enum SymmetryMonth {
 /// ## Further reading
 /// + Dr. Irvin L. Bromberg
 ///   + [*Basic Symmetry454 and Symmetry010 Calendar Arithmetic*](https://kalendis.free.nf/Symmetry454-Arithmetic.pdf)
-#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
 pub struct Symmetry<const T: bool, const U: bool>(CommonDate);
 
+impl<const T: bool, const U: bool> fmt::Debug for Symmetry<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<SymmetryMonth>("Symmetry", f)
+    }
+}
+
 /// Symmetry454 calendar with 293 year leap rule
 ///
 /// See [Symmetry] for more details.
@@ -279,26 +289,13 @@ impl<const T: bool, const U: bool> AllowYearZero for Symmetry<T, U> {}
 impl<const T: bool, const U: bool> ToFromOrdinalDate for Symmetry<T, U> {
     fn valid_ordinal(ord: OrdinalDate) -> Result<(), CalendarError> {
         // Not described by Dr. Bromberg
-        let new_year_0 = Self::new_year_day_unchecked(ord.year, Self::epoch().get_day_i());
-        let new_year_1 = Self::new_year_day_unchecked(ord.year + 1, Self::epoch().get_day_i());
-        let diff = new_year_1 - new_year_0;
-        if (ord.day_of_year as i64) < 1 || (ord.day_of_year as i64) > diff {
-            Err(CalendarError::InvalidDayOfYear)
-        } else {
-            Ok(())
-        }
+        Self::valid_leap_week_ordinal(ord)
     }
 
     fn ordinal_from_fixed(fixed_date: Fixed) -> OrdinalDate {
         //LISTING FixedToSym (*Basic Symmetry454 and Symmetry010 Calendar Arithmetic* by Dr. Irvin L. Bromberg)
         //Only the SymYear and DayOfYear terms.
-        let date = fixed_date.get_day_i();
-        let (sym_year, start_of_year) = Self::year_from_fixed(date, Self::epoch().get_day_i());
-        let day_of_year = (date - start_of_year + 1) as u16;
-        OrdinalDate {
-            year: sym_year,
-            day_of_year: day_of_year,
-        }
+        Self::leap_week_ordinal(fixed_date)
     }
 
     fn to_ordinal(self) -> OrdinalDate {
@@ -340,6 +337,113 @@ impl<const T: bool, const U: bool> HasLeapYears for Symmetry<T, U> {
     }
 }
 
+const MONTH_LENGTHS_454: [u8; 13] = [28, 35, 28, 28, 35, 28, 28, 35, 28, 28, 35, 28, 0];
+const LEAP_MONTH_LENGTHS_454: [u8; 13] = [28, 35, 28, 28, 35, 28, 28, 35, 28, 28, 35, 28, 7];
+const MONTH_LENGTHS_010: [u8; 13] = [30, 31, 30, 30, 31, 30, 30, 31, 30, 30, 31, 30, 0];
+const LEAP_MONTH_LENGTHS_010: [u8; 13] = [30, 31, 30, 30, 31, 30, 30, 31, 30, 30, 31, 30, 7];
+
+impl<const T: bool, const U: bool> MonthTable for Symmetry<T, U> {
+    const COMMON_MONTH_LENGTHS: &'static [u8] = if T {
+        &MONTH_LENGTHS_454
+    } else {
+        &MONTH_LENGTHS_010
+    };
+    const LEAP_MONTH_LENGTHS: &'static [u8] = if T {
+        &LEAP_MONTH_LENGTHS_454
+    } else {
+        &LEAP_MONTH_LENGTHS_010
+    };
+    const COMMON_DAYS_BEFORE_MONTH: &'static [u16] = if T {
+        &cumulative_lengths(MONTH_LENGTHS_454)
+    } else {
+        &cumulative_lengths(MONTH_LENGTHS_010)
+    };
+    const LEAP_DAYS_BEFORE_MONTH: &'static [u16] = if T {
+        &cumulative_lengths(LEAP_MONTH_LENGTHS_454)
+    } else {
+        &cumulative_lengths(LEAP_MONTH_LENGTHS_010)
+    };
+}
+
+impl<const T: bool, const U: bool> LeapWeekCalendar for Symmetry<T, U> {
+    fn year_start_day(sym_year: i32) -> i64 {
+        Self::new_year_day_unchecked(sym_year, Self::epoch().get_day_i())
+    }
+
+    fn year_and_start(date: i64) -> (i32, i64) {
+        Self::year_from_fixed(date, Self::epoch().get_day_i())
+    }
+}
+
+impl<const T: bool, const U: bool> Symmetry<T, U> {
+    /// Find the date in another Symmetry calendar which is on the same day
+    ///
+    /// Variants with the same leap rule share their new year days, so only the month and
+    /// day are recalculated. Otherwise the year is found from the new year days of the
+    /// other leap rule.
+    ///
+    /// ```
+    /// use radnelac::calendar::*;
+    /// use radnelac::day_count::*;
+    ///
+    /// let a = Symmetry454::try_new(2025, SymmetryMonth::February, 1).unwrap();
+    /// let b = a.reinterpret::<Symmetry010>();
+    /// assert_eq!(b.to_common_date(), CommonDate::new(2025, 1, 29));
+    /// assert_eq!(b.reinterpret::<Symmetry454>(), a);
+    /// assert_eq!(a.reinterpret::<Symmetry454Solstice>(), a.convert::<Symmetry454Solstice>());
+    /// ```
+    pub fn reinterpret<S>(self) -> S
+    where
+        S: LeapWeekCalendar + ToFromOrdinalDate + ToFromCommonDate<SymmetryMonth>,
+    {
+        let ord = self.to_ordinal();
+        let start = Self::year_start_day(ord.year);
+        if S::year_start_day(ord.year) == start {
+            return S::from_ordinal_unchecked(ord);
+        }
+        let date = start + (ord.day_of_year as i64) - 1;
+        let (year, other_start) = S::year_and_start(date);
+        S::from_ordinal_unchecked(OrdinalDate {
+            year,
+            day_of_year: (date - other_start + 1) as u16,
+        })
+    }
+
+    /// [`true`] if the Symmetry454 and Symmetry010 month lengths put this date in the same month
+    ///
+    /// The variants are compared using the same leap rule, so the day of year is unchanged.
+    /// In every quarter there are 4 days where the month lengths disagree.
+    ///
+    /// ```
+    /// use radnelac::calendar::*;
+    ///
+    /// let a = Symmetry454::try_new(2025, SymmetryMonth::January, 28).unwrap();
+    /// assert!(a.month_rules_agree());
+    /// let b = Symmetry010::try_new(2025, SymmetryMonth::January, 29).unwrap();
+    /// assert!(!b.month_rules_agree());
+    /// ```
+    pub fn month_rules_agree(self) -> bool {
+        let ord = self.to_ordinal();
+        let other = if T {
+            Symmetry::<false, U>::from_ordinal_unchecked(ord).0
+        } else {
+            Symmetry::<true, U>::from_ordinal_unchecked(ord).0
+        };
+        self.0.month == other.month
+    }
+
+    /// Find every date in a year where the Symmetry454 and Symmetry010 month lengths disagree
+    ///
+    /// See [`month_rules_agree`](Symmetry::month_rules_agree) for details.
+    pub fn month_rule_disagreements(year: i32) -> Vec<Self> {
+        let days = 7 * (Self::weeks_in_year(year) as u16);
+        (1..=days)
+            .map(|day_of_year| Self::from_ordinal_unchecked(OrdinalDate { year, day_of_year }))
+            .filter(|d| !d.month_rules_agree())
+            .collect()
+    }
+}
+
 impl<const T: bool, const U: bool> CalculatedBounds for Symmetry<T, U> {}
 
 impl<const T: bool, const U: bool> Epoch for Symmetry<T, U> {
@@ -360,13 +464,16 @@ impl<const T: bool, const U: bool> FromFixed for Symmetry<T, U> {
 impl<const T: bool, const U: bool> ToFixed for Symmetry<T, U> {
     fn to_fixed(self) -> Fixed {
         //LISTING SymToFixed (*Basic Symmetry454 and Symmetry010 Calendar Arithmetic* by Dr. Irvin L. Bromberg)
-        let new_year_day = Self::new_year_day_unchecked(self.0.year, Self::epoch().get_day_i());
+        let new_year_day = Self::year_start_day(self.0.year);
         let day_of_year = Self::day_of_year(self.0.month, self.0.day) as i64;
         Fixed::cast_new(new_year_day + day_of_year - 1)
     }
 }
 
 impl<const T: bool, const U: bool> ToFromCommonDate<SymmetryMonth> for Symmetry<T, U> {
+    const EFFECTIVE_YEAR_MIN: i32 = if U { -47013939 } else { -47014026 };
+    const EFFECTIVE_YEAR_MAX: i32 = if U { 47013940 } else { 47014027 };
+
     fn to_common_date(self) -> CommonDate {
         self.0
     }
@@ -398,12 +505,13 @@ impl<const T: bool, const U: bool> ToFromCommonDate<SymmetryMonth> for Symmetry<
         CommonDate::new(year, m as u8, Self::month_length(year, m))
     }
 
-    fn month_length(_year: i32, month: SymmetryMonth) -> u8 {
+    fn month_length(year: i32, month: SymmetryMonth) -> u8 {
         // This function is not described by Dr. Bromberg and is not
         // used in conversion to and from other timekeeping systems.
         // Instead it is used for checking if a [CommonDate] is valid.
         match (month, T) {
-            (SymmetryMonth::Irvember, _) => 7,
+            (SymmetryMonth::Irvember, _) if Self::is_leap(year) => 7,
+            (SymmetryMonth::Irvember, _) => 0,
             (_, true) => (28 + (7 * ((month as u8).modulus(3).div_euclid(2)))) as u8,
             (_, false) => (30 + (month as u8).modulus(3).div_euclid(2)) as u8,
         }
@@ -443,6 +551,17 @@ mod tests {
     use proptest::proptest;
     const MAX_YEARS: i32 = (FIXED_MAX / 365.25) as i32;
 
+    #[test]
+    fn irvember_only_in_leap_years() {
+        let c = CommonDate::new(2009, SymmetryMonth::Irvember as u8, 7);
+        assert!(Symmetry454::try_from_common_date(c).is_ok());
+        let c = CommonDate::new(2010, SymmetryMonth::Irvember as u8, 1);
+        assert!(matches!(
+            Symmetry454::try_from_common_date(c),
+            Err(CalendarError::InvalidDay)
+        ));
+    }
+
     #[test]
     fn is_leap_example() {
         assert!(Symmetry454::is_leap(2009));
@@ -685,5 +804,28 @@ mod tests {
             let d = Symmetry010Solstice::try_from_common_date(c).unwrap();
             assert_ne!(d.convert::<Weekday>(), Weekday::Friday);
         }
+
+        #[test]
+        fn reinterpret(t in -FIXED_MAX..FIXED_MAX) {
+            let d = Symmetry454::from_fixed(RataDie::new(t).to_fixed());
+            assert_eq!(d.reinterpret::<Symmetry454>(), d);
+            assert_eq!(d.reinterpret::<Symmetry010>(), d.convert::<Symmetry010>());
+            assert_eq!(d.reinterpret::<Symmetry454Solstice>(), d.convert::<Symmetry454Solstice>());
+            assert_eq!(d.reinterpret::<Symmetry010Solstice>(), d.convert::<Symmetry010Solstice>());
+            let d = Symmetry010Solstice::from_fixed(RataDie::new(t).to_fixed());
+            assert_eq!(d.reinterpret::<Symmetry454>(), d.convert::<Symmetry454>());
+            assert_eq!(d.reinterpret::<Symmetry010>(), d.convert::<Symmetry010>());
+        }
+
+        #[test]
+        fn month_rule_disagreements(year in -MAX_YEARS..MAX_YEARS) {
+            let a = Symmetry454::month_rule_disagreements(year);
+            let b = Symmetry010::month_rule_disagreements(year);
+            assert_eq!(a.len(), 16);
+            for (x, y) in a.iter().zip(b.iter()) {
+                assert_eq!(x.reinterpret::<Symmetry010>(), *y);
+                assert_ne!(x.to_common_date().month, y.to_common_date().month);
+            }
+        }
     }
 }
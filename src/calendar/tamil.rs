@@ -0,0 +1,296 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::hindu::fixed_month_start;
+use crate::calendar::hindu::solar_from_fixed;
+use crate::calendar::hindu::sunset;
+use crate::calendar::prelude::CommonDate;
+use crate::calendar::prelude::CommonWeekOfYear;
+use crate::calendar::prelude::GuaranteedMonth;
+use crate::calendar::prelude::HasLeapYears;
+use crate::calendar::prelude::OrdinalDate;
+use crate::calendar::prelude::Quarter;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::AllowYearZero;
+use crate::calendar::CalendarMoment;
+use crate::calendar::ToFromOrdinalDate;
+use crate::common::error::CalendarError;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::CalculatedBounds;
+use crate::day_count::Epoch;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use std::num::NonZero;
+
+#[allow(unused_imports)] //FromPrimitive is needed for derive
+use num_traits::FromPrimitive;
+use std::fmt;
+
+/// Years from the start of the Kali Yuga to the start of the Saka era
+const SAKA_ERA: i32 = 3179;
+
+/// Represents a month in the Tamil calendar
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, FromPrimitive, ToPrimitive)]
+pub enum TamilMonth {
+    Chithirai = 1,
+    Vaikasi,
+    Aani,
+    Aadi,
+    Aavani,
+    Purattasi,
+    Aippasi,
+    Karthigai,
+    Margazhi,
+    Thai,
+    Maasi,
+    Panguni,
+}
+
+/// Represents a date in the Tamil solar calendar
+///
+/// ## Introduction
+///
+/// The Tamil calendar is a sidereal solar calendar used in Tamil Nadu and Sri Lanka. Each
+/// month starts when the sun enters a sign of the sidereal zodiac.
+///
+/// There is no arithmetic rule for the lengths of the months. Tamil almanacs calculate
+/// them from the position of the sun. This crate approximates them using the rules of the
+/// Surya Siddhanta and the "Tamil rule" described in *Calendrical Calculations*: if the
+/// sun enters a sign before sunset, the month starts on that day, and otherwise it starts
+/// on the next day. Sunset is calculated for Ujjain.
+///
+/// Modern almanacs use more accurate astronomical calculations, so the start of some
+/// months may differ by a day from published Tamil calendars.
+///
+/// ## Basic Structure
+///
+/// Years are divided into 12 months. Months have 29 to 32 days, and years have 365 or 366
+/// days. This crate treats a year with 366 days as a leap year.
+///
+/// Tamil almanacs name the years in a cycle of 60. This crate numbers the years instead.
+///
+/// ## Epoch
+///
+/// This crate counts the elapsed years of the Saka era, as *Calendrical Calculations*
+/// does. The first year starts on March 13, 79 Common Era in the proleptic Gregorian
+/// calendar.
+///
+/// ## Representation and Examples
+///
+/// The months are represented in this crate as [`TamilMonth`].
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// let g = Gregorian::try_new(2024, GregorianMonth::April, 14).unwrap();
+/// let t = g.convert::<Tamil>();
+/// assert_eq!(t, Tamil::try_new(1946, TamilMonth::Chithirai, 1).unwrap());
+/// ```
+///
+/// ## Further reading
+/// + [Wikipedia](https://en.wikipedia.org/wiki/Tamil_calendar)
+/// + *Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
+pub struct Tamil(CommonDate);
+
+impl fmt::Debug for Tamil {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<TamilMonth>("Tamil", f)
+    }
+}
+
+impl Tamil {
+    /// Fixed day number of the first day of a month
+    fn month_start_fixed(year: i32, month: u8) -> i64 {
+        fixed_month_start(year, month, SAKA_ERA, sunset)
+    }
+
+    fn year_length(year: i32) -> u16 {
+        (Tamil::month_start_fixed(year + 1, 1) - Tamil::month_start_fixed(year, 1)) as u16
+    }
+}
+
+impl AllowYearZero for Tamil {}
+
+impl ToFromOrdinalDate for Tamil {
+    fn valid_ordinal(ord: OrdinalDate) -> Result<(), CalendarError> {
+        if ord.day_of_year > 0 && ord.day_of_year <= Tamil::year_length(ord.year) {
+            Ok(())
+        } else {
+            Err(CalendarError::InvalidDayOfYear)
+        }
+    }
+
+    fn ordinal_from_fixed(fixed_date: Fixed) -> OrdinalDate {
+        let date = fixed_date.get_day_i();
+        let year = solar_from_fixed(date, SAKA_ERA, sunset).year;
+        OrdinalDate {
+            year,
+            day_of_year: (date - Tamil::month_start_fixed(year, 1) + 1) as u16,
+        }
+    }
+
+    fn to_ordinal(self) -> OrdinalDate {
+        let start = Tamil::month_start_fixed(self.0.year, 1);
+        let date = Tamil::month_start_fixed(self.0.year, self.0.month) - 1;
+        OrdinalDate {
+            year: self.0.year,
+            day_of_year: (date - start + (self.0.day as i64) + 1) as u16,
+        }
+    }
+
+    fn from_ordinal_unchecked(ord: OrdinalDate) -> Self {
+        let start = Tamil::month_start_fixed(ord.year, 1);
+        let date = start + (ord.day_of_year as i64) - 1;
+        Tamil(solar_from_fixed(date, SAKA_ERA, sunset))
+    }
+}
+
+impl HasLeapYears for Tamil {
+    /// [`true`] if the year has 366 days
+    fn is_leap(year: i32) -> bool {
+        Tamil::year_length(year) > 365
+    }
+}
+
+impl CalculatedBounds for Tamil {}
+
+impl Epoch for Tamil {
+    fn epoch() -> Fixed {
+        Fixed::cast_new(Tamil::month_start_fixed(1, 1))
+    }
+}
+
+impl FromFixed for Tamil {
+    fn from_fixed(fixed_date: Fixed) -> Tamil {
+        Tamil(solar_from_fixed(fixed_date.get_day_i(), SAKA_ERA, sunset))
+    }
+}
+
+impl ToFixed for Tamil {
+    fn to_fixed(self) -> Fixed {
+        let start = Tamil::month_start_fixed(self.0.year, self.0.month);
+        Fixed::cast_new(start + (self.0.day as i64) - 1)
+    }
+}
+
+impl ToFromCommonDate<TamilMonth> for Tamil {
+    const EFFECTIVE_YEAR_MIN: i32 = -47011901;
+    const EFFECTIVE_YEAR_MAX: i32 = 47011746;
+
+    fn to_common_date(self) -> CommonDate {
+        self.0
+    }
+
+    fn from_common_date_unchecked(date: CommonDate) -> Self {
+        debug_assert!(Self::valid_ymd(date).is_ok());
+        Self(date)
+    }
+
+    fn valid_ymd(date: CommonDate) -> Result<(), CalendarError> {
+        match TamilMonth::from_u8(date.month) {
+            None => Err(CalendarError::InvalidMonth),
+            Some(m) if date.day < 1 || date.day > Self::month_length(date.year, m) => {
+                Err(CalendarError::InvalidDay)
+            }
+            Some(_) => Ok(()),
+        }
+    }
+
+    fn year_end_date(year: i32) -> CommonDate {
+        let m = TamilMonth::Panguni;
+        CommonDate::new(year, m as u8, Self::month_length(year, m))
+    }
+
+    fn month_length(year: i32, month: TamilMonth) -> u8 {
+        let m = month as u8;
+        let next = if m == 12 {
+            Tamil::month_start_fixed(year + 1, 1)
+        } else {
+            Tamil::month_start_fixed(year, m + 1)
+        };
+        (next - Tamil::month_start_fixed(year, m)) as u8
+    }
+}
+
+impl Quarter for Tamil {
+    fn quarter(self) -> NonZero<u8> {
+        NonZero::new(((self.to_common_date().month - 1) / 3) + 1).expect("(m-1)/3 > -1")
+    }
+}
+
+impl GuaranteedMonth<TamilMonth> for Tamil {}
+impl CommonWeekOfYear<TamilMonth> for Tamil {}
+
+/// Represents a date *and time* in the Tamil solar calendar
+pub type TamilMoment = CalendarMoment<Tamil>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Gregorian;
+    use crate::calendar::GregorianMonth;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use proptest::proptest;
+
+    #[test]
+    fn new_years() {
+        for year in 2019..=2025 {
+            let t = Tamil::try_year_start(year - 78).unwrap();
+            let g = t.convert::<Gregorian>().to_common_date();
+            assert_eq!(g, CommonDate::new(year, 4, 14));
+        }
+    }
+
+    #[test]
+    fn thai() {
+        let d_list = [
+            CommonDate::new(2020, 1, 15),
+            CommonDate::new(2021, 1, 14),
+            CommonDate::new(2023, 1, 15),
+            CommonDate::new(2024, 1, 15),
+            CommonDate::new(2025, 1, 14),
+        ];
+        for g in d_list {
+            let t = Gregorian::try_from_common_date(g)
+                .unwrap()
+                .convert::<Tamil>();
+            assert_eq!(t.to_common_date(), CommonDate::new(g.year - 79, 10, 1));
+        }
+    }
+
+    #[test]
+    fn epoch() {
+        let g = Gregorian::from_fixed(Tamil::epoch());
+        assert_eq!(g.year(), 79);
+        assert_eq!(g.month(), GregorianMonth::March);
+        assert_eq!(g.day(), 13);
+    }
+
+    proptest! {
+        #[test]
+        fn month_lengths(t in FIXED_MIN..FIXED_MAX) {
+            let b = Tamil::from_fixed(Fixed::new(t));
+            let year = b.year();
+            let mut total = 0;
+            for m in 1..=12 {
+                let len = Tamil::month_length(year, TamilMonth::from_u8(m).unwrap());
+                assert!((29..=32).contains(&len));
+                total += len as u16;
+            }
+            assert_eq!(total, Tamil::year_length(year));
+            assert!(total == 365 || total == 366);
+        }
+
+        #[test]
+        fn month_start_matches_from_fixed(t in FIXED_MIN..FIXED_MAX) {
+            let b = Tamil::from_fixed(Fixed::new(t));
+            let start = Tamil::month_start_fixed(b.year(), b.to_common_date().month);
+            assert_eq!(b.to_fixed().get_day_i(), start + (b.day() as i64) - 1);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// Arithmetic for perennial calendars with 13 months of 28 days and epagomenal days
+//
+// Every year has a "year day" outside of any month. Leap years have an additional
+// "leap day", at an ordinal position which differs between calendars. The year day is the
+// last day of the year, unless the leap day is placed after it.
+//
+// Calendars which start on January 1 of a Gregorian year can also use the Gregorian lock
+// functions, where `year_offset` is added to a year to get the Gregorian year.
+
+use crate::calendar::gregorian::Gregorian;
+use crate::calendar::prelude::HasLeapYears;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::prelude::ToFromOrdinalDate;
+use crate::calendar::OrdinalDate;
+use crate::day_count::Fixed;
+use crate::day_count::ToFixed;
+
+const DAYS_PER_MONTH: u16 = 28;
+const MONTHS_PER_YEAR: u16 = 13;
+const COMMON_YEAR_LENGTH: u16 = (MONTHS_PER_YEAR * DAYS_PER_MONTH) + 1;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum ThirteenMonthDay {
+    Month(u8, u8),
+    LeapDay,
+    YearDay,
+}
+
+fn year_day_ordinal(is_leap: bool, leap_day: u16) -> u16 {
+    if is_leap && leap_day <= COMMON_YEAR_LENGTH {
+        COMMON_YEAR_LENGTH + 1
+    } else {
+        COMMON_YEAR_LENGTH
+    }
+}
+
+pub(crate) fn from_ordinal(day_of_year: u16, is_leap: bool, leap_day: u16) -> ThirteenMonthDay {
+    if is_leap && day_of_year == leap_day {
+        ThirteenMonthDay::LeapDay
+    } else if day_of_year == year_day_ordinal(is_leap, leap_day) {
+        ThirteenMonthDay::YearDay
+    } else {
+        let correction = if is_leap && day_of_year > leap_day {
+            1
+        } else {
+            0
+        };
+        let d = day_of_year - correction - 1;
+        let month = (d / DAYS_PER_MONTH) + 1;
+        let day = (d % DAYS_PER_MONTH) + 1;
+        ThirteenMonthDay::Month(month as u8, day as u8)
+    }
+}
+
+pub(crate) fn to_ordinal(day: ThirteenMonthDay, is_leap: bool, leap_day: u16) -> u16 {
+    match day {
+        ThirteenMonthDay::LeapDay => leap_day,
+        ThirteenMonthDay::YearDay => year_day_ordinal(is_leap, leap_day),
+        ThirteenMonthDay::Month(month, day) => {
+            let approx = ((month as u16 - 1) * DAYS_PER_MONTH) + (day as u16);
+            if is_leap && approx >= leap_day {
+                approx + 1
+            } else {
+                approx
+            }
+        }
+    }
+}
+
+pub(crate) fn is_leap(year: i32, year_offset: i32) -> bool {
+    Gregorian::is_leap(year + year_offset)
+}
+
+pub(crate) fn ordinal_from_fixed(fixed_date: Fixed, year_offset: i32) -> OrdinalDate {
+    let ord_g = Gregorian::ordinal_from_fixed(fixed_date);
+    OrdinalDate {
+        year: ord_g.year - year_offset,
+        day_of_year: ord_g.day_of_year,
+    }
+}
+
+/// Fixed day number of the last day before the start of a year
+pub(crate) fn prior_elapsed_days(year: i32, year_offset: i32) -> i64 {
+    //The Gregorian year start may be slightly beyond the effective bounds
+    let start = Gregorian::year_start_date(year + year_offset);
+    Gregorian::from_common_date_unchecked(start)
+        .to_fixed()
+        .get_day_i()
+        - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::proptest;
+
+    proptest! {
+        #[test]
+        fn roundtrip(leap_day in 1..367u16, is_leap: bool) {
+            let len = if is_leap { 366 } else { 365 };
+            let mut year_days = 0;
+            let mut leap_days = 0;
+            for doy in 1..=len {
+                let day = from_ordinal(doy, is_leap, leap_day);
+                match day {
+                    ThirteenMonthDay::Month(m, d) => {
+                        assert!((1..=13).contains(&m));
+                        assert!((1..=28).contains(&d));
+                    }
+                    ThirteenMonthDay::LeapDay => leap_days += 1,
+                    ThirteenMonthDay::YearDay => year_days += 1,
+                }
+                assert_eq!(to_ordinal(day, is_leap, leap_day), doy);
+            }
+            assert_eq!(year_days, 1);
+            assert_eq!(leap_days, if is_leap { 1 } else { 0 });
+        }
+    }
+}
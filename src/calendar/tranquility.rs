@@ -2,6 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use crate::calendar::gregorian::days_in_gregorian_month;
 use crate::calendar::gregorian::Gregorian;
 use crate::calendar::prelude::CommonDate;
 use crate::calendar::prelude::HasLeapYears;
@@ -10,8 +11,12 @@ use crate::calendar::prelude::Perennial;
 use crate::calendar::prelude::Quarter;
 use crate::calendar::prelude::ToFromCommonDate;
 use crate::calendar::prelude::ToFromOrdinalDate;
+use crate::calendar::prelude::YearNumbering;
+use crate::calendar::thirteen_month;
+use crate::calendar::thirteen_month::ThirteenMonthDay;
 use crate::calendar::CalendarMoment;
 use crate::calendar::HasEpagemonae;
+use crate::calendar::YearZeroPolicy;
 use crate::clock::ClockTime;
 use crate::clock::TimeOfDay;
 use crate::common::error::CalendarError;
@@ -26,6 +31,7 @@ use crate::day_cycle::Weekday;
 #[allow(unused_imports)] //FromPrimitive is needed for derive
 use num_traits::FromPrimitive;
 use std::cmp::Ordering;
+use std::fmt;
 use std::num::NonZero;
 
 const TRANQUILITY_EPOCH_GREGORIAN: CommonDate = CommonDate {
@@ -35,11 +41,7 @@ const TRANQUILITY_EPOCH_GREGORIAN: CommonDate = CommonDate {
 };
 const NON_MONTH: u8 = 0;
 
-const TRANQUILITY_EPOCH_CLOCK: ClockTime = ClockTime {
-    hours: 20,
-    minutes: 18,
-    seconds: 1.2,
-};
+const TRANQUILITY_EPOCH_CLOCK: ClockTime = ClockTime::new_unchecked(20, 18, 1.2);
 
 /// Represents a month of the Tranquility Calendar
 ///
@@ -66,8 +68,6 @@ pub enum TranquilityMonth {
     Mendel,
 }
 
-const AFTER_H27: i64 = (TranquilityMonth::Hippocrates as i64) * 28;
-
 /// Represents a complementary day of the Tranquility Calendar
 ///
 /// These are a bit more complex than the complementary days of the Positivist
@@ -404,24 +404,201 @@ pub enum TranquilityComplementaryDay {
 ///   + [archive.org copy of mithrandir.com](https://web.archive.org/web/20161025042320/http://www.mithrandir.com/Tranquility/tranquilityArticle.html)
 ///   + [archive.org copy of OMNI July 1989, pages 63, 64](https://archive.org/details/omni-archive/OMNI_1989_07/page/n63/mode/2up)
 ///   + [archive.org copy of OMNI July 1989, pages 65, 66](https://archive.org/details/omni-archive/OMNI_1989_07/page/n65/mode/2up)
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct Tranquility(CommonDate);
 
+impl fmt::Debug for Tranquility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<TranquilityMonth>("Tranquility", f)
+    }
+}
+
 impl Tranquility {
+    /// Number of days from Archimedes 1 to Faraday 24, which are before January 1
+    ///
+    /// Adding this to a Gregorian day of year gives the Tranquility day of year, modulo
+    /// the length of the Gregorian year.
+    pub const GREGORIAN_ORDINAL_SHIFT: u16 = ((TranquilityMonth::Faraday as u16) * 28) - 4;
+
+    /// Day of year of Aldrin Day in leap years, after Hippocrates 27
+    pub const LEAP_DAY_ORDINAL: u16 = (TranquilityMonth::Hippocrates as u16) * 28;
+
+    /// Gregorian month and day of the first day of each Tranquility month
+    ///
+    /// Every Tranquility month is locked to the same Gregorian dates in every year. February
+    /// 29 is always Aldrin Day, so it is never part of a month.
+    pub const GREGORIAN_MONTH_STARTS: [(u8, u8); 13] = [
+        (7, 21),
+        (8, 18),
+        (9, 15),
+        (10, 13),
+        (11, 10),
+        (12, 8),
+        (1, 5),
+        (2, 2),
+        (3, 2),
+        (3, 30),
+        (4, 27),
+        (5, 25),
+        (6, 22),
+    ];
+
+    fn gregorian_start_year(year: i32) -> i32 {
+        let y = if year < 0 { year + 1 } else { year };
+        (TRANQUILITY_EPOCH_GREGORIAN.year - 1) + y
+    }
+
+    fn from_gregorian_start_year(g_year: i32) -> i32 {
+        let y = g_year - (TRANQUILITY_EPOCH_GREGORIAN.year - 1);
+        if y < 1 {
+            y - 1
+        } else {
+            y
+        }
+    }
+
+    /// Gregorian date of a Tranquility date
+    ///
+    /// This uses the fixed Gregorian dates of each Tranquility month and complementary
+    /// day, instead of converting through a [`Fixed`] day number. It is intended for
+    /// cross-validating other implementations of the Tranquility calendar.
+    ///
+    /// ```
+    /// use radnelac::calendar::*;
+    ///
+    /// let c = CommonDate::new(-1, TranquilityMonth::Mendel as u8, 28);
+    /// let q = Tranquility::try_from_common_date(c).unwrap();
+    /// let g = Gregorian::try_new(1969, GregorianMonth::July, 19).unwrap();
+    /// assert_eq!(q.gregorian_equivalent(), g);
+    /// assert_eq!(Tranquility::from_gregorian(g), q);
+    /// ```
+    pub fn gregorian_equivalent(self) -> Gregorian {
+        let start_year = Tranquility::gregorian_start_year(self.0.year);
+        let date = match self.epagomenae() {
+            Some(TranquilityComplementaryDay::MoonLandingDay) => TRANQUILITY_EPOCH_GREGORIAN,
+            Some(TranquilityComplementaryDay::ArmstrongDay) => CommonDate::new(
+                start_year + 1,
+                TRANQUILITY_EPOCH_GREGORIAN.month,
+                TRANQUILITY_EPOCH_GREGORIAN.day,
+            ),
+            Some(TranquilityComplementaryDay::AldrinDay) => CommonDate::new(start_year + 1, 2, 29),
+            None => {
+                let (m0, d0) = Tranquility::GREGORIAN_MONTH_STARTS[(self.0.month - 1) as usize];
+                let year = if self.0.month < (TranquilityMonth::Galileo as u8) {
+                    start_year
+                } else {
+                    start_year + 1
+                };
+                //February 29 is skipped, so always use the length of a common year
+                let len = days_in_gregorian_month(1, m0).expect("Valid month");
+                let day = d0 + self.0.day - 1;
+                if day <= len {
+                    CommonDate::new(year, m0, day)
+                } else if m0 == 12 {
+                    CommonDate::new(year + 1, 1, day - len)
+                } else {
+                    CommonDate::new(year, m0 + 1, day - len)
+                }
+            }
+        };
+        Gregorian::from_common_date_unchecked(date)
+    }
+
+    /// Tranquility date of a Gregorian date
+    ///
+    /// This is the inverse of [`gregorian_equivalent`](Tranquility::gregorian_equivalent).
+    pub fn from_gregorian(g: Gregorian) -> Tranquility {
+        let gc = g.to_common_date();
+        let date = if gc == TRANQUILITY_EPOCH_GREGORIAN {
+            CommonDate::new(
+                0,
+                NON_MONTH,
+                TranquilityComplementaryDay::MoonLandingDay as u8,
+            )
+        } else if gc.month == TRANQUILITY_EPOCH_GREGORIAN.month
+            && gc.day == TRANQUILITY_EPOCH_GREGORIAN.day
+        {
+            CommonDate::new(
+                Tranquility::from_gregorian_start_year(gc.year - 1),
+                NON_MONTH,
+                TranquilityComplementaryDay::ArmstrongDay as u8,
+            )
+        } else if gc.month == 2 && gc.day == 29 {
+            CommonDate::new(
+                Tranquility::from_gregorian_start_year(gc.year - 1),
+                NON_MONTH,
+                TranquilityComplementaryDay::AldrinDay as u8,
+            )
+        } else {
+            let (i, offset, wrapped) = Tranquility::GREGORIAN_MONTH_STARTS
+                .iter()
+                .enumerate()
+                .find_map(|(i, &(m0, d0))| {
+                    let len = days_in_gregorian_month(1, m0).expect("Valid month");
+                    let next = (m0 % 12) + 1;
+                    if gc.month == m0 && gc.day >= d0 && gc.day - d0 < 28 {
+                        Some((i, gc.day - d0, false))
+                    } else if gc.month == next && gc.day + len - d0 < 28 {
+                        Some((i, gc.day + len - d0, next < m0))
+                    } else {
+                        None
+                    }
+                })
+                .expect("Every other Gregorian date is in a Tranquility month");
+            let month_year = if wrapped { gc.year - 1 } else { gc.year };
+            let start_year = if i < (TranquilityMonth::Galileo as usize) - 1 {
+                month_year
+            } else {
+                month_year - 1
+            };
+            CommonDate::new(
+                Tranquility::from_gregorian_start_year(start_year),
+                (i + 1) as u8,
+                offset + 1,
+            )
+        };
+        Tranquility(date)
+    }
+
+    /// Fixed day number of the last day before the start of a year
     pub fn prior_elapsed_days(year: i32) -> i64 {
         if year == 0 {
             TranquilityMoment::epoch().get_day_i() - 1
         } else {
             let y = if year < 0 { year + 1 } else { year };
-            let prior_g = Gregorian::try_from_common_date(CommonDate {
+            //Month and day known to be valid
+            let prior_g = Gregorian::from_common_date_unchecked(CommonDate {
                 year: (y - 1) + TRANQUILITY_EPOCH_GREGORIAN.year,
                 month: TRANQUILITY_EPOCH_GREGORIAN.month,
                 day: TRANQUILITY_EPOCH_GREGORIAN.day,
-            })
-            .expect("Month and day known to be valid.");
+            });
             prior_g.to_fixed().get_day_i()
         }
     }
+
+    /// Number of days in a year
+    ///
+    /// Most years have 365 days, and leap years have 366 days. The special cases are
+    /// 1 BT, which has 364 days because Armstrong Day is skipped, and the year 0, which
+    /// only contains Moon Landing Day.
+    ///
+    /// ```
+    /// use radnelac::calendar::*;
+    ///
+    /// assert_eq!(Tranquility::year_length(0), 1);
+    /// assert_eq!(Tranquility::year_length(-1), 364);
+    /// assert_eq!(Tranquility::year_length(1), 365);
+    /// assert!(TranquilityMoment::is_leap(3));
+    /// assert_eq!(TranquilityMoment::year_length(3), 366);
+    /// assert_eq!(TranquilityMoment::epagomenae_count(3), 2);
+    /// ```
+    pub fn year_length(year: i32) -> u16 {
+        if year == 0 {
+            1
+        } else {
+            (13 * 28) + (Self::epagomenae_count(year) as u16)
+        }
+    }
 }
 
 impl ToFromOrdinalDate for Tranquility {
@@ -449,7 +626,7 @@ impl ToFromOrdinalDate for Tranquility {
         //Greg  ord:   (1)...........(60)(61).........(202)(203).........(366)
         //Tranquility: (far25)......(ald)(hip28)......(arm)(arc1)......(far24)
         //Tran  ord:   (165)........(224)(225)........(366)(1)...........(164)
-        const ORDINAL_SHIFT: i64 = ((TranquilityMonth::Faraday as i64) * 28) - 4;
+        const ORDINAL_SHIFT: i64 = Tranquility::GREGORIAN_ORDINAL_SHIFT as i64;
         let g_ord = Gregorian::ordinal_from_fixed(fixed_date);
         let g_doy_shift = (g_ord.day_of_year as i64) + ORDINAL_SHIFT;
         let g_len = if Gregorian::is_leap(g_ord.year) {
@@ -480,45 +657,45 @@ impl ToFromOrdinalDate for Tranquility {
     }
 
     fn to_ordinal(self) -> OrdinalDate {
-        let comp_count = Self::epagomenae_count(self.0.year) as i64;
-        let ordinal_day = match self.epagomenae() {
-            Some(TranquilityComplementaryDay::MoonLandingDay) => 1,
-            Some(TranquilityComplementaryDay::ArmstrongDay) => 364 + comp_count,
-            Some(TranquilityComplementaryDay::AldrinDay) => AFTER_H27,
-            None => {
-                let month = self.0.month as i64;
-                let day = self.0.day as i64;
-                let approx = ((month - 1) * 28) + day;
-                let correction = if approx < AFTER_H27 || comp_count < 2 {
-                    0
-                } else {
-                    1
-                };
-                approx + correction
+        let day = match self.epagomenae() {
+            Some(TranquilityComplementaryDay::MoonLandingDay) => {
+                return OrdinalDate {
+                    year: self.0.year,
+                    day_of_year: 1,
+                }
             }
+            Some(TranquilityComplementaryDay::ArmstrongDay) => ThirteenMonthDay::YearDay,
+            Some(TranquilityComplementaryDay::AldrinDay) => ThirteenMonthDay::LeapDay,
+            None => ThirteenMonthDay::Month(self.0.month, self.0.day),
         };
+        let is_leap = TranquilityMoment::is_leap(self.0.year);
         OrdinalDate {
             year: self.0.year,
-            day_of_year: ordinal_day as u16,
+            day_of_year: thirteen_month::to_ordinal(day, is_leap, Tranquility::LEAP_DAY_ORDINAL),
         }
     }
 
     fn from_ordinal_unchecked(ord: OrdinalDate) -> Self {
-        let date_tq = match (
-            ord.day_of_year as i64,
-            ord.year,
-            TranquilityMoment::is_leap(ord.year),
-        ) {
-            (_, 0, _) => CommonDate::new(0, NON_MONTH, 0),
-            (365, _, false) => CommonDate::new(ord.year, NON_MONTH, 1),
-            (366, _, true) => CommonDate::new(ord.year, NON_MONTH, 1),
-            (AFTER_H27, _, true) => CommonDate::new(ord.year, NON_MONTH, 2),
-            (doy, y, is_leap) => {
-                let correction = if doy < AFTER_H27 || !is_leap { 0 } else { 1 };
-                let month = ((((doy - correction) - 1) as i64).div_euclid(28) + 1) as u8;
-                let day = ((doy - correction) as i64).adjusted_remainder(28) as u8;
-                debug_assert!(month > 0 && month < 14, "doy: {}, y: {}", doy, y);
-                CommonDate::new(y, month, day)
+        if ord.year == 0 {
+            return Tranquility(CommonDate::new(0, NON_MONTH, 0));
+        }
+        let is_leap = TranquilityMoment::is_leap(ord.year);
+        let day =
+            thirteen_month::from_ordinal(ord.day_of_year, is_leap, Tranquility::LEAP_DAY_ORDINAL);
+        let date_tq = match day {
+            ThirteenMonthDay::YearDay => CommonDate::new(
+                ord.year,
+                NON_MONTH,
+                TranquilityComplementaryDay::ArmstrongDay as u8,
+            ),
+            ThirteenMonthDay::LeapDay => CommonDate::new(
+                ord.year,
+                NON_MONTH,
+                TranquilityComplementaryDay::AldrinDay as u8,
+            ),
+            ThirteenMonthDay::Month(month, day) => {
+                debug_assert!(month > 0 && month < 14, "ord: {:?}", ord);
+                CommonDate::new(ord.year, month, day)
             }
         };
         Tranquility(date_tq)
@@ -576,6 +753,10 @@ impl Perennial<TranquilityMonth, Weekday> for Tranquility {
     }
 }
 
+impl YearNumbering for Tranquility {
+    const YEAR_ZERO: YearZeroPolicy = YearZeroPolicy::EpochOnly;
+}
+
 impl HasLeapYears for Tranquility {
     fn is_leap(t_year: i32) -> bool {
         if t_year > 0 {
@@ -616,6 +797,9 @@ impl ToFixed for Tranquility {
 }
 
 impl ToFromCommonDate<TranquilityMonth> for Tranquility {
+    const EFFECTIVE_YEAR_MIN: i32 = -47015885;
+    const EFFECTIVE_YEAR_MAX: i32 = 47011948;
+
     fn to_common_date(self) -> CommonDate {
         self.0
     }
@@ -699,6 +883,13 @@ impl Quarter for Tranquility {
 pub type TranquilityMoment = CalendarMoment<Tranquility>;
 
 impl TranquilityMoment {
+    /// Number of days in a year
+    ///
+    /// This is the same as [`Tranquility::year_length`].
+    pub fn year_length(year: i32) -> u16 {
+        Tranquility::year_length(year)
+    }
+
     pub fn is_after_tranquility(self) -> bool {
         if self.date().0.year == 0 {
             self.time_of_day() > TRANQUILITY_EPOCH_CLOCK
@@ -719,6 +910,20 @@ mod tests {
 
     use proptest::proptest;
 
+    #[test]
+    fn debug() {
+        let d = Tranquility::try_from_common_date(CommonDate::new(10, 8, 7)).unwrap();
+        assert_eq!(
+            d.to_debug_string(),
+            "Tranquility { year: 10, month: Hippocrates, day: 7 }"
+        );
+        let d = Tranquility::from_fixed(TranquilityMoment::epoch().to_day());
+        assert_eq!(
+            format!("{:?}", d),
+            "Tranquility { year: 0, month: 0, day: 0 }"
+        );
+    }
+
     #[test]
     fn moon_landing_edge_cases() {
         let f0 = TranquilityMoment::epoch();
@@ -1109,6 +1314,17 @@ mod tests {
     }
 
     proptest! {
+        #[test]
+        fn year_length(year in -100000..100000i32) {
+            for y in [year, -1, 0, 1] {
+                let start = Tranquility::try_year_start(y).unwrap().to_fixed().get_day_i();
+                let end = Tranquility::try_year_end(y).unwrap().to_fixed().get_day_i();
+                assert_eq!(Tranquility::year_length(y) as i64, end - start + 1);
+                let ord = Tranquility::try_year_end(y).unwrap().to_ordinal();
+                assert_eq!(Tranquility::year_length(y), ord.day_of_year);
+            }
+        }
+
         #[test]
         fn gregorian_lookup(t in FIXED_MIN..FIXED_MAX) {
             // https://web.archive.org/web/20180818233025/https://en.wikipedia.org/wiki/Tranquility_calendar
@@ -1156,6 +1372,25 @@ mod tests {
             }
         }
 
+        #[test]
+        fn gregorian_lock(t in FIXED_MIN..FIXED_MAX) {
+            let f = RataDie::new(t).to_fixed().to_day();
+            let g = Gregorian::from_fixed(f);
+            let q = Tranquility::from_fixed(f);
+            assert_eq!(q.gregorian_equivalent(), g);
+            assert_eq!(Tranquility::from_gregorian(g), q);
+        }
+
+        #[test]
+        fn gregorian_lock_small(t in i16::MIN..i16::MAX) {
+            let e = TranquilityMoment::epoch().get_day_i();
+            let f = Fixed::cast_new(e + (t as i64));
+            let g = Gregorian::from_fixed(f);
+            let q = Tranquility::from_fixed(f);
+            assert_eq!(q.gregorian_equivalent(), g, "q: {:?}", q);
+            assert_eq!(Tranquility::from_gregorian(g), q, "g: {:?}", g);
+        }
+
         #[test]
         fn gregorian_lookup_small(t in i8::MIN..i8::MAX) {
             // https://web.archive.org/web/20180818233025/https://en.wikipedia.org/wiki/Tranquility_calendar
@@ -0,0 +1,688 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::gregorian::Gregorian;
+use crate::calendar::islamic::IslamicArith;
+use crate::calendar::islamic::IslamicMonth;
+use crate::calendar::prelude::CommonDate;
+use crate::calendar::prelude::CommonWeekOfYear;
+use crate::calendar::prelude::GuaranteedMonth;
+use crate::calendar::prelude::HasLeapYears;
+use crate::calendar::prelude::Quarter;
+use crate::calendar::prelude::ToFromCommonDate;
+use crate::calendar::rest_days::RestDays;
+use crate::calendar::rest_days::RestWeekdays;
+use crate::calendar::AllowYearZero;
+use crate::calendar::CalendarMoment;
+use crate::calendar::OrdinalDate;
+use crate::calendar::ToFromOrdinalDate;
+use crate::common::error::CalendarError;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::CalculatedBounds;
+use crate::day_count::Epoch;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use num_traits::FromPrimitive;
+use std::fmt;
+use std::num::NonZero;
+use std::ops::RangeInclusive;
+
+const TABLE_START_GREGORIAN: CommonDate = CommonDate {
+    year: 1882,
+    month: 11,
+    day: 12,
+};
+
+/// Years covered by [`MONTH_LENGTHS`]
+const TABLE_YEARS: RangeInclusive<i32> = 1300..=1600;
+
+/// Lengths of the months of each year in the table
+///
+/// The most significant of the 12 bits is Muharram. A set bit is a 30 day month, and a
+/// clear bit is a 29 day month. This is the same data as the Umm al-Qura tables
+/// distributed with ICU and OpenJDK.
+const MONTH_LENGTHS: [u16; 301] = [
+    0b1010_1010_1010, //1300
+    0b1101_0101_0100, //1301
+    0b1110_1100_1001, //1302
+    0b0110_1101_0100, //1303
+    0b0110_1110_1010, //1304
+    0b0011_0110_1100, //1305
+    0b1010_1010_1101, //1306
+    0b0101_0101_0101, //1307
+    0b0110_1010_1001, //1308
+    0b0111_1001_0010, //1309
+    0b1011_1010_1001, //1310
+    0b0101_1101_0100, //1311
+    0b1010_1101_1010, //1312
+    0b0101_0101_1100, //1313
+    0b1101_0010_1101, //1314
+    0b0110_1001_0101, //1315
+    0b0111_0100_1010, //1316
+    0b1011_0101_0100, //1317
+    0b1011_0110_1010, //1318
+    0b0101_1010_1101, //1319
+    0b0100_1010_1110, //1320
+    0b1010_0100_1111, //1321
+    0b0101_0001_0111, //1322
+    0b0110_1000_1011, //1323
+    0b0110_1010_0101, //1324
+    0b1010_1101_0101, //1325
+    0b0010_1101_0110, //1326
+    0b1001_0101_1011, //1327
+    0b0100_1001_1101, //1328
+    0b1010_0100_1101, //1329
+    0b1101_0010_0110, //1330
+    0b1101_1001_0101, //1331
+    0b0101_1010_1100, //1332
+    0b1001_1011_0110, //1333
+    0b0010_1011_1010, //1334
+    0b1010_0101_1011, //1335
+    0b0101_0010_1011, //1336
+    0b1010_1001_0101, //1337
+    0b0110_1100_1010, //1338
+    0b1010_1110_1001, //1339
+    0b0010_1111_0100, //1340
+    0b1001_0111_0110, //1341
+    0b0010_1011_0110, //1342
+    0b1001_0101_0110, //1343
+    0b1010_1100_1010, //1344
+    0b1011_1010_0100, //1345
+    0b1011_1101_0010, //1346
+    0b0101_1101_1001, //1347
+    0b0010_1101_1100, //1348
+    0b1001_0110_1101, //1349
+    0b0101_0100_1101, //1350
+    0b1010_1010_0101, //1351
+    0b1011_0101_0010, //1352
+    0b1011_1010_0101, //1353
+    0b0101_1011_0100, //1354
+    0b1001_1011_0110, //1355
+    0b0101_0101_0111, //1356
+    0b0010_1001_0111, //1357
+    0b0101_0100_1011, //1358
+    0b0110_1010_0011, //1359
+    0b0111_0101_0010, //1360
+    0b1011_0110_0101, //1361
+    0b0101_0110_1010, //1362
+    0b1010_1010_1011, //1363
+    0b0101_0010_1011, //1364
+    0b1100_1001_0101, //1365
+    0b1101_0100_1010, //1366
+    0b1101_1010_0101, //1367
+    0b0101_1100_1010, //1368
+    0b1010_1101_0110, //1369
+    0b1001_0101_0111, //1370
+    0b0100_1010_1011, //1371
+    0b1001_0100_1011, //1372
+    0b1010_1010_0101, //1373
+    0b1011_0101_0010, //1374
+    0b1011_0110_1010, //1375
+    0b0101_0111_0101, //1376
+    0b0010_0111_0110, //1377
+    0b1000_1011_0111, //1378
+    0b0100_0101_1011, //1379
+    0b0101_0101_0101, //1380
+    0b0101_1010_1001, //1381
+    0b0101_1011_0100, //1382
+    0b1001_1101_1010, //1383
+    0b0100_1101_1101, //1384
+    0b0010_0110_1110, //1385
+    0b1001_0011_0110, //1386
+    0b1010_1010_1010, //1387
+    0b1101_0101_0100, //1388
+    0b1101_1011_0010, //1389
+    0b0101_1101_0101, //1390
+    0b0010_1101_1010, //1391
+    0b1001_0101_1011, //1392
+    0b0100_1010_1011, //1393
+    0b1010_0101_0101, //1394
+    0b1011_0100_1001, //1395
+    0b1011_0110_0100, //1396
+    0b1011_0111_0001, //1397
+    0b0101_1011_0100, //1398
+    0b1010_1011_0101, //1399
+    0b1010_0101_0101, //1400
+    0b1101_0010_0101, //1401
+    0b1110_1001_0010, //1402
+    0b1110_1100_1001, //1403
+    0b0110_1101_0100, //1404
+    0b1010_1110_1001, //1405
+    0b1001_0110_1011, //1406
+    0b0100_1010_1011, //1407
+    0b1010_1001_0011, //1408
+    0b1101_0100_1001, //1409
+    0b1101_1010_0100, //1410
+    0b1101_1011_0010, //1411
+    0b1010_1011_1001, //1412
+    0b0100_1011_1010, //1413
+    0b1010_0101_1011, //1414
+    0b0101_0010_1011, //1415
+    0b1010_1001_0101, //1416
+    0b1011_0010_1010, //1417
+    0b1011_0101_0101, //1418
+    0b0101_0101_1100, //1419
+    0b0100_1011_1101, //1420
+    0b0010_0011_1101, //1421
+    0b1001_0001_1101, //1422
+    0b1010_1001_0101, //1423
+    0b1011_0100_1010, //1424
+    0b1011_0101_1010, //1425
+    0b0101_0110_1101, //1426
+    0b0010_1011_0110, //1427
+    0b1001_0011_1011, //1428
+    0b0100_1001_1011, //1429
+    0b0110_0101_0101, //1430
+    0b0110_1010_1001, //1431
+    0b0111_0101_0100, //1432
+    0b1011_0110_1010, //1433
+    0b0101_0110_1100, //1434
+    0b1010_1010_1101, //1435
+    0b0101_0101_0101, //1436
+    0b1011_0010_1001, //1437
+    0b1011_1001_0010, //1438
+    0b1011_1010_1001, //1439
+    0b0101_1101_0100, //1440
+    0b1010_1101_1010, //1441
+    0b0101_0101_1010, //1442
+    0b1010_1010_1011, //1443
+    0b0101_1001_0101, //1444
+    0b0111_0100_1001, //1445
+    0b0111_0110_0100, //1446
+    0b1011_1010_1010, //1447
+    0b0101_1011_0101, //1448
+    0b0010_1011_0110, //1449
+    0b1010_0101_0110, //1450
+    0b1110_0100_1101, //1451
+    0b1011_0010_0101, //1452
+    0b1011_0101_0010, //1453
+    0b1011_0110_1010, //1454
+    0b0101_1010_1101, //1455
+    0b0010_1010_1110, //1456
+    0b1001_0010_1111, //1457
+    0b0100_1001_0111, //1458
+    0b0110_0100_1011, //1459
+    0b0110_1010_0101, //1460
+    0b0110_1010_1100, //1461
+    0b1010_1101_0110, //1462
+    0b0101_0101_1101, //1463
+    0b0100_1001_1101, //1464
+    0b1010_0100_1101, //1465
+    0b1101_0001_0110, //1466
+    0b1101_1001_0101, //1467
+    0b0101_1010_1010, //1468
+    0b0101_1011_0101, //1469
+    0b0010_1101_1010, //1470
+    0b1001_0101_1011, //1471
+    0b0100_1010_1101, //1472
+    0b0101_1001_0101, //1473
+    0b0110_1100_1010, //1474
+    0b0110_1110_0100, //1475
+    0b1010_1110_1010, //1476
+    0b0100_1111_0101, //1477
+    0b0010_1011_0110, //1478
+    0b1001_0101_0110, //1479
+    0b1010_1010_1010, //1480
+    0b1011_0101_0100, //1481
+    0b1011_1101_0010, //1482
+    0b0101_1101_1001, //1483
+    0b0010_1110_1010, //1484
+    0b1001_0110_1101, //1485
+    0b0100_1010_1101, //1486
+    0b1010_1001_0101, //1487
+    0b1011_0100_1010, //1488
+    0b1011_1010_0101, //1489
+    0b0101_1011_0010, //1490
+    0b1001_1011_0101, //1491
+    0b0100_1101_0110, //1492
+    0b1010_1001_0111, //1493
+    0b0101_0100_0111, //1494
+    0b0110_1001_0011, //1495
+    0b0111_0100_1001, //1496
+    0b1011_0101_0101, //1497
+    0b0101_0110_1010, //1498
+    0b1010_0110_1011, //1499
+    0b0101_0010_1011, //1500
+    0b1010_1000_1011, //1501
+    0b1101_0100_0110, //1502
+    0b1101_1010_0011, //1503
+    0b0101_1100_1010, //1504
+    0b1010_1101_0110, //1505
+    0b0100_1101_1011, //1506
+    0b0010_0110_1011, //1507
+    0b1001_0100_1011, //1508
+    0b1010_1010_0101, //1509
+    0b1011_0101_0010, //1510
+    0b1011_0110_1001, //1511
+    0b0101_0111_0101, //1512
+    0b0001_0111_0110, //1513
+    0b1000_1011_0111, //1514
+    0b0010_0101_1011, //1515
+    0b0101_0010_1011, //1516
+    0b0101_0110_0101, //1517
+    0b0101_1011_0100, //1518
+    0b1001_1101_1010, //1519
+    0b0100_1110_1101, //1520
+    0b0001_0110_1101, //1521
+    0b1000_1011_0110, //1522
+    0b1010_1010_0110, //1523
+    0b1101_0101_0010, //1524
+    0b1101_1010_1001, //1525
+    0b0101_1101_0100, //1526
+    0b1010_1101_1010, //1527
+    0b1001_0101_1011, //1528
+    0b0100_1010_1011, //1529
+    0b0110_0101_0011, //1530
+    0b0111_0010_1001, //1531
+    0b0111_0110_0010, //1532
+    0b1011_1010_1001, //1533
+    0b0101_1011_0010, //1534
+    0b1010_1011_0101, //1535
+    0b0101_0101_0101, //1536
+    0b1011_0010_0101, //1537
+    0b1101_1001_0010, //1538
+    0b1110_1100_1001, //1539
+    0b0110_1101_0010, //1540
+    0b1010_1110_1001, //1541
+    0b0101_0110_1011, //1542
+    0b0100_1010_1011, //1543
+    0b1010_0101_0101, //1544
+    0b1101_0010_1001, //1545
+    0b1101_0101_0100, //1546
+    0b1101_1010_1010, //1547
+    0b1001_1011_0101, //1548
+    0b0100_1011_1010, //1549
+    0b1010_0011_1011, //1550
+    0b0100_1001_1011, //1551
+    0b1010_0100_1101, //1552
+    0b1010_1010_1010, //1553
+    0b1010_1101_0101, //1554
+    0b0010_1101_1010, //1555
+    0b1001_0101_1101, //1556
+    0b0100_0101_1110, //1557
+    0b1010_0010_1110, //1558
+    0b1100_1001_1010, //1559
+    0b1101_0101_0101, //1560
+    0b0110_1011_0010, //1561
+    0b0110_1011_1001, //1562
+    0b0100_1011_1010, //1563
+    0b1010_0101_1101, //1564
+    0b0101_0010_1101, //1565
+    0b1010_1001_0101, //1566
+    0b1011_0101_0010, //1567
+    0b1011_1010_1000, //1568
+    0b1011_1011_0100, //1569
+    0b0101_1011_1001, //1570
+    0b0010_1101_1010, //1571
+    0b1001_0101_1010, //1572
+    0b1011_0100_1010, //1573
+    0b1101_1010_0100, //1574
+    0b1110_1101_0001, //1575
+    0b0110_1110_1000, //1576
+    0b1011_0110_1010, //1577
+    0b0101_0110_1101, //1578
+    0b0101_0011_0101, //1579
+    0b0110_1001_0101, //1580
+    0b1101_0100_1010, //1581
+    0b1101_1010_1000, //1582
+    0b1101_1101_0100, //1583
+    0b0110_1101_1010, //1584
+    0b0101_0101_1011, //1585
+    0b0010_1001_1101, //1586
+    0b0110_0010_1011, //1587
+    0b1011_0001_0101, //1588
+    0b1011_0100_1010, //1589
+    0b1011_1001_0101, //1590
+    0b0101_1010_1010, //1591
+    0b1010_1010_1110, //1592
+    0b1001_0010_1110, //1593
+    0b1100_1000_1111, //1594
+    0b0101_0010_0111, //1595
+    0b0110_1001_0101, //1596
+    0b0110_1010_1010, //1597
+    0b1010_1101_0110, //1598
+    0b0101_0101_1101, //1599
+    0b0010_1001_1101, //1600
+];
+
+const fn days_before_table_year() -> [u32; 302] {
+    let mut result = [0; 302];
+    let mut i = 0;
+    while i < 301 {
+        result[i + 1] = result[i] + 348 + MONTH_LENGTHS[i].count_ones();
+        i += 1;
+    }
+    result
+}
+
+const DAYS_BEFORE_TABLE_YEAR: [u32; 302] = days_before_table_year();
+
+/// Represents a date in the Umm al-Qura Islamic calendar
+///
+/// ## Introduction
+///
+/// The Umm al-Qura calendar is the Islamic calendar used for civil purposes in Saudi
+/// Arabia. The start of each month is determined in advance from astronomical
+/// calculations, and published as a table.
+///
+/// `IslamicUmmAlQura` is **table driven**. It contains the published month lengths for
+/// the years 1300 to 1600 AH inclusive (12 November 1882 to 25 November 2174 Common Era).
+/// Outside of this range, it falls back to the tabular Islamic calendar: every date
+/// before 1300 AH or after 1600 AH is the same as in [`IslamicArith`]. The first and last
+/// days of the table happen to line up exactly with [`IslamicArith`], so there are no
+/// gaps or overlaps at the edges of the table.
+///
+/// Use [`IslamicUmmAlQura::is_tabulated`] to check if a year is within the table.
+///
+/// Dates outside the table, and dates announced after the table was published, may
+/// differ from the dates used in practice.
+///
+/// ## Basic Structure
+///
+/// Years are divided into 12 months. Each month has either 29 or 30 days. Within the table
+/// there is no fixed pattern, so a year may have 354 or 355 days.
+///
+/// ## Epoch
+///
+/// The epoch is the same as [`IslamicArith`].
+///
+/// ## Representation and Examples
+///
+/// The months are represented in this crate as [`IslamicMonth`].
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// let g = Gregorian::try_new(2024, GregorianMonth::July, 7).unwrap();
+/// let u = g.convert::<IslamicUmmAlQura>();
+/// assert_eq!(u, IslamicUmmAlQura::try_new(1446, IslamicMonth::Muharram, 1).unwrap());
+/// assert!(IslamicUmmAlQura::is_tabulated(1446));
+///
+/// let a = g.convert::<IslamicArith>();
+/// assert_eq!(a, IslamicArith::try_new(1445, IslamicMonth::DhuAlHijjah, 30).unwrap());
+/// ```
+///
+/// ## Further reading
+/// + [Wikipedia](https://en.wikipedia.org/wiki/Islamic_calendar#Saudi_Arabia's_Umm_al-Qura_calendar)
+/// + [ICU](https://unicode-org.github.io/icu/userguide/datetime/calendar/)
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
+pub struct IslamicUmmAlQura(CommonDate);
+
+impl fmt::Debug for IslamicUmmAlQura {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<IslamicMonth>("IslamicUmmAlQura", f)
+    }
+}
+
+impl IslamicUmmAlQura {
+    /// [`true`] if the month lengths of a year are taken from the table
+    pub fn is_tabulated(year: i32) -> bool {
+        TABLE_YEARS.contains(&year)
+    }
+
+    fn table_start() -> i64 {
+        Gregorian::from_common_date_unchecked(TABLE_START_GREGORIAN)
+            .to_fixed()
+            .get_day_i()
+    }
+
+    fn table_index(year: i32) -> usize {
+        (year - TABLE_YEARS.start()) as usize
+    }
+
+    fn prior_elapsed_days(year: i32) -> i64 {
+        if Self::is_tabulated(year) {
+            let days = DAYS_BEFORE_TABLE_YEAR[Self::table_index(year)] as i64;
+            Self::table_start() - 1 + days
+        } else {
+            IslamicArith::prior_elapsed_days(year)
+        }
+    }
+
+    fn year_length(year: i32) -> u16 {
+        if Self::is_tabulated(year) {
+            let i = Self::table_index(year);
+            (DAYS_BEFORE_TABLE_YEAR[i + 1] - DAYS_BEFORE_TABLE_YEAR[i]) as u16
+        } else if IslamicArith::is_leap(year) {
+            355
+        } else {
+            354
+        }
+    }
+}
+
+impl AllowYearZero for IslamicUmmAlQura {}
+
+impl ToFromOrdinalDate for IslamicUmmAlQura {
+    fn valid_ordinal(ord: OrdinalDate) -> Result<(), CalendarError> {
+        if ord.day_of_year > 0 && ord.day_of_year <= Self::year_length(ord.year) {
+            Ok(())
+        } else {
+            Err(CalendarError::InvalidDayOfYear)
+        }
+    }
+
+    fn ordinal_from_fixed(fixed_date: Fixed) -> OrdinalDate {
+        let date = fixed_date.get_day_i();
+        let offset = date - Self::table_start();
+        if offset < 0 || offset >= (DAYS_BEFORE_TABLE_YEAR[301] as i64) {
+            return IslamicArith::ordinal_from_fixed(fixed_date);
+        }
+        let i = DAYS_BEFORE_TABLE_YEAR.partition_point(|d| (*d as i64) <= offset) - 1;
+        OrdinalDate {
+            year: TABLE_YEARS.start() + (i as i32),
+            day_of_year: (offset - (DAYS_BEFORE_TABLE_YEAR[i] as i64) + 1) as u16,
+        }
+    }
+
+    fn to_ordinal(self) -> OrdinalDate {
+        let prior: u16 = (1..self.0.month)
+            .filter_map(IslamicMonth::from_u8)
+            .map(|m| Self::month_length(self.0.year, m) as u16)
+            .sum();
+        OrdinalDate {
+            year: self.0.year,
+            day_of_year: prior + (self.0.day as u16),
+        }
+    }
+
+    fn from_ordinal_unchecked(ord: OrdinalDate) -> Self {
+        let mut day = ord.day_of_year;
+        let mut month = IslamicMonth::Muharram;
+        loop {
+            let length = Self::month_length(ord.year, month) as u16;
+            if day <= length || month == IslamicMonth::DhuAlHijjah {
+                break;
+            }
+            day -= length;
+            month = IslamicMonth::from_u8((month as u8) + 1).expect("Not the last month");
+        }
+        IslamicUmmAlQura(CommonDate::new(ord.year, month as u8, day as u8))
+    }
+}
+
+impl HasLeapYears for IslamicUmmAlQura {
+    /// [`true`] if the year has 355 days
+    fn is_leap(year: i32) -> bool {
+        Self::year_length(year) > 354
+    }
+}
+
+impl RestDays for IslamicUmmAlQura {
+    const REST_WEEKDAYS: RestWeekdays = RestWeekdays::FRIDAY;
+}
+
+impl CalculatedBounds for IslamicUmmAlQura {}
+
+impl Epoch for IslamicUmmAlQura {
+    fn epoch() -> Fixed {
+        IslamicArith::epoch()
+    }
+}
+
+impl FromFixed for IslamicUmmAlQura {
+    fn from_fixed(fixed_date: Fixed) -> IslamicUmmAlQura {
+        Self::from_ordinal_unchecked(Self::ordinal_from_fixed(fixed_date))
+    }
+}
+
+impl ToFixed for IslamicUmmAlQura {
+    fn to_fixed(self) -> Fixed {
+        let offset_prior = Self::prior_elapsed_days(self.0.year);
+        Fixed::cast_new(offset_prior + (self.to_ordinal().day_of_year as i64))
+    }
+}
+
+impl ToFromCommonDate<IslamicMonth> for IslamicUmmAlQura {
+    const EFFECTIVE_YEAR_MIN: i32 = IslamicArith::EFFECTIVE_YEAR_MIN;
+    const EFFECTIVE_YEAR_MAX: i32 = IslamicArith::EFFECTIVE_YEAR_MAX;
+
+    fn to_common_date(self) -> CommonDate {
+        self.0
+    }
+
+    fn from_common_date_unchecked(date: CommonDate) -> Self {
+        debug_assert!(Self::valid_ymd(date).is_ok());
+        Self(date)
+    }
+
+    fn valid_ymd(date: CommonDate) -> Result<(), CalendarError> {
+        match IslamicMonth::from_u8(date.month) {
+            None => Err(CalendarError::InvalidMonth),
+            Some(m) if date.day < 1 || date.day > Self::month_length(date.year, m) => {
+                Err(CalendarError::InvalidDay)
+            }
+            Some(_) => Ok(()),
+        }
+    }
+
+    fn year_end_date(year: i32) -> CommonDate {
+        let m = IslamicMonth::DhuAlHijjah;
+        CommonDate::new(year, m as u8, Self::month_length(year, m))
+    }
+
+    fn month_length(year: i32, month: IslamicMonth) -> u8 {
+        if Self::is_tabulated(year) {
+            let bits = MONTH_LENGTHS[Self::table_index(year)];
+            if bits & (1 << (12 - (month as u16))) != 0 {
+                30
+            } else {
+                29
+            }
+        } else {
+            IslamicArith::month_length(year, month)
+        }
+    }
+}
+
+impl Quarter for IslamicUmmAlQura {
+    fn quarter(self) -> NonZero<u8> {
+        NonZero::new(((self.to_common_date().month - 1) / 3) + 1).expect("(m-1)/3 > -1")
+    }
+}
+
+impl GuaranteedMonth<IslamicMonth> for IslamicUmmAlQura {}
+impl CommonWeekOfYear<IslamicMonth> for IslamicUmmAlQura {}
+
+/// Represents a date *and time* in the Umm al-Qura Islamic calendar
+pub type IslamicUmmAlQuraMoment = CalendarMoment<IslamicUmmAlQura>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use proptest::proptest;
+
+    #[test]
+    fn table_edges() {
+        let first = IslamicUmmAlQura::try_year_start(1300).unwrap();
+        assert_eq!(
+            first.to_fixed(),
+            IslamicArith::try_year_start(1300).unwrap().to_fixed()
+        );
+        let g = Gregorian::from_fixed(first.to_fixed());
+        assert_eq!(g.to_common_date(), TABLE_START_GREGORIAN);
+        let after = IslamicUmmAlQura::try_year_start(1601).unwrap();
+        assert_eq!(
+            after.to_fixed(),
+            IslamicArith::try_year_start(1601).unwrap().to_fixed()
+        );
+        let last = IslamicUmmAlQura::try_year_end(1600).unwrap();
+        assert_eq!(
+            last.to_fixed().get_day_i() + 1,
+            after.to_fixed().get_day_i()
+        );
+        assert_eq!(
+            Gregorian::from_fixed(last.to_fixed()).to_common_date(),
+            CommonDate::new(2174, 11, 25)
+        );
+        assert!(!IslamicUmmAlQura::is_tabulated(1299));
+        assert!(!IslamicUmmAlQura::is_tabulated(1601));
+    }
+
+    #[test]
+    fn known_dates() {
+        let d_list = [
+            (CommonDate::new(1300, 1, 1), CommonDate::new(1882, 11, 12)),
+            (CommonDate::new(1400, 1, 1), CommonDate::new(1979, 11, 21)),
+            (CommonDate::new(1420, 10, 1), CommonDate::new(2000, 1, 8)),
+            (CommonDate::new(1445, 9, 1), CommonDate::new(2024, 3, 11)),
+            (CommonDate::new(1446, 1, 1), CommonDate::new(2024, 7, 7)),
+            (CommonDate::new(1500, 12, 29), CommonDate::new(2077, 11, 15)),
+            (CommonDate::new(1600, 12, 29), CommonDate::new(2174, 11, 24)),
+        ];
+        for (u, g) in d_list {
+            let f = IslamicUmmAlQura::try_from_common_date(u)
+                .unwrap()
+                .to_fixed();
+            assert_eq!(Gregorian::from_fixed(f).to_common_date(), g);
+            assert_eq!(IslamicUmmAlQura::from_fixed(f).to_common_date(), u);
+        }
+    }
+
+    #[test]
+    fn year_lengths() {
+        for year in 1290..1610 {
+            let total: u16 = (1..=12)
+                .map(|m| {
+                    IslamicUmmAlQura::month_length(year, IslamicMonth::from_u8(m).unwrap()) as u16
+                })
+                .sum();
+            assert_eq!(total, IslamicUmmAlQura::year_length(year));
+            assert!(total == 354 || total == 355);
+            assert_eq!(IslamicUmmAlQura::is_leap(year), total == 355);
+            let end = IslamicUmmAlQura::try_year_end(year).unwrap();
+            assert_eq!(end.to_ordinal().day_of_year, total);
+            let next = IslamicUmmAlQura::try_year_start(year + 1).unwrap();
+            assert_eq!(end.to_fixed().get_day_i() + 1, next.to_fixed().get_day_i());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn same_as_arith_outside_table(t in FIXED_MIN..FIXED_MAX) {
+            let f = Fixed::new(t);
+            let u = IslamicUmmAlQura::from_fixed(f);
+            let a = IslamicArith::from_fixed(f);
+            if !IslamicUmmAlQura::is_tabulated(u.year()) {
+                assert_eq!(u.to_common_date(), a.to_common_date());
+            }
+            assert_eq!(u.to_fixed(), f.to_day());
+        }
+
+        #[test]
+        fn table_round_trip(t in 687337.0..794002.0) {
+            let f = Fixed::new(t).to_day();
+            let u = IslamicUmmAlQura::from_fixed(f);
+            assert!(IslamicUmmAlQura::is_tabulated(u.year()));
+            assert_eq!(u.to_fixed(), f);
+            let ord = u.to_ordinal();
+            assert_eq!(IslamicUmmAlQura::try_from_ordinal(ord).unwrap(), u);
+            assert_eq!(IslamicUmmAlQura::ordinal_from_fixed(f), ord);
+        }
+    }
+}
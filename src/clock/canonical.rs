@@ -0,0 +1,74 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::clock::ClockTime;
+use crate::clock::TimeOfDay;
+use num_traits::FromPrimitive;
+
+/// Represents one of the canonical hours of the Christian liturgy
+///
+/// ## Introduction
+///
+/// The canonical hours divide the day into periods of prayer. Historically, the exact time
+/// of each hour depended on the time of sunrise and sunset. This crate uses a simplified
+/// system in which the hours start every three hours, beginning at midnight.
+///
+/// ## Representation and Examples
+///
+/// ```
+/// use radnelac::clock::*;
+///
+/// let t = ClockTime::try_new(13, 30, 0.0).unwrap();
+/// assert_eq!(CanonicalHour::from_clock(t), CanonicalHour::Sext);
+/// assert_eq!(CanonicalHour::Vespers.start(), ClockTime::try_new(18, 0, 0.0).unwrap());
+/// ```
+///
+/// ## Further reading
+/// + [Wikipedia](https://en.wikipedia.org/wiki/Canonical_hours)
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, FromPrimitive, ToPrimitive)]
+pub enum CanonicalHour {
+    Matins = 0,
+    Lauds,
+    Prime,
+    Terce,
+    Sext,
+    None,
+    Vespers,
+    Compline,
+}
+
+impl CanonicalHour {
+    /// Calculate the most recent canonical hour
+    pub fn from_clock(clock: ClockTime) -> CanonicalHour {
+        CanonicalHour::from_u8(clock.hours.min(23) / 3).expect("Within 8 hours")
+    }
+
+    /// Calculate the most recent canonical hour
+    pub fn from_time_of_day(t: TimeOfDay) -> CanonicalHour {
+        CanonicalHour::from_clock(t.to_clock())
+    }
+
+    /// Start of the canonical hour
+    pub fn start(self) -> ClockTime {
+        ClockTime::new_unchecked((self as u8) * 3, 0, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_hour() {
+        for h in 0..24 {
+            let c = CanonicalHour::from_clock(ClockTime::try_new(h, 59, 0.0).unwrap());
+            assert!(c.start().hours <= h && c.start().hours + 3 > h);
+            assert_eq!(CanonicalHour::from_clock(c.start()), c);
+        }
+        assert_eq!(
+            CanonicalHour::from_time_of_day(TimeOfDay::noon()),
+            CanonicalHour::Sext
+        );
+    }
+}
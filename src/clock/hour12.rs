@@ -0,0 +1,113 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::clock::ClockTime;
+use crate::common::math::TermNum;
+use crate::CalendarError;
+
+/// Represents either half of the day on a 12-hour clock
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub enum HalfDay {
+    /// Ante meridiem, from midnight until noon
+    AM,
+    /// Post meridiem, from noon until midnight
+    PM,
+}
+
+/// Represents an hour on a 12-hour clock
+///
+/// Midnight is 12 AM and noon is 12 PM.
+///
+/// ```
+/// use radnelac::clock::*;
+///
+/// let h = Hour12::from_hour_0_to_23(0).unwrap();
+/// assert_eq!(h.hour(), 12);
+/// assert_eq!(h.half_day(), HalfDay::AM);
+/// let h = Hour12::try_new(1, HalfDay::PM).unwrap();
+/// assert_eq!(h.to_hour_0_to_23(), 13);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Hour12 {
+    hour: u8,
+    half_day: HalfDay,
+}
+
+impl Hour12 {
+    /// Attempt to create an hour on a 12-hour clock
+    ///
+    /// Returns an error if `hour` is not from 1 to 12.
+    pub fn try_new(hour: u8, half_day: HalfDay) -> Result<Hour12, CalendarError> {
+        if !(1..=12).contains(&hour) {
+            Err(CalendarError::InvalidHour)
+        } else {
+            Ok(Hour12 { hour, half_day })
+        }
+    }
+
+    /// Attempt to create an hour on a 12-hour clock from an hour on a 24-hour clock
+    pub fn from_hour_0_to_23(hour: u8) -> Result<Hour12, CalendarError> {
+        if hour > 23 {
+            Err(CalendarError::InvalidHour)
+        } else {
+            let half_day = if hour < 12 { HalfDay::AM } else { HalfDay::PM };
+            let h = (hour as i64).adjusted_remainder(12) as u8;
+            Ok(Hour12 { hour: h, half_day })
+        }
+    }
+
+    /// Create an hour on a 12-hour clock from the hour of a [`ClockTime`]
+    pub fn from_clock(clock: ClockTime) -> Result<Hour12, CalendarError> {
+        Hour12::from_hour_0_to_23(clock.hours)
+    }
+
+    /// Hour from 1 to 12
+    pub fn hour(self) -> u8 {
+        self.hour
+    }
+
+    pub fn half_day(self) -> HalfDay {
+        self.half_day
+    }
+
+    /// Hour from 0 to 23
+    pub fn to_hour_0_to_23(self) -> u8 {
+        match self.half_day {
+            HalfDay::AM => self.hour % 12,
+            HalfDay::PM => (self.hour % 12) + 12,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for h in 0..24 {
+            let h12 = Hour12::from_hour_0_to_23(h).unwrap();
+            assert_eq!(h12.to_hour_0_to_23(), h);
+            assert_eq!(h12, Hour12::try_new(h12.hour(), h12.half_day()).unwrap());
+            let c = ClockTime::try_new(h, 0, 0.0).unwrap();
+            assert_eq!(h12.hour(), c.hour_1_to_12());
+            assert_eq!(Hour12::from_clock(c).unwrap(), h12);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(Hour12::from_hour_0_to_23(24).is_err());
+        assert!(Hour12::try_new(0, HalfDay::AM).is_err());
+        assert!(Hour12::try_new(13, HalfDay::PM).is_err());
+    }
+
+    #[test]
+    fn noon_and_midnight() {
+        let noon = Hour12::from_hour_0_to_23(12).unwrap();
+        assert_eq!((noon.hour(), noon.half_day()), (12, HalfDay::PM));
+        let midnight = Hour12::from_hour_0_to_23(0).unwrap();
+        assert_eq!((midnight.hour(), midnight.half_day()), (12, HalfDay::AM));
+    }
+}
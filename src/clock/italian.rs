@@ -0,0 +1,77 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::clock::TimeOfDay;
+use crate::CalendarError;
+
+/// Represents an hour counted from sunset
+///
+/// ## Introduction
+///
+/// Italian hours (*ore italiche*) were used in Italy and elsewhere from the late Middle
+/// Ages until the 18th century. The day was divided into 24 equal hours, counted from
+/// sunset. The first hour started at sunset, and the 24th hour ended at the next sunset.
+///
+/// This crate does not predict astronomical events, so the time of sunset must be provided.
+///
+/// ## Representation and Examples
+///
+/// ```
+/// use radnelac::clock::*;
+///
+/// let sunset = TimeOfDay::new(0.75); // 18:00
+/// let t = TimeOfDay::new(0.8); // 19:12
+/// assert_eq!(ItalianHour::try_from_time_of_day(t, sunset).unwrap().hour(), 2);
+/// let t = TimeOfDay::new(0.5); // 12:00
+/// assert_eq!(ItalianHour::try_from_time_of_day(t, sunset).unwrap().hour(), 19);
+/// ```
+///
+/// ## Further reading
+/// + [Wikipedia](https://en.wikipedia.org/wiki/Italian_hours)
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct ItalianHour(u8);
+
+impl ItalianHour {
+    /// Attempt to calculate the Italian hour of a time of day, given the time of sunset
+    pub fn try_from_time_of_day(t: TimeOfDay, sunset: TimeOfDay) -> Result<Self, CalendarError> {
+        if t.get().is_nan() || sunset.get().is_nan() {
+            return Err(CalendarError::EncounteredNaN);
+        }
+        let since_sunset = (t.get() - sunset.get()).rem_euclid(1.0);
+        let h = ((since_sunset * 24.0).floor() as u8).min(23);
+        Ok(ItalianHour(h + 1))
+    }
+
+    /// Hour from 1 to 24
+    pub fn hour(self) -> u8 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::proptest;
+
+    #[test]
+    fn at_sunset() {
+        let sunset = TimeOfDay::new(0.8);
+        let h = ItalianHour::try_from_time_of_day(sunset, sunset).unwrap();
+        assert_eq!(h.hour(), 1);
+        let before = TimeOfDay::new(0.8 - 1e-6);
+        let h = ItalianHour::try_from_time_of_day(before, sunset).unwrap();
+        assert_eq!(h.hour(), 24);
+        let nan = TimeOfDay::new(f64::NAN);
+        assert!(ItalianHour::try_from_time_of_day(nan, sunset).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn range(t in 0.0..1.0, sunset in 0.0..1.0) {
+            let h = ItalianHour::try_from_time_of_day(TimeOfDay::new(t), TimeOfDay::new(sunset));
+            let h = h.unwrap().hour();
+            assert!((1..=24).contains(&h));
+        }
+    }
+}
@@ -0,0 +1,106 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::clock::ClockTime;
+use crate::clock::HalfDay;
+use crate::clock::TimeOfDay;
+use num_traits::FromPrimitive;
+
+/// Represents one quarter of the day, such as "morning" or "evening"
+///
+/// ## Introduction
+///
+/// Historical sources often describe a time only roughly, for example "in the evening".
+/// This crate uses a simplified system in which each part of the day is exactly six hours
+/// long, beginning at midnight.
+///
+/// ## Representation and Examples
+///
+/// ```
+/// use radnelac::clock::*;
+///
+/// let t = ClockTime::try_new(13, 30, 0.0).unwrap();
+/// assert_eq!(PartOfDay::from_clock(t), PartOfDay::Afternoon);
+/// assert_eq!(PartOfDay::Afternoon.half_day(), HalfDay::PM);
+/// assert_eq!(PartOfDay::Evening.start(), ClockTime::try_new(18, 0, 0.0).unwrap());
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, FromPrimitive, ToPrimitive)]
+pub enum PartOfDay {
+    /// From midnight until 06:00
+    Night = 0,
+    /// From 06:00 until noon
+    Morning,
+    /// From noon until 18:00
+    Afternoon,
+    /// From 18:00 until midnight
+    Evening,
+}
+
+impl PartOfDay {
+    /// Calculate the current part of the day
+    pub fn from_clock(clock: ClockTime) -> PartOfDay {
+        PartOfDay::from_u8(clock.hours.min(23) / 6).expect("Within 4 parts")
+    }
+
+    /// Calculate the current part of the day
+    pub fn from_time_of_day(t: TimeOfDay) -> PartOfDay {
+        PartOfDay::from_clock(t.to_clock())
+    }
+
+    /// Start of the part of the day
+    pub fn start(self) -> ClockTime {
+        ClockTime::new_unchecked((self as u8) * 6, 0, 0.0)
+    }
+
+    /// Half of the day containing this part of the day
+    pub fn half_day(self) -> HalfDay {
+        match self {
+            PartOfDay::Night | PartOfDay::Morning => HalfDay::AM,
+            PartOfDay::Afternoon | PartOfDay::Evening => HalfDay::PM,
+        }
+    }
+}
+
+impl ClockTime {
+    /// Calculate the current part of the day
+    pub fn part_of_day(self) -> PartOfDay {
+        PartOfDay::from_clock(self)
+    }
+
+    /// Calculate the current half of the day
+    pub fn half_day(self) -> HalfDay {
+        self.part_of_day().half_day()
+    }
+}
+
+impl TimeOfDay {
+    /// Calculate the current part of the day
+    pub fn part_of_day(self) -> PartOfDay {
+        PartOfDay::from_time_of_day(self)
+    }
+
+    /// Calculate the current half of the day
+    pub fn half_day(self) -> HalfDay {
+        self.part_of_day().half_day()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::Hour12;
+
+    #[test]
+    fn every_hour() {
+        for h in 0..24 {
+            let c = ClockTime::try_new(h, 59, 0.0).unwrap();
+            let p = c.part_of_day();
+            assert!(p.start().hours <= h && p.start().hours + 6 > h);
+            assert_eq!(PartOfDay::from_clock(p.start()), p);
+            assert_eq!(c.half_day(), Hour12::from_clock(c).unwrap().half_day());
+        }
+        assert_eq!(TimeOfDay::noon().part_of_day(), PartOfDay::Afternoon);
+        assert_eq!(TimeOfDay::midnight().half_day(), HalfDay::AM);
+    }
+}
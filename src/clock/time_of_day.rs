@@ -3,6 +3,8 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use crate::common::math::TermNum;
+use crate::common::units::day_fraction_to_seconds;
+use crate::common::units::SECONDS_PER_DAY;
 use crate::day_count::BoundedDayCount;
 use crate::day_count::Fixed;
 use crate::day_count::FromFixed;
@@ -17,13 +19,55 @@ pub struct ClockTime {
 }
 
 impl ClockTime {
+    /// Create a ClockTime without checking validity
+    ///
+    /// In almost all cases, [`try_new`](ClockTime::try_new) is preferred.
+    pub const fn new_unchecked(hours: u8, minutes: u8, seconds: f32) -> ClockTime {
+        ClockTime {
+            hours,
+            minutes,
+            seconds,
+        }
+    }
+
+    /// Attempt to create a ClockTime
+    ///
+    /// Returns an error identifying the first invalid field, if any.
+    pub fn try_new(hours: u8, minutes: u8, seconds: f32) -> Result<ClockTime, CalendarError> {
+        let result = ClockTime::new_unchecked(hours, minutes, seconds);
+        result.validate()?;
+        Ok(result)
+    }
+
+    /// Attempt to create a ClockTime from a count of seconds since midnight
+    ///
+    /// Counts outside of a single day are wrapped around, so -1.0 is 23:59:59.
+    pub fn from_seconds_of_day(seconds: f64) -> Result<ClockTime, CalendarError> {
+        if !seconds.is_finite() {
+            return Err(CalendarError::EncounteredNaN);
+        }
+        let s = seconds.rem_euclid(SECONDS_PER_DAY as f64);
+        //rem_euclid rounds tiny negative counts up to a full day
+        let s = if s >= (SECONDS_PER_DAY as f64) {
+            0.0
+        } else {
+            s
+        };
+        let hours = (s / 3600.0).floor();
+        let minutes = ((s - (hours * 3600.0)) / 60.0).floor();
+        let seconds = s - (hours * 3600.0) - (minutes * 60.0);
+        ClockTime::try_new(hours as u8, minutes as u8, seconds as f32)
+    }
+
     /// Returns an error if the ClockTime is invalid.
+    ///
+    /// The error identifies which field is invalid.
     pub fn validate(self) -> Result<(), CalendarError> {
         if self.hours > 23 {
             Err(CalendarError::InvalidHour)
         } else if self.minutes >= 60 {
             Err(CalendarError::InvalidMinute)
-        } else if self.seconds > 60.0 {
+        } else if self.seconds.is_nan() || self.seconds < 0.0 || self.seconds > 60.0 {
             //Allow 60.0 for leap second
             Err(CalendarError::InvalidSecond)
         } else {
@@ -36,6 +80,18 @@ impl ClockTime {
     }
 }
 
+/// Describes how to discard the digits of a number of seconds which are too precise to keep
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[non_exhaustive]
+pub enum SecondsRounding {
+    /// Round towards the start of the day
+    Floor,
+    /// Round to the nearest value, with halfway values rounded away from the start of the day
+    Nearest,
+    /// Round towards the end of the day
+    Ceiling,
+}
+
 /// Represents a clock time as a fraction of a day
 ///
 /// This is internally a floating point number, where the fractional portion represents
@@ -67,6 +123,13 @@ impl TimeOfDay {
         self.0
     }
 
+    /// Number of seconds since midnight
+    ///
+    /// Any whole days in the `TimeOfDay` are ignored.
+    pub fn to_seconds_of_day(self) -> f64 {
+        day_fraction_to_seconds(self.get().rem_euclid(1.0))
+    }
+
     /// Split `TimeOfDay` into hours, minutes, and seconds
     pub fn to_clock(self) -> ClockTime {
         //LISTING 1.44 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
@@ -119,6 +182,29 @@ mod tests {
         assert_eq!(j0.convert::<TimeOfDay>().0, 0.5);
     }
 
+    #[test]
+    fn to_seconds_of_day() {
+        assert_eq!(TimeOfDay::midnight().to_seconds_of_day(), 0.0);
+        assert_eq!(TimeOfDay::noon().to_seconds_of_day(), 43200.0);
+        assert_eq!(TimeOfDay::new(2.75).to_seconds_of_day(), 64800.0);
+        assert_eq!(TimeOfDay::new(-0.25).to_seconds_of_day(), 64800.0);
+    }
+
+    #[test]
+    fn clock_far_from_epoch() {
+        for day in [-312271662.0, -46409679.0, 46409679.0] {
+            for (h, m) in [(2, 0), (2, 2), (22, 48), (23, 59)] {
+                let c0 = ClockTime::try_new(h, m, 0.0).unwrap();
+                let t = TimeOfDay::try_from_clock(c0).unwrap().get();
+                let c1 = TimeOfDay::from_fixed(Fixed::new(day + t)).to_clock();
+                let s0 = (h as f64) * 3600.0 + (m as f64) * 60.0;
+                let s1 =
+                    (c1.hours as f64) * 3600.0 + (c1.minutes as f64) * 60.0 + c1.seconds as f64;
+                assert!((s0 - s1).abs() < 0.1, "{:?} {:?}", c0, c1);
+            }
+        }
+    }
+
     #[test]
     fn obvious_clock_times() {
         assert_eq!(
@@ -177,6 +263,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn invalid_fields() {
+        assert!(matches!(
+            ClockTime::try_new(24, 0, 0.0),
+            Err(CalendarError::InvalidHour)
+        ));
+        assert!(matches!(
+            ClockTime::try_new(0, 60, 0.0),
+            Err(CalendarError::InvalidMinute)
+        ));
+        assert!(matches!(
+            ClockTime::try_new(0, 0, -1.0),
+            Err(CalendarError::InvalidSecond)
+        ));
+        assert!(matches!(
+            ClockTime::try_new(0, 0, f32::NAN),
+            Err(CalendarError::InvalidSecond)
+        ));
+        assert_eq!(
+            ClockTime::from_seconds_of_day(-1.0).unwrap(),
+            ClockTime::new_unchecked(23, 59, 59.0)
+        );
+        assert!(ClockTime::from_seconds_of_day(f64::NAN).is_err());
+        for tiny in [-1e-12, -1e-13, -f64::MIN_POSITIVE] {
+            assert_eq!(
+                ClockTime::from_seconds_of_day(tiny).unwrap(),
+                ClockTime::new_unchecked(0, 0, 0.0)
+            );
+        }
+    }
+
     proptest! {
         #[test]
         fn clock_time_round_trip(ahr in 0..24,amn in 0..59,asc in 0..59) {
@@ -196,6 +313,20 @@ mod tests {
             c.validate().unwrap();
         }
 
+        #[test]
+        fn try_new(ahr in 0..u8::MAX,amn in 0..u8::MAX,asc in -1.0f32..62.0) {
+            let c0 = ClockTime::new_unchecked(ahr, amn, asc);
+            assert_eq!(ClockTime::try_new(ahr, amn, asc).ok(), c0.validate().ok().map(|_| c0));
+        }
+
+        #[test]
+        fn seconds_of_day(s in -1e9..1e9) {
+            let c = ClockTime::from_seconds_of_day(s).unwrap();
+            c.validate().unwrap();
+            let s0 = (c.hours as f64) * 3600.0 + (c.minutes as f64) * 60.0 + (c.seconds as f64);
+            assert!((s0 - s.rem_euclid(86400.0)).abs() < 0.01);
+        }
+
         #[test]
         fn invalid_hour(ahr in 25..u8::MAX,amn in 0..59,asc in 0..59) {
             let c0 = ClockTime { hours: ahr as u8, minutes: amn as u8, seconds: asc as f32 };
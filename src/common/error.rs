@@ -6,7 +6,26 @@ use std::error::Error;
 use std::fmt;
 use std::fmt::Display;
 
+/// Errors returned by fallible operations in this crate
+///
+/// New variants may be added in future versions, so matches on this type need a
+/// wildcard arm. The `is_*` methods group the variants into broad categories which
+/// will remain stable as variants are added.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::CalendarError;
+///
+/// let e = Gregorian::try_new(2025, GregorianMonth::February, 30).unwrap_err();
+/// assert!(e.is_invalid_date());
+/// let message = match e {
+///     CalendarError::InvalidDay => "no such day",
+///     _ => "something else",
+/// };
+/// assert_eq!(message, "no such day");
+/// ```
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum CalendarError {
     InvalidYear,
     InvalidMonth,
@@ -22,6 +41,41 @@ pub enum CalendarError {
     MixedRadixZeroBase,
     EncounteredNaN,
     ImpossibleResult,
+    InvalidInterval,
+    IntegerOverflow,
+    InvalidDayCount,
+}
+
+impl CalendarError {
+    /// [`true`] if a year, month, day, day of year or week is invalid
+    pub fn is_invalid_date(&self) -> bool {
+        matches!(
+            self,
+            CalendarError::InvalidYear
+                | CalendarError::InvalidMonth
+                | CalendarError::InvalidDay
+                | CalendarError::InvalidDayOfYear
+                | CalendarError::InvalidWeek
+        )
+    }
+
+    /// [`true`] if an hour, minute or second is invalid
+    pub fn is_invalid_time(&self) -> bool {
+        matches!(
+            self,
+            CalendarError::InvalidHour
+                | CalendarError::InvalidMinute
+                | CalendarError::InvalidSecond
+        )
+    }
+
+    /// [`true`] if a result does not fit within the supported range of time or its type
+    pub fn is_out_of_range(&self) -> bool {
+        matches!(
+            self,
+            CalendarError::OutOfBounds | CalendarError::IntegerOverflow
+        )
+    }
 }
 
 impl Display for CalendarError {
@@ -41,6 +95,9 @@ impl Display for CalendarError {
             CalendarError::MixedRadixZeroBase => write!(f, "Mixed radix base contains zero"),
             CalendarError::EncounteredNaN => write!(f, "Encountered Not a Number (NaN)"),
             CalendarError::ImpossibleResult => write!(f, "Impossible result"),
+            CalendarError::InvalidInterval => write!(f, "Invalid interval"),
+            CalendarError::IntegerOverflow => write!(f, "Integer overflow"),
+            CalendarError::InvalidDayCount => write!(f, "Invalid day count"),
         }
     }
 }
@@ -3,6 +3,10 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use crate::common::error::CalendarError;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::Fixed;
+use crate::day_count::FIXED_MAX;
+use crate::day_count::FIXED_MIN;
 use num_traits::AsPrimitive;
 use num_traits::Bounded;
 use num_traits::Euclid;
@@ -11,6 +15,7 @@ use num_traits::NumAssign;
 use num_traits::ToPrimitive;
 use num_traits::Zero;
 use std::cmp::PartialOrd;
+use std::ops::Range;
 
 // https://en.m.wikipedia.org/wiki/Double-precision_floating-point_format
 // > Between 2^52=4,503,599,627,370,496 and 2^53=9,007,199,254,740,992 the
@@ -194,24 +199,37 @@ pub trait TermNum:
             Err(error) => return Err(error),
         };
 
+        //Modified so that if a digit is rounded up, all less significant digits are zero.
+        let mut rounded_up = false;
         for i in 0..(n + 1) {
             if i == 0 {
                 let p0: f64 = TermNum::product(|j| b[j], |j| j < k, 0).as_();
-                let q0 = Self::from_f64((x / p0).approx_floor() as f64);
+                let v0 = x / p0;
+                let f0 = v0.approx_floor();
+                rounded_up = f0 > v0.floor();
+                let q0 = Self::from_f64(f0);
                 match q0 {
                     Some(q) => a[i] = q,
                     None => return Err(CalendarError::ImpossibleResult),
                 }
+            } else if rounded_up {
+                a[i] = Self::zero();
             } else if i > 0 && i < k {
                 let p1: f64 = TermNum::product(|j| b[j], |j| j < k, i).as_();
-                let q1 = Self::from_f64((x / p1).approx_floor() as f64);
+                let v1 = x / p1;
+                let f1 = v1.approx_floor();
+                rounded_up = f1 > v1.floor();
+                let q1 = Self::from_f64(f1);
                 match q1 {
                     Some(q) => a[i] = q.modulus(b[i - 1]),
                     None => return Err(CalendarError::ImpossibleResult),
                 }
             } else if i >= k && i < n {
                 let p2: f64 = TermNum::product(|j| b[j], |j| j < i, k).as_();
-                let q2 = Self::from_f64((x * p2).approx_floor() as f64);
+                let v2 = x * p2;
+                let f2 = v2.approx_floor();
+                rounded_up = f2 > v2.floor();
+                let q2 = Self::from_f64(f2);
                 match q2 {
                     Some(q) => a[i] = q.modulus(b[i - 1]),
                     None => return Err(CalendarError::ImpossibleResult),
@@ -385,11 +403,235 @@ impl TermNum for f32 {
 
 // TODO: binary search (listing 1.35)
 // TODO: inverse f (listing 1.36)
-// TODO: list_of_fixed_from_moments (listing 1.37)
-// TODO: range (1.38)
-// TODO: scan_range (1.39)
-// TODO: positions_in_range (1.40)
-// TODO: angles, minutes, degrees
+
+/// Find the day on which each moment occurs
+///
+/// ```
+/// use radnelac::math::*;
+///
+/// assert_eq!(list_of_fixed_from_moments(&[1.5, -0.25, 3.0]), vec![1.0, -1.0, 3.0]);
+/// ```
+pub fn list_of_fixed_from_moments(moments: &[f64]) -> Vec<f64> {
+    //LISTING 1.37 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
+    moments.iter().map(|t| t.floor()).collect()
+}
+
+/// Attempt to create the half-open range of moments \[a, b)
+///
+/// Returns an error if either endpoint is NaN or if `a` is after `b`.
+///
+/// ```
+/// use radnelac::math::*;
+///
+/// let r = range(1.0, 3.0).unwrap();
+/// assert!(r.contains(&1.0));
+/// assert!(!r.contains(&3.0));
+/// assert!(range(3.0, 1.0).is_err());
+/// ```
+pub fn range(a: f64, b: f64) -> Result<Range<f64>, CalendarError> {
+    //LISTING 1.38 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
+    //Modified to use std::ops::Range
+    if !a.is_a_number() || !b.is_a_number() {
+        Err(CalendarError::EncounteredNaN)
+    } else if a > b {
+        Err(CalendarError::InvalidInterval)
+    } else {
+        Ok(a..b)
+    }
+}
+
+/// Find the moments from a list which are within a range
+///
+/// ```
+/// use radnelac::math::*;
+///
+/// let r = range(1.0, 3.0).unwrap();
+/// assert_eq!(scan_range(&[0.0, 1.0, 2.5, 3.0], r), vec![1.0, 2.5]);
+/// ```
+pub fn scan_range(moments: &[f64], range: Range<f64>) -> Vec<f64> {
+    //LISTING 1.39 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
+    //Modified to use iteration instead of recursion
+    moments
+        .iter()
+        .copied()
+        .filter(|t| range.contains(t))
+        .collect()
+}
+
+/// Find all occurrences of a cyclic event within a range
+///
+/// The event occurs at moments `t` where `(t + delta) mod c == p`. Returns an empty list
+/// if `c` is not positive.
+///
+/// For example, the days of week repeat every 7 days, and [`Fixed`](crate::day_count::Fixed)
+/// 0 is a Sunday. So the Fridays within a range are:
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+/// use radnelac::day_cycle::*;
+/// use radnelac::math::*;
+///
+/// let a = Gregorian::try_new(2025, GregorianMonth::January, 1).unwrap().to_fixed();
+/// let b = Gregorian::try_new(2025, GregorianMonth::February, 1).unwrap().to_fixed();
+/// let fridays = positions_in_range(5.0, 7.0, 0.0, range(a.get(), b.get()).unwrap());
+/// assert_eq!(fridays.len(), 5);
+/// for t in fridays {
+///     assert_eq!(Weekday::from_fixed(Fixed::new(t)), Weekday::Friday);
+/// }
+/// ```
+pub fn positions_in_range(p: f64, c: f64, delta: f64, range: Range<f64>) -> Vec<f64> {
+    //LISTING 1.40 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
+    //Modified to use iteration instead of recursion
+    let mut result = Vec::new();
+    if c.is_nan() || c <= 0.0 || range.end.is_nan() {
+        return result;
+    }
+    let mut a = range.start;
+    loop {
+        let t = (p - delta).interval_modulus(a, a + c);
+        if t >= range.end {
+            return result;
+        }
+        result.push(t);
+        a += c;
+    }
+}
+
+/// Find the days within a range which match a condition
+///
+/// Every day which starts within the range is checked in order, so the range may be
+/// unbounded at the end. Days outside of [`FIXED_MIN`](crate::day_count::FIXED_MIN) and
+/// [`FIXED_MAX`](crate::day_count::FIXED_MAX) are never checked.
+/// This is the same scan as [`scan_range`], except that the days are found lazily.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+/// use radnelac::day_cycle::*;
+/// use radnelac::math::*;
+///
+/// let a = Gregorian::try_new(2025, GregorianMonth::January, 1).unwrap().to_fixed();
+/// let friday_13 = |t: Fixed| {
+///     let d = Gregorian::from_fixed(t);
+///     d.day() == 13 && d.convert::<Weekday>() == Weekday::Friday
+/// };
+/// let days: Vec<Gregorian> = fixed_days_matching(friday_13, range(a.get(), f64::INFINITY).unwrap())
+///     .take(3)
+///     .map(Gregorian::from_fixed)
+///     .collect();
+/// assert_eq!(days[0], Gregorian::try_new(2025, GregorianMonth::June, 13).unwrap());
+/// assert_eq!(days[2], Gregorian::try_new(2026, GregorianMonth::March, 13).unwrap());
+/// ```
+pub fn fixed_days_matching(
+    p: impl Fn(Fixed) -> bool,
+    range: Range<f64>,
+) -> impl Iterator<Item = Fixed> {
+    let start = if range.start < FIXED_MIN {
+        FIXED_MIN
+    } else {
+        range.start
+    };
+    let end = if range.end > FIXED_MAX {
+        FIXED_MAX
+    } else {
+        range.end
+    };
+    let first = start.ceil();
+    (0..)
+        .map(move |i: i64| first + (i as f64))
+        .take_while(move |t| *t < end)
+        .map(Fixed::new)
+        .filter(move |t| p(*t))
+}
+
+/// Represents an angle as degrees, arcminutes and arcseconds
+///
+/// All fields have the same sign as the angle.
+///
+/// ```
+/// use radnelac::math::*;
+///
+/// let a = DegreesMinutesSeconds::try_from_degrees(-12.5125).unwrap();
+/// assert_eq!(a.degrees, -12);
+/// assert_eq!(a.minutes, -30);
+/// assert!((a.seconds - (-45.0)).abs() < 1e-6);
+/// assert!((a.to_degrees() - (-12.5125)).abs() < 1e-9);
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct DegreesMinutesSeconds {
+    pub degrees: i32,
+    pub minutes: i8,
+    pub seconds: f64,
+}
+
+impl DegreesMinutesSeconds {
+    /// Split an angle in degrees into degrees, arcminutes and arcseconds
+    ///
+    /// Returns an error if the whole number of degrees does not fit into an [`i32`].
+    pub fn try_from_degrees(theta: f64) -> Result<Self, CalendarError> {
+        if !theta.is_a_number() {
+            return Err(CalendarError::ImpossibleResult);
+        }
+        let sign = if theta < 0.0 { -1.0 } else { 1.0 };
+        let mut a = [0.0, 0.0, 0.0];
+        TermNum::to_mixed_radix(theta.abs(), &[60.0, 60.0], 0, &mut a)?;
+        let degrees = (sign * a[0]).to_i32().ok_or(CalendarError::OutOfBounds)?;
+        Ok(DegreesMinutesSeconds {
+            degrees,
+            minutes: (sign * a[1]) as i8,
+            seconds: sign * a[2],
+        })
+    }
+
+    /// Combine degrees, arcminutes and arcseconds into an angle in degrees
+    pub fn to_degrees(self) -> f64 {
+        (self.degrees as f64) + ((self.minutes as f64) / 60.0) + (self.seconds / 3600.0)
+    }
+}
+
+/// Normalize an angle in degrees to the range \[0, 360)
+///
+/// ```
+/// use radnelac::math::*;
+///
+/// assert_eq!(normalize_degrees(370.0), 10.0);
+/// assert_eq!(normalize_degrees(-90.0), 270.0);
+/// ```
+pub fn normalize_degrees(theta: f64) -> f64 {
+    theta.modulus(360.0)
+}
+
+/// Normalize an angle in degrees to the range \[-180, 180)
+///
+/// ```
+/// use radnelac::math::*;
+///
+/// assert_eq!(normalize_degrees_signed(270.0), -90.0);
+/// assert_eq!(normalize_degrees_signed(-190.0), 170.0);
+/// ```
+pub fn normalize_degrees_signed(theta: f64) -> f64 {
+    theta.interval_modulus(-180.0, 180.0)
+}
+
+/// Convert an hour angle into degrees
+///
+/// One hour of rotation is 15 degrees.
+///
+/// ```
+/// use radnelac::math::*;
+///
+/// assert_eq!(hours_to_degrees(6.0), 90.0);
+/// assert_eq!(degrees_to_hours(hours_to_degrees(-1.5)), -1.5);
+/// ```
+pub fn hours_to_degrees(hours: f64) -> f64 {
+    hours * 15.0
+}
+
+/// Convert an angle in degrees into an hour angle
+pub fn degrees_to_hours(theta: f64) -> f64 {
+    theta / 15.0
+}
 
 #[cfg(test)]
 mod tests {
@@ -461,7 +703,115 @@ mod tests {
         assert_eq!(z, 499.0);
     }
 
+    #[test]
+    fn dms_basics() {
+        let a = DegreesMinutesSeconds::try_from_degrees(0.5).unwrap();
+        assert_eq!((a.degrees, a.minutes), (0, 30));
+        assert!(a.seconds.approx_eq(0.0));
+        let b = DegreesMinutesSeconds::try_from_degrees(-0.5).unwrap();
+        assert_eq!((b.degrees, b.minutes), (0, -30));
+        assert!(DegreesMinutesSeconds::try_from_degrees(f64::NAN).is_err());
+        assert!(DegreesMinutesSeconds::try_from_degrees(1e12).is_err());
+    }
+
+    #[test]
+    fn positions_in_range_invalid_cycle() {
+        assert!(positions_in_range(1.0, 0.0, 0.0, 0.0..10.0).is_empty());
+        assert!(positions_in_range(1.0, -7.0, 0.0, 0.0..10.0).is_empty());
+        assert!(positions_in_range(1.0, f64::NAN, 0.0, 0.0..10.0).is_empty());
+        assert!(range(f64::NAN, 1.0).is_err());
+    }
+
+    #[test]
+    fn fixed_days_matching_bounds() {
+        assert_eq!(fixed_days_matching(|_| true, f64::NAN..10.0).count(), 0);
+        assert_eq!(fixed_days_matching(|_| true, 0.0..f64::NAN).count(), 0);
+        assert_eq!(fixed_days_matching(|_| true, 0.5..0.5).count(), 0);
+        let days: Vec<f64> = fixed_days_matching(|_| true, 0.5..2.0)
+            .map(|t| t.get())
+            .collect();
+        assert_eq!(days, vec![1.0]);
+        let days: Vec<f64> = fixed_days_matching(|_| true, 7.0229722874472005..9.5)
+            .map(|t| t.get())
+            .collect();
+        assert_eq!(days, vec![8.0, 9.0]);
+        let last = fixed_days_matching(|_| true, (FIXED_MAX - 10.0)..f64::INFINITY).last();
+        assert!(last.unwrap().get() <= FIXED_MAX);
+        let first = fixed_days_matching(|_| true, f64::NEG_INFINITY..0.0).next();
+        assert!(first.unwrap().get() >= FIXED_MIN);
+    }
+
     proptest! {
+        #[test]
+        fn positions_in_range_cycle(
+            p in -1000.0..1000.0,
+            c in 1.0..100.0,
+            delta in -1000.0..1000.0,
+            a in -10000.0..10000.0,
+            len in 0.0..1000.0,
+        ) {
+            let r = range(a, a + len).unwrap();
+            let x = positions_in_range(p, c, delta, r.clone());
+            assert_eq!(scan_range(&x, r.clone()), x);
+            for t in x.iter() {
+                let m = (t + delta - p).modulus(c);
+                assert!(m.approx_eq(0.0) || m.approx_eq(c));
+            }
+            for w in x.windows(2) {
+                assert!((w[1] - w[0]).approx_eq(c));
+            }
+            if let Some(first) = x.first() {
+                assert!(!r.contains(&(first - c)));
+            }
+            if let Some(last) = x.last() {
+                assert!(!r.contains(&(last + c)));
+            }
+        }
+
+        #[test]
+        fn fixed_days_matching_scan(
+            c in 1.0..20.0f64,
+            a in -10000.0..10000.0,
+            len in 0.0..1000.0,
+        ) {
+            let c = c.floor();
+            let r = range(a, a + len).unwrap();
+            let x: Vec<f64> = fixed_days_matching(|t| t.get().modulus(c) == 0.0, r.clone())
+                .map(|t| t.get())
+                .collect();
+            let y = positions_in_range(0.0, c, 0.0, r);
+            assert_eq!(x.len(), y.len());
+            for (t0, t1) in x.iter().zip(y.iter()) {
+                assert!(t0.approx_eq(*t1));
+            }
+        }
+
+        #[test]
+        fn list_of_fixed_from_moments_floor(x in proptest::collection::vec(EFFECTIVE_MIN..EFFECTIVE_MAX, 0..20)) {
+            let y = list_of_fixed_from_moments(&x);
+            assert_eq!(x.len(), y.len());
+            for (t, d) in x.iter().zip(y.iter()) {
+                assert!(d <= t && *t < d + 1.0);
+            }
+        }
+
+        #[test]
+        fn dms_round_trip(theta in -1e6..1e6) {
+            let a = DegreesMinutesSeconds::try_from_degrees(theta).unwrap();
+            assert!(a.to_degrees().approx_eq(theta));
+            assert!(a.minutes.abs() < 60);
+            assert!(a.seconds.abs() < 60.0);
+        }
+
+        #[test]
+        fn normalize_range(theta in EFFECTIVE_MIN..EFFECTIVE_MAX) {
+            let a = normalize_degrees(theta);
+            let b = normalize_degrees_signed(theta);
+            assert!((0.0..360.0).contains(&a));
+            assert!((-180.0..180.0).contains(&b));
+            assert!(((a - b).modulus(360.0)).approx_eq(0.0) || (a - b).approx_eq(360.0));
+        }
+
         #[test]
         fn mixed_radix_time(ahr in 0..24,amn in 0..59,asc in 0..59) {
             let ahr = ahr as f64;
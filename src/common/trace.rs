@@ -0,0 +1,15 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+/// Emit a `tracing` event at the trace level, if the `tracing` feature is enabled
+///
+/// The arguments are discarded without being evaluated if the feature is disabled.
+macro_rules! conversion_trace {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::trace!($($arg)*);
+    };
+}
+
+pub(crate) use conversion_trace;
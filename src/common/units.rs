@@ -0,0 +1,118 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+/// Number of seconds in a minute
+pub const SECONDS_PER_MINUTE: i64 = 60;
+/// Number of minutes in an hour
+pub const MINUTES_PER_HOUR: i64 = 60;
+/// Number of hours in a day
+pub const HOURS_PER_DAY: i64 = 24;
+/// Number of days in a week
+pub const DAYS_PER_WEEK: i64 = 7;
+/// Number of seconds in an hour
+pub const SECONDS_PER_HOUR: i64 = SECONDS_PER_MINUTE * MINUTES_PER_HOUR;
+/// Number of minutes in a day
+pub const MINUTES_PER_DAY: i64 = MINUTES_PER_HOUR * HOURS_PER_DAY;
+/// Number of seconds in a day
+///
+/// Every day in this crate has exactly this many seconds. There are no leap seconds.
+pub const SECONDS_PER_DAY: i64 = SECONDS_PER_HOUR * HOURS_PER_DAY;
+/// Number of milliseconds in a day
+pub const MILLIS_PER_DAY: i64 = SECONDS_PER_DAY * 1000;
+/// Number of seconds in a week
+pub const SECONDS_PER_WEEK: i64 = SECONDS_PER_DAY * DAYS_PER_WEEK;
+
+/// Number of seconds in a number of days
+///
+/// Returns [`None`] if the result overflows.
+pub const fn days_to_seconds(days: i64) -> Option<i64> {
+    days.checked_mul(SECONDS_PER_DAY)
+}
+
+/// Number of minutes in a number of days
+///
+/// Returns [`None`] if the result overflows.
+pub const fn days_to_minutes(days: i64) -> Option<i64> {
+    days.checked_mul(MINUTES_PER_DAY)
+}
+
+/// Number of days in a number of weeks
+///
+/// Returns [`None`] if the result overflows.
+pub const fn weeks_to_days(weeks: i64) -> Option<i64> {
+    weeks.checked_mul(DAYS_PER_WEEK)
+}
+
+/// Split a number of seconds into whole days and the remaining seconds
+///
+/// The remaining seconds are always between 0 and [`SECONDS_PER_DAY`] (exclusive), so
+/// negative counts are rounded towards negative infinity.
+pub const fn seconds_to_days(seconds: i64) -> (i64, i64) {
+    (
+        seconds.div_euclid(SECONDS_PER_DAY),
+        seconds.rem_euclid(SECONDS_PER_DAY),
+    )
+}
+
+/// Split a number of minutes into whole days and the remaining minutes
+///
+/// The remaining minutes are always between 0 and [`MINUTES_PER_DAY`] (exclusive).
+pub const fn minutes_to_days(minutes: i64) -> (i64, i64) {
+    (
+        minutes.div_euclid(MINUTES_PER_DAY),
+        minutes.rem_euclid(MINUTES_PER_DAY),
+    )
+}
+
+/// Split a number of days into whole weeks and the remaining days
+///
+/// The remaining days are always between 0 and [`DAYS_PER_WEEK`] (exclusive).
+pub const fn days_to_weeks(days: i64) -> (i64, i64) {
+    (
+        days.div_euclid(DAYS_PER_WEEK),
+        days.rem_euclid(DAYS_PER_WEEK),
+    )
+}
+
+/// Number of seconds in a fraction of a day
+pub fn day_fraction_to_seconds(days: f64) -> f64 {
+    days * (SECONDS_PER_DAY as f64)
+}
+
+/// Fraction of a day in a number of seconds
+pub fn seconds_to_day_fraction(seconds: f64) -> f64 {
+    seconds / (SECONDS_PER_DAY as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::proptest;
+
+    #[test]
+    fn constants() {
+        assert_eq!(SECONDS_PER_DAY, 86400);
+        assert_eq!(MINUTES_PER_DAY, 1440);
+        assert_eq!(MILLIS_PER_DAY, 86400000);
+        assert_eq!(SECONDS_PER_WEEK, 604800);
+        assert_eq!(days_to_seconds(i64::MAX), None);
+        assert_eq!(weeks_to_days(i64::MIN), None);
+        assert_eq!(seconds_to_days(-1), (-1, SECONDS_PER_DAY - 1));
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrip(days in -1000000000i64..1000000000) {
+            let s = days_to_seconds(days).unwrap();
+            assert_eq!(seconds_to_days(s), (days, 0));
+            assert_eq!(seconds_to_days(s + 1), (days, 1));
+            let m = days_to_minutes(days).unwrap();
+            assert_eq!(minutes_to_days(m - 1), (days - 1, MINUTES_PER_DAY - 1));
+            let (w, d) = days_to_weeks(days);
+            assert_eq!(weeks_to_days(w).unwrap() + d, days);
+            let f = seconds_to_day_fraction(s as f64);
+            assert_eq!(day_fraction_to_seconds(f), s as f64);
+        }
+    }
+}
@@ -0,0 +1,153 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::day_count::fixed::Fixed;
+use crate::day_count::jd::JulianDay;
+use crate::day_count::mjd::ModifiedJulianDay;
+use crate::day_count::prelude::BoundedDayCount;
+use crate::day_count::rd::RataDie;
+use std::ops::Add;
+use std::ops::Neg;
+use std::ops::Sub;
+
+/// Represents a signed difference between two day counts
+///
+/// The integer portion is a number of whole days and the fractional portion is a part
+/// of a day.
+///
+/// ```
+/// use radnelac::day_count::*;
+///
+/// let a = JulianDay::new(2460882.5);
+/// let b = a + 10;
+/// assert_eq!(b - a, Days::new(10.0));
+/// assert_eq!(b - Days::new(0.25), JulianDay::new(2460892.25));
+/// assert_eq!((a - b).get(), -10.0);
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default)]
+pub struct Days(f64);
+
+impl Days {
+    pub const fn new(days: f64) -> Days {
+        Days(days)
+    }
+
+    pub const fn get(self) -> f64 {
+        self.0
+    }
+}
+
+impl From<i64> for Days {
+    fn from(days: i64) -> Days {
+        Days(days as f64)
+    }
+}
+
+impl Neg for Days {
+    type Output = Days;
+
+    fn neg(self) -> Days {
+        Days(-self.0)
+    }
+}
+
+macro_rules! day_count_arith {
+    ($t:ty) => {
+        impl $t {
+            /// Add a number of days, returning [`None`] if the result is out of bounds
+            pub fn checked_add_days(self, days: i64) -> Option<$t> {
+                let t = self.get() + (days as f64);
+                <$t>::in_effective_bounds(t).ok()?;
+                Some(<$t>::new(t))
+            }
+
+            /// Subtract a number of days, returning [`None`] if the result is out of bounds
+            pub fn checked_sub_days(self, days: i64) -> Option<$t> {
+                self.checked_add_days(days.checked_neg()?)
+            }
+        }
+
+        impl Add<i64> for $t {
+            type Output = $t;
+
+            fn add(self, days: i64) -> $t {
+                <$t>::new(self.get() + (days as f64))
+            }
+        }
+
+        impl Sub<i64> for $t {
+            type Output = $t;
+
+            fn sub(self, days: i64) -> $t {
+                <$t>::new(self.get() - (days as f64))
+            }
+        }
+
+        impl Add<Days> for $t {
+            type Output = $t;
+
+            fn add(self, days: Days) -> $t {
+                <$t>::new(self.get() + days.get())
+            }
+        }
+
+        impl Sub<Days> for $t {
+            type Output = $t;
+
+            fn sub(self, days: Days) -> $t {
+                <$t>::new(self.get() - days.get())
+            }
+        }
+
+        impl Sub for $t {
+            type Output = Days;
+
+            fn sub(self, other: $t) -> Days {
+                Days(self.get() - other.get())
+            }
+        }
+    };
+}
+
+day_count_arith!(Fixed);
+day_count_arith!(JulianDay);
+day_count_arith!(ModifiedJulianDay);
+day_count_arith!(RataDie);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_count::fixed::FromFixed;
+    use crate::day_count::fixed::ToFixed;
+    use crate::day_count::EffectiveBound;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use proptest::proptest;
+
+    #[test]
+    fn checked_bounds() {
+        let max = Fixed::effective_max();
+        assert!(max.checked_add_days(1).is_none());
+        assert!(max.checked_sub_days(1).is_some());
+        assert!(Fixed::effective_min().checked_sub_days(1).is_none());
+        assert!(Fixed::new(0.0).checked_sub_days(i64::MIN).is_none());
+        assert!(Fixed::new(0.0).checked_add_days(i64::MAX).is_none());
+    }
+
+    proptest! {
+        #[test]
+        fn same_as_fixed(t in (FIXED_MIN as i64 + 1000)..(FIXED_MAX as i64 - 1000), n in -1000..1000i64) {
+            let f = Fixed::cast_new(t);
+            let expected = (f + n).get();
+            assert_eq!((JulianDay::from_fixed(f) + n).to_fixed().get(), expected);
+            assert_eq!((ModifiedJulianDay::from_fixed(f) + n).to_fixed().get(), expected);
+            assert_eq!((RataDie::from_fixed(f) + n).to_fixed().get(), expected);
+            assert_eq!(f.checked_add_days(n), Some(f + n));
+            assert_eq!(f.checked_sub_days(n), Some(f - n));
+            assert_eq!((f + n) - f, Days::from(n));
+            assert_eq!(f + Days::from(n), f + n);
+            assert_eq!(f - (-Days::from(n)), f + n);
+        }
+    }
+}
@@ -0,0 +1,212 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::*;
+use crate::common::error::CalendarError;
+use crate::day_count::fixed::Fixed;
+use crate::day_count::fixed::FromFixed;
+use crate::day_count::fixed::ToFixed;
+use crate::day_count::fixed::ToFromEpochDays;
+use crate::day_count::UnixMoment;
+#[cfg(feature = "fiction")]
+use crate::fiction::Shire;
+
+/// Represents a day as a count of days since the Unix epoch, in 4 bytes
+///
+/// This matches the semantics of the Arrow `Date32` type. It is intended for storing large
+/// numbers of dates compactly. Convert to a calendar type for any other computation.
+///
+/// Every `Date32` is within the effective bounds of this crate, so converting into a
+/// calendar always succeeds. Converting from a calendar fails if the day does not fit
+/// into an [`i32`]. The time of day is discarded.
+///
+/// This is the same count as [`ToFromEpochDays`] for [`UnixMoment`].
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// let d = Date32::new(20223);
+/// let g = Gregorian::from(d);
+/// assert_eq!(g, Gregorian::try_new(2025, GregorianMonth::May, 15).unwrap());
+/// assert_eq!(Date32::try_from(g).unwrap(), d);
+/// assert_eq!(d.to_date::<Julian>(), Julian::from(d));
+/// assert_eq!(std::mem::size_of::<Date32>(), 4);
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+pub struct Date32(i32);
+
+impl Date32 {
+    /// Create a `Date32` from a count of days since the Unix epoch
+    pub const fn new(days: i32) -> Date32 {
+        Date32(days)
+    }
+
+    /// Count of days since the Unix epoch
+    pub const fn get(self) -> i32 {
+        self.0
+    }
+
+    /// Attempt to create a `Date32` from the day of a [`Fixed`]
+    ///
+    /// Returns an error if the day does not fit into an [`i32`].
+    pub fn try_from_fixed(t: Fixed) -> Result<Date32, CalendarError> {
+        Ok(Date32(UnixMoment::from_fixed(t).to_epoch_days_i32()?))
+    }
+
+    /// Convert to a date in a specific timekeeping system
+    pub fn to_date<T: FromFixed>(self) -> T {
+        T::from_fixed(self.to_fixed())
+    }
+}
+
+impl ToFixed for Date32 {
+    fn to_fixed(self) -> Fixed {
+        UnixMoment::try_from_epoch_days_i32(self.0)
+            .expect("Every i32 day is within the effective bounds")
+            .to_fixed()
+    }
+}
+
+impl From<i32> for Date32 {
+    fn from(days: i32) -> Date32 {
+        Date32(days)
+    }
+}
+
+impl From<Date32> for i32 {
+    fn from(d: Date32) -> i32 {
+        d.0
+    }
+}
+
+impl<T: FromFixed> From<Date32> for CalendarMoment<T> {
+    fn from(d: Date32) -> CalendarMoment<T> {
+        d.to_date()
+    }
+}
+
+impl<T: ToFixed> TryFrom<CalendarMoment<T>> for Date32 {
+    type Error = CalendarError;
+
+    fn try_from(value: CalendarMoment<T>) -> Result<Date32, CalendarError> {
+        Date32::try_from_fixed(value.to_fixed())
+    }
+}
+
+impl<B: FromFixed + ToFixed, const Y: i32, const D: i64> From<Date32> for OffsetCalendar<B, Y, D> {
+    fn from(d: Date32) -> OffsetCalendar<B, Y, D> {
+        d.to_date()
+    }
+}
+
+impl<B: FromFixed + ToFixed, const Y: i32, const D: i64> TryFrom<OffsetCalendar<B, Y, D>>
+    for Date32
+{
+    type Error = CalendarError;
+
+    fn try_from(value: OffsetCalendar<B, Y, D>) -> Result<Date32, CalendarError> {
+        Date32::try_from_fixed(value.to_fixed())
+    }
+}
+
+macro_rules! date32_conversion {
+    ([$($g:tt)*] $t:ty) => {
+        impl<$($g)*> From<Date32> for $t {
+            fn from(d: Date32) -> $t {
+                d.to_date()
+            }
+        }
+
+        impl<$($g)*> TryFrom<$t> for Date32 {
+            type Error = CalendarError;
+
+            fn try_from(value: $t) -> Result<Date32, CalendarError> {
+                Date32::try_from_fixed(value.to_fixed())
+            }
+        }
+    };
+    ($t:ty) => {
+        date32_conversion!([] $t);
+    };
+}
+
+date32_conversion!(Gregorian);
+date32_conversion!(Bengali);
+date32_conversion!(BikramSambat);
+date32_conversion!(Tamil);
+date32_conversion!(IslamicArith);
+date32_conversion!(IslamicUmmAlQura);
+date32_conversion!(ISO);
+date32_conversion!(Julian);
+date32_conversion!(PersianArith);
+date32_conversion!([const P: u16, const M: u8, const W: u8, const N: bool] Retail<P, M, W, N>);
+#[cfg(feature = "ancient-calendars")]
+date32_conversion!(Armenian);
+#[cfg(feature = "ancient-calendars")]
+date32_conversion!(AtticArith);
+#[cfg(feature = "ancient-calendars")]
+date32_conversion!(BabylonianArith);
+#[cfg(feature = "ancient-calendars")]
+date32_conversion!([const S: bool] Coligny<S>);
+#[cfg(feature = "ancient-calendars")]
+date32_conversion!(Coptic);
+#[cfg(feature = "ancient-calendars")]
+date32_conversion!(Egyptian);
+#[cfg(feature = "ancient-calendars")]
+date32_conversion!(Ethiopic);
+#[cfg(feature = "ancient-calendars")]
+date32_conversion!(Icelandic);
+#[cfg(feature = "ancient-calendars")]
+date32_conversion!(Roman);
+#[cfg(feature = "reform-calendars")]
+date32_conversion!(Cotsworth);
+#[cfg(feature = "reform-calendars")]
+date32_conversion!([const L: bool] FrenchRevArith<L>);
+#[cfg(feature = "reform-calendars")]
+date32_conversion!(Holocene);
+#[cfg(feature = "reform-calendars")]
+date32_conversion!(Pataphysical);
+#[cfg(feature = "reform-calendars")]
+date32_conversion!(Positivist);
+#[cfg(feature = "reform-calendars")]
+date32_conversion!([const T: bool, const U: bool] Symmetry<T, U>);
+#[cfg(feature = "reform-calendars")]
+date32_conversion!(Tranquility);
+#[cfg(feature = "fiction")]
+date32_conversion!(Shire);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_count::BoundedDayCount;
+    use crate::day_count::Epoch;
+    use crate::day_count::FIXED_MAX;
+    use proptest::proptest;
+
+    #[test]
+    fn overflow() {
+        let g = Gregorian::from_fixed(Fixed::new(FIXED_MAX));
+        assert!(matches!(
+            Date32::try_from(g),
+            Err(CalendarError::IntegerOverflow)
+        ));
+        let d = Date32::new(i32::MIN);
+        assert_eq!(Date32::try_from(Julian::from(d)).unwrap(), d);
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrip(x: i32) {
+            let d = Date32::from(x);
+            assert_eq!(Date32::try_from(Gregorian::from(d)).unwrap(), d);
+            assert_eq!(Date32::try_from(ISO::from(d)).unwrap(), d);
+            assert_eq!(Date32::try_from(GregorianMoment::from(d)).unwrap(), d);
+            assert_eq!(Date32::try_from(Kurdish::from(d)).unwrap(), d);
+            assert_eq!(i32::from(d), x);
+            let t = d.to_fixed().get_day_i() - UnixMoment::epoch().get_day_i();
+            assert_eq!(t, x as i64);
+        }
+    }
+}
@@ -0,0 +1,172 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::day_count::interval::FixedInterval;
+use crate::day_count::interval::IntervalEndpoint;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+
+/// Represents a named point or span of time, independent of any calendar
+///
+/// An `Event` can be converted into any timekeeping system, and checked for overlaps
+/// with other events.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// let a = Gregorian::try_new(2025, GregorianMonth::May, 1).unwrap().to_fixed();
+/// let b = Gregorian::try_new(2025, GregorianMonth::May, 4).unwrap().to_fixed();
+/// let c = Gregorian::try_new(2025, GregorianMonth::May, 3).unwrap().to_fixed();
+/// let events = [
+///     Event::new("Conference", FixedInterval::half_open(a, b).unwrap()),
+///     Event::at("Launch", c),
+///     Event::at("Retrospective", b),
+/// ];
+/// assert_eq!(Event::conflicts(&events), vec![(0, 1)]);
+/// assert_eq!(events[0].days::<Julian>().len(), 3);
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Event {
+    name: String,
+    interval: FixedInterval,
+}
+
+impl Event {
+    /// Create an event spanning an interval
+    pub fn new(name: &str, interval: FixedInterval) -> Event {
+        Event {
+            name: name.to_string(),
+            interval,
+        }
+    }
+
+    /// Create an event at a single point in time
+    pub fn at(name: &str, t: Fixed) -> Event {
+        let interval = FixedInterval::closed(t, t).expect("Closed interval with equal endpoints");
+        Event::new(name, interval)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn interval(&self) -> FixedInterval {
+        self.interval
+    }
+
+    /// Start of the first day on which the event occurs
+    pub fn first_day(&self) -> Fixed {
+        self.interval.start().to_day()
+    }
+
+    /// Start of the last day on which the event occurs
+    ///
+    /// An event which ends exactly at midnight, excluding that midnight, does not occur
+    /// on the following day.
+    pub fn last_day(&self) -> Fixed {
+        let end = self.interval.end();
+        let day = end.to_day();
+        if day == end
+            && self.interval.end_endpoint() == IntervalEndpoint::Open
+            && day > self.first_day()
+        {
+            Fixed::cast_new(day.get_day_i() - 1)
+        } else {
+            day
+        }
+    }
+
+    /// Create a date for every day on which the event occurs
+    pub fn days<T: FromFixed>(&self) -> Vec<T> {
+        (self.first_day().get_day_i()..=self.last_day().get_day_i())
+            .map(|d| T::from_fixed(Fixed::cast_new(d)))
+            .collect()
+    }
+
+    /// [`true`] if at least one point in time is within both events
+    pub fn overlaps(&self, other: &Event) -> bool {
+        self.interval.overlaps(other.interval)
+    }
+
+    /// Find every pair of overlapping events
+    ///
+    /// Each pair contains the indices of the events in `events`, with the smaller index
+    /// first. The pairs are sorted.
+    pub fn conflicts(events: &[Event]) -> Vec<(usize, usize)> {
+        let mut order: Vec<usize> = (0..events.len()).collect();
+        order.sort_by(|a, b| {
+            let ta = events[*a].interval.start();
+            let tb = events[*b].interval.start();
+            ta.partial_cmp(&tb).expect("Intervals exclude NaN")
+        });
+        let mut result = Vec::new();
+        for (n, i) in order.iter().enumerate() {
+            let a = &events[*i];
+            for j in order[(n + 1)..].iter() {
+                let b = &events[*j];
+                if b.interval.start() > a.interval.end() {
+                    break;
+                }
+                if a.overlaps(b) {
+                    result.push((*i.min(j), *i.max(j)));
+                }
+            }
+        }
+        result.sort();
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_count::RataDie;
+    use crate::day_count::ToFixed;
+    use proptest::proptest;
+
+    #[test]
+    fn last_day() {
+        let a = Fixed::new(10.0);
+        let b = Fixed::new(12.0);
+        let e = Event::new("a", FixedInterval::half_open(a, b).unwrap());
+        assert_eq!(e.last_day(), Fixed::new(11.0));
+        let e = Event::new("a", FixedInterval::closed(a, b).unwrap());
+        assert_eq!(e.last_day(), b);
+        let e = Event::at("a", Fixed::new(10.5));
+        assert_eq!((e.first_day(), e.last_day()), (a, a));
+        assert_eq!(e.days::<RataDie>(), vec![RataDie::new(10.0)]);
+    }
+
+    proptest! {
+        #[test]
+        fn conflicts_brute_force(
+            x in proptest::collection::vec((-100.0..100.0f64, 0.0..20.0f64), 0..20)
+        ) {
+            let events: Vec<Event> = x
+                .iter()
+                .map(|(t, d)| {
+                    let a = Fixed::new(*t);
+                    let b = Fixed::new(t + d);
+                    Event::new("e", FixedInterval::closed(a, b).unwrap())
+                })
+                .collect();
+            let mut expected = Vec::new();
+            for i in 0..events.len() {
+                for j in (i + 1)..events.len() {
+                    if events[i].overlaps(&events[j]) {
+                        expected.push((i, j));
+                    }
+                }
+            }
+            assert_eq!(Event::conflicts(&events), expected);
+            for e in events {
+                let days = e.days::<RataDie>();
+                assert_eq!(days[0].to_fixed(), e.first_day());
+                assert_eq!(days[days.len() - 1].to_fixed(), e.last_day());
+            }
+        }
+    }
+}
@@ -2,11 +2,14 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use crate::common::error::CalendarError;
 use crate::common::math::TermNum;
 use crate::common::math::EFFECTIVE_MAX;
 use crate::common::math::EFFECTIVE_MIN;
+use crate::common::trace::conversion_trace;
 use crate::day_count::prelude::BoundedDayCount;
 use crate::day_count::prelude::EffectiveBound;
+use std::cmp::Ordering;
 
 const FIXED_MAX_SCALE: f64 = 2048.0;
 
@@ -41,6 +44,23 @@ pub const FIXED_MIN: f64 = (EFFECTIVE_MIN * (FIXED_MAX_SCALE - 1.0)) / FIXED_MAX
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default)]
 pub struct Fixed(f64);
 
+/// Policies for rounding a moment to a day
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum DayRounding {
+    /// Round towards the start of the day
+    #[default]
+    Floor,
+    /// Round to the nearest midnight, with noon rounded to the following midnight
+    HalfUp,
+    /// Round towards the start of the day, unless the moment is within
+    /// floating point error of the following midnight
+    ///
+    /// This is consistent with the rounding used for clock times, so a moment which is
+    /// displayed as 00:00:00 is always on the following day.
+    Snap,
+}
+
 impl Fixed {
     /// Returns a new `Fixed` with day 0 and the same time of day.
     pub fn to_time_of_day(self) -> Fixed {
@@ -49,16 +69,75 @@ impl Fixed {
     }
 
     /// Returns a new `Fixed` with the same day and midnight as the time of day.
+    ///
+    /// This always rounds towards the start of the day, even if the time of day is a tiny
+    /// fraction of a second before midnight. See [`to_day_rounded`](Fixed::to_day_rounded)
+    /// for other policies.
     pub fn to_day(self) -> Fixed {
         //LISTING 1.12 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
         Fixed(self.0.floor())
     }
 
+    /// Returns a new `Fixed` with midnight as the time of day, using a specific rounding policy.
+    ///
+    /// ```
+    /// use radnelac::day_count::*;
+    ///
+    /// let f = Fixed::new(10.0 - 1e-9);
+    /// assert_eq!(f.to_day_rounded(DayRounding::Floor), Fixed::new(9.0));
+    /// assert_eq!(f.to_day_rounded(DayRounding::Snap), Fixed::new(10.0));
+    /// assert_eq!(Fixed::new(9.5).to_day_rounded(DayRounding::HalfUp), Fixed::new(10.0));
+    /// assert_eq!(Fixed::new(9.5).to_day_rounded(DayRounding::Snap), Fixed::new(9.0));
+    /// ```
+    pub fn to_day_rounded(self, rounding: DayRounding) -> Fixed {
+        match rounding {
+            DayRounding::Floor => self.to_day(),
+            DayRounding::HalfUp => Fixed(self.0.floor_round()),
+            DayRounding::Snap => {
+                //Must match the rounding in TimeOfDay::to_clock
+                let t = self.to_time_of_day().get();
+                if t.approx_floor() > t.floor() {
+                    Fixed(self.0.floor() + 1.0)
+                } else {
+                    self.to_day()
+                }
+            }
+        }
+    }
+
     /// Returns the day as an integer
     pub fn get_day_i(self) -> i64 {
         self.to_day().get() as i64
     }
 
+    /// Split into a whole number of days and the fraction of the day which has elapsed
+    ///
+    /// The fraction is always in the range \[0, 1). A moment within floating point error
+    /// of the following midnight is treated as that midnight, as with [`DayRounding::Snap`].
+    ///
+    /// ```
+    /// use radnelac::day_count::*;
+    ///
+    /// assert_eq!(Fixed::new(-1.25).split(), (-2, 0.75));
+    /// assert_eq!(Fixed::new(10.0 - 1e-9).split(), (10, 0.0));
+    /// assert_eq!(Fixed::compose(-2, 0.75), Fixed::new(-1.25));
+    /// ```
+    pub fn split(self) -> (i64, f64) {
+        let day = self.to_day_rounded(DayRounding::Snap);
+        let fraction = self.0 - day.0;
+        (day.get_day_i(), if fraction < 0.0 { 0.0 } else { fraction })
+    }
+
+    /// Combine a whole number of days and a fraction of a day
+    ///
+    /// Fractions outside of \[0, 1) move the result into earlier or later days. A fraction
+    /// within floating point error below a whole number is treated as that whole number.
+    pub fn compose(days: i64, fraction: f64) -> Fixed {
+        let whole = fraction.approx_floor();
+        let rest = fraction - whole;
+        Fixed::new((days as f64) + whole + if rest < 0.0 { 0.0 } else { rest })
+    }
+
     /// Returns true if `self` and `other` represent the same second of time.
     pub fn same_second(self, other: Self) -> bool {
         self.0.approx_eq(other.0)
@@ -100,14 +179,109 @@ pub trait ToFixed: Copy + Clone {
     fn to_fixed(self) -> Fixed;
     fn convert<T: FromFixed>(self) -> T {
         //LISTING ?? SECTION 1.1 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
-        T::from_fixed(self.to_fixed())
+        let t = self.to_fixed();
+        conversion_trace!(
+            from = std::any::type_name::<Self>(),
+            to = std::any::type_name::<T>(),
+            fixed = t.get(),
+            "convert"
+        );
+        T::from_fixed(t)
     }
 }
 
+/// Convert a value from one timekeeping system to another
+///
+/// This is the same as [`ToFixed::convert`], but reads naturally in generic code.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// let g = Gregorian::try_new(2025, GregorianMonth::July, 26).unwrap();
+/// let j: Julian = convert(g);
+/// assert_eq!(j, g.convert::<Julian>());
+/// assert_eq!(convert::<Julian, Gregorian>(j), g);
+/// ```
+pub fn convert<S: ToFixed, T: FromFixed>(value: S) -> T {
+    value.convert::<T>()
+}
+
 pub trait Epoch: FromFixed {
     fn epoch() -> Fixed;
 }
 
+/// Number of days from the epoch of `B` to the epoch of `A`
+///
+/// Calendars which are "locked" together have dates which differ by exactly this many days.
+/// Epochs which start in the middle of a day (such as the Julian Day Number) are rounded
+/// down to the start of the day.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// assert_eq!(epoch_offset::<Ethiopic, Coptic>(), -100809);
+/// assert_eq!(epoch_offset::<Coptic, Ethiopic>(), 100809);
+/// assert_eq!(epoch_offset::<Gregorian, RataDie>(), 1);
+/// ```
+pub fn epoch_offset<A: Epoch, B: Epoch>() -> i64 {
+    A::epoch().get_day_i() - B::epoch().get_day_i()
+}
+
+/// Timekeeping systems in which a day can be stored as a count of days since the epoch
+///
+/// This is intended for compact storage in databases and columnar formats.
+pub trait ToFromEpochDays: Epoch + ToFixed {
+    /// Attempt to count the days since the epoch
+    ///
+    /// Returns an error if the count does not fit into an [`i32`].
+    fn to_epoch_days_i32(self) -> Result<i32, CalendarError> {
+        let d = self.to_fixed().get_day_i() - Self::epoch().get_day_i();
+        i32::try_from(d).map_err(|_| CalendarError::IntegerOverflow)
+    }
+
+    /// Attempt to create a day from a count of days since the epoch
+    fn try_from_epoch_days_i32(d: i32) -> Result<Self, CalendarError> {
+        let t = (Self::epoch().get_day_i() + (d as i64)) as f64;
+        Fixed::in_effective_bounds(t)?;
+        Ok(Self::from_fixed(Fixed::new(t)))
+    }
+}
+
+impl<T: Epoch + ToFixed> ToFromEpochDays for T {}
+
+/// Timekeeping systems which can be compared day by day with other timekeeping systems
+///
+/// The time of day is ignored, so dates and moments can be compared with each other.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+/// use std::cmp::Ordering;
+///
+/// let g = Gregorian::try_new(1752, GregorianMonth::September, 14).unwrap();
+/// let j = Julian::try_new(1752, JulianMonth::September, 3).unwrap();
+/// assert!(g.same_day(j));
+/// let j = Julian::try_new(1752, JulianMonth::September, 14).unwrap();
+/// assert_eq!(g.cmp_day(j), Ordering::Less);
+/// ```
+pub trait SameDay: ToFixed {
+    /// [`true`] if `self` and `other` are on the same day
+    fn same_day<U: ToFixed>(self, other: U) -> bool {
+        self.to_fixed().get_day_i() == other.to_fixed().get_day_i()
+    }
+
+    /// Compare the days of `self` and `other`
+    fn cmp_day<U: ToFixed>(self, other: U) -> Ordering {
+        self.to_fixed()
+            .get_day_i()
+            .cmp(&other.to_fixed().get_day_i())
+    }
+}
+
+impl<T: ToFixed> SameDay for T {}
+
 pub trait CalculatedBounds: FromFixed + ToFixed + PartialEq + PartialOrd {}
 
 impl<T: CalculatedBounds> EffectiveBound for T {
@@ -187,5 +361,33 @@ mod tests {
             let d = Fixed::new(t).get_day_i();
             assert_eq!(d as f64, f);
         }
+
+        #[test]
+        fn day_rounded(t in FIXED_MIN..FIXED_MAX) {
+            let x = Fixed::new(t);
+            let f = x.to_day().get();
+            assert_eq!(x.to_day_rounded(DayRounding::Floor).get(), f);
+            let h = x.to_day_rounded(DayRounding::HalfUp).get();
+            assert!((h - t).abs() <= 0.5);
+            let s = x.to_day_rounded(DayRounding::Snap).get();
+            assert!(s == f || (s == f + 1.0 && (s - t) < EFFECTIVE_EPSILON));
+        }
+
+        #[test]
+        fn split_compose(t in FIXED_MIN..FIXED_MAX) {
+            let x = Fixed::new(t);
+            let (d, f) = x.split();
+            assert!((0.0..1.0).contains(&f));
+            assert_eq!(d as f64, x.to_day_rounded(DayRounding::Snap).get());
+            assert!(Fixed::compose(d, f).same_second(x));
+        }
+
+        #[test]
+        fn compose_normalize(d in -1000000i64..1000000, f in -10.0..10.0) {
+            let x = Fixed::compose(d, f);
+            assert!(x.get().approx_eq((d as f64) + f));
+            let (d1, f1) = x.split();
+            assert!(Fixed::compose(d1, f1).same_second(x));
+        }
     }
 }
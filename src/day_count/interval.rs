@@ -0,0 +1,291 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::common::error::CalendarError;
+use crate::common::math::TermNum;
+use crate::day_count::fixed::Fixed;
+use crate::day_count::fixed::FromFixed;
+use crate::day_count::prelude::BoundedDayCount;
+
+/// Describes whether the endpoint of a [`FixedInterval`] is part of the interval
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub enum IntervalEndpoint {
+    /// The endpoint is included in the interval
+    Closed,
+    /// The endpoint is excluded from the interval
+    Open,
+}
+
+/// Represents a non-empty interval of time
+///
+/// Each endpoint of the interval may be open or closed. An interval in which both endpoints
+/// are the same point in time must have closed endpoints, otherwise it would be empty.
+///
+/// ## Representation and Examples
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// let a = Gregorian::try_new(2025, GregorianMonth::January, 1).unwrap().to_fixed();
+/// let b = Gregorian::try_new(2025, GregorianMonth::January, 8).unwrap().to_fixed();
+/// let c = Gregorian::try_new(2025, GregorianMonth::January, 15).unwrap().to_fixed();
+/// let week1 = FixedInterval::half_open(a, b).unwrap();
+/// let week2 = FixedInterval::half_open(b, c).unwrap();
+/// assert!(!week1.overlaps(week2));
+/// assert_eq!(week1.union(week2), Some(FixedInterval::half_open(a, c).unwrap()));
+/// assert_eq!(week1.duration(), 7.0);
+/// let days = week1.days::<Gregorian>();
+/// assert_eq!(days.len(), 7);
+/// assert_eq!(days[6], Gregorian::try_new(2025, GregorianMonth::January, 7).unwrap());
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct FixedInterval {
+    start: Fixed,
+    start_endpoint: IntervalEndpoint,
+    end: Fixed,
+    end_endpoint: IntervalEndpoint,
+}
+
+impl FixedInterval {
+    /// Attempt to create a `FixedInterval`
+    ///
+    /// Returns an error if the interval would be empty.
+    pub fn try_new(
+        start: Fixed,
+        start_endpoint: IntervalEndpoint,
+        end: Fixed,
+        end_endpoint: IntervalEndpoint,
+    ) -> Result<FixedInterval, CalendarError> {
+        if !start.get().is_a_number() || !end.get().is_a_number() {
+            Err(CalendarError::EncounteredNaN)
+        } else if start > end
+            || (start == end
+                && (start_endpoint == IntervalEndpoint::Open
+                    || end_endpoint == IntervalEndpoint::Open))
+        {
+            Err(CalendarError::InvalidInterval)
+        } else {
+            Ok(FixedInterval {
+                start,
+                start_endpoint,
+                end,
+                end_endpoint,
+            })
+        }
+    }
+
+    /// Attempt to create a `FixedInterval` including both endpoints
+    pub fn closed(start: Fixed, end: Fixed) -> Result<FixedInterval, CalendarError> {
+        FixedInterval::try_new(
+            start,
+            IntervalEndpoint::Closed,
+            end,
+            IntervalEndpoint::Closed,
+        )
+    }
+
+    /// Attempt to create a `FixedInterval` including the start but not the end
+    pub fn half_open(start: Fixed, end: Fixed) -> Result<FixedInterval, CalendarError> {
+        FixedInterval::try_new(start, IntervalEndpoint::Closed, end, IntervalEndpoint::Open)
+    }
+
+    /// Start of the interval, which may or may not be included
+    pub fn start(self) -> Fixed {
+        self.start
+    }
+
+    /// Whether the start is included in the interval
+    pub fn start_endpoint(self) -> IntervalEndpoint {
+        self.start_endpoint
+    }
+
+    /// End of the interval, which may or may not be included
+    pub fn end(self) -> Fixed {
+        self.end
+    }
+
+    /// Whether the end is included in the interval
+    pub fn end_endpoint(self) -> IntervalEndpoint {
+        self.end_endpoint
+    }
+
+    /// Length of the interval in days
+    pub fn duration(self) -> f64 {
+        self.end.get() - self.start.get()
+    }
+
+    /// [`true`] if `t` is within the interval
+    pub fn contains(self, t: Fixed) -> bool {
+        let after_start = match self.start_endpoint {
+            IntervalEndpoint::Closed => t >= self.start,
+            IntervalEndpoint::Open => t > self.start,
+        };
+        let before_end = match self.end_endpoint {
+            IntervalEndpoint::Closed => t <= self.end,
+            IntervalEndpoint::Open => t < self.end,
+        };
+        after_start && before_end
+    }
+
+    /// Calculate the interval contained by both `self` and `other`
+    ///
+    /// Returns [`None`] if the intervals do not overlap.
+    pub fn intersection(self, other: FixedInterval) -> Option<FixedInterval> {
+        let (start, start_endpoint) = if self.start > other.start {
+            (self.start, self.start_endpoint)
+        } else if self.start < other.start {
+            (other.start, other.start_endpoint)
+        } else if self.start_endpoint == IntervalEndpoint::Open {
+            (self.start, self.start_endpoint)
+        } else {
+            (other.start, other.start_endpoint)
+        };
+        let (end, end_endpoint) = if self.end < other.end {
+            (self.end, self.end_endpoint)
+        } else if self.end > other.end {
+            (other.end, other.end_endpoint)
+        } else if self.end_endpoint == IntervalEndpoint::Open {
+            (self.end, self.end_endpoint)
+        } else {
+            (other.end, other.end_endpoint)
+        };
+        FixedInterval::try_new(start, start_endpoint, end, end_endpoint).ok()
+    }
+
+    /// Calculate the interval contained by either `self` or `other`
+    ///
+    /// Returns [`None`] if there is a gap between the intervals.
+    pub fn union(self, other: FixedInterval) -> Option<FixedInterval> {
+        if !(self.overlaps(other) || self.touches(other) || other.touches(self)) {
+            return None;
+        }
+        let (start, start_endpoint) = if self.start < other.start {
+            (self.start, self.start_endpoint)
+        } else if self.start > other.start {
+            (other.start, other.start_endpoint)
+        } else if self.start_endpoint == IntervalEndpoint::Closed {
+            (self.start, self.start_endpoint)
+        } else {
+            (other.start, other.start_endpoint)
+        };
+        let (end, end_endpoint) = if self.end > other.end {
+            (self.end, self.end_endpoint)
+        } else if self.end < other.end {
+            (other.end, other.end_endpoint)
+        } else if self.end_endpoint == IntervalEndpoint::Closed {
+            (self.end, self.end_endpoint)
+        } else {
+            (other.end, other.end_endpoint)
+        };
+        FixedInterval::try_new(start, start_endpoint, end, end_endpoint).ok()
+    }
+
+    /// [`true`] if at least one point in time is within both `self` and `other`
+    pub fn overlaps(self, other: FixedInterval) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    fn touches(self, other: FixedInterval) -> bool {
+        self.end == other.start
+            && (self.end_endpoint == IntervalEndpoint::Closed
+                || other.start_endpoint == IntervalEndpoint::Closed)
+    }
+
+    /// Create a date for every day which starts within the interval
+    ///
+    /// The length of the result is proportional to the duration of the interval.
+    pub fn days<T: FromFixed>(self) -> Vec<T> {
+        let first = self.start.get().ceil() as i64;
+        let last = self.end.get_day_i();
+        (first..=last)
+            .map(Fixed::cast_new)
+            .filter(|t| self.contains(*t))
+            .map(T::from_fixed)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_count::RataDie;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use proptest::proptest;
+
+    fn interval(a: f64, b: f64, sa: IntervalEndpoint, sb: IntervalEndpoint) -> FixedInterval {
+        FixedInterval::try_new(Fixed::new(a), sa, Fixed::new(b), sb).unwrap()
+    }
+
+    #[test]
+    fn empty() {
+        let t = Fixed::new(1.0);
+        assert!(FixedInterval::half_open(t, t).is_err());
+        assert!(FixedInterval::closed(t, t).is_ok());
+        assert!(FixedInterval::closed(t, Fixed::new(0.0)).is_err());
+    }
+
+    #[test]
+    fn touching() {
+        use IntervalEndpoint::*;
+        let a = interval(0.0, 1.0, Closed, Open);
+        let b = interval(1.0, 2.0, Open, Closed);
+        let c = interval(1.0, 2.0, Closed, Closed);
+        assert!(a.union(b).is_none());
+        assert!(!a.overlaps(c));
+        assert_eq!(a.union(c), Some(interval(0.0, 2.0, Closed, Closed)));
+        assert_eq!(c.union(a), Some(interval(0.0, 2.0, Closed, Closed)));
+        let d = interval(0.0, 1.0, Open, Closed);
+        assert_eq!(d.intersection(c), Some(interval(1.0, 1.0, Closed, Closed)));
+        assert_eq!(d.intersection(a), Some(interval(0.0, 1.0, Open, Open)));
+    }
+
+    #[test]
+    fn days() {
+        let a = FixedInterval::closed(Fixed::new(0.5), Fixed::new(3.0)).unwrap();
+        let d = a.days::<RataDie>();
+        assert_eq!(
+            d,
+            vec![RataDie::new(1.0), RataDie::new(2.0), RataDie::new(3.0)]
+        );
+        let b = FixedInterval::half_open(Fixed::new(0.0), Fixed::new(3.0)).unwrap();
+        let d = b.days::<RataDie>();
+        assert_eq!(
+            d,
+            vec![RataDie::new(0.0), RataDie::new(1.0), RataDie::new(2.0)]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn set_operations(
+            t0 in FIXED_MIN..FIXED_MAX,
+            t1 in FIXED_MIN..FIXED_MAX,
+            t2 in FIXED_MIN..FIXED_MAX,
+            t3 in FIXED_MIN..FIXED_MAX,
+            t in FIXED_MIN..FIXED_MAX,
+        ) {
+            let a = FixedInterval::closed(Fixed::new(t0.min(t1)), Fixed::new(t0.max(t1))).unwrap();
+            let b = FixedInterval::half_open(Fixed::new(t2.min(t3)), Fixed::new(t2.max(t3)));
+            if let Ok(b) = b {
+                let x = Fixed::new(t);
+                let in_both = a.contains(x) && b.contains(x);
+                match a.intersection(b) {
+                    Some(i) => {
+                        assert_eq!(i.contains(x), in_both);
+                        assert!(i.duration() <= a.duration() && i.duration() <= b.duration());
+                        assert!(a.union(b).is_some());
+                    }
+                    None => assert!(!in_both),
+                }
+                if let Some(u) = a.union(b) {
+                    assert_eq!(u.contains(x), a.contains(x) || b.contains(x));
+                    assert!(u.duration() >= a.duration() && u.duration() >= b.duration());
+                }
+                assert_eq!(a.overlaps(b), b.overlaps(a));
+            }
+        }
+    }
+}
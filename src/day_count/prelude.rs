@@ -4,15 +4,46 @@
 
 use crate::common::error::CalendarError;
 use crate::common::math::TermNum;
+use crate::day_count::fixed::Fixed;
+use crate::day_count::fixed::ToFixed;
 use num_traits::AsPrimitive;
 
+/// Timekeeping systems with a limited range of supported values
+///
+/// Calculations outside of the effective bounds may be inaccurate. See [`Fixed`] for
+/// details.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+///
+/// assert!(Gregorian::contains(Fixed::new(0.0)));
+/// assert!(!Gregorian::contains(Fixed::new(FIXED_MAX + 1.0)));
+/// assert!(RataDie::contains(Fixed::effective_max()));
+/// ```
 pub trait EffectiveBound: Copy + Clone + PartialEq + PartialOrd {
+    /// Earliest supported value
     fn effective_min() -> Self;
+    /// Latest supported value
     fn effective_max() -> Self;
+
+    /// [`true`] if `t` is within the effective bounds of this timekeeping system
+    fn contains(t: Fixed) -> bool
+    where
+        Self: ToFixed,
+    {
+        t >= Self::effective_min().to_fixed() && t <= Self::effective_max().to_fixed()
+    }
 }
 
+/// Timekeeping systems which identify a day using a single number
 pub trait BoundedDayCount<T: TermNum>: EffectiveBound {
+    /// Create a day count
+    ///
+    /// The value is not checked in release builds. Use
+    /// [`in_effective_bounds`](BoundedDayCount::in_effective_bounds) to check it first.
     fn new(t: T) -> Self;
+    /// Underlying number of the day count
     fn get(self) -> T;
 
     fn almost_in_effective_bounds(t: T, dt: T) -> Result<(), CalendarError> {
@@ -29,10 +60,12 @@ pub trait BoundedDayCount<T: TermNum>: EffectiveBound {
         }
     }
 
+    /// Returns an error if `t` is NaN or outside of the effective bounds
     fn in_effective_bounds(t: T) -> Result<(), CalendarError> {
         Self::almost_in_effective_bounds(t, T::zero())
     }
 
+    /// Create a day count from any primitive number
     fn cast_new<U: AsPrimitive<T>>(t: U) -> Self {
         Self::new(t.as_())
     }
@@ -2,6 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use crate::common::units::SECONDS_PER_DAY;
 use crate::day_count::fixed::CalculatedBounds;
 use crate::day_count::fixed::Epoch;
 use crate::day_count::fixed::Fixed;
@@ -11,7 +12,7 @@ use crate::day_count::prelude::BoundedDayCount;
 
 //LISTING 1.9 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
 const UNIX_EPOCH: f64 = 719163.0;
-const UNIX_DAY: f64 = 24.0 * 60.0 * 60.0;
+const UNIX_DAY: f64 = SECONDS_PER_DAY as f64;
 
 /// Represents seconds since the Unix epoch
 ///
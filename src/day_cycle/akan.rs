@@ -91,6 +91,57 @@ impl Akan {
     pub fn prefix(self) -> AkanPrefix {
         self.prefix
     }
+
+    /// Position in the 42 day cycle, from 1 to 42
+    ///
+    /// The prefixes repeat every 6 days and the stems repeat every 7 days, so each
+    /// combination of prefix and stem occurs exactly once in 42 days. The position `n`
+    /// of a day satisfies `n ≡ prefix (mod 6)` and `n ≡ stem (mod 7)`, where the prefix
+    /// and stem are numbered from 1.
+    ///
+    /// ```
+    /// use radnelac::day_cycle::*;
+    ///
+    /// let a = Akan::new(AkanPrefix::Fo, AkanStem::Kwasi);
+    /// assert_eq!(a.position(), 12);
+    /// assert_eq!(Akan::day_name(12), a);
+    /// ```
+    pub fn position(self) -> u8 {
+        let p = self.prefix as i64;
+        let s = self.stem as i64;
+        //7 is congruent to 1 modulo 6, and 36 is congruent to 1 modulo 7
+        let n = (7 * p + 36 * s).adjusted_remainder(CYCLE_LENGTH as i64);
+        debug_assert_eq!(Akan::day_name(n), self);
+        n as u8
+    }
+
+    /// First day on or after `date` with a specific stem
+    ///
+    /// This is independent of the prefix, so the result is within 7 days.
+    ///
+    /// ```
+    /// use radnelac::day_count::*;
+    /// use radnelac::day_cycle::*;
+    ///
+    /// let t = Fixed::cast_new(739372);
+    /// let k = Akan::stem_on_or_after(AkanStem::Kwasi, t);
+    /// assert_eq!(Akan::from_fixed(k).stem(), AkanStem::Kwasi);
+    /// assert!(k.get_day_i() - t.get_day_i() < 7);
+    /// ```
+    pub fn stem_on_or_after(stem: AkanStem, date: Fixed) -> Fixed {
+        let current = Akan::from_fixed(date).stem as i64;
+        let diff = (stem as i64 - current).modulus(7);
+        Fixed::cast_new(date.get_day_i() + diff)
+    }
+
+    /// First day on or after `date` with a specific prefix
+    ///
+    /// This is independent of the stem, so the result is within 6 days.
+    pub fn prefix_on_or_after(prefix: AkanPrefix, date: Fixed) -> Fixed {
+        let current = Akan::from_fixed(date).prefix as i64;
+        let diff = (prefix as i64 - current).modulus(6);
+        Fixed::cast_new(date.get_day_i() + diff)
+    }
 }
 
 impl FromFixed for Akan {
@@ -160,6 +211,31 @@ mod tests {
             assert_eq!(a1.stem(), a4.stem());
         }
 
+        #[test]
+        fn position(n in 1..=42i64) {
+            let a = Akan::day_name(n);
+            assert_eq!(a.position() as i64, n);
+            assert_eq!(a.to_i64().unwrap(), n);
+            assert_eq!((n - 1) % 6 + 1, a.prefix() as i64);
+            assert_eq!((n - 1) % 7 + 1, a.stem() as i64);
+        }
+
+        #[test]
+        fn next_occurrence(x in FIXED_MIN..(FIXED_MAX - 42.0), p in 1..=6i64, s in 1..=7i64) {
+            let t = Fixed::new(x);
+            let prefix = AkanPrefix::from_i64(p).unwrap();
+            let stem = AkanStem::from_i64(s).unwrap();
+            let ts = Akan::stem_on_or_after(stem, t);
+            let tp = Akan::prefix_on_or_after(prefix, t);
+            assert_eq!(Akan::from_fixed(ts).stem(), stem);
+            assert_eq!(Akan::from_fixed(tp).prefix(), prefix);
+            assert!((0..7).contains(&(ts.get_day_i() - t.get_day_i())));
+            assert!((0..6).contains(&(tp.get_day_i() - t.get_day_i())));
+            let ta = Akan::new(prefix, stem).on_or_after(t);
+            assert!(ta >= ts && ta >= tp);
+            assert_eq!(Akan::from_fixed(ta), Akan::new(prefix, stem));
+        }
+
         #[test]
         fn prefix_stem_sequence(x in FIXED_MIN..FIXED_MAX) {
             let f0 = Fixed::new(x);
@@ -5,18 +5,21 @@
 use crate::day_cycle::Akan;
 use crate::day_cycle::AkanPrefix;
 use crate::day_cycle::AkanStem;
-use crate::display::private::get_dict;
+use crate::display::private::get_table;
 use crate::display::text::prelude::Language;
-use std::fmt;
+use crate::display::CycleDisplay;
+use core::fmt;
 
-impl fmt::Display for Akan {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let dict = get_dict(Language::EN)
-            .akan_cycle
-            .as_ref()
-            .expect("Known to exist");
-        // Using names from Wikipedia
-        // https://en.wikipedia.org/wiki/Akan_calendar
+impl CycleDisplay for Akan {
+    //The Twi names are not translated, so every language falls back to the same table
+    fn supported_cycle_lang(lang: Language) -> bool {
+        get_table(lang, |d| d.akan_cycle.as_ref()).is_some()
+    }
+
+    fn fmt_cycle_name(&self, w: &mut impl fmt::Write, lang: Language) -> fmt::Result {
+        let Some(dict) = get_table(lang, |d| d.akan_cycle.as_ref()) else {
+            return Ok(());
+        };
         let prefix = match self.prefix() {
             AkanPrefix::Nwona => dict.nwona,
             AkanPrefix::Nkyi => dict.nkyi,
@@ -37,6 +40,28 @@ impl fmt::Display for Akan {
             AkanStem::Dwo => dict.dwo,
             AkanStem::Bene => dict.bene,
         };
-        write!(f, "{}-{}", prefix, stem)
+        write!(w, "{}{}{}", prefix, dict.seperator, stem)
+    }
+}
+
+impl fmt::Display for Akan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_cycle_name(f, Language::EN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn akan_display_french() {
+        assert!(Akan::supported_cycle_lang(Language::EN));
+        assert!(Akan::supported_cycle_lang(Language::FR));
+        for i in 0..42 {
+            let a = Akan::day_name(i);
+            assert_eq!(a.cycle_name(Language::EN), Some(a.to_string()));
+            assert_eq!(a.cycle_name(Language::FR), a.cycle_name(Language::EN));
+        }
     }
 }
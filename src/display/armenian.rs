@@ -12,19 +12,23 @@ use crate::day_count::ToFixed;
 use crate::day_cycle::Weekday;
 use crate::display::moment::DisplayMomentItem;
 use crate::display::prelude::PresetDisplay;
-use crate::display::private::fmt_days_since_epoch;
-use crate::display::private::fmt_number;
-use crate::display::private::fmt_quarter;
-use crate::display::private::fmt_seconds_since_epoch;
-use crate::display::private::fmt_string;
+use crate::display::prelude::PresetFormat;
 use crate::display::private::get_dict;
+use crate::display::private::get_table;
+use crate::display::private::write_days_since_epoch;
+use crate::display::private::write_half_year;
+use crate::display::private::write_number;
+use crate::display::private::write_quarter;
+use crate::display::private::write_seconds_since_epoch;
+use crate::display::private::write_string;
+use crate::display::private::write_week_of_month_name;
 use crate::display::private::DisplayItem;
 use crate::display::private::NumericContent;
 use crate::display::text::prelude::Language;
 use crate::display::LONG_DATE;
 use crate::display::LONG_DAY_OF_MONTH;
 use crate::display::YYYYMMDD_DASH;
-use std::fmt;
+use core::fmt;
 
 use crate::display::private::TextContent;
 
@@ -35,27 +39,41 @@ impl DisplayItem for Armenian {
         get_dict(lang).armenian.as_ref().is_some()
     }
 
-    fn fmt_numeric(&self, n: NumericContent, opt: DisplayOptions) -> String {
+    fn fmt_numeric(
+        &self,
+        w: &mut impl fmt::Write,
+        n: NumericContent,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
         match n {
             NumericContent::Month | NumericContent::DayOfMonth | NumericContent::Year => {
-                self.to_common_date().fmt_numeric(n, opt)
+                self.to_common_date().fmt_numeric(w, n, opt)
             }
-            NumericContent::DayOfWeek => self.convert::<Weekday>().fmt_numeric(n, opt),
-            NumericContent::DayOfYear => self.to_ordinal().fmt_numeric(n, opt),
+            NumericContent::DayOfWeek => self.convert::<Weekday>().fmt_numeric(w, n, opt),
+            NumericContent::DayOfYear => self.to_ordinal().fmt_numeric(w, n, opt),
             NumericContent::Hour1to12
             | NumericContent::Hour0to23
             | NumericContent::Minute
-            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(n, opt),
-            NumericContent::SecondsSinceEpoch => fmt_seconds_since_epoch(*self, opt),
-            NumericContent::Quarter => fmt_quarter(*self, opt),
-            NumericContent::DaysSinceEpoch => fmt_days_since_epoch(*self, opt),
-            NumericContent::ComplementaryDay => "".to_string(),
-            NumericContent::WeekOfYear => fmt_number(self.week_of_year() as i16, opt),
+            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(w, n, opt),
+            NumericContent::SecondsSinceEpoch => write_seconds_since_epoch(w, *self, opt),
+            NumericContent::Quarter => write_quarter(w, *self, opt),
+            NumericContent::HalfYear => write_half_year(w, *self, opt),
+            NumericContent::DaysSinceEpoch => write_days_since_epoch(w, *self, opt),
+            NumericContent::ComplementaryDay => Ok(()),
+            NumericContent::WeekOfYear => write_number(w, self.week_of_year() as i16, opt),
+            NumericContent::WeekOfMonth => write_number(w, self.week_of_month() as i16, opt),
+            NumericContent::DayOfMonthWeek => write_number(w, self.day_of_month_week() as i16, opt),
         }
     }
-    fn fmt_text(&self, t: TextContent, lang: Language, opt: DisplayOptions) -> String {
+    fn fmt_text(
+        &self,
+        w: &mut impl fmt::Write,
+        t: TextContent,
+        lang: Language,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
         //https://en.wikipedia.org/wiki/Armenian_calendar
-        match (t, get_dict(lang).armenian.as_ref()) {
+        match (t, get_table(lang, |d| d.armenian.as_ref())) {
             (TextContent::MonthName, Some(dict)) => {
                 let months: [&str; 13] = [
                     dict.nawasardi,
@@ -74,7 +92,7 @@ impl DisplayItem for Armenian {
                 ];
                 let m = self.to_common_date().month;
                 let name = months[m as usize - 1];
-                fmt_string(name, opt)
+                write_string(w, name, opt)
             }
             (TextContent::DayOfMonthName, Some(dict)) => {
                 let days: [&str; 30] = [
@@ -110,48 +128,51 @@ impl DisplayItem for Armenian {
                     dict.giseravar,
                 ];
                 match self.day_name() {
-                    Some(d) => fmt_string(days[d as usize - 1], opt),
-                    None => fmt_string("", opt),
+                    Some(d) => write_string(w, days[d as usize - 1], opt),
+                    None => write_string(w, "", opt),
                 }
             }
             (TextContent::DayOfWeekName, Some(_)) => {
-                self.convert::<Weekday>().fmt_text(t, lang, opt)
-            }
-            (TextContent::HalfDayName | TextContent::HalfDayAbbrev, Some(_)) => {
-                self.convert::<TimeOfDay>().fmt_text(t, lang, opt)
+                self.convert::<Weekday>().fmt_text(w, t, lang, opt)
             }
+            (
+                TextContent::HalfDayName | TextContent::HalfDayAbbrev | TextContent::PartOfDayName,
+                Some(_),
+            ) => self.convert::<TimeOfDay>().fmt_text(w, t, lang, opt),
             (TextContent::EraName, Some(dict)) => {
                 if self.to_common_date().year < 0 {
-                    fmt_string(dict.before_epoch_full, opt)
+                    write_string(w, dict.before_epoch_full, opt)
                 } else {
-                    fmt_string(dict.after_epoch_full, opt)
+                    write_string(w, dict.after_epoch_full, opt)
                 }
             }
             (TextContent::EraAbbreviation, Some(dict)) => {
                 if self.to_common_date().year < 0 {
-                    fmt_string(dict.before_epoch_abr, opt)
+                    write_string(w, dict.before_epoch_abr, opt)
                 } else {
-                    fmt_string(dict.after_epoch_abr, opt)
+                    write_string(w, dict.after_epoch_abr, opt)
                 }
             }
-            (_, _) => fmt_string("", opt),
+            (TextContent::WeekOfMonthName, _) => {
+                write_week_of_month_name(w, Some(self.week_of_month()), lang, opt)
+            }
+            (_, _) => write_string(w, "", opt),
         }
     }
 }
 
 impl PresetDisplay for Armenian {
-    fn long_date(&self) -> String {
-        let p = match self.epagomenae() {
+    fn long_date_preset(&self) -> PresetFormat<'static> {
+        match self.epagomenae() {
             None => LONG_DAY_OF_MONTH,
             Some(_) => LONG_DATE,
-        };
-        self.preset_str(Language::EN, p)
+        }
     }
 }
 
 impl fmt::Display for Armenian {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.long_date())
+        self.fmt_into(f, Language::EN, self.long_date_preset())
     }
 }
 
@@ -164,5 +185,6 @@ mod tests {
     #[test]
     fn expected_languages() {
         assert!(Armenian::supported_lang(Language::EN));
+        assert!(Armenian::supported_lang(Language::FR));
     }
 }
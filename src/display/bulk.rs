@@ -0,0 +1,84 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::display::prelude::PresetDisplay;
+use crate::display::prelude::PresetFormat;
+use crate::display::text::prelude::Language;
+use rayon::prelude::*;
+
+/// Format many dates in parallel
+///
+/// The result is in the same order as `dates`.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::display::*;
+///
+/// let dates: Vec<Gregorian> = (1..=3)
+///     .map(|d| Gregorian::try_new(2025, GregorianMonth::May, d).unwrap())
+///     .collect();
+/// let s = format_many(&dates, Language::EN, YYYYMMDD_DASH);
+/// assert_eq!(s, vec!["2025-05-01", "2025-05-02", "2025-05-03"]);
+/// ```
+///
+/// ## Crate Features
+///
+/// This is only available if `rayon` is enabled.
+pub fn format_many<T: PresetDisplay + Sync>(
+    dates: &[T],
+    lang: Language,
+    preset: PresetFormat,
+) -> Vec<String> {
+    let mut result = Vec::with_capacity(dates.len());
+    dates
+        .par_iter()
+        .map(|d| d.preset_str(lang, preset))
+        .collect_into_vec(&mut result);
+    result
+}
+
+/// Format many points in time as dates in a specific calendar, in parallel
+///
+/// The result is in the same order as `times`.
+///
+/// ## Crate Features
+///
+/// This is only available if `rayon` is enabled.
+pub fn format_many_fixed<T: FromFixed + PresetDisplay>(
+    times: &[Fixed],
+    lang: Language,
+    preset: PresetFormat,
+) -> Vec<String> {
+    let mut result = Vec::with_capacity(times.len());
+    times
+        .par_iter()
+        .map(|t| T::from_fixed(*t).preset_str(lang, preset))
+        .collect_into_vec(&mut result);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Julian;
+    use crate::day_count::BoundedDayCount;
+    use crate::display::LONG_DATE;
+    use proptest::proptest;
+
+    proptest! {
+        #[test]
+        fn same_as_sequential(t0 in -1000000.0..1000000.0, n in 0..200) {
+            let times: Vec<Fixed> = (0..n).map(|i| Fixed::new(t0 + (i as f64))).collect();
+            let dates: Vec<Julian> = times.iter().map(|t| Julian::from_fixed(*t)).collect();
+            let expected: Vec<String> = dates
+                .iter()
+                .map(|d| d.preset_str(Language::FR, LONG_DATE))
+                .collect();
+            assert_eq!(format_many(&dates, Language::FR, LONG_DATE), expected);
+            assert_eq!(format_many_fixed::<Julian>(&times, Language::FR, LONG_DATE), expected);
+        }
+    }
+}
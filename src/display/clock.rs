@@ -3,44 +3,67 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use crate::clock::ClockTime;
+use crate::clock::PartOfDay;
+use crate::clock::SecondsRounding;
 use crate::clock::TimeOfDay;
 use crate::common::math::TermNum;
 use crate::display::prelude::PresetDisplay;
 use crate::display::prelude::HHMMSS_COLON;
-use crate::display::private::fmt_number;
-use crate::display::private::fmt_string;
 use crate::display::private::get_dict;
+use crate::display::private::get_table;
+use crate::display::private::write_number;
+use crate::display::private::write_string;
 use crate::display::private::DisplayItem;
 use crate::display::private::DisplayOptions;
 use crate::display::private::NumericContent;
 use crate::display::private::TextContent;
 use crate::display::text::prelude::Language;
-use std::fmt;
+use core::fmt;
 
 impl DisplayItem for ClockTime {
     fn supported_lang(lang: Language) -> bool {
         get_dict(lang).common_clock.as_ref().is_some()
     }
 
-    fn fmt_numeric(&self, n: NumericContent, opt: DisplayOptions) -> String {
+    fn fmt_numeric(
+        &self,
+        w: &mut impl fmt::Write,
+        n: NumericContent,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
         match n {
-            NumericContent::Hour1to12 => fmt_number(self.hour_1_to_12() as i64, opt),
-            NumericContent::Hour0to23 => fmt_number(self.hours as i16, opt),
-            NumericContent::Minute => fmt_number(self.minutes as i16, opt),
-            NumericContent::Second => fmt_number(self.seconds as i16, opt),
-            _ => "".to_string(),
+            NumericContent::Hour1to12 => write_number(w, self.hour_1_to_12() as i64, opt),
+            NumericContent::Hour0to23 => write_number(w, self.hours as i16, opt),
+            NumericContent::Minute => write_number(w, self.minutes as i16, opt),
+            NumericContent::Second => write_number(w, self.seconds as i16, opt),
+            _ => Ok(()),
         }
     }
 
-    fn fmt_text(&self, t: TextContent, lang: Language, opt: DisplayOptions) -> String {
-        let dict_opt = get_dict(lang).common_clock.as_ref();
+    fn fmt_text(
+        &self,
+        w: &mut impl fmt::Write,
+        t: TextContent,
+        lang: Language,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
+        let dict_opt = get_table(lang, |d| d.common_clock.as_ref());
         let before_noon = *self < TimeOfDay::noon().to_clock();
         match (t, dict_opt, before_noon) {
-            (TextContent::HalfDayName, Some(dict), true) => fmt_string(dict.am_full, opt),
-            (TextContent::HalfDayName, Some(dict), false) => fmt_string(dict.pm_full, opt),
-            (TextContent::HalfDayAbbrev, Some(dict), true) => fmt_string(dict.am_abr, opt),
-            (TextContent::HalfDayAbbrev, Some(dict), false) => fmt_string(dict.pm_abr, opt),
-            (_, _, _) => "".to_string(),
+            (TextContent::HalfDayName, Some(dict), true) => write_string(w, dict.am_full, opt),
+            (TextContent::HalfDayName, Some(dict), false) => write_string(w, dict.pm_full, opt),
+            (TextContent::HalfDayAbbrev, Some(dict), true) => write_string(w, dict.am_abr, opt),
+            (TextContent::HalfDayAbbrev, Some(dict), false) => write_string(w, dict.pm_abr, opt),
+            (TextContent::PartOfDayName, Some(dict), _) => {
+                let name = match self.part_of_day() {
+                    PartOfDay::Night => dict.night,
+                    PartOfDay::Morning => dict.morning,
+                    PartOfDay::Afternoon => dict.afternoon,
+                    PartOfDay::Evening => dict.evening,
+                };
+                write_string(w, name, opt)
+            }
+            (_, _, _) => Ok(()),
         }
     }
 }
@@ -49,7 +72,76 @@ impl PresetDisplay for ClockTime {}
 
 impl fmt::Display for ClockTime {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.preset_str(Language::EN, HHMMSS_COLON))
+        self.fmt_into(f, Language::EN, HHMMSS_COLON)
+    }
+}
+
+impl TimeOfDay {
+    /// Format the time of day as hours, minutes and seconds with a fractional part
+    ///
+    /// The seconds are written with `precision` digits after the decimal point, up to a
+    /// maximum of 9 digits. Values which round up to the end of the day wrap around
+    /// to midnight.
+    ///
+    /// ```
+    /// use radnelac::clock::*;
+    ///
+    /// let t = TimeOfDay::new(0.5 + (1.25 / 86400.0));
+    /// assert_eq!(t.sexagesimal_str(2, SecondsRounding::Nearest), "12:00:01.25");
+    /// assert_eq!(t.sexagesimal_str(1, SecondsRounding::Floor), "12:00:01.2");
+    /// assert_eq!(t.sexagesimal_str(0, SecondsRounding::Ceiling), "12:00:02");
+    /// ```
+    ///
+    /// ## Crate Features
+    ///
+    /// This is only available if `display` is enabled.
+    pub fn sexagesimal_str(self, precision: u8, rounding: SecondsRounding) -> String {
+        let mut result = String::new();
+        self.fmt_sexagesimal_into(&mut result, precision, rounding)
+            .expect("Writing to String does not fail");
+        result
+    }
+
+    /// Write the time of day as hours, minutes and seconds into a buffer
+    ///
+    /// This is the same as [`sexagesimal_str`](TimeOfDay::sexagesimal_str) without the
+    /// intermediate `String`.
+    ///
+    /// ## Crate Features
+    ///
+    /// This is only available if `display` is enabled.
+    pub fn fmt_sexagesimal_into(
+        self,
+        w: &mut impl fmt::Write,
+        precision: u8,
+        rounding: SecondsRounding,
+    ) -> fmt::Result {
+        let precision = precision.min(9);
+        let scale = 10_u64.pow(precision as u32);
+        let x = self.to_seconds_of_day() * (scale as f64);
+        let x = match rounding {
+            SecondsRounding::Floor => x.floor(),
+            SecondsRounding::Nearest => x.round(),
+            SecondsRounding::Ceiling => x.ceil(),
+        };
+        let total = (x as u64) % (24 * 60 * 60 * scale);
+        let fraction = total % scale;
+        let seconds = (total / scale) % 60;
+        let minutes = (total / (scale * 60)) % 60;
+        let hours = total / (scale * 60 * 60);
+        if precision == 0 {
+            write!(w, "{:02}:{:02}:{:02}", hours, minutes, seconds)
+        } else {
+            write!(
+                w,
+                "{:02}:{:02}:{:02}.{:0width$}",
+                hours,
+                minutes,
+                seconds,
+                fraction,
+                width = precision as usize
+            )
+        }
     }
 }
 
@@ -58,12 +150,23 @@ impl DisplayItem for TimeOfDay {
         get_dict(lang).common_clock.as_ref().is_some()
     }
 
-    fn fmt_numeric(&self, n: NumericContent, opt: DisplayOptions) -> String {
-        self.to_clock().fmt_numeric(n, opt)
+    fn fmt_numeric(
+        &self,
+        w: &mut impl fmt::Write,
+        n: NumericContent,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
+        self.to_clock().fmt_numeric(w, n, opt)
     }
 
-    fn fmt_text(&self, t: TextContent, lang: Language, opt: DisplayOptions) -> String {
-        self.to_clock().fmt_text(t, lang, opt)
+    fn fmt_text(
+        &self,
+        w: &mut impl fmt::Write,
+        t: TextContent,
+        lang: Language,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
+        self.to_clock().fmt_text(w, t, lang, opt)
     }
 }
 
@@ -71,17 +174,54 @@ impl PresetDisplay for TimeOfDay {}
 
 impl fmt::Display for TimeOfDay {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.preset_str(Language::EN, HHMMSS_COLON))
+        self.fmt_into(f, Language::EN, HHMMSS_COLON)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::display::prelude::HALF_DAY_ONLY;
+    use crate::display::prelude::PART_OF_DAY_ONLY;
 
     #[test]
     fn expected_languages() {
         assert!(TimeOfDay::supported_lang(Language::EN));
         assert!(TimeOfDay::supported_lang(Language::FR));
     }
+
+    #[test]
+    fn coarse() {
+        let t = TimeOfDay::new(0.8);
+        assert_eq!(t.preset_str(Language::EN, PART_OF_DAY_ONLY), "Evening");
+        assert_eq!(t.preset_str(Language::FR, PART_OF_DAY_ONLY), "Soir");
+        assert_eq!(t.preset_str(Language::EN, HALF_DAY_ONLY), "PM");
+        let t = TimeOfDay::new(0.1);
+        assert_eq!(t.preset_str(Language::EN, PART_OF_DAY_ONLY), "Night");
+        assert_eq!(t.preset_str(Language::EN, HALF_DAY_ONLY), "AM");
+        let t = ClockTime::try_new(12, 0, 0.0).unwrap();
+        assert_eq!(t.preset_str(Language::FR, PART_OF_DAY_ONLY), "Après-midi");
+    }
+
+    #[test]
+    fn sexagesimal() {
+        let t = TimeOfDay::new(1.0 - (0.0004 / 86400.0));
+        assert_eq!(t.sexagesimal_str(3, SecondsRounding::Floor), "23:59:59.999");
+        assert_eq!(
+            t.sexagesimal_str(3, SecondsRounding::Nearest),
+            "00:00:00.000"
+        );
+        assert_eq!(
+            t.sexagesimal_str(2, SecondsRounding::Ceiling),
+            "00:00:00.00"
+        );
+        let t = TimeOfDay::new(0.25 + (0.06 / 86400.0));
+        assert_eq!(t.sexagesimal_str(20, SecondsRounding::Floor).len(), 18);
+        assert_eq!(t.sexagesimal_str(1, SecondsRounding::Nearest), "06:00:00.1");
+        assert_eq!(t.sexagesimal_str(0, SecondsRounding::Nearest), "06:00:00");
+        assert_eq!(
+            TimeOfDay::midnight().sexagesimal_str(6, SecondsRounding::Ceiling),
+            "00:00:00.000000"
+        );
+    }
 }
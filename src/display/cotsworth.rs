@@ -11,55 +11,79 @@ use crate::clock::TimeOfDay;
 use crate::day_count::ToFixed;
 use crate::display::moment::DisplayMomentItem;
 use crate::display::prelude::PresetDisplay;
-use crate::display::private::fmt_days_since_epoch;
-use crate::display::private::fmt_number;
-use crate::display::private::fmt_quarter;
-use crate::display::private::fmt_seconds_since_epoch;
-use crate::display::private::fmt_string;
+use crate::display::prelude::PresetFormat;
+use crate::display::prelude::LONG_DATE_NO_WEEKDAY;
 use crate::display::private::get_dict;
+use crate::display::private::get_table;
+use crate::display::private::write_days_since_epoch;
+use crate::display::private::write_half_year;
+use crate::display::private::write_number;
+use crate::display::private::write_quarter;
+use crate::display::private::write_seconds_since_epoch;
+use crate::display::private::write_string;
+use crate::display::private::write_week_of_month_name;
 use crate::display::private::DisplayItem;
 use crate::display::private::DisplayOptions;
 use crate::display::private::NumericContent;
 use crate::display::private::TextContent;
 use crate::display::text::prelude::Language;
-use crate::display::LONG_COMPL;
 use crate::display::LONG_DATE;
-use std::fmt;
+use core::fmt;
 
 impl DisplayItem for Cotsworth {
     fn supported_lang(lang: Language) -> bool {
         get_dict(lang).cotsworth.as_ref().is_some()
     }
 
-    fn fmt_numeric(&self, n: NumericContent, opt: DisplayOptions) -> String {
+    fn fmt_numeric(
+        &self,
+        w: &mut impl fmt::Write,
+        n: NumericContent,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
         match n {
             NumericContent::Month | NumericContent::DayOfMonth | NumericContent::Year => {
-                self.to_common_date().fmt_numeric(n, opt)
+                self.to_common_date().fmt_numeric(w, n, opt)
             }
             NumericContent::DayOfWeek => match self.weekday() {
-                Some(d) => d.fmt_numeric(n, opt),
-                None => "".to_string(),
+                Some(d) => d.fmt_numeric(w, n, opt),
+                None => Ok(()),
             },
-            NumericContent::DayOfYear => self.to_ordinal().fmt_numeric(n, opt),
+            NumericContent::DayOfYear => self.to_ordinal().fmt_numeric(w, n, opt),
             NumericContent::Hour1to12
             | NumericContent::Hour0to23
             | NumericContent::Minute
-            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(n, opt),
-            NumericContent::SecondsSinceEpoch => fmt_seconds_since_epoch(*self, opt),
-            NumericContent::Quarter => fmt_quarter(*self, opt),
-            NumericContent::DaysSinceEpoch => fmt_days_since_epoch(*self, opt),
+            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(w, n, opt),
+            NumericContent::SecondsSinceEpoch => write_seconds_since_epoch(w, *self, opt),
+            NumericContent::Quarter => write_quarter(w, *self, opt),
+            NumericContent::HalfYear => write_half_year(w, *self, opt),
+            NumericContent::DaysSinceEpoch => write_days_since_epoch(w, *self, opt),
             NumericContent::ComplementaryDay => match self.epagomenae() {
-                Some(d) => fmt_number(d as i8, opt),
-                None => "".to_string(),
+                Some(d) => write_number(w, d as i8, opt),
+                None => Ok(()),
             },
             NumericContent::WeekOfYear => match self.try_week_of_year() {
-                Some(w) => fmt_number(w as i8, opt),
-                None => "".to_string(),
+                Some(week) => write_number(w, week as i8, opt),
+                None => Ok(()),
+            },
+            NumericContent::WeekOfMonth => match self.try_week_of_month() {
+                Some(week) => write_number(w, week as i8, opt),
+                None => Ok(()),
+            },
+            NumericContent::DayOfMonthWeek => match self.try_day_of_month_week() {
+                Some(d) => write_number(w, d as i8, opt),
+                None => Ok(()),
             },
         }
     }
-    fn fmt_text(&self, t: TextContent, lang: Language, opt: DisplayOptions) -> String {
-        match (t, get_dict(lang).cotsworth.as_ref()) {
+    fn fmt_text(
+        &self,
+        w: &mut impl fmt::Write,
+        t: TextContent,
+        lang: Language,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
+        match (t, get_table(lang, |d| d.cotsworth.as_ref())) {
             (TextContent::MonthName, Some(dict)) => {
                 let months: [&str; 13] = [
                     dict.january,
@@ -80,27 +104,28 @@ impl DisplayItem for Cotsworth {
                     Some(m) => months[(m as usize) - 1],
                     None => "",
                 };
-                fmt_string(name, opt)
+                write_string(w, name, opt)
             }
             (TextContent::DayOfWeekName, _) => match self.weekday() {
-                Some(m) => m.fmt_text(t, lang, opt),
-                None => fmt_string("", opt),
+                Some(m) => m.fmt_text(w, t, lang, opt),
+                None => write_string(w, "", opt),
             },
-            (TextContent::HalfDayName | TextContent::HalfDayAbbrev, _) => {
-                self.convert::<TimeOfDay>().fmt_text(t, lang, opt)
-            }
+            (
+                TextContent::HalfDayName | TextContent::HalfDayAbbrev | TextContent::PartOfDayName,
+                _,
+            ) => self.convert::<TimeOfDay>().fmt_text(w, t, lang, opt),
             (TextContent::EraName, Some(dict)) => {
                 if self.to_common_date().year < 0 {
-                    fmt_string(dict.before_epoch_full, opt)
+                    write_string(w, dict.before_epoch_full, opt)
                 } else {
-                    fmt_string(dict.after_epoch_full, opt)
+                    write_string(w, dict.after_epoch_full, opt)
                 }
             }
             (TextContent::EraAbbreviation, Some(dict)) => {
                 if self.to_common_date().year < 0 {
-                    fmt_string(dict.before_epoch_abr, opt)
+                    write_string(w, dict.before_epoch_abr, opt)
                 } else {
-                    fmt_string(dict.after_epoch_abr, opt)
+                    write_string(w, dict.after_epoch_abr, opt)
                 }
             }
             (TextContent::ComplementaryDayName, Some(dict)) => {
@@ -109,23 +134,30 @@ impl DisplayItem for Cotsworth {
                     Some(d) => compl[(d as usize) - 1],
                     None => "",
                 };
-                fmt_string(name, opt)
+                write_string(w, name, opt)
+            }
+            (TextContent::WeekOfMonthName, _) => {
+                write_week_of_month_name(w, self.try_week_of_month(), lang, opt)
             }
-            (_, _) => fmt_string("", opt),
+            (_, _) => write_string(w, "", opt),
         }
     }
 }
 
 impl PresetDisplay for Cotsworth {
-    fn long_date(&self) -> String {
-        //Avoid non-existent weekday
-        self.preset_str(Language::EN, LONG_DATE).trim().to_string()
+    fn long_date_preset(&self) -> PresetFormat<'static> {
+        //Complementary days have no weekday, but are still numbered within a month
+        if self.epagomenae().is_some() {
+            LONG_DATE_NO_WEEKDAY
+        } else {
+            LONG_DATE
+        }
     }
 }
 
 impl fmt::Display for Cotsworth {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.long_date())
+        self.fmt_into(f, Language::EN, self.long_date_preset())
     }
 }
 
@@ -134,10 +166,53 @@ impl DisplayMomentItem for Cotsworth {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::calendar::CommonDate;
+    use crate::display::DAY_WEEK_OF_MONTH;
+    use crate::display::MONTH_WEEK_DAY;
 
     #[test]
     fn expected_languages() {
         assert!(Cotsworth::supported_lang(Language::EN));
         assert!(Cotsworth::supported_lang(Language::FR));
     }
+
+    #[test]
+    fn week_of_month() {
+        let d_list = [
+            (CommonDate::new(2025, 7, 16), "2025-07-W3-2", "Sol 16, W3"),
+            (CommonDate::new(2025, 1, 1), "2025-01-W1-1", "January 1, W1"),
+            (
+                CommonDate::new(2025, 13, 28),
+                "2025-13-W4-7",
+                "December 28, W4",
+            ),
+        ];
+        for item in d_list {
+            let d = Cotsworth::try_from_common_date(item.0).unwrap();
+            assert_eq!(d.preset_str(Language::EN, MONTH_WEEK_DAY), item.1);
+            assert_eq!(d.preset_str(Language::EN, DAY_WEEK_OF_MONTH), item.2);
+        }
+        let d = Cotsworth::try_from_common_date(CommonDate::new(2025, 13, 29)).unwrap();
+        assert_eq!(d.try_day_of_month_week(), None);
+    }
+
+    #[test]
+    fn long_date() {
+        let d_list = [
+            (
+                CommonDate::new(2025, 7, 16),
+                "Monday Sol 16, 2025 Cotsworth Era",
+            ),
+            (
+                CommonDate::new(2025, 13, 29),
+                "December 29, 2025 Cotsworth Era",
+            ),
+            (CommonDate::new(2024, 6, 29), "June 29, 2024 Cotsworth Era"),
+        ];
+        for item in d_list {
+            let d = Cotsworth::try_from_common_date(item.0).unwrap();
+            assert_eq!(d.long_date(), item.1);
+            assert_eq!(d.to_string(), item.1);
+        }
+    }
 }
@@ -0,0 +1,44 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::display::text::prelude::Language;
+use core::fmt;
+
+/// Localized names for positions in a repeating day cycle
+///
+/// ```
+/// use radnelac::day_cycle::*;
+/// use radnelac::display::*;
+///
+/// assert_eq!(Weekday::Friday.cycle_name(Language::FR).unwrap(), "Vendredi");
+/// let a = Akan::new(AkanPrefix::Nwona, AkanStem::Kwasi);
+/// assert_eq!(a.cycle_name(Language::EN).unwrap(), "Nwona-Kwasi");
+/// ```
+///
+/// ## Crate Features
+///
+/// This is only available if `display` is enabled.
+pub trait CycleDisplay {
+    /// Checks if language is supported
+    fn supported_cycle_lang(lang: Language) -> bool;
+
+    /// Write the name of this day into a buffer
+    ///
+    /// Nothing is written if the language is not supported.
+    fn fmt_cycle_name(&self, w: &mut impl fmt::Write, lang: Language) -> fmt::Result;
+
+    /// Name of this day in a specific language
+    ///
+    /// Returns [`None`] if the language is not supported.
+    fn cycle_name(&self, lang: Language) -> Option<String> {
+        if Self::supported_cycle_lang(lang) {
+            let mut result = String::new();
+            self.fmt_cycle_name(&mut result, lang)
+                .expect("Writing to String does not fail");
+            Some(result)
+        } else {
+            None
+        }
+    }
+}
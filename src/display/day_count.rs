@@ -0,0 +1,119 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::common::error::CalendarError;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::JulianDay;
+use crate::day_count::ModifiedJulianDay;
+use crate::day_count::RataDie;
+use crate::day_count::UnixMoment;
+use core::fmt;
+use core::str::FromStr;
+
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = s.get(..prefix.len())?;
+    if head.eq_ignore_ascii_case(prefix) {
+        s.get(prefix.len()..)
+    } else {
+        None
+    }
+}
+
+macro_rules! day_count_text {
+    ($t:ty, $prefix:literal) => {
+        /// Formats the day count with a prefix
+        ///
+        /// The width, precision and sign flags apply to the number.
+        impl fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{} ", $prefix)?;
+                fmt::Display::fmt(&self.get(), f)
+            }
+        }
+
+        /// Parses a day count, with or without a prefix
+        ///
+        /// The prefix is case insensitive.
+        impl FromStr for $t {
+            type Err = CalendarError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let s = s.trim();
+                let rest = strip_prefix_ignore_case(s, $prefix).unwrap_or(s);
+                let t = rest
+                    .trim()
+                    .parse()
+                    .map_err(|_| CalendarError::InvalidDayCount)?;
+                Self::in_effective_bounds(t)?;
+                Ok(Self::new(t))
+            }
+        }
+    };
+}
+
+day_count_text!(JulianDay, "JD");
+day_count_text!(ModifiedJulianDay, "MJD");
+day_count_text!(RataDie, "RD");
+day_count_text!(UnixMoment, "Unix");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_count::Fixed;
+    use crate::day_count::FromFixed;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use proptest::proptest;
+
+    #[test]
+    fn examples() {
+        let jd = JulianDay::new(2460882.5);
+        assert_eq!(jd.to_string(), "JD 2460882.5");
+        assert_eq!(format!("{:.2}", jd), "JD 2460882.50");
+        assert_eq!("JD 2460882.5".parse::<JulianDay>().unwrap(), jd);
+        assert_eq!("jd2460882.5".parse::<JulianDay>().unwrap(), jd);
+        assert_eq!(" 2460882.5 ".parse::<JulianDay>().unwrap(), jd);
+        assert_eq!(UnixMoment::new(-1).to_string(), "Unix -1");
+        assert_eq!(RataDie::new(1.0).to_string(), "RD 1");
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            "MJD 1".parse::<JulianDay>(),
+            Err(CalendarError::InvalidDayCount)
+        ));
+        assert!(matches!(
+            "JD".parse::<JulianDay>(),
+            Err(CalendarError::InvalidDayCount)
+        ));
+        assert!(matches!(
+            "JD NaN".parse::<JulianDay>(),
+            Err(CalendarError::EncounteredNaN)
+        ));
+        assert!(matches!(
+            "RD 1e300".parse::<RataDie>(),
+            Err(CalendarError::OutOfBounds)
+        ));
+        assert!(matches!(
+            "Unix 1.5".parse::<UnixMoment>(),
+            Err(CalendarError::InvalidDayCount)
+        ));
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrip(t in FIXED_MIN..FIXED_MAX) {
+            let f = Fixed::new(t);
+            let jd = JulianDay::from_fixed(f);
+            assert_eq!(jd.to_string().parse::<JulianDay>().unwrap(), jd);
+            let mjd = ModifiedJulianDay::from_fixed(f);
+            assert_eq!(mjd.to_string().parse::<ModifiedJulianDay>().unwrap(), mjd);
+            let rd = RataDie::from_fixed(f);
+            assert_eq!(rd.to_string().parse::<RataDie>().unwrap(), rd);
+            let u = UnixMoment::from_fixed(f);
+            assert_eq!(u.to_string().parse::<UnixMoment>().unwrap(), u);
+        }
+    }
+}
@@ -0,0 +1,208 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::Gregorian;
+use crate::calendar::Julian;
+use crate::calendar::ToFromCommonDate;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use crate::display::private::get_dict;
+use crate::display::private::get_table;
+use crate::display::private::Content;
+use crate::display::private::DisplayItem;
+use crate::display::private::DisplayOptions;
+use crate::display::private::Item;
+use crate::display::private::NumericContent;
+use crate::display::private::Sign;
+use crate::display::private::TextContent;
+use crate::display::text::prelude::Language;
+use core::fmt;
+
+const O_PLAIN: DisplayOptions = DisplayOptions {
+    numerals: None,
+    width: None,
+    align: None,
+    padding: None,
+    case: None,
+    sign: Sign::OnlyNegative,
+};
+
+/// Represents a day with both an Old Style and a New Style date
+///
+/// ## Introduction
+///
+/// During the centuries in which the Gregorian reform was adopted, many documents were
+/// written with both the Julian (Old Style) and Gregorian (New Style) date. Historians and
+/// genealogists often still write dates from this period this way, to avoid ambiguity.
+///
+/// `DualDate` formats a single day with both dates, sharing whatever the two dates have in
+/// common. Whichever parts of the date differ are separated by a slash, with the Julian
+/// part first.
+///
+/// Note that this crate uses a Julian calendar in which the year always starts on January 1.
+/// Historical documents using a year starting on March 25 may show a different Old Style
+/// year for dates in January, February and March.
+///
+/// ## Representation and Examples
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+/// use radnelac::display::*;
+///
+/// let g = Gregorian::try_new(1732, GregorianMonth::February, 22).unwrap();
+/// let d = g.convert::<DualDate>();
+/// assert_eq!(d.to_string(), "11/22 February 1732 (O.S./N.S.)");
+///
+/// let g = Gregorian::try_new(1752, GregorianMonth::September, 14).unwrap();
+/// let d = g.convert::<DualDate>();
+/// assert_eq!(d.to_string(), "3/14 September 1752 (O.S./N.S.)");
+///
+/// let g = Gregorian::try_new(1700, GregorianMonth::January, 5).unwrap();
+/// let d = g.convert::<DualDate>();
+/// assert_eq!(d.to_string(), "26 December 1699/5 January 1700 (O.S./N.S.)");
+/// ```
+///
+/// ## Crate Features
+///
+/// This is only available if `display` is enabled.
+///
+/// ## Further reading
+/// + [Wikipedia](https://en.wikipedia.org/wiki/Old_Style_and_New_Style_dates)
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct DualDate {
+    julian: Julian,
+    gregorian: Gregorian,
+}
+
+impl DualDate {
+    /// Returns the Old Style date
+    pub fn julian(self) -> Julian {
+        self.julian
+    }
+
+    /// Returns the New Style date
+    pub fn gregorian(self) -> Gregorian {
+        self.gregorian
+    }
+
+    /// Checks if language is supported
+    pub fn supported_lang(lang: Language) -> bool {
+        Julian::supported_lang(lang)
+            && Gregorian::supported_lang(lang)
+            && get_dict(lang).julian.as_ref().is_some()
+    }
+
+    /// Write the day with both dates in a specific language into a buffer
+    pub fn fmt_dual_into(&self, w: &mut impl fmt::Write, lang: Language) -> fmt::Result {
+        let j = self.julian.to_common_date();
+        let g = self.gregorian.to_common_date();
+        let day = Item::new(Content::Numeric(NumericContent::DayOfMonth), O_PLAIN);
+        let month = Item::new(Content::Text(TextContent::MonthName), O_PLAIN);
+        let year = Item::new(Content::Numeric(NumericContent::Year), O_PLAIN);
+        let space = Item::new(Content::Literal(" "), O_PLAIN);
+        let slash = Item::new(Content::Literal("/"), O_PLAIN);
+        let julian: &[Item] = if j == g {
+            &[]
+        } else if j.year != g.year {
+            &[day, space, month, space, year, slash]
+        } else if j.month != g.month {
+            &[day, space, month, slash]
+        } else {
+            &[day, slash]
+        };
+        for item in julian {
+            self.julian.fmt_item_into(w, lang, *item)?;
+        }
+        for item in [day, space, month, space, year] {
+            self.gregorian.fmt_item_into(w, lang, item)?;
+        }
+        match get_table(lang, |d| d.julian.as_ref()) {
+            Some(dict) => write!(w, " ({}/{})", dict.old_style_abr, dict.new_style_abr),
+            None => Ok(()),
+        }
+    }
+
+    /// Format the day with both dates in a specific language
+    pub fn dual_str(&self, lang: Language) -> String {
+        let mut result = String::new();
+        self.fmt_dual_into(&mut result, lang)
+            .expect("Writing to String does not fail");
+        result
+    }
+}
+
+impl FromFixed for DualDate {
+    fn from_fixed(t: Fixed) -> DualDate {
+        DualDate {
+            julian: Julian::from_fixed(t),
+            gregorian: Gregorian::from_fixed(t),
+        }
+    }
+}
+
+impl ToFixed for DualDate {
+    fn to_fixed(self) -> Fixed {
+        self.gregorian.to_fixed()
+    }
+}
+
+impl fmt::Display for DualDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_dual_into(f, Language::EN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::GregorianMonth;
+    use crate::calendar::GuaranteedMonth;
+    use crate::day_count::BoundedDayCount;
+    use crate::day_count::FIXED_MAX;
+    use proptest::proptest;
+
+    #[test]
+    fn expected_languages() {
+        assert!(DualDate::supported_lang(Language::EN));
+        assert!(DualDate::supported_lang(Language::FR));
+    }
+
+    #[test]
+    fn washington_birthday() {
+        let g = Gregorian::try_new(1732, GregorianMonth::February, 22).unwrap();
+        let d = g.convert::<DualDate>();
+        assert_eq!(d.to_string(), "11/22 February 1732 (O.S./N.S.)");
+        assert_eq!(
+            d.dual_str(Language::FR),
+            "11/22 février 1732 (v. st./n. st.)"
+        );
+    }
+
+    #[test]
+    fn month_boundary() {
+        let g = Gregorian::try_new(1752, GregorianMonth::September, 1).unwrap();
+        let d = g.convert::<DualDate>();
+        assert_eq!(d.to_string(), "21 August/1 September 1752 (O.S./N.S.)");
+    }
+
+    #[test]
+    fn same_date() {
+        let g = Gregorian::try_new(200, GregorianMonth::March, 1).unwrap();
+        let d = g.convert::<DualDate>();
+        assert_eq!(d.to_string(), "1 March 200 (O.S./N.S.)");
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrip(t in -FIXED_MAX..FIXED_MAX) {
+            let f = Fixed::new(t).to_day();
+            let d = DualDate::from_fixed(f);
+            assert_eq!(d.to_fixed(), f);
+            assert_eq!(d.julian().to_fixed(), d.gregorian().to_fixed());
+            assert!(d.to_string().ends_with(" (O.S./N.S.)"));
+        }
+    }
+}
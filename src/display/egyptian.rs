@@ -12,18 +12,22 @@ use crate::day_count::ToFixed;
 use crate::day_cycle::Weekday;
 use crate::display::moment::DisplayMomentItem;
 use crate::display::prelude::PresetDisplay;
-use crate::display::private::fmt_days_since_epoch;
-use crate::display::private::fmt_number;
-use crate::display::private::fmt_quarter;
-use crate::display::private::fmt_seconds_since_epoch;
-use crate::display::private::fmt_string;
+use crate::display::prelude::PresetFormat;
 use crate::display::private::get_dict;
+use crate::display::private::get_table;
+use crate::display::private::write_days_since_epoch;
+use crate::display::private::write_half_year;
+use crate::display::private::write_number;
+use crate::display::private::write_quarter;
+use crate::display::private::write_seconds_since_epoch;
+use crate::display::private::write_string;
+use crate::display::private::write_week_of_month_name;
 use crate::display::private::DisplayItem;
 use crate::display::private::NumericContent;
 use crate::display::text::prelude::Language;
 use crate::display::LONG_COMPL;
 use crate::display::LONG_DATE;
-use std::fmt;
+use core::fmt;
 
 use crate::display::private::TextContent;
 
@@ -34,29 +38,43 @@ impl DisplayItem for Egyptian {
         get_dict(lang).egyptian.as_ref().is_some()
     }
 
-    fn fmt_numeric(&self, n: NumericContent, opt: DisplayOptions) -> String {
+    fn fmt_numeric(
+        &self,
+        w: &mut impl fmt::Write,
+        n: NumericContent,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
         match n {
             NumericContent::Month | NumericContent::DayOfMonth | NumericContent::Year => {
-                self.to_common_date().fmt_numeric(n, opt)
+                self.to_common_date().fmt_numeric(w, n, opt)
             }
-            NumericContent::DayOfWeek => self.convert::<Weekday>().fmt_numeric(n, opt),
-            NumericContent::DayOfYear => self.to_ordinal().fmt_numeric(n, opt),
+            NumericContent::DayOfWeek => self.convert::<Weekday>().fmt_numeric(w, n, opt),
+            NumericContent::DayOfYear => self.to_ordinal().fmt_numeric(w, n, opt),
             NumericContent::Hour1to12
             | NumericContent::Hour0to23
             | NumericContent::Minute
-            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(n, opt),
-            NumericContent::SecondsSinceEpoch => fmt_seconds_since_epoch(*self, opt),
-            NumericContent::Quarter => fmt_quarter(*self, opt),
-            NumericContent::DaysSinceEpoch => fmt_days_since_epoch(*self, opt),
+            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(w, n, opt),
+            NumericContent::SecondsSinceEpoch => write_seconds_since_epoch(w, *self, opt),
+            NumericContent::Quarter => write_quarter(w, *self, opt),
+            NumericContent::HalfYear => write_half_year(w, *self, opt),
+            NumericContent::DaysSinceEpoch => write_days_since_epoch(w, *self, opt),
             NumericContent::ComplementaryDay => match self.epagomenae() {
-                Some(d) => fmt_number(d as i16, opt),
-                None => "".to_string(),
+                Some(d) => write_number(w, d as i16, opt),
+                None => Ok(()),
             },
-            NumericContent::WeekOfYear => fmt_number(self.week_of_year() as i16, opt),
+            NumericContent::WeekOfYear => write_number(w, self.week_of_year() as i16, opt),
+            NumericContent::WeekOfMonth => write_number(w, self.week_of_month() as i16, opt),
+            NumericContent::DayOfMonthWeek => write_number(w, self.day_of_month_week() as i16, opt),
         }
     }
-    fn fmt_text(&self, t: TextContent, lang: Language, opt: DisplayOptions) -> String {
-        match (t, get_dict(lang).egyptian.as_ref()) {
+    fn fmt_text(
+        &self,
+        w: &mut impl fmt::Write,
+        t: TextContent,
+        lang: Language,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
+        match (t, get_table(lang, |d| d.egyptian.as_ref())) {
             (TextContent::MonthName, Some(dict)) => {
                 let months: [&str; 13] = [
                     dict.thoth,
@@ -75,25 +93,26 @@ impl DisplayItem for Egyptian {
                 ];
                 let m = self.to_common_date().month;
                 let name = months[m as usize - 1];
-                fmt_string(name, opt)
-            }
-            (TextContent::DayOfMonthName, _) => fmt_string("", opt),
-            (TextContent::DayOfWeekName, _) => self.convert::<Weekday>().fmt_text(t, lang, opt),
-            (TextContent::HalfDayName | TextContent::HalfDayAbbrev, _) => {
-                self.convert::<TimeOfDay>().fmt_text(t, lang, opt)
+                write_string(w, name, opt)
             }
+            (TextContent::DayOfMonthName, _) => write_string(w, "", opt),
+            (TextContent::DayOfWeekName, _) => self.convert::<Weekday>().fmt_text(w, t, lang, opt),
+            (
+                TextContent::HalfDayName | TextContent::HalfDayAbbrev | TextContent::PartOfDayName,
+                _,
+            ) => self.convert::<TimeOfDay>().fmt_text(w, t, lang, opt),
             (TextContent::EraName, Some(dict)) => {
                 if self.to_common_date().year < 0 {
-                    fmt_string(dict.before_nabonassar_full, opt)
+                    write_string(w, dict.before_nabonassar_full, opt)
                 } else {
-                    fmt_string(dict.after_nabonassar_full, opt)
+                    write_string(w, dict.after_nabonassar_full, opt)
                 }
             }
             (TextContent::EraAbbreviation, Some(dict)) => {
                 if self.to_common_date().year < 0 {
-                    fmt_string(dict.before_nabonassar_abr, opt)
+                    write_string(w, dict.before_nabonassar_abr, opt)
                 } else {
-                    fmt_string(dict.after_nabonassar_abr, opt)
+                    write_string(w, dict.after_nabonassar_abr, opt)
                 }
             }
             (TextContent::ComplementaryDayName, Some(dict)) => {
@@ -106,28 +125,30 @@ impl DisplayItem for Egyptian {
                     dict.birth_of_nephthys,
                 ];
                 match self.epagomenae() {
-                    Some(d) => fmt_string(days[d as usize - 1], opt),
-                    None => fmt_string("", opt),
+                    Some(d) => write_string(w, days[d as usize - 1], opt),
+                    None => write_string(w, "", opt),
                 }
             }
-            (_, _) => fmt_string("", opt),
+            (TextContent::WeekOfMonthName, _) => {
+                write_week_of_month_name(w, Some(self.week_of_month()), lang, opt)
+            }
+            (_, _) => write_string(w, "", opt),
         }
     }
 }
 
 impl PresetDisplay for Egyptian {
-    fn long_date(&self) -> String {
-        let p = match self.epagomenae() {
+    fn long_date_preset(&self) -> PresetFormat<'static> {
+        match self.epagomenae() {
             None => LONG_DATE,
             Some(_) => LONG_COMPL,
-        };
-        self.preset_str(Language::EN, p)
+        }
     }
 }
 
 impl fmt::Display for Egyptian {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.long_date())
+        self.fmt_into(f, Language::EN, self.long_date_preset())
     }
 }
 
@@ -140,5 +161,6 @@ mod tests {
     #[test]
     fn expected_languages() {
         assert!(Egyptian::supported_lang(Language::EN));
+        assert!(Egyptian::supported_lang(Language::FR));
     }
 }
@@ -0,0 +1,355 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::Armenian;
+use crate::calendar::Bengali;
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::Coptic;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::Cotsworth;
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::Egyptian;
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::Ethiopic;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::FrenchRevArith;
+use crate::calendar::Gregorian;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::Holocene;
+use crate::calendar::IslamicArith;
+use crate::calendar::Julian;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::Positivist;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::Symmetry;
+use crate::calendar::Tamil;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::Tranquility;
+use crate::calendar::YearNumbering;
+use crate::calendar::YearZeroPolicy;
+use crate::common::error::CalendarError;
+use crate::display::private::get_dict;
+use crate::display::private::LANGUAGES;
+use crate::display::text::prelude::Language;
+use core::cmp::Reverse;
+
+/// Full and abbreviated names of the eras before and after an epoch
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct EraNames {
+    pub before_full: &'static str,
+    pub before_abr: &'static str,
+    pub after_full: &'static str,
+    pub after_abr: &'static str,
+}
+
+/// Calendars with era names in one or more languages
+///
+/// The names are the same as those used when formatting dates. Parsing ignores case, and
+/// accepts the era name either before or after the year.
+///
+/// A year before the epoch is parsed as a negative number, which is the same numbering used
+/// when formatting dates. Year 0 is rejected in calendars which skip year 0, as described by
+/// [`YearNumbering::YEAR_ZERO`].
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::display::*;
+///
+/// assert_eq!(Julian::try_year_from_any_era_str("44 BC").unwrap(), -44);
+/// assert_eq!(Julian::try_year_from_any_era_str("AD 1066").unwrap(), 1066);
+/// assert_eq!(Julian::try_year_from_any_era_str("44 av. J.-C.").unwrap(), -44);
+/// assert!(Julian::try_year_from_any_era_str("0 AD").is_err());
+/// assert_eq!(Gregorian::year_from_era_str("2025 CE", Language::EN).unwrap(), 2025);
+/// assert_eq!(IslamicArith::try_year_from_any_era_str("AH 1446").unwrap(), 1446);
+/// ```
+/// ## Crate Features
+///
+/// This is only available if `display` is enabled.
+pub trait EraYear: YearNumbering {
+    /// Names of the eras, or [`None`] if the language is not supported
+    fn era_names(lang: Language) -> Option<EraNames>;
+
+    /// Parse a year with an optional era name in a specific language
+    fn year_from_era_str(s: &str, lang: Language) -> Result<i32, CalendarError> {
+        let s = s.trim().to_lowercase();
+        if let Ok(year) = s.parse::<i32>() {
+            return check_year_zero(year, Self::YEAR_ZERO);
+        }
+        let names = Self::era_names(lang).ok_or(CalendarError::InvalidYear)?;
+        let mut candidates = [
+            (names.before_full, true),
+            (names.before_abr, true),
+            (names.after_full, false),
+            (names.after_abr, false),
+        ];
+        //Try longer names first so that "BCE" is not mistaken for "CE"
+        candidates.sort_by_key(|(name, _)| Reverse(name.len()));
+        for (name, before) in candidates {
+            let name = name.to_lowercase();
+            if name.is_empty() {
+                continue;
+            }
+            let rest = s.strip_prefix(&name).or_else(|| s.strip_suffix(&name));
+            if let Some(Ok(n)) = rest.map(|r| r.trim().parse::<u32>()) {
+                let n = i32::try_from(n).map_err(|_| CalendarError::OutOfBounds)?;
+                let year = if before { -n } else { n };
+                return check_year_zero(year, Self::YEAR_ZERO);
+            }
+        }
+        Err(CalendarError::InvalidYear)
+    }
+
+    /// Parse a year with an optional era name in any supported language
+    fn try_year_from_any_era_str(s: &str) -> Result<i32, CalendarError> {
+        LANGUAGES
+            .iter()
+            .find_map(|lang| Self::year_from_era_str(s, *lang).ok())
+            .ok_or(CalendarError::InvalidYear)
+    }
+}
+
+fn check_year_zero(year: i32, policy: YearZeroPolicy) -> Result<i32, CalendarError> {
+    if year == 0 && policy == YearZeroPolicy::Skipped {
+        Err(CalendarError::InvalidYear)
+    } else {
+        Ok(year)
+    }
+}
+
+macro_rules! era_year {
+    ([$($g:tt)*] $t:ty, $dict:ident, [$bf:ident, $ba:ident, $af:ident, $aa:ident]) => {
+        impl<$($g)*> EraYear for $t {
+            fn era_names(lang: Language) -> Option<EraNames> {
+                let dict = get_dict(lang).$dict.as_ref()?;
+                Some(EraNames {
+                    before_full: dict.$bf,
+                    before_abr: dict.$ba,
+                    after_full: dict.$af,
+                    after_abr: dict.$aa,
+                })
+            }
+        }
+    };
+    ($t:ty, $dict:ident, [$bf:ident, $ba:ident, $af:ident, $aa:ident]) => {
+        era_year!([] $t, $dict, [$bf, $ba, $af, $aa]);
+    };
+}
+
+era_year!(
+    Gregorian,
+    gregorian,
+    [
+        before_common_era_full,
+        before_common_era_abr,
+        common_era_full,
+        common_era_abr
+    ]
+);
+era_year!(
+    Julian,
+    julian,
+    [
+        before_christ_full,
+        before_christ_abr,
+        anno_domini_full,
+        anno_domini_abr
+    ]
+);
+era_year!(
+    Bengali,
+    bengali,
+    [
+        before_epoch_full,
+        before_epoch_abr,
+        after_epoch_full,
+        after_epoch_abr
+    ]
+);
+era_year!(
+    Tamil,
+    tamil,
+    [
+        before_epoch_full,
+        before_epoch_abr,
+        after_epoch_full,
+        after_epoch_abr
+    ]
+);
+#[cfg(feature = "ancient-calendars")]
+era_year!(
+    Armenian,
+    armenian,
+    [
+        before_epoch_full,
+        before_epoch_abr,
+        after_epoch_full,
+        after_epoch_abr
+    ]
+);
+#[cfg(feature = "ancient-calendars")]
+era_year!(
+    Coptic,
+    coptic,
+    [
+        before_martyrs_full,
+        before_martyrs_abr,
+        after_martyrs_full,
+        after_martyrs_abr
+    ]
+);
+#[cfg(feature = "ancient-calendars")]
+era_year!(
+    Egyptian,
+    egyptian,
+    [
+        before_nabonassar_full,
+        before_nabonassar_abr,
+        after_nabonassar_full,
+        after_nabonassar_abr
+    ]
+);
+#[cfg(feature = "ancient-calendars")]
+era_year!(
+    Ethiopic,
+    ethiopic,
+    [
+        before_incarnation_full,
+        before_incarnation_abr,
+        after_incarnation_full,
+        after_incarnation_abr
+    ]
+);
+#[cfg(feature = "reform-calendars")]
+era_year!(
+    Cotsworth,
+    cotsworth,
+    [
+        before_epoch_full,
+        before_epoch_abr,
+        after_epoch_full,
+        after_epoch_abr
+    ]
+);
+#[cfg(feature = "reform-calendars")]
+era_year!(
+    [const L: bool] FrenchRevArith<L>,
+    french_rev,
+    [
+        before_republic_full,
+        before_republic_abr,
+        after_republic_full,
+        after_republic_abr
+    ]
+);
+#[cfg(feature = "reform-calendars")]
+era_year!(
+    Holocene,
+    holocene,
+    [
+        before_human_era_full,
+        before_human_era_abr,
+        human_era_full,
+        human_era_abr
+    ]
+);
+#[cfg(feature = "reform-calendars")]
+era_year!(
+    Positivist,
+    positivist,
+    [
+        before_crisis_full,
+        before_crisis_abr,
+        after_crisis_full,
+        after_crisis_abr
+    ]
+);
+#[cfg(feature = "reform-calendars")]
+era_year!(
+    [const T: bool, const U: bool] Symmetry<T, U>,
+    symmetry,
+    [
+        before_epoch_full,
+        before_epoch_abr,
+        after_epoch_full,
+        after_epoch_abr
+    ]
+);
+#[cfg(feature = "reform-calendars")]
+era_year!(
+    Tranquility,
+    tranquility,
+    [
+        before_tranquility_full,
+        before_tranquility_abr,
+        after_tranquility_full,
+        after_tranquility_abr
+    ]
+);
+
+impl EraYear for IslamicArith {
+    fn era_names(lang: Language) -> Option<EraNames> {
+        match lang {
+            Language::EN => Some(EraNames {
+                before_full: "Before Hijra",
+                before_abr: "BH",
+                after_full: IslamicArith::ERA_NAME,
+                after_abr: "AH",
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn longest_name_first() {
+        assert_eq!(Gregorian::try_year_from_any_era_str("44 BCE").unwrap(), -44);
+        assert_eq!(Gregorian::try_year_from_any_era_str("44 CE").unwrap(), 44);
+        assert_eq!(
+            Gregorian::try_year_from_any_era_str("44 before common era").unwrap(),
+            -44
+        );
+    }
+
+    #[test]
+    fn prefix_and_suffix() {
+        assert_eq!(Julian::try_year_from_any_era_str("  ad 44 ").unwrap(), 44);
+        assert_eq!(Julian::try_year_from_any_era_str("44 AD").unwrap(), 44);
+        assert_eq!(Julian::try_year_from_any_era_str("-44").unwrap(), -44);
+    }
+
+    #[test]
+    fn year_zero() {
+        assert!(Julian::try_year_from_any_era_str("0").is_err());
+        assert!(Julian::try_year_from_any_era_str("0 BC").is_err());
+        assert_eq!(Gregorian::try_year_from_any_era_str("0 CE").unwrap(), 0);
+    }
+
+    #[test]
+    fn invalid() {
+        for s in ["", "BC", "44 XY", "44 BC BC", "BC -44", "99999999999 BC"] {
+            assert!(matches!(
+                Julian::try_year_from_any_era_str(s),
+                Err(CalendarError::InvalidYear)
+            ));
+        }
+        assert!(matches!(
+            Julian::year_from_era_str("4000000000 BC", Language::EN),
+            Err(CalendarError::OutOfBounds)
+        ));
+    }
+
+    #[test]
+    fn unsupported_language() {
+        assert!(IslamicArith::year_from_era_str("1446 AH", Language::FR).is_err());
+        assert_eq!(
+            IslamicArith::year_from_era_str("1446", Language::FR).unwrap(),
+            1446
+        );
+    }
+}
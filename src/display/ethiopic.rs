@@ -11,18 +11,21 @@ use crate::day_count::ToFixed;
 use crate::day_cycle::Weekday;
 use crate::display::moment::DisplayMomentItem;
 use crate::display::prelude::PresetDisplay;
-use crate::display::private::fmt_days_since_epoch;
-use crate::display::private::fmt_number;
-use crate::display::private::fmt_quarter;
-use crate::display::private::fmt_seconds_since_epoch;
-use crate::display::private::fmt_string;
 use crate::display::private::get_dict;
+use crate::display::private::get_table;
+use crate::display::private::write_days_since_epoch;
+use crate::display::private::write_half_year;
+use crate::display::private::write_number;
+use crate::display::private::write_quarter;
+use crate::display::private::write_seconds_since_epoch;
+use crate::display::private::write_string;
+use crate::display::private::write_week_of_month_name;
 use crate::display::private::DisplayItem;
 use crate::display::private::DisplayOptions;
 use crate::display::private::NumericContent;
 use crate::display::private::TextContent;
 use crate::display::text::prelude::Language;
-use std::fmt;
+use core::fmt;
 //use crate::calendar::EthiopicMonth;
 
 impl DisplayItem for Ethiopic {
@@ -30,27 +33,41 @@ impl DisplayItem for Ethiopic {
         get_dict(lang).ethiopic.as_ref().is_some()
     }
 
-    fn fmt_numeric(&self, n: NumericContent, opt: DisplayOptions) -> String {
+    fn fmt_numeric(
+        &self,
+        w: &mut impl fmt::Write,
+        n: NumericContent,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
         match n {
             NumericContent::Month | NumericContent::DayOfMonth | NumericContent::Year => {
-                self.to_common_date().fmt_numeric(n, opt)
+                self.to_common_date().fmt_numeric(w, n, opt)
             }
-            NumericContent::DayOfWeek => self.convert::<Weekday>().fmt_numeric(n, opt),
-            NumericContent::DayOfYear => self.to_ordinal().fmt_numeric(n, opt),
+            NumericContent::DayOfWeek => self.convert::<Weekday>().fmt_numeric(w, n, opt),
+            NumericContent::DayOfYear => self.to_ordinal().fmt_numeric(w, n, opt),
             NumericContent::Hour1to12
             | NumericContent::Hour0to23
             | NumericContent::Minute
-            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(n, opt),
-            NumericContent::SecondsSinceEpoch => fmt_seconds_since_epoch(*self, opt),
-            NumericContent::Quarter => fmt_quarter(*self, opt),
-            NumericContent::DaysSinceEpoch => fmt_days_since_epoch(*self, opt),
-            NumericContent::ComplementaryDay => String::from(""),
-            NumericContent::WeekOfYear => fmt_number(self.week_of_year() as i16, opt),
+            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(w, n, opt),
+            NumericContent::SecondsSinceEpoch => write_seconds_since_epoch(w, *self, opt),
+            NumericContent::Quarter => write_quarter(w, *self, opt),
+            NumericContent::HalfYear => write_half_year(w, *self, opt),
+            NumericContent::DaysSinceEpoch => write_days_since_epoch(w, *self, opt),
+            NumericContent::ComplementaryDay => Ok(()),
+            NumericContent::WeekOfYear => write_number(w, self.week_of_year() as i16, opt),
+            NumericContent::WeekOfMonth => write_number(w, self.week_of_month() as i16, opt),
+            NumericContent::DayOfMonthWeek => write_number(w, self.day_of_month_week() as i16, opt),
         }
     }
 
-    fn fmt_text(&self, t: TextContent, lang: Language, opt: DisplayOptions) -> String {
-        match (t, get_dict(lang).ethiopic.as_ref()) {
+    fn fmt_text(
+        &self,
+        w: &mut impl fmt::Write,
+        t: TextContent,
+        lang: Language,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
+        match (t, get_table(lang, |d| d.ethiopic.as_ref())) {
             (TextContent::MonthName, Some(dict)) => {
                 let months: [&str; 13] = [
                     dict.maskaram,
@@ -68,28 +85,32 @@ impl DisplayItem for Ethiopic {
                     dict.paguemen,
                 ];
                 let name = months[self.to_common_date().month as usize - 1];
-                fmt_string(name, opt)
-            }
-            (TextContent::DayOfMonthName, _) => fmt_string("", opt),
-            (TextContent::DayOfWeekName, _) => self.convert::<Weekday>().fmt_text(t, lang, opt),
-            (TextContent::HalfDayName | TextContent::HalfDayAbbrev, _) => {
-                self.convert::<TimeOfDay>().fmt_text(t, lang, opt)
+                write_string(w, name, opt)
             }
+            (TextContent::DayOfMonthName, _) => write_string(w, "", opt),
+            (TextContent::DayOfWeekName, _) => self.convert::<Weekday>().fmt_text(w, t, lang, opt),
+            (
+                TextContent::HalfDayName | TextContent::HalfDayAbbrev | TextContent::PartOfDayName,
+                _,
+            ) => self.convert::<TimeOfDay>().fmt_text(w, t, lang, opt),
             (TextContent::EraName, Some(dict)) => {
                 if self.to_common_date().year < 0 {
-                    fmt_string(dict.before_incarnation_full, opt)
+                    write_string(w, dict.before_incarnation_full, opt)
                 } else {
-                    fmt_string(dict.after_incarnation_full, opt)
+                    write_string(w, dict.after_incarnation_full, opt)
                 }
             }
             (TextContent::EraAbbreviation, Some(dict)) => {
                 if self.to_common_date().year < 0 {
-                    fmt_string(dict.before_incarnation_abr, opt)
+                    write_string(w, dict.before_incarnation_abr, opt)
                 } else {
-                    fmt_string(dict.after_incarnation_abr, opt)
+                    write_string(w, dict.after_incarnation_abr, opt)
                 }
             }
-            (_, _) => String::from(""),
+            (TextContent::WeekOfMonthName, _) => {
+                write_week_of_month_name(w, Some(self.week_of_month()), lang, opt)
+            }
+            (_, _) => Ok(()),
         }
     }
 }
@@ -98,7 +119,7 @@ impl PresetDisplay for Ethiopic {}
 
 impl fmt::Display for Ethiopic {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.long_date())
+        self.fmt_into(f, Language::EN, self.long_date_preset())
     }
 }
 
@@ -111,5 +132,6 @@ mod tests {
     #[test]
     fn expected_languages() {
         assert!(Ethiopic::supported_lang(Language::EN));
+        assert!(Ethiopic::supported_lang(Language::FR));
     }
 }
@@ -0,0 +1,110 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::day_count::Event;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::display::prelude::PresetDisplay;
+use crate::display::text::prelude::Language;
+
+/// Formats a day in a specific calendar
+///
+/// Returns [`None`] if the language is not supported by the calendar.
+///
+/// ## Crate Features
+///
+/// This is only available if `display` is enabled.
+pub type EventRenderer = fn(Fixed, Language) -> Option<String>;
+
+/// Formats a day as a long date in a specific calendar
+///
+/// This can be used as an [`EventRenderer`].
+///
+/// ## Crate Features
+///
+/// This is only available if `display` is enabled.
+pub fn render_long_date<T: FromFixed + PresetDisplay>(t: Fixed, lang: Language) -> Option<String> {
+    if !T::supported_display_lang(lang) {
+        return None;
+    }
+    let d = T::from_fixed(t);
+    Some(d.preset_str(lang, d.long_date_preset()))
+}
+
+impl Event {
+    /// Format the days of the event in several calendars at once
+    ///
+    /// Each entry of `calendars` is a label and a renderer. The result has the label and
+    /// the formatted days for each calendar which supports `lang`. An event lasting more than
+    /// one day is written as the first and last days, separated by an en dash.
+    ///
+    /// ```
+    /// use radnelac::calendar::*;
+    /// use radnelac::day_count::*;
+    /// use radnelac::display::*;
+    ///
+    /// let t = Gregorian::try_new(1969, GregorianMonth::July, 20).unwrap().to_fixed();
+    /// let e = Event::at("Moon landing", t);
+    /// let calendars: [(&str, EventRenderer); 2] = [
+    ///     ("Gregorian", render_long_date::<Gregorian>),
+    ///     ("Julian", render_long_date::<Julian>),
+    /// ];
+    /// let r = e.render_in(Language::EN, &calendars);
+    /// assert_eq!(r[0], ("Gregorian", "Sunday July 20, 1969 Common Era".to_string()));
+    /// assert_eq!(r[1].0, "Julian");
+    /// ```
+    ///
+    /// ## Crate Features
+    ///
+    /// This is only available if `display` is enabled.
+    pub fn render_in<'a>(
+        &self,
+        lang: Language,
+        calendars: &[(&'a str, EventRenderer)],
+    ) -> Vec<(&'a str, String)> {
+        let first = self.first_day();
+        let last = self.last_day();
+        calendars
+            .iter()
+            .filter_map(|(label, render)| {
+                let s0 = render(first, lang)?;
+                if first == last {
+                    Some((*label, s0))
+                } else {
+                    let s1 = render(last, lang)?;
+                    Some((*label, format!("{} – {}", s0, s1)))
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Gregorian;
+    use crate::calendar::ISO;
+    use crate::day_count::BoundedDayCount;
+    use crate::day_count::FixedInterval;
+    use crate::display::YYYYMMDD_DASH;
+
+    fn numeric(t: Fixed, lang: Language) -> Option<String> {
+        Some(Gregorian::from_fixed(t).preset_str(lang, YYYYMMDD_DASH))
+    }
+
+    #[test]
+    fn span() {
+        let a = Fixed::new(739372.25);
+        let b = Fixed::new(739374.0);
+        let e = Event::new("e", FixedInterval::half_open(a, b).unwrap());
+        let calendars: [(&str, EventRenderer); 2] =
+            [("numeric", numeric), ("iso", render_long_date::<ISO>)];
+        let r = e.render_in(Language::EN, &calendars);
+        assert_eq!(r[0], ("numeric", "2025-05-01 – 2025-05-02".to_string()));
+        assert_eq!(
+            r.len(),
+            ISO::supported_display_lang(Language::EN) as usize + 1
+        );
+    }
+}
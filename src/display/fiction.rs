@@ -0,0 +1,234 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::HasEpagemonae;
+use crate::calendar::ToFromCommonDate;
+use crate::calendar::ToFromOrdinalDate;
+use crate::clock::TimeOfDay;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::ToFixed;
+use crate::display::moment::DisplayMomentItem;
+use crate::display::prelude::PresetDisplay;
+use crate::display::prelude::PresetFormat;
+use crate::display::prelude::LONG_COMPL;
+use crate::display::prelude::LONG_DATE;
+use crate::display::private::get_dict;
+use crate::display::private::get_table;
+use crate::display::private::write_days_since_epoch;
+use crate::display::private::write_half_year;
+use crate::display::private::write_number;
+use crate::display::private::write_quarter;
+use crate::display::private::write_seconds_since_epoch;
+use crate::display::private::write_string;
+use crate::display::private::DisplayItem;
+use crate::display::private::DisplayOptions;
+use crate::display::private::NumericContent;
+use crate::display::private::TextContent;
+use crate::display::text::prelude::Language;
+use crate::fiction::LongNowYear;
+use crate::fiction::Shire;
+use crate::fiction::Stardate;
+use core::fmt;
+
+impl fmt::Display for Stardate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:.1}", self.get())
+    }
+}
+
+impl fmt::Display for LongNowYear {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:05}", self.get())
+    }
+}
+
+impl DisplayItem for Shire {
+    fn supported_lang(lang: Language) -> bool {
+        get_dict(lang).shire.as_ref().is_some()
+    }
+
+    fn fmt_numeric(
+        &self,
+        w: &mut impl fmt::Write,
+        n: NumericContent,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
+        match n {
+            NumericContent::Month | NumericContent::DayOfMonth | NumericContent::Year => {
+                self.to_common_date().fmt_numeric(w, n, opt)
+            }
+            NumericContent::DayOfWeek => match self.weekday() {
+                Some(d) => d.fmt_numeric(w, n, opt),
+                None => Ok(()),
+            },
+            NumericContent::DayOfYear => self.to_ordinal().fmt_numeric(w, n, opt),
+            NumericContent::Hour1to12
+            | NumericContent::Hour0to23
+            | NumericContent::Minute
+            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(w, n, opt),
+            NumericContent::SecondsSinceEpoch => write_seconds_since_epoch(w, *self, opt),
+            NumericContent::Quarter => write_quarter(w, *self, opt),
+            NumericContent::HalfYear => write_half_year(w, *self, opt),
+            NumericContent::DaysSinceEpoch => write_days_since_epoch(w, *self, opt),
+            NumericContent::ComplementaryDay => match self.epagomenae() {
+                Some(d) => write_number(w, d as i8, opt),
+                None => Ok(()),
+            },
+            NumericContent::WeekOfYear
+            | NumericContent::WeekOfMonth
+            | NumericContent::DayOfMonthWeek => Ok(()),
+        }
+    }
+
+    fn fmt_text(
+        &self,
+        w: &mut impl fmt::Write,
+        t: TextContent,
+        lang: Language,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
+        match (t, get_table(lang, |d| d.shire.as_ref())) {
+            (TextContent::MonthName, Some(dict)) => {
+                let months: [&str; 12] = [
+                    dict.afteryule,
+                    dict.solmath,
+                    dict.rethe,
+                    dict.astron,
+                    dict.thrimidge,
+                    dict.forelithe,
+                    dict.afterlithe,
+                    dict.wedmath,
+                    dict.halimath,
+                    dict.winterfilth,
+                    dict.blotmath,
+                    dict.foreyule,
+                ];
+                let name = match self.try_month() {
+                    Some(m) => months[(m as usize) - 1],
+                    None => "",
+                };
+                write_string(w, name, opt)
+            }
+            (TextContent::DayOfWeekName, Some(dict)) => {
+                let weekdays: [&str; 7] = [
+                    dict.sunday,
+                    dict.monday,
+                    dict.trewsday,
+                    dict.hevensday,
+                    dict.mersday,
+                    dict.highday,
+                    dict.sterday,
+                ];
+                let name = match self.weekday() {
+                    Some(d) => weekdays[d as usize],
+                    None => "",
+                };
+                write_string(w, name, opt)
+            }
+            (
+                TextContent::HalfDayName | TextContent::HalfDayAbbrev | TextContent::PartOfDayName,
+                _,
+            ) => self.convert::<TimeOfDay>().fmt_text(w, t, lang, opt),
+            //Years are numbered the same way as in the Gregorian calendar
+            (TextContent::EraName, _) => match get_table(lang, |d| d.gregorian.as_ref()) {
+                Some(dict) if self.year() < 0 => write_string(w, dict.before_common_era_full, opt),
+                Some(dict) => write_string(w, dict.common_era_full, opt),
+                None => Ok(()),
+            },
+            (TextContent::EraAbbreviation, _) => match get_table(lang, |d| d.gregorian.as_ref()) {
+                Some(dict) if self.year() < 0 => write_string(w, dict.before_common_era_abr, opt),
+                Some(dict) => write_string(w, dict.common_era_abr, opt),
+                None => Ok(()),
+            },
+            (TextContent::ComplementaryDayName, Some(dict)) => {
+                let compl: [&str; 6] = [
+                    dict.second_yule,
+                    dict.first_lithe,
+                    dict.mid_years_day,
+                    dict.overlithe,
+                    dict.second_lithe,
+                    dict.first_yule,
+                ];
+                let name = match self.epagomenae() {
+                    Some(d) => compl[(d as usize) - 1],
+                    None => "",
+                };
+                write_string(w, name, opt)
+            }
+            (_, _) => Ok(()),
+        }
+    }
+}
+
+impl PresetDisplay for Shire {
+    fn long_date_preset(&self) -> PresetFormat<'static> {
+        if self.epagomenae().is_some() {
+            LONG_COMPL
+        } else {
+            LONG_DATE
+        }
+    }
+}
+
+impl fmt::Display for Shire {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_into(f, Language::EN, self.long_date_preset())
+    }
+}
+
+impl DisplayMomentItem for Shire {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Gregorian;
+    use crate::calendar::GregorianMonth;
+    use crate::calendar::GuaranteedMonth;
+    use crate::calendar::ToFromCommonDate;
+    use crate::day_count::ToFixed;
+
+    #[test]
+    fn stardate() {
+        let g = Gregorian::try_new(2364, GregorianMonth::January, 1).unwrap();
+        assert_eq!(g.convert::<Stardate>().to_string(), "41000.0");
+        assert_eq!(Stardate::new(41153.74).to_string(), "41153.7");
+    }
+
+    #[test]
+    fn shire() {
+        let g = Gregorian::try_new(2025, GregorianMonth::January, 1).unwrap();
+        let s = g.convert::<Shire>();
+        assert_eq!(s.to_string(), "Monday Afteryule 9, 2025 Common Era");
+        assert_eq!(s.to_string(), s.long_date());
+        let g = Gregorian::try_new(2025, GregorianMonth::January, 3).unwrap();
+        let s = g.convert::<Shire>();
+        assert_eq!(s.to_string(), "Hevensday Afteryule 11, 2025 Common Era");
+        let g = Gregorian::try_new(2024, GregorianMonth::June, 23).unwrap();
+        assert_eq!(
+            g.convert::<Shire>().to_string(),
+            "Overlithe, 2024 Common Era"
+        );
+        let g = Gregorian::try_new(2024, GregorianMonth::December, 23).unwrap();
+        assert_eq!(g.convert::<Shire>().to_string(), "2 Yule, 2025 Common Era");
+    }
+
+    #[test]
+    fn shire_languages() {
+        assert!(Shire::supported_lang(Language::EN));
+        assert!(!Shire::supported_lang(Language::FR));
+        let g = Gregorian::try_new(2025, GregorianMonth::January, 1).unwrap();
+        let s = g.convert::<Shire>();
+        assert_eq!(
+            s.preset_str(Language::FR, LONG_DATE),
+            "Monday Afteryule 9, 2025 l'ère commune"
+        );
+    }
+
+    #[test]
+    fn long_now() {
+        assert_eq!(LongNowYear::new(2025).to_string(), "02025");
+        assert_eq!(LongNowYear::new(12025).to_string(), "12025");
+        assert_eq!(LongNowYear::new(-44).to_string(), "-0044");
+    }
+}
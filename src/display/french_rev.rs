@@ -11,19 +11,23 @@ use crate::clock::TimeOfDay;
 use crate::day_count::ToFixed;
 use crate::display::moment::DisplayMomentItem;
 use crate::display::prelude::PresetDisplay;
+use crate::display::prelude::PresetFormat;
 use crate::display::prelude::LONG_COMPL;
 use crate::display::prelude::LONG_DATE;
-use crate::display::private::fmt_days_since_epoch;
-use crate::display::private::fmt_number;
-use crate::display::private::fmt_quarter;
-use crate::display::private::fmt_seconds_since_epoch;
-use crate::display::private::fmt_string;
 use crate::display::private::get_dict;
+use crate::display::private::get_table;
+use crate::display::private::write_days_since_epoch;
+use crate::display::private::write_half_year;
+use crate::display::private::write_number;
+use crate::display::private::write_quarter;
+use crate::display::private::write_seconds_since_epoch;
+use crate::display::private::write_string;
+use crate::display::private::write_week_of_month_name;
 use crate::display::private::DisplayItem;
 use crate::display::private::NumericContent;
 use crate::display::private::TextContent;
 use crate::display::text::prelude::Language;
-use std::fmt;
+use core::fmt;
 
 use crate::display::private::DisplayOptions;
 
@@ -32,35 +36,55 @@ impl<const L: bool> DisplayItem for FrenchRevArith<L> {
         get_dict(lang).french_rev.as_ref().is_some()
     }
 
-    fn fmt_numeric(&self, n: NumericContent, opt: DisplayOptions) -> String {
+    fn fmt_numeric(
+        &self,
+        w: &mut impl fmt::Write,
+        n: NumericContent,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
         match n {
             NumericContent::Month | NumericContent::DayOfMonth | NumericContent::Year => {
-                self.to_common_date().fmt_numeric(n, opt)
+                self.to_common_date().fmt_numeric(w, n, opt)
             }
             NumericContent::DayOfWeek => match self.weekday() {
-                Some(d) => fmt_number(d as i8, opt),
-                None => "".to_string(),
+                Some(d) => write_number(w, d as i8, opt),
+                None => Ok(()),
             },
-            NumericContent::DayOfYear => self.to_ordinal().fmt_numeric(n, opt),
+            NumericContent::DayOfYear => self.to_ordinal().fmt_numeric(w, n, opt),
             NumericContent::Hour1to12
             | NumericContent::Hour0to23
             | NumericContent::Minute
-            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(n, opt),
-            NumericContent::SecondsSinceEpoch => fmt_seconds_since_epoch(*self, opt),
-            NumericContent::Quarter => fmt_quarter(*self, opt),
-            NumericContent::DaysSinceEpoch => fmt_days_since_epoch(*self, opt),
+            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(w, n, opt),
+            NumericContent::SecondsSinceEpoch => write_seconds_since_epoch(w, *self, opt),
+            NumericContent::Quarter => write_quarter(w, *self, opt),
+            NumericContent::HalfYear => write_half_year(w, *self, opt),
+            NumericContent::DaysSinceEpoch => write_days_since_epoch(w, *self, opt),
             NumericContent::ComplementaryDay => match self.epagomenae() {
-                Some(d) => fmt_number(d as i8, opt),
-                None => "".to_string(),
+                Some(d) => write_number(w, d as i8, opt),
+                None => Ok(()),
             },
             NumericContent::WeekOfYear => match self.try_week_of_year() {
-                Some(w) => fmt_number(w as i8, opt),
-                None => "".to_string(),
+                Some(week) => write_number(w, week as i8, opt),
+                None => Ok(()),
+            },
+            NumericContent::WeekOfMonth => match self.try_week_of_month() {
+                Some(week) => write_number(w, week as i8, opt),
+                None => Ok(()),
+            },
+            NumericContent::DayOfMonthWeek => match self.try_day_of_month_week() {
+                Some(d) => write_number(w, d as i8, opt),
+                None => Ok(()),
             },
         }
     }
-    fn fmt_text(&self, t: TextContent, lang: Language, opt: DisplayOptions) -> String {
-        match (t, get_dict(lang).french_rev.as_ref()) {
+    fn fmt_text(
+        &self,
+        w: &mut impl fmt::Write,
+        t: TextContent,
+        lang: Language,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
+        match (t, get_table(lang, |d| d.french_rev.as_ref())) {
             (TextContent::MonthName, Some(dict)) => {
                 let months: [&str; 12] = [
                     dict.vendemiaire,
@@ -80,9 +104,9 @@ impl<const L: bool> DisplayItem for FrenchRevArith<L> {
                     Some(m) => months[(m as usize) - 1],
                     None => "",
                 };
-                fmt_string(name, opt)
+                write_string(w, name, opt)
             }
-            (TextContent::DayOfMonthName, _) => fmt_string("", opt),
+            (TextContent::DayOfMonthName, _) => write_string(w, "", opt),
             (TextContent::DayOfWeekName, Some(dict)) => {
                 let weekdays: [&str; 10] = [
                     dict.primidi,
@@ -100,23 +124,24 @@ impl<const L: bool> DisplayItem for FrenchRevArith<L> {
                     Some(m) => weekdays[(m as usize) - 1],
                     None => "",
                 };
-                fmt_string(name, opt)
-            }
-            (TextContent::HalfDayName | TextContent::HalfDayAbbrev, _) => {
-                self.convert::<TimeOfDay>().fmt_text(t, lang, opt)
+                write_string(w, name, opt)
             }
+            (
+                TextContent::HalfDayName | TextContent::HalfDayAbbrev | TextContent::PartOfDayName,
+                _,
+            ) => self.convert::<TimeOfDay>().fmt_text(w, t, lang, opt),
             (TextContent::EraName, Some(dict)) => {
                 if self.to_common_date().year < 0 {
-                    fmt_string(dict.before_republic_full, opt)
+                    write_string(w, dict.before_republic_full, opt)
                 } else {
-                    fmt_string(dict.after_republic_full, opt)
+                    write_string(w, dict.after_republic_full, opt)
                 }
             }
             (TextContent::EraAbbreviation, Some(dict)) => {
                 if self.to_common_date().year < 0 {
-                    fmt_string(dict.before_republic_abr, opt)
+                    write_string(w, dict.before_republic_abr, opt)
                 } else {
-                    fmt_string(dict.after_republic_abr, opt)
+                    write_string(w, dict.after_republic_abr, opt)
                 }
             }
             (TextContent::ComplementaryDayName, Some(dict)) => {
@@ -132,26 +157,29 @@ impl<const L: bool> DisplayItem for FrenchRevArith<L> {
                     Some(d) => sansculottides[(d as usize) - 1],
                     None => "",
                 };
-                fmt_string(name, opt)
+                write_string(w, name, opt)
+            }
+            (TextContent::WeekOfMonthName, _) => {
+                write_week_of_month_name(w, self.try_week_of_month(), lang, opt)
             }
-            (_, _) => fmt_string("", opt),
+            (_, _) => write_string(w, "", opt),
         }
     }
 }
 
 impl<const L: bool> PresetDisplay for FrenchRevArith<L> {
-    fn long_date(&self) -> String {
+    fn long_date_preset(&self) -> PresetFormat<'static> {
         if self.epagomenae().is_some() {
-            self.preset_str(Language::EN, LONG_COMPL)
+            LONG_COMPL
         } else {
-            self.preset_str(Language::EN, LONG_DATE)
+            LONG_DATE
         }
     }
 }
 
 impl<const L: bool> fmt::Display for FrenchRevArith<L> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.long_date())
+        self.fmt_into(f, Language::EN, self.long_date_preset())
     }
 }
 
@@ -168,4 +196,16 @@ mod tests {
         assert!(FrenchRevArith::<false>::supported_lang(Language::FR));
         assert!(FrenchRevArith::<false>::supported_lang(Language::FR));
     }
+
+    #[test]
+    fn week_of_month() {
+        use crate::calendar::CommonDate;
+        use crate::calendar::ToFromCommonDate;
+        use crate::display::NTH_WEEKDAY_OF_MONTH;
+        let d = FrenchRevArith::<true>::try_from_common_date(CommonDate::new(2, 3, 25)).unwrap();
+        assert_eq!(
+            d.preset_str(Language::FR, NTH_WEEKDAY_OF_MONTH),
+            "troisième Quintidi de Frimaire 2"
+        );
+    }
 }
@@ -11,45 +11,62 @@ use crate::day_count::ToFixed;
 use crate::day_cycle::Weekday;
 use crate::display::moment::DisplayMomentItem;
 use crate::display::prelude::PresetDisplay;
-use crate::display::private::fmt_days_since_epoch;
-use crate::display::private::fmt_number;
-use crate::display::private::fmt_quarter;
-use crate::display::private::fmt_seconds_since_epoch;
-use crate::display::private::fmt_string;
 use crate::display::private::get_dict;
+use crate::display::private::get_table;
+use crate::display::private::write_days_since_epoch;
+use crate::display::private::write_half_year;
+use crate::display::private::write_number;
+use crate::display::private::write_quarter;
+use crate::display::private::write_seconds_since_epoch;
+use crate::display::private::write_string;
+use crate::display::private::write_week_of_month_name;
 use crate::display::private::DisplayItem;
 use crate::display::private::DisplayOptions;
 use crate::display::private::NumericContent;
 use crate::display::private::TextContent;
 use crate::display::text::prelude::Language;
-use std::fmt;
+use core::fmt;
 
 impl DisplayItem for Gregorian {
     fn supported_lang(lang: Language) -> bool {
         get_dict(lang).gregorian.as_ref().is_some()
     }
 
-    fn fmt_numeric(&self, n: NumericContent, opt: DisplayOptions) -> String {
+    fn fmt_numeric(
+        &self,
+        w: &mut impl fmt::Write,
+        n: NumericContent,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
         match n {
             NumericContent::Month | NumericContent::DayOfMonth | NumericContent::Year => {
-                self.to_common_date().fmt_numeric(n, opt)
+                self.to_common_date().fmt_numeric(w, n, opt)
             }
-            NumericContent::DayOfWeek => self.convert::<Weekday>().fmt_numeric(n, opt),
-            NumericContent::DayOfYear => self.to_ordinal().fmt_numeric(n, opt),
+            NumericContent::DayOfWeek => self.convert::<Weekday>().fmt_numeric(w, n, opt),
+            NumericContent::DayOfYear => self.to_ordinal().fmt_numeric(w, n, opt),
             NumericContent::Hour1to12
             | NumericContent::Hour0to23
             | NumericContent::Minute
-            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(n, opt),
-            NumericContent::SecondsSinceEpoch => fmt_seconds_since_epoch(*self, opt),
-            NumericContent::Quarter => fmt_quarter(*self, opt),
-            NumericContent::DaysSinceEpoch => fmt_days_since_epoch(*self, opt),
-            NumericContent::ComplementaryDay => String::from(""),
-            NumericContent::WeekOfYear => fmt_number(self.week_of_year() as i16, opt),
+            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(w, n, opt),
+            NumericContent::SecondsSinceEpoch => write_seconds_since_epoch(w, *self, opt),
+            NumericContent::Quarter => write_quarter(w, *self, opt),
+            NumericContent::HalfYear => write_half_year(w, *self, opt),
+            NumericContent::DaysSinceEpoch => write_days_since_epoch(w, *self, opt),
+            NumericContent::ComplementaryDay => Ok(()),
+            NumericContent::WeekOfYear => write_number(w, self.week_of_year() as i16, opt),
+            NumericContent::WeekOfMonth => write_number(w, self.week_of_month() as i16, opt),
+            NumericContent::DayOfMonthWeek => write_number(w, self.day_of_month_week() as i16, opt),
         }
     }
 
-    fn fmt_text(&self, t: TextContent, lang: Language, opt: DisplayOptions) -> String {
-        match (t, get_dict(lang).gregorian.as_ref()) {
+    fn fmt_text(
+        &self,
+        w: &mut impl fmt::Write,
+        t: TextContent,
+        lang: Language,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
+        match (t, get_table(lang, |d| d.gregorian.as_ref())) {
             (TextContent::MonthName, Some(dict)) => {
                 let months: [&str; 12] = [
                     dict.january,
@@ -66,28 +83,32 @@ impl DisplayItem for Gregorian {
                     dict.december,
                 ];
                 let name = months[self.to_common_date().month as usize - 1];
-                fmt_string(name, opt)
-            }
-            (TextContent::DayOfMonthName, _) => fmt_string("", opt),
-            (TextContent::DayOfWeekName, _) => self.convert::<Weekday>().fmt_text(t, lang, opt),
-            (TextContent::HalfDayName | TextContent::HalfDayAbbrev, _) => {
-                self.convert::<TimeOfDay>().fmt_text(t, lang, opt)
+                write_string(w, name, opt)
             }
+            (TextContent::DayOfMonthName, _) => write_string(w, "", opt),
+            (TextContent::DayOfWeekName, _) => self.convert::<Weekday>().fmt_text(w, t, lang, opt),
+            (
+                TextContent::HalfDayName | TextContent::HalfDayAbbrev | TextContent::PartOfDayName,
+                _,
+            ) => self.convert::<TimeOfDay>().fmt_text(w, t, lang, opt),
             (TextContent::EraName, Some(dict)) => {
                 if self.to_common_date().year < 0 {
-                    fmt_string(dict.before_common_era_full, opt)
+                    write_string(w, dict.before_common_era_full, opt)
                 } else {
-                    fmt_string(dict.common_era_full, opt)
+                    write_string(w, dict.common_era_full, opt)
                 }
             }
             (TextContent::EraAbbreviation, Some(dict)) => {
                 if self.to_common_date().year < 0 {
-                    fmt_string(dict.before_common_era_abr, opt)
+                    write_string(w, dict.before_common_era_abr, opt)
                 } else {
-                    fmt_string(dict.common_era_abr, opt)
+                    write_string(w, dict.common_era_abr, opt)
                 }
             }
-            (_, _) => String::from(""),
+            (TextContent::WeekOfMonthName, _) => {
+                write_week_of_month_name(w, Some(self.week_of_month()), lang, opt)
+            }
+            (_, _) => Ok(()),
         }
     }
 }
@@ -96,7 +117,7 @@ impl PresetDisplay for Gregorian {}
 
 impl fmt::Display for Gregorian {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.long_date())
+        self.fmt_into(f, Language::EN, self.long_date_preset())
     }
 }
 
@@ -106,6 +127,10 @@ impl DisplayMomentItem for Gregorian {}
 mod tests {
     use super::*;
     use crate::calendar::CommonDate;
+    use crate::display::HALF_YEAR;
+    use crate::display::NTH_WEEKDAY_OF_MONTH;
+    use crate::display::YEAR_QUARTER;
+    use crate::display::YYYYMMDD_SLASH;
 
     #[test]
     fn expected_languages() {
@@ -166,4 +191,65 @@ mod tests {
             assert_eq!(s, item.1);
         }
     }
+
+    #[test]
+    fn nth_weekday_of_month() {
+        let d_list = [
+            (
+                CommonDate::new(2025, 7, 26),
+                "fourth Saturday of July 2025",
+                "quatrième Samedi de juillet 2025",
+            ),
+            (
+                CommonDate::new(2025, 7, 1),
+                "first Tuesday of July 2025",
+                "premier Mardi de juillet 2025",
+            ),
+            (
+                CommonDate::new(2024, 12, 31),
+                "fifth Tuesday of December 2024",
+                "cinquième Mardi de décembre 2024",
+            ),
+            (
+                CommonDate::new(2025, 4, 13),
+                "second Sunday of April 2025",
+                "deuxième Dimanche d'avril 2025",
+            ),
+            (
+                CommonDate::new(2025, 8, 31),
+                "fifth Sunday of August 2025",
+                "cinquième Dimanche d'août 2025",
+            ),
+        ];
+        for item in d_list {
+            let d = Gregorian::try_from_common_date(item.0).unwrap();
+            assert_eq!(d.preset_str(Language::EN, NTH_WEEKDAY_OF_MONTH), item.1);
+            assert_eq!(d.preset_str(Language::FR, NTH_WEEKDAY_OF_MONTH), item.2);
+        }
+    }
+
+    #[test]
+    fn quarter_and_half_year() {
+        let d_list = [
+            (CommonDate::new(2025, 1, 1), "2025-Q1", "H1 2025"),
+            (CommonDate::new(2025, 6, 30), "2025-Q2", "H1 2025"),
+            (CommonDate::new(2025, 7, 1), "2025-Q3", "H2 2025"),
+            (CommonDate::new(2025, 12, 31), "2025-Q4", "H2 2025"),
+        ];
+        for item in d_list {
+            let d = Gregorian::try_from_common_date(item.0).unwrap();
+            assert_eq!(d.preset_str(Language::EN, YEAR_QUARTER), item.1);
+            assert_eq!(d.preset_str(Language::FR, HALF_YEAR), item.2);
+        }
+    }
+
+    #[test]
+    fn fmt_into() {
+        use core::fmt::Write;
+        let d = Gregorian::try_from_common_date(CommonDate::new(2025, 7, 1)).unwrap();
+        let mut buf = String::from("Date: ");
+        d.fmt_into(&mut buf, Language::FR, YYYYMMDD_SLASH).unwrap();
+        write!(buf, " / {}", d).unwrap();
+        assert_eq!(buf, "Date: 2025/07/01 / Tuesday July 1, 2025 Common Era");
+    }
 }
@@ -7,80 +7,97 @@ use crate::clock::TimeOfDay;
 use crate::day_count::ToFixed;
 use crate::display::moment::DisplayMomentItem;
 use crate::display::prelude::PresetDisplay;
+use crate::display::prelude::PresetFormat;
 use crate::display::prelude::YEAR_WEEK_DAY;
-use crate::display::private::fmt_days_since_epoch;
-use crate::display::private::fmt_number;
-use crate::display::private::fmt_quarter;
-use crate::display::private::fmt_seconds_since_epoch;
-use crate::display::private::fmt_string;
 use crate::display::private::get_dict;
+use crate::display::private::get_table;
+use crate::display::private::write_days_since_epoch;
+use crate::display::private::write_half_year;
+use crate::display::private::write_number;
+use crate::display::private::write_quarter;
+use crate::display::private::write_seconds_since_epoch;
+use crate::display::private::write_string;
 use crate::display::private::DisplayItem;
 use crate::display::private::DisplayOptions;
 use crate::display::private::NumericContent;
 use crate::display::private::TextContent;
 use crate::display::text::prelude::Language;
-use std::fmt;
+use core::fmt;
 
 impl DisplayItem for ISO {
     fn supported_lang(lang: Language) -> bool {
         get_dict(lang).iso.as_ref().is_some()
     }
 
-    fn fmt_numeric(&self, n: NumericContent, opt: DisplayOptions) -> String {
+    fn fmt_numeric(
+        &self,
+        w: &mut impl fmt::Write,
+        n: NumericContent,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
         match n {
-            NumericContent::Month | NumericContent::DayOfMonth => "".to_string(),
-            NumericContent::Year => fmt_number(self.year(), opt),
-            NumericContent::DayOfWeek => fmt_number(self.day_num() as i8, opt),
-            NumericContent::DayOfYear => "".to_string(),
+            NumericContent::Month | NumericContent::DayOfMonth => Ok(()),
+            NumericContent::Year => write_number(w, self.year(), opt),
+            NumericContent::DayOfWeek => write_number(w, self.day_num() as i8, opt),
+            NumericContent::DayOfYear => Ok(()),
             NumericContent::Hour1to12
             | NumericContent::Hour0to23
             | NumericContent::Minute
-            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(n, opt),
-            NumericContent::SecondsSinceEpoch => fmt_seconds_since_epoch(*self, opt),
-            NumericContent::Quarter => fmt_quarter(*self, opt),
-            NumericContent::DaysSinceEpoch => fmt_days_since_epoch(*self, opt),
-            NumericContent::ComplementaryDay => "".to_string(),
-            NumericContent::WeekOfYear => fmt_number(self.week().get() as i8, opt),
+            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(w, n, opt),
+            NumericContent::SecondsSinceEpoch => write_seconds_since_epoch(w, *self, opt),
+            NumericContent::Quarter => write_quarter(w, *self, opt),
+            NumericContent::HalfYear => write_half_year(w, *self, opt),
+            NumericContent::DaysSinceEpoch => write_days_since_epoch(w, *self, opt),
+            NumericContent::ComplementaryDay => Ok(()),
+            NumericContent::WeekOfYear => write_number(w, self.week().get() as i8, opt),
+            NumericContent::WeekOfMonth | NumericContent::DayOfMonthWeek => Ok(()),
         }
     }
-    fn fmt_text(&self, t: TextContent, lang: Language, opt: DisplayOptions) -> String {
-        match (t, get_dict(lang).iso.as_ref()) {
-            (TextContent::DayOfWeekName, _) => self.day().fmt_text(t, lang, opt),
-            (TextContent::HalfDayName | TextContent::HalfDayAbbrev, _) => {
-                self.convert::<TimeOfDay>().fmt_text(t, lang, opt)
-            }
+    fn fmt_text(
+        &self,
+        w: &mut impl fmt::Write,
+        t: TextContent,
+        lang: Language,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
+        match (t, get_table(lang, |d| d.iso.as_ref())) {
+            (TextContent::DayOfWeekName, _) => self.day().fmt_text(w, t, lang, opt),
+            (
+                TextContent::HalfDayName | TextContent::HalfDayAbbrev | TextContent::PartOfDayName,
+                _,
+            ) => self.convert::<TimeOfDay>().fmt_text(w, t, lang, opt),
             (TextContent::EraName, Some(dict)) => {
                 if self.year() < 0 {
-                    fmt_string(dict.before_epoch_full, opt)
+                    write_string(w, dict.before_epoch_full, opt)
                 } else {
-                    fmt_string(dict.after_epoch_full, opt)
+                    write_string(w, dict.after_epoch_full, opt)
                 }
             }
             (TextContent::EraAbbreviation, Some(dict)) => {
                 if self.year() < 0 {
-                    fmt_string(dict.before_epoch_abr, opt)
+                    write_string(w, dict.before_epoch_abr, opt)
                 } else {
-                    fmt_string(dict.after_epoch_abr, opt)
+                    write_string(w, dict.after_epoch_abr, opt)
                 }
             }
-            (_, _) => String::from(""),
+            (_, _) => Ok(()),
         }
     }
 }
 
 impl PresetDisplay for ISO {
-    fn long_date(&self) -> String {
-        self.preset_str(Language::EN, YEAR_WEEK_DAY)
+    fn long_date_preset(&self) -> PresetFormat<'static> {
+        YEAR_WEEK_DAY
     }
 
-    fn short_date(&self) -> String {
-        self.preset_str(Language::EN, YEAR_WEEK_DAY)
+    fn short_date_preset(&self) -> PresetFormat<'static> {
+        YEAR_WEEK_DAY
     }
 }
 
 impl fmt::Display for ISO {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.long_date())
+        self.fmt_into(f, Language::EN, self.long_date_preset())
     }
 }
 
@@ -96,6 +113,7 @@ mod tests {
     #[test]
     fn expected_languages() {
         assert!(ISO::supported_lang(Language::EN));
+        assert!(ISO::supported_lang(Language::FR));
     }
 
     #[test]
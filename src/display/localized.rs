@@ -0,0 +1,169 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::ToFromCommonDate;
+use crate::display::prelude::PresetDisplay;
+use crate::display::prelude::PresetFormat;
+use crate::display::private::Content;
+use crate::display::private::DisplayOptions;
+use crate::display::private::TextContent;
+use crate::display::text::prelude::Language;
+use core::cell::RefCell;
+use core::fmt;
+use core::marker::PhantomData;
+use num_traits::FromPrimitive;
+
+/// Formats many dates from one calendar in one language
+///
+/// The language is checked once, when the `LocalizedCalendar` is created. Month names are
+/// formatted once per month and reused for every later date in the same month.
+///
+/// This is intended for formatting many dates with the same language. For a single date,
+/// [`PresetDisplay::preset_str`] is simpler.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::display::*;
+///
+/// let fr = LocalizedCalendar::<Gregorian>::new(Language::FR).unwrap();
+/// let d = Gregorian::try_new(2025, GregorianMonth::July, 14).unwrap();
+/// assert_eq!(fr.preset_str(&d, LONG_DATE), d.preset_str(Language::FR, LONG_DATE));
+/// ```
+///
+/// ## Crate Features
+///
+/// This is only available if `display` is enabled.
+#[derive(Debug)]
+pub struct LocalizedCalendar<T> {
+    lang: Language,
+    month_names: RefCell<Vec<(u8, DisplayOptions, String)>>,
+    calendar: PhantomData<T>,
+}
+
+impl<T: PresetDisplay> LocalizedCalendar<T> {
+    /// Create a `LocalizedCalendar`
+    ///
+    /// Returns [`None`] if the language is not supported for this calendar.
+    pub fn new(lang: Language) -> Option<LocalizedCalendar<T>> {
+        if T::supported_display_lang(lang) {
+            Some(LocalizedCalendar {
+                lang,
+                month_names: RefCell::new(Vec::new()),
+                calendar: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn lang(&self) -> Language {
+        self.lang
+    }
+
+    fn write_month_name<S, W>(&self, w: &mut W, date: &T, opt: DisplayOptions) -> fmt::Result
+    where
+        S: FromPrimitive,
+        T: ToFromCommonDate<S> + Copy,
+        W: fmt::Write,
+    {
+        let month = date.to_common_date().month;
+        let mut cache = self.month_names.borrow_mut();
+        match cache.iter().find(|x| x.0 == month && x.1 == opt) {
+            Some(entry) => w.write_str(&entry.2),
+            None => {
+                let mut name = String::new();
+                date.fmt_text(&mut name, TextContent::MonthName, self.lang, opt)?;
+                w.write_str(&name)?;
+                cache.push((month, opt, name));
+                Ok(())
+            }
+        }
+    }
+
+    /// Write a date in any `PresetFormat` into a buffer
+    pub fn fmt_into<S>(
+        &self,
+        w: &mut impl fmt::Write,
+        date: &T,
+        preset: PresetFormat,
+    ) -> fmt::Result
+    where
+        S: FromPrimitive,
+        T: ToFromCommonDate<S> + Copy,
+    {
+        for item in preset.0 {
+            match item.content {
+                Content::Text(TextContent::MonthName) => {
+                    self.write_month_name(w, date, item.options)?
+                }
+                _ => date.fmt_item_into(w, self.lang, *item)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Format a date in any `PresetFormat`
+    pub fn preset_str<S>(&self, date: &T, preset: PresetFormat) -> String
+    where
+        S: FromPrimitive,
+        T: ToFromCommonDate<S> + Copy,
+    {
+        let mut result = String::new();
+        self.fmt_into(&mut result, date, preset)
+            .expect("Writing to String does not fail");
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "reform-calendars")]
+    use crate::calendar::Cotsworth;
+    #[cfg(feature = "reform-calendars")]
+    use crate::calendar::FrenchRevArith;
+    #[cfg(feature = "reform-calendars")]
+    use crate::calendar::Positivist;
+    use crate::calendar::Retail454;
+    #[cfg(feature = "reform-calendars")]
+    use crate::calendar::Tranquility;
+    use crate::day_count::BoundedDayCount;
+    use crate::day_count::Fixed;
+    use crate::day_count::FromFixed;
+    use crate::display::LONG_COMPL;
+    use crate::display::LONG_DATE;
+    use crate::display::YEAR_MDD;
+    use proptest::proptest;
+
+    fn same_as_preset<S, T>(t: f64)
+    where
+        S: FromPrimitive,
+        T: ToFromCommonDate<S> + PresetDisplay + FromFixed + Copy,
+    {
+        for lang in [Language::EN, Language::FR] {
+            if let Some(c) = LocalizedCalendar::<T>::new(lang) {
+                for i in 0..40 {
+                    let d = T::from_fixed(Fixed::new(t + (i as f64)));
+                    for preset in [LONG_DATE, LONG_COMPL, YEAR_MDD] {
+                        assert_eq!(c.preset_str(&d, preset), d.preset_str(lang, preset));
+                    }
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn calendars(t in -1000000.0..1000000.0) {
+            same_as_preset::<_, Retail454>(t);
+            #[cfg(feature = "reform-calendars")]
+            {
+                same_as_preset::<_, Cotsworth>(t);
+                same_as_preset::<_, FrenchRevArith<true>>(t);
+                same_as_preset::<_, Positivist>(t);
+                same_as_preset::<_, Tranquility>(t);
+            }
+        }
+    }
+}
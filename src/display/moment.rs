@@ -3,6 +3,7 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use crate::calendar::CalendarMoment;
+use crate::display::prelude::PresetFormat;
 use crate::display::private::DisplayItem;
 use crate::display::private::DisplayOptions;
 use crate::display::private::NumericContent;
@@ -10,7 +11,7 @@ use crate::display::private::TextContent;
 use crate::display::Language;
 use crate::display::PresetDisplay;
 use crate::display::HHMMSS_COLON;
-use std::fmt;
+use core::fmt;
 
 use crate::clock::ClockTime;
 
@@ -21,33 +22,44 @@ impl<T: DisplayItem + Clone + DisplayMomentItem> DisplayItem for CalendarMoment<
         T::supported_lang(lang) && ClockTime::supported_lang(lang)
     }
 
-    fn fmt_numeric(&self, n: NumericContent, opt: DisplayOptions) -> String {
+    fn fmt_numeric(
+        &self,
+        w: &mut impl fmt::Write,
+        n: NumericContent,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
         match n {
             NumericContent::Hour1to12
             | NumericContent::Hour0to23
             | NumericContent::Minute
-            | NumericContent::Second => self.clone().time_of_day().fmt_numeric(n, opt),
-            _ => self.clone().date().fmt_numeric(n, opt),
+            | NumericContent::Second => self.clone().time_of_day().fmt_numeric(w, n, opt),
+            _ => self.clone().date().fmt_numeric(w, n, opt),
         }
     }
 
-    fn fmt_text(&self, t: TextContent, lang: Language, opt: DisplayOptions) -> String {
+    fn fmt_text(
+        &self,
+        w: &mut impl fmt::Write,
+        t: TextContent,
+        lang: Language,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
         match t {
-            TextContent::HalfDayName | TextContent::HalfDayAbbrev => {
-                self.clone().time_of_day().fmt_text(t, lang, opt)
+            TextContent::HalfDayName | TextContent::HalfDayAbbrev | TextContent::PartOfDayName => {
+                self.clone().time_of_day().fmt_text(w, t, lang, opt)
             }
-            _ => self.clone().date().fmt_text(t, lang, opt),
+            _ => self.clone().date().fmt_text(w, t, lang, opt),
         }
     }
 }
 
 impl<T: PresetDisplay + Clone + DisplayMomentItem> PresetDisplay for CalendarMoment<T> {
-    fn long_date(&self) -> String {
-        self.clone().date().long_date()
+    fn long_date_preset(&self) -> PresetFormat<'static> {
+        self.clone().date().long_date_preset()
     }
 
-    fn short_date(&self) -> String {
-        self.clone().date().short_date()
+    fn short_date_preset(&self) -> PresetFormat<'static> {
+        self.clone().date().short_date_preset()
     }
 }
 
@@ -55,7 +67,8 @@ impl<T: fmt::Display + PresetDisplay + Clone + DisplayMomentItem> fmt::Display
     for CalendarMoment<T>
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} ", self.preset_str(Language::EN, HHMMSS_COLON));
+        self.fmt_into(f, Language::EN, HHMMSS_COLON)?;
+        f.write_str(" ")?;
         self.clone().date().fmt(f)
     }
 }
@@ -0,0 +1,450 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::ArmenianMonth;
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::AtticMonth;
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::BabylonianMonth;
+use crate::calendar::BengaliMonth;
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::ColignyMonth;
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::CopticMonth;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::CotsworthMonth;
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::EgyptianMonth;
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::EthiopicMonth;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::FrenchRevMonth;
+use crate::calendar::GregorianMonth;
+use crate::calendar::IslamicMonth;
+use crate::calendar::JulianMonth;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::PataphysicalMonth;
+use crate::calendar::PersianMonth;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::PositivistMonth;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::SymmetryMonth;
+use crate::calendar::TamilMonth;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::TranquilityMonth;
+use crate::common::error::CalendarError;
+use crate::display::private::get_dict;
+use crate::display::private::LANGUAGES;
+use crate::display::text::prelude::Language;
+use core::str::FromStr;
+use num_traits::FromPrimitive;
+
+/// Months with names in one or more languages
+///
+/// The names are the same as those used when formatting dates. Parsing ignores case.
+///
+/// Month types also implement [`FromStr`], which accepts a name in any supported language.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::display::*;
+///
+/// let m = GregorianMonth::July;
+/// assert_eq!(m.month_name(Language::EN), Some("July"));
+/// assert_eq!(m.month_name(Language::FR), Some("juillet"));
+/// assert_eq!(GregorianMonth::from_month_name("JULY", Language::EN), Some(m));
+/// assert_eq!(GregorianMonth::from_month_name("juillet", Language::EN), None);
+/// assert_eq!("juillet".parse::<GregorianMonth>().unwrap(), m);
+/// assert!("Thermidor".parse::<GregorianMonth>().is_err());
+/// ```
+/// ## Crate Features
+///
+/// This is only available if `display` is enabled.
+pub trait MonthName: FromPrimitive + Copy {
+    /// Name of the month, or [`None`] if the language is not supported
+    fn month_name(self, lang: Language) -> Option<&'static str>;
+
+    /// Find the month with a particular name in a specific language
+    fn from_month_name(name: &str, lang: Language) -> Option<Self> {
+        let name = name.trim().to_lowercase();
+        (1..)
+            .map_while(Self::from_u8)
+            .find(|m| m.month_name(lang).is_some_and(|s| s.to_lowercase() == name))
+    }
+
+    /// Find the month with a particular name in any supported language
+    fn try_from_any_month_name(name: &str) -> Result<Self, CalendarError> {
+        LANGUAGES
+            .iter()
+            .find_map(|lang| Self::from_month_name(name, *lang))
+            .ok_or(CalendarError::InvalidMonth)
+    }
+}
+
+macro_rules! month_name {
+    ($m:ty, $dict:ident, [$($field:ident),+]) => {
+        impl MonthName for $m {
+            fn month_name(self, lang: Language) -> Option<&'static str> {
+                let dict = get_dict(lang).$dict.as_ref()?;
+                let names = [$(dict.$field),+];
+                names.get((self as usize) - 1).copied()
+            }
+        }
+
+        impl FromStr for $m {
+            type Err = CalendarError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::try_from_any_month_name(s)
+            }
+        }
+    };
+}
+
+month_name!(
+    GregorianMonth,
+    gregorian,
+    [
+        january, february, march, april, may, june, july, august, september, october, november,
+        december
+    ]
+);
+month_name!(
+    JulianMonth,
+    julian,
+    [
+        january, february, march, april, may, june, july, august, september, october, november,
+        december
+    ]
+);
+month_name!(
+    BengaliMonth,
+    bengali,
+    [
+        boishakh, joishtho, asharh, shrabon, bhadro, ashshin, kartik, ogrohayon, poush, magh,
+        falgun, choitro
+    ]
+);
+month_name!(
+    TamilMonth,
+    tamil,
+    [
+        chithirai, vaikasi, aani, aadi, aavani, purattasi, aippasi, karthigai, margazhi, thai,
+        maasi, panguni
+    ]
+);
+#[cfg(feature = "ancient-calendars")]
+month_name!(
+    ArmenianMonth,
+    armenian,
+    [
+        nawasardi, hori, sahmi, tre, kaloch, arach, mehekani, areg, ahekani, mareri, margach,
+        hrotich
+    ]
+);
+#[cfg(feature = "ancient-calendars")]
+month_name!(
+    CopticMonth,
+    coptic,
+    [
+        thoout, paope, athor, koiak, tobe, meshir, paremotep, parmoute, pashons, paone, epep,
+        mesore, epagomene
+    ]
+);
+#[cfg(feature = "ancient-calendars")]
+month_name!(
+    EgyptianMonth,
+    egyptian,
+    [
+        thoth, phaophi, athyr, choiak, tybi, mechir, phamenoth, pharmuthi, pachon, payni, epiphi,
+        mesori
+    ]
+);
+#[cfg(feature = "ancient-calendars")]
+month_name!(
+    EthiopicMonth,
+    ethiopic,
+    [
+        maskaram, teqemt, hedar, takhsas, ter, yakatit, magabit, miyazya, genbot, sane, hamle,
+        nahase, paguemen
+    ]
+);
+#[cfg(feature = "reform-calendars")]
+month_name!(
+    CotsworthMonth,
+    cotsworth,
+    [
+        january, february, march, april, may, june, sol, july, august, september, october,
+        november, december
+    ]
+);
+#[cfg(feature = "reform-calendars")]
+month_name!(
+    FrenchRevMonth,
+    french_rev,
+    [
+        vendemiaire,
+        brumaire,
+        frimaire,
+        nivose,
+        pluviose,
+        ventose,
+        germinal,
+        floreal,
+        prairial,
+        messidor,
+        thermidor,
+        fructidor
+    ]
+);
+#[cfg(feature = "reform-calendars")]
+month_name!(
+    PositivistMonth,
+    positivist,
+    [
+        moses,
+        homer,
+        aristotle,
+        archimedes,
+        caesar,
+        saint_paul,
+        charlemagne,
+        dante,
+        gutenburg,
+        shakespeare,
+        descartes,
+        frederick,
+        bichat
+    ]
+);
+#[cfg(feature = "reform-calendars")]
+month_name!(
+    SymmetryMonth,
+    symmetry,
+    [
+        january, february, march, april, may, june, july, august, september, october, november,
+        december, irvember
+    ]
+);
+#[cfg(feature = "reform-calendars")]
+month_name!(
+    TranquilityMonth,
+    tranquility,
+    [
+        archimedes,
+        brahe,
+        copernicus,
+        darwin,
+        einstein,
+        faraday,
+        galileo,
+        hippocrates,
+        imhotep,
+        jung,
+        kepler,
+        lavoisier,
+        mendel
+    ]
+);
+
+#[cfg(feature = "ancient-calendars")]
+month_name!(
+    AtticMonth,
+    attic,
+    [
+        hekatombaion,
+        metageitnion,
+        boedromion,
+        pyanepsion,
+        maimakterion,
+        poseideon,
+        second_poseideon,
+        gamelion,
+        anthesterion,
+        elaphebolion,
+        mounichion,
+        thargelion,
+        skirophorion
+    ]
+);
+#[cfg(feature = "ancient-calendars")]
+month_name!(
+    BabylonianMonth,
+    babylonian,
+    [
+        nisannu,
+        ayyaru,
+        simanu,
+        duzu,
+        abu,
+        ululu,
+        second_ululu,
+        tashritu,
+        arahsamnu,
+        kislimu,
+        tebetu,
+        shabatu,
+        addaru,
+        second_addaru
+    ]
+);
+#[cfg(feature = "ancient-calendars")]
+month_name!(
+    ColignyMonth,
+    coligny,
+    [
+        quimonios,
+        samonios,
+        dumannios,
+        riuros,
+        anagantios,
+        ogronnios,
+        cutios,
+        rantaranos,
+        giamonios,
+        simivisonnos,
+        equos,
+        elembivos,
+        edrinios,
+        cantlos
+    ]
+);
+month_name!(
+    IslamicMonth,
+    islamic,
+    [
+        muharram,
+        safar,
+        rabi_al_awwal,
+        rabi_al_thani,
+        jumada_al_ula,
+        jumada_al_akhira,
+        rajab,
+        shaban,
+        ramadan,
+        shawwal,
+        dhu_al_qadah,
+        dhu_al_hijjah
+    ]
+);
+#[cfg(feature = "reform-calendars")]
+month_name!(
+    PataphysicalMonth,
+    pataphysical,
+    [
+        absolu,
+        haha,
+        r#as,
+        sable,
+        decervelage,
+        gueules,
+        pedale,
+        clinamen,
+        palotin,
+        merdre,
+        gidouille,
+        tatane,
+        phalle
+    ]
+);
+month_name!(
+    PersianMonth,
+    persian,
+    [
+        farvardin,
+        ordibehesht,
+        khordad,
+        tir,
+        mordad,
+        shahrivar,
+        mehr,
+        aban,
+        azar,
+        dey,
+        bahman,
+        esfand
+    ]
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip<T: MonthName + PartialEq + std::fmt::Debug + FromStr>() {
+        for lang in LANGUAGES {
+            for m in (1..).map_while(T::from_u8) {
+                if let Some(name) = m.month_name(lang) {
+                    assert_eq!(T::from_month_name(name, lang), Some(m));
+                    assert_eq!(T::from_month_name(&name.to_uppercase(), lang), Some(m));
+                    assert!(T::from_str(name).is_ok());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn all_round_trip() {
+        round_trip::<GregorianMonth>();
+        round_trip::<JulianMonth>();
+        round_trip::<BengaliMonth>();
+        round_trip::<TamilMonth>();
+        round_trip::<IslamicMonth>();
+        round_trip::<PersianMonth>();
+        #[cfg(feature = "ancient-calendars")]
+        {
+            round_trip::<ArmenianMonth>();
+            round_trip::<AtticMonth>();
+            round_trip::<BabylonianMonth>();
+            round_trip::<ColignyMonth>();
+            round_trip::<CopticMonth>();
+            round_trip::<EgyptianMonth>();
+            round_trip::<EthiopicMonth>();
+        }
+        #[cfg(feature = "reform-calendars")]
+        {
+            round_trip::<CotsworthMonth>();
+            round_trip::<FrenchRevMonth>();
+            round_trip::<PataphysicalMonth>();
+            round_trip::<PositivistMonth>();
+            round_trip::<SymmetryMonth>();
+            round_trip::<TranquilityMonth>();
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!("".parse::<GregorianMonth>().is_err());
+        assert!("Smarch".parse::<GregorianMonth>().is_err());
+        assert!(matches!(
+            "Smarch".parse::<JulianMonth>(),
+            Err(CalendarError::InvalidMonth)
+        ));
+    }
+
+    #[test]
+    fn transliterated() {
+        assert_eq!(
+            "ramadan".parse::<IslamicMonth>().unwrap(),
+            IslamicMonth::Ramadan
+        );
+        assert_eq!(
+            IslamicMonth::from_month_name("Chaabane", Language::FR),
+            Some(IslamicMonth::Shaban)
+        );
+        assert_eq!(
+            "Chahrivar".parse::<PersianMonth>().unwrap(),
+            PersianMonth::Shahrivar
+        );
+    }
+
+    #[cfg(feature = "reform-calendars")]
+    #[test]
+    fn french_rev() {
+        assert_eq!(
+            "thermidor".parse::<FrenchRevMonth>().unwrap(),
+            FrenchRevMonth::Thermidor
+        );
+    }
+}
@@ -11,55 +11,79 @@ use crate::clock::TimeOfDay;
 use crate::day_count::ToFixed;
 use crate::display::moment::DisplayMomentItem;
 use crate::display::prelude::PresetDisplay;
+use crate::display::prelude::PresetFormat;
 use crate::display::prelude::LONG_COMPL;
 use crate::display::prelude::LONG_DATE;
-use crate::display::private::fmt_days_since_epoch;
-use crate::display::private::fmt_number;
-use crate::display::private::fmt_quarter;
-use crate::display::private::fmt_seconds_since_epoch;
-use crate::display::private::fmt_string;
 use crate::display::private::get_dict;
+use crate::display::private::get_table;
+use crate::display::private::write_days_since_epoch;
+use crate::display::private::write_half_year;
+use crate::display::private::write_number;
+use crate::display::private::write_quarter;
+use crate::display::private::write_seconds_since_epoch;
+use crate::display::private::write_string;
+use crate::display::private::write_week_of_month_name;
 use crate::display::private::DisplayItem;
 use crate::display::private::DisplayOptions;
 use crate::display::private::NumericContent;
 use crate::display::private::TextContent;
 use crate::display::text::prelude::Language;
-use std::fmt;
+use core::fmt;
 
 impl DisplayItem for Positivist {
     fn supported_lang(lang: Language) -> bool {
         get_dict(lang).positivist.as_ref().is_some()
     }
 
-    fn fmt_numeric(&self, n: NumericContent, opt: DisplayOptions) -> String {
+    fn fmt_numeric(
+        &self,
+        w: &mut impl fmt::Write,
+        n: NumericContent,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
         match n {
             NumericContent::Month | NumericContent::DayOfMonth | NumericContent::Year => {
-                self.to_common_date().fmt_numeric(n, opt)
+                self.to_common_date().fmt_numeric(w, n, opt)
             }
             NumericContent::DayOfWeek => match self.weekday() {
-                Some(d) => d.fmt_numeric(n, opt),
-                None => "".to_string(),
+                Some(d) => d.fmt_numeric(w, n, opt),
+                None => Ok(()),
             },
-            NumericContent::DayOfYear => self.to_ordinal().fmt_numeric(n, opt),
+            NumericContent::DayOfYear => self.to_ordinal().fmt_numeric(w, n, opt),
             NumericContent::Hour1to12
             | NumericContent::Hour0to23
             | NumericContent::Minute
-            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(n, opt),
-            NumericContent::SecondsSinceEpoch => fmt_seconds_since_epoch(*self, opt),
-            NumericContent::Quarter => fmt_quarter(*self, opt),
-            NumericContent::DaysSinceEpoch => fmt_days_since_epoch(*self, opt),
+            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(w, n, opt),
+            NumericContent::SecondsSinceEpoch => write_seconds_since_epoch(w, *self, opt),
+            NumericContent::Quarter => write_quarter(w, *self, opt),
+            NumericContent::HalfYear => write_half_year(w, *self, opt),
+            NumericContent::DaysSinceEpoch => write_days_since_epoch(w, *self, opt),
             NumericContent::ComplementaryDay => match self.epagomenae() {
-                Some(d) => fmt_number(d as i8, opt),
-                None => "".to_string(),
+                Some(d) => write_number(w, d as i8, opt),
+                None => Ok(()),
             },
             NumericContent::WeekOfYear => match self.try_week_of_year() {
-                Some(w) => fmt_number(w as i8, opt),
-                None => "".to_string(),
+                Some(week) => write_number(w, week as i8, opt),
+                None => Ok(()),
+            },
+            NumericContent::WeekOfMonth => match self.try_week_of_month() {
+                Some(week) => write_number(w, week as i8, opt),
+                None => Ok(()),
+            },
+            NumericContent::DayOfMonthWeek => match self.try_day_of_month_week() {
+                Some(d) => write_number(w, d as i8, opt),
+                None => Ok(()),
             },
         }
     }
-    fn fmt_text(&self, t: TextContent, lang: Language, opt: DisplayOptions) -> String {
-        match (t, get_dict(lang).positivist.as_ref()) {
+    fn fmt_text(
+        &self,
+        w: &mut impl fmt::Write,
+        t: TextContent,
+        lang: Language,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
+        match (t, get_table(lang, |d| d.positivist.as_ref())) {
             (TextContent::MonthName, Some(dict)) => {
                 let months: [&str; 13] = [
                     dict.moses,
@@ -80,28 +104,29 @@ impl DisplayItem for Positivist {
                     Some(m) => months[(m as usize) - 1],
                     None => "",
                 };
-                fmt_string(name, opt)
+                write_string(w, name, opt)
             }
-            (TextContent::DayOfMonthName, _) => fmt_string("", opt),
+            (TextContent::DayOfMonthName, _) => write_string(w, "", opt),
             (TextContent::DayOfWeekName, _) => match self.weekday() {
-                Some(m) => m.fmt_text(t, lang, opt),
-                None => fmt_string("", opt),
+                Some(m) => m.fmt_text(w, t, lang, opt),
+                None => write_string(w, "", opt),
             },
-            (TextContent::HalfDayName | TextContent::HalfDayAbbrev, _) => {
-                self.convert::<TimeOfDay>().fmt_text(t, lang, opt)
-            }
+            (
+                TextContent::HalfDayName | TextContent::HalfDayAbbrev | TextContent::PartOfDayName,
+                _,
+            ) => self.convert::<TimeOfDay>().fmt_text(w, t, lang, opt),
             (TextContent::EraName, Some(dict)) => {
                 if self.to_common_date().year < 0 {
-                    fmt_string(dict.before_crisis_full, opt)
+                    write_string(w, dict.before_crisis_full, opt)
                 } else {
-                    fmt_string(dict.after_crisis_full, opt)
+                    write_string(w, dict.after_crisis_full, opt)
                 }
             }
             (TextContent::EraAbbreviation, Some(dict)) => {
                 if self.to_common_date().year < 0 {
-                    fmt_string(dict.before_crisis_abr, opt)
+                    write_string(w, dict.before_crisis_abr, opt)
                 } else {
-                    fmt_string(dict.after_crisis_abr, opt)
+                    write_string(w, dict.after_crisis_abr, opt)
                 }
             }
             (TextContent::ComplementaryDayName, Some(dict)) => {
@@ -110,26 +135,29 @@ impl DisplayItem for Positivist {
                     Some(d) => compl[(d as usize) - 1],
                     None => "",
                 };
-                fmt_string(name, opt)
+                write_string(w, name, opt)
+            }
+            (TextContent::WeekOfMonthName, _) => {
+                write_week_of_month_name(w, self.try_week_of_month(), lang, opt)
             }
-            (_, _) => String::from(""),
+            (_, _) => Ok(()),
         }
     }
 }
 
 impl PresetDisplay for Positivist {
-    fn long_date(&self) -> String {
+    fn long_date_preset(&self) -> PresetFormat<'static> {
         if self.epagomenae().is_some() {
-            self.preset_str(Language::EN, LONG_COMPL)
+            LONG_COMPL
         } else {
-            self.preset_str(Language::EN, LONG_DATE)
+            LONG_DATE
         }
     }
 }
 
 impl fmt::Display for Positivist {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.long_date())
+        self.fmt_into(f, Language::EN, self.long_date_preset())
     }
 }
 
@@ -11,6 +11,7 @@ use crate::display::private::NumericContent;
 use crate::display::private::Sign;
 use crate::display::private::TextContent;
 pub use crate::display::text::prelude::Language;
+use core::fmt;
 
 const O_LITERAL: DisplayOptions = DisplayOptions {
     numerals: None,
@@ -164,6 +165,24 @@ NumericDateItems!(I_MMDDYYYY_SLASH, "/", Month, 2, DayOfMonth, 2, Year, 4);
 NumericDateItems!(I_YYYYOOO_DASH, "-", Year, 4, DayOfYear, 3);
 NumericDateItems!(I_YYYYYOOO_DASH, "-", Year, 5, DayOfYear, 3);
 
+const I_EXPANDED_YYYYMMDD_DASH: [Item<'_>; 5] = [
+    Item::new(
+        Content::Numeric(NumericContent::Year),
+        DisplayOptions {
+            numerals: None,
+            width: Some(7),
+            align: None,
+            padding: Some('0'),
+            case: None,
+            sign: Sign::Always,
+        },
+    ),
+    Item::new(Content::Literal("-"), O_LITERAL),
+    Item::new(Content::Numeric(NumericContent::Month), O_N2),
+    Item::new(Content::Literal("-"), O_LITERAL),
+    Item::new(Content::Numeric(NumericContent::DayOfMonth), O_N2),
+];
+
 const I_LONG_DATE: [Item<'_>; 9] = [
     Item::new(Content::Text(TextContent::DayOfWeekName), O_LITERAL),
     Item::new(Content::Literal(" "), O_LITERAL),
@@ -176,6 +195,16 @@ const I_LONG_DATE: [Item<'_>; 9] = [
     Item::new(Content::Text(TextContent::EraName), O_LITERAL),
 ];
 
+const I_LONG_DATE_NO_WEEKDAY: [Item<'_>; 7] = [
+    Item::new(Content::Text(TextContent::MonthName), O_LITERAL),
+    Item::new(Content::Literal(" "), O_LITERAL),
+    Item::new(Content::Numeric(NumericContent::DayOfMonth), O_LITERAL),
+    Item::new(Content::Literal(", "), O_LITERAL),
+    Item::new(Content::Numeric(NumericContent::Year), O_YEAR_IN_ERA),
+    Item::new(Content::Literal(" "), O_LITERAL),
+    Item::new(Content::Text(TextContent::EraName), O_LITERAL),
+];
+
 const I_LONG_DAY_OF_MONTH: [Item<'_>; 9] = [
     Item::new(Content::Text(TextContent::DayOfWeekName), O_LITERAL),
     Item::new(Content::Literal(" "), O_LITERAL),
@@ -236,6 +265,36 @@ const I_YEAR_WEEK_DAY: [Item<'_>; 5] = [
     Item::new(Content::Numeric(NumericContent::DayOfWeek), O_N1),
 ];
 
+const I_NTH_WEEKDAY_OF_MONTH: [Item<'_>; 8] = [
+    Item::new(Content::Text(TextContent::WeekOfMonthName), O_LITERAL),
+    Item::new(Content::Literal(" "), O_LITERAL),
+    Item::new(Content::Text(TextContent::DayOfWeekName), O_LITERAL),
+    Item::new(Content::Literal(" "), O_LITERAL),
+    //The connector includes the following space, if there is one
+    Item::new(Content::Text(TextContent::WeekOfMonthConnector), O_LITERAL),
+    Item::new(Content::Text(TextContent::MonthName), O_LITERAL),
+    Item::new(Content::Literal(" "), O_LITERAL),
+    Item::new(Content::Numeric(NumericContent::Year), O_LITERAL),
+];
+
+const I_MONTH_WEEK_DAY: [Item<'_>; 7] = [
+    Item::new(Content::Numeric(NumericContent::Year), O_LITERAL),
+    Item::new(Content::Literal("-"), O_LITERAL),
+    Item::new(Content::Numeric(NumericContent::Month), O_N2),
+    Item::new(Content::Literal("-W"), O_LITERAL),
+    Item::new(Content::Numeric(NumericContent::WeekOfMonth), O_N1),
+    Item::new(Content::Literal("-"), O_LITERAL),
+    Item::new(Content::Numeric(NumericContent::DayOfMonthWeek), O_N1),
+];
+
+const I_DAY_WEEK_OF_MONTH: [Item<'_>; 5] = [
+    Item::new(Content::Text(TextContent::MonthName), O_LITERAL),
+    Item::new(Content::Literal(" "), O_LITERAL),
+    Item::new(Content::Numeric(NumericContent::DayOfMonth), O_LITERAL),
+    Item::new(Content::Literal(", W"), O_LITERAL),
+    Item::new(Content::Numeric(NumericContent::WeekOfMonth), O_LITERAL),
+];
+
 const I_YEAR_MDD: [Item<'_>; 4] = [
     Item::new(Content::Numeric(NumericContent::Year), O_LITERAL),
     Item::new(Content::Literal("-"), O_LITERAL),
@@ -259,6 +318,44 @@ const I_WEEKDAY_NAME_ONLY: [Item<'_>; 1] = [Item::new(
     O_LITERAL,
 )];
 
+const I_HALF_DAY_ONLY: [Item<'_>; 1] = [Item::new(
+    Content::Text(TextContent::HalfDayAbbrev),
+    O_LITERAL,
+)];
+
+const I_PART_OF_DAY_ONLY: [Item<'_>; 1] = [Item::new(
+    Content::Text(TextContent::PartOfDayName),
+    O_LITERAL,
+)];
+
+const I_YEAR_QUARTER: [Item<'_>; 3] = [
+    Item::new(
+        Content::Numeric(NumericContent::Year),
+        DisplayOptions {
+            numerals: None,
+            width: Some(4),
+            align: None,
+            padding: Some('0'),
+            case: None,
+            sign: Sign::OnlyNegative,
+        },
+    ),
+    Item::new(Content::Literal("-Q"), O_LITERAL),
+    Item::new(Content::Numeric(NumericContent::Quarter), O_N1),
+];
+
+const I_HALF_YEAR: [Item<'_>; 4] = [
+    Item::new(Content::Literal("H"), O_LITERAL),
+    Item::new(Content::Numeric(NumericContent::HalfYear), O_N1),
+    Item::new(Content::Literal(" "), O_LITERAL),
+    Item::new(Content::Numeric(NumericContent::Year), O_LITERAL),
+];
+
+const I_FISCAL_YEAR: [Item<'_>; 2] = [
+    Item::new(Content::Text(TextContent::EraAbbreviation), O_LITERAL),
+    Item::new(Content::Numeric(NumericContent::Year), O_YEAR_IN_ERA),
+];
+
 const I_EPOCH_SECONDS_ONLY: [Item<'_>; 1] = [Item::new(
     Content::Numeric(NumericContent::SecondsSinceEpoch),
     O_LITERAL,
@@ -274,7 +371,7 @@ const I_EPOCH_DAYS_ONLY: [Item<'_>; 1] = [Item::new(
 ///
 /// This is only available if `display` is enabled.
 #[derive(Debug, PartialEq, Clone, Copy)]
-pub struct PresetFormat<'a>(&'a [Item<'a>]);
+pub struct PresetFormat<'a>(pub(crate) &'a [Item<'a>]);
 
 /// HH:MM AM/PM time format
 /// ## Crate Features
@@ -299,6 +396,17 @@ pub const YYYYMMDD_DASH: PresetFormat<'static> = PresetFormat::<'static>(&I_YYYY
 ///
 /// This is only available if `display` is enabled.
 pub const YYYYYMMDD_DASH: PresetFormat<'static> = PresetFormat::<'static>(&I_YYYYYMMDD_DASH);
+/// ±YYYYYY-MM-DD numeric date format, with at least 6 digits in the year
+///
+/// This is the expanded representation of ISO 8601, which always has a sign so that
+/// years before 1 and after 9999 can be represented. For example, 15 May 2025 is
+/// `+002025-05-15`, and years with more than 6 digits are never truncated.
+///
+/// ## Crate Features
+///
+/// This is only available if `display` is enabled.
+pub const EXPANDED_YYYYMMDD_DASH: PresetFormat<'static> =
+    PresetFormat::<'static>(&I_EXPANDED_YYYYMMDD_DASH);
 /// YYYY/MM/DD numeric date format
 /// ## Crate Features
 ///
@@ -336,6 +444,9 @@ pub const YYYYYOOO_DASH: PresetFormat<'static> = PresetFormat::<'static>(&I_YYYY
 ///
 /// This is only available if `display` is enabled.
 pub const LONG_DATE: PresetFormat<'static> = PresetFormat::<'static>(&I_LONG_DATE);
+/// Long date format for days which are within a month but outside of any week
+pub(crate) const LONG_DATE_NO_WEEKDAY: PresetFormat<'static> =
+    PresetFormat::<'static>(&I_LONG_DATE_NO_WEEKDAY);
 /// Calendar-specific long date format with day of month name
 /// ## Crate Features
 ///
@@ -375,6 +486,35 @@ pub const LONG_COMPL_ERA_ABBR: PresetFormat<'static> =
 ///
 /// This is only available if `display` is enabled.
 pub const YEAR_WEEK_DAY: PresetFormat<'static> = PresetFormat::<'static>(&I_YEAR_WEEK_DAY);
+/// Format which describes a date as an occurence of a day of the week within a month
+///
+/// For example, 26 July 2025 in the Gregorian calendar is the "fourth Saturday of July
+/// 2025". This is intended for describing recurring events. The inverse is
+/// [`nth_kday_of_month`](crate::calendar::CommonWeekOfYear::nth_kday_of_month).
+/// ## Crate Features
+///
+/// This is only available if `display` is enabled.
+pub const NTH_WEEKDAY_OF_MONTH: PresetFormat<'static> =
+    PresetFormat::<'static>(&I_NTH_WEEKDAY_OF_MONTH);
+/// YYYY-MM-Wn-D alphanumeric date format, where n is the week of the month
+///
+/// D is the position of the date within its week of the month, starting from 1. This is
+/// intended for perennial calendars, where every month starts on the same day of the week.
+/// For example, Sol 16 in the Cotsworth calendar is "2025-07-W3-2". Complementary days
+/// are not part of any week, so use [`YEAR_COMPL`] for them instead.
+/// ## Crate Features
+///
+/// This is only available if `display` is enabled.
+pub const MONTH_WEEK_DAY: PresetFormat<'static> = PresetFormat::<'static>(&I_MONTH_WEEK_DAY);
+/// Format which is the month name and day of month, followed by the week of the month
+///
+/// This is intended for perennial calendars, such as "Sol 16, W3" in the Cotsworth
+/// calendar. Complementary days are not part of any week, so use [`COMPL_ONLY`] for them
+/// instead.
+/// ## Crate Features
+///
+/// This is only available if `display` is enabled.
+pub const DAY_WEEK_OF_MONTH: PresetFormat<'static> = PresetFormat::<'static>(&I_DAY_WEEK_OF_MONTH);
 /// Y-mDD alphanumeric date format, where Y has variable length, m is a single character
 ///
 /// This is intended for the Tranquility calendar
@@ -402,6 +542,49 @@ pub const COMPL_ONLY: PresetFormat<'static> = PresetFormat::<'static>(&I_COMPL_O
 ///
 /// This is only available if `display` is enabled.
 pub const WEEKDAY_NAME_ONLY: PresetFormat<'static> = PresetFormat::<'static>(&I_WEEKDAY_NAME_ONLY);
+/// Format which is the abbreviated half of the day only, such as "AM"
+///
+/// This is intended for approximate times where the clock time is unknown.
+///
+/// ## Crate Features
+///
+/// This is only available if `display` is enabled.
+pub const HALF_DAY_ONLY: PresetFormat<'static> = PresetFormat::<'static>(&I_HALF_DAY_ONLY);
+/// Format which is the name of the part of the day only, such as "Evening"
+///
+/// The day is divided into night, morning, afternoon and evening, each six hours long.
+/// This is intended for approximate times where the clock time is unknown.
+///
+/// ## Crate Features
+///
+/// This is only available if `display` is enabled.
+pub const PART_OF_DAY_ONLY: PresetFormat<'static> = PresetFormat::<'static>(&I_PART_OF_DAY_ONLY);
+/// YYYY-Qn quarter format, such as "2025-Q3"
+///
+/// This is intended for calendars with quarters.
+///
+/// ## Crate Features
+///
+/// This is only available if `display` is enabled.
+pub const YEAR_QUARTER: PresetFormat<'static> = PresetFormat::<'static>(&I_YEAR_QUARTER);
+/// Hn Y half year format, such as "H2 2025"
+///
+/// The first half of the year is the first and second quarters.
+///
+/// ## Crate Features
+///
+/// This is only available if `display` is enabled.
+pub const HALF_YEAR: PresetFormat<'static> = PresetFormat::<'static>(&I_HALF_YEAR);
+/// Fiscal year label, such as "FY2026"
+///
+/// This is the abbreviated era followed by the year, so it is intended for fiscal
+/// calendars such as [`Retail`](crate::calendar::Retail), where the era abbreviation
+/// is the fiscal year abbreviation.
+///
+/// ## Crate Features
+///
+/// This is only available if `display` is enabled.
+pub const FISCAL_YEAR: PresetFormat<'static> = PresetFormat::<'static>(&I_FISCAL_YEAR);
 /// Format which is the seconds since an epoch only
 ///
 /// The epoch is specific to the timekeeping system.
@@ -426,26 +609,67 @@ pub const EPOCH_DAYS_ONLY: PresetFormat<'static> = PresetFormat::<'static>(&I_EP
 /// This is only available if `display` is enabled.
 pub trait PresetDisplay: DisplayItem {
     /// Checks if language is supported
+    ///
+    /// Any text which has not been translated into the language is written in English
+    /// instead, so a language is supported if either it or English is supported.
     fn supported_display_lang(lang: Language) -> bool {
+        Self::supported_lang(lang) || Self::supported_lang(Language::EN)
+    }
+
+    /// Checks if all text is translated into the language, without falling back to English
+    ///
+    /// ```
+    /// use radnelac::calendar::*;
+    /// use radnelac::display::*;
+    ///
+    /// assert!(Egyptian::has_complete_translation(Language::FR));
+    /// let d = Egyptian::try_from_common_date(CommonDate::new(1, 1, 1)).unwrap();
+    /// assert!(d.preset_str(Language::FR, LONG_DATE).contains("l'ère de Nabonassar"));
+    /// ```
+    fn has_complete_translation(lang: Language) -> bool {
         Self::supported_lang(lang)
     }
 
+    /// Write a date in any `PresetFormat` into a buffer
+    ///
+    /// The literal parts of the format are written without any intermediate `String`.
+    fn fmt_into(
+        &self,
+        w: &mut impl fmt::Write,
+        lang: Language,
+        preset: PresetFormat,
+    ) -> fmt::Result {
+        for item in preset.0 {
+            self.fmt_item_into(w, lang, *item)?;
+        }
+        Ok(())
+    }
+
     /// Format a date in any `PresetFormat`
     fn preset_str(&self, lang: Language, preset: PresetFormat) -> String {
         let mut result = String::new();
-        for item in preset.0 {
-            result.push_str(&self.fmt_item(lang, *item))
-        }
+        self.fmt_into(&mut result, lang, preset)
+            .expect("Writing to String does not fail");
         result
     }
 
+    /// The `PresetFormat` used by [`long_date`](PresetDisplay::long_date) for this date
+    fn long_date_preset(&self) -> PresetFormat<'static> {
+        LONG_DATE
+    }
+
+    /// The `PresetFormat` used by [`short_date`](PresetDisplay::short_date) for this date
+    fn short_date_preset(&self) -> PresetFormat<'static> {
+        YYYYMMDD_DASH
+    }
+
     /// Format a date in a calendar-specific long format
     fn long_date(&self) -> String {
-        self.preset_str(Language::EN, LONG_DATE)
+        self.preset_str(Language::EN, self.long_date_preset())
     }
 
     /// Format a date in a calendar-specific short format
     fn short_date(&self) -> String {
-        self.preset_str(Language::EN, YYYYMMDD_DASH)
+        self.preset_str(Language::EN, self.short_date_preset())
     }
 }
@@ -5,6 +5,7 @@
 use crate::calendar::CommonDate;
 use crate::calendar::OrdinalDate;
 use crate::calendar::Quarter;
+use crate::common::units::day_fraction_to_seconds;
 use crate::day_count::BoundedDayCount;
 use crate::day_count::Epoch;
 use crate::day_count::ToFixed;
@@ -12,13 +13,18 @@ use crate::display::text::en::EN_DICTIONARY;
 use crate::display::text::fr::FR_DICTIONARY;
 use crate::display::text::prelude::Dictionary;
 use crate::display::text::prelude::Language;
+use crate::display::PresetDisplay;
+use crate::display::YYYYOOO_DASH;
 use convert_case;
 use convert_case::Casing;
+use core::cmp::max;
+use core::fmt;
+use core::fmt::Write;
+use core::str;
 use num_traits::NumAssign;
 use num_traits::Signed;
 use num_traits::ToPrimitive;
 use numerals::roman::Roman;
-use std::cmp::max;
 
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
 pub enum NumericContent {
@@ -33,9 +39,12 @@ pub enum NumericContent {
     SecondsSinceEpoch,
     Year,
     Quarter,
+    HalfYear,
     DaysSinceEpoch,
     ComplementaryDay,
     WeekOfYear,
+    WeekOfMonth,
+    DayOfMonthWeek,
 }
 
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
@@ -45,9 +54,12 @@ pub enum TextContent {
     DayOfWeekName,
     HalfDayName,
     HalfDayAbbrev,
+    PartOfDayName,
     EraName,
     EraAbbreviation,
     ComplementaryDayName,
+    WeekOfMonthName,
+    WeekOfMonthConnector,
 }
 
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
@@ -111,80 +123,151 @@ impl<'a> Item<'a> {
 
 pub trait DisplayItem {
     fn supported_lang(lang: Language) -> bool;
-    fn fmt_numeric(&self, n: NumericContent, opt: DisplayOptions) -> String;
-    fn fmt_text(&self, t: TextContent, lang: Language, opt: DisplayOptions) -> String;
-
-    fn fmt_item(&self, lang: Language, item: Item) -> String {
+    fn fmt_numeric(
+        &self,
+        w: &mut impl fmt::Write,
+        n: NumericContent,
+        opt: DisplayOptions,
+    ) -> fmt::Result;
+    fn fmt_text(
+        &self,
+        w: &mut impl fmt::Write,
+        t: TextContent,
+        lang: Language,
+        opt: DisplayOptions,
+    ) -> fmt::Result;
+
+    fn fmt_item_into(&self, w: &mut impl fmt::Write, lang: Language, item: Item) -> fmt::Result {
         match item.content {
-            Content::Literal(s) => String::from(s),
-            Content::Numeric(n) => self.fmt_numeric(n, item.options),
-            Content::Text(t) => self.fmt_text(t, lang, item.options),
+            Content::Literal(s) => w.write_str(s),
+            Content::Numeric(n) => self.fmt_numeric(w, n, item.options),
+            Content::Text(TextContent::WeekOfMonthConnector) => {
+                let mut month = StackStr::<64>::new();
+                //A month name which does not fit is still long enough to check the first letter
+                let _ = self.fmt_text(&mut month, TextContent::MonthName, lang, item.options);
+                write_week_of_month_connector(w, month.as_str(), lang, item.options)
+            }
+            Content::Text(t) => self.fmt_text(w, t, lang, item.options),
         }
     }
 }
 
-pub fn fmt_string(root: &str, opt: DisplayOptions) -> String {
-    let mut result = String::new();
-    let cased_root = if opt.case.is_some() {
-        let case = match opt.case.unwrap() {
-            Case::Upper => convert_case::Case::UpperFlat,
-            Case::Lower => convert_case::Case::Flat,
-            Case::Title => convert_case::Case::UpperCamel,
-        };
-        root.to_case(case)
-    } else {
-        String::from(root)
-    };
+/// Fixed capacity buffer for short strings such as formatted numbers
+pub struct StackStr<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
 
-    if opt.width.is_some() && opt.width.unwrap() > cased_root.len() {
-        let align = opt.align.unwrap_or(Align::Left);
-        let pad_char = opt.padding.unwrap_or(' ');
-        let pad_width = opt.width.unwrap() - cased_root.len();
-        let pad_left = std::iter::repeat(pad_char)
-            .take((pad_width / 2) + (pad_width % 2))
-            .collect::<String>();
-        let pad_right = std::iter::repeat(pad_char)
-            .take(pad_width - ((pad_width / 2) + (pad_width % 2)))
-            .collect::<String>();
-        let positions: [&str; 3] = match align {
-            Align::Left => [&pad_left, &pad_right, &cased_root],
-            Align::Right => [&cased_root, &pad_left, &pad_right],
-            Align::Center => [&pad_left, &cased_root, &pad_right],
-        };
-        for item in positions {
-            result.push_str(item);
+impl<const N: usize> StackStr<N> {
+    pub const fn new() -> Self {
+        StackStr {
+            buf: [0; N],
+            len: 0,
         }
-    } else {
-        result.push_str(&cased_root);
-        let max_len = opt.width.unwrap_or(cased_root.len());
-        if cased_root.len() > max_len {
-            let max_idx = cased_root
+    }
+
+    pub fn as_str(&self) -> &str {
+        str::from_utf8(&self.buf[..self.len]).expect("Only whole strings are written")
+    }
+}
+
+impl<const N: usize> fmt::Write for StackStr<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > N {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+fn write_repeat(w: &mut impl fmt::Write, c: char, n: usize) -> fmt::Result {
+    for _ in 0..n {
+        w.write_char(c)?;
+    }
+    Ok(())
+}
+
+fn write_cased(w: &mut impl fmt::Write, root: &str, opt: DisplayOptions) -> fmt::Result {
+    match opt.width {
+        Some(width) if width > root.len() => {
+            let pad_char = opt.padding.unwrap_or(' ');
+            let pad_width = width - root.len();
+            let pad_left = (pad_width / 2) + (pad_width % 2);
+            let pad_right = pad_width - pad_left;
+            match opt.align.unwrap_or(Align::Left) {
+                Align::Left => {
+                    write_repeat(w, pad_char, pad_left + pad_right)?;
+                    w.write_str(root)
+                }
+                Align::Right => {
+                    w.write_str(root)?;
+                    write_repeat(w, pad_char, pad_left + pad_right)
+                }
+                Align::Center => {
+                    write_repeat(w, pad_char, pad_left)?;
+                    w.write_str(root)?;
+                    write_repeat(w, pad_char, pad_right)
+                }
+            }
+        }
+        Some(width) if root.len() > width => {
+            let max_idx = root
                 .char_indices()
                 .map(|x| x.0)
-                .rfind(|x| *x <= max_len)
+                .rfind(|x| *x <= width)
                 .unwrap_or(0);
-            result.truncate(max_idx);
+            w.write_str(&root[..max_idx])
+        }
+        _ => w.write_str(root),
+    }
+}
+
+/// Write a string with padding, truncation and case conversion
+///
+/// Only case conversion allocates.
+pub fn write_string(w: &mut impl fmt::Write, root: &str, opt: DisplayOptions) -> fmt::Result {
+    match opt.case {
+        Some(case) => {
+            let case = match case {
+                Case::Upper => convert_case::Case::UpperFlat,
+                Case::Lower => convert_case::Case::Flat,
+                Case::Title => convert_case::Case::UpperCamel,
+            };
+            write_cased(w, &root.to_case(case), opt)
         }
+        None => write_cased(w, root, opt),
     }
+}
+
+pub fn fmt_string(root: &str, opt: DisplayOptions) -> String {
+    let mut result = String::new();
+    write_string(&mut result, root, opt).expect("Writing to String does not fail");
     result
 }
 
-pub fn fmt_number<T: itoa::Integer + NumAssign + Signed + PartialOrd + ToPrimitive>(
+/// Write a number with padding, sign and numeral options
+///
+/// The number is assembled in a fixed size buffer, so this does not allocate unless case
+/// conversion is requested.
+pub fn write_number<T: itoa::Integer + NumAssign + Signed + PartialOrd + ToPrimitive>(
+    w: &mut impl fmt::Write,
     n: T,
     opt: DisplayOptions,
-) -> String {
+) -> fmt::Result {
+    let mut root_buffer = itoa::Buffer::new();
+    let mut roman_buffer = StackStr::<64>::new();
     let root = match opt.numerals {
         Some(Numerals::Roman) => {
             if n > T::zero() && n.to_i16().is_some() {
-                format!("{:X}", Roman::from(n.to_i16().expect("Checked in if")))
-            } else {
-                "".to_string()
+                let r = Roman::from(n.to_i16().expect("Checked in if"));
+                write!(roman_buffer, "{:X}", r)?;
             }
+            roman_buffer.as_str()
         }
-        _ => {
-            let mut root_buffer = itoa::Buffer::new();
-            root_buffer.format(n.abs()).to_string()
-        }
+        _ => root_buffer.format(n.abs()),
     };
     let prefix = match (opt.sign, n >= T::zero()) {
         (Sign::Always, true) => "+",
@@ -193,31 +276,118 @@ pub fn fmt_number<T: itoa::Integer + NumAssign + Signed + PartialOrd + ToPrimiti
         (Sign::OnlyNegative, false) => "-",
         (Sign::Never, _) => "",
     };
-    let mut joined = String::from(prefix);
+    let mut joined = StackStr::<128>::new();
+    joined.write_str(prefix)?;
     if opt.padding == Some('0') && opt.align.unwrap_or(Align::Left) == Align::Left {
         let non_pad_width = prefix.len() + root.len();
         let arg_width = opt.width.unwrap_or(non_pad_width);
         let pad_width = max(arg_width, non_pad_width) - non_pad_width;
-        let padding = std::iter::repeat('0').take(pad_width).collect::<String>();
-        joined.push_str(&padding);
+        write_repeat(&mut joined, '0', pad_width)?;
+    }
+    joined.write_str(root)?;
+    let joined = joined.as_str();
+    if opt.padding == Some('0') {
+        //Zero-padded numbers are never truncated, so the width is only a minimum
+        let width = opt.width.map(|w| max(w, joined.len()));
+        write_string(w, joined, DisplayOptions { width, ..opt })
+    } else {
+        write_string(w, joined, opt)
     }
-    joined.push_str(&root);
-    fmt_string(&joined, opt)
 }
 
-pub fn fmt_days_since_epoch<T: Epoch + ToFixed>(t: T, opt: DisplayOptions) -> String {
-    fmt_number(t.to_fixed().get_day_i() - T::epoch().get_day_i(), opt)
+pub fn fmt_number<T: itoa::Integer + NumAssign + Signed + PartialOrd + ToPrimitive>(
+    n: T,
+    opt: DisplayOptions,
+) -> String {
+    let mut result = String::new();
+    write_number(&mut result, n, opt).expect("Number fits in buffer");
+    result
 }
 
-pub fn fmt_seconds_since_epoch<T: Epoch + ToFixed>(t: T, opt: DisplayOptions) -> String {
-    fmt_number(
-        ((t.to_fixed().get() - T::epoch().get()) * (24.0 * 60.0 * 60.0)) as i64,
+pub fn write_days_since_epoch<T: Epoch + ToFixed>(
+    w: &mut impl fmt::Write,
+    t: T,
+    opt: DisplayOptions,
+) -> fmt::Result {
+    write_number(w, t.to_fixed().get_day_i() - T::epoch().get_day_i(), opt)
+}
+
+pub fn write_seconds_since_epoch<T: Epoch + ToFixed>(
+    w: &mut impl fmt::Write,
+    t: T,
+    opt: DisplayOptions,
+) -> fmt::Result {
+    write_number(
+        w,
+        day_fraction_to_seconds(t.to_fixed().get() - T::epoch().get()) as i64,
         opt,
     )
 }
 
-pub fn fmt_quarter<T: Quarter>(t: T, opt: DisplayOptions) -> String {
-    fmt_number(t.quarter().get() as i16, opt)
+pub fn write_quarter<T: Quarter>(
+    w: &mut impl fmt::Write,
+    t: T,
+    opt: DisplayOptions,
+) -> fmt::Result {
+    write_number(w, t.quarter().get() as i16, opt)
+}
+
+pub fn write_half_year<T: Quarter>(
+    w: &mut impl fmt::Write,
+    t: T,
+    opt: DisplayOptions,
+) -> fmt::Result {
+    let half = if t.quarter().get() <= 2 { 1 } else { 2 };
+    write_number(w, half, opt)
+}
+
+pub fn write_week_of_month_name(
+    w: &mut impl fmt::Write,
+    week: Option<u8>,
+    lang: Language,
+    opt: DisplayOptions,
+) -> fmt::Result {
+    match (week, get_table(lang, |d| d.week_of_month.as_ref())) {
+        (Some(week), Some(dict)) => {
+            let names: [&str; 6] = [
+                dict.first,
+                dict.second,
+                dict.third,
+                dict.fourth,
+                dict.fifth,
+                dict.sixth,
+            ];
+            match week {
+                1..=6 => write_string(w, names[(week as usize) - 1], opt),
+                _ => write_number(w, week as i16, opt),
+            }
+        }
+        (_, _) => Ok(()),
+    }
+}
+
+//Writes the connector followed by a space, unless the connector is elided
+fn write_week_of_month_connector(
+    w: &mut impl fmt::Write,
+    month_name: &str,
+    lang: Language,
+    opt: DisplayOptions,
+) -> fmt::Result {
+    let starts_with_vowel = month_name
+        .chars()
+        .next()
+        .and_then(|c| c.to_lowercase().next())
+        .is_some_and(|c| "aeiouàâäéèêëîïôöùûü".contains(c));
+    match get_table(lang, |d| d.week_of_month.as_ref()) {
+        Some(dict) => match dict.of_month_elided {
+            Some(elided) if starts_with_vowel => write_string(w, elided, opt),
+            _ => {
+                write_string(w, dict.of_month, opt)?;
+                w.write_str(" ")
+            }
+        },
+        None => Ok(()),
+    }
 }
 
 impl DisplayItem for CommonDate {
@@ -225,16 +395,27 @@ impl DisplayItem for CommonDate {
         true
     }
 
-    fn fmt_numeric(&self, n: NumericContent, opt: DisplayOptions) -> String {
+    fn fmt_numeric(
+        &self,
+        w: &mut impl fmt::Write,
+        n: NumericContent,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
         match n {
-            NumericContent::Month => fmt_number(self.month as i16, opt),
-            NumericContent::DayOfMonth => fmt_number(self.day as i16, opt),
-            NumericContent::Year => fmt_number(self.year, opt),
-            _ => String::from(""),
+            NumericContent::Month => write_number(w, self.month as i16, opt),
+            NumericContent::DayOfMonth => write_number(w, self.day as i16, opt),
+            NumericContent::Year => write_number(w, self.year, opt),
+            _ => Ok(()),
         }
     }
-    fn fmt_text(&self, _t: TextContent, lang: Language, _opt: DisplayOptions) -> String {
-        String::from("")
+    fn fmt_text(
+        &self,
+        w: &mut impl fmt::Write,
+        _t: TextContent,
+        lang: Language,
+        _opt: DisplayOptions,
+    ) -> fmt::Result {
+        Ok(())
     }
 }
 
@@ -243,18 +424,40 @@ impl DisplayItem for OrdinalDate {
         true
     }
 
-    fn fmt_numeric(&self, n: NumericContent, opt: DisplayOptions) -> String {
+    fn fmt_numeric(
+        &self,
+        w: &mut impl fmt::Write,
+        n: NumericContent,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
         match n {
-            NumericContent::DayOfYear => fmt_number(self.day_of_year as i16, opt),
-            NumericContent::Year => fmt_number(self.year, opt),
-            _ => String::from(""),
+            NumericContent::DayOfYear => write_number(w, self.day_of_year as i16, opt),
+            NumericContent::Year => write_number(w, self.year, opt),
+            _ => Ok(()),
         }
     }
-    fn fmt_text(&self, _t: TextContent, lang: Language, _opt: DisplayOptions) -> String {
-        String::from("")
+    fn fmt_text(
+        &self,
+        w: &mut impl fmt::Write,
+        _t: TextContent,
+        lang: Language,
+        _opt: DisplayOptions,
+    ) -> fmt::Result {
+        Ok(())
     }
 }
 
+impl PresetDisplay for OrdinalDate {}
+
+impl fmt::Display for OrdinalDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_into(f, Language::EN, YYYYOOO_DASH)
+    }
+}
+
+/// Every supported language, in the order used when parsing text in any language
+pub const LANGUAGES: [Language; 2] = [Language::EN, Language::FR];
+
 pub fn get_dict(lang: Language) -> &'static Dictionary<'static> {
     match (lang) {
         Language::EN => &EN_DICTIONARY,
@@ -262,10 +465,88 @@ pub fn get_dict(lang: Language) -> &'static Dictionary<'static> {
     }
 }
 
+/// Get a table from the dictionary of a language, falling back to English
+///
+/// Each table is looked up separately, so a language which is missing some tables can
+/// still be used for the rest.
+pub fn get_table<T>(
+    lang: Language,
+    f: fn(&'static Dictionary<'static>) -> Option<&'static T>,
+) -> Option<&'static T> {
+    fallback_table(get_dict(lang), f)
+}
+
+fn fallback_table<T>(
+    dict: &'static Dictionary<'static>,
+    f: fn(&'static Dictionary<'static>) -> Option<&'static T>,
+) -> Option<&'static T> {
+    f(dict).or_else(|| f(&EN_DICTIONARY))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn fallback() {
+        static EMPTY: Dictionary = Dictionary {
+            akan_cycle: None,
+            armenian: None,
+            attic: None,
+            babylonian: None,
+            bengali: None,
+            coligny: None,
+            common_clock: None,
+            coptic: None,
+            cotsworth: None,
+            egyptian: None,
+            ethiopic: None,
+            french_rev: None,
+            gregorian: None,
+            holocene: None,
+            islamic: None,
+            iso: None,
+            julian: None,
+            pataphysical: None,
+            persian: None,
+            positivist: None,
+            retail: None,
+            roman: None,
+            shire: None,
+            symmetry: None,
+            tamil: None,
+            tranquility: None,
+            common_weekday: None,
+            week_of_month: None,
+        };
+        let g = fallback_table(&EMPTY, |d| d.gregorian.as_ref()).unwrap();
+        assert_eq!(g.july, "July");
+        let g = get_table(Language::FR, |d| d.gregorian.as_ref()).unwrap();
+        assert_eq!(g.july, "juillet");
+        let r = get_table(Language::FR, |d| d.roman.as_ref()).unwrap();
+        assert_eq!(r.ides, "Ides");
+    }
+
+    #[test]
+    fn fmt_into_stack_str() {
+        let ord = OrdinalDate {
+            year: 2025,
+            day_of_year: 7,
+        };
+        let mut buf = StackStr::<8>::new();
+        ord.fmt_into(&mut buf, Language::EN, YYYYOOO_DASH).unwrap();
+        assert_eq!(buf.as_str(), "2025-007");
+    }
+
+    #[test]
+    fn stack_str() {
+        let mut buf = StackStr::<4>::new();
+        assert!(write!(buf, "{}", 123).is_ok());
+        assert_eq!(buf.as_str(), "123");
+        assert!(buf.write_str("45").is_err());
+        assert_eq!(buf.as_str(), "123");
+    }
+
     #[test]
     fn basic_number() {
         let opt_0 = DisplayOptions {
@@ -298,6 +579,16 @@ mod tests {
         };
         assert_eq!(fmt_number(2025, opt_2), "2025");
         assert_eq!(fmt_number(-2025, opt_2), "-2025");
+        let opt_3 = DisplayOptions {
+            numerals: None,
+            width: Some(4),
+            align: None,
+            padding: Some('0'),
+            case: None,
+            sign: Sign::Always,
+        };
+        assert_eq!(fmt_number(5, opt_3), "+005");
+        assert_eq!(fmt_number(-12345, opt_3), "-12345");
     }
 
     #[test]
@@ -313,6 +604,29 @@ mod tests {
         assert_eq!(fmt_string("January", opt_0), "January");
     }
 
+    #[test]
+    fn week_of_month_text() {
+        let opt_0 = DisplayOptions {
+            numerals: None,
+            width: None,
+            align: None,
+            padding: None,
+            case: None,
+            sign: Sign::Never,
+        };
+        let mut s = String::new();
+        write_week_of_month_name(&mut s, Some(6), Language::FR, opt_0).unwrap();
+        assert_eq!(s, "sixième");
+        for (month, expected) in [("avril", "d'"), ("Octobre", "d'"), ("juillet", "de ")] {
+            let mut s = String::new();
+            write_week_of_month_connector(&mut s, month, Language::FR, opt_0).unwrap();
+            assert_eq!(s, expected);
+        }
+        let mut s = String::new();
+        write_week_of_month_connector(&mut s, "April", Language::EN, opt_0).unwrap();
+        assert_eq!(s, "of ");
+    }
+
     #[test]
     fn case_text() {
         let opt_0 = DisplayOptions {
@@ -502,4 +816,18 @@ mod tests {
         assert_eq!(fmt_string("😀", opt_1), "😀");
         assert_eq!(fmt_string("😀😂", opt_1), "😀");
     }
+
+    #[test]
+    fn ordinal_date() {
+        let ord = OrdinalDate {
+            year: 2025,
+            day_of_year: 7,
+        };
+        assert_eq!(ord.to_string(), "2025-007");
+        let ord = OrdinalDate {
+            year: -44,
+            day_of_year: 75,
+        };
+        assert_eq!(ord.to_string(), "-044-075");
+    }
 }
@@ -0,0 +1,141 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::CommonWeekOfYear;
+use crate::calendar::Retail;
+use crate::calendar::Retail445;
+use crate::calendar::Retail454;
+use crate::calendar::Retail544;
+use crate::calendar::ToFromCommonDate;
+use crate::calendar::ToFromOrdinalDate;
+use crate::clock::TimeOfDay;
+use crate::day_count::ToFixed;
+use crate::day_cycle::Weekday;
+use crate::display::moment::DisplayMomentItem;
+use crate::display::prelude::PresetDisplay;
+use crate::display::private::get_dict;
+use crate::display::private::get_table;
+use crate::display::private::write_days_since_epoch;
+use crate::display::private::write_half_year;
+use crate::display::private::write_number;
+use crate::display::private::write_quarter;
+use crate::display::private::write_seconds_since_epoch;
+use crate::display::private::write_string;
+use crate::display::private::write_week_of_month_name;
+use crate::display::private::DisplayItem;
+use crate::display::private::DisplayOptions;
+use crate::display::private::NumericContent;
+use crate::display::private::StackStr;
+use crate::display::private::TextContent;
+use crate::display::text::prelude::Language;
+use core::fmt;
+use core::fmt::Write;
+
+impl<const P: u16, const M: u8, const W: u8, const N: bool> DisplayItem for Retail<P, M, W, N> {
+    fn supported_lang(lang: Language) -> bool {
+        get_dict(lang).retail.as_ref().is_some()
+    }
+
+    fn fmt_numeric(
+        &self,
+        w: &mut impl fmt::Write,
+        n: NumericContent,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
+        match n {
+            NumericContent::Month | NumericContent::DayOfMonth | NumericContent::Year => {
+                self.to_common_date().fmt_numeric(w, n, opt)
+            }
+            NumericContent::DayOfWeek => self.convert::<Weekday>().fmt_numeric(w, n, opt),
+            NumericContent::DayOfYear => self.to_ordinal().fmt_numeric(w, n, opt),
+            NumericContent::Hour1to12
+            | NumericContent::Hour0to23
+            | NumericContent::Minute
+            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(w, n, opt),
+            NumericContent::SecondsSinceEpoch => write_seconds_since_epoch(w, *self, opt),
+            NumericContent::Quarter => write_quarter(w, *self, opt),
+            NumericContent::HalfYear => write_half_year(w, *self, opt),
+            NumericContent::DaysSinceEpoch => write_days_since_epoch(w, *self, opt),
+            NumericContent::ComplementaryDay => Ok(()),
+            NumericContent::WeekOfYear => write_number(w, self.week_of_year() as i16, opt),
+            NumericContent::WeekOfMonth => write_number(w, self.week_of_month() as i16, opt),
+            NumericContent::DayOfMonthWeek => write_number(w, self.day_of_month_week() as i16, opt),
+        }
+    }
+
+    fn fmt_text(
+        &self,
+        w: &mut impl fmt::Write,
+        t: TextContent,
+        lang: Language,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
+        match (t, get_table(lang, |d| d.retail.as_ref())) {
+            (TextContent::MonthName, Some(dict)) => {
+                let mut name = StackStr::<64>::new();
+                write!(name, "{} {}", dict.period, self.to_common_date().month)
+                    .expect("Period name fits in buffer");
+                write_string(w, name.as_str(), opt)
+            }
+            (TextContent::DayOfMonthName, _) => write_string(w, "", opt),
+            (TextContent::DayOfWeekName, _) => self.convert::<Weekday>().fmt_text(w, t, lang, opt),
+            (
+                TextContent::HalfDayName | TextContent::HalfDayAbbrev | TextContent::PartOfDayName,
+                _,
+            ) => self.convert::<TimeOfDay>().fmt_text(w, t, lang, opt),
+            (TextContent::EraName, Some(dict)) => write_string(w, dict.fiscal_year_full, opt),
+            (TextContent::EraAbbreviation, Some(dict)) => {
+                write_string(w, dict.fiscal_year_abr, opt)
+            }
+            (TextContent::WeekOfMonthName, _) => {
+                write_week_of_month_name(w, Some(self.week_of_month()), lang, opt)
+            }
+            (_, _) => Ok(()),
+        }
+    }
+}
+
+impl<const P: u16, const M: u8, const W: u8, const N: bool> PresetDisplay for Retail<P, M, W, N> {}
+
+impl<const P: u16, const M: u8, const W: u8, const N: bool> fmt::Display for Retail<P, M, W, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_into(f, Language::EN, self.long_date_preset())
+    }
+}
+
+impl DisplayMomentItem for Retail445 {}
+impl DisplayMomentItem for Retail454 {}
+impl DisplayMomentItem for Retail544 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::CommonDate;
+    use crate::display::FISCAL_YEAR;
+    use crate::display::LONG_DATE_ERA_ABBR;
+
+    #[test]
+    fn expected_languages() {
+        assert!(Retail445::supported_lang(Language::EN));
+        assert!(Retail445::supported_lang(Language::FR));
+        assert!(Retail454::supported_lang(Language::EN));
+        assert!(Retail454::supported_lang(Language::FR));
+        assert!(Retail544::supported_lang(Language::EN));
+        assert!(Retail544::supported_lang(Language::FR));
+    }
+
+    #[test]
+    fn period_name() {
+        let d = Retail454::try_from_common_date(CommonDate::new(2025, 7, 12)).unwrap();
+        let s = d.preset_str(Language::EN, LONG_DATE_ERA_ABBR);
+        assert_eq!(&s, "Thursday Period 7 12, 2025 FY");
+    }
+
+    #[test]
+    fn fiscal_year() {
+        let d = Retail454::try_from_common_date(CommonDate::new(2026, 1, 1)).unwrap();
+        assert_eq!(d.preset_str(Language::EN, FISCAL_YEAR), "FY2026");
+        assert_eq!(d.preset_str(Language::FR, FISCAL_YEAR), "Ex.2026");
+    }
+}
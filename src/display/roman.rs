@@ -6,8 +6,8 @@ use crate::calendar::Roman;
 use crate::calendar::RomanMonthlyEvent;
 use crate::display::private::get_dict;
 use crate::display::text::prelude::Language;
+use core::fmt;
 use numerals;
-use std::fmt;
 
 impl fmt::Display for Roman {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
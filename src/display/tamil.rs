@@ -0,0 +1,134 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::CommonWeekOfYear;
+use crate::calendar::Tamil;
+use crate::calendar::ToFromCommonDate;
+use crate::calendar::ToFromOrdinalDate;
+use crate::clock::TimeOfDay;
+use crate::day_count::ToFixed;
+use crate::day_cycle::Weekday;
+use crate::display::moment::DisplayMomentItem;
+use crate::display::prelude::PresetDisplay;
+use crate::display::private::get_dict;
+use crate::display::private::get_table;
+use crate::display::private::write_days_since_epoch;
+use crate::display::private::write_half_year;
+use crate::display::private::write_number;
+use crate::display::private::write_quarter;
+use crate::display::private::write_seconds_since_epoch;
+use crate::display::private::write_string;
+use crate::display::private::write_week_of_month_name;
+use crate::display::private::DisplayItem;
+use crate::display::private::DisplayOptions;
+use crate::display::private::NumericContent;
+use crate::display::private::TextContent;
+use crate::display::text::prelude::Language;
+use core::fmt;
+
+impl DisplayItem for Tamil {
+    fn supported_lang(lang: Language) -> bool {
+        get_dict(lang).tamil.as_ref().is_some()
+    }
+
+    fn fmt_numeric(
+        &self,
+        w: &mut impl fmt::Write,
+        n: NumericContent,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
+        match n {
+            NumericContent::Month | NumericContent::DayOfMonth | NumericContent::Year => {
+                self.to_common_date().fmt_numeric(w, n, opt)
+            }
+            NumericContent::DayOfWeek => self.convert::<Weekday>().fmt_numeric(w, n, opt),
+            NumericContent::DayOfYear => self.to_ordinal().fmt_numeric(w, n, opt),
+            NumericContent::Hour1to12
+            | NumericContent::Hour0to23
+            | NumericContent::Minute
+            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(w, n, opt),
+            NumericContent::SecondsSinceEpoch => write_seconds_since_epoch(w, *self, opt),
+            NumericContent::Quarter => write_quarter(w, *self, opt),
+            NumericContent::HalfYear => write_half_year(w, *self, opt),
+            NumericContent::DaysSinceEpoch => write_days_since_epoch(w, *self, opt),
+            NumericContent::ComplementaryDay => Ok(()),
+            NumericContent::WeekOfYear => write_number(w, self.week_of_year() as i16, opt),
+            NumericContent::WeekOfMonth => write_number(w, self.week_of_month() as i16, opt),
+            NumericContent::DayOfMonthWeek => write_number(w, self.day_of_month_week() as i16, opt),
+        }
+    }
+
+    fn fmt_text(
+        &self,
+        w: &mut impl fmt::Write,
+        t: TextContent,
+        lang: Language,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
+        match (t, get_table(lang, |d| d.tamil.as_ref())) {
+            (TextContent::MonthName, Some(dict)) => {
+                let months: [&str; 12] = [
+                    dict.chithirai,
+                    dict.vaikasi,
+                    dict.aani,
+                    dict.aadi,
+                    dict.aavani,
+                    dict.purattasi,
+                    dict.aippasi,
+                    dict.karthigai,
+                    dict.margazhi,
+                    dict.thai,
+                    dict.maasi,
+                    dict.panguni,
+                ];
+                let name = months[self.to_common_date().month as usize - 1];
+                write_string(w, name, opt)
+            }
+            (TextContent::DayOfWeekName, _) => self.convert::<Weekday>().fmt_text(w, t, lang, opt),
+            (
+                TextContent::HalfDayName | TextContent::HalfDayAbbrev | TextContent::PartOfDayName,
+                _,
+            ) => self.convert::<TimeOfDay>().fmt_text(w, t, lang, opt),
+            (TextContent::EraName, Some(dict)) => {
+                if self.to_common_date().year < 0 {
+                    write_string(w, dict.before_epoch_full, opt)
+                } else {
+                    write_string(w, dict.after_epoch_full, opt)
+                }
+            }
+            (TextContent::EraAbbreviation, Some(dict)) => {
+                if self.to_common_date().year < 0 {
+                    write_string(w, dict.before_epoch_abr, opt)
+                } else {
+                    write_string(w, dict.after_epoch_abr, opt)
+                }
+            }
+            (TextContent::WeekOfMonthName, _) => {
+                write_week_of_month_name(w, Some(self.week_of_month()), lang, opt)
+            }
+            (_, _) => Ok(()),
+        }
+    }
+}
+
+impl PresetDisplay for Tamil {}
+
+impl fmt::Display for Tamil {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_into(f, Language::EN, self.long_date_preset())
+    }
+}
+
+impl DisplayMomentItem for Tamil {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expected_languages() {
+        assert!(Tamil::supported_lang(Language::EN));
+        assert!(Tamil::supported_lang(Language::FR));
+    }
+}
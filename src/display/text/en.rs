@@ -74,11 +74,81 @@ pub const EN_DICTIONARY: Dictionary = Dictionary {
         before_epoch_abr: "BAE",
         after_epoch_abr: "AE",
     }),
+    attic: Some(AtticDictionary {
+        hekatombaion: "Hekatombaion",
+        metageitnion: "Metageitnion",
+        boedromion: "Boedromion",
+        pyanepsion: "Pyanepsion",
+        maimakterion: "Maimakterion",
+        poseideon: "Poseideon",
+        second_poseideon: "Poseideon II",
+        gamelion: "Gamelion",
+        anthesterion: "Anthesterion",
+        elaphebolion: "Elaphebolion",
+        mounichion: "Mounichion",
+        thargelion: "Thargelion",
+        skirophorion: "Skirophorion",
+    }),
+    babylonian: Some(BabylonianDictionary {
+        nisannu: "Nisannu",
+        ayyaru: "Ayyaru",
+        simanu: "Simanu",
+        duzu: "Duzu",
+        abu: "Abu",
+        ululu: "Ululu",
+        second_ululu: "Ululu II",
+        tashritu: "Tashritu",
+        arahsamnu: "Arahsamnu",
+        kislimu: "Kislimu",
+        tebetu: "Tebetu",
+        shabatu: "Shabatu",
+        addaru: "Addaru",
+        second_addaru: "Addaru II",
+    }),
+    bengali: Some(BengaliDictionary {
+        boishakh: "Boishakh",
+        joishtho: "Joishtho",
+        asharh: "Asharh",
+        shrabon: "Shrabon",
+        bhadro: "Bhadro",
+        ashshin: "Ashshin",
+        kartik: "Kartik",
+        ogrohayon: "Ogrohayon",
+        poush: "Poush",
+        magh: "Magh",
+        falgun: "Falgun",
+        choitro: "Choitro",
+        before_epoch_full: "Before Bangabda", //TODO: find the correct one
+        after_epoch_full: "Bangabda",
+        before_epoch_abr: "BB", //TODO: find the correct one
+        after_epoch_abr: "BS",
+    }),
+    coligny: Some(ColignyDictionary {
+        //The Gaulish names are used as-is
+        quimonios: "Quimonios",
+        samonios: "Samonios",
+        dumannios: "Dumannios",
+        riuros: "Riuros",
+        anagantios: "Anagantios",
+        ogronnios: "Ogronnios",
+        cutios: "Cutios",
+        rantaranos: "Rantaranos",
+        giamonios: "Giamonios",
+        simivisonnos: "Simivisonnos",
+        equos: "Equos",
+        elembivos: "Elembivos",
+        edrinios: "Edrinios",
+        cantlos: "Cantlos",
+    }),
     common_clock: Some(CommonClockDictionary {
         am_full: "Ante Meridiem",
         pm_full: "Post Meridiem",
         am_abr: "AM",
         pm_abr: "PM",
+        night: "Night",
+        morning: "Morning",
+        afternoon: "Afternoon",
+        evening: "Evening",
     }),
     coptic: Some(CopticDictionary {
         thoout: "Thoout",
@@ -254,6 +324,20 @@ pub const EN_DICTIONARY: Dictionary = Dictionary {
         before_human_era_abr: "BHE",
         human_era_abr: "HE",
     }),
+    islamic: Some(IslamicDictionary {
+        muharram: "Muharram",
+        safar: "Safar",
+        rabi_al_awwal: "Rabi al-Awwal",
+        rabi_al_thani: "Rabi al-Thani",
+        jumada_al_ula: "Jumada al-Ula",
+        jumada_al_akhira: "Jumada al-Akhira",
+        rajab: "Rajab",
+        shaban: "Shaban",
+        ramadan: "Ramadan",
+        shawwal: "Shawwal",
+        dhu_al_qadah: "Dhu al-Qadah",
+        dhu_al_hijjah: "Dhu al-Hijjah",
+    }),
     iso: Some(ISODictionary {
         before_epoch_full: "Before ISO Era", //TODO: what to call this?
         after_epoch_full: "ISO Era",         //TODO: And this??
@@ -278,6 +362,39 @@ pub const EN_DICTIONARY: Dictionary = Dictionary {
         anno_domini_full: "Anno Domini",
         before_christ_abr: "BC",
         anno_domini_abr: "AD",
+        //Dual dating
+        old_style_abr: "O.S.",
+        new_style_abr: "N.S.",
+    }),
+    pataphysical: Some(PataphysicalDictionary {
+        //The original French names are used as-is
+        absolu: "Absolu",
+        haha: "Haha",
+        r#as: "As",
+        sable: "Sable",
+        decervelage: "Décervelage",
+        gueules: "Gueules",
+        pedale: "Pédale",
+        clinamen: "Clinamen",
+        palotin: "Palotin",
+        merdre: "Merdre",
+        gidouille: "Gidouille",
+        tatane: "Tatane",
+        phalle: "Phalle",
+    }),
+    persian: Some(PersianDictionary {
+        farvardin: "Farvardin",
+        ordibehesht: "Ordibehesht",
+        khordad: "Khordad",
+        tir: "Tir",
+        mordad: "Mordad",
+        shahrivar: "Shahrivar",
+        mehr: "Mehr",
+        aban: "Aban",
+        azar: "Azar",
+        dey: "Dey",
+        bahman: "Bahman",
+        esfand: "Esfand",
     }),
     positivist: Some(PositivistDictionary {
         moses: "Moses",
@@ -300,6 +417,11 @@ pub const EN_DICTIONARY: Dictionary = Dictionary {
         festival_of_dead: "Festival of the Dead",
         festival_of_holy_women: "Festival of Holy Women",
     }),
+    retail: Some(RetailDictionary {
+        period: "Period",
+        fiscal_year_full: "Fiscal Year",
+        fiscal_year_abr: "FY",
+    }),
     roman: Some(RomanDictionary {
         //https://www.reddit.com/r/AskHistorians/comments/hhtz06/how_did_romans_describe_dates_before_the_founding/
         //Seems like the Romans didn't bother using AUC for dates before Rome's founding
@@ -331,6 +453,34 @@ pub const EN_DICTIONARY: Dictionary = Dictionary {
         bissextum: "bissextum",
         x_of_y: "of", //TODO: more robust system than inserting "of"
     }),
+    shire: Some(ShireDictionary {
+        //Appendix D of *The Lord of the Rings*
+        afteryule: "Afteryule",
+        solmath: "Solmath",
+        rethe: "Rethe",
+        astron: "Astron",
+        thrimidge: "Thrimidge",
+        forelithe: "Forelithe",
+        afterlithe: "Afterlithe",
+        wedmath: "Wedmath",
+        halimath: "Halimath",
+        winterfilth: "Winterfilth",
+        blotmath: "Blotmath",
+        foreyule: "Foreyule",
+        sterday: "Sterday",
+        sunday: "Sunday",
+        monday: "Monday",
+        trewsday: "Trewsday",
+        hevensday: "Hevensday",
+        mersday: "Mersday",
+        highday: "Highday",
+        second_yule: "2 Yule",
+        first_lithe: "1 Lithe",
+        mid_years_day: "Mid-year's Day",
+        overlithe: "Overlithe",
+        second_lithe: "2 Lithe",
+        first_yule: "1 Yule",
+    }),
     symmetry: Some(SymmetryDictionary {
         january: "January",
         february: "February",
@@ -350,6 +500,24 @@ pub const EN_DICTIONARY: Dictionary = Dictionary {
         before_epoch_abr: "BSE",
         after_epoch_abr: "SE",
     }),
+    tamil: Some(TamilDictionary {
+        chithirai: "Chithirai",
+        vaikasi: "Vaikasi",
+        aani: "Aani",
+        aadi: "Aadi",
+        aavani: "Aavani",
+        purattasi: "Purattasi",
+        aippasi: "Aippasi",
+        karthigai: "Karthigai",
+        margazhi: "Margazhi",
+        thai: "Thai",
+        maasi: "Maasi",
+        panguni: "Panguni",
+        before_epoch_full: "Before Saka Era",
+        after_epoch_full: "Saka Era",
+        before_epoch_abr: "BSE",
+        after_epoch_abr: "SE",
+    }),
     tranquility: Some(TranquilityDictionary {
         archimedes: "Archimedes",
         brahe: "Brahe",
@@ -381,4 +549,14 @@ pub const EN_DICTIONARY: Dictionary = Dictionary {
         friday: "Friday",
         saturday: "Saturday",
     }),
+    week_of_month: Some(WeekOfMonthDictionary {
+        first: "first",
+        second: "second",
+        third: "third",
+        fourth: "fourth",
+        fifth: "fifth",
+        sixth: "sixth",
+        of_month: "of",
+        of_month_elided: None,
+    }),
 };
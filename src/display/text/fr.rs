@@ -5,15 +5,157 @@
 use crate::display::text::prelude::*;
 
 pub const FR_DICTIONARY: Dictionary = Dictionary {
-    akan_cycle: None,
-    armenian: None,
+    akan_cycle: None, //The Twi names are used as-is, so the English table applies
+    armenian: Some(ArmenianDictionary {
+        //The Hübschmann-Meillet transliterations are used as-is
+        //https://fr.wikipedia.org/wiki/Calendrier_arm%C3%A9nien
+        nawasardi: "Nawasard",
+        hori: "Hoṙi",
+        sahmi: "Sahmi",
+        tre: "Trē",
+        kaloch: "Kʿałocʿ",
+        arach: "Aracʿ",
+        mehekani: "Mehekan",
+        areg: "Areg",
+        ahekani: "Ahekan",
+        mareri: "Mareri",
+        margach: "Margacʿ",
+        hrotich: "Hroticʿ",
+        aweleac: "aweleacʿ",
+        areg_day: "Areg",
+        hrand: "Hrand",
+        aram: "Aram",
+        margar: "Margar",
+        ahrank: "Ahrank’",
+        mazdel: "Mazdeł",
+        astlik: "Astłik",
+        mihr: "Mihr",
+        jopaber: "Jopaber",
+        murc: "Murç",
+        erezhan: "Erezhan",
+        ani: "Ani",
+        parkhar: "Parkhar",
+        vanat: "Vanat",
+        aramazd: "Aramazd",
+        mani: "Mani",
+        asak: "Asak",
+        masis: "Masis",
+        anahit: "Anahit",
+        aragats: "Aragats",
+        gorgor: "Gorgor",
+        kordvik: "Kordvik",
+        tsmak: "Tsmak",
+        lusnak: "Lusnak",
+        tsron: "Tsrōn",
+        npat: "Npat",
+        vahagn: "Vahagn",
+        sim: "Sim",
+        varag: "Varag",
+        giseravar: "Gišeravar",
+        before_epoch_full: "avant l'ère arménienne",
+        after_epoch_full: "l'ère arménienne",
+        before_epoch_abr: "AEA",
+        after_epoch_abr: "EA",
+    }),
+    attic: Some(AtticDictionary {
+        hekatombaion: "Hécatombéon",
+        metageitnion: "Métageitnion",
+        boedromion: "Boédromion",
+        pyanepsion: "Pyanepsion",
+        maimakterion: "Maimactérion",
+        poseideon: "Poséidéon",
+        second_poseideon: "Poséidéon II",
+        gamelion: "Gamélion",
+        anthesterion: "Anthestérion",
+        elaphebolion: "Élaphébolion",
+        mounichion: "Mounychion",
+        thargelion: "Thargélion",
+        skirophorion: "Scirophorion",
+    }),
+    babylonian: Some(BabylonianDictionary {
+        //The transliterations are the same as in English
+        nisannu: "Nisannu",
+        ayyaru: "Ayyaru",
+        simanu: "Simanu",
+        duzu: "Duzu",
+        abu: "Abu",
+        ululu: "Ululu",
+        second_ululu: "Ululu II",
+        tashritu: "Tashritu",
+        arahsamnu: "Arahsamnu",
+        kislimu: "Kislimu",
+        tebetu: "Tebetu",
+        shabatu: "Shabatu",
+        addaru: "Addaru",
+        second_addaru: "Addaru II",
+    }),
+    bengali: Some(BengaliDictionary {
+        //The transliterations are used as-is
+        boishakh: "Boishakh",
+        joishtho: "Joishtho",
+        asharh: "Asharh",
+        shrabon: "Shrabon",
+        bhadro: "Bhadro",
+        ashshin: "Ashshin",
+        kartik: "Kartik",
+        ogrohayon: "Ogrohayon",
+        poush: "Poush",
+        magh: "Magh",
+        falgun: "Falgun",
+        choitro: "Choitro",
+        before_epoch_full: "avant le Bangabda", //TODO: find the correct one
+        after_epoch_full: "Bangabda",
+        before_epoch_abr: "av. BS", //TODO: find the correct one
+        after_epoch_abr: "BS",
+    }),
+    coligny: Some(ColignyDictionary {
+        //The Gaulish names are used as-is
+        quimonios: "Quimonios",
+        samonios: "Samonios",
+        dumannios: "Dumannios",
+        riuros: "Riuros",
+        anagantios: "Anagantios",
+        ogronnios: "Ogronnios",
+        cutios: "Cutios",
+        rantaranos: "Rantaranos",
+        giamonios: "Giamonios",
+        simivisonnos: "Simivisonnos",
+        equos: "Equos",
+        elembivos: "Elembivos",
+        edrinios: "Edrinios",
+        cantlos: "Cantlos",
+    }),
     common_clock: Some(CommonClockDictionary {
         am_full: "Ante Meridiem",
         pm_full: "Post Meridiem",
         am_abr: "AM",
         pm_abr: "PM",
+        night: "Nuit",
+        morning: "Matin",
+        afternoon: "Après-midi",
+        evening: "Soir",
+    }),
+    coptic: Some(CopticDictionary {
+        //The transliterations are used as-is
+        //https://fr.wikipedia.org/wiki/Calendrier_copte
+        thoout: "Thoout",
+        paope: "Paope",
+        athor: "Athor",
+        koiak: "Koiak",
+        tobe: "Tobe",
+        meshir: "Meshir",
+        paremotep: "Paremotep",
+        parmoute: "Parmoute",
+        pashons: "Pashons",
+        paone: "Paone",
+        epep: "Epep",
+        mesore: "Mesore",
+        epagomene: "Épagomène",
+        before_martyrs_full: "avant l'ère des Martyrs", //TODO: find the correct one
+        after_martyrs_full: "l'ère des Martyrs",
+        before_martyrs_abr: "AEM", //TODO: find the correct one
+        after_martyrs_abr: "EM",
     }),
-    coptic: None,
     cotsworth: Some(CotsworthDictionary {
         //https://fr.wikipedia.org/wiki/Mois
         //https://fr.wikipedia.org/wiki/Calendrier_fixe
@@ -39,8 +181,51 @@ pub const FR_DICTIONARY: Dictionary = Dictionary {
         year_day: "Jour de l'année",    //TODO: find the correct one
         leap_day: "Journée bissextile", //TODO: find the correct one
     }),
-    egyptian: None,
-    ethiopic: None,
+    egyptian: Some(EgyptianDictionary {
+        //https://fr.wikipedia.org/wiki/Calendrier_%C3%A9gyptien
+        thoth: "Thot",
+        phaophi: "Phaophi",
+        athyr: "Athyr",
+        choiak: "Choiak",
+        tybi: "Tybi",
+        mechir: "Méchir",
+        phamenoth: "Phaménoth",
+        pharmuthi: "Pharmouthi",
+        pachon: "Pachons",
+        payni: "Payni",
+        epiphi: "Épiphi",
+        mesori: "Mésori",
+        epagomenae: "Épagomènes",
+        before_nabonassar_full: "avant l'ère de Nabonassar",
+        after_nabonassar_full: "l'ère de Nabonassar",
+        before_nabonassar_abr: "AEN",
+        after_nabonassar_abr: "EN",
+        birth_of_osiris: "Naissance d'Osiris",
+        birth_of_horus: "Naissance d'Horus",
+        birth_of_seth: "Naissance de Seth",
+        birth_of_isis: "Naissance d'Isis",
+        birth_of_nephthys: "Naissance de Nephthys",
+    }),
+    ethiopic: Some(EthiopicDictionary {
+        //The transliterations are used as-is
+        maskaram: "Mäskäräm",
+        teqemt: "Ṭəqəmt",
+        hedar: "Ḫədar",
+        takhsas: "Taḫśaś",
+        ter: "Ṭərr",
+        yakatit: "Yäkatit",
+        magabit: "Mägabit",
+        miyazya: "Miyazya",
+        genbot: "Gənbo",
+        sane: "Säne",
+        hamle: "Ḥamle",
+        nahase: "Nähase",
+        paguemen: "Ṗagʷəmen",
+        before_incarnation_full: "avant l'ère de l'Incarnation",
+        after_incarnation_full: "l'ère de l'Incarnation",
+        before_incarnation_abr: "AEI",
+        after_incarnation_abr: "EI",
+    }),
     french_rev: Some(FrenchRevolutionaryDictionary {
         vendemiaire: "Vendémiaire",
         brumaire: "Brumaire",
@@ -117,7 +302,26 @@ pub const FR_DICTIONARY: Dictionary = Dictionary {
         before_human_era_abr: "AEH",
         human_era_abr: "EH",
     }),
-    iso: None,
+    islamic: Some(IslamicDictionary {
+        muharram: "Mouharram",
+        safar: "Safar",
+        rabi_al_awwal: "Rabia al-Awal",
+        rabi_al_thani: "Rabia ath-Thani",
+        jumada_al_ula: "Joumada al-Oula",
+        jumada_al_akhira: "Joumada ath-Thania",
+        rajab: "Rajab",
+        shaban: "Chaabane",
+        ramadan: "Ramadan",
+        shawwal: "Chawwal",
+        dhu_al_qadah: "Dhou al-Qi'da",
+        dhu_al_hijjah: "Dhou al-Hijja",
+    }),
+    iso: Some(ISODictionary {
+        before_epoch_full: "avant l'ère ISO", //TODO: same problem as in English
+        after_epoch_full: "l'ère ISO",
+        before_epoch_abr: "AEI",
+        after_epoch_abr: "EI",
+    }),
     julian: Some(JulianDictionary {
         //https://fr.wikipedia.org/wiki/Mois
         //https://fr.wikipedia.org/wiki/%C3%88re_chr%C3%A9tienne
@@ -138,6 +342,38 @@ pub const FR_DICTIONARY: Dictionary = Dictionary {
         anno_domini_full: "après Jésus-Christ",
         before_christ_abr: "av. J.-C.",
         anno_domini_abr: "apr. J.-C.",
+        //Dual dating
+        old_style_abr: "v. st.",
+        new_style_abr: "n. st.",
+    }),
+    pataphysical: Some(PataphysicalDictionary {
+        absolu: "Absolu",
+        haha: "Haha",
+        r#as: "As",
+        sable: "Sable",
+        decervelage: "Décervelage",
+        gueules: "Gueules",
+        pedale: "Pédale",
+        clinamen: "Clinamen",
+        palotin: "Palotin",
+        merdre: "Merdre",
+        gidouille: "Gidouille",
+        tatane: "Tatane",
+        phalle: "Phalle",
+    }),
+    persian: Some(PersianDictionary {
+        farvardin: "Farvardin",
+        ordibehesht: "Ordibehecht",
+        khordad: "Khordad",
+        tir: "Tir",
+        mordad: "Mordad",
+        shahrivar: "Chahrivar",
+        mehr: "Mehr",
+        aban: "Aban",
+        azar: "Azar",
+        dey: "Dey",
+        bahman: "Bahman",
+        esfand: "Esfand",
     }),
     positivist: Some(PositivistDictionary {
         //https://gallica.bnf.fr/ark:/12148/bpt6k21868f/f42.planchecontact
@@ -162,7 +398,13 @@ pub const FR_DICTIONARY: Dictionary = Dictionary {
         festival_of_dead: "La Fête universelle des Morts",
         festival_of_holy_women: "La Fête Générale des Saintes Femmes",
     }),
-    roman: None,
+    retail: Some(RetailDictionary {
+        period: "Période",
+        fiscal_year_full: "Exercice",
+        fiscal_year_abr: "Ex.",
+    }),
+    roman: None, //Roman dates are only written in English
+    shire: None, //Shire dates are only written in English
     symmetry: Some(SymmetryDictionary {
         january: "janvier",
         february: "février",
@@ -182,7 +424,47 @@ pub const FR_DICTIONARY: Dictionary = Dictionary {
         before_epoch_abr: "AES",
         after_epoch_abr: "ES",
     }),
-    tranquility: None,
+    tamil: Some(TamilDictionary {
+        //The transliterations are used as-is
+        chithirai: "Chithirai",
+        vaikasi: "Vaikasi",
+        aani: "Aani",
+        aadi: "Aadi",
+        aavani: "Aavani",
+        purattasi: "Purattasi",
+        aippasi: "Aippasi",
+        karthigai: "Karthigai",
+        margazhi: "Margazhi",
+        thai: "Thai",
+        maasi: "Maasi",
+        panguni: "Panguni",
+        before_epoch_full: "avant l'ère Saka",
+        after_epoch_full: "ère Saka",
+        before_epoch_abr: "av. Saka",
+        after_epoch_abr: "Saka",
+    }),
+    tranquility: Some(TranquilityDictionary {
+        archimedes: "Archimède",
+        brahe: "Brahe",
+        copernicus: "Copernic",
+        darwin: "Darwin",
+        einstein: "Einstein",
+        faraday: "Faraday",
+        galileo: "Galilée",
+        hippocrates: "Hippocrate",
+        imhotep: "Imhotep",
+        jung: "Jung",
+        kepler: "Kepler",
+        lavoisier: "Lavoisier",
+        mendel: "Mendel",
+        before_tranquility_full: "avant la Tranquillité",
+        after_tranquility_full: "après la Tranquillité",
+        before_tranquility_abr: "av. T",
+        after_tranquility_abr: "apr. T",
+        moon_landing_day: "Jour de l'alunissage",
+        armstrong_day: "Jour d'Armstrong",
+        aldrin_day: "Jour d'Aldrin",
+    }),
     common_weekday: Some(CommonWeekdayDictionary {
         sunday: "Dimanche",
         monday: "Lundi",
@@ -192,4 +474,14 @@ pub const FR_DICTIONARY: Dictionary = Dictionary {
         friday: "Vendredi",
         saturday: "Samedi",
     }),
+    week_of_month: Some(WeekOfMonthDictionary {
+        first: "premier",
+        second: "deuxième",
+        third: "troisième",
+        fourth: "quatrième",
+        fifth: "cinquième",
+        sixth: "sixième",
+        of_month: "de",
+        of_month_elided: Some("d'"),
+    }),
 };
@@ -14,12 +14,15 @@
 /// + names of days of weeks
 /// + names of days of month
 ///
-/// Currently, Language::EN (English) is supported for all timekeeping systems, and
-/// Language::FR (French) is only supported for a subset of timekeeping systems.
+/// Currently, Language::EN (English) and Language::FR (French) are supported for all
+/// timekeeping systems. Any text which is missing from a language is written in English
+/// instead, and `PresetDisplay::has_complete_translation` checks whether this happens. New
+/// languages may be added in future versions, so matches on this type need a wildcard arm.
 ///
 /// # Further Reading
 /// + [Wikipedia](//https://en.wikipedia.org/wiki/List_of_ISO_639_language_codes)
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[non_exhaustive]
 pub enum Language {
     /// English
     EN,
@@ -27,6 +30,56 @@ pub enum Language {
     FR,
 }
 
+impl Language {
+    /// Find the language of a locale string
+    ///
+    /// Only the language code at the start of the string is used, so both BCP 47 tags
+    /// such as `fr-CA` and POSIX locales such as `fr_CA.UTF-8` are accepted. The POSIX
+    /// locales `C` and `POSIX` are treated as English. Returns [`None`] if the language is
+    /// not supported.
+    ///
+    /// ```
+    /// use radnelac::display::*;
+    ///
+    /// assert_eq!(Language::from_locale_str("fr-CA"), Some(Language::FR));
+    /// assert_eq!(Language::from_locale_str("en_US.UTF-8"), Some(Language::EN));
+    /// assert_eq!(Language::from_locale_str("FR"), Some(Language::FR));
+    /// assert_eq!(Language::from_locale_str("de-DE"), None);
+    /// ```
+    pub fn from_locale_str(s: &str) -> Option<Language> {
+        let code = s.trim().split(['-', '_', '.', '@']).next()?;
+        if code.eq_ignore_ascii_case("en")
+            || code.eq_ignore_ascii_case("c")
+            || code.eq_ignore_ascii_case("posix")
+        {
+            Some(Language::EN)
+        } else if code.eq_ignore_ascii_case("fr") {
+            Some(Language::FR)
+        } else {
+            None
+        }
+    }
+
+    /// Choose the first supported language from locale strings in order of preference
+    ///
+    /// Returns [`None`] if none of the languages are supported.
+    ///
+    /// ```
+    /// use radnelac::calendar::*;
+    /// use radnelac::display::*;
+    ///
+    /// let lang = Language::negotiate(&["de-CH", "fr-CH", "en"]).unwrap_or(Language::EN);
+    /// assert_eq!(lang, Language::FR);
+    /// let d = Gregorian::try_new(2025, GregorianMonth::July, 14).unwrap();
+    /// assert_eq!(d.preset_str(lang, LONG_DATE), d.preset_str(Language::FR, LONG_DATE));
+    /// ```
+    pub fn negotiate(preferences: &[&str]) -> Option<Language> {
+        preferences
+            .iter()
+            .find_map(|s| Language::from_locale_str(s))
+    }
+}
+
 #[derive(Debug)]
 pub struct AkanCycleDictionary<'a> {
     //Prefix
@@ -104,12 +157,168 @@ pub struct ArmenianDictionary<'a> {
     pub after_epoch_abr: &'a str,
 }
 
+#[derive(Debug)]
+pub struct BengaliDictionary<'a> {
+    //Months
+    pub boishakh: &'a str,
+    pub joishtho: &'a str,
+    pub asharh: &'a str,
+    pub shrabon: &'a str,
+    pub bhadro: &'a str,
+    pub ashshin: &'a str,
+    pub kartik: &'a str,
+    pub ogrohayon: &'a str,
+    pub poush: &'a str,
+    pub magh: &'a str,
+    pub falgun: &'a str,
+    pub choitro: &'a str,
+    //Epoch
+    pub before_epoch_full: &'a str,
+    pub after_epoch_full: &'a str,
+    pub before_epoch_abr: &'a str,
+    pub after_epoch_abr: &'a str,
+}
+
+#[derive(Debug)]
+pub struct AtticDictionary<'a> {
+    //Months
+    pub hekatombaion: &'a str,
+    pub metageitnion: &'a str,
+    pub boedromion: &'a str,
+    pub pyanepsion: &'a str,
+    pub maimakterion: &'a str,
+    pub poseideon: &'a str,
+    pub second_poseideon: &'a str,
+    pub gamelion: &'a str,
+    pub anthesterion: &'a str,
+    pub elaphebolion: &'a str,
+    pub mounichion: &'a str,
+    pub thargelion: &'a str,
+    pub skirophorion: &'a str,
+}
+
+#[derive(Debug)]
+pub struct BabylonianDictionary<'a> {
+    //Months
+    pub nisannu: &'a str,
+    pub ayyaru: &'a str,
+    pub simanu: &'a str,
+    pub duzu: &'a str,
+    pub abu: &'a str,
+    pub ululu: &'a str,
+    pub second_ululu: &'a str,
+    pub tashritu: &'a str,
+    pub arahsamnu: &'a str,
+    pub kislimu: &'a str,
+    pub tebetu: &'a str,
+    pub shabatu: &'a str,
+    pub addaru: &'a str,
+    pub second_addaru: &'a str,
+}
+
+#[derive(Debug)]
+pub struct ColignyDictionary<'a> {
+    //Months
+    pub quimonios: &'a str,
+    pub samonios: &'a str,
+    pub dumannios: &'a str,
+    pub riuros: &'a str,
+    pub anagantios: &'a str,
+    pub ogronnios: &'a str,
+    pub cutios: &'a str,
+    pub rantaranos: &'a str,
+    pub giamonios: &'a str,
+    pub simivisonnos: &'a str,
+    pub equos: &'a str,
+    pub elembivos: &'a str,
+    pub edrinios: &'a str,
+    pub cantlos: &'a str,
+}
+
+#[derive(Debug)]
+pub struct IslamicDictionary<'a> {
+    //Months
+    pub muharram: &'a str,
+    pub safar: &'a str,
+    pub rabi_al_awwal: &'a str,
+    pub rabi_al_thani: &'a str,
+    pub jumada_al_ula: &'a str,
+    pub jumada_al_akhira: &'a str,
+    pub rajab: &'a str,
+    pub shaban: &'a str,
+    pub ramadan: &'a str,
+    pub shawwal: &'a str,
+    pub dhu_al_qadah: &'a str,
+    pub dhu_al_hijjah: &'a str,
+}
+
+#[derive(Debug)]
+pub struct PataphysicalDictionary<'a> {
+    //Months
+    pub absolu: &'a str,
+    pub haha: &'a str,
+    pub r#as: &'a str,
+    pub sable: &'a str,
+    pub decervelage: &'a str,
+    pub gueules: &'a str,
+    pub pedale: &'a str,
+    pub clinamen: &'a str,
+    pub palotin: &'a str,
+    pub merdre: &'a str,
+    pub gidouille: &'a str,
+    pub tatane: &'a str,
+    pub phalle: &'a str,
+}
+
+#[derive(Debug)]
+pub struct PersianDictionary<'a> {
+    //Months
+    pub farvardin: &'a str,
+    pub ordibehesht: &'a str,
+    pub khordad: &'a str,
+    pub tir: &'a str,
+    pub mordad: &'a str,
+    pub shahrivar: &'a str,
+    pub mehr: &'a str,
+    pub aban: &'a str,
+    pub azar: &'a str,
+    pub dey: &'a str,
+    pub bahman: &'a str,
+    pub esfand: &'a str,
+}
+
+#[derive(Debug)]
+pub struct TamilDictionary<'a> {
+    //Months
+    pub chithirai: &'a str,
+    pub vaikasi: &'a str,
+    pub aani: &'a str,
+    pub aadi: &'a str,
+    pub aavani: &'a str,
+    pub purattasi: &'a str,
+    pub aippasi: &'a str,
+    pub karthigai: &'a str,
+    pub margazhi: &'a str,
+    pub thai: &'a str,
+    pub maasi: &'a str,
+    pub panguni: &'a str,
+    //Epoch
+    pub before_epoch_full: &'a str,
+    pub after_epoch_full: &'a str,
+    pub before_epoch_abr: &'a str,
+    pub after_epoch_abr: &'a str,
+}
+
 #[derive(Debug)]
 pub struct CommonClockDictionary<'a> {
     pub am_full: &'a str,
     pub pm_full: &'a str,
     pub am_abr: &'a str,
     pub pm_abr: &'a str,
+    pub night: &'a str,
+    pub morning: &'a str,
+    pub afternoon: &'a str,
+    pub evening: &'a str,
 }
 
 #[derive(Debug)]
@@ -327,6 +536,9 @@ pub struct JulianDictionary<'a> {
     pub anno_domini_full: &'a str,
     pub before_christ_abr: &'a str,
     pub anno_domini_abr: &'a str,
+    //Dual dating
+    pub old_style_abr: &'a str,
+    pub new_style_abr: &'a str,
 }
 
 #[derive(Debug)]
@@ -391,6 +603,46 @@ pub struct RomanDictionary<'a> {
     pub x_of_y: &'a str,
 }
 
+#[derive(Debug)]
+pub struct ShireDictionary<'a> {
+    //Months
+    pub afteryule: &'a str,
+    pub solmath: &'a str,
+    pub rethe: &'a str,
+    pub astron: &'a str,
+    pub thrimidge: &'a str,
+    pub forelithe: &'a str,
+    pub afterlithe: &'a str,
+    pub wedmath: &'a str,
+    pub halimath: &'a str,
+    pub winterfilth: &'a str,
+    pub blotmath: &'a str,
+    pub foreyule: &'a str,
+    //Days of week
+    pub sterday: &'a str,
+    pub sunday: &'a str,
+    pub monday: &'a str,
+    pub trewsday: &'a str,
+    pub hevensday: &'a str,
+    pub mersday: &'a str,
+    pub highday: &'a str,
+    //Days outside any month
+    pub second_yule: &'a str,
+    pub first_lithe: &'a str,
+    pub mid_years_day: &'a str,
+    pub overlithe: &'a str,
+    pub second_lithe: &'a str,
+    pub first_yule: &'a str,
+}
+
+#[derive(Debug)]
+pub struct RetailDictionary<'a> {
+    pub period: &'a str,
+    //Epoch
+    pub fiscal_year_full: &'a str,
+    pub fiscal_year_abr: &'a str,
+}
+
 #[derive(Debug)]
 pub struct SymmetryDictionary<'a> {
     //Months
@@ -452,10 +704,28 @@ pub struct CommonWeekdayDictionary<'a> {
     pub saturday: &'a str,
 }
 
+#[derive(Debug)]
+pub struct WeekOfMonthDictionary<'a> {
+    pub first: &'a str,
+    pub second: &'a str,
+    pub third: &'a str,
+    pub fourth: &'a str,
+    pub fifth: &'a str,
+    pub sixth: &'a str,
+    //Connects the week of month to the month, as in "fourth Saturday of July"
+    pub of_month: &'a str,
+    //Replaces of_month before a month name starting with a vowel, as in "d'avril"
+    pub of_month_elided: Option<&'a str>,
+}
+
 #[derive(Debug)]
 pub struct Dictionary<'a> {
     pub akan_cycle: Option<AkanCycleDictionary<'a>>,
     pub armenian: Option<ArmenianDictionary<'a>>,
+    pub attic: Option<AtticDictionary<'a>>,
+    pub babylonian: Option<BabylonianDictionary<'a>>,
+    pub bengali: Option<BengaliDictionary<'a>>,
+    pub coligny: Option<ColignyDictionary<'a>>,
     pub common_clock: Option<CommonClockDictionary<'a>>,
     pub coptic: Option<CopticDictionary<'a>>,
     pub cotsworth: Option<CotsworthDictionary<'a>>,
@@ -464,11 +734,47 @@ pub struct Dictionary<'a> {
     pub french_rev: Option<FrenchRevolutionaryDictionary<'a>>,
     pub gregorian: Option<GregorianDictionary<'a>>,
     pub holocene: Option<HoloceneDictionary<'a>>,
+    pub islamic: Option<IslamicDictionary<'a>>,
     pub iso: Option<ISODictionary<'a>>,
     pub julian: Option<JulianDictionary<'a>>,
+    pub pataphysical: Option<PataphysicalDictionary<'a>>,
+    pub persian: Option<PersianDictionary<'a>>,
     pub positivist: Option<PositivistDictionary<'a>>,
+    pub retail: Option<RetailDictionary<'a>>,
     pub roman: Option<RomanDictionary<'a>>,
+    pub shire: Option<ShireDictionary<'a>>,
     pub symmetry: Option<SymmetryDictionary<'a>>,
+    pub tamil: Option<TamilDictionary<'a>>,
     pub tranquility: Option<TranquilityDictionary<'a>>,
     pub common_weekday: Option<CommonWeekdayDictionary<'a>>,
+    pub week_of_month: Option<WeekOfMonthDictionary<'a>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locale_str() {
+        for s in [
+            "en",
+            "en-GB",
+            "en_CA.UTF-8",
+            " EN ",
+            "C",
+            "POSIX",
+            "C.UTF-8",
+        ] {
+            assert_eq!(Language::from_locale_str(s), Some(Language::EN), "{}", s);
+        }
+        for s in ["fr", "fr-CA", "fr_BE@euro", "Fr-fr"] {
+            assert_eq!(Language::from_locale_str(s), Some(Language::FR), "{}", s);
+        }
+        for s in ["", "-", "eng", "frc", "de", "zh-Hant-TW", "c++"] {
+            assert_eq!(Language::from_locale_str(s), None, "{}", s);
+        }
+        assert_eq!(Language::negotiate(&[]), None);
+        assert_eq!(Language::negotiate(&["de", "es"]), None);
+        assert_eq!(Language::negotiate(&["en-US", "fr"]), Some(Language::EN));
+    }
 }
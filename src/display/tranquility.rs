@@ -13,59 +13,83 @@ use crate::clock::ClockTime;
 use crate::clock::TimeOfDay;
 use crate::day_count::ToFixed;
 use crate::display::prelude::PresetDisplay;
+use crate::display::prelude::PresetFormat;
 use crate::display::prelude::COMPL_ONLY;
 use crate::display::prelude::LONG_COMPL;
 use crate::display::prelude::LONG_DATE;
 use crate::display::prelude::YEAR_COMPL;
 use crate::display::prelude::YEAR_MDD;
-use crate::display::private::fmt_days_since_epoch;
-use crate::display::private::fmt_number;
-use crate::display::private::fmt_quarter;
-use crate::display::private::fmt_seconds_since_epoch;
-use crate::display::private::fmt_string;
 use crate::display::private::get_dict;
+use crate::display::private::get_table;
+use crate::display::private::write_days_since_epoch;
+use crate::display::private::write_half_year;
+use crate::display::private::write_number;
+use crate::display::private::write_quarter;
+use crate::display::private::write_seconds_since_epoch;
+use crate::display::private::write_string;
+use crate::display::private::write_week_of_month_name;
 use crate::display::private::DisplayItem;
 use crate::display::private::DisplayOptions;
 use crate::display::private::NumericContent;
 use crate::display::private::TextContent;
 use crate::display::text::prelude::Language;
 use crate::display::HHMMSS_COLON;
-use std::fmt;
+use core::fmt;
 
 impl DisplayItem for Tranquility {
     fn supported_lang(lang: Language) -> bool {
         get_dict(lang).tranquility.as_ref().is_some()
     }
 
-    fn fmt_numeric(&self, n: NumericContent, opt: DisplayOptions) -> String {
+    fn fmt_numeric(
+        &self,
+        w: &mut impl fmt::Write,
+        n: NumericContent,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
         match n {
             NumericContent::Month | NumericContent::DayOfMonth | NumericContent::Year => {
-                self.to_common_date().fmt_numeric(n, opt)
+                self.to_common_date().fmt_numeric(w, n, opt)
             }
             NumericContent::DayOfWeek => match self.weekday() {
-                Some(d) => d.fmt_numeric(n, opt),
-                None => "".to_string(),
+                Some(d) => d.fmt_numeric(w, n, opt),
+                None => Ok(()),
             },
-            NumericContent::DayOfYear => self.to_ordinal().fmt_numeric(n, opt),
+            NumericContent::DayOfYear => self.to_ordinal().fmt_numeric(w, n, opt),
             NumericContent::Hour1to12
             | NumericContent::Hour0to23
             | NumericContent::Minute
-            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(n, opt),
-            NumericContent::SecondsSinceEpoch => fmt_seconds_since_epoch(*self, opt),
-            NumericContent::Quarter => fmt_quarter(*self, opt),
-            NumericContent::DaysSinceEpoch => fmt_days_since_epoch(*self, opt),
+            | NumericContent::Second => self.convert::<TimeOfDay>().fmt_numeric(w, n, opt),
+            NumericContent::SecondsSinceEpoch => write_seconds_since_epoch(w, *self, opt),
+            NumericContent::Quarter => write_quarter(w, *self, opt),
+            NumericContent::HalfYear => write_half_year(w, *self, opt),
+            NumericContent::DaysSinceEpoch => write_days_since_epoch(w, *self, opt),
             NumericContent::ComplementaryDay => match self.epagomenae() {
-                Some(d) => fmt_number(d as i8, opt),
-                None => "".to_string(),
+                Some(d) => write_number(w, d as i8, opt),
+                None => Ok(()),
             },
             NumericContent::WeekOfYear => match self.try_week_of_year() {
-                Some(w) => fmt_number(w as i8, opt),
-                None => "".to_string(),
+                Some(week) => write_number(w, week as i8, opt),
+                None => Ok(()),
+            },
+            NumericContent::WeekOfMonth => match self.try_week_of_month() {
+                Some(week) => write_number(w, week as i8, opt),
+                None => Ok(()),
+            },
+            NumericContent::DayOfMonthWeek => match self.try_day_of_month_week() {
+                Some(d) => write_number(w, d as i8, opt),
+                None => Ok(()),
             },
         }
     }
-    fn fmt_text(&self, t: TextContent, lang: Language, opt: DisplayOptions) -> String {
-        match (t, get_dict(lang).tranquility.as_ref()) {
+    fn fmt_text(
+        &self,
+        w: &mut impl fmt::Write,
+        t: TextContent,
+        lang: Language,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
+        match (t, get_table(lang, |d| d.tranquility.as_ref())) {
             (TextContent::MonthName, Some(dict)) => {
                 let months: [&str; 13] = [
                     dict.archimedes,
@@ -86,32 +110,33 @@ impl DisplayItem for Tranquility {
                     Some(m) => months[(m as usize) - 1],
                     None => "",
                 };
-                fmt_string(name, opt)
+                write_string(w, name, opt)
             }
-            (TextContent::DayOfMonthName, _) => fmt_string("", opt),
+            (TextContent::DayOfMonthName, _) => write_string(w, "", opt),
             (TextContent::DayOfWeekName, _) => match self.weekday() {
-                Some(m) => m.fmt_text(t, lang, opt),
-                None => fmt_string("", opt),
+                Some(m) => m.fmt_text(w, t, lang, opt),
+                None => write_string(w, "", opt),
             },
-            (TextContent::HalfDayName | TextContent::HalfDayAbbrev, _) => {
-                self.convert::<TimeOfDay>().fmt_text(t, lang, opt)
-            }
+            (
+                TextContent::HalfDayName | TextContent::HalfDayAbbrev | TextContent::PartOfDayName,
+                _,
+            ) => self.convert::<TimeOfDay>().fmt_text(w, t, lang, opt),
             (TextContent::EraName, Some(dict)) => {
                 if self.year() > 0 {
-                    fmt_string(dict.after_tranquility_full, opt)
+                    write_string(w, dict.after_tranquility_full, opt)
                 } else if self.year() < 0 {
-                    fmt_string(dict.before_tranquility_full, opt)
+                    write_string(w, dict.before_tranquility_full, opt)
                 } else {
-                    "".to_string()
+                    Ok(())
                 }
             }
             (TextContent::EraAbbreviation, Some(dict)) => {
                 if self.year() > 0 {
-                    fmt_string(dict.after_tranquility_abr, opt)
+                    write_string(w, dict.after_tranquility_abr, opt)
                 } else if self.year() < 0 {
-                    fmt_string(dict.before_tranquility_abr, opt)
+                    write_string(w, dict.before_tranquility_abr, opt)
                 } else {
-                    "".to_string()
+                    Ok(())
                 }
             }
             (TextContent::ComplementaryDayName, Some(dict)) => {
@@ -120,35 +145,36 @@ impl DisplayItem for Tranquility {
                     Some(d) => compl[d as usize],
                     None => "",
                 };
-                fmt_string(name, opt)
+                write_string(w, name, opt)
             }
-            (_, _) => String::from(""),
+            (TextContent::WeekOfMonthName, _) => {
+                write_week_of_month_name(w, self.try_week_of_month(), lang, opt)
+            }
+            (_, _) => Ok(()),
         }
     }
 }
 
 impl PresetDisplay for Tranquility {
-    fn long_date(&self) -> String {
-        let p = match self.epagomenae() {
+    fn long_date_preset(&self) -> PresetFormat<'static> {
+        match self.epagomenae() {
             None => LONG_DATE,
             Some(TranquilityComplementaryDay::MoonLandingDay) => COMPL_ONLY,
             Some(_) => LONG_COMPL,
-        };
-        self.preset_str(Language::EN, p)
+        }
     }
 
-    fn short_date(&self) -> String {
-        let p = match self.epagomenae() {
+    fn short_date_preset(&self) -> PresetFormat<'static> {
+        match self.epagomenae() {
             None => YEAR_MDD,
             Some(_) => YEAR_COMPL,
-        };
-        self.preset_str(Language::EN, p)
+        }
     }
 }
 
 impl fmt::Display for Tranquility {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.long_date())
+        self.fmt_into(f, Language::EN, self.long_date_preset())
     }
 }
 
@@ -157,54 +183,66 @@ impl DisplayItem for TranquilityMoment {
         Tranquility::supported_lang(lang) && ClockTime::supported_lang(lang)
     }
 
-    fn fmt_numeric(&self, n: NumericContent, opt: DisplayOptions) -> String {
+    fn fmt_numeric(
+        &self,
+        w: &mut impl fmt::Write,
+        n: NumericContent,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
         match n {
             NumericContent::Hour1to12
             | NumericContent::Hour0to23
             | NumericContent::Minute
-            | NumericContent::Second => self.clone().time_of_day().fmt_numeric(n, opt),
-            _ => self.clone().date().fmt_numeric(n, opt),
+            | NumericContent::Second => (*self).time_of_day().fmt_numeric(w, n, opt),
+            _ => (*self).date().fmt_numeric(w, n, opt),
         }
     }
 
-    fn fmt_text(&self, t: TextContent, lang: Language, opt: DisplayOptions) -> String {
-        match (t, get_dict(lang).tranquility.as_ref()) {
-            (TextContent::HalfDayName, _) | (TextContent::HalfDayAbbrev, _) => {
-                self.clone().time_of_day().fmt_text(t, lang, opt)
-            }
+    fn fmt_text(
+        &self,
+        w: &mut impl fmt::Write,
+        t: TextContent,
+        lang: Language,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
+        match (t, get_table(lang, |d| d.tranquility.as_ref())) {
+            (TextContent::HalfDayName, _)
+            | (TextContent::HalfDayAbbrev, _)
+            | (TextContent::PartOfDayName, _) => (*self).time_of_day().fmt_text(w, t, lang, opt),
             (TextContent::EraName, Some(dict)) => {
                 if self.is_after_tranquility() {
-                    fmt_string(dict.after_tranquility_full, opt)
+                    write_string(w, dict.after_tranquility_full, opt)
                 } else {
-                    fmt_string(dict.before_tranquility_full, opt)
+                    write_string(w, dict.before_tranquility_full, opt)
                 }
             }
             (TextContent::EraAbbreviation, Some(dict)) => {
                 if self.is_after_tranquility() {
-                    fmt_string(dict.after_tranquility_abr, opt)
+                    write_string(w, dict.after_tranquility_abr, opt)
                 } else {
-                    fmt_string(dict.before_tranquility_abr, opt)
+                    write_string(w, dict.before_tranquility_abr, opt)
                 }
             }
 
-            _ => self.clone().date().fmt_text(t, lang, opt),
+            _ => (*self).date().fmt_text(w, t, lang, opt),
         }
     }
 }
 
 impl PresetDisplay for TranquilityMoment {
-    fn long_date(&self) -> String {
-        self.clone().date().long_date()
+    fn long_date_preset(&self) -> PresetFormat<'static> {
+        (*self).date().long_date_preset()
     }
 
-    fn short_date(&self) -> String {
-        self.clone().date().short_date()
+    fn short_date_preset(&self) -> PresetFormat<'static> {
+        (*self).date().short_date_preset()
     }
 }
 
 impl fmt::Display for TranquilityMoment {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} ", self.preset_str(Language::EN, HHMMSS_COLON));
+        self.fmt_into(f, Language::EN, HHMMSS_COLON)?;
+        f.write_str(" ")?;
         self.date().fmt(f)
     }
 }
@@ -217,6 +255,7 @@ mod tests {
     #[test]
     fn expected_languages() {
         assert!(TranquilityMoment::supported_lang(Language::EN));
+        assert!(TranquilityMoment::supported_lang(Language::FR));
     }
 
     #[test]
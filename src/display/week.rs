@@ -3,9 +3,10 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use crate::day_cycle::Weekday;
-use crate::display::private::fmt_number;
-use crate::display::private::fmt_string;
 use crate::display::private::get_dict;
+use crate::display::private::get_table;
+use crate::display::private::write_number;
+use crate::display::private::write_string;
 use crate::display::private::Content;
 use crate::display::private::DisplayItem;
 use crate::display::private::Item;
@@ -14,9 +15,10 @@ use crate::display::private::Sign;
 use crate::display::private::TextContent;
 use crate::display::text::en::EN_DICTIONARY;
 use crate::display::text::prelude::Language;
+use crate::display::CycleDisplay;
 use crate::display::PresetDisplay;
 use crate::display::WEEKDAY_NAME_ONLY;
-use std::fmt;
+use core::fmt;
 
 use crate::display::private::DisplayOptions;
 
@@ -25,14 +27,25 @@ impl DisplayItem for Weekday {
         get_dict(lang).common_weekday.as_ref().is_some()
     }
 
-    fn fmt_numeric(&self, n: NumericContent, opt: DisplayOptions) -> String {
+    fn fmt_numeric(
+        &self,
+        w: &mut impl fmt::Write,
+        n: NumericContent,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
         match n {
-            NumericContent::DayOfWeek => fmt_number(*self as i16, opt),
-            _ => "".to_string(),
+            NumericContent::DayOfWeek => write_number(w, *self as i16, opt),
+            _ => Ok(()),
         }
     }
-    fn fmt_text(&self, t: TextContent, lang: Language, opt: DisplayOptions) -> String {
-        match (t, get_dict(lang).common_weekday.as_ref()) {
+    fn fmt_text(
+        &self,
+        w: &mut impl fmt::Write,
+        t: TextContent,
+        lang: Language,
+        opt: DisplayOptions,
+    ) -> fmt::Result {
+        match (t, get_table(lang, |d| d.common_weekday.as_ref())) {
             (TextContent::DayOfWeekName, Some(dict)) => {
                 let days: [&str; 7] = [
                     dict.sunday,
@@ -44,15 +57,25 @@ impl DisplayItem for Weekday {
                     dict.saturday,
                 ];
                 let name = days[*self as usize];
-                fmt_string(name, opt)
+                write_string(w, name, opt)
             }
-            (_, _) => "".to_string(),
+            (_, _) => Ok(()),
         }
     }
 }
 
 impl PresetDisplay for Weekday {}
 
+impl CycleDisplay for Weekday {
+    fn supported_cycle_lang(lang: Language) -> bool {
+        Self::supported_lang(lang)
+    }
+
+    fn fmt_cycle_name(&self, w: &mut impl fmt::Write, lang: Language) -> fmt::Result {
+        self.fmt_into(w, lang, WEEKDAY_NAME_ONLY)
+    }
+}
+
 impl fmt::Display for Weekday {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         const O: DisplayOptions = DisplayOptions {
@@ -64,7 +87,7 @@ impl fmt::Display for Weekday {
             sign: Sign::Never,
         };
         let item = Item::new(Content::Text(TextContent::DayOfWeekName), O);
-        write!(f, "{}", self.fmt_item(Language::EN, item))
+        self.fmt_item_into(f, Language::EN, item)
     }
 }
 
@@ -95,6 +118,7 @@ mod tests {
             let s1_fr = w.preset_str(Language::FR, WEEKDAY_NAME_ONLY);
             assert_eq!(s0_en, s1_en);
             assert_eq!(s0_fr, s1_fr);
+            assert_eq!(w.cycle_name(Language::FR).unwrap(), s0_fr);
             const O: DisplayOptions = DisplayOptions {
                 numerals: None,
                 width: None,
@@ -103,7 +127,10 @@ mod tests {
                 case: None,
                 sign: Sign::Never,
             };
-            assert_eq!(w.fmt_numeric(NumericContent::DayOfWeek, O), n0);
+            let mut n1 = String::new();
+            w.fmt_numeric(&mut n1, NumericContent::DayOfWeek, O)
+                .unwrap();
+            assert_eq!(n1, n0);
         }
     }
 }
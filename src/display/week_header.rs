@@ -0,0 +1,173 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::Cotsworth;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::FrenchRevArith;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::Positivist;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::Symmetry;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::Tranquility;
+use crate::day_cycle::Weekday;
+use crate::display::private::get_dict;
+use crate::display::text::prelude::Language;
+
+/// Names of the days of the common week, starting from any day
+///
+/// This is intended for the header row of a month grid. Returns [`None`] if the language
+/// is not supported.
+///
+/// ```
+/// use radnelac::day_cycle::*;
+/// use radnelac::display::*;
+///
+/// let h = weekday_header(Language::EN, Weekday::Monday).unwrap();
+/// assert_eq!(h[0], "Monday");
+/// assert_eq!(h[6], "Sunday");
+/// ```
+///
+/// ## Crate Features
+///
+/// This is only available if `display` is enabled.
+pub fn weekday_header(lang: Language, first: Weekday) -> Option<[&'static str; 7]> {
+    let dict = get_dict(lang).common_weekday.as_ref()?;
+    let days: [&'static str; 7] = [
+        dict.sunday,
+        dict.monday,
+        dict.tuesday,
+        dict.wednesday,
+        dict.thursday,
+        dict.friday,
+        dict.saturday,
+    ];
+    Some(core::array::from_fn(|i| days[(first as usize + i) % 7]))
+}
+
+/// Perennial calendars with a header row for month grids
+///
+/// In perennial calendars every month starts on the same day of the week, so the header
+/// row is the same for every month. The length of the header is the length of the week,
+/// which is 10 days for the French Revolutionary calendar and 7 days for the others.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::display::*;
+///
+/// assert_eq!(Positivist::month_header(Language::EN).unwrap()[0], "Monday");
+/// let h = FrenchRevArith::<true>::month_header(Language::FR).unwrap();
+/// assert_eq!(h.len(), 10);
+/// assert_eq!(h[9], "Décadi");
+/// ```
+///
+/// ## Crate Features
+///
+/// This is only available if `display` is enabled.
+pub trait MonthHeader {
+    /// Names of the days of the week, in the order they appear in every month
+    fn month_header(lang: Language) -> Option<Vec<&'static str>>;
+}
+
+macro_rules! weekday_month_header {
+    ([$($g:tt)*] $t:ty, $first:ident) => {
+        impl<$($g)*> MonthHeader for $t {
+            fn month_header(lang: Language) -> Option<Vec<&'static str>> {
+                weekday_header(lang, Weekday::$first).map(|h| h.to_vec())
+            }
+        }
+    };
+    ($t:ty, $first:ident) => {
+        weekday_month_header!([] $t, $first);
+    };
+}
+
+#[cfg(feature = "reform-calendars")]
+weekday_month_header!(Cotsworth, Sunday);
+#[cfg(feature = "reform-calendars")]
+weekday_month_header!(Positivist, Monday);
+#[cfg(feature = "reform-calendars")]
+weekday_month_header!([const T: bool, const U: bool] Symmetry<T, U>, Monday);
+#[cfg(feature = "reform-calendars")]
+weekday_month_header!(Tranquility, Friday);
+
+#[cfg(feature = "reform-calendars")]
+impl<const L: bool> MonthHeader for FrenchRevArith<L> {
+    fn month_header(lang: Language) -> Option<Vec<&'static str>> {
+        let dict = get_dict(lang).french_rev.as_ref()?;
+        Some(vec![
+            dict.primidi,
+            dict.duodi,
+            dict.tridi,
+            dict.quartidi,
+            dict.quintidi,
+            dict.sextidi,
+            dict.septidi,
+            dict.octidi,
+            dict.nonidi,
+            dict.decadi,
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotation() {
+        let h = weekday_header(Language::FR, Weekday::Sunday).unwrap();
+        assert_eq!(h[0], "Dimanche");
+        assert_eq!(h[1], "Lundi");
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "reform-calendars")]
+mod perennial_tests {
+    use super::*;
+    use crate::calendar::day_of_week_of;
+    use crate::calendar::CommonDate;
+    use crate::calendar::CotsworthMonth;
+    use crate::calendar::Perennial;
+    use crate::calendar::PositivistMonth;
+    use crate::calendar::Symmetry454;
+    use crate::calendar::SymmetryMonth;
+    use crate::calendar::TranquilityMonth;
+    use crate::display::CycleDisplay;
+    use num_traits::FromPrimitive;
+    use num_traits::ToPrimitive;
+
+    fn first_matches_header<S, T>(year: i32)
+    where
+        S: FromPrimitive + ToPrimitive,
+        T: MonthHeader + Perennial<S, Weekday>,
+    {
+        let header = T::month_header(Language::EN).unwrap();
+        for m in 1..=13 {
+            if let Ok(d) = T::try_from_common_date(CommonDate::new(year, m, 1)) {
+                let w = d.weekday().unwrap();
+                assert_eq!(w.cycle_name(Language::EN).unwrap(), header[0]);
+            }
+        }
+    }
+
+    #[test]
+    fn perennial_month_starts() {
+        for year in [-100, 1, 2024, 2025] {
+            first_matches_header::<CotsworthMonth, Cotsworth>(year);
+            first_matches_header::<PositivistMonth, Positivist>(year);
+            first_matches_header::<TranquilityMonth, Tranquility>(year);
+        }
+    }
+
+    #[test]
+    fn symmetry_month_starts() {
+        for m in (1..=13).map_while(SymmetryMonth::from_u8) {
+            let w = day_of_week_of::<SymmetryMonth, Symmetry454>(2026, m, 1);
+            assert_eq!(w.unwrap(), Weekday::Monday);
+        }
+    }
+}
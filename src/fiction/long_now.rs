@@ -0,0 +1,47 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::Gregorian;
+use crate::calendar::ToFromOrdinalDate;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+
+/// Represents a Gregorian year written with 5 digits
+///
+/// The Long Now Foundation writes years with a leading zero (such as 02025 instead of 2025)
+/// to encourage thinking about the next 10000 years. This is only a different way of
+/// writing the Gregorian year: there is no change to the calendar itself.
+///
+/// `LongNowYear` only stores the year, so it cannot be converted back into a specific day.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+/// use radnelac::fiction::*;
+///
+/// let g = Gregorian::try_new(2025, GregorianMonth::May, 15).unwrap();
+/// let y = g.convert::<LongNowYear>();
+/// assert_eq!(y.get(), 2025);
+/// ```
+///
+/// ## Further reading
+/// + [The Long Now Foundation](https://longnow.org/)
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default)]
+pub struct LongNowYear(i32);
+
+impl LongNowYear {
+    pub fn new(year: i32) -> Self {
+        LongNowYear(year)
+    }
+
+    pub fn get(self) -> i32 {
+        self.0
+    }
+}
+
+impl FromFixed for LongNowYear {
+    fn from_fixed(t: Fixed) -> LongNowYear {
+        LongNowYear(Gregorian::ordinal_from_fixed(t).year)
+    }
+}
@@ -0,0 +1,433 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::AllowYearZero;
+use crate::calendar::CalendarMoment;
+use crate::calendar::CommonDate;
+use crate::calendar::Gregorian;
+use crate::calendar::HasEpagemonae;
+use crate::calendar::HasLeapYears;
+use crate::calendar::OrdinalDate;
+use crate::calendar::Quarter;
+use crate::calendar::ToFromCommonDate;
+use crate::calendar::ToFromOrdinalDate;
+use crate::common::error::CalendarError;
+use crate::common::math::TermNum;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::CalculatedBounds;
+use crate::day_count::Epoch;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use crate::day_cycle::Weekday;
+use std::cmp::Ordering;
+use std::num::NonZero;
+
+#[allow(unused_imports)] //FromPrimitive is needed for derive
+use num_traits::FromPrimitive;
+use std::fmt;
+
+const NON_MONTH: u8 = 0;
+/// Number of days between 2 Yule and 1 January in the Gregorian calendar
+const GREGORIAN_SHIFT: i64 = 9;
+const FIRST_HALF_END: i64 = 1 + (6 * 30);
+
+/// Represents a month of the Shire Calendar
+///
+/// Note that the Yule and Lithe days of the Shire calendar have no month and thus are
+/// not represented by ShireMonth. When representing an arbitrary day in the Shire
+/// calendar, use an `Option<ShireMonth>` for the month field.
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, FromPrimitive, ToPrimitive)]
+pub enum ShireMonth {
+    Afteryule = 1,
+    Solmath,
+    Rethe,
+    Astron,
+    Thrimidge,
+    Forelithe,
+    Afterlithe,
+    Wedmath,
+    Halimath,
+    Winterfilth,
+    Blotmath,
+    Foreyule,
+}
+
+/// Represents a day of the Shire Calendar which is not part of any month
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, FromPrimitive, ToPrimitive)]
+pub enum ShireComplementaryDay {
+    /// This is the first day of every year.
+    SecondYule = 1,
+    /// This is the day after 30 Forelithe.
+    FirstLithe,
+    /// This is the middle day of the year. It is not part of any week.
+    MidYearsDay,
+    /// This is an extra day added after Mid-year's Day in leap years. It is not part of
+    /// any week.
+    Overlithe,
+    /// This is the day before 1 Afterlithe.
+    SecondLithe,
+    /// This is the last day of every year.
+    FirstYule,
+}
+
+/// Represents a date in the Shire Calendar
+///
+/// ## Introduction
+///
+/// The Shire Calendar (also called Shire Reckoning) is used by the hobbits in the novels of
+/// J.R.R. Tolkien. It is described in Appendix D of *The Lord of the Rings*.
+///
+/// **This is fiction.** Tolkien did not give an exact correspondence between the Shire
+/// Calendar and the Gregorian calendar. This crate uses the correspondence described below.
+///
+/// ## Basic Structure
+///
+/// Years are divided into 12 months of 30 days each. There are also 5 or 6 days which are
+/// not part of any month:
+/// + 2 Yule, which is the first day of the year
+/// + 1 Lithe, Mid-year's Day and 2 Lithe, in the middle of the year
+/// + Overlithe, after Mid-year's Day in leap years
+/// + 1 Yule, which is the last day of the year
+///
+/// Weeks have 7 days, and each year starts on Sterday (Saturday). Mid-year's Day and Overlithe
+/// are not part of any week, so each date falls on the same day of the week every year.
+///
+/// ## Correspondence with the Gregorian calendar
+///
+/// From Appendix D of *The Lord of the Rings*:
+/// > ... our New Year's Day corresponded more or less to the Shire January 9th.
+///
+/// In this crate, 9 Afteryule is always 1 January in the proleptic Gregorian calendar.
+/// As a result, 2 Yule is always 23 December of the previous Gregorian year, and Mid-year's
+/// Day is 22 June in leap years and 23 June in common years.
+///
+/// Leap years occur at the same time as Gregorian leap years, and the year numbers
+/// are the same as the Gregorian year numbers. This is not the same as the Shire Reckoning
+/// used in the novels.
+///
+/// ## Representation and Examples
+///
+/// The months are represented in this crate as [`ShireMonth`], and the days outside
+/// any month are represented as [`ShireComplementaryDay`]. When converting to and from a
+/// [`CommonDate`], the days outside any month are treated as month 0.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+/// use radnelac::day_cycle::*;
+/// use radnelac::fiction::*;
+///
+/// let g = Gregorian::try_new(2025, GregorianMonth::January, 1).unwrap();
+/// let s = g.convert::<Shire>();
+/// assert_eq!(s.try_month().unwrap(), ShireMonth::Afteryule);
+/// assert_eq!(s.day(), 9);
+///
+/// let g = Gregorian::try_new(2024, GregorianMonth::December, 23).unwrap();
+/// let s = g.convert::<Shire>();
+/// assert_eq!(s.epagomenae().unwrap(), ShireComplementaryDay::SecondYule);
+/// assert_eq!(s.weekday().unwrap(), Weekday::Saturday);
+/// ```
+///
+/// ## Further reading
+/// + [Tolkien Gateway](https://tolkiengateway.net/wiki/Shire_Calendar)
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Shire(CommonDate);
+
+impl fmt::Debug for Shire {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug::<ShireMonth>("Shire", f)
+    }
+}
+
+impl Shire {
+    fn leap_correction(year: i32) -> i64 {
+        if Shire::is_leap(year) {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// Returns the day of the week, or `None` for Mid-year's Day and Overlithe
+    pub fn weekday(self) -> Option<Weekday> {
+        let doy = self.to_ordinal().day_of_year as i64;
+        let count = match self.epagomenae() {
+            Some(ShireComplementaryDay::MidYearsDay | ShireComplementaryDay::Overlithe) => {
+                return None;
+            }
+            _ if doy <= FIRST_HALF_END + 1 => doy,
+            _ => doy - 1 - Shire::leap_correction(self.0.year),
+        };
+        Weekday::from_i64((count + (Weekday::Saturday as i64) - 1).modulus(7))
+    }
+}
+
+impl AllowYearZero for Shire {}
+
+impl ToFromOrdinalDate for Shire {
+    fn valid_ordinal(ord: OrdinalDate) -> Result<(), CalendarError> {
+        Gregorian::valid_ordinal(ord)
+    }
+
+    fn ordinal_from_fixed(fixed_date: Fixed) -> OrdinalDate {
+        let shifted = Fixed::cast_new(fixed_date.get_day_i() + GREGORIAN_SHIFT);
+        Gregorian::ordinal_from_fixed(shifted)
+    }
+
+    fn to_ordinal(self) -> OrdinalDate {
+        let leap = Shire::leap_correction(self.0.year);
+        let doy = match self.epagomenae() {
+            Some(ShireComplementaryDay::SecondYule) => 1,
+            Some(ShireComplementaryDay::FirstLithe) => FIRST_HALF_END + 1,
+            Some(ShireComplementaryDay::MidYearsDay) => FIRST_HALF_END + 2,
+            Some(ShireComplementaryDay::Overlithe) => FIRST_HALF_END + 3,
+            Some(ShireComplementaryDay::SecondLithe) => FIRST_HALF_END + 3 + leap,
+            Some(ShireComplementaryDay::FirstYule) => 365 + leap,
+            None => {
+                let month = self.0.month as i64;
+                let day = self.0.day as i64;
+                if month <= (ShireMonth::Forelithe as i64) {
+                    1 + ((month - 1) * 30) + day
+                } else {
+                    FIRST_HALF_END + 3 + leap + ((month - 7) * 30) + day
+                }
+            }
+        };
+        OrdinalDate {
+            year: self.0.year,
+            day_of_year: doy as u16,
+        }
+    }
+
+    fn from_ordinal_unchecked(ord: OrdinalDate) -> Self {
+        let leap = Shire::leap_correction(ord.year);
+        let doy = ord.day_of_year as i64;
+        let second_lithe = FIRST_HALF_END + 3 + leap;
+        let complementary =
+            |d: ShireComplementaryDay| CommonDate::new(ord.year, NON_MONTH, d as u8);
+        let date = match doy {
+            1 => complementary(ShireComplementaryDay::SecondYule),
+            d if d <= FIRST_HALF_END => {
+                CommonDate::new(ord.year, ((d - 2) / 30 + 1) as u8, ((d - 2) % 30 + 1) as u8)
+            }
+            d if d == FIRST_HALF_END + 1 => complementary(ShireComplementaryDay::FirstLithe),
+            d if d == FIRST_HALF_END + 2 => complementary(ShireComplementaryDay::MidYearsDay),
+            d if d < second_lithe => complementary(ShireComplementaryDay::Overlithe),
+            d if d == second_lithe => complementary(ShireComplementaryDay::SecondLithe),
+            d if d == 365 + leap => complementary(ShireComplementaryDay::FirstYule),
+            d => {
+                let d = d - second_lithe - 1;
+                CommonDate::new(ord.year, (d / 30 + 7) as u8, (d % 30 + 1) as u8)
+            }
+        };
+        Shire(date)
+    }
+}
+
+impl PartialOrd for Shire {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else {
+            self.to_ordinal().partial_cmp(&other.to_ordinal())
+        }
+    }
+}
+
+impl HasEpagemonae<ShireComplementaryDay> for Shire {
+    fn epagomenae(self) -> Option<ShireComplementaryDay> {
+        if self.0.month == NON_MONTH {
+            ShireComplementaryDay::from_u8(self.0.day)
+        } else {
+            None
+        }
+    }
+
+    fn epagomenae_count(year: i32) -> u8 {
+        if Shire::is_leap(year) {
+            6
+        } else {
+            5
+        }
+    }
+}
+
+impl HasLeapYears for Shire {
+    fn is_leap(year: i32) -> bool {
+        Gregorian::is_leap(year)
+    }
+}
+
+impl CalculatedBounds for Shire {}
+
+impl Epoch for Shire {
+    fn epoch() -> Fixed {
+        Fixed::cast_new(Gregorian::prior_elapsed_days(1) - GREGORIAN_SHIFT + 1)
+    }
+}
+
+impl FromFixed for Shire {
+    fn from_fixed(date: Fixed) -> Shire {
+        Shire::from_ordinal_unchecked(Shire::ordinal_from_fixed(date))
+    }
+}
+
+impl ToFixed for Shire {
+    fn to_fixed(self) -> Fixed {
+        let offset_y = Gregorian::prior_elapsed_days(self.0.year) - GREGORIAN_SHIFT;
+        Fixed::cast_new(offset_y + (self.to_ordinal().day_of_year as i64))
+    }
+}
+
+impl ToFromCommonDate<ShireMonth> for Shire {
+    const EFFECTIVE_YEAR_MIN: i32 = -47013915;
+    const EFFECTIVE_YEAR_MAX: i32 = 47013917;
+
+    fn to_common_date(self) -> CommonDate {
+        self.0
+    }
+
+    fn from_common_date_unchecked(date: CommonDate) -> Self {
+        debug_assert!(Self::valid_ymd(date).is_ok());
+        Self(date)
+    }
+
+    fn valid_ymd(date: CommonDate) -> Result<(), CalendarError> {
+        if date.month > (ShireMonth::Foreyule as u8) {
+            Err(CalendarError::InvalidMonth)
+        } else if date.month == NON_MONTH {
+            match ShireComplementaryDay::from_u8(date.day) {
+                None => Err(CalendarError::InvalidDay),
+                Some(ShireComplementaryDay::Overlithe) if !Shire::is_leap(date.year) => {
+                    Err(CalendarError::InvalidDay)
+                }
+                Some(_) => Ok(()),
+            }
+        } else if date.day < 1 || date.day > 30 {
+            Err(CalendarError::InvalidDay)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn year_start_date(year: i32) -> CommonDate {
+        CommonDate::new(year, NON_MONTH, ShireComplementaryDay::SecondYule as u8)
+    }
+
+    fn year_end_date(year: i32) -> CommonDate {
+        CommonDate::new(year, NON_MONTH, ShireComplementaryDay::FirstYule as u8)
+    }
+
+    fn month_length(_year: i32, _month: ShireMonth) -> u8 {
+        30
+    }
+}
+
+impl Quarter for Shire {
+    fn quarter(self) -> NonZero<u8> {
+        let q = match (self.try_month(), self.epagomenae()) {
+            (Some(m), _) => ((m as u8) - 1) / 3 + 1,
+            (None, Some(ShireComplementaryDay::SecondYule)) => 1,
+            (None, Some(ShireComplementaryDay::SecondLithe)) => 3,
+            (None, Some(ShireComplementaryDay::FirstYule)) => 4,
+            (None, _) => 2,
+        };
+        NonZero::new(q).expect("q > 0")
+    }
+}
+
+/// Represents a date *and time* in the Shire Calendar
+pub type ShireMoment = CalendarMoment<Shire>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use proptest::proptest;
+
+    #[test]
+    fn gregorian_correspondence() {
+        let expected = [
+            (2024, 12, 23, 2025, NON_MONTH, 1),
+            (2025, 1, 1, 2025, 1, 9),
+            (2025, 6, 21, 2025, 6, 30),
+            (2025, 6, 22, 2025, NON_MONTH, 2),
+            (2025, 6, 23, 2025, NON_MONTH, 3),
+            (2025, 6, 24, 2025, NON_MONTH, 5),
+            (2025, 6, 25, 2025, 7, 1),
+            (2025, 12, 22, 2025, NON_MONTH, 6),
+            (2024, 6, 22, 2024, NON_MONTH, 3),
+            (2024, 6, 23, 2024, NON_MONTH, 4),
+            (2024, 6, 24, 2024, NON_MONTH, 5),
+            (2024, 12, 22, 2024, NON_MONTH, 6),
+        ];
+        for (gy, gm, gd, sy, sm, sd) in expected {
+            let g = Gregorian::try_from_common_date(CommonDate::new(gy, gm, gd)).unwrap();
+            let s = Shire::try_from_common_date(CommonDate::new(sy, sm, sd)).unwrap();
+            assert_eq!(g.convert::<Shire>(), s);
+            assert_eq!(s.convert::<Gregorian>(), g);
+        }
+    }
+
+    #[test]
+    fn weekdays() {
+        let s = Shire::try_from_common_date(CommonDate::new(2025, ShireMonth::Afteryule as u8, 1))
+            .unwrap();
+        assert_eq!(s.weekday(), Some(Weekday::Sunday));
+        let s = Shire::try_from_common_date(CommonDate::new(2025, ShireMonth::Afterlithe as u8, 1))
+            .unwrap();
+        assert_eq!(s.weekday(), Some(Weekday::Sunday));
+        let s = Shire::try_year_end(2024).unwrap();
+        assert_eq!(s.weekday(), Some(Weekday::Friday));
+        let s = Shire::try_from_common_date(CommonDate::new(2024, NON_MONTH, 4)).unwrap();
+        assert_eq!(s.weekday(), None);
+    }
+
+    #[test]
+    fn invalid() {
+        let c = CommonDate::new(2025, NON_MONTH, ShireComplementaryDay::Overlithe as u8);
+        assert!(matches!(
+            Shire::try_from_common_date(c),
+            Err(CalendarError::InvalidDay)
+        ));
+        let c = CommonDate::new(2025, NON_MONTH, 7);
+        assert!(Shire::try_from_common_date(c).is_err());
+        let c = CommonDate::new(2025, 13, 1);
+        assert!(Shire::try_from_common_date(c).is_err());
+        let c = CommonDate::new(2025, 1, 31);
+        assert!(Shire::try_from_common_date(c).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrip(t in FIXED_MIN..FIXED_MAX) {
+            let f = Fixed::new(t).to_day();
+            let s = Shire::from_fixed(f);
+            assert_eq!(s.to_fixed(), f);
+            assert_eq!(Shire::try_from_common_date(s.to_common_date()).unwrap(), s);
+            assert_eq!(Shire::try_from_ordinal(s.to_ordinal()).unwrap(), s);
+        }
+
+        #[test]
+        fn weekday_perennial(t in FIXED_MIN..FIXED_MAX) {
+            let f = Fixed::new(t).to_day();
+            let s0 = Shire::from_fixed(f);
+            let s1 = Shire::from_fixed(Fixed::cast_new(f.get_day_i() + 1));
+            match (s0.weekday(), s1.weekday()) {
+                (Some(w0), Some(w1)) if s1.to_ordinal().day_of_year == 1 => {
+                    assert_eq!(w0, Weekday::Friday);
+                    assert_eq!(w1, Weekday::Saturday);
+                }
+                (Some(w0), Some(w1)) => assert_eq!(
+                    ((w0 as i64) + 1).modulus(7),
+                    w1 as i64
+                ),
+                (None, None) => assert_eq!(s1.epagomenae(), Some(ShireComplementaryDay::Overlithe)),
+                (_, _) => (),
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::Gregorian;
+use crate::calendar::ToFromOrdinalDate;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::CalculatedBounds;
+use crate::day_count::Epoch;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+
+const STARDATE_EPOCH_YEAR: i32 = 2323;
+const UNITS_PER_YEAR: f64 = 1000.0;
+
+fn gregorian_year_start(year: i32) -> f64 {
+    (Gregorian::prior_elapsed_days(year) + 1) as f64
+}
+
+/// Represents a stardate in the style of *Star Trek: The Next Generation*
+///
+/// **This is not canonical.** The stardates used on screen were not generated by any
+/// consistent formula. `Stardate` uses a popular fan approximation, in which each
+/// Gregorian year is divided into 1000 equal units, and stardate 41000.0 is the start
+/// of 2364 Common Era (the first season of *The Next Generation*).
+///
+/// This means that the length of a unit of stardate is slightly longer in leap years.
+///
+/// This is internally a floating point number, where the fractional portion represents a
+/// particular time of day. Stardates before 2323 Common Era are negative.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+/// use radnelac::fiction::*;
+///
+/// let g = Gregorian::try_new(2364, GregorianMonth::January, 1).unwrap();
+/// assert_eq!(g.convert::<Stardate>().get(), 41000.0);
+/// let g = Gregorian::try_new(2364, GregorianMonth::July, 2).unwrap();
+/// assert_eq!(g.convert::<Stardate>().get().floor(), 41500.0);
+/// ```
+///
+/// ## Further reading
+/// + [Memory Alpha](https://memory-alpha.fandom.com/wiki/Stardate)
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default)]
+pub struct Stardate(f64);
+
+impl CalculatedBounds for Stardate {}
+
+impl FromFixed for Stardate {
+    fn from_fixed(t: Fixed) -> Stardate {
+        let year = Gregorian::ordinal_from_fixed(t).year;
+        let start = gregorian_year_start(year);
+        let length = gregorian_year_start(year + 1) - start;
+        let years = (year - STARDATE_EPOCH_YEAR) as f64 + ((t.get() - start) / length);
+        Stardate(years * UNITS_PER_YEAR)
+    }
+}
+
+impl ToFixed for Stardate {
+    fn to_fixed(self) -> Fixed {
+        let years = self.0 / UNITS_PER_YEAR;
+        let year = STARDATE_EPOCH_YEAR + (years.floor() as i32);
+        let start = gregorian_year_start(year);
+        let length = gregorian_year_start(year + 1) - start;
+        Fixed::new(start + (years - years.floor()) * length)
+    }
+}
+
+impl Epoch for Stardate {
+    fn epoch() -> Fixed {
+        Fixed::new(gregorian_year_start(STARDATE_EPOCH_YEAR))
+    }
+}
+
+impl BoundedDayCount<f64> for Stardate {
+    fn new(t: f64) -> Stardate {
+        debug_assert!(Stardate::in_effective_bounds(t).is_ok());
+        Stardate(t)
+    }
+    fn get(self) -> f64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::GregorianMonth;
+    use crate::calendar::GuaranteedMonth;
+    use crate::calendar::ToFromCommonDate;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use proptest::proptest;
+
+    #[test]
+    fn epoch() {
+        assert_eq!(Stardate::from_fixed(Stardate::epoch()).get(), 0.0);
+        let g = Gregorian::try_new(2323, GregorianMonth::January, 1).unwrap();
+        assert_eq!(g.to_fixed(), Stardate::epoch());
+        assert_eq!(Stardate::new(41000.0).convert::<Gregorian>().year(), 2364);
+        assert_eq!(Stardate::new(-0.5).convert::<Gregorian>().year(), 2322);
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrip(t in FIXED_MIN..FIXED_MAX) {
+            let f0 = Fixed::new(t);
+            let f1 = Stardate::from_fixed(f0).to_fixed();
+            assert!((f0.get() - f1.get()).abs() < 0.001);
+        }
+
+        #[test]
+        fn year_is_1000_units(year in -100000..100000) {
+            let f0 = Fixed::new(gregorian_year_start(year));
+            let f1 = Fixed::new(gregorian_year_start(year + 1));
+            let s0 = Stardate::from_fixed(f0).get();
+            let s1 = Stardate::from_fixed(f1).get();
+            assert_eq!(s1 - s0, 1000.0);
+        }
+    }
+}
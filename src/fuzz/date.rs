@@ -0,0 +1,128 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::*;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::JulianDay;
+use crate::day_count::ModifiedJulianDay;
+use crate::day_count::RataDie;
+use crate::day_count::UnixMoment;
+use crate::day_count::FIXED_MAX;
+use crate::day_count::FIXED_MIN;
+#[cfg(feature = "fiction")]
+use crate::fiction::Shire;
+use arbitrary::Arbitrary;
+use arbitrary::Result;
+use arbitrary::Unstructured;
+
+fn arbitrary_day(u: &mut Unstructured<'_>) -> Result<Fixed> {
+    let day = u.int_in_range((FIXED_MIN as i64)..=(FIXED_MAX as i64))?;
+    Ok(Fixed::cast_new(day))
+}
+
+impl<'a> Arbitrary<'a> for Fixed {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let day = arbitrary_day(u)?.get();
+        let fraction = (u.arbitrary::<u32>()? as f64) / ((u32::MAX as f64) + 1.0);
+        Ok(Fixed::new((day + fraction).min(FIXED_MAX)))
+    }
+}
+
+impl<'a, T: FromFixed> Arbitrary<'a> for CalendarMoment<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::from_fixed(Fixed::arbitrary(u)?))
+    }
+}
+
+impl<'a, B: FromFixed, const Y: i32, const D: i64> Arbitrary<'a> for OffsetCalendar<B, Y, D> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::from_fixed(arbitrary_day(u)?))
+    }
+}
+
+macro_rules! arbitrary_from_fixed {
+    ([$($g:tt)*] $t:ty, $f:path) => {
+        impl<'a, $($g)*> Arbitrary<'a> for $t {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                Ok(Self::from_fixed($f(u)?))
+            }
+        }
+    };
+    ($t:ty, $f:path) => {
+        arbitrary_from_fixed!([] $t, $f);
+    };
+}
+
+arbitrary_from_fixed!(JulianDay, Fixed::arbitrary);
+arbitrary_from_fixed!(ModifiedJulianDay, Fixed::arbitrary);
+arbitrary_from_fixed!(RataDie, Fixed::arbitrary);
+arbitrary_from_fixed!(UnixMoment, Fixed::arbitrary);
+
+arbitrary_from_fixed!(Gregorian, arbitrary_day);
+arbitrary_from_fixed!(Bengali, arbitrary_day);
+arbitrary_from_fixed!(BikramSambat, arbitrary_day);
+arbitrary_from_fixed!(Tamil, arbitrary_day);
+arbitrary_from_fixed!(IslamicArith, arbitrary_day);
+arbitrary_from_fixed!(IslamicUmmAlQura, arbitrary_day);
+arbitrary_from_fixed!(ISO, arbitrary_day);
+arbitrary_from_fixed!(Julian, arbitrary_day);
+arbitrary_from_fixed!(PersianArith, arbitrary_day);
+arbitrary_from_fixed!([const P: u16, const M: u8, const W: u8, const N: bool] Retail<P, M, W, N>, arbitrary_day);
+#[cfg(feature = "ancient-calendars")]
+arbitrary_from_fixed!(Armenian, arbitrary_day);
+#[cfg(feature = "ancient-calendars")]
+arbitrary_from_fixed!(AtticArith, arbitrary_day);
+#[cfg(feature = "ancient-calendars")]
+arbitrary_from_fixed!(BabylonianArith, arbitrary_day);
+#[cfg(feature = "ancient-calendars")]
+arbitrary_from_fixed!([const S: bool] Coligny<S>, arbitrary_day);
+#[cfg(feature = "ancient-calendars")]
+arbitrary_from_fixed!(Coptic, arbitrary_day);
+#[cfg(feature = "ancient-calendars")]
+arbitrary_from_fixed!(Egyptian, arbitrary_day);
+#[cfg(feature = "ancient-calendars")]
+arbitrary_from_fixed!(Ethiopic, arbitrary_day);
+#[cfg(feature = "ancient-calendars")]
+arbitrary_from_fixed!(Icelandic, arbitrary_day);
+#[cfg(feature = "ancient-calendars")]
+arbitrary_from_fixed!(Roman, arbitrary_day);
+#[cfg(feature = "reform-calendars")]
+arbitrary_from_fixed!(Cotsworth, arbitrary_day);
+#[cfg(feature = "reform-calendars")]
+arbitrary_from_fixed!([const L: bool] FrenchRevArith<L>, arbitrary_day);
+#[cfg(feature = "reform-calendars")]
+arbitrary_from_fixed!(Holocene, arbitrary_day);
+#[cfg(feature = "reform-calendars")]
+arbitrary_from_fixed!(Pataphysical, arbitrary_day);
+#[cfg(feature = "reform-calendars")]
+arbitrary_from_fixed!(Positivist, arbitrary_day);
+#[cfg(feature = "reform-calendars")]
+arbitrary_from_fixed!([const T: bool, const U: bool] Symmetry<T, U>, arbitrary_day);
+#[cfg(feature = "reform-calendars")]
+arbitrary_from_fixed!(Tranquility, arbitrary_day);
+#[cfg(feature = "fiction")]
+arbitrary_from_fixed!(Shire, arbitrary_day);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_count::ToFixed;
+    use proptest::proptest;
+
+    proptest! {
+        #[test]
+        fn valid(data: Vec<u8>) {
+            let mut u = Unstructured::new(&data);
+            let g = Gregorian::arbitrary(&mut u).unwrap();
+            assert!(Gregorian::valid_ymd(g.to_common_date()).is_ok());
+            assert_eq!(g.to_fixed(), g.to_fixed().to_day());
+            let f = Fixed::arbitrary(&mut u).unwrap();
+            assert!((FIXED_MIN..=FIXED_MAX).contains(&f.get()));
+            let m = GregorianMoment::arbitrary(&mut u).unwrap();
+            assert_eq!(GregorianMoment::from_fixed(m.to_fixed()), m);
+        }
+    }
+}
@@ -32,9 +32,22 @@
 //! mechanism. Applications should disable features they are not using to reduce the number
 //! of dependencies, size of binaries and time spent compiling.
 //!
-//! The following feature is available:
+//! The following features are available:
 //!
 //! - `display` (*enabled by default*): implements [std::fmt::Display] and string conversion for all supported timekeeping systems
+//! - `ancient-calendars` (*enabled by default*): the Egyptian, Armenian, Attic, Babylonian, Coligny, Coptic, Ethiopic, Icelandic and Roman calendars, and Olympiads
+//! - `reform-calendars` (*enabled by default*): the Cotsworth, Positivist, Symmetry, Tranquility, French Revolutionary, Holocene and Pataphysical calendars, and Erisian years
+//! - `arrow`: converts columns of integers with Arrow `Date32` and `Timestamp` semantics into dates
+//! - `arbitrary`: implements [arbitrary](https://docs.rs/arbitrary)`::Arbitrary` for dates, for use in fuzzing
+//! - `rayon`: formats many dates in parallel using [rayon](https://docs.rs/rayon)
+//! - `fiction`: fictional and non-canonical timekeeping systems such as the Shire calendar, *Star Trek* stardates, and Long Now years
+//! - `proptest`: strategies for property testing using [proptest](https://docs.rs/proptest)
+//! - `serde`: serializes and deserializes dates using [serde](https://docs.rs/serde) in a stable format
+//! - `tracing`: emits trace-level events from conversion functions using [tracing](https://docs.rs/tracing)
+//!
+//! The Gregorian, Julian, ISO, Persian, tabular and Umm al-Qura Islamic, revised Bengali, Bikram Sambat, Tamil and retail calendars (along with calendars which only
+//! renumber the years of the Gregorian or Persian calendars) are always available. Disabling `ancient-calendars` and
+//! `reform-calendars` leaves only these calendars.
 //!
 //! ## Limitations
 //!
@@ -55,7 +68,6 @@
 //! but not supported yet:
 //! - predicting astronomical events (ex. lunar phases, eclipses, equinoxes)
 //! - astronomical calendars (ex. Chinese Lunar calendar)
-//! - arranging days in a grid
 //! - parsing dates
 //! - reading and writing CalDAV
 //!
@@ -82,8 +94,10 @@
 //! ### Year Zero and Negative Years
 //!
 //! Additionally most calendars, including the Gregorian, are assumed to have a Year 0. One
-//! notable exception is the Julian. If a calendar does not allow Year 0, it will implement
-//! [`calendar::AllowYearZero`].
+//! notable exception is the Julian. If a calendar allows Year 0, it will implement
+//! [`calendar::AllowYearZero`]. The treatment of Year 0 in each calendar is described by
+//! [`calendar::YearNumbering`], which can also convert between astronomical and historical
+//! year numbering.
 //!
 //! The following is a quotation from Chapter 1.16 of *Calendrical Calculations: The Ultimate
 //! Edition* by Reingold & Dershowitz, which applies quite well to this situation.
@@ -105,19 +119,36 @@ extern crate num_derive;
 pub mod clock {
     mod time_of_day;
 
+    mod canonical;
+    mod hour12;
+    mod italian;
+    mod part_of_day;
+
+    pub use canonical::CanonicalHour;
+    pub use hour12::HalfDay;
+    pub use hour12::Hour12;
+    pub use italian::ItalianHour;
+    pub use part_of_day::PartOfDay;
     pub use time_of_day::ClockTime;
+    pub use time_of_day::SecondsRounding;
     pub use time_of_day::TimeOfDay;
 }
 mod common {
     pub mod error;
     pub mod math;
+    pub mod trace;
+    pub mod units;
 }
 pub use common::error::CalendarError;
 /// Timekeeping systems which identify a day using a single field
 pub mod day_count {
     mod prelude;
 
+    mod arith;
+    mod date32;
+    mod event;
     mod fixed;
+    mod interval;
     mod jd;
     mod mjd;
     mod rd;
@@ -125,13 +156,23 @@ pub mod day_count {
 
     pub use prelude::*;
 
+    pub use arith::Days;
+    pub use date32::Date32;
+    pub use event::Event;
+    pub use fixed::convert;
+    pub use fixed::epoch_offset;
     pub use fixed::CalculatedBounds;
+    pub use fixed::DayRounding;
     pub use fixed::Epoch;
     pub use fixed::Fixed;
     pub use fixed::FromFixed;
+    pub use fixed::SameDay;
     pub use fixed::ToFixed;
+    pub use fixed::ToFromEpochDays;
     pub use fixed::FIXED_MAX;
     pub use fixed::FIXED_MIN;
+    pub use interval::FixedInterval;
+    pub use interval::IntervalEndpoint;
     pub use jd::JulianDay;
     pub use mjd::ModifiedJulianDay;
     pub use rd::RataDie;
@@ -156,81 +197,409 @@ pub mod calendar {
     mod moment;
     mod prelude;
 
+    mod anniversary;
+    mod approx;
+    #[cfg(feature = "ancient-calendars")]
     mod armenian;
+    #[cfg(feature = "ancient-calendars")]
+    mod attic;
+    #[cfg(feature = "ancient-calendars")]
+    mod babylonian;
+    mod bengali;
+    mod bikram_sambat;
+    #[cfg(feature = "ancient-calendars")]
+    mod coligny;
+    mod converter;
+    #[cfg(feature = "ancient-calendars")]
     mod coptic;
+    mod correlation;
+    #[cfg(feature = "reform-calendars")]
     mod cotsworth;
+    #[cfg(feature = "ancient-calendars")]
     mod egyptian;
+    mod era;
+    #[cfg(feature = "ancient-calendars")]
     mod ethiopic;
+    #[cfg(feature = "reform-calendars")]
     mod french_rev_arith;
     mod gregorian;
+    mod grid;
+    mod hindu;
+    #[cfg(feature = "reform-calendars")]
     mod holocene;
+    #[cfg(feature = "ancient-calendars")]
+    mod icelandic;
+    mod info;
+    mod islamic;
     mod iso;
     mod julian;
+    mod leap_week;
+    #[cfg(feature = "ancient-calendars")]
+    mod metonic;
+    mod month_table;
+    mod offset;
+    #[cfg(feature = "ancient-calendars")]
     mod olympiad;
+    #[cfg(feature = "reform-calendars")]
+    mod pataphysical;
+    mod persian;
+    #[cfg(feature = "reform-calendars")]
+    mod policy;
+    #[cfg(feature = "reform-calendars")]
     mod positivist;
+    mod rest_days;
+    mod retail;
+    #[cfg(feature = "ancient-calendars")]
     mod roman;
+    mod stats;
+    #[cfg(feature = "reform-calendars")]
     mod symmetry;
+    mod tamil;
+    #[cfg(feature = "reform-calendars")]
+    mod thirteen_month;
+    #[cfg(feature = "reform-calendars")]
     mod tranquility;
+    mod umm_al_qura;
 
     pub use moment::CalendarMoment;
     pub use prelude::*;
 
+    pub use anniversary::Anniversary;
+    pub use anniversary::AnniversaryPolicy;
+    pub use approx::ApproxDate;
+    pub use approx::ApproxQualifier;
+    #[cfg(feature = "ancient-calendars")]
     pub use armenian::Armenian;
+    #[cfg(feature = "ancient-calendars")]
     pub use armenian::ArmenianDaysOfMonth;
+    #[cfg(feature = "ancient-calendars")]
     pub use armenian::ArmenianMoment;
+    #[cfg(feature = "ancient-calendars")]
     pub use armenian::ArmenianMonth;
+    #[cfg(feature = "ancient-calendars")]
+    pub use attic::AtticArith;
+    #[cfg(feature = "ancient-calendars")]
+    pub use attic::AtticArithMoment;
+    #[cfg(feature = "ancient-calendars")]
+    pub use attic::AtticMonth;
+    #[cfg(feature = "ancient-calendars")]
+    pub use babylonian::BabylonianArith;
+    #[cfg(feature = "ancient-calendars")]
+    pub use babylonian::BabylonianArithMoment;
+    #[cfg(feature = "ancient-calendars")]
+    pub use babylonian::BabylonianMonth;
+    pub use bengali::Bengali;
+    pub use bengali::BengaliMoment;
+    pub use bengali::BengaliMonth;
+    pub use bikram_sambat::BikramSambat;
+    pub use bikram_sambat::BikramSambatMoment;
+    pub use bikram_sambat::BikramSambatMonth;
+    #[cfg(feature = "ancient-calendars")]
+    pub use coligny::Coligny;
+    #[cfg(feature = "ancient-calendars")]
+    pub use coligny::ColignyMoment;
+    #[cfg(feature = "ancient-calendars")]
+    pub use coligny::ColignyMonth;
+    pub use converter::Converter;
+    #[cfg(feature = "ancient-calendars")]
     pub use coptic::Coptic;
+    #[cfg(feature = "ancient-calendars")]
     pub use coptic::CopticMoment;
+    #[cfg(feature = "ancient-calendars")]
     pub use coptic::CopticMonth;
+    pub use correlation::Correlation;
+    pub use correlation::CorrelationTable;
+    #[cfg(feature = "reform-calendars")]
     pub use cotsworth::Cotsworth;
+    #[cfg(feature = "reform-calendars")]
     pub use cotsworth::CotsworthComplementaryDay;
+    #[cfg(feature = "reform-calendars")]
     pub use cotsworth::CotsworthMoment;
+    #[cfg(feature = "reform-calendars")]
     pub use cotsworth::CotsworthMonth;
+    #[cfg(feature = "ancient-calendars")]
     pub use egyptian::Egyptian;
+    #[cfg(feature = "ancient-calendars")]
     pub use egyptian::EgyptianDaysUponTheYear;
+    #[cfg(feature = "ancient-calendars")]
     pub use egyptian::EgyptianMoment;
+    #[cfg(feature = "ancient-calendars")]
     pub use egyptian::EgyptianMonth;
+    pub use era::Era;
+    pub use era::EraOffset;
+    #[cfg(feature = "ancient-calendars")]
     pub use ethiopic::Ethiopic;
+    #[cfg(feature = "ancient-calendars")]
     pub use ethiopic::EthiopicMoment;
+    #[cfg(feature = "ancient-calendars")]
     pub use ethiopic::EthiopicMonth;
+    #[cfg(feature = "reform-calendars")]
     pub use french_rev_arith::FrenchRevArith;
+    #[cfg(feature = "reform-calendars")]
     pub use french_rev_arith::FrenchRevArithMoment;
+    #[cfg(feature = "reform-calendars")]
     pub use french_rev_arith::FrenchRevMonth;
+    #[cfg(feature = "reform-calendars")]
     pub use french_rev_arith::FrenchRevWeekday;
+    #[cfg(feature = "reform-calendars")]
     pub use french_rev_arith::Sansculottide;
+    pub use gregorian::days_in_gregorian_month;
+    pub use gregorian::is_valid_gregorian_date;
     pub use gregorian::Gregorian;
     pub use gregorian::GregorianMoment;
     pub use gregorian::GregorianMonth;
+    pub use grid::day_of_week_of;
+    pub use grid::GridWeek;
+    pub use grid::WeekRule;
+    #[cfg(feature = "reform-calendars")]
     pub use holocene::Holocene;
+    #[cfg(feature = "reform-calendars")]
     pub use holocene::HoloceneMoment;
+    #[cfg(feature = "reform-calendars")]
     pub use holocene::HoloceneMonth;
+    #[cfg(feature = "ancient-calendars")]
+    pub use icelandic::Icelandic;
+    #[cfg(feature = "ancient-calendars")]
+    pub use icelandic::IcelandicMoment;
+    #[cfg(feature = "ancient-calendars")]
+    pub use icelandic::IcelandicMonth;
+    #[cfg(feature = "ancient-calendars")]
+    pub use icelandic::IcelandicSeason;
+    pub use info::all;
+    pub use info::CalendarFamily;
+    pub use info::CalendarInfo;
+    pub use info::YearZeroPolicy;
+    pub use islamic::IslamicArith;
+    pub use islamic::IslamicArithMoment;
+    pub use islamic::IslamicMonth;
     pub use iso::ISOMoment;
+    pub use iso::ISOWeekDate;
     pub use iso::ISO;
+    pub use julian::days_in_julian_month;
+    pub use julian::is_valid_julian_date;
     pub use julian::Julian;
     pub use julian::JulianMoment;
     pub use julian::JulianMonth;
+    pub use leap_week::LeapWeekCalendar;
+    pub use month_table::MonthTable;
+    #[cfg(feature = "reform-calendars")]
+    pub use offset::ErisianYear;
+    #[cfg(feature = "reform-calendars")]
+    pub use offset::ErisianYearMoment;
+    pub use offset::Minguo;
+    pub use offset::MinguoMoment;
+    pub use offset::OffsetCalendar;
+    pub use offset::ThaiSolar;
+    pub use offset::ThaiSolarMoment;
+    #[cfg(feature = "ancient-calendars")]
     pub use olympiad::Olympiad;
+    #[cfg(feature = "reform-calendars")]
+    pub use pataphysical::Pataphysical;
+    #[cfg(feature = "reform-calendars")]
+    pub use pataphysical::PataphysicalMoment;
+    #[cfg(feature = "reform-calendars")]
+    pub use pataphysical::PataphysicalMonth;
+    pub use persian::Afghan;
+    pub use persian::AfghanMoment;
+    pub use persian::AfghanMonth;
+    pub use persian::Kurdish;
+    pub use persian::KurdishMoment;
+    pub use persian::KurdishMonth;
+    pub use persian::PersianArith;
+    pub use persian::PersianArithMoment;
+    pub use persian::PersianMonth;
+    #[cfg(feature = "reform-calendars")]
+    pub use policy::PolicyAmbiguity;
+    #[cfg(feature = "reform-calendars")]
     pub use positivist::Positivist;
+    #[cfg(feature = "reform-calendars")]
     pub use positivist::PositivistComplementaryDay;
+    #[cfg(feature = "reform-calendars")]
     pub use positivist::PositivistMoment;
+    #[cfg(feature = "reform-calendars")]
     pub use positivist::PositivistMonth;
+    pub use rest_days::RestDays;
+    pub use rest_days::RestWeekdays;
+    pub use retail::Retail;
+    pub use retail::Retail445;
+    pub use retail::Retail445Moment;
+    pub use retail::Retail454;
+    pub use retail::Retail454Moment;
+    pub use retail::Retail544;
+    pub use retail::Retail544Moment;
+    pub use retail::RetailPeriod;
+    #[cfg(feature = "ancient-calendars")]
     pub use roman::Roman;
+    #[cfg(feature = "ancient-calendars")]
     pub use roman::RomanMonth;
+    #[cfg(feature = "ancient-calendars")]
     pub use roman::RomanMonthlyEvent;
+    pub use stats::day_of_month_weekdays;
+    pub use stats::leap_year_density;
+    pub use stats::month_start_weekdays;
+    pub use stats::year_start_weekdays;
+    pub use stats::WeekdayDistribution;
+    #[cfg(feature = "reform-calendars")]
     pub use symmetry::Symmetry;
+    #[cfg(feature = "reform-calendars")]
     pub use symmetry::Symmetry010;
+    #[cfg(feature = "reform-calendars")]
     pub use symmetry::Symmetry010Moment;
+    #[cfg(feature = "reform-calendars")]
     pub use symmetry::Symmetry010Solstice;
+    #[cfg(feature = "reform-calendars")]
     pub use symmetry::Symmetry010SolsticeMoment;
+    #[cfg(feature = "reform-calendars")]
     pub use symmetry::Symmetry454;
+    #[cfg(feature = "reform-calendars")]
     pub use symmetry::Symmetry454Moment;
+    #[cfg(feature = "reform-calendars")]
     pub use symmetry::Symmetry454Solstice;
+    #[cfg(feature = "reform-calendars")]
     pub use symmetry::Symmetry454SolsticeMoment;
+    #[cfg(feature = "reform-calendars")]
     pub use symmetry::SymmetryMonth;
+    pub use tamil::Tamil;
+    pub use tamil::TamilMoment;
+    pub use tamil::TamilMonth;
+    #[cfg(feature = "reform-calendars")]
     pub use tranquility::Tranquility;
+    #[cfg(feature = "reform-calendars")]
     pub use tranquility::TranquilityComplementaryDay;
+    #[cfg(feature = "reform-calendars")]
     pub use tranquility::TranquilityMoment;
+    #[cfg(feature = "reform-calendars")]
     pub use tranquility::TranquilityMonth;
+    pub use umm_al_qura::IslamicUmmAlQura;
+    pub use umm_al_qura::IslamicUmmAlQuraMoment;
+}
+/// Integer arithmetic underlying the calendars in this crate
+///
+/// These functions are used internally by the calendars in [`calendar`], and are exposed
+/// so that applications can build custom calendars on the same arithmetic. They operate
+/// on plain integers, do not construct any dates, and do not check whether their inputs
+/// are within the supported range of time.
+///
+/// The day counts are relative to the start of year 1 of the calendar, rather than to
+/// any epoch used elsewhere in this crate.
+///
+/// ```
+/// use radnelac::raw::*;
+///
+/// assert!(is_gregorian_leap_year(2024));
+/// assert_eq!(gregorian_days_before_year(2), 365);
+/// assert_eq!(days_before_month(3, true), 60);
+/// assert_eq!(julian_days_before_year(-1), -366);
+/// ```
+pub mod raw {
+    mod gregorian;
+    mod julian;
+
+    pub use gregorian::days_before_month;
+    pub use gregorian::gregorian_days_before_year;
+    pub use gregorian::gregorian_leap_days_before_year;
+    pub use gregorian::is_gregorian_leap_year;
+    pub use julian::is_julian_leap_year;
+    pub use julian::julian_days_before_year;
+    pub use julian::julian_leap_days_before_year;
+}
+/// Mathematical utilities underlying the timekeeping systems in this crate
+///
+/// ```
+/// use radnelac::math::*;
+///
+/// assert_eq!(normalize_degrees(-45.0), 315.0);
+/// ```
+pub mod math {
+    pub use crate::common::math::degrees_to_hours;
+    pub use crate::common::math::fixed_days_matching;
+    pub use crate::common::math::hours_to_degrees;
+    pub use crate::common::math::list_of_fixed_from_moments;
+    pub use crate::common::math::normalize_degrees;
+    pub use crate::common::math::normalize_degrees_signed;
+    pub use crate::common::math::positions_in_range;
+    pub use crate::common::math::range;
+    pub use crate::common::math::scan_range;
+    pub use crate::common::math::DegreesMinutesSeconds;
+}
+/// Fixed relationships between units of time
+///
+/// Every day has exactly 24 hours of 60 minutes of 60 seconds: like Unix time, this crate
+/// ignores leap seconds. The conversions between integer units return [`None`] instead of
+/// overflowing.
+///
+/// ```
+/// use radnelac::day_count::*;
+/// use radnelac::units::*;
+///
+/// let week_later = UnixMoment::new(days_to_seconds(7).unwrap());
+/// assert_eq!(week_later.get(), SECONDS_PER_WEEK);
+/// assert_eq!(seconds_to_days(week_later.get() + 90), (7, 90));
+/// assert_eq!(seconds_to_day_fraction(43200.0), 0.5);
+/// ```
+pub mod units {
+    pub use crate::common::units::day_fraction_to_seconds;
+    pub use crate::common::units::days_to_minutes;
+    pub use crate::common::units::days_to_seconds;
+    pub use crate::common::units::days_to_weeks;
+    pub use crate::common::units::minutes_to_days;
+    pub use crate::common::units::seconds_to_day_fraction;
+    pub use crate::common::units::seconds_to_days;
+    pub use crate::common::units::weeks_to_days;
+    pub use crate::common::units::DAYS_PER_WEEK;
+    pub use crate::common::units::HOURS_PER_DAY;
+    pub use crate::common::units::MILLIS_PER_DAY;
+    pub use crate::common::units::MINUTES_PER_DAY;
+    pub use crate::common::units::MINUTES_PER_HOUR;
+    pub use crate::common::units::SECONDS_PER_DAY;
+    pub use crate::common::units::SECONDS_PER_HOUR;
+    pub use crate::common::units::SECONDS_PER_MINUTE;
+    pub use crate::common::units::SECONDS_PER_WEEK;
+}
+/// Describing a moment in every timekeeping system at once
+///
+/// This is intended for diagnostics, and for demonstrations such as GUIs and web pages.
+///
+/// ```
+/// use radnelac::day_count::*;
+/// use radnelac::report::*;
+///
+/// for e in report(Fixed::new(739458.0)) {
+///     assert!(!e.debug.is_empty());
+/// }
+/// ```
+pub mod report {
+    mod summary;
+
+    pub use summary::report;
+    pub use summary::ReportEntry;
+}
+/// Converting columns of integers into dates
+/// ## Crate Features
+///
+/// This module is only available if `arrow` is enabled.
+#[cfg(feature = "arrow")]
+pub mod arrow {
+    mod column;
+
+    pub use column::*;
+}
+/// Timekeeping systems from fiction and other non-canonical sources
+/// ## Crate Features
+///
+/// This module is only available if `fiction` is enabled.
+#[cfg(feature = "fiction")]
+pub mod fiction {
+    mod long_now;
+    mod shire;
+    mod stardate;
+
+    pub use long_now::LongNowYear;
+    pub use shire::Shire;
+    pub use shire::ShireComplementaryDay;
+    pub use shire::ShireMoment;
+    pub use shire::ShireMonth;
+    pub use stardate::Stardate;
 }
 /// Formatting datestamps and timestamps
 /// ## Crate Features
@@ -249,41 +618,192 @@ pub mod display {
     }
 
     mod akan;
+    #[cfg(feature = "ancient-calendars")]
     mod armenian;
+    mod bengali;
+    #[cfg(feature = "rayon")]
+    mod bulk;
     mod clock;
+    #[cfg(feature = "ancient-calendars")]
     mod coptic;
+    #[cfg(feature = "reform-calendars")]
     mod cotsworth;
+    mod cycle;
+    mod day_count;
+    mod dual;
+    #[cfg(feature = "ancient-calendars")]
     mod egyptian;
+    mod era;
+    #[cfg(feature = "ancient-calendars")]
     mod ethiopic;
+    mod event;
+    #[cfg(feature = "fiction")]
+    mod fiction;
+    #[cfg(feature = "reform-calendars")]
     mod french_rev;
     mod gregorian;
+    #[cfg(feature = "reform-calendars")]
     mod holocene;
     mod iso;
     mod julian;
+    mod localized;
+    mod month;
+    #[cfg(feature = "reform-calendars")]
     mod positivist;
+    mod retail;
+    #[cfg(feature = "ancient-calendars")]
     mod roman;
+    #[cfg(feature = "reform-calendars")]
     mod symmetry;
+    mod tamil;
+    #[cfg(feature = "reform-calendars")]
     mod tranquility;
     mod week;
+    mod week_header;
 
     pub use moment::*;
     pub use prelude::*;
 
     pub use akan::*;
+    #[cfg(feature = "ancient-calendars")]
     pub use armenian::*;
+    pub use bengali::*;
+    #[cfg(feature = "rayon")]
+    pub use bulk::*;
     pub use clock::*;
+    #[cfg(feature = "ancient-calendars")]
     pub use coptic::*;
+    #[cfg(feature = "reform-calendars")]
     pub use cotsworth::*;
+    pub use cycle::*;
+    pub use day_count::*;
+    pub use dual::*;
+    #[cfg(feature = "ancient-calendars")]
     pub use egyptian::*;
+    pub use era::*;
+    #[cfg(feature = "ancient-calendars")]
     pub use ethiopic::*;
+    pub use event::*;
+    #[cfg(feature = "reform-calendars")]
     pub use french_rev::*;
     pub use gregorian::*;
+    #[cfg(feature = "reform-calendars")]
     pub use holocene::*;
     pub use iso::*;
     pub use julian::*;
+    pub use localized::*;
+    pub use month::*;
+    #[cfg(feature = "reform-calendars")]
     pub use positivist::*;
+    pub use retail::*;
+    #[cfg(feature = "ancient-calendars")]
     pub use roman::*;
+    #[cfg(feature = "reform-calendars")]
     pub use symmetry::*;
+    pub use tamil::*;
+    #[cfg(feature = "reform-calendars")]
     pub use tranquility::*;
     pub use week::*;
+    pub use week_header::*;
+}
+#[cfg(feature = "arbitrary")]
+mod fuzz {
+    mod date;
+}
+/// Stable serialized representation of dates
+///
+/// Dates in calendar systems are serialized as a map with the following fields:
+/// + `version`: the value of [`FORMAT_VERSION`](serialize::FORMAT_VERSION) when the data was written
+/// + `year`, `month` and `day`: the fields of the date's [`CommonDate`](calendar::CommonDate)
+///
+/// [`CalendarMoment`](calendar::CalendarMoment) adds the fields `hours`, `minutes` and `seconds`.
+/// Day counts such as [`Fixed`](day_count::Fixed) and [`UnixMoment`](day_count::UnixMoment)
+/// are serialized as plain numbers.
+///
+/// ```
+/// use radnelac::calendar::*;
+///
+/// let g = Gregorian::try_new(2025, GregorianMonth::May, 15).unwrap();
+/// let s = serde_json::to_string(&g).unwrap();
+/// assert_eq!(s, r#"{"version":1,"year":2025,"month":5,"day":15}"#);
+/// assert_eq!(serde_json::from_str::<Gregorian>(&s).unwrap(), g);
+/// ```
+///
+/// ### Compatibility
+///
+/// The field names will not change. If the representation ever needs to change, the version
+/// number will be incremented, and data written with older version numbers will still be
+/// accepted. If the `version` field is missing, the data is treated as a raw `{year, month, day}`
+/// map, which is also accepted. Deserialization checks that the date is valid.
+///
+/// ## Crate Features
+///
+/// This module is only available if `serde` is enabled.
+#[cfg(feature = "serde")]
+pub mod serialize {
+    mod date;
+    mod day_count;
+
+    pub use date::common_date_from_raw;
+    pub use date::SerialDate;
+    pub use date::FORMAT_VERSION;
+}
+/// Strategies for property testing with [proptest](https://docs.rs/proptest)
+///
+/// Applications can use these strategies to test their own code with valid dates in
+/// any supported timekeeping system.
+///
+/// ## Crate Features
+///
+/// This module is only available if `proptest` is enabled.
+#[cfg(feature = "proptest")]
+pub mod strategy {
+    mod date;
+
+    pub use date::any_common_date;
+    pub use date::any_day;
+    pub use date::any_moment;
+}
+/// Commonly used traits and types
+///
+/// ```
+/// use radnelac::prelude::*;
+/// use radnelac::calendar::Gregorian;
+/// use radnelac::calendar::GregorianMonth;
+/// use radnelac::calendar::Julian;
+///
+/// let g = Gregorian::try_new(2025, GregorianMonth::July, 26).unwrap();
+/// let j = g.convert::<Julian>();
+/// assert_eq!(j.to_common_date(), CommonDate::new(2025, 7, 13));
+/// assert_eq!(j.convert::<Weekday>(), Weekday::Saturday);
+/// ```
+pub mod prelude {
+    pub use crate::calendar::CalendarMoment;
+    pub use crate::calendar::CommonDate;
+    pub use crate::calendar::CommonWeekOfYear;
+    pub use crate::calendar::GuaranteedMonth;
+    pub use crate::calendar::HasLeapYears;
+    pub use crate::calendar::OrdinalDate;
+    pub use crate::calendar::Perennial;
+    pub use crate::calendar::Quarter;
+    pub use crate::calendar::ToFromCommonDate;
+    pub use crate::calendar::ToFromOrdinalDate;
+    pub use crate::calendar::YearNumbering;
+    pub use crate::clock::ClockTime;
+    pub use crate::clock::TimeOfDay;
+    pub use crate::day_count::BoundedDayCount;
+    pub use crate::day_count::EffectiveBound;
+    pub use crate::day_count::Epoch;
+    pub use crate::day_count::Fixed;
+    pub use crate::day_count::FromFixed;
+    pub use crate::day_count::SameDay;
+    pub use crate::day_count::ToFixed;
+    pub use crate::day_cycle::BoundedCycle;
+    pub use crate::day_cycle::OnOrBefore;
+    pub use crate::day_cycle::Weekday;
+    #[cfg(feature = "display")]
+    pub use crate::display::Language;
+    #[cfg(feature = "display")]
+    pub use crate::display::PresetDisplay;
+    pub use crate::CalendarError;
 }
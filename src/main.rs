@@ -2,39 +2,108 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use num_traits::FromPrimitive;
+use radnelac::calendar::all;
+use radnelac::calendar::Afghan;
 use radnelac::calendar::Armenian;
+use radnelac::calendar::AtticArith;
+use radnelac::calendar::BabylonianArith;
+use radnelac::calendar::Bengali;
+use radnelac::calendar::BikramSambat;
+use radnelac::calendar::Coligny;
 use radnelac::calendar::Coptic;
+use radnelac::calendar::CorrelationTable;
 use radnelac::calendar::Cotsworth;
 use radnelac::calendar::Egyptian;
+use radnelac::calendar::ErisianYear;
 use radnelac::calendar::Ethiopic;
 use radnelac::calendar::FrenchRevArith;
 use radnelac::calendar::Gregorian;
 use radnelac::calendar::Holocene;
+use radnelac::calendar::IslamicArith;
+use radnelac::calendar::IslamicUmmAlQura;
 use radnelac::calendar::Julian;
-use radnelac::calendar::Olympiad;
+use radnelac::calendar::Kurdish;
+use radnelac::calendar::Minguo;
+use radnelac::calendar::Pataphysical;
+use radnelac::calendar::PersianArith;
 use radnelac::calendar::Positivist;
-use radnelac::calendar::Roman;
+use radnelac::calendar::Retail445;
+use radnelac::calendar::Retail454;
+use radnelac::calendar::Retail544;
 use radnelac::calendar::Symmetry010;
 use radnelac::calendar::Symmetry010Solstice;
 use radnelac::calendar::Symmetry454;
 use radnelac::calendar::Symmetry454Solstice;
-use radnelac::calendar::TranquilityMoment;
+use radnelac::calendar::Tamil;
+use radnelac::calendar::ThaiSolar;
+use radnelac::calendar::Tranquility;
+#[cfg(feature = "display")]
 use radnelac::calendar::ISO;
-use radnelac::clock::TimeOfDay;
-use radnelac::day_count::BoundedDayCount;
-use radnelac::day_count::EffectiveBound;
-use radnelac::day_count::Fixed;
-use radnelac::day_count::FromFixed;
-use radnelac::day_count::JulianDay;
-use radnelac::day_count::ModifiedJulianDay;
+#[cfg(feature = "display")]
 use radnelac::day_count::RataDie;
-use radnelac::day_count::ToFixed;
 use radnelac::day_count::UnixMoment;
-use radnelac::day_cycle::Akan;
-use radnelac::day_cycle::Weekday;
+#[cfg(feature = "fiction")]
+use radnelac::fiction::Shire;
+use radnelac::prelude::*;
+use radnelac::report::report;
+use std::env;
+#[cfg(feature = "display")]
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+use std::process::ExitCode;
 use std::time::{SystemTime, UNIX_EPOCH};
 
-fn main() {
+/// Largest number of years in the output of the table subcommand
+const MAX_TABLE_YEARS: i64 = 10000;
+
+#[cfg(feature = "display")]
+const DOC_USAGE: &str = "Usage: radnelac doc [--format markdown|html] [--lang en|fr] \
+[--date <rata die>]
+
+Prints the date in every calendar which supports display, as a table.
+The date defaults to today.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(|s| s.as_str()) {
+        None => {
+            print_summary();
+            ExitCode::SUCCESS
+        }
+        Some("list") => {
+            print_list();
+            ExitCode::SUCCESS
+        }
+        Some("table") => match table_command(&args[1..]) {
+            Ok(s) => {
+                print!("{}", s);
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("{}\n\n{}", e, table_usage());
+                ExitCode::from(2)
+            }
+        },
+        #[cfg(feature = "display")]
+        Some("doc") => match doc_command(&args[1..]) {
+            Ok(s) => {
+                print!("{}", s);
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("{}\n\n{}", e, DOC_USAGE);
+                ExitCode::from(2)
+            }
+        },
+        Some(other) => {
+            eprintln!("Unknown command: {}\n\n{}", other, table_usage());
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn print_summary() {
     println!("Today is:");
     print_today();
     println!("\n\n");
@@ -45,160 +114,313 @@ fn main() {
     print_t(Fixed::effective_max());
 }
 
-fn print_today() {
+fn print_list() {
+    for c in all() {
+        println!(
+            "{:<24}{:<36}{:?}, year zero {:?}",
+            c.id, c.name, c.family, c.year_zero
+        );
+    }
+}
+
+fn table_command(args: &[String]) -> Result<String, String> {
+    let mut from = None;
+    let mut to = None;
+    let mut year = None;
+    let mut end_year = None;
+    let mut format = "text";
+    let mut iter = args.iter();
+    while let Some(flag) = iter.next() {
+        let value = iter
+            .next()
+            .ok_or_else(|| format!("Missing value for {}", flag))?;
+        match flag.as_str() {
+            "--from" => from = Some(value.as_str()),
+            "--to" => to = Some(value.as_str()),
+            "--year" => year = Some(parse_year(value)?),
+            "--end-year" => end_year = Some(parse_year(value)?),
+            "--format" => format = value.as_str(),
+            _ => return Err(format!("Unknown option: {}", flag)),
+        }
+    }
+    let from = table_calendar(from.ok_or("Missing --from")?)?;
+    let to = table_calendar(to.ok_or("Missing --to")?)?;
+    let year = year.ok_or("Missing --year")?;
+    let end_year = end_year.unwrap_or(year);
+    for y in [year, end_year] {
+        if !from.years.contains(&y) {
+            return Err(format!(
+                "Year {} is outside of the supported range {} to {}",
+                y,
+                from.years.start(),
+                from.years.end()
+            ));
+        }
+    }
+    if end_year < year {
+        return Err(format!("--end-year {} is before --year {}", end_year, year));
+    }
+    if (end_year as i64) - (year as i64) >= MAX_TABLE_YEARS {
+        return Err(format!(
+            "Too many years: a table covers at most {} years",
+            MAX_TABLE_YEARS
+        ));
+    }
+    let table = (from.month_starts)(year..=end_year, to.common_date);
+    //Rows are in chronological order, so only the first and last need to be checked
+    for row in [table.rows().first(), table.rows().last()]
+        .into_iter()
+        .flatten()
+    {
+        if !(to.contains)((from.fixed)(row.from)) {
+            return Err(format!(
+                "{}-{}-{} is outside of the supported range of the target calendar",
+                row.from.year, row.from.month, row.from.day
+            ));
+        }
+    }
+    match format {
+        "text" => Ok(table.to_string()),
+        "csv" => Ok(table.to_csv()),
+        "json" => Ok(table.to_json()),
+        _ => Err(format!("Unknown format: {}", format)),
+    }
+}
+
+fn parse_year(s: &str) -> Result<i32, String> {
+    s.parse::<i32>().map_err(|_| format!("Invalid year: {}", s))
+}
+
+type CommonDateFn = fn(Fixed) -> CommonDate;
+
+/// Conversions needed to correlate a calendar with another one
+struct TableCalendar {
+    month_starts: fn(RangeInclusive<i32>, CommonDateFn) -> CorrelationTable,
+    common_date: CommonDateFn,
+    fixed: fn(CommonDate) -> Fixed,
+    contains: fn(Fixed) -> bool,
+    years: RangeInclusive<i32>,
+}
+
+fn table_calendar_of<S, T>() -> TableCalendar
+where
+    S: FromPrimitive,
+    T: ToFromCommonDate<S> + FromFixed + ToFixed,
+{
+    TableCalendar {
+        month_starts: CorrelationTable::month_starts_with::<S, T, _>,
+        common_date: |t| T::from_fixed(t).to_common_date(),
+        fixed: |d| T::from_common_date_unchecked(d).to_fixed(),
+        contains: T::contains,
+        years: T::EFFECTIVE_YEAR_MIN..=T::EFFECTIVE_YEAR_MAX,
+    }
+}
+
+/// Look up a calendar from [`all`] which can be used in a table
+fn table_calendar(id: &str) -> Result<TableCalendar, String> {
+    if !all().iter().any(|c| c.id == id) {
+        return Err(format!("Unknown calendar: {}", id));
+    }
+    table_calendar_by_id(id).ok_or_else(|| format!("Calendar does not support tables: {}", id))
+}
+
+fn table_calendar_by_id(id: &str) -> Option<TableCalendar> {
+    Some(match id {
+        "afghan" => table_calendar_of::<_, Afghan>(),
+        "armenian" => table_calendar_of::<_, Armenian>(),
+        "attic" => table_calendar_of::<_, AtticArith>(),
+        "babylonian" => table_calendar_of::<_, BabylonianArith>(),
+        "bengali" => table_calendar_of::<_, Bengali>(),
+        "bikram-sambat" => table_calendar_of::<_, BikramSambat>(),
+        "coligny" => table_calendar_of::<_, Coligny<false>>(),
+        "coligny-saeculum" => table_calendar_of::<_, Coligny<true>>(),
+        "coptic" => table_calendar_of::<_, Coptic>(),
+        "cotsworth" => table_calendar_of::<_, Cotsworth>(),
+        "egyptian" => table_calendar_of::<_, Egyptian>(),
+        "erisian-year" => table_calendar_of::<_, ErisianYear>(),
+        "ethiopic" => table_calendar_of::<_, Ethiopic>(),
+        "french-rev" => table_calendar_of::<_, FrenchRevArith<true>>(),
+        "french-rev-unadjusted" => table_calendar_of::<_, FrenchRevArith<false>>(),
+        "gregorian" => table_calendar_of::<_, Gregorian>(),
+        "holocene" => table_calendar_of::<_, Holocene>(),
+        "islamic" => table_calendar_of::<_, IslamicArith>(),
+        "islamic-umm-al-qura" => table_calendar_of::<_, IslamicUmmAlQura>(),
+        "julian" => table_calendar_of::<_, Julian>(),
+        "kurdish" => table_calendar_of::<_, Kurdish>(),
+        "minguo" => table_calendar_of::<_, Minguo>(),
+        "pataphysical" => table_calendar_of::<_, Pataphysical>(),
+        "persian" => table_calendar_of::<_, PersianArith>(),
+        "positivist" => table_calendar_of::<_, Positivist>(),
+        "retail445" => table_calendar_of::<_, Retail445>(),
+        "retail454" => table_calendar_of::<_, Retail454>(),
+        "retail544" => table_calendar_of::<_, Retail544>(),
+        #[cfg(feature = "fiction")]
+        "shire" => table_calendar_of::<_, Shire>(),
+        "symmetry010" => table_calendar_of::<_, Symmetry010>(),
+        "symmetry010-solstice" => table_calendar_of::<_, Symmetry010Solstice>(),
+        "symmetry454" => table_calendar_of::<_, Symmetry454>(),
+        "symmetry454-solstice" => table_calendar_of::<_, Symmetry454Solstice>(),
+        "tamil" => table_calendar_of::<_, Tamil>(),
+        "thai-solar" => table_calendar_of::<_, ThaiSolar>(),
+        "tranquility" => table_calendar_of::<_, Tranquility>(),
+        _ => return None,
+    })
+}
+
+fn table_usage() -> String {
+    let ids: Vec<&str> = all()
+        .into_iter()
+        .map(|c| c.id)
+        .filter(|id| table_calendar_by_id(id).is_some())
+        .collect();
+    let mut result = String::from(
+        "Usage: radnelac table --from <calendar> --to <calendar> \
+--year <year> [--end-year <year>] [--format text|csv|json]\n\nCalendars:",
+    );
+    for line in ids.chunks(5) {
+        result.push_str("\n  ");
+        result.push_str(&line.join(", "));
+    }
+    result
+}
+
+#[cfg(feature = "display")]
+fn doc_command(args: &[String]) -> Result<String, String> {
+    let mut format = "markdown";
+    let mut lang = Language::EN;
+    let mut t_fixed = None;
+    let mut iter = args.iter();
+    while let Some(flag) = iter.next() {
+        let value = iter
+            .next()
+            .ok_or_else(|| format!("Missing value for {}", flag))?;
+        match flag.as_str() {
+            "--format" => format = value.as_str(),
+            "--lang" => {
+                lang = match value.to_lowercase().as_str() {
+                    "en" => Language::EN,
+                    "fr" => Language::FR,
+                    _ => return Err(format!("Unknown language: {}", value)),
+                }
+            }
+            "--date" => {
+                let rd = value
+                    .parse::<RataDie>()
+                    .map_err(|e| format!("Invalid date: {} ({})", value, e))?;
+                t_fixed = Some(rd.to_fixed());
+            }
+            _ => return Err(format!("Unknown option: {}", flag)),
+        }
+    }
+    let t_fixed = t_fixed.unwrap_or_else(today);
+    let rows: Vec<(&str, String)> = all()
+        .into_iter()
+        .filter_map(|c| long_date_of(c.id, t_fixed, lang).map(|d| (c.name, d)))
+        .collect();
+    match format {
+        "markdown" => Ok(markdown_table(&rows)),
+        "html" => Ok(html_table(&rows)),
+        _ => Err(format!("Unknown format: {}", format)),
+    }
+}
+
+#[cfg(feature = "display")]
+fn long_date_of(id: &str, t_fixed: Fixed, lang: Language) -> Option<String> {
+    match id {
+        "armenian" => long_date::<Armenian>(t_fixed, lang),
+        "bengali" => long_date::<Bengali>(t_fixed, lang),
+        "coptic" => long_date::<Coptic>(t_fixed, lang),
+        "cotsworth" => long_date::<Cotsworth>(t_fixed, lang),
+        "egyptian" => long_date::<Egyptian>(t_fixed, lang),
+        "ethiopic" => long_date::<Ethiopic>(t_fixed, lang),
+        "french-rev" => long_date::<FrenchRevArith<true>>(t_fixed, lang),
+        "french-rev-unadjusted" => long_date::<FrenchRevArith<false>>(t_fixed, lang),
+        "gregorian" => long_date::<Gregorian>(t_fixed, lang),
+        "holocene" => long_date::<Holocene>(t_fixed, lang),
+        "iso" => long_date::<ISO>(t_fixed, lang),
+        "julian" => long_date::<Julian>(t_fixed, lang),
+        "positivist" => long_date::<Positivist>(t_fixed, lang),
+        "retail445" => long_date::<Retail445>(t_fixed, lang),
+        "retail454" => long_date::<Retail454>(t_fixed, lang),
+        "retail544" => long_date::<Retail544>(t_fixed, lang),
+        "symmetry010" => long_date::<Symmetry010>(t_fixed, lang),
+        "symmetry010-solstice" => long_date::<Symmetry010Solstice>(t_fixed, lang),
+        "symmetry454" => long_date::<Symmetry454>(t_fixed, lang),
+        "symmetry454-solstice" => long_date::<Symmetry454Solstice>(t_fixed, lang),
+        "tamil" => long_date::<Tamil>(t_fixed, lang),
+        "tranquility" => long_date::<Tranquility>(t_fixed, lang),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "display")]
+fn long_date<T: FromFixed + PresetDisplay>(t_fixed: Fixed, lang: Language) -> Option<String> {
+    if !T::supported_display_lang(lang) {
+        return None;
+    }
+    let d = T::from_fixed(t_fixed);
+    Some(d.preset_str(lang, d.long_date_preset()))
+}
+
+#[cfg(feature = "display")]
+fn markdown_table(rows: &[(&str, String)]) -> String {
+    let mut result = String::from("| Calendar | Date |\n| --- | --- |\n");
+    for (name, date) in rows {
+        writeln!(
+            result,
+            "| {} | {} |",
+            name.replace('|', "\\|"),
+            date.replace('|', "\\|")
+        )
+        .expect("Writing to String does not fail");
+    }
+    result
+}
+
+#[cfg(feature = "display")]
+fn html_table(rows: &[(&str, String)]) -> String {
+    let mut result = String::from(
+        "<table>\n<thead>\n<tr><th>Calendar</th><th>Date</th></tr>\n</thead>\n<tbody>\n",
+    );
+    for (name, date) in rows {
+        writeln!(
+            result,
+            "<tr><td>{}</td><td>{}</td></tr>",
+            html_escape(name),
+            html_escape(date)
+        )
+        .expect("Writing to String does not fail");
+    }
+    result.push_str("</tbody>\n</table>\n");
+    result
+}
+
+#[cfg(feature = "display")]
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn today() -> Fixed {
     let t_system = match SystemTime::now().duration_since(UNIX_EPOCH) {
         Ok(n) => n.as_secs(),
         Err(_) => panic!("SystemTime before UNIX_EPOCH"),
     };
-    let t_unix = UnixMoment::new(t_system as i64);
-    let t_fixed = t_unix.to_fixed();
-    print_t(t_fixed);
+    UnixMoment::new(t_system as i64).to_fixed()
 }
 
-#[cfg(not(feature = "display"))]
-fn print_t(t_fixed: Fixed) {
-    let m_clk = TimeOfDay::from_fixed(t_fixed);
-    let t_unix = UnixMoment::from_fixed(t_fixed);
-    let t_jd = JulianDay::from_fixed(t_fixed);
-    let t_mjd = ModifiedJulianDay::from_fixed(t_fixed);
-    let t_rd = RataDie::from_fixed(t_fixed);
-    let w_week = Weekday::from_fixed(t_fixed);
-    let w_akan = Akan::from_fixed(t_fixed);
-    let d_egyptian = Egyptian::from_fixed(t_fixed);
-    let d_armenian = Armenian::from_fixed(t_fixed);
-    let d_gregorian = Gregorian::from_fixed(t_fixed);
-    let d_julian = Julian::from_fixed(t_fixed);
-    let d_roman = Roman::from_fixed(t_fixed);
-    let d_coptic = Coptic::from_fixed(t_fixed);
-    let d_ethiopic = Ethiopic::from_fixed(t_fixed);
-    let d_iso = ISO::from_fixed(t_fixed);
-    let d_holocene = Holocene::from_fixed(t_fixed);
-    let d_french0 = FrenchRevArith::<true>::from_fixed(t_fixed);
-    let d_french1 = FrenchRevArith::<false>::from_fixed(t_fixed);
-    let d_positivist = Positivist::from_fixed(t_fixed);
-    let d_cotsworth = Cotsworth::from_fixed(t_fixed);
-    let d_symmetry454 = Symmetry454::from_fixed(t_fixed);
-    let d_symmetry010 = Symmetry010::from_fixed(t_fixed);
-    let d_symmetry454s = Symmetry454Solstice::from_fixed(t_fixed);
-    let d_symmetry010s = Symmetry010Solstice::from_fixed(t_fixed);
-    let d_tranquility = TranquilityMoment::from_fixed(t_fixed);
-    let y_roman = Roman::auc_year_from_julian(d_julian.nz_year());
-    let y_olympiad = Olympiad::from_julian_year(d_julian.nz_year());
-
-    println!("{:?}", m_clk);
-    println!("{:?}", t_unix);
-    println!("{:?}", t_jd);
-    println!("{:?}", t_mjd);
-    println!("{:?}", t_rd);
-    println!("{:?}", w_week);
-    println!("{:?}", w_akan);
-    println!("{:?}", d_egyptian);
-    println!("{:?}", d_armenian);
-    println!("{:?}", d_gregorian);
-    println!("{:?}", d_julian);
-    println!("{:?}", d_coptic);
-    println!("{:?}", d_ethiopic);
-    println!("{:?}", d_roman);
-    println!("{:?}", d_iso);
-    println!("{:?}", d_holocene);
-    println!("{:?} mode: {:?}", d_french0, d_french0.is_adjusted());
-    println!("{:?} mode: {:?}", d_french1, d_french1.is_adjusted());
-    println!("{:?}", d_positivist);
-    println!("{:?}", d_cotsworth);
-    println!("{:?} mode: {:?}", d_symmetry454, d_symmetry454.mode());
-    println!("{:?} mode: {:?}", d_symmetry010, d_symmetry010.mode());
-    println!("{:?} mode: {:?}", d_symmetry454s, d_symmetry454s.mode());
-    println!("{:?} mode: {:?}", d_symmetry010s, d_symmetry010s.mode());
-    println!("{:?}", d_tranquility);
-    println!("{:?} AUC", y_roman);
-    println!("{:?}", y_olympiad);
+fn print_today() {
+    print_t(today());
 }
 
-#[cfg(feature = "display")]
 fn print_t(t_fixed: Fixed) {
-    let m_clk = TimeOfDay::from_fixed(t_fixed);
-    let t_unix = UnixMoment::from_fixed(t_fixed);
-    let t_jd = JulianDay::from_fixed(t_fixed);
-    let t_mjd = ModifiedJulianDay::from_fixed(t_fixed);
-    let t_rd = RataDie::from_fixed(t_fixed);
-    let w_week = Weekday::from_fixed(t_fixed);
-    let w_akan = Akan::from_fixed(t_fixed);
-    let d_egyptian = Egyptian::from_fixed(t_fixed);
-    let d_armenian = Armenian::from_fixed(t_fixed);
-    let d_gregorian = Gregorian::from_fixed(t_fixed);
-    let d_julian = Julian::from_fixed(t_fixed);
-    let d_roman = Roman::from_fixed(t_fixed);
-    let d_coptic = Coptic::from_fixed(t_fixed);
-    let d_ethiopic = Ethiopic::from_fixed(t_fixed);
-    let d_iso = ISO::from_fixed(t_fixed);
-    let d_holocene = Holocene::from_fixed(t_fixed);
-    let d_french0 = FrenchRevArith::<true>::from_fixed(t_fixed);
-    let d_french1 = FrenchRevArith::<false>::from_fixed(t_fixed);
-    let d_positivist = Positivist::from_fixed(t_fixed);
-    let d_cotsworth = Cotsworth::from_fixed(t_fixed);
-    let d_symmetry454 = Symmetry454::from_fixed(t_fixed);
-    let d_symmetry010 = Symmetry010::from_fixed(t_fixed);
-    let d_symmetry454s = Symmetry454Solstice::from_fixed(t_fixed);
-    let d_symmetry010s = Symmetry010Solstice::from_fixed(t_fixed);
-    let d_tranquility = TranquilityMoment::from_fixed(t_fixed);
-    let y_roman = Roman::auc_year_from_julian(d_julian.nz_year());
-    let y_olympiad = Olympiad::from_julian_year(d_julian.nz_year());
-
-    println!("{} ({:?})", m_clk, m_clk);
-    println!("{:?}", t_unix);
-    println!("{:?}", t_jd);
-    println!("{:?}", t_mjd);
-    println!("{:?}", t_rd);
-    println!("{} ({:?})", w_week, w_week);
-    println!("{} ({:?})", w_akan, w_akan);
-    println!("{} ({:?})", d_egyptian, d_egyptian);
-    println!("{} ({:?})", d_armenian, d_armenian);
-    println!("{} ({:?})", d_gregorian, d_gregorian);
-    println!("{} ({:?})", d_julian, d_julian);
-    println!("{} ({:?})", d_coptic, d_coptic);
-    println!("{} ({:?})", d_ethiopic, d_ethiopic);
-    println!("{} ({:?})", d_roman, d_roman);
-    println!("{} ({:?})", d_iso, d_iso);
-    println!("{} ({:?})", d_holocene, d_holocene);
-    println!(
-        "{} ({:?} mode: {:?})",
-        d_french0,
-        d_french0,
-        d_french0.is_adjusted()
-    );
-    println!(
-        "{} ({:?} mode: {:?})",
-        d_french1,
-        d_french1,
-        d_french1.is_adjusted()
-    );
-    println!("{} ({:?})", d_positivist, d_positivist);
-    println!("{} ({:?})", d_cotsworth, d_cotsworth);
-    println!(
-        "{} ({:?} mode: {:?})",
-        d_symmetry454,
-        d_symmetry454,
-        d_symmetry454.mode()
-    );
-    println!(
-        "{} ({:?} mode: {:?})",
-        d_symmetry010,
-        d_symmetry010,
-        d_symmetry010.mode()
-    );
-    println!(
-        "{} ({:?} mode: {:?})",
-        d_symmetry454s,
-        d_symmetry454s,
-        d_symmetry454s.mode()
-    );
-    println!(
-        "{} ({:?} mode: {:?})",
-        d_symmetry010s,
-        d_symmetry010s,
-        d_symmetry010s.mode()
-    );
-    println!("{} ({:?})", d_tranquility, d_tranquility);
-    println!("{:?} AUC", y_roman);
-    println!("{:?}", y_olympiad);
+    for e in report(t_fixed) {
+        match e.text {
+            Some(text) => println!("{} ({})", text, e.debug),
+            None => println!("{}", e.debug),
+        }
+    }
 }
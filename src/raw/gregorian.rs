@@ -0,0 +1,75 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+/// [`true`] if a year is a leap year in the Gregorian calendar
+pub const fn is_gregorian_leap_year(year: i64) -> bool {
+    //LISTING 2.16 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
+    let m400 = year.rem_euclid(400);
+    year.rem_euclid(4) == 0 && m400 != 100 && m400 != 200 && m400 != 300
+}
+
+/// Number of leap days in the Gregorian calendar from the start of year 1 to the start of `year`
+///
+/// This is negative for years before 1.
+pub const fn gregorian_leap_days_before_year(year: i64) -> i64 {
+    //LISTING 2.17 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
+    (year - 1).div_euclid(4) - (year - 1).div_euclid(100) + (year - 1).div_euclid(400)
+}
+
+/// Number of days in the Gregorian calendar from the start of year 1 to the start of `year`
+///
+/// This is negative for years before 1.
+pub const fn gregorian_days_before_year(year: i64) -> i64 {
+    //LISTING 2.17 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
+    365 * (year - 1) + gregorian_leap_days_before_year(year)
+}
+
+/// Number of days from the start of a year to the start of `month`
+///
+/// This applies to both the Gregorian and Julian calendars, since the months have the same
+/// lengths in both calendars. The month is assumed to be between 1 and 12.
+pub const fn days_before_month(month: u8, leap: bool) -> u16 {
+    //LISTING 2.17 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
+    let month = month as i64;
+    let offset_m = ((367 * month) - 362).div_euclid(12);
+    let offset_x = if month <= 2 {
+        0
+    } else if leap {
+        -1
+    } else {
+        -2
+    };
+    (offset_m + offset_x) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Gregorian;
+    use crate::calendar::GregorianMonth;
+    use crate::calendar::GuaranteedMonth;
+    use crate::calendar::HasLeapYears;
+    use crate::calendar::ToFromCommonDate;
+    use crate::calendar::ToFromOrdinalDate;
+    use crate::day_count::Epoch;
+    use crate::day_count::ToFixed;
+    use num_traits::FromPrimitive;
+    use proptest::proptest;
+
+    proptest! {
+        #[test]
+        fn same_as_calendar(year in -100000..100000i32, month in 1..=12u8) {
+            let leap = Gregorian::is_leap(year);
+            assert_eq!(is_gregorian_leap_year(year as i64), leap);
+            let start = Gregorian::try_year_start(year).unwrap().to_fixed();
+            let epoch = Gregorian::epoch();
+            assert_eq!(gregorian_days_before_year(year as i64), start.get_day_i() - epoch.get_day_i());
+            let m = GregorianMonth::from_u8(month).unwrap();
+            let d = Gregorian::try_new(year, m, 1).unwrap();
+            assert_eq!(days_before_month(month, leap), d.to_ordinal().day_of_year - 1);
+            let leap_days = gregorian_leap_days_before_year(year as i64 + 1) - gregorian_leap_days_before_year(year as i64);
+            assert_eq!(leap_days == 1, leap);
+        }
+    }
+}
@@ -0,0 +1,65 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//The Julian calendar has no year 0, so year -1 is immediately followed by year 1
+const fn year_from_1(year: i64) -> i64 {
+    if year < 0 {
+        year + 1
+    } else {
+        year
+    }
+}
+
+/// [`true`] if a year is a leap year in the Julian calendar
+///
+/// Year 0 does not exist in the Julian calendar, and years before 1 are counted so that
+/// year -1 is a leap year.
+pub const fn is_julian_leap_year(year: i64) -> bool {
+    //LISTING 3.1 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
+    let m4 = year.rem_euclid(4);
+    if year > 0 {
+        m4 == 0
+    } else {
+        m4 == 3
+    }
+}
+
+/// Number of leap days in the Julian calendar from the start of year 1 to the start of `year`
+///
+/// This is negative for years before 1. Year 0 does not exist in the Julian calendar.
+pub const fn julian_leap_days_before_year(year: i64) -> i64 {
+    //LISTING 3.3 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
+    (year_from_1(year) - 1).div_euclid(4)
+}
+
+/// Number of days in the Julian calendar from the start of year 1 to the start of `year`
+///
+/// This is negative for years before 1. Year 0 does not exist in the Julian calendar.
+pub const fn julian_days_before_year(year: i64) -> i64 {
+    //LISTING 3.3 (*Calendrical Calculations: The Ultimate Edition* by Reingold & Dershowitz.)
+    365 * (year_from_1(year) - 1) + julian_leap_days_before_year(year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::HasLeapYears;
+    use crate::calendar::Julian;
+    use crate::calendar::ToFromCommonDate;
+    use crate::day_count::Epoch;
+    use crate::day_count::ToFixed;
+    use proptest::prop_assume;
+    use proptest::proptest;
+
+    proptest! {
+        #[test]
+        fn same_as_calendar(year in -100000..100000i32) {
+            prop_assume!(year != 0);
+            assert_eq!(is_julian_leap_year(year as i64), Julian::is_leap(year));
+            let start = Julian::try_year_start(year).unwrap().to_fixed();
+            let epoch = Julian::epoch();
+            assert_eq!(julian_days_before_year(year as i64), start.get_day_i() - epoch.get_day_i());
+        }
+    }
+}
@@ -0,0 +1,292 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::all;
+use crate::calendar::Afghan;
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::Armenian;
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::AtticArith;
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::BabylonianArith;
+use crate::calendar::Bengali;
+use crate::calendar::BikramSambat;
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::Coligny;
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::Coptic;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::Cotsworth;
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::Egyptian;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::ErisianYear;
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::Ethiopic;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::FrenchRevArith;
+use crate::calendar::Gregorian;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::Holocene;
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::Icelandic;
+use crate::calendar::IslamicArith;
+use crate::calendar::IslamicUmmAlQura;
+use crate::calendar::Julian;
+use crate::calendar::Kurdish;
+use crate::calendar::Minguo;
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::Olympiad;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::Pataphysical;
+use crate::calendar::PersianArith;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::Positivist;
+use crate::calendar::Retail445;
+use crate::calendar::Retail454;
+use crate::calendar::Retail544;
+#[cfg(feature = "ancient-calendars")]
+use crate::calendar::Roman;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::Symmetry010;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::Symmetry010Solstice;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::Symmetry454;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::Symmetry454Solstice;
+use crate::calendar::Tamil;
+use crate::calendar::ThaiSolar;
+#[cfg(feature = "reform-calendars")]
+use crate::calendar::TranquilityMoment;
+use crate::calendar::ISO;
+use crate::clock::TimeOfDay;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::JulianDay;
+use crate::day_count::ModifiedJulianDay;
+use crate::day_count::RataDie;
+use crate::day_count::UnixMoment;
+use crate::day_cycle::Akan;
+use crate::day_cycle::Weekday;
+#[cfg(feature = "fiction")]
+use crate::fiction::Shire;
+use std::fmt;
+
+/// One line of a [`report`]
+#[derive(Debug, PartialEq, Clone)]
+pub struct ReportEntry {
+    /// Stable identifier of the timekeeping system
+    ///
+    /// Calendar systems use the same identifiers as [`all`](crate::calendar::all).
+    pub id: &'static str,
+    /// Human readable text, or [`None`] if this is not available
+    ///
+    /// This is always [`None`] if `display` is not enabled.
+    pub text: Option<String>,
+    /// Fields of the value, and the mode of the timekeeping system if it has one
+    pub debug: String,
+}
+
+#[cfg(feature = "display")]
+fn entry<T: fmt::Display + fmt::Debug>(id: &'static str, x: T) -> ReportEntry {
+    ReportEntry {
+        id,
+        text: Some(x.to_string()),
+        debug: format!("{:?}", x),
+    }
+}
+
+#[cfg(not(feature = "display"))]
+fn entry<T: fmt::Debug>(id: &'static str, x: T) -> ReportEntry {
+    ReportEntry {
+        id,
+        text: None,
+        debug: format!("{:?}", x),
+    }
+}
+
+#[cfg(feature = "reform-calendars")]
+fn with_mode<T: fmt::Debug>(mut e: ReportEntry, mode: T) -> ReportEntry {
+    e.debug = format!("{} mode: {:?}", e.debug, mode);
+    e
+}
+
+fn debug_entry<T: fmt::Debug>(id: &'static str, x: T) -> ReportEntry {
+    ReportEntry {
+        id,
+        text: None,
+        debug: format!("{:?}", x),
+    }
+}
+
+#[cfg(feature = "ancient-calendars")]
+fn debug_only(id: &'static str, debug: String) -> ReportEntry {
+    ReportEntry {
+        id,
+        text: None,
+        debug,
+    }
+}
+
+/// Describe a moment in one of the calendar systems from [`all`]
+fn calendar_entry(id: &'static str, t: Fixed) -> Option<ReportEntry> {
+    Some(match id {
+        "afghan" => debug_entry(id, Afghan::from_fixed(t)),
+        #[cfg(feature = "ancient-calendars")]
+        "armenian" => entry(id, Armenian::from_fixed(t)),
+        #[cfg(feature = "ancient-calendars")]
+        "attic" => debug_entry(id, AtticArith::from_fixed(t)),
+        #[cfg(feature = "ancient-calendars")]
+        "babylonian" => debug_entry(id, BabylonianArith::from_fixed(t)),
+        "bengali" => entry(id, Bengali::from_fixed(t)),
+        "bikram-sambat" => debug_entry(id, BikramSambat::from_fixed(t)),
+        #[cfg(feature = "ancient-calendars")]
+        "coligny" => debug_entry(id, Coligny::<false>::from_fixed(t)),
+        #[cfg(feature = "ancient-calendars")]
+        "coligny-saeculum" => debug_entry(id, Coligny::<true>::from_fixed(t)),
+        #[cfg(feature = "ancient-calendars")]
+        "coptic" => entry(id, Coptic::from_fixed(t)),
+        #[cfg(feature = "reform-calendars")]
+        "cotsworth" => entry(id, Cotsworth::from_fixed(t)),
+        #[cfg(feature = "ancient-calendars")]
+        "egyptian" => entry(id, Egyptian::from_fixed(t)),
+        #[cfg(feature = "reform-calendars")]
+        "erisian-year" => debug_entry(id, ErisianYear::from_fixed(t)),
+        #[cfg(feature = "ancient-calendars")]
+        "ethiopic" => entry(id, Ethiopic::from_fixed(t)),
+        #[cfg(feature = "reform-calendars")]
+        "french-rev" => {
+            let d = FrenchRevArith::<true>::from_fixed(t);
+            with_mode(entry(id, d), d.is_adjusted())
+        }
+        #[cfg(feature = "reform-calendars")]
+        "french-rev-unadjusted" => {
+            let d = FrenchRevArith::<false>::from_fixed(t);
+            with_mode(entry(id, d), d.is_adjusted())
+        }
+        "gregorian" => entry(id, Gregorian::from_fixed(t)),
+        #[cfg(feature = "reform-calendars")]
+        "holocene" => entry(id, Holocene::from_fixed(t)),
+        #[cfg(feature = "ancient-calendars")]
+        "icelandic" => debug_entry(id, Icelandic::from_fixed(t)),
+        "islamic" => debug_entry(id, IslamicArith::from_fixed(t)),
+        "islamic-umm-al-qura" => debug_entry(id, IslamicUmmAlQura::from_fixed(t)),
+        "iso" => entry(id, ISO::from_fixed(t)),
+        "julian" => entry(id, Julian::from_fixed(t)),
+        "kurdish" => debug_entry(id, Kurdish::from_fixed(t)),
+        "minguo" => debug_entry(id, Minguo::from_fixed(t)),
+        #[cfg(feature = "reform-calendars")]
+        "pataphysical" => debug_entry(id, Pataphysical::from_fixed(t)),
+        "persian" => debug_entry(id, PersianArith::from_fixed(t)),
+        #[cfg(feature = "reform-calendars")]
+        "positivist" => entry(id, Positivist::from_fixed(t)),
+        "retail445" => entry(id, Retail445::from_fixed(t)),
+        "retail454" => entry(id, Retail454::from_fixed(t)),
+        "retail544" => entry(id, Retail544::from_fixed(t)),
+        #[cfg(feature = "ancient-calendars")]
+        "roman" => entry(id, Roman::from_fixed(t)),
+        #[cfg(feature = "fiction")]
+        "shire" => entry(id, Shire::from_fixed(t)),
+        #[cfg(feature = "reform-calendars")]
+        "symmetry010" => {
+            let d = Symmetry010::from_fixed(t);
+            with_mode(entry(id, d), d.mode())
+        }
+        #[cfg(feature = "reform-calendars")]
+        "symmetry010-solstice" => {
+            let d = Symmetry010Solstice::from_fixed(t);
+            with_mode(entry(id, d), d.mode())
+        }
+        #[cfg(feature = "reform-calendars")]
+        "symmetry454" => {
+            let d = Symmetry454::from_fixed(t);
+            with_mode(entry(id, d), d.mode())
+        }
+        #[cfg(feature = "reform-calendars")]
+        "symmetry454-solstice" => {
+            let d = Symmetry454Solstice::from_fixed(t);
+            with_mode(entry(id, d), d.mode())
+        }
+        "tamil" => entry(id, Tamil::from_fixed(t)),
+        "thai-solar" => debug_entry(id, ThaiSolar::from_fixed(t)),
+        #[cfg(feature = "reform-calendars")]
+        "tranquility" => entry(id, TranquilityMoment::from_fixed(t)),
+        _ => return None,
+    })
+}
+
+/// Describe a moment in every timekeeping system
+///
+/// This is intended for diagnostics and demonstrations. The order of the entries is
+/// stable, but the set of timekeeping systems depends on the crate features. Every
+/// calendar system from [`all`] is included, in the same order.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::day_count::*;
+/// use radnelac::report::*;
+///
+/// let t = Gregorian::try_new(2025, GregorianMonth::July, 26).unwrap().to_fixed();
+/// let r = report(t);
+/// let g = r.iter().find(|e| e.id == "gregorian").unwrap();
+/// assert!(g.debug.contains("2025"));
+/// assert!(r.iter().any(|e| e.id == "rata-die"));
+/// ```
+pub fn report(t: Fixed) -> Vec<ReportEntry> {
+    let mut result = vec![
+        entry("time-of-day", TimeOfDay::from_fixed(t)),
+        entry("unix", UnixMoment::from_fixed(t)),
+        entry("julian-day", JulianDay::from_fixed(t)),
+        entry("modified-julian-day", ModifiedJulianDay::from_fixed(t)),
+        entry("rata-die", RataDie::from_fixed(t)),
+        entry("weekday", Weekday::from_fixed(t)),
+        entry("akan", Akan::from_fixed(t)),
+    ];
+    for c in all() {
+        result.push(calendar_entry(c.id, t).expect("Every calendar is in the report"));
+    }
+    #[cfg(feature = "ancient-calendars")]
+    {
+        let d_julian = Julian::from_fixed(t);
+        let y_roman = Roman::auc_year_from_julian(d_julian.nz_year());
+        result.push(debug_only("roman-auc", format!("{:?} AUC", y_roman)));
+        let y_olympiad = Olympiad::from_julian_year(d_julian.nz_year());
+        result.push(debug_only("olympiad", format!("{:?}", y_olympiad)));
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_count::BoundedDayCount;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use proptest::proptest;
+    use std::collections::HashSet;
+
+    #[test]
+    fn ids() {
+        let r = report(Fixed::new(0.0));
+        let ids: HashSet<&str> = r.iter().map(|e| e.id).collect();
+        assert_eq!(ids.len(), r.len());
+        for c in all() {
+            assert!(ids.contains(c.id), "{}", c.id);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn stable_order(t in FIXED_MIN..FIXED_MAX) {
+            let a = report(Fixed::new(t));
+            let b = report(Fixed::new(0.0));
+            assert_eq!(a.len(), b.len());
+            for (x, y) in a.iter().zip(b.iter()) {
+                assert_eq!(x.id, y.id);
+                assert!(!x.debug.is_empty());
+            }
+        }
+    }
+}
@@ -0,0 +1,618 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::*;
+use crate::clock::ClockTime;
+use crate::common::error::CalendarError;
+use crate::common::math::TermNum;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::ToFixed;
+use crate::day_cycle::Weekday;
+#[cfg(feature = "fiction")]
+use crate::fiction::Shire;
+#[cfg(feature = "fiction")]
+use crate::fiction::ShireMonth;
+use serde::de::Error;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+#[allow(unused_imports)] //FromPrimitive is needed for from_u8
+use num_traits::FromPrimitive;
+
+/// Version of the serialized representation written by this crate
+///
+/// Data written by older versions of this crate can always be read by newer versions.
+/// Data with a version number greater than this is rejected.
+pub const FORMAT_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+struct DateRepr {
+    #[serde(default)]
+    version: u32,
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MomentRepr {
+    #[serde(default)]
+    version: u32,
+    year: i32,
+    month: u8,
+    day: u8,
+    hours: u8,
+    minutes: u8,
+    seconds: f32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum SeasonRepr {
+    Summer,
+    Winter,
+}
+
+#[derive(Serialize, Deserialize)]
+struct WeekDateRepr {
+    #[serde(default)]
+    version: u32,
+    year: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    season: Option<SeasonRepr>,
+    week: u8,
+    day: u8,
+}
+
+#[derive(Serialize, Deserialize)]
+struct WeekMomentRepr {
+    #[serde(default)]
+    version: u32,
+    year: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    season: Option<SeasonRepr>,
+    week: u8,
+    day: u8,
+    hours: u8,
+    minutes: u8,
+    seconds: f32,
+}
+
+fn check_version<E: Error>(version: u32) -> Result<(), E> {
+    if version > FORMAT_VERSION {
+        Err(E::custom(format!("unsupported format version {}", version)))
+    } else {
+        Ok(())
+    }
+}
+
+/// Attempt to create a [`CommonDate`] from the fields of a raw `{year, month, day}` map
+///
+/// This is intended for migrating data which was stored without using this crate's
+/// serialized representation. Any fields other than `year`, `month` and `day` are ignored.
+///
+/// Returns an error if a field is missing or out of range.
+///
+/// ```
+/// use radnelac::calendar::*;
+/// use radnelac::serialize::*;
+/// use std::collections::HashMap;
+///
+/// let raw = HashMap::from([("year", 2025), ("month", 5), ("day", 15)]);
+/// let c = common_date_from_raw(raw.iter().map(|(k, v)| (*k, *v))).unwrap();
+/// assert_eq!(c, CommonDate::new(2025, 5, 15));
+/// let g = Gregorian::try_from_common_date(c).unwrap();
+/// ```
+pub fn common_date_from_raw<'a, I>(fields: I) -> Result<CommonDate, CalendarError>
+where
+    I: IntoIterator<Item = (&'a str, i64)>,
+{
+    let mut year = Err(CalendarError::InvalidYear);
+    let mut month = Err(CalendarError::InvalidMonth);
+    let mut day = Err(CalendarError::InvalidDay);
+    for (key, value) in fields {
+        match key {
+            "year" => year = i32::try_from(value).map_err(|_| CalendarError::InvalidYear),
+            "month" => month = u8::try_from(value).map_err(|_| CalendarError::InvalidMonth),
+            "day" => day = u8::try_from(value).map_err(|_| CalendarError::InvalidDay),
+            _ => (),
+        }
+    }
+    Ok(CommonDate::new(year?, month?, day?))
+}
+
+impl Serialize for CommonDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let repr = DateRepr {
+            version: FORMAT_VERSION,
+            year: self.year,
+            month: self.month,
+            day: self.day,
+        };
+        repr.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CommonDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = DateRepr::deserialize(deserializer)?;
+        check_version(repr.version)?;
+        Ok(CommonDate::new(repr.year, repr.month, repr.day))
+    }
+}
+
+/// Calendar systems with a stable serialized representation
+///
+/// Dates are serialized as their [`CommonDate`], with the same field names and version
+/// number as [`CommonDate`] itself.
+///
+/// [`Roman`] dates are serialized as the same day in the [`Julian`] calendar.
+///
+/// [`ISO`] and [`Icelandic`] dates do not implement this trait, because they have no
+/// month. They are serialized as `{version, year, week, day}`, where `day` is the ISO
+/// day number (Monday is 1 and Sunday is 7). [`Icelandic`] dates also have a `season`
+/// field, which is either `"summer"` or `"winter"`. Their moments add the same time
+/// fields as other calendars.
+pub trait SerialDate: Copy {
+    /// Convert to the serialized representation
+    fn to_serial(self) -> CommonDate;
+    /// Attempt to convert from the serialized representation
+    ///
+    /// Returns an error if the date is not valid in this calendar.
+    fn try_from_serial(date: CommonDate) -> Result<Self, CalendarError>;
+}
+
+macro_rules! serial_date {
+    ([$($g:tt)*] $t:ty, $m:ty) => {
+        impl<$($g)*> SerialDate for $t {
+            fn to_serial(self) -> CommonDate {
+                ToFromCommonDate::<$m>::to_common_date(self)
+            }
+
+            fn try_from_serial(date: CommonDate) -> Result<Self, CalendarError> {
+                <$t as ToFromCommonDate<$m>>::try_from_common_date(date)
+            }
+        }
+
+        impl<$($g)*> Serialize for $t {
+            fn serialize<Z: Serializer>(&self, serializer: Z) -> Result<Z::Ok, Z::Error> {
+                self.to_serial().serialize(serializer)
+            }
+        }
+
+        impl<'de, $($g)*> Deserialize<'de> for $t {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let date = CommonDate::deserialize(deserializer)?;
+                Self::try_from_serial(date).map_err(D::Error::custom)
+            }
+        }
+    };
+    ($t:ty, $m:ty) => {
+        serial_date!([] $t, $m);
+    };
+}
+
+serial_date!(Gregorian, GregorianMonth);
+serial_date!(Bengali, BengaliMonth);
+serial_date!(BikramSambat, BikramSambatMonth);
+serial_date!(Tamil, TamilMonth);
+serial_date!(IslamicArith, IslamicMonth);
+serial_date!(IslamicUmmAlQura, IslamicMonth);
+serial_date!(Julian, JulianMonth);
+serial_date!(PersianArith, PersianMonth);
+serial_date!([const P: u16, const M: u8, const W: u8, const N: bool] Retail<P, M, W, N>, RetailPeriod);
+#[cfg(feature = "ancient-calendars")]
+serial_date!(Armenian, ArmenianMonth);
+#[cfg(feature = "ancient-calendars")]
+serial_date!(AtticArith, AtticMonth);
+#[cfg(feature = "ancient-calendars")]
+serial_date!(BabylonianArith, BabylonianMonth);
+#[cfg(feature = "ancient-calendars")]
+serial_date!([const S: bool] Coligny<S>, ColignyMonth);
+#[cfg(feature = "ancient-calendars")]
+serial_date!(Coptic, CopticMonth);
+#[cfg(feature = "ancient-calendars")]
+serial_date!(Egyptian, EgyptianMonth);
+#[cfg(feature = "ancient-calendars")]
+serial_date!(Ethiopic, EthiopicMonth);
+#[cfg(feature = "reform-calendars")]
+serial_date!(Cotsworth, CotsworthMonth);
+#[cfg(feature = "reform-calendars")]
+serial_date!([const L: bool] FrenchRevArith<L>, FrenchRevMonth);
+#[cfg(feature = "reform-calendars")]
+serial_date!(Holocene, HoloceneMonth);
+#[cfg(feature = "reform-calendars")]
+serial_date!(Pataphysical, PataphysicalMonth);
+#[cfg(feature = "reform-calendars")]
+serial_date!(Positivist, PositivistMonth);
+#[cfg(feature = "reform-calendars")]
+serial_date!([const T: bool, const U: bool] Symmetry<T, U>, SymmetryMonth);
+#[cfg(feature = "reform-calendars")]
+serial_date!(Tranquility, TranquilityMonth);
+#[cfg(feature = "fiction")]
+serial_date!(Shire, ShireMonth);
+
+#[cfg(feature = "ancient-calendars")]
+impl SerialDate for Roman {
+    fn to_serial(self) -> CommonDate {
+        Julian::from_fixed(self.to_fixed()).to_common_date()
+    }
+
+    fn try_from_serial(date: CommonDate) -> Result<Self, CalendarError> {
+        let j = Julian::try_from_common_date(date)?;
+        Ok(Roman::from_fixed(j.to_fixed()))
+    }
+}
+
+#[cfg(feature = "ancient-calendars")]
+impl Serialize for Roman {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_serial().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "ancient-calendars")]
+impl<'de> Deserialize<'de> for Roman {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let date = CommonDate::deserialize(deserializer)?;
+        Self::try_from_serial(date).map_err(D::Error::custom)
+    }
+}
+
+impl<B, const Y: i32, const D: i64> SerialDate for OffsetCalendar<B, Y, D>
+where
+    B: SerialDate + FromFixed + ToFixed,
+{
+    fn to_serial(self) -> CommonDate {
+        let d = self.base().to_serial();
+        CommonDate::new(d.year + Y, d.month, d.day)
+    }
+
+    fn try_from_serial(date: CommonDate) -> Result<Self, CalendarError> {
+        let base = B::try_from_serial(CommonDate::new(date.year - Y, date.month, date.day))?;
+        Ok(Self::from_fixed(Fixed::new(
+            base.to_fixed().get() + (D as f64),
+        )))
+    }
+}
+
+impl<B, const Y: i32, const D: i64> Serialize for OffsetCalendar<B, Y, D>
+where
+    B: SerialDate + FromFixed + ToFixed,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_serial().serialize(serializer)
+    }
+}
+
+impl<'de, B, const Y: i32, const D: i64> Deserialize<'de> for OffsetCalendar<B, Y, D>
+where
+    B: SerialDate + FromFixed + ToFixed,
+{
+    fn deserialize<Z: Deserializer<'de>>(deserializer: Z) -> Result<Self, Z::Error> {
+        let date = CommonDate::deserialize(deserializer)?;
+        Self::try_from_serial(date).map_err(Z::Error::custom)
+    }
+}
+
+impl<T: SerialDate> Serialize for CalendarMoment<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let d = self.date().to_serial();
+        let t = self.time_of_day();
+        let repr = MomentRepr {
+            version: FORMAT_VERSION,
+            year: d.year,
+            month: d.month,
+            day: d.day,
+            hours: t.hours,
+            minutes: t.minutes,
+            seconds: t.seconds,
+        };
+        repr.serialize(serializer)
+    }
+}
+
+impl<'de, T: SerialDate> Deserialize<'de> for CalendarMoment<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = MomentRepr::deserialize(deserializer)?;
+        check_version(repr.version)?;
+        let date = T::try_from_serial(CommonDate::new(repr.year, repr.month, repr.day))
+            .map_err(D::Error::custom)?;
+        let clock = ClockTime {
+            hours: repr.hours,
+            minutes: repr.minutes,
+            seconds: repr.seconds,
+        };
+        CalendarMoment::try_from_clock(date, clock).map_err(D::Error::custom)
+    }
+}
+
+/// Calendar systems which identify a day by its week instead of its month
+trait SerialWeekDate: Copy {
+    fn season(self) -> Option<SeasonRepr>;
+    fn year(self) -> i32;
+    fn week(self) -> u8;
+    fn weekday(self) -> Weekday;
+    fn try_from_week<E: Error>(
+        year: i32,
+        season: Option<SeasonRepr>,
+        week: u8,
+        day: Weekday,
+    ) -> Result<Self, E>;
+}
+
+impl SerialWeekDate for ISO {
+    fn season(self) -> Option<SeasonRepr> {
+        None
+    }
+
+    fn year(self) -> i32 {
+        ISO::year(self)
+    }
+
+    fn week(self) -> u8 {
+        ISO::week(self).get()
+    }
+
+    fn weekday(self) -> Weekday {
+        ISO::day(self)
+    }
+
+    fn try_from_week<E: Error>(
+        year: i32,
+        season: Option<SeasonRepr>,
+        week: u8,
+        day: Weekday,
+    ) -> Result<Self, E> {
+        if season.is_some() {
+            return Err(E::custom("unexpected field `season`"));
+        }
+        ISO::try_new(year, week, day).map_err(E::custom)
+    }
+}
+
+#[cfg(feature = "ancient-calendars")]
+impl SerialWeekDate for Icelandic {
+    fn season(self) -> Option<SeasonRepr> {
+        match Icelandic::season(self) {
+            IcelandicSeason::Summer => Some(SeasonRepr::Summer),
+            IcelandicSeason::Winter => Some(SeasonRepr::Winter),
+        }
+    }
+
+    fn year(self) -> i32 {
+        Icelandic::year(self)
+    }
+
+    fn week(self) -> u8 {
+        Icelandic::week(self).get()
+    }
+
+    fn weekday(self) -> Weekday {
+        Icelandic::day(self)
+    }
+
+    fn try_from_week<E: Error>(
+        year: i32,
+        season: Option<SeasonRepr>,
+        week: u8,
+        day: Weekday,
+    ) -> Result<Self, E> {
+        let season = match season {
+            Some(SeasonRepr::Summer) => IcelandicSeason::Summer,
+            Some(SeasonRepr::Winter) => IcelandicSeason::Winter,
+            None => return Err(E::missing_field("season")),
+        };
+        Icelandic::try_new(year, season, week, day).map_err(E::custom)
+    }
+}
+
+fn weekday_from_num<E: Error>(day: u8) -> Result<Weekday, E> {
+    if (1..=7).contains(&day) {
+        Ok(Weekday::from_u8(day % 7).expect("Checked in if"))
+    } else {
+        Err(E::custom(CalendarError::InvalidDay))
+    }
+}
+
+macro_rules! serial_week_date {
+    ($t:ty) => {
+        impl Serialize for $t {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let repr = WeekDateRepr {
+                    version: FORMAT_VERSION,
+                    year: SerialWeekDate::year(*self),
+                    season: SerialWeekDate::season(*self),
+                    week: SerialWeekDate::week(*self),
+                    day: (self.weekday() as u8).adjusted_remainder(7),
+                };
+                repr.serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $t {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let repr = WeekDateRepr::deserialize(deserializer)?;
+                check_version(repr.version)?;
+                let day = weekday_from_num(repr.day)?;
+                Self::try_from_week(repr.year, repr.season, repr.week, day)
+            }
+        }
+
+        impl Serialize for CalendarMoment<$t> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let d = self.date();
+                let t = self.time_of_day();
+                let repr = WeekMomentRepr {
+                    version: FORMAT_VERSION,
+                    year: SerialWeekDate::year(d),
+                    season: SerialWeekDate::season(d),
+                    week: SerialWeekDate::week(d),
+                    day: (d.weekday() as u8).adjusted_remainder(7),
+                    hours: t.hours,
+                    minutes: t.minutes,
+                    seconds: t.seconds,
+                };
+                repr.serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for CalendarMoment<$t> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let repr = WeekMomentRepr::deserialize(deserializer)?;
+                check_version(repr.version)?;
+                let day = weekday_from_num(repr.day)?;
+                let date = <$t>::try_from_week(repr.year, repr.season, repr.week, day)?;
+                let clock = ClockTime {
+                    hours: repr.hours,
+                    minutes: repr.minutes,
+                    seconds: repr.seconds,
+                };
+                CalendarMoment::try_from_clock(date, clock).map_err(D::Error::custom)
+            }
+        }
+    };
+}
+
+serial_week_date!(ISO);
+#[cfg(feature = "ancient-calendars")]
+serial_week_date!(Icelandic);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::TimeOfDay;
+    use crate::day_count::FIXED_MAX;
+    use crate::day_count::FIXED_MIN;
+    use proptest::proptest;
+
+    #[test]
+    fn field_names() {
+        let g = Gregorian::try_new(2025, GregorianMonth::May, 15).unwrap();
+        let s = serde_json::to_string(&g).unwrap();
+        assert_eq!(s, r#"{"version":1,"year":2025,"month":5,"day":15}"#);
+        let m = GregorianMoment::new(g, TimeOfDay::noon());
+        let s = serde_json::to_string(&m).unwrap();
+        let expected =
+            r#"{"version":1,"year":2025,"month":5,"day":15,"hours":12,"minutes":0,"seconds":0.0}"#;
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn raw_maps() {
+        let g: Gregorian = serde_json::from_str(r#"{"year":2025,"month":5,"day":15}"#).unwrap();
+        assert_eq!(g.to_common_date(), CommonDate::new(2025, 5, 15));
+        let raw = [("year", 2025), ("month", 5), ("day", 15), ("extra", 0)];
+        assert_eq!(common_date_from_raw(raw).unwrap(), g.to_common_date());
+        let missing = [("year", 2025), ("day", 15)];
+        assert!(matches!(
+            common_date_from_raw(missing),
+            Err(CalendarError::InvalidMonth)
+        ));
+        let overflow = [("year", 2025), ("month", 5), ("day", 256)];
+        assert!(matches!(
+            common_date_from_raw(overflow),
+            Err(CalendarError::InvalidDay)
+        ));
+    }
+
+    #[test]
+    fn invalid() {
+        let future = r#"{"version":2,"year":2025,"month":5,"day":15}"#;
+        assert!(serde_json::from_str::<Gregorian>(future).is_err());
+        let invalid = r#"{"version":1,"year":2025,"month":2,"day":30}"#;
+        assert!(serde_json::from_str::<Gregorian>(invalid).is_err());
+        assert!(serde_json::from_str::<CommonDate>(invalid).is_ok());
+        let year_zero = r#"{"version":1,"year":0,"month":1,"day":1}"#;
+        assert!(serde_json::from_str::<Julian>(year_zero).is_err());
+        assert!(serde_json::from_str::<Afghan>(year_zero).is_err());
+        let bad_time = r#"{"year":2025,"month":5,"day":15,"hours":25,"minutes":0,"seconds":0.0}"#;
+        assert!(serde_json::from_str::<GregorianMoment>(bad_time).is_err());
+    }
+
+    #[test]
+    fn week_dates() {
+        let i = ISO::try_new(2025, 20, Weekday::Sunday).unwrap();
+        let s = serde_json::to_string(&i).unwrap();
+        assert_eq!(s, r#"{"version":1,"year":2025,"week":20,"day":7}"#);
+        assert_eq!(serde_json::from_str::<ISO>(&s).unwrap(), i);
+        let bad_day = r#"{"version":1,"year":2025,"week":20,"day":0}"#;
+        assert!(serde_json::from_str::<ISO>(bad_day).is_err());
+        let season = r#"{"version":1,"year":2025,"season":"summer","week":20,"day":7}"#;
+        assert!(serde_json::from_str::<ISO>(season).is_err());
+    }
+
+    #[cfg(feature = "ancient-calendars")]
+    #[test]
+    fn icelandic() {
+        let i = Icelandic::new_year(2025);
+        let s = serde_json::to_string(&i).unwrap();
+        assert_eq!(
+            s,
+            r#"{"version":1,"year":2025,"season":"summer","week":1,"day":4}"#
+        );
+        assert_eq!(serde_json::from_str::<Icelandic>(&s).unwrap(), i);
+        let no_season = r#"{"version":1,"year":2025,"week":1,"day":4}"#;
+        assert!(serde_json::from_str::<Icelandic>(no_season).is_err());
+        let r = Roman::from_fixed(
+            Julian::try_new(2025, JulianMonth::March, 15)
+                .unwrap()
+                .to_fixed(),
+        );
+        let s = serde_json::to_string(&r).unwrap();
+        assert_eq!(s, r#"{"version":1,"year":2025,"month":3,"day":15}"#);
+        assert_eq!(serde_json::from_str::<Roman>(&s).unwrap(), r);
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrip(t in FIXED_MIN..FIXED_MAX) {
+            let f = Fixed::new(t).to_day();
+            let g = Gregorian::from_fixed(f);
+            let s = serde_json::to_string(&g).unwrap();
+            assert_eq!(serde_json::from_str::<Gregorian>(&s).unwrap(), g);
+            let k = Kurdish::from_fixed(f);
+            let s = serde_json::to_string(&k).unwrap();
+            assert_eq!(serde_json::from_str::<Kurdish>(&s).unwrap(), k);
+            let r = Retail454::from_fixed(f);
+            let s = serde_json::to_string(&r).unwrap();
+            assert_eq!(serde_json::from_str::<Retail454>(&s).unwrap(), r);
+        }
+
+        #[test]
+        fn roundtrip_moment(t in FIXED_MIN..FIXED_MAX) {
+            let m = JulianMoment::from_fixed(Fixed::new(t));
+            let s = serde_json::to_string(&m).unwrap();
+            let m2 = serde_json::from_str::<JulianMoment>(&s).unwrap();
+            assert_eq!(m2.date(), m.date());
+            assert_eq!(m2.time_of_day(), m.time_of_day());
+            let m = ISOMoment::from_fixed(Fixed::new(t));
+            let s = serde_json::to_string(&m).unwrap();
+            let m2 = serde_json::from_str::<ISOMoment>(&s).unwrap();
+            assert_eq!(m2.date(), m.date());
+            assert_eq!(m2.time_of_day(), m.time_of_day());
+        }
+
+        #[cfg(feature = "ancient-calendars")]
+        #[test]
+        fn roundtrip_ancient(t in FIXED_MIN..FIXED_MAX) {
+            let f = Fixed::new(t).to_day();
+            let i = Icelandic::from_fixed(f);
+            let s = serde_json::to_string(&i).unwrap();
+            assert_eq!(serde_json::from_str::<Icelandic>(&s).unwrap(), i);
+            let r = Roman::from_fixed(f);
+            let s = serde_json::to_string(&r).unwrap();
+            assert_eq!(serde_json::from_str::<Roman>(&s).unwrap(), r);
+            let m = IcelandicMoment::from_fixed(Fixed::new(t));
+            let s = serde_json::to_string(&m).unwrap();
+            let m2 = serde_json::from_str::<IcelandicMoment>(&s).unwrap();
+            assert_eq!(m2.date(), m.date());
+            assert_eq!(m2.time_of_day(), m.time_of_day());
+        }
+    }
+}
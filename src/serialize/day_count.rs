@@ -0,0 +1,53 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::day_count::BoundedDayCount;
+use crate::day_count::Fixed;
+use crate::day_count::JulianDay;
+use crate::day_count::ModifiedJulianDay;
+use crate::day_count::RataDie;
+use crate::day_count::UnixMoment;
+use serde::de::Error;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+macro_rules! serial_day_count {
+    ($t:ty, $n:ty) => {
+        impl Serialize for $t {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.get().serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $t {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let t = <$n>::deserialize(deserializer)?;
+                <$t>::in_effective_bounds(t).map_err(D::Error::custom)?;
+                Ok(<$t>::new(t))
+            }
+        }
+    };
+}
+
+serial_day_count!(Fixed, f64);
+serial_day_count!(JulianDay, f64);
+serial_day_count!(ModifiedJulianDay, f64);
+serial_day_count!(RataDie, f64);
+serial_day_count!(UnixMoment, i64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_numbers() {
+        let u = UnixMoment::new(1234567890);
+        assert_eq!(serde_json::to_string(&u).unwrap(), "1234567890");
+        let j: JulianDay = serde_json::from_str("2460000.5").unwrap();
+        assert_eq!(j.get(), 2460000.5);
+        assert!(serde_json::from_str::<Fixed>("1e300").is_err());
+    }
+}
@@ -0,0 +1,120 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::calendar::CalendarMoment;
+use crate::calendar::CommonDate;
+use crate::calendar::ToFromCommonDate;
+use crate::day_count::BoundedDayCount;
+use crate::day_count::Fixed;
+use crate::day_count::FromFixed;
+use crate::day_count::FIXED_MAX;
+use crate::day_count::FIXED_MIN;
+use num_traits::FromPrimitive;
+use proptest::prop_oneof;
+use proptest::strategy::Strategy;
+use std::fmt::Debug;
+
+//Covers year 0 and the years on either side of most epochs
+const SMALL_YEARS: std::ops::RangeInclusive<i32> = -10..=10;
+const RECENT_YEARS: std::ops::RangeInclusive<i32> = 1800..=2200;
+//Month 0 and months above 12 are used for epagomenae in some calendars
+const MONTHS: std::ops::RangeInclusive<u8> = 0..=14;
+//Day 0 is used for Moon Landing Day, and retail periods can have 6 weeks
+const DAYS: std::ops::RangeInclusive<u8> = 0..=42;
+
+/// Strategy generating any day within the supported range of time
+///
+/// Every day is equally likely, so unusual days such as epagomenae are rarely generated.
+/// Use [`any_common_date`] to generate unusual days more often.
+pub fn any_day<T: FromFixed + Debug>() -> impl Strategy<Value = T> {
+    (FIXED_MIN..FIXED_MAX).prop_map(|t| T::from_fixed(Fixed::new(t).to_day()))
+}
+
+/// Strategy generating any date and time within the supported range of time
+pub fn any_moment<T: FromFixed + Debug>() -> impl Strategy<Value = CalendarMoment<T>> {
+    (FIXED_MIN..FIXED_MAX).prop_map(|t| CalendarMoment::<T>::from_fixed(Fixed::new(t)))
+}
+
+/// Strategy generating valid dates, biased towards unusual days
+///
+/// Years near 0 and years near the present are generated more often than other years.
+/// The first and last days of the year are generated more often than other days. In
+/// many calendars, these are epagomenae, leap days or leap weeks.
+///
+/// ```
+/// use proptest::proptest;
+/// use radnelac::calendar::*;
+/// use radnelac::strategy::*;
+///
+/// proptest!(|(d in any_common_date::<GregorianMonth, Gregorian>())| {
+///     assert!(d.day() <= Gregorian::month_length(d.year(), d.month()));
+/// });
+/// ```
+pub fn any_common_date<S, T>() -> impl Strategy<Value = T>
+where
+    S: FromPrimitive,
+    T: ToFromCommonDate<S> + Debug,
+{
+    let years = prop_oneof![
+        SMALL_YEARS,
+        RECENT_YEARS,
+        T::EFFECTIVE_YEAR_MIN..=T::EFFECTIVE_YEAR_MAX,
+    ];
+    (years, 0..4u8, MONTHS, DAYS).prop_filter_map("invalid date", |(year, kind, month, day)| {
+        let d = match kind {
+            0 => T::year_start_date(year),
+            1 => T::year_end_date(year),
+            _ => CommonDate::new(year, month, day),
+        };
+        T::try_from_common_date(d).ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Gregorian;
+    use crate::calendar::GregorianMoment;
+    use crate::calendar::GregorianMonth;
+    use crate::day_count::ToFixed;
+    use proptest::proptest;
+
+    #[cfg(feature = "reform-calendars")]
+    #[test]
+    fn moon_landing_day() {
+        use crate::calendar::HasEpagemonae;
+        use crate::calendar::Tranquility;
+        use crate::calendar::TranquilityComplementaryDay;
+        use crate::calendar::TranquilityMonth;
+        use proptest::strategy::ValueTree;
+        use proptest::test_runner::TestRunner;
+
+        let s = any_common_date::<TranquilityMonth, Tranquility>();
+        let mut runner = TestRunner::deterministic();
+        let found = (0..10000).any(|_| {
+            let d = s.new_tree(&mut runner).unwrap().current();
+            d.epagomenae() == Some(TranquilityComplementaryDay::MoonLandingDay)
+        });
+        assert!(found);
+    }
+
+    proptest! {
+        #[test]
+        fn valid(d in any_common_date::<GregorianMonth, Gregorian>()) {
+            assert!(Gregorian::valid_ymd(d.to_common_date()).is_ok());
+        }
+
+        #[test]
+        fn days(d in any_day::<Gregorian>()) {
+            assert_eq!(d.to_fixed(), d.to_fixed().to_day());
+        }
+
+        #[test]
+        fn moments(m in any_moment::<Gregorian>()) {
+            let t = m.to_fixed().get();
+            assert!((FIXED_MIN..=FIXED_MAX).contains(&t));
+            assert_eq!(GregorianMoment::from_fixed(m.to_fixed()), m);
+        }
+    }
+}
@@ -2,52 +2,134 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use num_traits::FromPrimitive;
 use radnelac::calendar::*;
 use radnelac::day_count::BoundedDayCount;
 use radnelac::day_count::EffectiveBound;
 use radnelac::day_count::Fixed;
 use radnelac::day_count::FromFixed;
+use radnelac::day_count::ToFixed;
+use radnelac::day_count::FIXED_MAX;
+use radnelac::day_count::FIXED_MIN;
+use radnelac::CalendarError;
+use std::fmt::Debug;
 
-fn bounds_actually_work<T: EffectiveBound + FromFixed + std::cmp::PartialOrd>() {
+fn bounds_actually_work<T: EffectiveBound + FromFixed + ToFixed + std::cmp::PartialOrd>() {
     assert!(T::from_fixed(Fixed::effective_min()) < T::from_fixed(Fixed::cast_new(0)));
     assert!(T::from_fixed(Fixed::effective_max()) > T::from_fixed(Fixed::cast_new(0)));
-    assert!(T::effective_min() < T::effective_max())
+    assert!(T::effective_min() < T::effective_max());
+    assert!(T::contains(Fixed::cast_new(0)));
+    assert!(T::contains(T::effective_min().to_fixed()));
+    assert!(T::contains(T::effective_max().to_fixed()));
+    assert!(!T::contains(Fixed::new(FIXED_MIN - 1.0)));
+    assert!(!T::contains(Fixed::new(FIXED_MAX + 1.0)));
+}
+
+fn extremes_work<S, T>()
+where
+    S: FromPrimitive,
+    T: ToFromCommonDate<S> + ToFromOrdinalDate + FromFixed + ToFixed + Debug,
+{
+    assert!(T::min_date() < T::max_date());
+    for d in [T::min_date(), T::max_date()] {
+        let c = d.to_common_date();
+        assert!(T::in_effective_bounds(c));
+        assert_eq!(T::try_from_common_date(c).unwrap(), d);
+        assert_eq!(T::from_fixed(d.to_fixed()), d);
+        let ord = d.to_ordinal();
+        assert_eq!(T::try_from_ordinal(ord).unwrap(), d);
+    }
+    let inner_min = T::from_fixed(Fixed::new(T::min_date().to_fixed().get() + 1.0));
+    let inner_max = T::from_fixed(Fixed::new(T::max_date().to_fixed().get() - 1.0));
+    assert!(T::min_date() < inner_min);
+    assert!(inner_max < T::max_date());
+    let min = T::min_date().to_common_date();
+    let max = T::max_date().to_common_date();
+    for y in [
+        i64::MIN,
+        i64::MAX,
+        (min.year as i64) - 1,
+        (max.year as i64) + 1,
+    ] {
+        assert!(T::try_from_ymd_i64(y, 1, 1).is_err());
+    }
+    let d = T::try_from_ymd_i64(max.year as i64, max.month, max.day).unwrap();
+    assert_eq!(d, T::max_date());
+    assert!(T::try_year_start(T::EFFECTIVE_YEAR_MIN).unwrap() >= T::min_date());
+    assert!(T::try_year_end(T::EFFECTIVE_YEAR_MAX).unwrap() <= T::max_date());
+    assert_eq!(T::EFFECTIVE_YEAR_MIN - 1, min.year);
+    assert_eq!(T::EFFECTIVE_YEAR_MAX + 1, max.year);
+    for y in [min.year - 1, max.year + 1, i32::MIN, i32::MAX] {
+        let err = T::try_from_common_date(CommonDate::new(y, 1, 1)).unwrap_err();
+        assert!(matches!(err, CalendarError::OutOfBounds));
+    }
 }
 
 #[test]
 fn armenian() {
     bounds_actually_work::<Armenian>();
+    extremes_work::<_, Armenian>();
     bounds_actually_work::<ArmenianMoment>();
 }
 
+#[test]
+fn attic() {
+    bounds_actually_work::<AtticArith>();
+    extremes_work::<_, AtticArith>();
+    bounds_actually_work::<AtticArithMoment>();
+}
+
+#[test]
+fn babylonian() {
+    bounds_actually_work::<BabylonianArith>();
+    extremes_work::<_, BabylonianArith>();
+    bounds_actually_work::<BabylonianArithMoment>();
+}
+
+#[test]
+fn coligny() {
+    bounds_actually_work::<Coligny<true>>();
+    extremes_work::<_, Coligny<true>>();
+    bounds_actually_work::<Coligny<false>>();
+    extremes_work::<_, Coligny<false>>();
+    bounds_actually_work::<ColignyMoment<true>>();
+    bounds_actually_work::<ColignyMoment<false>>();
+}
+
 #[test]
 fn coptic() {
     bounds_actually_work::<Coptic>();
+    extremes_work::<_, Coptic>();
     bounds_actually_work::<CopticMoment>();
 }
 
 #[test]
 fn cotsworth() {
     bounds_actually_work::<Cotsworth>();
+    extremes_work::<_, Cotsworth>();
     bounds_actually_work::<CotsworthMoment>();
 }
 
 #[test]
 fn egyptian() {
     bounds_actually_work::<Egyptian>();
+    extremes_work::<_, Egyptian>();
     bounds_actually_work::<EgyptianMoment>();
 }
 
 #[test]
 fn ethiopic() {
     bounds_actually_work::<Ethiopic>();
+    extremes_work::<_, Ethiopic>();
     bounds_actually_work::<EthiopicMoment>();
 }
 
 #[test]
 fn french_rev_arith() {
     bounds_actually_work::<FrenchRevArith<true>>();
+    extremes_work::<_, FrenchRevArith<true>>();
     bounds_actually_work::<FrenchRevArith<false>>();
+    extremes_work::<_, FrenchRevArith<false>>();
     bounds_actually_work::<FrenchRevArithMoment<true>>();
     bounds_actually_work::<FrenchRevArithMoment<false>>();
 }
@@ -55,15 +137,80 @@ fn french_rev_arith() {
 #[test]
 fn gregorian() {
     bounds_actually_work::<Gregorian>();
+    extremes_work::<_, Gregorian>();
     bounds_actually_work::<GregorianMoment>();
 }
 
 #[test]
 fn holocene() {
     bounds_actually_work::<Holocene>();
+    extremes_work::<_, Holocene>();
     bounds_actually_work::<HoloceneMoment>();
 }
 
+#[test]
+fn icelandic() {
+    bounds_actually_work::<Icelandic>();
+    bounds_actually_work::<IcelandicMoment>();
+}
+
+#[test]
+fn bengali() {
+    bounds_actually_work::<Bengali>();
+    extremes_work::<_, Bengali>();
+    bounds_actually_work::<BengaliMoment>();
+}
+
+#[test]
+fn bikram_sambat() {
+    //The bounds are the edges of the month table, so there are no partial years
+    let min = BikramSambat::min_date();
+    let max = BikramSambat::max_date();
+    assert_eq!(BikramSambat::from_fixed(Fixed::effective_min()), min);
+    assert_eq!(BikramSambat::from_fixed(Fixed::effective_max()), max);
+    assert!(!BikramSambat::contains(Fixed::cast_new(0)));
+    assert!(BikramSambat::contains(min.to_fixed()));
+    assert!(BikramSambat::contains(max.to_fixed()));
+    assert!(!BikramSambat::contains(Fixed::new(
+        min.to_fixed().get() - 1.0
+    )));
+    assert!(!BikramSambat::contains(Fixed::new(
+        max.to_fixed().get() + 1.0
+    )));
+    let first = BikramSambat::try_year_start(BikramSambat::EFFECTIVE_YEAR_MIN).unwrap();
+    assert_eq!(first, min);
+    let last = BikramSambat::try_year_end(BikramSambat::EFFECTIVE_YEAR_MAX).unwrap();
+    assert_eq!(last, max);
+    for y in [
+        BikramSambat::EFFECTIVE_YEAR_MIN - 1,
+        BikramSambat::EFFECTIVE_YEAR_MAX + 1,
+        i32::MIN,
+        i32::MAX,
+    ] {
+        let err = BikramSambat::try_from_common_date(CommonDate::new(y, 1, 1)).unwrap_err();
+        assert!(matches!(err, CalendarError::OutOfBounds));
+    }
+    for y in [i64::MIN, i64::MAX] {
+        assert!(BikramSambat::try_from_ymd_i64(y, 1, 1).is_err());
+    }
+    assert!(BikramSambatMoment::effective_min() < BikramSambatMoment::effective_max());
+    assert!(BikramSambatMoment::contains(min.to_fixed()));
+}
+
+#[test]
+fn islamic() {
+    bounds_actually_work::<IslamicArith>();
+    extremes_work::<_, IslamicArith>();
+    bounds_actually_work::<IslamicArithMoment>();
+}
+
+#[test]
+fn islamic_umm_al_qura() {
+    bounds_actually_work::<IslamicUmmAlQura>();
+    extremes_work::<_, IslamicUmmAlQura>();
+    bounds_actually_work::<IslamicUmmAlQuraMoment>();
+}
+
 #[test]
 fn iso() {
     bounds_actually_work::<ISO>();
@@ -73,15 +220,63 @@ fn iso() {
 #[test]
 fn julian() {
     bounds_actually_work::<Julian>();
+    extremes_work::<_, Julian>();
     bounds_actually_work::<JulianMoment>();
 }
 
+#[test]
+fn offset() {
+    bounds_actually_work::<ThaiSolar>();
+    extremes_work::<_, ThaiSolar>();
+    bounds_actually_work::<ThaiSolarMoment>();
+    bounds_actually_work::<Minguo>();
+    extremes_work::<_, Minguo>();
+    bounds_actually_work::<MinguoMoment>();
+    bounds_actually_work::<ErisianYear>();
+    extremes_work::<_, ErisianYear>();
+    bounds_actually_work::<ErisianYearMoment>();
+    bounds_actually_work::<Kurdish>();
+    extremes_work::<_, Kurdish>();
+    bounds_actually_work::<KurdishMoment>();
+    bounds_actually_work::<Afghan>();
+    extremes_work::<_, Afghan>();
+    bounds_actually_work::<AfghanMoment>();
+}
+
+#[test]
+fn pataphysical() {
+    bounds_actually_work::<Pataphysical>();
+    extremes_work::<_, Pataphysical>();
+    bounds_actually_work::<PataphysicalMoment>();
+}
+
+#[test]
+fn persian() {
+    bounds_actually_work::<PersianArith>();
+    extremes_work::<_, PersianArith>();
+    bounds_actually_work::<PersianArithMoment>();
+}
+
 #[test]
 fn positivist() {
     bounds_actually_work::<Positivist>();
+    extremes_work::<_, Positivist>();
     bounds_actually_work::<PositivistMoment>();
 }
 
+#[test]
+fn retail() {
+    bounds_actually_work::<Retail445>();
+    extremes_work::<_, Retail445>();
+    bounds_actually_work::<Retail445Moment>();
+    bounds_actually_work::<Retail454>();
+    extremes_work::<_, Retail454>();
+    bounds_actually_work::<Retail454Moment>();
+    bounds_actually_work::<Retail544>();
+    extremes_work::<_, Retail544>();
+    bounds_actually_work::<Retail544Moment>();
+}
+
 #[test]
 fn roman() {
     bounds_actually_work::<Roman>();
@@ -90,17 +285,29 @@ fn roman() {
 #[test]
 fn symmetry() {
     bounds_actually_work::<Symmetry010>();
+    extremes_work::<_, Symmetry010>();
     bounds_actually_work::<Symmetry454>();
+    extremes_work::<_, Symmetry454>();
     bounds_actually_work::<Symmetry010Solstice>();
+    extremes_work::<_, Symmetry010Solstice>();
     bounds_actually_work::<Symmetry454Solstice>();
+    extremes_work::<_, Symmetry454Solstice>();
     bounds_actually_work::<Symmetry010Moment>();
     bounds_actually_work::<Symmetry454Moment>();
     bounds_actually_work::<Symmetry010SolsticeMoment>();
     bounds_actually_work::<Symmetry454SolsticeMoment>();
 }
 
+#[test]
+fn tamil() {
+    bounds_actually_work::<Tamil>();
+    extremes_work::<_, Tamil>();
+    bounds_actually_work::<TamilMoment>();
+}
+
 #[test]
 fn tranquility() {
     bounds_actually_work::<Tranquility>();
+    extremes_work::<_, Tranquility>();
     bounds_actually_work::<TranquilityMoment>();
 }
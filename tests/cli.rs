@@ -0,0 +1,48 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::process::Command;
+
+fn table(year: &str) -> std::process::Output {
+    table_to("coptic", year)
+}
+
+fn table_to(to: &str, year: &str) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_radnelac"))
+        .args(["table", "--from", "gregorian", "--to", to])
+        .args(["--year", year, "--format", "csv"])
+        .output()
+        .expect("Binary should run")
+}
+
+#[test]
+fn table_in_range() {
+    let out = table("2025");
+    assert!(out.status.success());
+    let csv = String::from_utf8(out.stdout).unwrap();
+    assert_eq!(csv.lines().count(), 13);
+}
+
+#[test]
+fn table_out_of_range() {
+    for year in ["2147483647", "-2147483648", "100000000", "-100000000"] {
+        let out = table(year);
+        assert!(!out.status.success(), "{}", year);
+        assert!(out.stdout.is_empty(), "{}", year);
+        let err = String::from_utf8(out.stderr).unwrap();
+        assert!(err.contains("outside of the supported range"), "{}", err);
+    }
+}
+
+#[test]
+fn table_target_out_of_range() {
+    assert!(table_to("bikram-sambat", "2025").status.success());
+    for year in ["1900", "2026"] {
+        let out = table_to("bikram-sambat", year);
+        assert!(!out.status.success(), "{}", year);
+        assert!(out.stdout.is_empty(), "{}", year);
+        let err = String::from_utf8(out.stderr).unwrap();
+        assert!(err.contains("outside of the supported range"), "{}", err);
+    }
+}
@@ -29,7 +29,7 @@ mod display_logic {
     pub use radnelac::display::PresetDisplay;
     pub use radnelac::display::COMPL_ONLY;
 
-    pub const MAX_YEARS: i32 = (FIXED_MAX / 365.25) as i32;
+    pub const MAX_YEARS: i32 = ((FIXED_MAX / 365.25) - 20000.0) as i32; //Room for calendars with distant epochs
 
     pub fn perennial_compl<S, T, U>(cd: CommonDate, lang: Language, expected: &str)
     where
@@ -6,6 +6,7 @@
 mod display_logic {
     pub use proptest::proptest;
     pub use radnelac::calendar::Armenian;
+    pub use radnelac::calendar::Bengali;
     pub use radnelac::calendar::Coptic;
     pub use radnelac::calendar::Cotsworth;
     pub use radnelac::calendar::Egyptian;
@@ -19,6 +20,7 @@ mod display_logic {
     pub use radnelac::calendar::Symmetry010Solstice;
     pub use radnelac::calendar::Symmetry454;
     pub use radnelac::calendar::Symmetry454Solstice;
+    pub use radnelac::calendar::Tamil;
     pub use radnelac::day_count::BoundedDayCount;
     pub use radnelac::day_count::Fixed;
     pub use radnelac::day_count::FromFixed;
@@ -54,6 +56,11 @@ proptest! {
         display_midnight::<Armenian>(t0);
     }
 
+    #[test]
+    fn bengali_midnight(t0 in -FIXED_MAX..FIXED_MAX) {
+        display_midnight::<Bengali>(t0);
+    }
+
     #[test]
     fn coptic_midnight(t0 in -FIXED_MAX..FIXED_MAX) {
         display_midnight::<Coptic>(t0);
@@ -128,6 +135,11 @@ proptest! {
         display_midnight::<Symmetry454Solstice>(t0);
     }
 
+    #[test]
+    fn tamil_midnight(t0 in -FIXED_MAX..FIXED_MAX) {
+        display_midnight::<Tamil>(t0);
+    }
+
     #[test]
     fn symmetry_epagomenae(t0 in -FIXED_MAX..FIXED_MAX) {
         display_blank_complementary::<Symmetry010>(t0);
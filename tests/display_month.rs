@@ -5,6 +5,7 @@
 #[cfg(feature = "display")]
 mod display_logic {
     pub use num_traits::cast::FromPrimitive;
+    pub use num_traits::cast::ToPrimitive;
     pub use proptest::proptest;
     pub use radnelac::calendar::Cotsworth;
     pub use radnelac::calendar::CotsworthMonth;
@@ -25,7 +26,7 @@ mod display_logic {
     pub use radnelac::display::PresetDisplay;
     pub use radnelac::display::LONG_DATE;
 
-    pub const MAX_YEARS: i32 = (FIXED_MAX / 365.25) as i32;
+    pub const MAX_YEARS: i32 = ((FIXED_MAX / 365.25) - 20000.0) as i32; //Room for calendars with distant epochs
 
     pub fn long_date_contains<T: PresetDisplay>(d: T, lang: Language, s: &str) {
         assert!(d.preset_str(lang, LONG_DATE).contains(s));
@@ -39,11 +40,12 @@ mod display_logic {
     }
 
     pub fn gregorian_like_date_contains<
-        T: PresetDisplay + Copy + GuaranteedMonth<GregorianMonth>,
+        S: FromPrimitive + ToPrimitive + Into<GregorianMonth>,
+        T: PresetDisplay + Copy + GuaranteedMonth<S>,
     >(
         d: T,
     ) {
-        match d.month() {
+        match d.month().into() {
             GregorianMonth::January => bilingual_long_date_contains(d, "January", "janvier"),
             GregorianMonth::February => bilingual_long_date_contains(d, "February", "février"),
             GregorianMonth::March => bilingual_long_date_contains(d, "March", "mars"),
@@ -63,15 +63,13 @@ mod display_logic {
         let ymd1 = d.preset_str(Language::EN, DDMMYYYY_SLASH);
         let ymd2 = d.preset_str(Language::EN, MMDDYYYY_SLASH);
         let ymd3 = d.preset_str(Language::EN, YYYYMMDD_DASH);
-        assert_eq!(&ymd0[0..4], &ymd1[6..10]);
-        assert_eq!(&ymd0[5..7], &ymd1[3..5]);
-        assert_eq!(&ymd0[8..10], &ymd1[0..2]);
-        assert_eq!(&ymd0[0..4], &ymd2[6..10]);
-        assert_eq!(&ymd0[5..7], &ymd2[0..2]);
-        assert_eq!(&ymd0[8..10], &ymd2[3..5]);
-        assert_eq!(&ymd0[0..4], &ymd3[0..4]);
-        assert_eq!(&ymd0[5..7], &ymd3[5..7]);
-        assert_eq!(&ymd0[8..10], &ymd3[8..10]);
+        let ymd0: Vec<&str> = ymd0.split('/').collect();
+        let dmy: Vec<&str> = ymd1.split('/').collect();
+        let mdy: Vec<&str> = ymd2.split('/').collect();
+        let ymd3: Vec<&str> = ymd3.split('-').collect();
+        assert_eq!(ymd0, [dmy[2], dmy[1], dmy[0]]);
+        assert_eq!(ymd0, [mdy[2], mdy[0], mdy[1]]);
+        assert_eq!(ymd0, ymd3);
     }
 
     pub fn epoch_order<T: FromFixed + PresetDisplay + PartialOrd>(
@@ -239,6 +237,25 @@ proptest! {
         epoch_order::<Gregorian>(EPOCH_DAYS_ONLY, t0, t1);
     }
 
+    #[test]
+    fn gregorian_expanded(t in FIXED_MIN..FIXED_MAX) {
+        let d = Gregorian::from_fixed(Fixed::new(t).to_day());
+        let s = d.preset_str(Language::EN, EXPANDED_YYYYMMDD_DASH);
+        let c = d.to_common_date();
+        let sign = if c.year < 0 { "-" } else { "+" };
+        assert!(s.starts_with(sign));
+        let fields: Vec<&str> = s[1..].split('-').collect();
+        assert!(fields[0].len() >= 6);
+        assert_eq!(fields[0].parse::<i32>().unwrap(), c.year.abs());
+        assert_eq!(fields[1].parse::<u8>().unwrap(), c.month);
+        assert_eq!(fields[2].parse::<u8>().unwrap(), c.day);
+    }
+
+    #[test]
+    fn gregorian_expanded_order(t0 in MIN_4DIGIT..MAX_4DIGIT, t1 in MIN_4DIGIT..MAX_4DIGIT) {
+        ymd_order::<Gregorian>(EXPANDED_YYYYMMDD_DASH, t0, t1);
+    }
+
     #[test]
     fn holocene(t0 in HL_MIN_5DIGIT..HL_MAX_5DIGIT, t1 in HL_MIN_5DIGIT..HL_MAX_5DIGIT) {
         ymd_order::<Holocene>(YYYYYMMDD_DASH, t0, t1);
@@ -0,0 +1,141 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "display")]
+mod display_logic {
+    pub use proptest::proptest;
+    pub use radnelac::calendar::*;
+    pub use radnelac::day_count::*;
+    pub use radnelac::display::EraYear;
+    pub use radnelac::display::Language;
+    pub use radnelac::display::MonthName;
+    pub use radnelac::display::PresetDisplay;
+    pub use radnelac::display::FISCAL_YEAR;
+    pub use radnelac::display::LONG_DATE;
+    pub use radnelac::display::LONG_DATE_ERA_ABBR;
+    pub use std::fmt::Debug;
+    pub use std::str::FromStr;
+
+    const LANGUAGES: [Language; 2] = [Language::EN, Language::FR];
+
+    /// Split a long date into the month name and the year with its era
+    fn long_date_parts(s: &str) -> (&str, &str) {
+        let (weekday_month_day, year_era) = s.rsplit_once(", ").expect("Has year");
+        let (weekday_month, _) = weekday_month_day.rsplit_once(' ').expect("Has day");
+        let (_, month) = weekday_month.split_once(' ').expect("Has weekday");
+        (month, year_era)
+    }
+
+    pub fn parse_long_date<T, U>(t0: f64)
+    where
+        T: MonthName + FromStr + PartialEq + Debug,
+        <T as FromStr>::Err: Debug,
+        U: ToFromCommonDate<T> + EraYear + PresetDisplay + FromFixed,
+    {
+        let d = U::from_fixed(Fixed::new(t0));
+        let Some(month) = d.try_month() else {
+            return;
+        };
+        let year = d.year();
+        for lang in LANGUAGES.iter().filter(|l| U::supported_display_lang(**l)) {
+            for preset in [LONG_DATE, LONG_DATE_ERA_ABBR] {
+                let s = d.preset_str(*lang, preset);
+                let (month_name, year_era) = long_date_parts(&s);
+                assert_eq!(T::from_month_name(month_name, *lang), Some(month), "{}", s);
+                assert_eq!(month_name.parse::<T>().unwrap(), month, "{}", s);
+                assert_eq!(
+                    U::year_from_era_str(year_era, *lang).unwrap(),
+                    year,
+                    "{}",
+                    s
+                );
+                assert_eq!(
+                    U::try_year_from_any_era_str(year_era).unwrap(),
+                    year,
+                    "{}",
+                    s
+                );
+            }
+            let s = d.preset_str(*lang, FISCAL_YEAR);
+            assert_eq!(U::year_from_era_str(&s, *lang).unwrap(), year, "{}", s);
+        }
+    }
+}
+
+#[cfg(feature = "display")]
+use display_logic::*;
+
+#[cfg(feature = "display")]
+proptest! {
+    #[test]
+    fn bengali(t0 in -FIXED_MAX..FIXED_MAX) {
+        parse_long_date::<BengaliMonth, Bengali>(t0);
+    }
+
+    #[test]
+    fn gregorian(t0 in -FIXED_MAX..FIXED_MAX) {
+        parse_long_date::<GregorianMonth, Gregorian>(t0);
+    }
+
+    #[test]
+    fn julian(t0 in -FIXED_MAX..FIXED_MAX) {
+        parse_long_date::<JulianMonth, Julian>(t0);
+    }
+
+    #[test]
+    fn tamil(t0 in -FIXED_MAX..FIXED_MAX) {
+        parse_long_date::<TamilMonth, Tamil>(t0);
+    }
+}
+
+#[cfg(all(feature = "display", feature = "ancient-calendars"))]
+proptest! {
+    #[test]
+    fn armenian(t0 in -FIXED_MAX..FIXED_MAX) {
+        parse_long_date::<ArmenianMonth, Armenian>(t0);
+    }
+
+    #[test]
+    fn coptic(t0 in -FIXED_MAX..FIXED_MAX) {
+        parse_long_date::<CopticMonth, Coptic>(t0);
+    }
+
+    #[test]
+    fn egyptian(t0 in -FIXED_MAX..FIXED_MAX) {
+        parse_long_date::<EgyptianMonth, Egyptian>(t0);
+    }
+
+    #[test]
+    fn ethiopic(t0 in -FIXED_MAX..FIXED_MAX) {
+        parse_long_date::<EthiopicMonth, Ethiopic>(t0);
+    }
+}
+
+#[cfg(all(feature = "display", feature = "reform-calendars"))]
+proptest! {
+    #[test]
+    fn cotsworth(t0 in -FIXED_MAX..FIXED_MAX) {
+        parse_long_date::<CotsworthMonth, Cotsworth>(t0);
+    }
+
+    #[test]
+    fn french_rev(t0 in -FIXED_MAX..FIXED_MAX) {
+        parse_long_date::<FrenchRevMonth, FrenchRevArith<true>>(t0);
+    }
+
+    #[test]
+    fn positivist(t0 in -FIXED_MAX..FIXED_MAX) {
+        parse_long_date::<PositivistMonth, Positivist>(t0);
+    }
+
+    #[test]
+    fn symmetry(t0 in -FIXED_MAX..FIXED_MAX) {
+        parse_long_date::<SymmetryMonth, Symmetry454>(t0);
+    }
+
+    #[test]
+    fn tranquility(t0 in -FIXED_MAX..FIXED_MAX) {
+        parse_long_date::<TranquilityMonth, Tranquility>(t0);
+    }
+}
@@ -78,13 +78,18 @@ mod display_logic {
         }
     }
 
-    pub fn compare_common_weekday<U: FromFixed + ToString>(t0: f64, t1: f64, m_idx: usize) {
+    pub fn compare_common_weekday<U: FromFixed + ToFixed + ToString>(
+        t0: f64,
+        t1: f64,
+        m_idx: usize,
+    ) {
         let f0 = Fixed::new(t0);
         let f1 = Fixed::new(t1);
-        let w0 = Weekday::from_fixed(f0);
-        let w1 = Weekday::from_fixed(f1);
         let a0 = U::from_fixed(f0);
         let a1 = U::from_fixed(f1);
+        //Moments within floating point error of midnight are on the following day
+        let w0 = Weekday::from_fixed(a0.to_fixed());
+        let w1 = Weekday::from_fixed(a1.to_fixed());
         let s0 = a0.to_string();
         let s1 = a1.to_string();
         let v0: Vec<&str> = s0.split(' ').collect();
@@ -185,6 +190,54 @@ proptest! {
         compare_era_abbrev::<ArmenianMoment>(t0, t1);
     }
 
+    #[test]
+    fn bengali_blanks(t0 in -FIXED_MAX..FIXED_MAX) {
+        reasonable_blanks::<Bengali>(t0);
+        reasonable_blanks::<BengaliMoment>(t0);
+    }
+
+    #[test]
+    fn bengali_month(t0 in -FIXED_MAX..FIXED_MAX, t1 in -FIXED_MAX..FIXED_MAX) {
+        compare_month::<BengaliMonth, Bengali>(t0, t1, 1);
+        compare_month::<BengaliMonth, BengaliMoment>(t0, t1, 2);
+    }
+
+    #[test]
+    fn bengali_weekday(t0 in -FIXED_MAX..FIXED_MAX, t1 in -FIXED_MAX..FIXED_MAX) {
+        compare_common_weekday::<Bengali>(t0, t1, 0);
+        compare_common_weekday::<BengaliMoment>(t0, t1, 1);
+    }
+
+    #[test]
+    fn bengali_era_abbrev(t0 in -FIXED_MAX..FIXED_MAX, t1 in -FIXED_MAX..FIXED_MAX) {
+        compare_era_abbrev::<Bengali>(t0, t1);
+        compare_era_abbrev::<BengaliMoment>(t0, t1);
+    }
+
+    #[test]
+    fn tamil_blanks(t0 in -FIXED_MAX..FIXED_MAX) {
+        reasonable_blanks::<Tamil>(t0);
+        reasonable_blanks::<TamilMoment>(t0);
+    }
+
+    #[test]
+    fn tamil_month(t0 in -FIXED_MAX..FIXED_MAX, t1 in -FIXED_MAX..FIXED_MAX) {
+        compare_month::<TamilMonth, Tamil>(t0, t1, 1);
+        compare_month::<TamilMonth, TamilMoment>(t0, t1, 2);
+    }
+
+    #[test]
+    fn tamil_weekday(t0 in -FIXED_MAX..FIXED_MAX, t1 in -FIXED_MAX..FIXED_MAX) {
+        compare_common_weekday::<Tamil>(t0, t1, 0);
+        compare_common_weekday::<TamilMoment>(t0, t1, 1);
+    }
+
+    #[test]
+    fn tamil_era_abbrev(t0 in -FIXED_MAX..FIXED_MAX, t1 in -FIXED_MAX..FIXED_MAX) {
+        compare_era_abbrev::<Tamil>(t0, t1);
+        compare_era_abbrev::<TamilMoment>(t0, t1);
+    }
+
     #[test]
     fn coptic_blanks(t0 in -FIXED_MAX..FIXED_MAX) {
         reasonable_blanks::<Coptic>(t0);
@@ -0,0 +1,80 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use num_traits::FromPrimitive;
+use proptest::proptest;
+use radnelac::calendar::*;
+use radnelac::clock::TimeOfDay;
+use radnelac::day_count::*;
+
+fn elapsed<S, T>(t: f64)
+where
+    S: FromPrimitive,
+    T: ElapsedFraction<S> + FromFixed,
+{
+    let d = T::from_fixed(Fixed::new(t).to_day());
+    let y = d.fraction_of_year_elapsed();
+    assert!((0.0..1.0).contains(&y));
+    if d.to_common_date() == T::year_start_date(d.year()) {
+        assert_eq!(y, 0.0);
+    }
+    match d.fraction_of_month_elapsed() {
+        Some(m) => {
+            assert!((0.0..1.0).contains(&m));
+            assert_eq!(m == 0.0, d.day() == 1);
+        }
+        None => assert!(d.try_month().is_none()),
+    }
+}
+
+#[test]
+fn gregorian() {
+    let d = Gregorian::try_new(2025, GregorianMonth::July, 2).unwrap();
+    assert_eq!(d.fraction_of_year_elapsed(), 182.0 / 365.0);
+    assert_eq!(d.fraction_of_month_elapsed(), Some(1.0 / 31.0));
+    let d = Gregorian::try_new(2024, GregorianMonth::February, 15).unwrap();
+    assert_eq!(d.fraction_of_year_elapsed(), 45.0 / 366.0);
+    assert_eq!(d.fraction_of_month_elapsed(), Some(14.0 / 29.0));
+}
+
+#[test]
+fn moment() {
+    let d = Gregorian::try_new(2025, GregorianMonth::February, 1).unwrap();
+    let m = GregorianMoment::new(d, TimeOfDay::noon());
+    assert_eq!(m.fraction_of_year_elapsed(), 31.5 / 365.0);
+    assert_eq!(m.fraction_of_month_elapsed(), Some(0.5 / 28.0));
+}
+
+#[test]
+fn no_month() {
+    let d = Egyptian::try_from_common_date(CommonDate::new(1000, 13, 5)).unwrap();
+    assert!(d.fraction_of_month_elapsed().is_none());
+    assert_eq!(d.fraction_of_year_elapsed(), 364.0 / 365.0);
+}
+
+proptest! {
+    #[test]
+    fn elapsed_fractions(t in FIXED_MIN..FIXED_MAX) {
+        elapsed::<ArmenianMonth, Armenian>(t);
+        elapsed::<BengaliMonth, Bengali>(t);
+        elapsed::<BikramSambatMonth, BikramSambat>(t);
+        elapsed::<TamilMonth, Tamil>(t);
+        elapsed::<CopticMonth, Coptic>(t);
+        elapsed::<CotsworthMonth, Cotsworth>(t);
+        elapsed::<EgyptianMonth, Egyptian>(t);
+        elapsed::<EthiopicMonth, Ethiopic>(t);
+        elapsed::<FrenchRevMonth, FrenchRevArith<true>>(t);
+        elapsed::<GregorianMonth, Gregorian>(t);
+        elapsed::<HoloceneMonth, Holocene>(t);
+        elapsed::<IslamicMonth, IslamicArith>(t);
+        elapsed::<IslamicMonth, IslamicUmmAlQura>(t);
+        elapsed::<JulianMonth, Julian>(t);
+        elapsed::<PataphysicalMonth, Pataphysical>(t);
+        elapsed::<PersianMonth, PersianArith>(t);
+        elapsed::<PositivistMonth, Positivist>(t);
+        elapsed::<RetailPeriod, Retail454>(t);
+        elapsed::<SymmetryMonth, Symmetry454>(t);
+        elapsed::<TranquilityMonth, Tranquility>(t);
+    }
+}
@@ -0,0 +1,75 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use num_traits::FromPrimitive;
+use proptest::proptest;
+use radnelac::calendar::*;
+use radnelac::day_count::*;
+use std::hash::BuildHasher;
+use std::hash::Hash;
+use std::hash::RandomState;
+
+fn hash_of<T: Hash>(state: &RandomState, x: T) -> u64 {
+    state.hash_one(x)
+}
+
+fn hash<S, T>(t0: f64, t1: f64)
+where
+    S: FromPrimitive,
+    T: ToFromCommonDate<S> + FromFixed + Hash + Eq + Copy,
+{
+    let state = RandomState::new();
+    let d0 = T::from_fixed(Fixed::new(t0));
+    let d1 = T::from_fixed(Fixed::new(t1));
+    let c0 = d0.to_common_date();
+    assert_eq!(hash_of(&state, d0), hash_of(&state, c0));
+    assert_eq!(d0.stable_hash_u64(), c0.stable_hash_u64());
+    let same_time = T::from_fixed(Fixed::new(t0).to_day());
+    assert_eq!(hash_of(&state, d0), hash_of(&state, same_time));
+    if d0 == d1 {
+        assert_eq!(hash_of(&state, d0), hash_of(&state, d1));
+        assert_eq!(d0.stable_hash_u64(), d1.stable_hash_u64());
+    }
+}
+
+#[test]
+fn stable_hash_known_values() {
+    let d = Gregorian::try_new(2025, GregorianMonth::July, 26).unwrap();
+    assert_eq!(d.stable_hash_u64(), 0xb1626b08e514b4d8);
+    let d = Julian::try_new(2025, JulianMonth::July, 26).unwrap();
+    assert_eq!(d.stable_hash_u64(), 0xb1626b08e514b4d8);
+    assert_eq!(
+        CommonDate::new(0, 0, 0).stable_hash_u64(),
+        0xd7e4fcfa299d713d
+    );
+    assert_ne!(
+        CommonDate::new(-1, 1, 1).stable_hash_u64(),
+        CommonDate::new(1, 1, 1).stable_hash_u64()
+    );
+}
+
+proptest! {
+    #[test]
+    fn hash_is_common_date(t0 in FIXED_MIN..FIXED_MAX, t1 in -1000.0..1000.0) {
+        hash::<ArmenianMonth, Armenian>(t0, t1);
+        hash::<BengaliMonth, Bengali>(t0, t1);
+        hash::<BikramSambatMonth, BikramSambat>(t0, t1);
+        hash::<TamilMonth, Tamil>(t0, t1);
+        hash::<CopticMonth, Coptic>(t0, t1);
+        hash::<CotsworthMonth, Cotsworth>(t0, t1);
+        hash::<EgyptianMonth, Egyptian>(t0, t1);
+        hash::<EthiopicMonth, Ethiopic>(t0, t1);
+        hash::<FrenchRevMonth, FrenchRevArith<true>>(t0, t1);
+        hash::<GregorianMonth, Gregorian>(t0, t1);
+        hash::<HoloceneMonth, Holocene>(t0, t1);
+        hash::<IslamicMonth, IslamicArith>(t0, t1);
+        hash::<IslamicMonth, IslamicUmmAlQura>(t0, t1);
+        hash::<JulianMonth, Julian>(t0, t1);
+        hash::<PataphysicalMonth, Pataphysical>(t0, t1);
+        hash::<PersianMonth, PersianArith>(t0, t1);
+        hash::<PositivistMonth, Positivist>(t0, t1);
+        hash::<SymmetryMonth, Symmetry454>(t0, t1);
+        hash::<TranquilityMonth, Tranquility>(t0, t1);
+    }
+}
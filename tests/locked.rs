@@ -30,6 +30,8 @@ use radnelac::calendar::PositivistMonth;
 use radnelac::calendar::ToFromCommonDate;
 use radnelac::calendar::TranquilityMoment;
 use radnelac::calendar::TranquilityMonth;
+use radnelac::day_count::convert;
+use radnelac::day_count::epoch_offset;
 use radnelac::day_count::BoundedDayCount;
 use radnelac::day_count::Epoch;
 use radnelac::day_count::Fixed;
@@ -44,7 +46,7 @@ use radnelac::day_cycle::Akan;
 use radnelac::day_cycle::AkanStem;
 use radnelac::day_cycle::Weekday;
 
-const MAX_YEARS: i32 = (FIXED_MAX / 365.25) as i32;
+const MAX_YEARS: i32 = ((FIXED_MAX / 365.25) - 20000.0) as i32; //Room for calendars with distant epochs
 
 fn locked_multi<R, S, T, U>(d0: CommonDate, d1: CommonDate)
 where
@@ -58,7 +60,7 @@ where
     let fa = a.to_fixed();
     let fe = e.to_fixed();
     let diff_f = fa.get() - fe.get();
-    let diff_e = T::epoch().get() - U::epoch().to_day().get();
+    let diff_e = epoch_offset::<T, U>() as f64;
     assert_eq!(diff_f, diff_e);
 }
 
@@ -72,6 +74,17 @@ where
     locked_multi::<R, S, T, U>(d, d);
 }
 
+fn locked_from<T, U>(a: T)
+where
+    T: ToFixed + FromFixed + From<U> + PartialEq + std::fmt::Debug,
+    U: ToFixed + FromFixed + From<T> + PartialEq + std::fmt::Debug,
+{
+    let e = U::from(a);
+    assert_eq!(e, a.convert::<U>());
+    assert_eq!(e, convert::<T, U>(a));
+    assert_eq!(T::from(e), a);
+}
+
 fn locked_alt_multi<R, S, T, U>(d0: CommonDate, d1: CommonDate)
 where
     R: FromPrimitive + ToPrimitive,
@@ -116,6 +129,7 @@ proptest! {
         let a = Armenian::try_from_common_date(d).unwrap();
         let e = Egyptian::try_from_common_date(d).unwrap();
         assert_eq!(a.epagomenae().is_some(), e.epagomenae().is_some());
+        locked_from::<Armenian, Egyptian>(a);
     }
 
     #[test]
@@ -123,6 +137,7 @@ proptest! {
         let d = CommonDate{ year: year, month: month as u8, day: day as u8 };
         locked::<HoloceneMonth, GregorianMonth, Holocene, Gregorian>(d);
         locked_alt::<HoloceneMonth, GregorianMonth, Holocene, Gregorian>(d, 10000);
+        locked_from::<Holocene, Gregorian>(Holocene::try_from_common_date(d).unwrap());
     }
 
     #[test]
@@ -170,6 +185,7 @@ proptest! {
     fn ethiopic_locked_to_coptic(year in -MAX_YEARS..MAX_YEARS, month in 1..12, day in 1..30) {
         let d = CommonDate{ year: year, month: month as u8, day: day as u8 };
         locked::<EthiopicMonth, CopticMonth, Ethiopic, Coptic>(d);
+        locked_from::<Ethiopic, Coptic>(Ethiopic::try_from_common_date(d).unwrap());
     }
 
     #[test]
@@ -0,0 +1,84 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use proptest::proptest;
+use radnelac::calendar::*;
+use radnelac::clock::ClockTime;
+use radnelac::clock::TimeOfDay;
+use radnelac::day_count::BoundedDayCount;
+use radnelac::day_count::Fixed;
+use radnelac::day_count::FromFixed;
+use radnelac::day_count::RataDie;
+use radnelac::day_count::ToFixed;
+use radnelac::day_count::FIXED_MAX;
+use radnelac::day_count::FIXED_MIN;
+use std::fmt::Debug;
+
+fn moment_api<T>(t0: f64, t1: f64, h: u8, m: u8)
+where
+    T: FromFixed + ToFixed + PartialEq + Debug,
+{
+    let f0 = Fixed::new(t0);
+    let m0 = CalendarMoment::<T>::from_fixed(f0);
+    assert_eq!(m0.date(), T::from_fixed(f0));
+    assert_eq!(m0.time_of_day(), TimeOfDay::from_fixed(f0).to_clock());
+    assert_eq!(
+        CalendarMoment::new(m0.date(), TimeOfDay::from_fixed(f0)),
+        m0
+    );
+
+    let d1 = T::from_fixed(Fixed::new(t1));
+    let m1 = m0.with_date(d1);
+    assert_eq!(m1.date(), d1);
+    assert_eq!(m1.time_of_day(), m0.time_of_day());
+
+    let clock = ClockTime::try_new(h, m, 0.0).unwrap();
+    let m2 = m0.try_with_clock(clock).unwrap();
+    assert_eq!(m2.date(), m0.date());
+    assert_eq!(m2.time_of_day(), clock);
+    assert_eq!(
+        m2,
+        CalendarMoment::try_from_clock(m0.date(), clock).unwrap()
+    );
+    let t = TimeOfDay::try_from_clock(clock).unwrap();
+    assert_eq!(m0.with_time(t), m2);
+    assert!(m0
+        .try_with_clock(ClockTime::new_unchecked(24, m, 0.0))
+        .is_err());
+
+    let r = m2.convert::<CalendarMoment<RataDie>>();
+    assert_eq!(r.date().to_fixed().to_day(), m2.date().to_fixed());
+    assert!((r.to_fixed().get() - m2.to_fixed().get()).abs() < 1e-6);
+    let back = r.convert::<CalendarMoment<T>>();
+    assert_eq!(back.date(), m2.date());
+    assert!((back.to_fixed().get() - m2.to_fixed().get()).abs() < 1e-6);
+}
+
+proptest! {
+    #[test]
+    fn aliases(t0 in FIXED_MIN..FIXED_MAX, t1 in FIXED_MIN..FIXED_MAX, h in 0..24u8, m in 0..60u8) {
+        moment_api::<Armenian>(t0, t1, h, m);
+        moment_api::<Coptic>(t0, t1, h, m);
+        moment_api::<Cotsworth>(t0, t1, h, m);
+        moment_api::<Egyptian>(t0, t1, h, m);
+        moment_api::<Ethiopic>(t0, t1, h, m);
+        moment_api::<FrenchRevArith<true>>(t0, t1, h, m);
+        moment_api::<FrenchRevArith<false>>(t0, t1, h, m);
+        moment_api::<Gregorian>(t0, t1, h, m);
+        moment_api::<Holocene>(t0, t1, h, m);
+        moment_api::<ISO>(t0, t1, h, m);
+        moment_api::<Julian>(t0, t1, h, m);
+        moment_api::<Minguo>(t0, t1, h, m);
+        moment_api::<Positivist>(t0, t1, h, m);
+        moment_api::<Retail445>(t0, t1, h, m);
+        moment_api::<Retail454>(t0, t1, h, m);
+        moment_api::<Retail544>(t0, t1, h, m);
+        moment_api::<Symmetry454>(t0, t1, h, m);
+        moment_api::<Symmetry010>(t0, t1, h, m);
+        moment_api::<Symmetry454Solstice>(t0, t1, h, m);
+        moment_api::<Symmetry010Solstice>(t0, t1, h, m);
+        moment_api::<ThaiSolar>(t0, t1, h, m);
+        moment_api::<Tranquility>(t0, t1, h, m);
+    }
+}
@@ -25,6 +25,10 @@ use radnelac::calendar::Julian;
 use radnelac::calendar::JulianMonth;
 use radnelac::calendar::Positivist;
 use radnelac::calendar::PositivistMonth;
+use radnelac::calendar::Retail445;
+use radnelac::calendar::Retail454;
+use radnelac::calendar::Retail544;
+use radnelac::calendar::RetailPeriod;
 use radnelac::calendar::Roman;
 use radnelac::calendar::Symmetry010;
 use radnelac::calendar::Symmetry010Solstice;
@@ -38,6 +42,8 @@ use radnelac::calendar::ISO;
 use radnelac::day_count::BoundedDayCount;
 use radnelac::day_count::Fixed;
 use radnelac::day_count::FromFixed;
+use radnelac::day_count::SameDay;
+use radnelac::day_count::ToFixed;
 use radnelac::day_count::FIXED_MAX;
 use radnelac::day_count::FIXED_MIN;
 use std::fmt::Debug;
@@ -73,6 +79,18 @@ fn consistent_order<
     assert_eq!(f0 > f1, c0 > c1);
 }
 
+fn consistent_same_day<T: FromFixed + ToFixed, U: FromFixed + ToFixed>(t0: f64, t1: f64) {
+    let f0 = Fixed::new(t0);
+    let f1 = Fixed::new(t1);
+    let d0 = T::from_fixed(f0);
+    let d1 = U::from_fixed(f1);
+    let expected = f0.get_day_i().cmp(&f1.get_day_i());
+    assert_eq!(d0.cmp_day(d1), expected);
+    assert_eq!(d1.cmp_day(d0), expected.reverse());
+    assert_eq!(d0.same_day(d1), f0.get_day_i() == f1.get_day_i());
+    assert!(d0.same_day(U::from_fixed(f0)));
+}
+
 fn consistent_order_ordinal<T: FromFixed + PartialEq + Debug + ToFromOrdinalDate>(
     t0: f64,
     t1: f64,
@@ -221,6 +239,26 @@ proptest! {
         consistent_order_ordinal::<Positivist>(t0, t0 + (diff as f64));
     }
 
+    #[test]
+    fn retail(t0 in FIXED_MIN..FIXED_MAX, t1 in FIXED_MIN..FIXED_MAX) {
+        consistent_order::<RetailPeriod, Retail445>(t0, t1);
+        consistent_order::<RetailPeriod, Retail454>(t0, t1);
+        consistent_order::<RetailPeriod, Retail544>(t0, t1);
+        consistent_order_ordinal::<Retail445>(t0, t1);
+        consistent_order_ordinal::<Retail454>(t0, t1);
+        consistent_order_ordinal::<Retail544>(t0, t1);
+    }
+
+    #[test]
+    fn retail_small(t0 in FIXED_MIN..FIXED_MAX, diff in i8::MIN..i8::MAX) {
+        consistent_order::<RetailPeriod, Retail445>(t0, t0 + (diff as f64));
+        consistent_order::<RetailPeriod, Retail454>(t0, t0 + (diff as f64));
+        consistent_order::<RetailPeriod, Retail544>(t0, t0 + (diff as f64));
+        consistent_order_ordinal::<Retail445>(t0, t0 + (diff as f64));
+        consistent_order_ordinal::<Retail454>(t0, t0 + (diff as f64));
+        consistent_order_ordinal::<Retail544>(t0, t0 + (diff as f64));
+    }
+
     #[test]
     fn roman(t0 in FIXED_MIN..FIXED_MAX, t1 in FIXED_MIN..FIXED_MAX) {
         consistent_order_basic::<Roman>(t0, t1);
@@ -266,4 +304,13 @@ proptest! {
         consistent_order_basic::<TranquilityMoment>(t0, t0 + (diff as f64));
         consistent_order_ordinal::<TranquilityMoment>(t0, t0 + (diff as f64));
     }
+
+    #[test]
+    fn same_day(t0 in FIXED_MIN..FIXED_MAX, diff in i8::MIN..i8::MAX) {
+        let t1 = t0 + (diff as f64);
+        consistent_same_day::<Gregorian, Julian>(t0, t1);
+        consistent_same_day::<Cotsworth, TranquilityMoment>(t0, t1);
+        consistent_same_day::<ISO, Roman>(t0, t1);
+        consistent_same_day::<FrenchRevArith<true>, Symmetry454>(t0, t1);
+    }
 }
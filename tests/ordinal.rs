@@ -37,6 +37,7 @@ use radnelac::calendar::ToFromCommonDate;
 use radnelac::calendar::ToFromOrdinalDate;
 use radnelac::calendar::TranquilityMoment;
 use radnelac::calendar::TranquilityMonth;
+use radnelac::day_count::ToFixed;
 use radnelac::day_count::FIXED_MAX;
 use std::fmt::Debug;
 
@@ -63,7 +64,64 @@ fn year_start<
     assert_eq!(d1.to_ordinal(), ord1);
 }
 
+fn succ_pred<T: ToFromOrdinalDate + ToFixed>(year: i32, day: u16) {
+    let ord = OrdinalDate {
+        year,
+        day_of_year: day,
+    };
+    if T::valid_ordinal(ord).is_ok() {
+        let next = ord.succ::<T>().unwrap();
+        let prev = ord.pred::<T>().unwrap();
+        assert!(next > ord);
+        assert!(prev < ord);
+        assert_eq!(next.pred::<T>().unwrap(), ord);
+        assert_eq!(prev.succ::<T>().unwrap(), ord);
+        let f = T::try_from_ordinal(ord).unwrap().to_fixed();
+        let f_next = T::try_from_ordinal(next).unwrap().to_fixed();
+        assert_eq!(f_next.get_day_i() - f.get_day_i(), 1);
+        assert_eq!(T::try_from_year_day(year, day).unwrap().to_fixed(), f);
+    } else {
+        assert!(ord.succ::<T>().is_err());
+        assert!(ord.pred::<T>().is_err());
+        assert!(T::try_from_year_day(year, day).is_err());
+    }
+}
+
+#[test]
+fn try_from_tuple() {
+    let ord = OrdinalDate::try_from((2025, 100)).unwrap();
+    assert_eq!(
+        ord,
+        OrdinalDate {
+            year: 2025,
+            day_of_year: 100
+        }
+    );
+    assert!(OrdinalDate::try_from((2025, 0)).is_err());
+    assert!(OrdinalDate::try_from((2025, 385)).is_ok());
+    assert!(OrdinalDate::try_from((2025, 386)).is_err());
+}
+
 proptest! {
+    #[test]
+    fn succ_pred_all(year in -MAX_YEARS..MAX_YEARS, day in 0..375) {
+        let d = day as u16;
+        succ_pred::<Armenian>(year, d);
+        succ_pred::<Coptic>(year, d);
+        succ_pred::<Cotsworth>(year, d);
+        succ_pred::<Egyptian>(year, d);
+        succ_pred::<Ethiopic>(year, d);
+        succ_pred::<FrenchRevArith<true>>(year, d);
+        succ_pred::<FrenchRevArith<false>>(year, d);
+        succ_pred::<Gregorian>(year, d);
+        succ_pred::<Holocene>(year, d);
+        succ_pred::<Julian>(year, d);
+        succ_pred::<Positivist>(year, d);
+        succ_pred::<Symmetry454>(year, d);
+        succ_pred::<Symmetry010Solstice>(year, d);
+        succ_pred::<TranquilityMoment>(year, d);
+    }
+
     #[test]
     fn valid_armenian(year: i32, day in 1..365) {
         let ord = OrdinalDate{ year: year, day_of_year: day as u16 };
@@ -0,0 +1,110 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use num_traits::FromPrimitive;
+use proptest::proptest;
+use radnelac::calendar::*;
+use radnelac::day_count::*;
+use radnelac::CalendarError;
+use std::fmt::Debug;
+
+fn packed<S, T>(t0: f64, t1: f64)
+where
+    S: FromPrimitive,
+    T: ToFromPackedDate<S> + FromFixed + ToFixed + Debug,
+{
+    let d0 = T::from_fixed(Fixed::new(t0).to_day());
+    let d1 = T::from_fixed(Fixed::new(t1).to_day());
+    match (d0.to_packed_i32(), d1.to_packed_i32()) {
+        (Ok(p0), Ok(p1)) => {
+            assert_eq!(T::try_from_packed_i32(p0).unwrap(), d0);
+            assert_eq!(T::try_from_packed_i32(p1).unwrap(), d1);
+            assert_eq!(
+                p0.cmp(&p1),
+                d0.to_fixed().partial_cmp(&d1.to_fixed()).unwrap()
+            );
+        }
+        (r0, r1) => {
+            for (r, d) in [(r0, d0), (r1, d1)] {
+                if let Err(e) = r {
+                    assert!(matches!(e, CalendarError::IntegerOverflow));
+                    assert!(d.year().abs() > 200000);
+                }
+            }
+        }
+    }
+}
+
+fn epoch_days<T: ToFromEpochDays + FromFixed + PartialEq + Debug>(t: f64) {
+    let d0 = T::from_fixed(Fixed::new(t).to_day());
+    match d0.to_epoch_days_i32() {
+        Ok(e) => assert_eq!(T::try_from_epoch_days_i32(e).unwrap(), d0),
+        Err(e) => assert!(matches!(e, CalendarError::IntegerOverflow)),
+    }
+}
+
+#[test]
+fn gregorian_packed() {
+    let d = Gregorian::try_new(2025, GregorianMonth::May, 15).unwrap();
+    assert_eq!(d.to_packed_i32().unwrap(), 20250515);
+    let d = Gregorian::try_new(-5, GregorianMonth::March, 1).unwrap();
+    assert_eq!(
+        Gregorian::try_from_packed_i32(d.to_packed_i32().unwrap()).unwrap(),
+        d
+    );
+    assert!(Gregorian::try_from_packed_i32(20251301).is_err());
+    let d = Gregorian::try_year_start(300000).unwrap();
+    assert!(matches!(
+        d.to_packed_i32(),
+        Err(CalendarError::IntegerOverflow)
+    ));
+}
+
+#[test]
+fn gregorian_epoch_days() {
+    let d = Gregorian::try_new(1, GregorianMonth::January, 2).unwrap();
+    assert_eq!(d.to_epoch_days_i32().unwrap(), 1);
+    let u = UnixMoment::try_from_epoch_days_i32(1).unwrap();
+    assert_eq!(u.get(), 86400);
+}
+
+const SMALL_MAX: f64 = 365.25 * 250000.0;
+
+proptest! {
+    #[test]
+    fn packed_dates(t0 in -SMALL_MAX..SMALL_MAX, t1 in -SMALL_MAX..SMALL_MAX) {
+        packed::<ArmenianMonth, Armenian>(t0, t1);
+        packed::<CopticMonth, Coptic>(t0, t1);
+        packed::<CotsworthMonth, Cotsworth>(t0, t1);
+        packed::<EgyptianMonth, Egyptian>(t0, t1);
+        packed::<EthiopicMonth, Ethiopic>(t0, t1);
+        packed::<FrenchRevMonth, FrenchRevArith<true>>(t0, t1);
+        packed::<GregorianMonth, Gregorian>(t0, t1);
+        packed::<HoloceneMonth, Holocene>(t0, t1);
+        packed::<JulianMonth, Julian>(t0, t1);
+        packed::<PositivistMonth, Positivist>(t0, t1);
+        packed::<RetailPeriod, Retail454>(t0, t1);
+        packed::<SymmetryMonth, Symmetry454>(t0, t1);
+        packed::<TranquilityMonth, Tranquility>(t0, t1);
+    }
+
+    #[test]
+    fn epoch_day_counts(t in FIXED_MIN..FIXED_MAX) {
+        epoch_days::<Armenian>(t);
+        epoch_days::<Coptic>(t);
+        epoch_days::<Gregorian>(t);
+        epoch_days::<Julian>(t);
+        epoch_days::<Positivist>(t);
+        epoch_days::<Tranquility>(t);
+        epoch_days::<RataDie>(t);
+    }
+
+    #[test]
+    fn epoch_day_counts_small(t in -SMALL_MAX..SMALL_MAX) {
+        epoch_days::<Gregorian>(t);
+        epoch_days::<Cotsworth>(t);
+        epoch_days::<FrenchRevArith<false>>(t);
+        epoch_days::<JulianDay>(t);
+    }
+}
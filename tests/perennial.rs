@@ -36,7 +36,7 @@ use radnelac::day_cycle::Weekday;
 
 use radnelac::calendar::Perennial;
 
-const MAX_YEARS: i32 = (FIXED_MAX / 365.25) as i32;
+const MAX_YEARS: i32 = ((FIXED_MAX / 365.25) - 20000.0) as i32; //Room for calendars with distant epochs
 
 fn complementary_xor_weekday<
     S: FromPrimitive + ToPrimitive,
@@ -28,6 +28,10 @@ use radnelac::calendar::JulianMonth;
 use radnelac::calendar::Positivist;
 use radnelac::calendar::PositivistMonth;
 use radnelac::calendar::Quarter;
+use radnelac::calendar::Retail445;
+use radnelac::calendar::Retail454;
+use radnelac::calendar::Retail544;
+use radnelac::calendar::RetailPeriod;
 use radnelac::calendar::Roman;
 use radnelac::calendar::Symmetry010;
 use radnelac::calendar::Symmetry010Solstice;
@@ -44,7 +48,7 @@ use radnelac::day_count::FromFixed;
 use radnelac::day_count::FIXED_MAX;
 use radnelac::day_count::FIXED_MIN;
 
-const MAX_YEARS: i32 = (FIXED_MAX / 365.25) as i32;
+const MAX_YEARS: i32 = ((FIXED_MAX / 365.25) - 20000.0) as i32; //Room for calendars with distant epochs
 
 fn quarter_tomorrow<T: Quarter + FromFixed>(f: f64) {
     let t0 = Fixed::new(f);
@@ -230,6 +234,20 @@ proptest! {
         quarter_boundary_cotsworth::<PositivistMonth, Positivist>(y);
     }
 
+    #[test]
+    fn retail(t in FIXED_MIN..FIXED_MAX) {
+        quarter_tomorrow::<Retail445>(t);
+        quarter_tomorrow::<Retail454>(t);
+        quarter_tomorrow::<Retail544>(t);
+    }
+
+    #[test]
+    fn retail_boundary(y in -MAX_YEARS..MAX_YEARS) {
+        quarter_boundary_m12::<RetailPeriod, Retail445>(y);
+        quarter_boundary_m12::<RetailPeriod, Retail454>(y);
+        quarter_boundary_m12::<RetailPeriod, Retail544>(y);
+    }
+
     #[test]
     fn roman(t in FIXED_MIN..FIXED_MAX) {
         quarter_tomorrow::<Roman>(t);
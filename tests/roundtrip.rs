@@ -3,21 +3,42 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use proptest::proptest;
+use radnelac::calendar::Afghan;
 use radnelac::calendar::Armenian;
+use radnelac::calendar::AtticArith;
+use radnelac::calendar::BabylonianArith;
+use radnelac::calendar::Bengali;
+use radnelac::calendar::BikramSambat;
+use radnelac::calendar::Coligny;
 use radnelac::calendar::Coptic;
 use radnelac::calendar::Cotsworth;
 use radnelac::calendar::Egyptian;
+use radnelac::calendar::ErisianYear;
 use radnelac::calendar::Ethiopic;
 use radnelac::calendar::FrenchRevArith;
 use radnelac::calendar::Gregorian;
 use radnelac::calendar::Holocene;
+use radnelac::calendar::Icelandic;
+use radnelac::calendar::IslamicArith;
+use radnelac::calendar::IslamicUmmAlQura;
 use radnelac::calendar::Julian;
+use radnelac::calendar::Kurdish;
+use radnelac::calendar::Minguo;
+use radnelac::calendar::OffsetCalendar;
+use radnelac::calendar::Pataphysical;
+use radnelac::calendar::PersianArith;
 use radnelac::calendar::Positivist;
+use radnelac::calendar::Retail;
+use radnelac::calendar::Retail445;
+use radnelac::calendar::Retail454;
+use radnelac::calendar::Retail544;
 use radnelac::calendar::Roman;
 use radnelac::calendar::Symmetry010;
 use radnelac::calendar::Symmetry010Solstice;
 use radnelac::calendar::Symmetry454;
 use radnelac::calendar::Symmetry454Solstice;
+use radnelac::calendar::Tamil;
+use radnelac::calendar::ThaiSolar;
 use radnelac::calendar::ToFromOrdinalDate;
 use radnelac::calendar::TranquilityMoment;
 use radnelac::calendar::ISO;
@@ -82,6 +103,26 @@ proptest! {
         roundtrip_ordinal::<Armenian>(t);
     }
 
+    #[test]
+    fn attic(t in FIXED_MIN..FIXED_MAX) {
+        roundtrip::<AtticArith>(t);
+        roundtrip_ordinal::<AtticArith>(t);
+    }
+
+    #[test]
+    fn babylonian(t in FIXED_MIN..FIXED_MAX) {
+        roundtrip::<BabylonianArith>(t);
+        roundtrip_ordinal::<BabylonianArith>(t);
+    }
+
+    #[test]
+    fn coligny(t in FIXED_MIN..FIXED_MAX) {
+        roundtrip::<Coligny<true>>(t);
+        roundtrip::<Coligny<false>>(t);
+        roundtrip_ordinal::<Coligny<true>>(t);
+        roundtrip_ordinal::<Coligny<false>>(t);
+    }
+
     #[test]
     fn coptic(t in FIXED_MIN..FIXED_MAX) {
         roundtrip::<Coptic>(t);
@@ -126,6 +167,36 @@ proptest! {
         roundtrip_ordinal::<Holocene>(t);
     }
 
+    #[test]
+    fn icelandic(t in FIXED_MIN..FIXED_MAX) {
+        roundtrip::<Icelandic>(t);
+    }
+
+    #[test]
+    fn bengali(t in FIXED_MIN..FIXED_MAX) {
+        roundtrip::<Bengali>(t);
+        roundtrip_ordinal::<Bengali>(t);
+    }
+
+    #[test]
+    fn bikram_sambat(t in 698446.0..739720.0) {
+        //Only the days in the month table can be round tripped
+        roundtrip::<BikramSambat>(t);
+        roundtrip_ordinal::<BikramSambat>(t);
+    }
+
+    #[test]
+    fn islamic(t in FIXED_MIN..FIXED_MAX) {
+        roundtrip::<IslamicArith>(t);
+        roundtrip_ordinal::<IslamicArith>(t);
+    }
+
+    #[test]
+    fn islamic_umm_al_qura(t in FIXED_MIN..FIXED_MAX) {
+        roundtrip::<IslamicUmmAlQura>(t);
+        roundtrip_ordinal::<IslamicUmmAlQura>(t);
+    }
+
     #[test]
     fn iso(t in FIXED_MIN..FIXED_MAX) {
         roundtrip::<ISO>(t);
@@ -137,12 +208,52 @@ proptest! {
         roundtrip_ordinal::<Julian>(t);
     }
 
+    #[test]
+    fn offset(t in FIXED_MIN..FIXED_MAX) {
+        roundtrip::<ThaiSolar>(t);
+        roundtrip::<Minguo>(t);
+        roundtrip::<OffsetCalendar<Coptic, 0, -100809>>(t);
+        roundtrip::<OffsetCalendar<Julian, 0, 2>>(t);
+        roundtrip_ordinal::<ThaiSolar>(t);
+        roundtrip_ordinal::<Minguo>(t);
+        roundtrip_ordinal::<OffsetCalendar<Coptic, 0, -100809>>(t);
+        roundtrip::<ErisianYear>(t);
+        roundtrip::<Kurdish>(t);
+        roundtrip::<Afghan>(t);
+        roundtrip_ordinal::<Kurdish>(t);
+        roundtrip_ordinal::<Afghan>(t);
+    }
+
+    #[test]
+    fn pataphysical(t in FIXED_MIN..FIXED_MAX) {
+        roundtrip::<Pataphysical>(t);
+        roundtrip_ordinal::<Pataphysical>(t);
+    }
+
+    #[test]
+    fn persian(t in FIXED_MIN..FIXED_MAX) {
+        roundtrip::<PersianArith>(t);
+        roundtrip_ordinal::<PersianArith>(t);
+    }
+
     #[test]
     fn positivist(t in FIXED_MIN..FIXED_MAX) {
         roundtrip::<Positivist>(t);
         roundtrip_ordinal::<Positivist>(t);
     }
 
+    #[test]
+    fn retail(t in FIXED_MIN..FIXED_MAX) {
+        roundtrip::<Retail445>(t);
+        roundtrip::<Retail454>(t);
+        roundtrip::<Retail544>(t);
+        roundtrip::<Retail<445, 12, 0, false>>(t);
+        roundtrip_ordinal::<Retail445>(t);
+        roundtrip_ordinal::<Retail454>(t);
+        roundtrip_ordinal::<Retail544>(t);
+        roundtrip_ordinal::<Retail<445, 12, 0, false>>(t);
+    }
+
     #[test]
     fn roman(t in FIXED_MIN..FIXED_MAX) {
         roundtrip::<Roman>(t);
@@ -160,6 +271,12 @@ proptest! {
         roundtrip_ordinal::<Symmetry010Solstice>(t);
     }
 
+    #[test]
+    fn tamil(t in FIXED_MIN..FIXED_MAX) {
+        roundtrip::<Tamil>(t);
+        roundtrip_ordinal::<Tamil>(t);
+    }
+
     #[test]
     fn tranquility(t in FIXED_MIN..FIXED_MAX) {
         roundtrip::<TranquilityMoment>(t);
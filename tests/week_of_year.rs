@@ -9,6 +9,7 @@ use radnelac::calendar::*;
 use radnelac::day_count::BoundedDayCount;
 use radnelac::day_count::Fixed;
 use radnelac::day_count::FromFixed;
+use radnelac::day_count::RataDie;
 use radnelac::day_count::FIXED_MAX;
 use radnelac::day_count::FIXED_MIN;
 use radnelac::day_cycle::Weekday;
@@ -84,7 +85,143 @@ where
     }
 }
 
+fn complementary_week_of_year_total<S, U, V>(max: u8, t: f64)
+where
+    S: FromPrimitive + ToPrimitive,
+    U: FromPrimitive + ToPrimitive,
+    V: ComplementaryWeekOfYear<S, U>,
+{
+    let d0 = V::from_fixed(Fixed::new(t));
+    let d1 = V::from_fixed(Fixed::new(t + 1.0));
+    let w0 = d0.complementary_week_of_year();
+    let w1 = d1.complementary_week_of_year();
+    assert!(w0 >= 1 && w0 <= max);
+    assert!(w1 >= 1 && w1 <= max);
+    if let Some(w) = d0.try_week_of_year() {
+        assert_eq!(w0, w);
+    }
+    if d0.year() == d1.year() {
+        assert!(w1 == w0 || w1 == w0 + 1);
+    } else {
+        assert_eq!(w1, 1);
+    }
+}
+
+fn new_year_weekday<S, T>(t: f64)
+where
+    S: FromPrimitive,
+    T: FromFixed + CommonWeekOfYear<S> + HasLeapYears,
+{
+    let d = T::from_fixed(Fixed::new(t));
+    let year = d.year();
+    if let Ok(w) = T::weekday_of_new_year(year) {
+        let start = T::try_year_start(year).unwrap();
+        assert_eq!(w, start.convert::<Weekday>());
+        assert_eq!(T::is_common_year_starting_on(year, w), !T::is_leap(year));
+        assert_eq!(T::is_leap_year_starting_on(year, w), T::is_leap(year));
+        let w1 = Weekday::from_i64(((w as i64) + 1) % 7).unwrap();
+        assert!(!T::is_common_year_starting_on(year, w1));
+        assert!(!T::is_leap_year_starting_on(year, w1));
+    }
+}
+
+fn kday_of_month<S, T>(t: f64, n: i16, k: u8)
+where
+    S: FromPrimitive + ToPrimitive,
+    T: FromFixed + CommonWeekOfYear<S>,
+{
+    let d = T::from_fixed(Fixed::new(t));
+    let k = Weekday::from_u8(k).unwrap();
+    let nz = std::num::NonZero::new(n).unwrap();
+    if let Some(month) = d.try_month() {
+        let c = d.to_common_date();
+        let length = T::month_length(c.year, month);
+        let month = S::from_u8(c.month).unwrap();
+        match T::nth_kday_of_month(c.year, month, nz, k) {
+            Ok(r) => {
+                let rc = r.to_common_date();
+                assert_eq!((rc.year, rc.month), (c.year, c.month));
+                assert_eq!(r.convert::<Weekday>(), k);
+                if n > 0 {
+                    assert_eq!(((rc.day - 1) / 7) + 1, n as u8);
+                    assert_eq!(r.week_of_month(), n as u8);
+                } else {
+                    assert_eq!(((length - rc.day) / 7) + 1, (-n) as u8);
+                }
+            }
+            Err(_) => assert!(7 * (n.unsigned_abs() as u8) > length),
+        }
+    }
+}
+
+#[test]
+fn gregorian_new_year() {
+    let w = Gregorian::weekday_of_new_year(2025).unwrap();
+    assert_eq!(w, Weekday::Wednesday);
+    assert!(Gregorian::is_common_year_starting_on(
+        2025,
+        Weekday::Wednesday
+    ));
+    assert!(Gregorian::is_leap_year_starting_on(2024, Weekday::Monday));
+    assert!(!Gregorian::is_common_year_starting_on(
+        2024,
+        Weekday::Monday
+    ));
+    assert!(Julian::weekday_of_new_year(0).is_err());
+    assert!(!Julian::is_common_year_starting_on(0, Weekday::Monday));
+}
+
+#[test]
+fn complementary_days() {
+    let leap = (2000..2100).find(|y| Cotsworth::is_leap(*y)).unwrap();
+    let leap_day = Cotsworth::try_from_common_date(CommonDate::new(leap, 6, 29)).unwrap();
+    assert_eq!(
+        leap_day.epagomenae(),
+        Some(CotsworthComplementaryDay::LeapDay)
+    );
+    assert_eq!(leap_day.complementary_week_of_year(), 24);
+    let year_day = Cotsworth::try_year_end(leap).unwrap();
+    assert_eq!(year_day.complementary_week_of_year(), 52);
+    let dead = Positivist::try_year_end(1).unwrap();
+    assert_eq!(
+        dead.epagomenae(),
+        Some(PositivistComplementaryDay::FestivalOfTheDead)
+    );
+    assert_eq!(dead.complementary_week_of_year(), 52);
+    let c = CommonDate::new(0, 0, TranquilityComplementaryDay::MoonLandingDay as u8);
+    let moon_landing = Tranquility::try_from_common_date(c).unwrap();
+    assert_eq!(moon_landing.complementary_week_of_year(), 1);
+    let leap = (1..100).find(|y| Tranquility::is_leap(*y)).unwrap();
+    let c = CommonDate::new(leap, 0, TranquilityComplementaryDay::AldrinDay as u8);
+    let aldrin = Tranquility::try_from_common_date(c).unwrap();
+    assert_eq!(aldrin.complementary_week_of_year(), 32);
+}
+
 proptest! {
+    #[test]
+    fn kday(t in FIXED_MIN..FIXED_MAX, n in 1..6i16, neg: bool, k in 0..7u8) {
+        let n = if neg { -n } else { n };
+        kday_of_month::<ArmenianMonth, Armenian>(t, n, k);
+        kday_of_month::<CopticMonth, Coptic>(t, n, k);
+        kday_of_month::<GregorianMonth, Gregorian>(t, n, k);
+        kday_of_month::<JulianMonth, Julian>(t, n, k);
+        kday_of_month::<RetailPeriod, Retail445>(t, n, k);
+        kday_of_month::<SymmetryMonth, Symmetry010>(t, n, k);
+        kday_of_month::<GregorianMonth, GregorianMoment>(t, n, k);
+    }
+
+    #[test]
+    fn new_year(t in FIXED_MIN..FIXED_MAX) {
+        new_year_weekday::<CopticMonth, Coptic>(t);
+        new_year_weekday::<EthiopicMonth, Ethiopic>(t);
+        new_year_weekday::<GregorianMonth, Gregorian>(t);
+        new_year_weekday::<HoloceneMonth, Holocene>(t);
+        new_year_weekday::<JulianMonth, Julian>(t);
+        new_year_weekday::<RetailPeriod, Retail454>(t);
+        new_year_weekday::<SymmetryMonth, Symmetry454>(t);
+        new_year_weekday::<GregorianMonth, GregorianMoment>(t);
+    }
+
     #[test]
     fn armenian(t in FIXED_MIN..FIXED_MAX, dt in 1..6) {
         common_week_of_year::<ArmenianMonth, Armenian>(53, t, dt as u8);
@@ -101,6 +238,7 @@ proptest! {
     fn cotsworth(t in FIXED_MIN..FIXED_MAX, dt in 1..6) {
         complementary_week_of_year::<CotsworthMonth, Weekday, Cotsworth>(false, 53, t, dt as u8);
         complementary_week_of_year::<CotsworthMonth, Weekday, CotsworthMoment>(false, 53, t, dt as u8);
+        complementary_week_of_year_total::<CotsworthMonth, Weekday, Cotsworth>(52, t);
     }
 
     #[test]
@@ -121,6 +259,8 @@ proptest! {
         complementary_week_of_year::<FrenchRevMonth, FrenchRevWeekday, FrenchRevArith<false>>(false, 36, t, dt as u8);
         complementary_week_of_year::<FrenchRevMonth, FrenchRevWeekday, FrenchRevArithMoment<true>>(false, 36, t, dt as u8);
         complementary_week_of_year::<FrenchRevMonth, FrenchRevWeekday, FrenchRevArithMoment<false>>(false, 36, t, dt as u8);
+        complementary_week_of_year_total::<FrenchRevMonth, FrenchRevWeekday, FrenchRevArith<true>>(36, t);
+        complementary_week_of_year_total::<FrenchRevMonth, FrenchRevWeekday, FrenchRevArith<false>>(36, t);
     }
 
     #[test]
@@ -151,6 +291,23 @@ proptest! {
 
     }
 
+    #[test]
+    fn iso_week(t in FIXED_MIN..FIXED_MAX) {
+        let f = Fixed::new(t);
+        let i = ISO::from_fixed(f);
+        let expected = (i.year(), i.week(), i.day());
+        assert_eq!(Gregorian::from_fixed(f).iso_week(), expected);
+        assert_eq!(GregorianMoment::from_fixed(f).iso_week(), expected);
+        assert_eq!(Julian::from_fixed(f).iso_week(), expected);
+        assert_eq!(Coptic::from_fixed(f).iso_week(), expected);
+        assert_eq!(Cotsworth::from_fixed(f).iso_week(), expected);
+        assert_eq!(Symmetry454::from_fixed(f).iso_week(), expected);
+        assert_eq!(Symmetry010Moment::from_fixed(f).iso_week(), expected);
+        assert_eq!(Tranquility::from_fixed(f).iso_week(), expected);
+        assert_eq!(i.iso_week(), expected);
+        assert_eq!(RataDie::from_fixed(f).iso_week(), expected);
+    }
+
     #[test]
     fn julian(t in FIXED_MIN..FIXED_MAX, dt in 1..6) {
         common_week_of_year::<JulianMonth, Julian>(53, t, dt as u8);
@@ -161,6 +318,7 @@ proptest! {
     fn positivist(t in FIXED_MIN..FIXED_MAX, dt in 1..6) {
         complementary_week_of_year::<PositivistMonth, Weekday, Positivist>(false, 53, t, dt as u8);
         complementary_week_of_year::<PositivistMonth, Weekday, PositivistMoment>(false, 53, t, dt as u8);
+        complementary_week_of_year_total::<PositivistMonth, Weekday, Positivist>(52, t);
     }
 
     #[test]
@@ -179,6 +337,8 @@ proptest! {
     fn tranquility(t in FIXED_MIN..FIXED_MAX, dt in 1..6) {
         complementary_week_of_year::<TranquilityMonth, Weekday, Tranquility>(true, 53, t, dt as u8);
         complementary_week_of_year::<TranquilityMonth, Weekday, TranquilityMoment>(true, 53, t, dt as u8);
+        complementary_week_of_year_total::<TranquilityMonth, Weekday, Tranquility>(52, t);
+        complementary_week_of_year_total::<TranquilityMonth, Weekday, TranquilityMoment>(52, t);
     }
 
 }
@@ -8,7 +8,7 @@ use proptest::proptest;
 use radnelac::calendar::*;
 use radnelac::day_count::FIXED_MAX;
 
-const MAX_YEARS: i32 = (FIXED_MAX / 365.25) as i32;
+const MAX_YEARS: i32 = ((FIXED_MAX / 365.25) - 20000.0) as i32; //Room for calendars with distant epochs
 
 fn common_date_roundtrip<T: FromPrimitive, U: ToFromCommonDate<T>, const V: bool>(d: CommonDate) {
     let e0 = U::try_from_common_date(d).unwrap();